@@ -0,0 +1,267 @@
+//! client hot-path benchmarks
+//!
+//! run with `cargo bench --bench client`. responses come from
+//! `benches/fixtures/device_page.json`, repeated to reach larger pages, and
+//! are served by a loopback http server so `execute` runs end to end. with
+//! `--features simd-json`, `codec` compares `SimdJson` decoding through a
+//! `Value` against decoding straight into the target type, from its 16 KiB
+//! threshold up.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use infrahub::{Client, ClientConfig, GraphQlResponse, OffsetPage, OffsetPaginator};
+use serde::Deserialize;
+use serde_json::Value;
+use std::hint::black_box;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tokio::runtime::Runtime;
+
+const FIXTURE: &str = include_str!("fixtures/device_page.json");
+
+/// page sizes as multiples of the fixture's 100 edges
+const SCALES: [usize; 3] = [1, 10, 50];
+
+#[derive(Deserialize)]
+struct DeviceData {
+    #[serde(rename = "InfraDevice")]
+    devices: DeviceConnection,
+}
+
+#[derive(Deserialize)]
+struct DeviceConnection {
+    count: i64,
+    edges: Vec<DeviceEdge>,
+}
+
+#[derive(Deserialize)]
+struct DeviceEdge {
+    node: Device,
+}
+
+// fields are only deserialized, never read
+#[allow(dead_code)]
+#[derive(Deserialize)]
+struct Device {
+    id: String,
+    name: Attribute,
+    description: Attribute,
+    role: Attribute,
+}
+
+#[allow(dead_code)]
+#[derive(Deserialize)]
+struct Attribute {
+    value: Option<String>,
+}
+
+/// the fixture with its edges repeated `scale` times
+fn response_body(scale: usize) -> String {
+    let edges = serde_json::from_str::<Value>(FIXTURE).expect("fixture")["data"]["InfraDevice"]
+        ["edges"]
+        .as_array()
+        .expect("edges")
+        .len();
+    body_with_edges(edges * scale)
+}
+
+/// the fixture with its edges cut or repeated to `count` edges
+fn body_with_edges(count: usize) -> String {
+    let mut body: Value = serde_json::from_str(FIXTURE).expect("fixture");
+    let connection = &mut body["data"]["InfraDevice"];
+    let edges = connection["edges"].as_array().expect("edges").clone();
+    let repeated: Vec<Value> = edges.iter().cycle().take(count).cloned().collect();
+    connection["count"] = repeated.len().into();
+    connection["edges"] = Value::Array(repeated);
+    body.to_string()
+}
+
+/// serve `body` to every request on a loopback port, returning the base url
+async fn serve(body: String) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let addr = listener.local_addr().expect("addr");
+    let response = format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{body}",
+        body.len()
+    );
+    tokio::spawn(async move {
+        loop {
+            let Ok((stream, _)) = listener.accept().await else {
+                return;
+            };
+            let response = response.clone();
+            tokio::spawn(async move {
+                let (read, mut write) = stream.into_split();
+                let mut reader = BufReader::new(read);
+                loop {
+                    let mut length = 0;
+                    let mut line = String::new();
+                    loop {
+                        line.clear();
+                        if reader.read_line(&mut line).await.unwrap_or(0) == 0 {
+                            return;
+                        }
+                        if line == "\r\n" {
+                            break;
+                        }
+                        if let Some((name, value)) = line.split_once(':') {
+                            if name.eq_ignore_ascii_case("content-length") {
+                                length = value.trim().parse().unwrap_or(0);
+                            }
+                        }
+                    }
+                    let mut request = vec![0; length];
+                    if reader.read_exact(&mut request).await.is_err()
+                        || write.write_all(response.as_bytes()).await.is_err()
+                    {
+                        return;
+                    }
+                }
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_graphql_response");
+    for scale in SCALES {
+        let body = response_body(scale);
+        group.throughput(Throughput::Bytes(body.len() as u64));
+        group.bench_with_input(BenchmarkId::new("value", scale), &body, |b, body| {
+            b.iter(|| {
+                let parsed: GraphQlResponse<Value> = serde_json::from_str(body).unwrap();
+                black_box(parsed)
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("typed", scale), &body, |b, body| {
+            b.iter(|| {
+                let parsed: GraphQlResponse<DeviceData> = serde_json::from_str(body).unwrap();
+                black_box(parsed)
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_execute(c: &mut Criterion) {
+    let runtime = Runtime::new().expect("runtime");
+    let mut group = c.benchmark_group("execute");
+    for scale in SCALES {
+        let body = response_body(scale);
+        group.throughput(Throughput::Bytes(body.len() as u64));
+        let url = runtime.block_on(serve(body));
+        let client = Client::new(ClientConfig::new(url, "token")).expect("client");
+        group.bench_function(BenchmarkId::new("typed", scale), |b| {
+            b.to_async(&runtime).iter(|| async {
+                let response = client
+                    .execute::<DeviceData>("{ InfraDevice { count } }", None, None)
+                    .await
+                    .unwrap();
+                black_box(response)
+            })
+        });
+    }
+    group.finish();
+}
+
+/// `SimdJson` behind only `JsonCodec::decode`, so responses take the `Value`
+/// path
+#[cfg(feature = "simd-json")]
+struct SimdJsonValue(infrahub::SimdJson);
+
+#[cfg(feature = "simd-json")]
+impl infrahub::JsonCodec for SimdJsonValue {
+    fn encode(&self, body: &Value) -> infrahub::Result<Vec<u8>> {
+        self.0.encode(body)
+    }
+
+    fn decode(&self, body: &mut [u8]) -> infrahub::Result<Value> {
+        self.0.decode(body)
+    }
+}
+
+/// end to end `execute` with `serde_json`, and with `SimdJson` decoding
+/// through a `Value` and straight into the target type, for a body just over
+/// the 16 KiB threshold and the larger pages
+#[cfg(feature = "simd-json")]
+fn bench_codec(c: &mut Criterion) {
+    use infrahub::SimdJson;
+
+    let runtime = Runtime::new().expect("runtime");
+    let threshold = (1..)
+        .map(body_with_edges)
+        .find(|body| body.len() >= 16 * 1024)
+        .expect("threshold body");
+    let bodies = std::iter::once(("16k".to_string(), threshold))
+        .chain(SCALES.map(|scale| (scale.to_string(), response_body(scale))));
+
+    let mut group = c.benchmark_group("codec");
+    for (size, body) in bodies {
+        group.throughput(Throughput::Bytes(body.len() as u64));
+        let url = runtime.block_on(serve(body));
+        let config = || ClientConfig::new(url.as_str(), "token");
+        let clients = [
+            ("serde_json", Client::new(config()).expect("client")),
+            (
+                "simd_json_value",
+                Client::new(config().with_json_codec(SimdJsonValue(SimdJson::new())))
+                    .expect("client"),
+            ),
+            (
+                "simd_json",
+                Client::new(config().with_json_codec(SimdJson::new())).expect("client"),
+            ),
+        ];
+        for (name, client) in &clients {
+            group.bench_function(BenchmarkId::new(*name, &size), |b| {
+                b.to_async(&runtime).iter(|| async {
+                    let response = client
+                        .execute::<DeviceData>("{ InfraDevice { count } }", None, None)
+                        .await
+                        .unwrap();
+                    black_box(response)
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_pagination(c: &mut Criterion) {
+    let runtime = Runtime::new().expect("runtime");
+    let body = response_body(50);
+    let devices: GraphQlResponse<DeviceData> = serde_json::from_str(&body).unwrap();
+    let data = devices.data.expect("data").devices;
+    let count = data.count;
+    let nodes: Vec<Device> = data.edges.into_iter().map(|edge| edge.node).collect();
+    let ids: Vec<&str> = nodes.iter().map(|node| node.id.as_str()).collect();
+
+    let mut group = c.benchmark_group("pagination");
+    group.throughput(Throughput::Elements(count as u64));
+    for limit in [50, 500] {
+        group.bench_function(BenchmarkId::new("offset", limit), |b| {
+            b.to_async(&runtime).iter(|| async {
+                let pages = OffsetPaginator::new(limit, |offset, limit| {
+                    let start = (offset as usize).min(ids.len());
+                    let end = (start + limit as usize).min(ids.len());
+                    let page = ids[start..end].to_vec();
+                    async move { Ok(OffsetPage { nodes: page, count }) }
+                });
+                black_box(pages.collect_all().await.unwrap())
+            })
+        });
+    }
+    group.finish();
+}
+
+#[cfg(not(feature = "simd-json"))]
+criterion_group!(benches, bench_parse, bench_execute, bench_pagination);
+#[cfg(feature = "simd-json")]
+criterion_group!(
+    benches,
+    bench_parse,
+    bench_execute,
+    bench_codec,
+    bench_pagination
+);
+criterion_main!(benches);
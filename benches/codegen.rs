@@ -0,0 +1,35 @@
+//! codegen benchmark
+//!
+//! run with `cargo bench --features codegen --bench codegen`. times a full
+//! `infrahub-codegen` run on the reference schema in `schema/infrahub.graphql`,
+//! including process startup and writing the generated crate.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::path::Path;
+use std::process::Command;
+
+fn bench_codegen(c: &mut Criterion) {
+    let schema = Path::new(env!("CARGO_MANIFEST_DIR")).join("schema/infrahub.graphql");
+    let out = std::env::temp_dir().join(format!("infrahub-codegen-bench-{}", std::process::id()));
+
+    let mut group = c.benchmark_group("codegen");
+    group.sample_size(10);
+    group.bench_function("reference_schema", |b| {
+        b.iter(|| {
+            let status = Command::new(env!("CARGO_BIN_EXE_infrahub-codegen"))
+                .arg("--schema")
+                .arg(&schema)
+                .arg("--out")
+                .arg(&out)
+                .status()
+                .expect("run infrahub-codegen");
+            assert!(status.success());
+        })
+    });
+    group.finish();
+
+    let _ = std::fs::remove_dir_all(&out);
+}
+
+criterion_group!(benches, bench_codegen);
+criterion_main!(benches);
@@ -0,0 +1,37 @@
+/* c interface to the infrahub client, built with the `ffi` feature.
+ * mirrors src/ffi.rs; cbindgen.toml regenerates it with cbindgen. */
+
+#ifndef INFRAHUB_H
+#define INFRAHUB_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* opaque client handle returned by infrahub_client_new */
+typedef struct InfrahubClient InfrahubClient;
+
+/* create a client for base_url authenticating with token. returns NULL on
+ * failure; if error is not NULL it then receives a message to release with
+ * infrahub_string_free. */
+InfrahubClient *infrahub_client_new(const char *base_url, const char *token, char **error);
+
+/* run a graphql query. variables_json and branch may be NULL. returns the
+ * response json, or {"error": {"kind": ..., "message": ...}}; never NULL.
+ * release with infrahub_string_free. */
+char *infrahub_execute(const InfrahubClient *client,
+                       const char *query,
+                       const char *variables_json,
+                       const char *branch);
+
+/* release a string returned by this library */
+void infrahub_string_free(char *value);
+
+/* release a client and shut down its runtime */
+void infrahub_client_free(InfrahubClient *client);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* INFRAHUB_H */
@@ -0,0 +1,134 @@
+//! compile-time checked graphql operations for the infrahub client
+//!
+//! use this through the `macros` feature of the `infrahub` crate, which
+//! re-exports [`query!`].
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use std::path::PathBuf;
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, LitStr, Token, Type, Visibility};
+
+mod validate;
+
+/// check a graphql document against a schema file and implement
+/// `infrahub::Operation` for it
+///
+/// ```ignore
+/// infrahub::query! {
+///     schema = "schema/infrahub.graphql",
+///     query = r#"
+///         query GetTags($name: String) {
+///             BuiltinTag(name__value: $name) { count edges { node { id } } }
+///         }
+///     "#,
+///     response = Tags,
+/// }
+/// ```
+///
+/// expands to a unit struct named after the operation (`GetTags`) that
+/// implements `Operation` with the document as `QUERY`. `schema` is a path
+/// relative to the calling crate's `Cargo.toml`. `response` is optional and
+/// defaults to `serde_json::Value`; `vis = pub` exports the struct. unknown fields, arguments, fragment
+/// types, and variable types, as well as missing or superfluous selections,
+/// fail the build.
+#[proc_macro]
+pub fn query(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as QueryInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+struct QueryInput {
+    schema: LitStr,
+    query: LitStr,
+    response: Option<Type>,
+    vis: Option<Visibility>,
+}
+
+impl Parse for QueryInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut schema = None;
+        let mut query = None;
+        let mut response = None;
+        let mut vis = None;
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            match key.to_string().as_str() {
+                "schema" if schema.is_none() => schema = Some(input.parse()?),
+                "query" if query.is_none() => query = Some(input.parse()?),
+                "response" if response.is_none() => response = Some(input.parse()?),
+                "vis" if vis.is_none() => vis = Some(input.parse()?),
+                "schema" | "query" | "response" | "vis" => {
+                    return Err(syn::Error::new(key.span(), format!("duplicate `{key}`")))
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!(
+                            "unknown key `{key}`; expected `schema`, `query`, `response`, or `vis`"
+                        ),
+                    ))
+                }
+            }
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+        let missing = |key| syn::Error::new(Span::call_site(), format!("missing `{key}`"));
+        Ok(Self {
+            schema: schema.ok_or_else(|| missing("schema"))?,
+            query: query.ok_or_else(|| missing("query"))?,
+            response,
+            vis,
+        })
+    }
+}
+
+fn expand(input: QueryInput) -> syn::Result<proc_macro2::TokenStream> {
+    let root = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let path = PathBuf::from(root).join(input.schema.value());
+    let sdl = std::fs::read_to_string(&path).map_err(|err| {
+        syn::Error::new(
+            input.schema.span(),
+            format!("cannot read schema {}: {err}", path.display()),
+        )
+    })?;
+    let schema =
+        validate::Schema::parse(&sdl).map_err(|err| syn::Error::new(input.schema.span(), err))?;
+    let document = input.query.value();
+    let name = schema
+        .validate(&document)
+        .map_err(|err| syn::Error::new(input.query.span(), err))?;
+
+    let ident = Ident::new(&name, input.query.span());
+    let document = document.trim();
+    let path = path.display().to_string();
+    let response = match input.response {
+        Some(response) => quote!(#response),
+        None => quote!(::infrahub::__private::serde_json::Value),
+    };
+    let vis = input.vis.unwrap_or(Visibility::Inherited);
+    let doc = format!(
+        "the `{name}` operation, checked against `{}`",
+        input.schema.value()
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, Default)]
+        #vis struct #ident;
+
+        impl ::infrahub::Operation for #ident {
+            const NAME: &'static str = #name;
+            const QUERY: &'static str = #document;
+            type Response = #response;
+        }
+
+        // rebuild when the schema changes
+        const _: &str = include_str!(#path);
+    })
+}
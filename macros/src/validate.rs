@@ -0,0 +1,460 @@
+//! graphql document validation
+//!
+//! checks an operation against a schema: every field, argument, fragment
+//! type, and variable type must exist, composite fields need a selection
+//! and leaf fields must not have one.
+
+use graphql_parser::query::{
+    self, Definition, FragmentDefinition, OperationDefinition, Selection, SelectionSet,
+    TypeCondition,
+};
+use graphql_parser::schema::{self, TypeDefinition, TypeExtension};
+use graphql_parser::Pos;
+use std::collections::{BTreeMap, BTreeSet};
+
+const BUILTIN_SCALARS: &[&str] = &["String", "Int", "Float", "Boolean", "ID"];
+
+/// the shape of a named schema type, as far as validation cares
+enum Kind {
+    /// object or interface, by field name: argument names and type name
+    Composite(BTreeMap<String, (Vec<String>, String)>),
+    /// union: only `__typename` and fragments can be selected
+    Union,
+    /// scalar or enum
+    Leaf,
+    /// input object
+    Input,
+}
+
+/// the types of a parsed schema
+pub(crate) struct Schema {
+    types: BTreeMap<String, Kind>,
+    query: String,
+    mutation: String,
+    subscription: String,
+}
+
+impl Schema {
+    /// parse and index schema sdl
+    pub(crate) fn parse(sdl: &str) -> Result<Self, String> {
+        let document = graphql_parser::parse_schema::<String>(sdl)
+            .map_err(|err| format!("invalid schema: {err}"))?;
+        let mut schema = Schema {
+            types: BUILTIN_SCALARS
+                .iter()
+                .map(|name| (name.to_string(), Kind::Leaf))
+                .collect(),
+            query: "Query".to_string(),
+            mutation: "Mutation".to_string(),
+            subscription: "Subscription".to_string(),
+        };
+        let mut extensions = Vec::new();
+        for definition in document.definitions {
+            match definition {
+                schema::Definition::SchemaDefinition(roots) => {
+                    if let Some(name) = roots.query {
+                        schema.query = name;
+                    }
+                    if let Some(name) = roots.mutation {
+                        schema.mutation = name;
+                    }
+                    if let Some(name) = roots.subscription {
+                        schema.subscription = name;
+                    }
+                }
+                schema::Definition::TypeDefinition(definition) => {
+                    let (name, kind) = match definition {
+                        TypeDefinition::Object(object) => (object.name, composite(object.fields)),
+                        TypeDefinition::Interface(interface) => {
+                            (interface.name, composite(interface.fields))
+                        }
+                        TypeDefinition::Union(union) => (union.name, Kind::Union),
+                        TypeDefinition::Scalar(scalar) => (scalar.name, Kind::Leaf),
+                        TypeDefinition::Enum(enumeration) => (enumeration.name, Kind::Leaf),
+                        TypeDefinition::InputObject(input) => (input.name, Kind::Input),
+                    };
+                    schema.types.insert(name, kind);
+                }
+                schema::Definition::TypeExtension(extension) => extensions.push(extension),
+                schema::Definition::DirectiveDefinition(_) => {}
+            }
+        }
+        for extension in extensions {
+            let (name, fields) = match extension {
+                TypeExtension::Object(object) => (object.name, object.fields),
+                TypeExtension::Interface(interface) => (interface.name, interface.fields),
+                _ => continue,
+            };
+            if let Some(Kind::Composite(existing)) = schema.types.get_mut(&name) {
+                if let Kind::Composite(added) = composite(fields) {
+                    existing.extend(added);
+                }
+            }
+        }
+        Ok(schema)
+    }
+
+    /// validate `document`, returning the name of its single operation
+    pub(crate) fn validate(&self, document: &str) -> Result<String, String> {
+        let document = graphql_parser::parse_query::<String>(document)
+            .map_err(|err| format!("invalid graphql: {err}"))?;
+        let mut fragments = BTreeMap::new();
+        let mut operations = Vec::new();
+        for definition in &document.definitions {
+            match definition {
+                Definition::Fragment(fragment) => {
+                    fragments.insert(fragment.name.as_str(), fragment);
+                }
+                Definition::Operation(operation) => operations.push(operation),
+            }
+        }
+        let [operation] = operations.as_slice() else {
+            return Err(format!(
+                "expected exactly one operation, found {}",
+                operations.len()
+            ));
+        };
+        let (name, root, variables, selection_set) = match operation {
+            OperationDefinition::Query(op) => (
+                &op.name,
+                &self.query,
+                &op.variable_definitions,
+                &op.selection_set,
+            ),
+            OperationDefinition::Mutation(op) => (
+                &op.name,
+                &self.mutation,
+                &op.variable_definitions,
+                &op.selection_set,
+            ),
+            OperationDefinition::Subscription(op) => (
+                &op.name,
+                &self.subscription,
+                &op.variable_definitions,
+                &op.selection_set,
+            ),
+            OperationDefinition::SelectionSet(_) => {
+                return Err("the operation needs a name, such as `query GetTags { ... }`".into())
+            }
+        };
+        let name = name
+            .clone()
+            .ok_or("the operation needs a name, such as `query GetTags { ... }`")?;
+        if !self.types.contains_key(root) {
+            return Err(format!("the schema has no `{root}` root type"));
+        }
+        for variable in variables {
+            let type_name = named_type(&variable.var_type);
+            match self.types.get(type_name) {
+                Some(Kind::Leaf | Kind::Input) => {}
+                Some(_) => {
+                    return Err(at(
+                        variable.position,
+                        format!(
+                            "variable `${}` has output type `{type_name}`",
+                            variable.name
+                        ),
+                    ))
+                }
+                None => {
+                    return Err(at(
+                        variable.position,
+                        format!(
+                            "unknown type `{type_name}` for variable `${}`",
+                            variable.name
+                        ),
+                    ))
+                }
+            }
+        }
+        let mut checker = Checker {
+            schema: self,
+            fragments: &fragments,
+            visiting: BTreeSet::new(),
+        };
+        checker.selection_set(root, selection_set)?;
+        Ok(name)
+    }
+}
+
+fn composite(fields: Vec<schema::Field<'_, String>>) -> Kind {
+    Kind::Composite(
+        fields
+            .into_iter()
+            .map(|field| {
+                let arguments = field.arguments.into_iter().map(|arg| arg.name).collect();
+                let type_name = named_type(&field.field_type).to_string();
+                (field.name, (arguments, type_name))
+            })
+            .collect(),
+    )
+}
+
+fn named_type<'a>(ty: &'a schema::Type<'_, String>) -> &'a str {
+    match ty {
+        schema::Type::NamedType(name) => name,
+        schema::Type::ListType(inner) | schema::Type::NonNullType(inner) => named_type(inner),
+    }
+}
+
+fn at(pos: Pos, message: String) -> String {
+    format!("{message} (line {}, column {})", pos.line, pos.column)
+}
+
+/// walks selection sets, following fragment spreads once per path
+struct Checker<'s, 'd> {
+    schema: &'s Schema,
+    fragments: &'d BTreeMap<&'d str, &'d FragmentDefinition<'d, String>>,
+    visiting: BTreeSet<&'d str>,
+}
+
+impl<'d> Checker<'_, 'd> {
+    fn selection_set(
+        &mut self,
+        type_name: &str,
+        selection_set: &'d SelectionSet<'d, String>,
+    ) -> Result<(), String> {
+        for selection in &selection_set.items {
+            match selection {
+                Selection::Field(field) => self.field(type_name, field)?,
+                Selection::InlineFragment(fragment) => {
+                    let target = match &fragment.type_condition {
+                        Some(TypeCondition::On(target)) => {
+                            self.composite_type(target, fragment.position)?;
+                            target.as_str()
+                        }
+                        None => type_name,
+                    };
+                    self.selection_set(target, &fragment.selection_set)?;
+                }
+                Selection::FragmentSpread(spread) => {
+                    let name = spread.fragment_name.as_str();
+                    let fragment = self
+                        .fragments
+                        .get(name)
+                        .ok_or_else(|| at(spread.position, format!("unknown fragment `{name}`")))?;
+                    if !self.visiting.insert(name) {
+                        return Err(at(
+                            spread.position,
+                            format!("fragment `{name}` spreads itself"),
+                        ));
+                    }
+                    let TypeCondition::On(target) = &fragment.type_condition;
+                    self.composite_type(target, fragment.position)?;
+                    self.selection_set(target, &fragment.selection_set)?;
+                    self.visiting.remove(name);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn field(
+        &mut self,
+        type_name: &str,
+        field: &'d query::Field<'d, String>,
+    ) -> Result<(), String> {
+        let name = field.name.as_str();
+        if name == "__typename" {
+            if !field.selection_set.items.is_empty() {
+                return Err(at(
+                    field.position,
+                    "`__typename` cannot have a selection".into(),
+                ));
+            }
+            return Ok(());
+        }
+        if type_name == self.schema.query && (name == "__schema" || name == "__type") {
+            return Ok(());
+        }
+        let fields = match self.schema.types.get(type_name) {
+            Some(Kind::Composite(fields)) => fields,
+            _ => {
+                return Err(at(
+                    field.position,
+                    format!(
+                        "cannot select `{name}` on union `{type_name}`; use an inline fragment"
+                    ),
+                ))
+            }
+        };
+        let (arguments, field_type) = fields.get(name).ok_or_else(|| {
+            at(
+                field.position,
+                format!(
+                    "unknown field `{name}` on `{type_name}`{}",
+                    suggest(name, fields)
+                ),
+            )
+        })?;
+        for (argument, _) in &field.arguments {
+            if !arguments.contains(argument) {
+                return Err(at(
+                    field.position,
+                    format!("unknown argument `{argument}` on `{type_name}.{name}`"),
+                ));
+            }
+        }
+        let has_selection = !field.selection_set.items.is_empty();
+        match self.schema.types.get(field_type.as_str()) {
+            Some(Kind::Composite(_) | Kind::Union) if !has_selection => Err(at(
+                field.position,
+                format!("field `{name}` of type `{field_type}` needs a selection"),
+            )),
+            Some(Kind::Composite(_) | Kind::Union) => {
+                self.selection_set(field_type, &field.selection_set)
+            }
+            _ if has_selection => Err(at(
+                field.position,
+                format!("field `{name}` of type `{field_type}` cannot have a selection"),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn composite_type(&self, name: &str, pos: Pos) -> Result<(), String> {
+        match self.schema.types.get(name) {
+            Some(Kind::Composite(_) | Kind::Union) => Ok(()),
+            Some(_) => Err(at(pos, format!("cannot spread fragments on `{name}`"))),
+            None => Err(at(pos, format!("unknown type `{name}`"))),
+        }
+    }
+}
+
+/// `; did you mean `x`?` for the closest known field, if one is close
+fn suggest<V>(name: &str, fields: &BTreeMap<String, V>) -> String {
+    fields
+        .keys()
+        .map(|candidate| (distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .min()
+        .map(|(_, candidate)| format!("; did you mean `{candidate}`?"))
+        .unwrap_or_default()
+}
+
+/// levenshtein distance between two short names
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                previous
+            } else {
+                1 + previous.min(row[j]).min(current)
+            };
+            previous = current;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r#"
+        type Query {
+            BuiltinTag(name__value: String, limit: Int): PaginatedBuiltinTag
+            node(id: ID!): Node
+            search(q: String!): SearchResult
+        }
+        type Mutation { BuiltinTagCreate(data: BuiltinTagCreateInput!): TagCreate }
+        interface Node { id: ID! }
+        union SearchResult = BuiltinTag | Branch
+        type PaginatedBuiltinTag { count: Int! edges: [EdgedBuiltinTag!]! }
+        type EdgedBuiltinTag { node: BuiltinTag }
+        type BuiltinTag implements Node { id: ID! name: TextAttribute color: Color }
+        type Branch { name: String! }
+        type TextAttribute { value: String }
+        type TagCreate { ok: Boolean object: BuiltinTag }
+        enum Color { RED BLUE }
+        input BuiltinTagCreateInput { name: String! }
+        extend type Branch { is_default: Boolean }
+    "#;
+
+    fn check(document: &str) -> Result<String, String> {
+        Schema::parse(SCHEMA).unwrap().validate(document)
+    }
+
+    #[test]
+    fn test_valid_documents() {
+        assert_eq!(
+            check(
+                "query GetTags($name: String) { BuiltinTag(name__value: $name) { count \
+                 edges { node { __typename id name { value } color } } } }"
+            )
+            .unwrap(),
+            "GetTags"
+        );
+        assert!(check(
+            "mutation Create($data: BuiltinTagCreateInput!) { BuiltinTagCreate(data: $data) \
+             { ok object { ...TagFields } } } fragment TagFields on BuiltinTag { id }"
+        )
+        .is_ok());
+        assert!(check(
+            "query Search { search(q: \"x\") { __typename ... on Branch { name is_default } \
+             ... on BuiltinTag { id } } node(id: \"1\") { id ... { id } } }"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_invalid_documents() {
+        let cases = [
+            (
+                "query Q { BuiltinTag { edges { node { nme { value } } } } }",
+                "unknown field `nme` on `BuiltinTag`; did you mean `name`? (line 1, column 39)",
+            ),
+            (
+                "query Q { BuiltinTag(name: \"x\") { count } }",
+                "unknown argument `name` on `Query.BuiltinTag`",
+            ),
+            ("query Q { BuiltinTag }", "needs a selection"),
+            (
+                "query Q { BuiltinTag { count { x } } }",
+                "cannot have a selection",
+            ),
+            (
+                "query Q { search(q: \"x\") { id } }",
+                "use an inline fragment",
+            ),
+            ("query Q { ...Missing }", "unknown fragment `Missing`"),
+            (
+                "query Q { node(id: \"1\") { ... on Nope { id } } }",
+                "unknown type `Nope`",
+            ),
+            ("query Q($x: Nope) { __typename }", "unknown type `Nope`"),
+            ("query Q($x: BuiltinTag) { __typename }", "output type"),
+            ("{ __typename }", "needs a name"),
+            (
+                "query A { __typename } query B { __typename }",
+                "exactly one operation",
+            ),
+            (
+                "query Q { node(id: \"1\") { ...F } } fragment F on Node { ...F }",
+                "spreads itself",
+            ),
+            ("query Q {", "invalid graphql"),
+        ];
+        for (document, expected) in cases {
+            let err = check(document).unwrap_err();
+            assert!(err.contains(expected), "{document}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_reference_schema() {
+        let sdl = include_str!("../../schema/infrahub.graphql");
+        let schema = Schema::parse(sdl).unwrap();
+        assert!(schema
+            .validate("query Tags { BuiltinTag(limit: 10) { count edges { node { id } } } }")
+            .is_ok());
+        assert!(schema
+            .validate("query Tags { BuiltinTag { edges { node { idd } } } }")
+            .is_err());
+    }
+}
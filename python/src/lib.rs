@@ -0,0 +1,198 @@
+//! python bindings
+//!
+//! exposes a blocking `Client` to python with raw graphql execution, schema
+//! fetch, and the schema-agnostic node and branch helpers. values cross the
+//! boundary as json, so queries take and return plain dicts and lists.
+//! every client owns a tokio runtime and releases the gil while a request
+//! is in flight.
+
+use infrahub::{ClientConfig, ErrorKind};
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use pyo3::types::PyModule;
+use serde_json::Value;
+use std::future::Future;
+use std::time::Duration;
+
+pyo3::create_exception!(
+    infrahub_rs,
+    InfrahubError,
+    PyException,
+    "raised when a request fails; `kind` holds the error classification"
+);
+
+/// blocking infrahub client
+#[pyclass(frozen)]
+struct Client {
+    client: infrahub::Client,
+    runtime: tokio::runtime::Runtime,
+}
+
+#[pymethods]
+impl Client {
+    /// create a client for `url` authenticating with `token`
+    #[new]
+    #[pyo3(signature = (url, token, default_branch = None, timeout = None))]
+    fn new(
+        url: &str,
+        token: &str,
+        default_branch: Option<&str>,
+        timeout: Option<f64>,
+    ) -> PyResult<Self> {
+        let mut config = ClientConfig::new(url, token);
+        if let Some(branch) = default_branch {
+            config = config.with_default_branch(branch);
+        }
+        if let Some(seconds) = timeout {
+            config = config.with_timeout(Duration::from_secs_f64(seconds));
+        }
+        Self::with_config(config)
+    }
+
+    /// create a client from an infrahubctl-style config file, or the
+    /// discovered one when `path` is omitted
+    #[staticmethod]
+    #[pyo3(signature = (path = None))]
+    fn from_config(path: Option<&str>) -> PyResult<Self> {
+        let config = match path {
+            Some(path) => ClientConfig::from_file(path),
+            None => ClientConfig::discover(),
+        }
+        .map_err(to_py_err)?;
+        Self::with_config(config)
+    }
+
+    /// run a graphql query and return the response as a dict with `data`,
+    /// `errors`, and `extensions`
+    #[pyo3(signature = (query, variables = None, branch = None))]
+    fn execute(
+        &self,
+        py: Python<'_>,
+        query: &str,
+        variables: Option<&Bound<'_, PyAny>>,
+        branch: Option<&str>,
+    ) -> PyResult<Py<PyAny>> {
+        let variables = variables.map(|vars| to_json(py, vars)).transpose()?;
+        let response = self.block_on(py, |client| client.execute_raw(query, variables, branch))?;
+        let response =
+            serde_json::to_value(response).map_err(|err| to_py_err(infrahub::Error::Json(err)))?;
+        from_json(py, &response)
+    }
+
+    /// fetch the graphql schema as sdl text
+    #[pyo3(signature = (branch = None))]
+    fn fetch_schema(&self, py: Python<'_>, branch: Option<&str>) -> PyResult<String> {
+        self.block_on(py, |client| client.fetch_schema(branch))
+    }
+
+    /// fetch every node of `kind` with `id` plus `selection`
+    #[pyo3(signature = (kind, selection, branch = None))]
+    fn query_nodes(
+        &self,
+        py: Python<'_>,
+        kind: &str,
+        selection: &str,
+        branch: Option<&str>,
+    ) -> PyResult<Py<PyAny>> {
+        let nodes = self.block_on(py, |client| client.query_nodes(kind, selection, branch))?;
+        from_json(py, &Value::Array(nodes))
+    }
+
+    /// create a node of `kind` and return its id
+    #[pyo3(signature = (kind, data, branch = None))]
+    fn create_node(
+        &self,
+        py: Python<'_>,
+        kind: &str,
+        data: &Bound<'_, PyAny>,
+        branch: Option<&str>,
+    ) -> PyResult<String> {
+        let data = to_json(py, data)?;
+        self.block_on(py, |client| client.create_node(kind, data, branch))
+    }
+
+    /// create or update a node of `kind` and return its id
+    #[pyo3(signature = (kind, data, branch = None))]
+    fn upsert_node(
+        &self,
+        py: Python<'_>,
+        kind: &str,
+        data: &Bound<'_, PyAny>,
+        branch: Option<&str>,
+    ) -> PyResult<String> {
+        let data = to_json(py, data)?;
+        self.block_on(py, |client| client.upsert_node(kind, data, branch))
+    }
+
+    /// create a branch and wait until it is ready
+    fn create_branch(&self, py: Python<'_>, name: &str) -> PyResult<()> {
+        self.block_on(py, |client| client.create_branch(name))
+    }
+
+    /// delete a branch and wait until the deletion completes
+    fn delete_branch(&self, py: Python<'_>, name: &str) -> PyResult<()> {
+        self.block_on(py, |client| client.delete_branch(name))
+    }
+}
+
+impl Client {
+    fn with_config(config: ClientConfig) -> PyResult<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .map_err(|err| to_py_err(infrahub::Error::Io(err)))?;
+        let client = {
+            let _entered = runtime.enter();
+            infrahub::Client::new(config).map_err(to_py_err)?
+        };
+        Ok(Self { client, runtime })
+    }
+
+    /// run the request built by `request` on the client's runtime with the
+    /// gil released
+    fn block_on<'a, T, F, Fut>(&'a self, py: Python<'_>, request: F) -> PyResult<T>
+    where
+        F: FnOnce(&'a infrahub::Client) -> Fut + Send,
+        Fut: Future<Output = infrahub::Result<T>>,
+        T: Send,
+    {
+        py.detach(|| self.runtime.block_on(request(&self.client)))
+            .map_err(to_py_err)
+    }
+}
+
+/// convert a library error into `InfrahubError`, with its `kind` attached
+fn to_py_err(err: infrahub::Error) -> PyErr {
+    let kind: ErrorKind = err.kind();
+    let py_err = InfrahubError::new_err(err.to_string());
+    Python::attach(|py| {
+        // best effort; the message alone still describes the failure
+        let _ = py_err.value(py).setattr("kind", kind.to_string());
+    });
+    py_err
+}
+
+/// convert a python object to json through the `json` module
+fn to_json(py: Python<'_>, value: &Bound<'_, PyAny>) -> PyResult<Value> {
+    let text: String = py
+        .import("json")?
+        .call_method1("dumps", (value,))?
+        .extract()?;
+    serde_json::from_str(&text).map_err(|err| to_py_err(infrahub::Error::Json(err)))
+}
+
+/// convert json to python objects through the `json` module
+fn from_json(py: Python<'_>, value: &Value) -> PyResult<Py<PyAny>> {
+    Ok(py
+        .import("json")?
+        .call_method1("loads", (value.to_string(),))?
+        .unbind())
+}
+
+#[pymodule]
+fn infrahub_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Client>()?;
+    m.add("InfrahubError", m.py().get_type::<InfrahubError>())?;
+    Ok(())
+}
@@ -0,0 +1,444 @@
+//! account and permission helpers
+//!
+//! typed wrappers around the `CoreAccount`, `CoreAccountGroup`, and
+//! `CoreAccountRole` mutations, the api token mutations, and the
+//! `AccountProfile` and `InfrahubPermissions` queries, for bootstrapping new
+//! instances.
+//!
+//! in infrahub an account gets roles through groups: a role holds
+//! permissions, a group holds roles, and accounts are members of groups.
+//!
+//! ```no_run
+//! use infrahub::{Client, ClientConfig, NewAccount};
+//!
+//! # async fn example() -> infrahub::Result<()> {
+//! let client = Client::new(ClientConfig::new("http://localhost:8000", "token"))?;
+//! let account = client
+//!     .create_account(&NewAccount::new("ci-bot").with_account_type("Script"))
+//!     .await?;
+//! let permission = client
+//!     .permission_id("object:*:*:any:allow_all")
+//!     .await?
+//!     .expect("permission exists");
+//! let role = client.create_account_role("automation", &[permission.as_str()]).await?;
+//! let group = client.create_account_group("automation", &[role.as_str()]).await?;
+//! client.add_account_group_members(&group, &[account.as_str()]).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::branch::ensure_ok;
+use crate::client::Client;
+use crate::error::{Error, Result};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+/// a `CoreAccount` to create
+#[derive(Debug, Clone)]
+pub struct NewAccount {
+    name: String,
+    attrs: Map<String, Value>,
+}
+
+impl NewAccount {
+    /// an account named `name`
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            attrs: Map::new(),
+        }
+    }
+
+    /// set the login password
+    pub fn with_password(self, password: impl Into<String>) -> Self {
+        self.with_attribute("password", password.into())
+    }
+
+    /// set the account type, e.g. `User` or `Script`
+    pub fn with_account_type(self, account_type: impl Into<String>) -> Self {
+        self.with_attribute("account_type", account_type.into())
+    }
+
+    /// set the display label
+    pub fn with_label(self, label: impl Into<String>) -> Self {
+        self.with_attribute("label", label.into())
+    }
+
+    /// set the description
+    pub fn with_description(self, description: impl Into<String>) -> Self {
+        self.with_attribute("description", description.into())
+    }
+
+    /// set the status, e.g. `active` or `inactive`
+    pub fn with_status(self, status: impl Into<String>) -> Self {
+        self.with_attribute("status", status.into())
+    }
+
+    fn with_attribute(mut self, field: &str, value: String) -> Self {
+        self.attrs
+            .insert(field.to_string(), json!({ "value": value }));
+        self
+    }
+
+    fn to_input(&self) -> Value {
+        let mut input = self.attrs.clone();
+        input.insert("name".to_string(), json!({ "value": self.name }));
+        Value::Object(input)
+    }
+}
+
+/// the account a client authenticates as, as returned by [`Client::whoami`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Account {
+    /// node id
+    pub id: String,
+    /// login name
+    pub name: String,
+    /// display label
+    pub label: Option<String>,
+    /// account type, e.g. `User` or `Script`
+    pub account_type: Option<String>,
+    /// status, e.g. `active`
+    pub status: Option<String>,
+}
+
+/// permissions granted to the current account
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountPermissions {
+    /// permissions for instance-wide actions
+    pub global: Vec<GlobalPermission>,
+    /// permissions on node kinds
+    pub object: Vec<ObjectPermission>,
+}
+
+/// a `CoreGlobalPermission` granted to the current account
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GlobalPermission {
+    /// node id
+    pub id: String,
+    /// permission name
+    pub name: String,
+    /// the action allowed or denied, e.g. `manage_accounts`
+    pub action: String,
+    /// `allow`, `deny`, or a branch-scoped decision
+    pub decision: String,
+    /// canonical identifier, e.g. `global:manage_accounts:allow_all`
+    pub identifier: String,
+}
+
+/// a `CoreObjectPermission` granted to the current account
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ObjectPermission {
+    /// node id
+    pub id: String,
+    /// kind namespace, or `*`
+    pub namespace: String,
+    /// kind name, or `*`
+    pub name: String,
+    /// `view`, `create`, `update`, `delete`, or `any`
+    pub action: String,
+    /// `allow`, `deny`, or a branch-scoped decision
+    pub decision: String,
+    /// canonical identifier, e.g. `object:Infra:Device:view:allow_all`
+    pub identifier: String,
+}
+
+/// an api token of the current account, without its secret
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ApiToken {
+    /// token id, used to delete it
+    pub id: String,
+    /// token name
+    pub name: Option<String>,
+    /// expiry timestamp, if any
+    pub expiration: Option<String>,
+}
+
+/// a newly created api token
+///
+/// the secret is only returned once, at creation.
+#[derive(Clone)]
+pub struct CreatedApiToken {
+    /// token id, used to delete it
+    pub id: String,
+    /// the token value for `X-INFRAHUB-KEY`
+    pub token: String,
+}
+
+impl std::fmt::Debug for CreatedApiToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CreatedApiToken")
+            .field("id", &self.id)
+            .field("token", &crate::redact::REDACTED)
+            .finish()
+    }
+}
+
+impl Client {
+    /// create an account, returning its id
+    pub async fn create_account(&self, account: &NewAccount) -> Result<String> {
+        let query = "mutation CreateAccount($data: CoreAccountCreateInput!) { CoreAccountCreate(data: $data) { ok object { id } } }";
+        let vars = json!({ "data": account.to_input() });
+        let response = self.execute_raw(query, Some(vars), None).await?;
+        created_id(response.data, "CoreAccountCreate")
+    }
+
+    /// look up an account id by name
+    pub async fn account_id(&self, name: &str) -> Result<Option<String>> {
+        let query = "query AccountId($name: String!) { CoreAccount(name__value: $name) { edges { node { id } } } }";
+        let vars = json!({ "name": name });
+        let response = self.execute_raw(query, Some(vars), None).await?;
+        let data = response.into_data()?;
+        Ok(data["CoreAccount"]["edges"][0]["node"]["id"]
+            .as_str()
+            .map(str::to_string))
+    }
+
+    /// fetch the account the client authenticates as
+    pub async fn whoami(&self) -> Result<Account> {
+        let query = "query Whoami { AccountProfile { id name { value } label { value } account_type { value } status { value } } }";
+        let response = self.execute_raw(query, None, None).await?;
+        parse_account(response.data)
+    }
+
+    /// delete an account
+    pub async fn delete_account(&self, id: &str) -> Result<()> {
+        let query =
+            "mutation DeleteAccount($id: String!) { CoreAccountDelete(data: { id: $id }) { ok } }";
+        let response = self
+            .execute_raw(query, Some(json!({ "id": id })), None)
+            .await?;
+        ensure_ok(response.data, "CoreAccountDelete")
+    }
+
+    /// create a role holding the given permission ids, returning its id
+    pub async fn create_account_role(&self, name: &str, permissions: &[&str]) -> Result<String> {
+        let query = "mutation CreateAccountRole($data: CoreAccountRoleCreateInput!) { CoreAccountRoleCreate(data: $data) { ok object { id } } }";
+        let vars = json!({
+            "data": { "name": { "value": name }, "permissions": related(permissions) }
+        });
+        let response = self.execute_raw(query, Some(vars), None).await?;
+        created_id(response.data, "CoreAccountRoleCreate")
+    }
+
+    /// create an account group holding the given role ids, returning its id
+    pub async fn create_account_group(&self, name: &str, roles: &[&str]) -> Result<String> {
+        let query = "mutation CreateAccountGroup($data: CoreAccountGroupCreateInput!) { CoreAccountGroupCreate(data: $data) { ok object { id } } }";
+        let vars = json!({
+            "data": { "name": { "value": name }, "roles": related(roles) }
+        });
+        let response = self.execute_raw(query, Some(vars), None).await?;
+        created_id(response.data, "CoreAccountGroupCreate")
+    }
+
+    /// replace the roles of an account group
+    pub async fn set_account_group_roles(&self, group: &str, roles: &[&str]) -> Result<()> {
+        let query = "mutation SetGroupRoles($data: CoreAccountGroupUpdateInput!) { CoreAccountGroupUpdate(data: $data) { ok } }";
+        let vars = json!({ "data": { "id": group, "roles": related(roles) } });
+        let response = self.execute_raw(query, Some(vars), None).await?;
+        ensure_ok(response.data, "CoreAccountGroupUpdate")
+    }
+
+    /// add accounts to an account group, keeping existing members
+    pub async fn add_account_group_members(&self, group: &str, accounts: &[&str]) -> Result<()> {
+        let query = "mutation AddGroupMembers($data: RelationshipNodesInput!) { RelationshipAdd(data: $data) { ok } }";
+        let vars = json!({
+            "data": { "id": group, "name": "members", "nodes": related(accounts) }
+        });
+        let response = self.execute_raw(query, Some(vars), None).await?;
+        ensure_ok(response.data, "RelationshipAdd")
+    }
+
+    /// remove accounts from an account group
+    pub async fn remove_account_group_members(&self, group: &str, accounts: &[&str]) -> Result<()> {
+        let query = "mutation RemoveGroupMembers($data: RelationshipNodesInput!) { RelationshipRemove(data: $data) { ok } }";
+        let vars = json!({
+            "data": { "id": group, "name": "members", "nodes": related(accounts) }
+        });
+        let response = self.execute_raw(query, Some(vars), None).await?;
+        ensure_ok(response.data, "RelationshipRemove")
+    }
+
+    /// look up a permission id by identifier, e.g.
+    /// `object:Infra:Device:view:allow_all`
+    pub async fn permission_id(&self, identifier: &str) -> Result<Option<String>> {
+        let query = "query PermissionId($identifier: String!) { CoreBasePermission(identifier__value: $identifier) { edges { node { id } } } }";
+        let vars = json!({ "identifier": identifier });
+        let response = self.execute_raw(query, Some(vars), None).await?;
+        let data = response.into_data()?;
+        Ok(data["CoreBasePermission"]["edges"][0]["node"]["id"]
+            .as_str()
+            .map(str::to_string))
+    }
+
+    /// list the permissions granted to the current account
+    pub async fn account_permissions(&self) -> Result<AccountPermissions> {
+        let query = "query AccountPermissions { InfrahubPermissions { global_permissions { edges { node { id name action decision identifier } } } object_permissions { edges { node { id namespace name action decision identifier } } } } }";
+        let response = self.execute_raw(query, None, None).await?;
+        parse_permissions(response.data)
+    }
+
+    /// list the api tokens of the current account
+    pub async fn list_api_tokens(&self) -> Result<Vec<ApiToken>> {
+        let query =
+            "query ApiTokens { InfrahubAccountToken { edges { node { id name expiration } } } }";
+        let response = self.execute_raw(query, None, None).await?;
+        let data = response.into_data()?;
+        edge_nodes(&data["InfrahubAccountToken"])
+    }
+
+    /// create an api token for the current account
+    ///
+    /// `expiration` is an iso 8601 timestamp; without one the token does not
+    /// expire.
+    pub async fn create_api_token(
+        &self,
+        name: &str,
+        expiration: Option<&str>,
+    ) -> Result<CreatedApiToken> {
+        let query = "mutation CreateApiToken($data: InfrahubAccountTokenCreateInput!) { InfrahubAccountTokenCreate(data: $data) { ok object { id token { value } } } }";
+        let vars = json!({ "data": { "name": name, "expiration": expiration } });
+        let response = self.execute_raw(query, Some(vars), None).await?;
+        let data = response.into_data()?;
+        let object = &data["InfrahubAccountTokenCreate"]["object"];
+        match (object["id"].as_str(), object["token"]["value"].as_str()) {
+            (Some(id), Some(token)) => Ok(CreatedApiToken {
+                id: id.to_string(),
+                token: token.to_string(),
+            }),
+            _ => Err(Error::Config(
+                "InfrahubAccountTokenCreate did not return a token".to_string(),
+            )),
+        }
+    }
+
+    /// delete an api token of the current account
+    pub async fn delete_api_token(&self, id: &str) -> Result<()> {
+        let query = "mutation DeleteApiToken($id: String!) { InfrahubAccountTokenDelete(data: { id: $id }) { ok } }";
+        let response = self
+            .execute_raw(query, Some(json!({ "id": id })), None)
+            .await?;
+        ensure_ok(response.data, "InfrahubAccountTokenDelete")
+    }
+}
+
+/// related node inputs for a list of ids
+pub(crate) fn related(ids: &[&str]) -> Value {
+    ids.iter().map(|id| json!({ "id": id })).collect()
+}
+
+/// the id of the object returned by a create mutation
+fn created_id(data: Option<Value>, mutation: &str) -> Result<String> {
+    let data = data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+    data[mutation]["object"]["id"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| Error::Config(format!("{mutation} did not return an id")))
+}
+
+/// deserialize the nodes of an `{ edges { node } }` connection
+fn edge_nodes<T: serde::de::DeserializeOwned>(connection: &Value) -> Result<Vec<T>> {
+    match connection["edges"].as_array() {
+        Some(edges) => edges
+            .iter()
+            .map(|edge| Ok(T::deserialize(&edge["node"])?))
+            .collect(),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn parse_account(data: Option<Value>) -> Result<Account> {
+    let data = data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+    let profile = &data["AccountProfile"];
+    let value = |field: &str| profile[field]["value"].as_str().map(str::to_string);
+    match (profile["id"].as_str(), value("name")) {
+        (Some(id), Some(name)) => Ok(Account {
+            id: id.to_string(),
+            name,
+            label: value("label"),
+            account_type: value("account_type"),
+            status: value("status"),
+        }),
+        _ => Err(Error::Config(
+            "AccountProfile did not return an account".to_string(),
+        )),
+    }
+}
+
+fn parse_permissions(data: Option<Value>) -> Result<AccountPermissions> {
+    let data = data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+    let permissions = &data["InfrahubPermissions"];
+    Ok(AccountPermissions {
+        global: edge_nodes(&permissions["global_permissions"])?,
+        object: edge_nodes(&permissions["object_permissions"])?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_account_input() {
+        let account = NewAccount::new("ci-bot")
+            .with_account_type("Script")
+            .with_password("hunter2");
+        assert_eq!(
+            account.to_input(),
+            json!({
+                "name": { "value": "ci-bot" },
+                "account_type": { "value": "Script" },
+                "password": { "value": "hunter2" },
+            })
+        );
+        assert_eq!(related(&["a", "b"]), json!([{ "id": "a" }, { "id": "b" }]));
+    }
+
+    #[test]
+    fn test_created_id() {
+        let data = json!({ "CoreAccountCreate": { "ok": true, "object": { "id": "acc-1" } } });
+        assert_eq!(
+            created_id(Some(data), "CoreAccountCreate").unwrap(),
+            "acc-1"
+        );
+        let data = json!({ "CoreAccountCreate": { "ok": false, "object": null } });
+        assert!(created_id(Some(data), "CoreAccountCreate").is_err());
+        assert!(created_id(None, "CoreAccountCreate").is_err());
+    }
+
+    #[test]
+    fn test_parse_account() {
+        let data = json!({ "AccountProfile": {
+            "id": "acc-1", "name": { "value": "ci-bot" }, "label": { "value": null },
+            "account_type": { "value": "Script" }, "status": { "value": "active" }
+        } });
+        let account = parse_account(Some(data)).unwrap();
+        assert_eq!(account.name, "ci-bot");
+        assert_eq!(account.label, None);
+        assert_eq!(account.account_type.as_deref(), Some("Script"));
+        assert!(parse_account(Some(json!({ "AccountProfile": null }))).is_err());
+    }
+
+    #[test]
+    fn test_parse_permissions() {
+        let data = json!({
+            "InfrahubPermissions": {
+                "global_permissions": { "edges": [{ "node": {
+                    "id": "g1", "name": "manage accounts", "action": "manage_accounts",
+                    "decision": "allow_all", "identifier": "global:manage_accounts:allow_all"
+                } }] },
+                "object_permissions": null
+            }
+        });
+        let permissions = parse_permissions(Some(data)).unwrap();
+        assert_eq!(permissions.global.len(), 1);
+        assert_eq!(permissions.global[0].action, "manage_accounts");
+        assert!(permissions.object.is_empty());
+
+        let token = CreatedApiToken {
+            id: "t1".to_string(),
+            token: "secret-value".to_string(),
+        };
+        assert!(!format!("{token:?}").contains("secret-value"));
+    }
+}
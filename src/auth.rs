@@ -0,0 +1,331 @@
+//! credential helpers
+//!
+//! types for token failover during credential rotation, and for tokens read
+//! from a file or an external command.
+
+use crate::error::{Error, Result};
+use crate::secret::sensitive_header;
+use reqwest::header::HeaderValue;
+use serde::Deserialize;
+use std::fmt;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+use zeroize::Zeroizing;
+
+/// emitted when the client switches from the primary to the fallback token
+#[derive(Debug, Clone)]
+pub struct TokenFailoverEvent {
+    /// http status that triggered the failover (normally 401)
+    pub status: u16,
+    /// url of the request that was rejected with the primary token
+    pub url: String,
+}
+
+/// callback invoked on token failover
+pub(crate) type TokenFailoverHook = std::sync::Arc<dyn Fn(&TokenFailoverEvent) + Send + Sync>;
+
+/// refresh tokens this long before a command-reported expiry
+const EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+/// where the api token comes from, instead of a fixed string
+///
+/// the token is loaded when the client is built, loaded again whenever the
+/// server rejects it with 401, and, for commands that report an expiry,
+/// shortly before it expires. this keeps secrets out of environment variables
+/// and process arguments, like kubeconfig credential plugins.
+#[derive(Clone)]
+pub struct TokenSource {
+    kind: SourceKind,
+}
+
+#[derive(Clone)]
+enum SourceKind {
+    File(PathBuf),
+    Command { program: String, args: Vec<String> },
+}
+
+impl TokenSource {
+    /// read the token from a file, e.g. a mounted secret
+    ///
+    /// surrounding whitespace is ignored.
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        Self {
+            kind: SourceKind::File(path.into()),
+        }
+    }
+
+    /// run `program` with `args` and read the token from its stdout
+    ///
+    /// the output is either the bare token or a json object such as
+    /// `{"token": "...", "expires_in": 3600}`, where `expires_in` (seconds)
+    /// makes the client run the command again before the token expires.
+    pub fn command<I, S>(program: impl Into<String>, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            kind: SourceKind::Command {
+                program: program.into(),
+                args: args.into_iter().map(Into::into).collect(),
+            },
+        }
+    }
+
+    /// load the token, blocking on file or process io
+    pub(crate) fn load(&self) -> Result<LoadedToken> {
+        match &self.kind {
+            SourceKind::File(path) => {
+                let text = std::fs::read_to_string(path)
+                    .map(Zeroizing::new)
+                    .map_err(|err| {
+                        Error::Config(format!(
+                            "failed to read token file {}: {err}",
+                            path.display()
+                        ))
+                    })?;
+                LoadedToken::new(text.trim(), None)
+            }
+            SourceKind::Command { program, args } => {
+                let output = Command::new(program)
+                    .args(args)
+                    .stdin(Stdio::null())
+                    .output()
+                    .map_err(|err| {
+                        Error::Config(format!("failed to run token command {program}: {err}"))
+                    })?;
+                if !output.status.success() {
+                    let stderr = String::from_utf8_lossy(&output.stderr);
+                    return Err(Error::Config(format!(
+                        "token command {program} failed ({}): {}",
+                        output.status,
+                        stderr.trim()
+                    )));
+                }
+                let stdout = Zeroizing::new(output.stdout);
+                parse_command_output(&String::from_utf8_lossy(&stdout))
+            }
+        }
+    }
+}
+
+impl fmt::Debug for TokenSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            SourceKind::File(path) => f.debug_tuple("TokenSource::File").field(path).finish(),
+            SourceKind::Command { program, .. } => f
+                .debug_struct("TokenSource::Command")
+                .field("program", program)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+/// parse a bare token or a `{"token", "expires_in"}` object
+fn parse_command_output(stdout: &str) -> Result<LoadedToken> {
+    #[derive(Deserialize)]
+    struct Credential {
+        token: String,
+        expires_in: Option<u64>,
+    }
+
+    let stdout = stdout.trim();
+    if !stdout.starts_with('{') {
+        return LoadedToken::new(stdout, None);
+    }
+    let credential: Credential = serde_json::from_str(stdout)
+        .map_err(|err| Error::Config(format!("invalid token command output: {err}")))?;
+    let lifetime = credential.expires_in.map(Duration::from_secs);
+    LoadedToken::new(&credential.token, lifetime)
+}
+
+/// a token read from a [`TokenSource`]
+#[derive(Debug, Clone)]
+pub(crate) struct LoadedToken {
+    pub(crate) value: HeaderValue,
+    refresh_at: Option<Instant>,
+}
+
+impl LoadedToken {
+    fn new(token: &str, lifetime: Option<Duration>) -> Result<Self> {
+        if token.is_empty() {
+            return Err(Error::Config(
+                "token source returned an empty token".to_string(),
+            ));
+        }
+        let value = sensitive_header(token)
+            .map_err(|err| Error::Config(format!("invalid api token header value: {err}")))?;
+        let refresh_at = lifetime.map(|lifetime| {
+            Instant::now() + lifetime.saturating_sub(EXPIRY_MARGIN.min(lifetime / 2))
+        });
+        Ok(Self { value, refresh_at })
+    }
+
+    fn expired(&self) -> bool {
+        self.refresh_at.is_some_and(|at| Instant::now() >= at)
+    }
+}
+
+/// the current token of a [`TokenSource`], shared by clones of a client
+///
+/// reloads run one at a time, so concurrent requests never start more than
+/// one command.
+pub(crate) struct TokenCache {
+    source: TokenSource,
+    current: tokio::sync::Mutex<LoadedToken>,
+    /// copy of the current token value, readable while a reload holds
+    /// `current`
+    value: RwLock<HeaderValue>,
+}
+
+impl TokenCache {
+    /// load the first token
+    pub(crate) fn new(source: TokenSource) -> Result<Self> {
+        let current = source.load()?;
+        Ok(Self {
+            source,
+            value: RwLock::new(current.value.clone()),
+            current: tokio::sync::Mutex::new(current),
+        })
+    }
+
+    /// the current token, reloaded first if it has expired
+    pub(crate) async fn token(&self) -> Result<HeaderValue> {
+        let mut current = self.current.lock().await;
+        if current.expired() {
+            self.replace(&mut current, self.reload().await?);
+        }
+        Ok(current.value.clone())
+    }
+
+    /// reload after the server rejected `rejected`, returning the new token
+    /// if it differs
+    ///
+    /// another request may already have reloaded, in which case its token is
+    /// returned without running the source again.
+    pub(crate) async fn reload_rejected(
+        &self,
+        rejected: &HeaderValue,
+    ) -> Result<Option<HeaderValue>> {
+        let mut current = self.current.lock().await;
+        if current.value == *rejected {
+            self.replace(&mut current, self.reload().await?);
+        }
+        Ok((current.value != *rejected).then(|| current.value.clone()))
+    }
+
+    /// the current token, without waiting for a reload in progress
+    pub(crate) fn current(&self) -> HeaderValue {
+        self.value
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    fn replace(&self, current: &mut LoadedToken, loaded: LoadedToken) {
+        *self
+            .value
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = loaded.value.clone();
+        *current = loaded;
+    }
+
+    async fn reload(&self) -> Result<LoadedToken> {
+        let source = self.source.clone();
+        tokio::task::spawn_blocking(move || source.load())
+            .await
+            .map_err(|err| Error::Config(format!("token source panicked: {err}")))?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_command_output() {
+        let bare = parse_command_output("  abc123\n").unwrap();
+        assert_eq!(bare.value, "abc123");
+        assert!(bare.refresh_at.is_none());
+
+        let expiring = parse_command_output(r#"{"token": "abc", "expires_in": 3600}"#).unwrap();
+        assert_eq!(expiring.value, "abc");
+        assert!(!expiring.expired());
+        let refresh_in = expiring.refresh_at.unwrap() - Instant::now();
+        assert!(refresh_in > Duration::from_secs(3500) && refresh_in <= Duration::from_secs(3570));
+
+        let expired = parse_command_output(r#"{"token": "abc", "expires_in": 0}"#).unwrap();
+        assert!(expired.expired());
+
+        assert!(parse_command_output("").is_err());
+        assert!(parse_command_output(r#"{"access_token": "abc"}"#).is_err());
+        assert!(parse_command_output("bad\ntoken").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_token_file_reloads_after_rejection() {
+        let path = std::env::temp_dir().join(format!("infrahub-token-{}", std::process::id()));
+        std::fs::write(&path, "first\n").unwrap();
+        let cache = TokenCache::new(TokenSource::file(&path)).unwrap();
+        let first = cache.token().await.unwrap();
+        assert_eq!(first, "first");
+
+        // unchanged file: nothing new to retry with
+        assert_eq!(cache.reload_rejected(&first).await.unwrap(), None);
+
+        std::fs::write(&path, "second").unwrap();
+        assert_eq!(cache.token().await.unwrap(), "first");
+        let second = cache.reload_rejected(&first).await.unwrap().unwrap();
+        assert_eq!(second, "second");
+        // a request that was rejected with the old token picks up the new one
+        assert_eq!(cache.reload_rejected(&first).await.unwrap(), Some(second));
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(TokenCache::new(TokenSource::file(&path)).is_err());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_token_command() {
+        let cache = TokenCache::new(TokenSource::command(
+            "sh",
+            ["-c", r#"echo '{"token": "from-command", "expires_in": 0}'"#],
+        ))
+        .unwrap();
+        // expired immediately, so every read runs the command again
+        assert_eq!(cache.token().await.unwrap(), "from-command");
+
+        let failing = TokenSource::command("sh", ["-c", "echo denied >&2; exit 3"]);
+        let err = TokenCache::new(failing).err().unwrap();
+        assert!(err.to_string().contains("denied"));
+        assert!(TokenCache::new(TokenSource::command("/nonexistent/helper", [""; 0])).is_err());
+        let debug = format!("{:?}", TokenSource::command("helper", ["--secret"]));
+        assert!(!debug.contains("--secret"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_current_during_reload() {
+        let cache = std::sync::Arc::new(
+            TokenCache::new(TokenSource::command(
+                "sh",
+                [
+                    "-c",
+                    r#"sleep 0.3; echo '{"token": "slow", "expires_in": 0}'"#,
+                ],
+            ))
+            .unwrap(),
+        );
+        let reloading = tokio::spawn({
+            let cache = cache.clone();
+            async move { cache.token().await }
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        // the reload holds the lock, but the token is still readable
+        assert!(cache.current.try_lock().is_err());
+        assert_eq!(cache.current(), "slow");
+        assert_eq!(reloading.await.unwrap().unwrap(), "slow");
+    }
+}
@@ -0,0 +1,488 @@
+//! mutation batches
+//!
+//! [`MutationBatch`] composes several mutations into one graphql document,
+//! each under its own alias with its own variables, and sends them in a
+//! single request. every pushed mutation returns a typed [`BatchKey`] that
+//! picks its payload out of the [`BatchResults`].
+//!
+//! ```no_run
+//! use infrahub::{BatchMutation, Client, ClientConfig, MutationBatch};
+//! use serde::Deserialize;
+//! use serde_json::json;
+//!
+//! #[derive(Deserialize)]
+//! struct Created {
+//!     ok: bool,
+//!     object: Option<serde_json::Value>,
+//! }
+//!
+//! # async fn example() -> infrahub::Result<()> {
+//! let client = Client::new(ClientConfig::new("http://localhost:8000", "token"))?;
+//! let mut batch = MutationBatch::new();
+//! let red = batch.push::<Created>(BatchMutation::create(
+//!     "BuiltinTag",
+//!     json!({ "name": { "value": "red" } }),
+//! ));
+//! let blue = batch.push::<Created>(BatchMutation::create(
+//!     "BuiltinTag",
+//!     json!({ "name": { "value": "blue" } }),
+//! ));
+//! let results = client.execute_batch(&batch, None).await?;
+//! assert!(results.get(red)?.ok && results.get(blue)?.ok);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Client::create_batch`] instead sends each mutation as its own request,
+//! a bounded number at a time, and reports every item's outcome separately,
+//! so one failing item leaves the others untouched:
+//!
+//! ```no_run
+//! use infrahub::{BatchMutation, Client, ClientConfig};
+//! use serde_json::json;
+//!
+//! # async fn example() -> infrahub::Result<()> {
+//! let client = Client::new(ClientConfig::new("http://localhost:8000", "token"))?;
+//! let mut batch = client.create_batch().with_concurrency(8);
+//! for name in ["red", "green", "blue"] {
+//!     batch.add(BatchMutation::create(
+//!         "BuiltinTag",
+//!         json!({ "name": { "value": name } }),
+//!     ));
+//! }
+//! for (index, outcome) in batch.execute(None).await.into_iter().enumerate() {
+//!     if let Err(error) = outcome {
+//!         eprintln!("item {index} failed: {error}");
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use futures_util::stream::{self, StreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::marker::PhantomData;
+
+/// selection used by [`BatchMutation::create`], `update`, and `upsert`
+const DEFAULT_SELECTION: &str = "ok object { id }";
+
+/// one mutation field of a [`MutationBatch`]
+#[derive(Debug, Clone)]
+pub struct BatchMutation {
+    field: String,
+    arguments: Vec<BatchArgument>,
+    selection: String,
+}
+
+#[derive(Debug, Clone)]
+struct BatchArgument {
+    name: String,
+    graphql_type: String,
+    value: Value,
+}
+
+impl BatchMutation {
+    /// the mutation field `field` (such as `BuiltinTagCreate`) selecting
+    /// `selection`, with no arguments yet
+    pub fn new(field: impl Into<String>, selection: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            arguments: Vec::new(),
+            selection: selection.into(),
+        }
+    }
+
+    /// `{kind}Create(data: {kind}CreateInput!)` selecting `ok object { id }`
+    pub fn create(kind: &str, data: Value) -> Self {
+        Self::with_data(kind, "Create", data)
+    }
+
+    /// `{kind}Update(data: {kind}UpdateInput!)` selecting `ok object { id }`
+    ///
+    /// `data` must carry the node `id` (or `hfid`).
+    pub fn update(kind: &str, data: Value) -> Self {
+        Self::with_data(kind, "Update", data)
+    }
+
+    /// `{kind}Upsert(data: {kind}UpsertInput!)` selecting `ok object { id }`
+    pub fn upsert(kind: &str, data: Value) -> Self {
+        Self::with_data(kind, "Upsert", data)
+    }
+
+    /// `{kind}Delete(data: DeleteInput!)` for the node `id`, selecting `ok`
+    pub fn delete(kind: &str, id: &str) -> Self {
+        Self::new(format!("{kind}Delete"), "ok").argument(
+            "data",
+            "DeleteInput!",
+            serde_json::json!({ "id": id }),
+        )
+    }
+
+    /// add the argument `name` of graphql type `graphql_type` (such as
+    /// `String!`), passed as a variable
+    pub fn argument(
+        mut self,
+        name: impl Into<String>,
+        graphql_type: impl Into<String>,
+        value: Value,
+    ) -> Self {
+        self.arguments.push(BatchArgument {
+            name: name.into(),
+            graphql_type: graphql_type.into(),
+            value,
+        });
+        self
+    }
+
+    /// replace the selection set
+    pub fn with_selection(mut self, selection: impl Into<String>) -> Self {
+        self.selection = selection.into();
+        self
+    }
+
+    fn with_data(kind: &str, action: &str, data: Value) -> Self {
+        Self::new(format!("{kind}{action}"), DEFAULT_SELECTION).argument(
+            "data",
+            format!("{kind}{action}Input!"),
+            data,
+        )
+    }
+}
+
+/// mutations sent together in one request, see [`Client::execute_batch`]
+#[derive(Debug, Clone, Default)]
+pub struct MutationBatch {
+    items: Vec<BatchMutation>,
+}
+
+/// typed handle to one mutation of a [`MutationBatch`]
+#[derive(Debug)]
+pub struct BatchKey<T> {
+    index: usize,
+    _output: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for BatchKey<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for BatchKey<T> {}
+
+impl<T> BatchKey<T> {
+    /// position of the mutation in its batch
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl MutationBatch {
+    /// an empty batch
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// add a mutation whose payload deserializes into `T`
+    pub fn push<T: DeserializeOwned>(&mut self, mutation: BatchMutation) -> BatchKey<T> {
+        self.items.push(mutation);
+        BatchKey {
+            index: self.items.len() - 1,
+            _output: PhantomData,
+        }
+    }
+
+    /// number of mutations
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// true if no mutation was added
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// the graphql document and its variables
+    ///
+    /// mutation `i` is aliased `m{i}` and its arguments become `$m{i}_{name}`.
+    pub fn document(&self) -> (String, Value) {
+        let mut declarations = Vec::new();
+        let mut fields = Vec::new();
+        let mut variables = Map::new();
+        for (index, item) in self.items.iter().enumerate() {
+            let alias = alias(index);
+            let mut arguments = Vec::new();
+            for argument in &item.arguments {
+                let variable = format!("{alias}_{}", argument.name);
+                declarations.push(format!("${variable}: {}", argument.graphql_type));
+                arguments.push(format!("{}: ${variable}", argument.name));
+                variables.insert(variable, argument.value.clone());
+            }
+            let arguments = if arguments.is_empty() {
+                String::new()
+            } else {
+                format!("({})", arguments.join(", "))
+            };
+            fields.push(format!(
+                "{alias}: {}{arguments} {{ {} }}",
+                item.field, item.selection
+            ));
+        }
+        let declarations = if declarations.is_empty() {
+            String::new()
+        } else {
+            format!("({})", declarations.join(", "))
+        };
+        let query = format!("mutation Batch{declarations} {{ {} }}", fields.join(" "));
+        (query, Value::Object(variables))
+    }
+}
+
+/// payloads of an executed [`MutationBatch`]
+#[derive(Debug, Clone)]
+pub struct BatchResults {
+    data: Map<String, Value>,
+}
+
+impl BatchResults {
+    /// the payload of the mutation behind `key`
+    ///
+    /// fails with [`Error::Config`] if the server returned no payload for it.
+    pub fn get<T: DeserializeOwned>(&self, key: BatchKey<T>) -> Result<T> {
+        let payload = self.raw(key.index).ok_or_else(|| {
+            Error::Config(format!("batch mutation {} returned no payload", key.index))
+        })?;
+        Ok(T::deserialize(payload)?)
+    }
+
+    /// the untyped payload of mutation `index`
+    pub fn raw(&self, index: usize) -> Option<&Value> {
+        self.data
+            .get(&alias(index))
+            .filter(|value| !value.is_null())
+    }
+}
+
+impl Client {
+    /// send every mutation of `batch` in one request
+    ///
+    /// the server runs the aliased fields in order. with the default
+    /// [`crate::ErrorPolicy::Raise`] any failing item fails the call, but
+    /// items before it may already be applied. large batches are subject to
+    /// [`crate::ClientConfig::with_max_query_length`] and the server's own
+    /// request limits; split them into several batches if needed.
+    pub async fn execute_batch(
+        &self,
+        batch: &MutationBatch,
+        branch: Option<&str>,
+    ) -> Result<BatchResults> {
+        if batch.is_empty() {
+            return Ok(BatchResults { data: Map::new() });
+        }
+        let (query, variables) = batch.document();
+        let response = self.execute_raw(&query, Some(variables), branch).await?;
+        match response.data {
+            Some(Value::Object(data)) => Ok(BatchResults { data }),
+            _ => Err(Error::Config("missing data".to_string())),
+        }
+    }
+}
+
+/// mutations sent as separate requests, see [`Client::create_batch`]
+pub struct Batch<'a> {
+    client: &'a Client,
+    items: Vec<BatchMutation>,
+    concurrency: usize,
+}
+
+impl Batch<'_> {
+    /// run up to `requests` mutations at the same time
+    pub fn with_concurrency(mut self, requests: usize) -> Self {
+        self.concurrency = requests.max(1);
+        self
+    }
+
+    /// add a mutation, returning its position in the results
+    pub fn add(&mut self, mutation: BatchMutation) -> usize {
+        self.items.push(mutation);
+        self.items.len() - 1
+    }
+
+    /// number of mutations
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// true if no mutation was added
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// send every mutation, returning their payloads in the order they were
+    /// added
+    ///
+    /// a failing mutation only fails its own entry; the rest still run.
+    pub async fn execute(self, branch: Option<&str>) -> Vec<Result<Value>> {
+        let client = self.client;
+        stream::iter(self.items)
+            .map(|item| async move {
+                let mut batch = MutationBatch::new();
+                let key = batch.push::<Value>(item);
+                client.execute_batch(&batch, branch).await?.get(key)
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+}
+
+impl Client {
+    /// an empty [`Batch`], sending one mutation at a time until
+    /// [`Batch::with_concurrency`] raises the limit
+    pub fn create_batch(&self) -> Batch<'_> {
+        Batch {
+            client: self,
+            items: Vec::new(),
+            concurrency: 1,
+        }
+    }
+}
+
+fn alias(index: usize) -> String {
+    format!("m{index}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockClient;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize)]
+    struct Created {
+        ok: bool,
+        object: Value,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Deleted {
+        ok: bool,
+    }
+
+    #[test]
+    fn test_document() {
+        let mut batch = MutationBatch::new();
+        batch.push::<Value>(BatchMutation::create("BuiltinTag", json!({ "name": "a" })));
+        batch.push::<Value>(BatchMutation::delete("BuiltinTag", "t1"));
+        batch.push::<Value>(BatchMutation::new("BranchCreate", "ok").argument(
+            "background_execution",
+            "Boolean",
+            json!(false),
+        ));
+        let (query, variables) = batch.document();
+        assert_eq!(
+            query,
+            "mutation Batch($m0_data: BuiltinTagCreateInput!, $m1_data: DeleteInput!, \
+             $m2_background_execution: Boolean) { \
+             m0: BuiltinTagCreate(data: $m0_data) { ok object { id } } \
+             m1: BuiltinTagDelete(data: $m1_data) { ok } \
+             m2: BranchCreate(background_execution: $m2_background_execution) { ok } }"
+        );
+        assert_eq!(
+            variables,
+            json!({
+                "m0_data": { "name": "a" },
+                "m1_data": { "id": "t1" },
+                "m2_background_execution": false,
+            })
+        );
+        assert!(graphql_parser::parse_query::<String>(&query).is_ok());
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_execute_batch() {
+        let mock = MockClient::new();
+        let handle = mock.expect("Batch").returning(json!({
+            "m0": { "ok": true, "object": { "id": "t1" } },
+            "m1": { "ok": true },
+        }));
+
+        let mut batch = MutationBatch::new();
+        let created = batch.push::<Created>(
+            BatchMutation::upsert("BuiltinTag", json!({ "name": { "value": "red" } }))
+                .with_selection("ok object { id name { value } }"),
+        );
+        let deleted = batch.push::<Deleted>(BatchMutation::delete("BuiltinTag", "t0"));
+        let results = mock
+            .client()
+            .execute_batch(&batch, Some("b1"))
+            .await
+            .unwrap();
+
+        let created = results.get(created).unwrap();
+        assert!(created.ok);
+        assert_eq!(created.object["id"], "t1");
+        assert!(results.get(deleted).unwrap().ok);
+        assert!(results.raw(2).is_none());
+
+        assert_eq!(handle.calls(), 1);
+        let calls = mock.calls();
+        assert_eq!(calls[0].branch.as_deref(), Some("b1"));
+        assert_eq!(calls[0].variables["m1_data"]["id"], "t0");
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_concurrent_batch() {
+        let mock = MockClient::new();
+        mock.expect("Batch")
+            .with_variables(json!({ "m0_data": { "name": { "value": "red" } } }))
+            .returning(json!({ "m0": { "ok": true, "object": { "id": "t1" } } }));
+        mock.expect("Batch")
+            .with_variables(json!({ "m0_data": { "id": "t9" } }))
+            .returning(json!({ "m0": null }));
+
+        let client = mock.client();
+        let mut batch = client.create_batch().with_concurrency(4);
+        batch.add(BatchMutation::create(
+            "BuiltinTag",
+            json!({ "name": { "value": "red" } }),
+        ));
+        assert_eq!(batch.add(BatchMutation::delete("BuiltinTag", "t9")), 1);
+        assert_eq!(batch.len(), 2);
+
+        let results = batch.execute(Some("b1")).await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap()["object"]["id"], "t1");
+        assert!(matches!(results[1], Err(Error::Config(_))));
+        assert_eq!(mock.calls().len(), 2);
+        assert!(mock
+            .calls()
+            .iter()
+            .all(|call| call.branch.as_deref() == Some("b1")));
+        assert!(client.create_batch().execute(None).await.is_empty());
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_missing_payload() {
+        let mock = MockClient::new();
+        mock.expect("Batch").returning(json!({ "m0": null }));
+        let mut batch = MutationBatch::new();
+        let key = batch.push::<Deleted>(BatchMutation::delete("BuiltinTag", "t0"));
+        let results = mock.client().execute_batch(&batch, None).await.unwrap();
+        assert!(matches!(results.get(key), Err(Error::Config(_))));
+
+        let empty = mock
+            .client()
+            .execute_batch(&MutationBatch::new(), None)
+            .await
+            .unwrap();
+        assert!(empty.raw(0).is_none());
+    }
+}
@@ -15,9 +15,10 @@ use graphql_parser::schema::{
 };
 use reqwest::blocking::Client as BlockingClient;
 use reqwest::header::{HeaderMap, HeaderValue};
+use serde::Deserialize;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 use url::Url;
 
@@ -26,10 +27,28 @@ struct Args {
     url: Option<String>,
     token: Option<String>,
     branch: Option<String>,
+    proxy: Option<String>,
+    no_proxy: bool,
+    identity_path: Option<PathBuf>,
+    ca_cert_path: Option<PathBuf>,
     schema_path: Option<PathBuf>,
+    schema_cache: Option<PathBuf>,
+    config_path: Option<PathBuf>,
     out_dir: PathBuf,
     crate_name: Option<String>,
     infrahub_path: Option<String>,
+    infrahub_version: Option<String>,
+    infrahub_git: Option<String>,
+    infrahub_rev: Option<String>,
+    license: Option<String>,
+    description: Option<String>,
+    repository: Option<String>,
+    publish: bool,
+    features: Vec<String>,
+    workspace_manifest: Option<PathBuf>,
+    smoke_example: bool,
+    read_only: bool,
+    mutations_only: bool,
 }
 
 enum ParseArgsError {
@@ -66,22 +85,73 @@ fn main() {
         }
     };
 
-    let ctx = SchemaContext::new(&document);
+    let mut ctx = SchemaContext::new(&document);
+    if let Some(path) = &args.config_path {
+        match load_config(path) {
+            Ok(config) => {
+                ctx.selection = config.selection;
+                ctx.capture_unknown_fields = config.types.capture_unknown_fields;
+            }
+            Err(err) => {
+                eprintln!("failed to load config: {err}");
+                std::process::exit(1);
+            }
+        }
+    }
+    ctx.chrono = args.features.iter().any(|feature| feature == "chrono");
+    ctx.ipnet = args.features.iter().any(|feature| feature == "ipnet");
+    if args.read_only {
+        ctx = ctx.read_only();
+    }
+    if args.mutations_only {
+        ctx = match ctx.mutations_only() {
+            Ok(ctx) => ctx,
+            Err(err) => {
+                eprintln!("codegen failed: {err}");
+                std::process::exit(1);
+            }
+        };
+    }
 
     if let Err(err) = generate_client(&args, &ctx) {
         eprintln!("codegen failed: {err}");
         std::process::exit(1);
     }
+
+    if let Some(manifest) = &args.workspace_manifest {
+        if let Err(err) = add_to_workspace(manifest, &args.out_dir) {
+            eprintln!("failed to add the crate to {}: {err}", manifest.display());
+            std::process::exit(1);
+        }
+    }
 }
 
 fn parse_args(args: Vec<String>) -> Result<Args, ParseArgsError> {
     let mut url = None;
     let mut token = None;
     let mut branch = None;
+    let mut proxy = None;
+    let mut no_proxy = false;
+    let mut identity_path = None;
+    let mut ca_cert_path = None;
     let mut schema_path = None;
+    let mut schema_cache = None;
+    let mut config_path = None;
     let mut out_dir = None;
     let mut crate_name = None;
     let mut infrahub_path = None;
+    let mut infrahub_version = None;
+    let mut infrahub_git = None;
+    let mut infrahub_rev = None;
+    let mut license = None;
+    let mut description = None;
+    let mut repository = None;
+    let mut publish = false;
+    let mut features = Vec::new();
+    let mut workspace_manifest = None;
+    let mut smoke_example = false;
+    let mut read_only = false;
+    let mut mutations_only = false;
 
     let mut iter = args.into_iter().skip(1);
     while let Some(arg) = iter.next() {
@@ -89,10 +159,28 @@ fn parse_args(args: Vec<String>) -> Result<Args, ParseArgsError> {
             "--url" => url = iter.next(),
             "--token" => token = iter.next(),
             "--branch" => branch = iter.next(),
+            "--proxy" => proxy = iter.next(),
+            "--no-proxy" => no_proxy = true,
+            "--identity" => identity_path = iter.next().map(PathBuf::from),
+            "--ca-cert" => ca_cert_path = iter.next().map(PathBuf::from),
             "--schema" => schema_path = iter.next().map(PathBuf::from),
+            "--schema-cache" => schema_cache = iter.next().map(PathBuf::from),
+            "--config" => config_path = iter.next().map(PathBuf::from),
             "--out" => out_dir = iter.next().map(PathBuf::from),
             "--crate-name" => crate_name = iter.next(),
             "--infrahub-path" => infrahub_path = iter.next(),
+            "--infrahub-version" => infrahub_version = iter.next(),
+            "--infrahub-git" => infrahub_git = iter.next(),
+            "--infrahub-rev" => infrahub_rev = iter.next(),
+            "--license" => license = iter.next(),
+            "--description" => description = iter.next(),
+            "--repository" => repository = iter.next(),
+            "--publish" => publish = true,
+            "--feature" => features.extend(iter.next()),
+            "--add-to-workspace" => workspace_manifest = iter.next().map(PathBuf::from),
+            "--smoke-example" => smoke_example = true,
+            "--read-only" => read_only = true,
+            "--mutations-only" => mutations_only = true,
             "--help" | "-h" => return Err(ParseArgsError::Help),
             _ => return Err(ParseArgsError::Message(format!("unknown argument: {arg}"))),
         }
@@ -107,17 +195,165 @@ fn parse_args(args: Vec<String>) -> Result<Args, ParseArgsError> {
         ));
     }
 
+    if schema_cache.is_some() && url.is_none() {
+        return Err(ParseArgsError::Message(
+            "--schema-cache requires --url".to_string(),
+        ));
+    }
+
+    if proxy.is_some() && no_proxy {
+        return Err(ParseArgsError::Message(
+            "--proxy and --no-proxy are mutually exclusive".to_string(),
+        ));
+    }
+
+    if mutations_only && read_only {
+        return Err(ParseArgsError::Message(
+            "--mutations-only and --read-only are mutually exclusive".to_string(),
+        ));
+    }
+
+    if mutations_only && smoke_example {
+        return Err(ParseArgsError::Message(
+            "--smoke-example has nothing to list with --mutations-only".to_string(),
+        ));
+    }
+
+    if smoke_example && crate_name.is_none() {
+        return Err(ParseArgsError::Message(
+            "--smoke-example requires --crate-name".to_string(),
+        ));
+    }
+
+    if infrahub_path.is_some() && infrahub_git.is_some() {
+        return Err(ParseArgsError::Message(
+            "--infrahub-path and --infrahub-git are mutually exclusive".to_string(),
+        ));
+    }
+
+    if infrahub_rev.is_some() && infrahub_git.is_none() {
+        return Err(ParseArgsError::Message(
+            "--infrahub-rev requires --infrahub-git".to_string(),
+        ));
+    }
+
+    if let Some(feature) = features
+        .iter()
+        .find(|feature| !MANIFEST_FEATURES.iter().any(|(name, _)| name == feature))
+    {
+        let known: Vec<&str> = MANIFEST_FEATURES.iter().map(|(name, _)| *name).collect();
+        return Err(ParseArgsError::Message(format!(
+            "unknown feature {feature:?} (known: {})",
+            known.join(", ")
+        )));
+    }
+
+    if workspace_manifest.is_some() && crate_name.is_none() {
+        return Err(ParseArgsError::Message(
+            "--add-to-workspace requires --crate-name".to_string(),
+        ));
+    }
+
+    let manifest_flags = infrahub_path.is_some()
+        || infrahub_version.is_some()
+        || infrahub_git.is_some()
+        || license.is_some()
+        || description.is_some()
+        || repository.is_some()
+        || publish
+        || !features.is_empty();
+    if manifest_flags && crate_name.is_none() {
+        return Err(ParseArgsError::Message(
+            "Cargo.toml options require --crate-name".to_string(),
+        ));
+    }
+
     Ok(Args {
         url,
         token,
         branch,
+        proxy,
+        no_proxy,
+        identity_path,
+        ca_cert_path,
         schema_path,
+        schema_cache,
+        config_path,
         out_dir,
         crate_name,
         infrahub_path,
+        infrahub_version,
+        infrahub_git,
+        infrahub_rev,
+        license,
+        description,
+        repository,
+        publish,
+        features,
+        workspace_manifest,
+        smoke_example,
+        read_only,
+        mutations_only,
     })
 }
 
+/// http client for schema downloads, honoring `--proxy`, `--no-proxy`,
+/// `--identity`, and `--ca-cert` the same way the matching `ClientConfig`
+/// settings do
+fn schema_http_client(args: &Args) -> Result<BlockingClient, String> {
+    let builder = BlockingClient::builder().timeout(Duration::from_secs(30));
+    let builder = match &args.proxy {
+        Some(proxy) => builder.proxy(
+            reqwest::Proxy::all(proxy.as_str()).map_err(|err| format!("invalid proxy: {err}"))?,
+        ),
+        None if args.no_proxy => builder.no_proxy(),
+        None => builder,
+    };
+    let builder = apply_tls(builder, args)?;
+    builder.build().map_err(|err| err.to_string())
+}
+
+/// add `--identity` and `--ca-cert` to the schema download client
+#[cfg(any(feature = "rustls", feature = "native-tls"))]
+fn apply_tls(
+    builder: reqwest::blocking::ClientBuilder,
+    args: &Args,
+) -> Result<reqwest::blocking::ClientBuilder, String> {
+    let builder = match &args.identity_path {
+        Some(path) => {
+            let identity = infrahub::ClientIdentity::from_pem_file(path)
+                .and_then(|identity| identity.to_reqwest())
+                .map_err(|err| format!("{}: {err}", path.display()))?;
+            builder.identity(identity)
+        }
+        None => builder,
+    };
+    let builder = match &args.ca_cert_path {
+        Some(path) => {
+            let certs = infrahub::RootCertificate::from_file(path)
+                .and_then(|certificate| certificate.to_reqwest())
+                .map_err(|err| format!("{}: {err}", path.display()))?;
+            builder.tls_certs_merge(certs)
+        }
+        None => builder,
+    };
+    Ok(builder)
+}
+
+/// without a tls backend `--identity` and `--ca-cert` cannot be honored
+#[cfg(not(any(feature = "rustls", feature = "native-tls")))]
+fn apply_tls(
+    builder: reqwest::blocking::ClientBuilder,
+    args: &Args,
+) -> Result<reqwest::blocking::ClientBuilder, String> {
+    if args.identity_path.is_some() || args.ca_cert_path.is_some() {
+        return Err(
+            "--identity and --ca-cert need the `rustls` or `native-tls` feature".to_string(),
+        );
+    }
+    Ok(builder)
+}
+
 fn load_schema(args: &Args) -> Result<String, String> {
     if let Some(schema_path) = &args.schema_path {
         return fs::read_to_string(schema_path)
@@ -128,13 +364,7 @@ fn load_schema(args: &Args) -> Result<String, String> {
         .url
         .as_ref()
         .ok_or_else(|| "--url is required when --schema not provided".to_string())?;
-
     let base = url.trim_end_matches('/');
-    let mut schema_url = Url::parse(&format!("{base}/schema.graphql"))
-        .map_err(|err| format!("invalid url: {err}"))?;
-    if let Some(branch) = &args.branch {
-        schema_url.query_pairs_mut().append_pair("branch", branch);
-    }
 
     let mut headers = HeaderMap::new();
     if let Some(token) = &args.token {
@@ -143,14 +373,61 @@ fn load_schema(args: &Args) -> Result<String, String> {
             HeaderValue::from_str(token).map_err(|err| err.to_string())?,
         );
     }
+    let client = schema_http_client(args)?;
+    let get = |path: &str| -> Result<String, String> {
+        let mut url =
+            Url::parse(&format!("{base}{path}")).map_err(|err| format!("invalid url: {err}"))?;
+        if let Some(branch) = &args.branch {
+            url.query_pairs_mut().append_pair("branch", branch);
+        }
+        fetch_text(&client, url, &headers)
+    };
 
-    let client = BlockingClient::builder()
-        .timeout(Duration::from_secs(30))
-        .build()
-        .map_err(|err| err.to_string())?;
+    let Some(cache) = &args.schema_cache else {
+        return get("/schema.graphql");
+    };
+
+    // an older server without a schema summary just disables the cache
+    let hash = get("/api/schema/summary")
+        .ok()
+        .and_then(|summary| serde_json::from_str::<serde_json::Value>(&summary).ok())
+        .and_then(|summary| summary["main"].as_str().map(str::to_string));
+    let hash_path = schema_cache_hash_path(cache);
+    if let Some(hash) = &hash {
+        let cached_hash = fs::read_to_string(&hash_path).unwrap_or_default();
+        if cached_hash.trim() == hash {
+            if let Ok(schema) = fs::read_to_string(cache) {
+                return Ok(schema);
+            }
+        }
+    }
+
+    let schema = get("/schema.graphql")?;
+    fs::write(cache, &schema)
+        .map_err(|err| format!("failed to write {}: {err}", cache.display()))?;
+    match &hash {
+        Some(hash) => fs::write(&hash_path, hash),
+        None => fs::remove_file(&hash_path).or_else(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => Ok(()),
+            _ => Err(err),
+        }),
+    }
+    .map_err(|err| format!("failed to write {}: {err}", hash_path.display()))?;
+    Ok(schema)
+}
+
+/// `<cache>.hash`, holding the schema hash the cached schema was fetched at
+fn schema_cache_hash_path(cache: &Path) -> PathBuf {
+    let mut path = cache.as_os_str().to_owned();
+    path.push(".hash");
+    PathBuf::from(path)
+}
+
+/// GET a text body, turning http errors into a short message
+fn fetch_text(client: &BlockingClient, url: Url, headers: &HeaderMap) -> Result<String, String> {
     let response = client
-        .get(schema_url)
-        .headers(headers)
+        .get(url)
+        .headers(headers.clone())
         .send()
         .map_err(|err| err.to_string())?;
 
@@ -170,6 +447,69 @@ fn load_schema(args: &Args) -> Result<String, String> {
         .map_err(|err| format!("failed to read schema response: {err}"))
 }
 
+/// codegen config file (`--config`)
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct CodegenConfig {
+    selection: SelectionDepth,
+    types: TypesConfig,
+}
+
+/// options for the generated types
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct TypesConfig {
+    /// add a flattened `extra` map to node types for fields this schema does
+    /// not know about
+    capture_unknown_fields: bool,
+}
+
+/// how deep default selection sets go, globally and per namespace or model
+///
+/// the most specific setting wins: model, then namespace, then `depth`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct SelectionDepth {
+    depth: usize,
+    namespaces: BTreeMap<String, usize>,
+    models: BTreeMap<String, usize>,
+}
+
+impl Default for SelectionDepth {
+    fn default() -> Self {
+        Self {
+            depth: 3,
+            namespaces: BTreeMap::new(),
+            models: BTreeMap::new(),
+        }
+    }
+}
+
+impl SelectionDepth {
+    /// maximum selection depth for a model, or a type returned for it such
+    /// as `PaginatedInfraDevice`
+    fn max_depth(&self, type_name: &str) -> usize {
+        let model = type_name.strip_prefix("Paginated").unwrap_or(type_name);
+        let namespace = namespace_from_type(model);
+        self.models
+            .get(model)
+            .or_else(|| {
+                self.namespaces
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case(&namespace))
+                    .map(|(_, depth)| depth)
+            })
+            .copied()
+            .unwrap_or(self.depth)
+    }
+}
+
+fn load_config(path: &std::path::Path) -> Result<CodegenConfig, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    toml::from_str(&text).map_err(|err| format!("invalid {}: {err}", path.display()))
+}
+
 struct SchemaContext<'a> {
     types: BTreeMap<String, TypeDefinition<'a, String>>,
     query_type: String,
@@ -179,6 +519,10 @@ struct SchemaContext<'a> {
     objects: BTreeSet<String>,
     unions: BTreeSet<String>,
     scalars: BTreeSet<String>,
+    selection: SelectionDepth,
+    capture_unknown_fields: bool,
+    chrono: bool,
+    ipnet: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -229,7 +573,7 @@ impl<'a> SchemaContext<'a> {
                         scalars.insert(scalar_ty.name.clone());
                         scalar_ty.name.clone()
                     }
-                    _ => continue,
+                    TypeDefinition::Interface(iface) => iface.name.clone(),
                 };
                 types.insert(name, ty.clone());
             } else if let Definition::SchemaDefinition(schema) = def {
@@ -249,10 +593,111 @@ impl<'a> SchemaContext<'a> {
             objects,
             unions,
             scalars,
+            selection: SelectionDepth::default(),
+            capture_unknown_fields: false,
+            chrono: false,
+            ipnet: false,
         }
     }
 }
 
+impl SchemaContext<'_> {
+    /// drop the mutation root (`Mutation` unless the schema names another) and
+    /// the payload types only it returns, so the generated crate contains no
+    /// write paths
+    fn read_only(mut self) -> Self {
+        let mutation_name = self.mutation_root();
+        self.mutation_type = None;
+        let payloads: BTreeSet<String> = match self.types.remove(&mutation_name) {
+            Some(TypeDefinition::Object(mutation)) => mutation
+                .fields
+                .iter()
+                .map(|field| base_type_name(&field.field_type))
+                .collect(),
+            _ => BTreeSet::new(),
+        };
+        self.objects.remove(&mutation_name);
+
+        let referenced: BTreeSet<String> = self
+            .types
+            .values()
+            .flat_map(|ty| match ty {
+                TypeDefinition::Object(obj) => obj
+                    .fields
+                    .iter()
+                    .map(|field| base_type_name(&field.field_type))
+                    .collect(),
+                TypeDefinition::Union(union_ty) => union_ty.types.clone(),
+                _ => Vec::new(),
+            })
+            .collect();
+        for payload in payloads.difference(&referenced) {
+            if self.objects.remove(payload) {
+                self.types.remove(payload);
+            }
+        }
+        self
+    }
+
+    /// keep only the mutation root and the types its operations take or
+    /// return, dropping every query
+    fn mutations_only(mut self) -> Result<Self, String> {
+        let mutation_name = self.mutation_root();
+        let Some(TypeDefinition::Object(mutation)) = self.types.get(&mutation_name) else {
+            return Err("schema has no mutation type".to_string());
+        };
+
+        let mut pending: Vec<String> = mutation
+            .fields
+            .iter()
+            .flat_map(|field| {
+                field
+                    .arguments
+                    .iter()
+                    .map(|arg| base_type_name(&arg.value_type))
+                    .chain([base_type_name(&field.field_type)])
+            })
+            .collect();
+        let mut reachable = BTreeSet::from([mutation_name.clone()]);
+        while let Some(name) = pending.pop() {
+            if !reachable.insert(name.clone()) {
+                continue;
+            }
+            match self.types.get(&name) {
+                Some(TypeDefinition::Object(obj)) => pending.extend(
+                    obj.fields
+                        .iter()
+                        .map(|field| base_type_name(&field.field_type)),
+                ),
+                Some(TypeDefinition::InputObject(input)) => pending.extend(
+                    input
+                        .fields
+                        .iter()
+                        .map(|field| base_type_name(&field.value_type)),
+                ),
+                Some(TypeDefinition::Union(union_ty)) => pending.extend(union_ty.types.clone()),
+                _ => {}
+            }
+        }
+
+        self.types
+            .retain(|name, ty| reachable.contains(name) || matches!(ty, TypeDefinition::Scalar(_)));
+        self.enums.retain(|name| reachable.contains(name));
+        self.inputs.retain(|name| reachable.contains(name));
+        self.objects.retain(|name| reachable.contains(name));
+        self.unions.retain(|name| reachable.contains(name));
+        self.mutation_type = Some(mutation_name);
+        Ok(self)
+    }
+
+    /// name of the mutation root type
+    fn mutation_root(&self) -> String {
+        self.mutation_type
+            .clone()
+            .unwrap_or_else(|| "Mutation".to_string())
+    }
+}
+
 fn generate_client(args: &Args, ctx: &SchemaContext) -> Result<(), String> {
     let out_dir = &args.out_dir;
     let src_dir = out_dir.join("src");
@@ -261,20 +706,15 @@ fn generate_client(args: &Args, ctx: &SchemaContext) -> Result<(), String> {
     fs::create_dir_all(&api_dir).map_err(|err| err.to_string())?;
 
     if let Some(crate_name) = &args.crate_name {
-        let mut cargo = String::new();
-        cargo.push_str("[package]\n");
-        cargo.push_str(&format!("name = \"{}\"\n", crate_name));
-        cargo.push_str(&format!("version = \"{}\"\n", env!("CARGO_PKG_VERSION")));
-        cargo.push_str("edition = \"2021\"\n\n");
-        cargo.push_str("[dependencies]\n");
-        if let Some(path) = &args.infrahub_path {
-            cargo.push_str(&format!("infrahub = {{ path = \"{}\" }}\n", path));
-        } else {
-            cargo.push_str(&format!("infrahub = \"{}\"\n", env!("CARGO_PKG_VERSION")));
-        }
-        cargo.push_str("serde = { version = \"1\", features = [\"derive\"] }\n");
-        cargo.push_str("serde_json = \"1\"\n");
+        let cargo = render_cargo_toml(args, crate_name);
         fs::write(out_dir.join("Cargo.toml"), cargo).map_err(|err| err.to_string())?;
+
+        if args.smoke_example {
+            let examples_dir = out_dir.join("examples");
+            fs::create_dir_all(&examples_dir).map_err(|err| err.to_string())?;
+            let smoke_rs = render_smoke_example(ctx, &crate_name.replace('-', "_"));
+            fs::write(examples_dir.join("smoke.rs"), smoke_rs).map_err(|err| err.to_string())?;
+        }
     }
 
     let types_rs = render_types(ctx);
@@ -297,29 +737,441 @@ fn generate_client(args: &Args, ctx: &SchemaContext) -> Result<(), String> {
         fs::write(api_dir.join(format!("{name}.rs")), content).map_err(|err| err.to_string())?;
     }
 
+    let kinds_rs = render_kinds(ctx);
+    fs::write(src_dir.join("kinds.rs"), kinds_rs).map_err(|err| err.to_string())?;
+
+    let schema_rs = render_schema_registry(ctx);
+    fs::write(src_dir.join("schema.rs"), schema_rs).map_err(|err| err.to_string())?;
+
     let lib_rs = render_lib();
     fs::write(src_dir.join("lib.rs"), lib_rs).map_err(|err| err.to_string())?;
 
     Ok(())
 }
 
+/// features `--feature` accepts for the generated crate, with what each one
+/// enables: an `infrahub` feature, or an optional dependency (with serde
+/// support) that the typed scalars in `types.rs` switch to
+const MANIFEST_FEATURES: &[(&str, &str)] = &[
+    ("yaml", "infrahub/yaml"),
+    ("chrono", "dep:chrono"),
+    ("ipnet", "dep:ipnet"),
+];
+
+/// quote a string as a toml basic string
+fn toml_string(value: &str) -> String {
+    toml::Value::String(value.to_string()).to_string()
+}
+
+fn render_cargo_toml(args: &Args, crate_name: &str) -> String {
+    let mut cargo = String::new();
+    cargo.push_str("[package]\n");
+    cargo.push_str(&format!("name = {}\n", toml_string(crate_name)));
+    cargo.push_str(&format!("version = \"{}\"\n", env!("CARGO_PKG_VERSION")));
+    cargo.push_str("edition = \"2021\"\n");
+    for (key, value) in [
+        ("license", &args.license),
+        ("description", &args.description),
+        ("repository", &args.repository),
+    ] {
+        if let Some(value) = value {
+            cargo.push_str(&format!("{key} = {}\n", toml_string(value)));
+        }
+    }
+    if !args.publish {
+        cargo.push_str("publish = false\n");
+    }
+
+    cargo.push_str("\n[dependencies]\n");
+    let mut infrahub = Vec::new();
+    if let Some(path) = &args.infrahub_path {
+        infrahub.push(format!("path = {}", toml_string(path)));
+    }
+    if let Some(git) = &args.infrahub_git {
+        infrahub.push(format!("git = {}", toml_string(git)));
+        if let Some(rev) = &args.infrahub_rev {
+            infrahub.push(format!("rev = {}", toml_string(rev)));
+        }
+    }
+    match &args.infrahub_version {
+        Some(version) => infrahub.push(format!("version = {}", toml_string(version))),
+        None if infrahub.is_empty() => {
+            infrahub.push(format!("version = \"{}\"", env!("CARGO_PKG_VERSION")))
+        }
+        None => {}
+    }
+    cargo.push_str(&format!("infrahub = {{ {} }}\n", infrahub.join(", ")));
+    cargo.push_str("serde = { version = \"1\", features = [\"derive\"] }\n");
+    cargo.push_str("serde_json = \"1\"\n");
+    if args.features.iter().any(|feature| feature == "chrono") {
+        cargo.push_str("chrono = { version = \"0.4\", features = [\"serde\"], optional = true }\n");
+    }
+    if args.features.iter().any(|feature| feature == "ipnet") {
+        cargo.push_str("ipnet = { version = \"2\", features = [\"serde\"], optional = true }\n");
+    }
+
+    if args.smoke_example {
+        cargo.push_str("\n[dev-dependencies]\n");
+        cargo.push_str(
+            "tokio = { version = \"1\", features = [\"rt-multi-thread\", \"macros\"] }\n",
+        );
+    }
+
+    if !args.features.is_empty() {
+        cargo.push_str("\n[features]\n");
+        for (name, enables) in MANIFEST_FEATURES {
+            if args.features.iter().any(|feature| feature == name) {
+                cargo.push_str(&format!("{name} = [\"{enables}\"]\n"));
+            }
+        }
+    }
+    cargo
+}
+
+/// list `crate_dir` in the `[workspace] members` of the manifest at
+/// `manifest`, keeping its formatting
+///
+/// returns false without touching the file if a member entry, or a
+/// `dir/*` glob, already covers the crate.
+fn add_to_workspace(manifest: &Path, crate_dir: &Path) -> Result<bool, String> {
+    let text = fs::read_to_string(manifest)
+        .map_err(|err| format!("failed to read {}: {err}", manifest.display()))?;
+    let mut doc: toml_edit::DocumentMut = text
+        .parse()
+        .map_err(|err| format!("invalid manifest: {err}"))?;
+
+    let root = fs::canonicalize(manifest)
+        .map_err(|err| err.to_string())?
+        .parent()
+        .map(Path::to_path_buf)
+        .ok_or_else(|| "manifest has no parent directory".to_string())?;
+    let crate_dir = fs::canonicalize(crate_dir).map_err(|err| err.to_string())?;
+    let relative = crate_dir
+        .strip_prefix(&root)
+        .map_err(|_| format!("{} is outside the workspace", crate_dir.display()))?;
+    let member = relative
+        .components()
+        .map(|part| part.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    if member.is_empty() {
+        return Err("the generated crate is the workspace root".to_string());
+    }
+
+    let workspace = doc
+        .get_mut("workspace")
+        .and_then(|item| item.as_table_like_mut())
+        .ok_or_else(|| "manifest has no [workspace] table".to_string())?;
+    if workspace.get("members").is_none() {
+        workspace.insert("members", toml_edit::value(toml_edit::Array::new()));
+    }
+    let members = workspace
+        .get_mut("members")
+        .and_then(|item| item.as_array_mut())
+        .ok_or_else(|| "workspace members is not an array".to_string())?;
+
+    let covered = members
+        .iter()
+        .filter_map(|value| value.as_str())
+        .any(|entry| {
+            let entry = entry.trim_start_matches("./").trim_end_matches('/');
+            entry == member
+                || entry
+                    .strip_suffix("/*")
+                    .and_then(|dir| member.strip_prefix(dir))
+                    .and_then(|rest| rest.strip_prefix('/'))
+                    .is_some_and(|name| !name.contains('/'))
+        });
+    if covered {
+        return Ok(false);
+    }
+
+    // match the layout of the existing entries, one per line or inline
+    let prefix = members
+        .iter()
+        .last()
+        .and_then(|last| last.decor().prefix())
+        .and_then(|prefix| prefix.as_str())
+        .map(|prefix| match prefix.rfind('\n') {
+            // drop comments carried in the prefix, keep the indentation
+            Some(newline) => prefix[newline..].to_string(),
+            None => prefix.to_string(),
+        });
+    members.push(member.as_str());
+    if let (Some(prefix), Some(added)) = (prefix, members.iter_mut().last()) {
+        added.decor_mut().set_prefix(prefix);
+    }
+
+    fs::write(manifest, doc.to_string())
+        .map_err(|err| format!("failed to write {}: {err}", manifest.display()))?;
+    Ok(true)
+}
+
 fn render_lib() -> String {
     let mut out = String::new();
     out.push_str("//! generated infrahub client\n\n");
     out.push_str("pub mod api;\n");
     out.push_str("pub mod client;\n");
     out.push_str("pub mod inputs;\n");
+    out.push_str("pub mod kinds;\n");
     out.push_str("pub mod responses;\n");
+    out.push_str("pub mod schema;\n");
     out.push_str("pub mod types;\n\n");
     out.push_str("pub use client::GeneratedClient;\n");
     out.push_str("pub use api::{Api, ApiClient};\n");
     out
 }
 
+fn render_kinds(ctx: &SchemaContext) -> String {
+    let mut out = String::new();
+    out.push_str("//! generated node kind names\n");
+    out.push_str("//!\n");
+    out.push_str("//! use these with the dynamic helpers on `infrahub::Client`, such as\n");
+    out.push_str("//! `query_nodes` and `create_node`, instead of string literals.\n\n");
+    let models = collect_models(ctx);
+    let mut seen = BTreeSet::new();
+    for model in models.values() {
+        let name = to_snake(&model.name).to_uppercase();
+        if seen.insert(name.clone()) {
+            out.push_str(&format!(
+                "pub const {name}: &str = \"{kind}\";\n",
+                kind = model.name
+            ));
+        }
+    }
+
+    let mut seen = BTreeSet::new();
+    let variants: Vec<(String, &str)> = models
+        .values()
+        .map(|model| (to_rust_ident(&model.name), model.name.as_str()))
+        .filter(|(variant, _)| seen.insert(variant.clone()))
+        .collect();
+
+    out.push_str("\n/// a node kind of this schema\n");
+    out.push_str("///\n");
+    out.push_str("/// implements `AsRef<str>` and `Into<String>`, so it can be passed to the\n");
+    out.push_str("/// dynamic helpers on `infrahub::Client` in place of a kind name.\n");
+    out.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]\n");
+    out.push_str("pub enum Kind {\n");
+    for (variant, _) in &variants {
+        out.push_str(&format!("    {variant},\n"));
+    }
+    out.push_str("}\n\n");
+
+    out.push_str("impl Kind {\n");
+    out.push_str("    /// every kind, in name order\n");
+    out.push_str("    pub const ALL: &'static [Kind] = &[\n");
+    for (variant, _) in &variants {
+        out.push_str(&format!("        Kind::{variant},\n"));
+    }
+    out.push_str("    ];\n\n");
+    out.push_str("    /// kind name as used by the graphql api\n");
+    out.push_str("    pub fn as_str(&self) -> &'static str {\n");
+    out.push_str("        match self {\n");
+    for (variant, kind) in &variants {
+        out.push_str(&format!("            Kind::{variant} => \"{kind}\",\n"));
+    }
+    out.push_str("        }\n");
+    out.push_str("    }\n");
+    out.push_str("}\n\n");
+
+    out.push_str("impl std::str::FromStr for Kind {\n");
+    out.push_str("    type Err = infrahub::Error;\n\n");
+    out.push_str("    fn from_str(kind: &str) -> Result<Self, Self::Err> {\n");
+    out.push_str("        match kind {\n");
+    for (variant, kind) in &variants {
+        out.push_str(&format!("            \"{kind}\" => Ok(Kind::{variant}),\n"));
+    }
+    out.push_str("            _ => Err(infrahub::Error::Config(format!(\"unknown node kind: {kind:?}\"))),\n");
+    out.push_str("        }\n");
+    out.push_str("    }\n");
+    out.push_str("}\n\n");
+
+    out.push_str("impl AsRef<str> for Kind {\n");
+    out.push_str("    fn as_ref(&self) -> &str {\n");
+    out.push_str("        self.as_str()\n");
+    out.push_str("    }\n");
+    out.push_str("}\n\n");
+    out.push_str("impl std::fmt::Display for Kind {\n");
+    out.push_str("    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {\n");
+    out.push_str("        f.write_str(self.as_str())\n");
+    out.push_str("    }\n");
+    out.push_str("}\n\n");
+    out.push_str("impl From<Kind> for String {\n");
+    out.push_str("    fn from(kind: Kind) -> Self {\n");
+    out.push_str("        kind.as_str().to_string()\n");
+    out.push_str("    }\n");
+    out.push_str("}\n");
+    out
+}
+
+/// static field table of every object and interface type, used to validate
+/// runtime selections
+fn render_schema_registry(ctx: &SchemaContext) -> String {
+    let mut out = String::new();
+    out.push_str("//! generated schema registry\n");
+    out.push_str("//!\n");
+    out.push_str("//! fields of every object and interface type with their base type names,\n");
+    out.push_str("//! used to validate selections passed to `list_with_selection`.\n\n");
+    out.push_str(
+        "pub static REGISTRY: infrahub::SchemaRegistry = infrahub::SchemaRegistry::new(&[\n",
+    );
+    for (name, ty) in &ctx.types {
+        let fields = match ty {
+            TypeDefinition::Object(obj) => &obj.fields,
+            TypeDefinition::Interface(iface) => &iface.fields,
+            _ => continue,
+        };
+        if *name == ctx.query_type || ctx.mutation_type.as_deref() == Some(name.as_str()) {
+            continue;
+        }
+        let fields: Vec<String> = fields
+            .iter()
+            .filter(|field| !should_skip_field(field))
+            .map(|field| {
+                format!(
+                    "(\"{}\", \"{}\")",
+                    field.name,
+                    base_type_name(&field.field_type)
+                )
+            })
+            .collect();
+        out.push_str(&format!("    (\"{name}\", &[{}]),\n", fields.join(", ")));
+    }
+    out.push_str("]);\n");
+    out
+}
+
+fn render_smoke_example(ctx: &SchemaContext, lib_name: &str) -> String {
+    let models = collect_models(ctx);
+    let mut out = String::new();
+    out.push_str("//! generated smoke test: list one page of each model against a live infrahub\n");
+    out.push_str("//!\n");
+    out.push_str("//! usage: INFRAHUB_URL=http://localhost:8000 INFRAHUB_TOKEN=... cargo run --example smoke\n");
+    out.push_str("//! set INFRAHUB_BRANCH to list from a branch other than the default.\n\n");
+    out.push_str(&format!("use {lib_name}::api::Api;\n\n"));
+    out.push_str("#[tokio::main]\n");
+    out.push_str("async fn main() {\n");
+    out.push_str(
+        "    let branch = std::env::var(\"INFRAHUB_BRANCH\").ok().filter(|b| !b.is_empty());\n",
+    );
+    out.push_str("    let api = match Api::from_env() {\n");
+    out.push_str("        Ok(api) => api,\n");
+    out.push_str("        Err(err) => {\n");
+    out.push_str("            eprintln!(\"invalid client config: {err}\");\n");
+    out.push_str("            std::process::exit(2);\n");
+    out.push_str("        }\n");
+    out.push_str("    };\n");
+    out.push_str("    let branch = branch.as_deref();\n");
+    out.push_str("    let mut report = Report::default();\n\n");
+
+    for model in models.values() {
+        let Some(query_field) = &model.query_field else {
+            continue;
+        };
+        let ns = to_snake(&model.namespace);
+        let accessor = model_accessor_name(&model.name, &model.namespace);
+        let filters = if query_field.arguments.iter().any(|arg| arg.name == "limit") {
+            format!(
+                "Some({lib_name}::api::{ns}::{}Filters {{ limit: Some(1), ..Default::default() }})",
+                model.name
+            )
+        } else {
+            "None".to_string()
+        };
+        out.push_str(&format!(
+            "    report.record(\"{}\", api.{ns}().{accessor}().list({filters}, branch).await.map(|items| items.len()));\n",
+            model.name
+        ));
+    }
+
+    out.push_str(
+        "\n    println!(\"{} of {} models ok\", report.total - report.failed, report.total);\n",
+    );
+    out.push_str("    if report.failed > 0 {\n");
+    out.push_str("        std::process::exit(1);\n");
+    out.push_str("    }\n");
+    out.push_str("}\n\n");
+    out.push_str("#[derive(Default)]\n");
+    out.push_str("struct Report {\n");
+    out.push_str("    total: usize,\n");
+    out.push_str("    failed: usize,\n");
+    out.push_str("}\n\n");
+    out.push_str("impl Report {\n");
+    out.push_str("    fn record(&mut self, model: &str, result: infrahub::Result<usize>) {\n");
+    out.push_str("        self.total += 1;\n");
+    out.push_str("        match result {\n");
+    out.push_str("            Ok(count) => println!(\"ok    {model} ({count} listed)\"),\n");
+    out.push_str("            Err(err) => {\n");
+    out.push_str("                self.failed += 1;\n");
+    out.push_str("                println!(\"FAIL  {model}: {err}\");\n");
+    out.push_str("            }\n");
+    out.push_str("        }\n");
+    out.push_str("    }\n");
+    out.push_str("}\n");
+    out
+}
+
+/// type aliases for the scalars a `--feature` switches to a typed
+/// representation, falling back to `String` while the feature is off
+fn render_scalar_aliases(ctx: &SchemaContext) -> String {
+    let mut aliases = Vec::new();
+    if ctx.chrono {
+        aliases.push((
+            "chrono",
+            "the `DateTime` scalar",
+            "DateTime",
+            "chrono::DateTime<chrono::Utc>",
+        ));
+    }
+    if ctx.ipnet {
+        aliases.push((
+            "ipnet",
+            "an ip address with its prefix length, such as `IPHost.value`",
+            "IpNet",
+            "ipnet::IpNet",
+        ));
+        aliases.push((
+            "ipnet",
+            "an ip address without a prefix length, such as `IPHost.ip`",
+            "IpAddr",
+            "std::net::IpAddr",
+        ));
+    }
+    let mut out = String::new();
+    for (feature, doc, alias, typed) in aliases {
+        out.push_str(&format!(
+            "/// {doc}; `{typed}` with the `{feature}` feature\n"
+        ));
+        out.push_str(&format!("#[cfg(feature = \"{feature}\")]\n"));
+        out.push_str(&format!("pub type {alias} = {typed};\n"));
+        out.push_str(&format!(
+            "/// {doc}; `{typed}` with the `{feature}` feature\n"
+        ));
+        out.push_str(&format!("#[cfg(not(feature = \"{feature}\"))]\n"));
+        out.push_str(&format!("pub type {alias} = String;\n\n"));
+    }
+    out
+}
+
+/// the alias for a string field of an ip attribute type that `--feature
+/// ipnet` parses
+fn ip_scalar(object: &str, field: &str, ctx: &SchemaContext) -> Option<&'static str> {
+    if !ctx.ipnet {
+        return None;
+    }
+    match (object, field) {
+        ("IPHost" | "IPNetwork", "value") => Some("crate::types::IpNet"),
+        ("IPHost", "ip") => Some("crate::types::IpAddr"),
+        _ => None,
+    }
+}
+
 fn render_types(ctx: &SchemaContext) -> String {
     let mut out = String::new();
     out.push_str("//! generated types\n\n");
     out.push_str("use serde::{Deserialize, Serialize};\n\n");
+    out.push_str(&render_scalar_aliases(ctx));
 
     for enum_name in &ctx.enums {
         if let Some(TypeDefinition::Enum(enum_ty)) = ctx.types.get(enum_name) {
@@ -337,11 +1189,25 @@ fn render_types(ctx: &SchemaContext) -> String {
         }
     }
 
+    let node_types: BTreeSet<&str> = if ctx.capture_unknown_fields {
+        collect_models(ctx)
+            .keys()
+            .filter_map(|model| match node_object_for_model(model, ctx) {
+                Some(TypeDefinition::Object(node)) => Some(node.name.as_str()),
+                _ => None,
+            })
+            .collect()
+    } else {
+        BTreeSet::new()
+    };
+
     for obj_name in &ctx.objects {
         if obj_name == &ctx.query_type {
             continue;
         }
         if let Some(TypeDefinition::Object(obj)) = ctx.types.get(obj_name) {
+            let is_connection = connection_node(obj_name, ctx).is_some();
+            let mut field_names = BTreeSet::new();
             out.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
             out.push_str(&format!("pub struct {} {{\n", obj_name));
             for field in &obj.fields {
@@ -349,11 +1215,34 @@ fn render_types(ctx: &SchemaContext) -> String {
                     continue;
                 }
                 let rust_name = to_rust_field(field.name.as_str());
-                let ty = rust_type(&field.field_type, ctx, false);
+                let ty = match ip_scalar(obj_name, &field.name, ctx) {
+                    Some(alias) => {
+                        rust_type(&field.field_type, ctx, false).replace("String", alias)
+                    }
+                    None => rust_type(&field.field_type, ctx, false),
+                };
                 if rust_name != field.name {
                     out.push_str(&format!("    #[serde(rename = \"{}\")]\n", field.name));
                 }
+                // relationship selections only ask for `count` by default
+                if is_connection && field.name == "edges" && ty.starts_with("Vec<") {
+                    out.push_str("    #[serde(default)]\n");
+                }
                 out.push_str(&format!("    pub {}: {},\n", rust_name, ty));
+                field_names.insert(rust_name);
+            }
+            if node_types.contains(obj_name.as_str()) {
+                let extra = ["extra", "extra_fields", "unknown_fields"]
+                    .into_iter()
+                    .find(|name| !field_names.contains(*name))
+                    .unwrap_or("__extra");
+                out.push_str(
+                    "    /// fields returned by the server that this schema does not define\n",
+                );
+                out.push_str("    #[serde(flatten)]\n");
+                out.push_str(&format!(
+                    "    pub {extra}: serde_json::Map<String, serde_json::Value>,\n"
+                ));
             }
             out.push_str("}\n\n");
         }
@@ -446,7 +1335,7 @@ fn render_responses(ctx: &SchemaContext) -> String {
 fn render_client(ctx: &SchemaContext) -> String {
     let mut out = String::new();
     out.push_str("//! generated client\n\n");
-    out.push_str("#![allow(non_snake_case, clippy::too_many_arguments, clippy::field_reassign_with_default)]\n\n");
+    out.push_str("#![allow(non_snake_case, unused_imports, clippy::too_many_arguments, clippy::field_reassign_with_default)]\n\n");
     out.push_str("use infrahub::{Client, GraphQlResponse, Result};\n");
     out.push_str("use serde_json::Value;\n\n");
     out.push_str("use crate::inputs::*;\n");
@@ -500,7 +1389,8 @@ fn render_api_mod<'a>(ctx: &SchemaContext<'a>) -> String {
 
     let mut out = String::new();
     out.push_str("//! generated ergonomic api\n\n");
-    out.push_str("use infrahub::Client;\n\n");
+    // absolute path: a schema namespace may itself be called `infrahub`
+    out.push_str("use ::infrahub::Client;\n\n");
     for ns in &namespaces {
         out.push_str(&format!("pub mod {};\n", ns));
     }
@@ -516,6 +1406,17 @@ fn render_api_mod<'a>(ctx: &SchemaContext<'a>) -> String {
     out.push_str("        Api { client: self }\n");
     out.push_str("    }\n");
     out.push_str("}\n\n");
+    out.push_str("impl Api<'static> {\n");
+    out.push_str("    /// an api over [`Client::from_env`], for examples and scripts\n");
+    out.push_str("    ///\n");
+    out.push_str("    /// the client is leaked and lives until the process exits.\n");
+    out.push_str("    pub fn from_env() -> ::infrahub::Result<Self> {\n");
+    out.push_str(
+        "        let client: &'static Client = Box::leak(Box::new(Client::from_env()?));\n",
+    );
+    out.push_str("        Ok(client.api())\n");
+    out.push_str("    }\n");
+    out.push_str("}\n\n");
     out.push_str("impl<'a> Api<'a> {\n");
     for ns in &namespaces {
         let struct_name = format!("{}Api", to_rust_ident(ns));
@@ -561,7 +1462,7 @@ fn render_api_module<'a>(
     out.push_str("//! generated api module\n\n");
     out.push_str("#![allow(non_snake_case, unused_imports, unused_assignments, clippy::field_reassign_with_default)]\n\n");
     out.push_str(
-        "use infrahub::{BoxExtract, BoxFetch, BoxFutureResult, Client, DynPaginator, EdgePage, Error, Result};\n",
+        "use infrahub::{BoxExtract, BoxFetch, BoxFutureResult, Client, DynPaginator, EdgePage, EdgePageInfo, Error, Result, Selection};\n",
     );
     out.push_str("use serde_json::Value;\n\n");
     out.push_str("use crate::inputs::*;\n");
@@ -650,7 +1551,13 @@ fn render_model_client<'a>(model: &ModelInfo<'a>, ctx: &SchemaContext<'a>) -> St
             .query_return
             .clone()
             .unwrap_or_else(|| "serde_json::Value".to_string());
-        let selection = selection_for_type(&return_type, ctx, &mut BTreeSet::new(), 0);
+        let selection = selection_for_type(
+            &return_type,
+            ctx,
+            &mut BTreeSet::new(),
+            0,
+            ctx.selection.max_depth(&model.name),
+        );
         let op_header = if vars_def.is_empty() {
             format!("query {}", query_name)
         } else {
@@ -674,7 +1581,7 @@ fn render_model_client<'a>(model: &ModelInfo<'a>, ctx: &SchemaContext<'a>) -> St
             "        let response = self.client.execute::<{}>(query, Some(vars), request_branch).await?;\n",
             response_type
         ));
-        out.push_str("        let data = response.data.ok_or_else(|| Error::Config(\"missing data\".to_string()))?;\n");
+        out.push_str("        let data = response.into_data()?;\n");
         out.push_str("        let mut items = Vec::new();\n");
         out.push_str(&format!(
             "        for edge in data.{field}.edges {{\n",
@@ -691,6 +1598,42 @@ fn render_model_client<'a>(model: &ModelInfo<'a>, ctx: &SchemaContext<'a>) -> St
         out.push_str("        Ok(items)\n");
         out.push_str("    }\n\n");
 
+        if let Some(TypeDefinition::Object(node)) = node_object_for_model(&model.name, ctx) {
+            out.push_str(&format!(
+                "    pub async fn list_with_selection<T: serde::de::DeserializeOwned>(&self, filters: Option<{filters_struct}>, selection: &Selection, request_branch: Option<&str>) -> Result<Vec<T>> {{\n"
+            ));
+            out.push_str(&format!(
+                "        crate::schema::REGISTRY.validate(\"{}\", selection)?;\n",
+                node.name
+            ));
+            out.push_str("        let vars = filters.map(|f| f.to_vars()).transpose()?.unwrap_or_else(|| Value::Object(serde_json::Map::new()));\n");
+            out.push_str(&format!(
+                "        let query = [r#\"{op} {{ {name}{args} {{ edges {{ node \"#, &selection.render()?, \" }} }} }}\"].concat();\n",
+                op = op_header,
+                name = query_name,
+                args = field_args,
+            ));
+            out.push_str("        let response = self.client.execute_raw(&query, Some(vars), request_branch).await?;\n");
+            out.push_str("        let mut data = response.into_data()?;\n");
+            out.push_str(&format!(
+                "        let edges = match data.get_mut(\"{query_name}\").and_then(|v| v.get_mut(\"edges\")).map(Value::take) {{\n"
+            ));
+            out.push_str("            Some(Value::Array(edges)) => edges,\n");
+            out.push_str("            _ => Vec::new(),\n");
+            out.push_str("        };\n");
+            out.push_str("        let mut items = Vec::new();\n");
+            out.push_str("        for mut edge in edges {\n");
+            out.push_str("            match edge.get_mut(\"node\").map(Value::take) {\n");
+            out.push_str("                Some(Value::Null) | None => {}\n");
+            out.push_str(
+                "                Some(node) => items.push(serde_json::from_value(node)?),\n",
+            );
+            out.push_str("            }\n");
+            out.push_str("        }\n");
+            out.push_str("        Ok(items)\n");
+            out.push_str("    }\n\n");
+        }
+
         out.push_str(&format!(
             "    pub fn paginate(&self, filters: Option<{filters_struct}>, request_branch: Option<&str>) -> DynPaginator<'a, {model_type}, String, ({response_type}, i64)> {{\n",
             filters_struct = format_args!("{}Filters", model.name),
@@ -732,7 +1675,7 @@ fn render_model_client<'a>(model: &ModelInfo<'a>, ctx: &SchemaContext<'a>) -> St
             "                let response = client.execute::<{}>(query, Some(vars), branch.as_deref()).await?;\n",
             response_type
         ));
-        out.push_str("                let data = response.data.ok_or_else(|| Error::Config(\"missing data\".to_string()))?;\n");
+        out.push_str("                let data = response.into_data()?;\n");
         out.push_str("                Ok((data, current_offset))\n");
         out.push_str("            })\n");
         out.push_str("        });\n");
@@ -745,6 +1688,16 @@ fn render_model_client<'a>(model: &ModelInfo<'a>, ctx: &SchemaContext<'a>) -> St
         out.push_str(", i64)| -> Result<EdgePage<");
         out.push_str(&model.node_type);
         out.push_str(", String>> {\n");
+        let count_nullable = count_field_nullable(&return_type, ctx);
+        match count_nullable {
+            Some(false) => out.push_str(&format!(
+                "            let total_count = Some(data.{model_field}.count);\n"
+            )),
+            Some(true) => out.push_str(&format!(
+                "            let total_count = data.{model_field}.count;\n"
+            )),
+            None => out.push_str("            let total_count: Option<i64> = None;\n"),
+        }
         out.push_str("            let mut items = Vec::new();\n");
         out.push_str("            let mut next: Option<String> = None;\n");
         out.push_str(&format!(
@@ -774,10 +1727,26 @@ fn render_model_client<'a>(model: &ModelInfo<'a>, ctx: &SchemaContext<'a>) -> St
                 "                next = Some((current_offset + items.len() as i64).to_string());\n",
             );
             out.push_str("            }\n");
+            out.push_str("            let has_next_page = total_count.map(|total| current_offset + (items.len() as i64) < total);\n");
+            out.push_str("            let end_cursor = None;\n");
+        } else {
+            out.push_str("            let has_next_page = None;\n");
+            out.push_str("            let end_cursor = next.clone();\n");
         }
-        out.push_str("            Ok(EdgePage { nodes: items, next_cursor: next })\n");
+        out.push_str(
+            "            let info = EdgePageInfo { has_next_page, end_cursor, total_count };\n",
+        );
+        out.push_str("            Ok(EdgePage::new(items, next).with_info(info))\n");
         out.push_str("        });\n");
-        out.push_str("        infrahub::Paginator::new(fetch, extract)\n");
+        match count_nullable {
+            Some(false) => out.push_str(&format!(
+                "        infrahub::Paginator::new(fetch, extract).with_total_count(|(data, _)| Some(data.{model_field}.count))\n"
+            )),
+            Some(true) => out.push_str(&format!(
+                "        infrahub::Paginator::new(fetch, extract).with_total_count(|(data, _)| data.{model_field}.count)\n"
+            )),
+            None => out.push_str("        infrahub::Paginator::new(fetch, extract)\n"),
+        }
         out.push_str("    }\n\n");
 
         if query_field.arguments.iter().any(|arg| arg.name == "ids") {
@@ -795,6 +1764,8 @@ fn render_model_client<'a>(model: &ModelInfo<'a>, ctx: &SchemaContext<'a>) -> St
             );
             out.push_str("        Ok(items.pop())\n");
             out.push_str("    }\n\n");
+
+            out.push_str(&render_relationship_pages(model, query_field, ctx));
         }
     }
 
@@ -803,6 +1774,215 @@ fn render_model_client<'a>(model: &ModelInfo<'a>, ctx: &SchemaContext<'a>) -> St
     out
 }
 
+/// `<relationship>_page` methods for the paginated relationships of a model,
+/// fetching one page of related nodes of a single node by id
+fn render_relationship_pages<'a>(
+    model: &ModelInfo<'a>,
+    query_field: &Field<'a, String>,
+    ctx: &SchemaContext<'a>,
+) -> String {
+    let mut out = String::new();
+    let Some(TypeDefinition::Object(node)) = node_object_for_model(&model.name, ctx) else {
+        return out;
+    };
+    let ids_type = query_field
+        .arguments
+        .iter()
+        .find(|arg| arg.name == "ids")
+        .map(|arg| format_gql_type(&arg.value_type))
+        .unwrap_or_else(|| "[ID]".to_string());
+
+    for field in &node.fields {
+        if should_skip_field(field) {
+            continue;
+        }
+        if !is_relationship_connection(field, ctx) {
+            continue;
+        }
+        let Some((related, related_base)) =
+            connection_node(&base_type_name(&field.field_type), ctx)
+        else {
+            continue;
+        };
+        let max_depth = ctx.selection.max_depth(&related_base);
+        let selection =
+            match selection_for_type(&related_base, ctx, &mut BTreeSet::new(), 2, max_depth) {
+                sel if sel.is_empty() => "{ id __typename }".to_string(),
+                sel => sel,
+            };
+        let method = format!("{}_page", to_snake(&field.name));
+        let op_name = format!("{}{}", model.name, to_rust_ident(&field.name));
+
+        out.push_str(&format!(
+            "    pub async fn {method}(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<{related}, String>> {{\n"
+        ));
+        out.push_str("        let id = id.into();\n");
+        out.push_str("        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);\n");
+        out.push_str(&format!(
+            "        let query = r#\"query {op_name}($ids: {ids_type}, $offset: Int, $limit: Int) {{ {model}(ids: $ids) {{ edges {{ node {{ {rel}(offset: $offset, limit: $limit) {{ count edges {{ node {selection} }} }} }} }} }} }}\"#;\n",
+            model = query_field.name,
+            rel = field.name,
+        ));
+        out.push_str("        let vars = serde_json::json!({ \"ids\": [id], \"offset\": offset, \"limit\": limit.max(1) });\n");
+        out.push_str("        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;\n");
+        out.push_str("        let data = response.into_data()?;\n");
+        out.push_str(&format!(
+            "        let connection = &data[\"{model}\"][\"edges\"][0][\"node\"][\"{rel}\"];\n",
+            model = query_field.name,
+            rel = field.name,
+        ));
+        out.push_str("        if connection.is_null() {\n");
+        out.push_str(&format!(
+            "            return Err(Error::Config(format!(\"{} {{id}} not found\")));\n",
+            model.name
+        ));
+        out.push_str("        }\n");
+        out.push_str("        let count = connection[\"count\"].as_i64().unwrap_or(0);\n");
+        out.push_str("        let mut nodes = Vec::new();\n");
+        out.push_str(
+            "        for edge in connection[\"edges\"].as_array().into_iter().flatten() {\n",
+        );
+        out.push_str("            if !edge[\"node\"].is_null() {\n");
+        out.push_str(
+            "                nodes.push(serde_json::from_value(edge[\"node\"].clone())?);\n",
+        );
+        out.push_str("            }\n");
+        out.push_str("        }\n");
+        out.push_str("        let next = offset + nodes.len() as i64;\n");
+        out.push_str("        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());\n");
+        out.push_str("        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };\n");
+        out.push_str("        Ok(EdgePage::new(nodes, next_cursor).with_info(info))\n");
+        out.push_str("    }\n\n");
+    }
+    out
+}
+
+/// object definition of the nodes listed for `model`
+fn node_object_for_model<'s, 'a>(
+    model: &str,
+    ctx: &'s SchemaContext<'a>,
+) -> Option<&'s TypeDefinition<'a, String>> {
+    let Some(TypeDefinition::Object(edge)) = ctx.types.get(&format!("Edged{model}")) else {
+        return None;
+    };
+    let node = edge.fields.iter().find(|f| f.name == "node")?;
+    ctx.types.get(&base_type_name(&node.field_type))
+}
+
+/// peer kind of a cardinality-one relationship (`NestedEdged<Kind>`)
+fn single_relationship_peer(type_name: &str, ctx: &SchemaContext) -> Option<String> {
+    if !type_name.starts_with("NestedEdged") {
+        return None;
+    }
+    let Some(TypeDefinition::Object(edge)) = ctx.types.get(type_name) else {
+        return None;
+    };
+    let node = edge.fields.iter().find(|f| f.name == "node")?;
+    Some(base_type_name(&node.field_type))
+}
+
+/// stub selection of a related node: `id`, `display_label`, and whatever
+/// else the generated type requires
+fn stub_selection(type_name: &str, ctx: &SchemaContext, stack: &mut BTreeSet<String>) -> String {
+    let fields = match ctx.types.get(type_name) {
+        Some(TypeDefinition::Object(obj)) => &obj.fields,
+        Some(TypeDefinition::Interface(iface)) => &iface.fields,
+        _ => return "{ __typename }".to_string(),
+    };
+    if !stack.insert(type_name.to_string()) {
+        return "{ __typename }".to_string();
+    }
+
+    let mut selected = Vec::new();
+    for field in fields {
+        let wanted = matches!(field.name.as_str(), "id" | "display_label")
+            || !is_optional(&field.field_type);
+        if !wanted || has_required_args(field) || should_skip_field(field) {
+            continue;
+        }
+        let field_base = base_type_name(&field.field_type);
+        if is_scalar_type(&field_base)
+            || ctx.enums.contains(&field_base)
+            || ctx.scalars.contains(&field_base)
+        {
+            selected.push(field.name.clone());
+        } else if connection_node(&field_base, ctx).is_some() {
+            selected.push(format!(
+                "{} {}",
+                field.name,
+                count_selection(&field_base, ctx)
+            ));
+        } else if single_relationship_peer(&field_base, ctx).is_some()
+            || ctx.unions.contains(&field_base)
+        {
+            selected.push(format!("{} {{ __typename }}", field.name));
+        } else if ctx.objects.contains(&field_base) {
+            selected.push(format!(
+                "{} {}",
+                field.name,
+                stub_selection(&field_base, ctx, stack)
+            ));
+        }
+    }
+
+    stack.remove(type_name);
+    if selected.is_empty() {
+        selected.push("__typename".to_string());
+    }
+    format!("{{ {} }}", selected.join(" "))
+}
+
+/// whether `field` is a paginated relationship of a node
+fn is_relationship_connection(field: &Field<String>, ctx: &SchemaContext) -> bool {
+    let takes = |name: &str| field.arguments.iter().any(|arg| arg.name == name);
+    takes("offset")
+        && takes("limit")
+        && connection_node(&base_type_name(&field.field_type), ctx).is_some()
+}
+
+/// selection of a connection without its edges: `count` plus whatever else
+/// the generated type requires
+fn count_selection(connection: &str, ctx: &SchemaContext) -> String {
+    let mut fields = vec!["count".to_string()];
+    if let Some(TypeDefinition::Object(obj)) = ctx.types.get(connection) {
+        for field in &obj.fields {
+            if matches!(field.name.as_str(), "count" | "edges")
+                || should_skip_field(field)
+                || is_optional(&field.field_type)
+            {
+                continue;
+            }
+            let field_base = base_type_name(&field.field_type);
+            if is_scalar_type(&field_base) || ctx.enums.contains(&field_base) {
+                fields.push(field.name.clone());
+            } else if connection_node(&field_base, ctx).is_some() {
+                fields.push(format!(
+                    "{} {}",
+                    field.name,
+                    count_selection(&field_base, ctx)
+                ));
+            }
+        }
+    }
+    format!("{{ {} }}", fields.join(" "))
+}
+
+/// rust type and graphql type of the nodes in a `count`/`edges` connection
+fn connection_node(connection: &str, ctx: &SchemaContext) -> Option<(String, String)> {
+    let Some(TypeDefinition::Object(obj)) = ctx.types.get(connection) else {
+        return None;
+    };
+    obj.fields.iter().find(|f| f.name == "count")?;
+    let edges = obj.fields.iter().find(|f| f.name == "edges")?;
+    let Some(TypeDefinition::Object(edge)) = ctx.types.get(&base_type_name(&edges.field_type))
+    else {
+        return None;
+    };
+    let node = edge.fields.iter().find(|f| f.name == "node")?;
+    let (rust, _) = strip_option_box(&rust_type(&node.field_type, ctx, false));
+    Some((rust, base_type_name(&node.field_type)))
+}
+
 fn render_mutation_helpers<'a>(model: &ModelInfo<'a>, ctx: &SchemaContext<'a>) -> String {
     let mut out = String::new();
     let mutations = [
@@ -818,7 +1998,13 @@ fn render_mutation_helpers<'a>(model: &ModelInfo<'a>, ctx: &SchemaContext<'a>) -
         let vars_def = render_variable_defs(&field.arguments);
         let field_args = render_field_args(&field.arguments);
         let return_type = base_type_name(&field.field_type);
-        let selection = selection_for_type(&return_type, ctx, &mut BTreeSet::new(), 0);
+        let selection = selection_for_type(
+            &return_type,
+            ctx,
+            &mut BTreeSet::new(),
+            0,
+            ctx.selection.max_depth(&model.name),
+        );
         let (object_type, object_boxed) = object_type_for_return(&return_type, ctx);
         let response_type = format!("{}Response", to_rust_ident(&field_name));
         let response_field = to_rust_field(&field_name);
@@ -861,7 +2047,7 @@ fn render_mutation_helpers<'a>(model: &ModelInfo<'a>, ctx: &SchemaContext<'a>) -
             "        let response = self.client.execute::<{resp}>(query, Some(vars), request_branch).await?;\n",
             resp = response_type
         ));
-        out.push_str("        let data = response.data.ok_or_else(|| Error::Config(\"missing data\".to_string()))?;\n");
+        out.push_str("        let data = response.into_data()?;\n");
         out.push_str(&format!(
             "        let payload = data.{field}.ok_or_else(|| Error::Config(\"missing payload\".to_string()))?;\n",
             field = response_field
@@ -882,6 +2068,27 @@ fn render_mutation_helpers<'a>(model: &ModelInfo<'a>, ctx: &SchemaContext<'a>) -
     out
 }
 
+/// whether object type `type_name` has a field called `field`
+fn has_field(type_name: &str, field: &str, ctx: &SchemaContext) -> bool {
+    matches!(
+        ctx.types.get(type_name),
+        Some(TypeDefinition::Object(obj)) if obj.fields.iter().any(|f| f.name == field)
+    )
+}
+
+/// whether the `count` field of a paginated type is nullable, or `None` if it
+/// has no integer `count` field
+fn count_field_nullable(type_name: &str, ctx: &SchemaContext) -> Option<bool> {
+    let Some(TypeDefinition::Object(obj)) = ctx.types.get(type_name) else {
+        return None;
+    };
+    let field = obj.fields.iter().find(|field| field.name == "count")?;
+    if should_skip_field(field) || base_type_name(&field.field_type) != "Int" {
+        return None;
+    }
+    Some(is_optional(&field.field_type))
+}
+
 fn collect_models<'a>(ctx: &SchemaContext<'a>) -> BTreeMap<String, ModelInfo<'a>> {
     let mut models: BTreeMap<String, ModelInfo<'a>> = BTreeMap::new();
 
@@ -930,6 +2137,11 @@ fn collect_models<'a>(ctx: &SchemaContext<'a>) -> BTreeMap<String, ModelInfo<'a>
                 } else {
                     continue;
                 };
+                // only crud payloads fit the generated helpers, e.g. not `BranchUpdate`
+                let payload_field = if slot == "delete" { "ok" } else { "object" };
+                if !has_field(&base_type_name(&field.field_type), payload_field, ctx) {
+                    continue;
+                }
                 let namespace = namespace_from_type(&model);
                 let (node_type, node_boxed) = node_type_for_model(&model, ctx);
                 let entry = models.entry(model.clone()).or_insert(ModelInfo {
@@ -1166,7 +2378,7 @@ fn selection_for_field(field: &Field<String>, ctx: &SchemaContext) -> String {
     }
 
     let mut stack = BTreeSet::new();
-    let selection = selection_for_type(&base, ctx, &mut stack, 0);
+    let selection = selection_for_type(&base, ctx, &mut stack, 0, ctx.selection.max_depth(&base));
     if selection.is_empty() {
         String::new()
     } else {
@@ -1179,8 +2391,9 @@ fn selection_for_type(
     ctx: &SchemaContext,
     stack: &mut BTreeSet<String>,
     depth: usize,
+    max_depth: usize,
 ) -> String {
-    if depth > 3 {
+    if depth > max_depth {
         return "{ __typename }".to_string();
     }
 
@@ -1210,8 +2423,30 @@ fn selection_for_type(
                 continue;
             }
 
+            // relationship nodes are fetched with `<relationship>_page`; the
+            // count alone tells whether the nested list is complete
+            if is_relationship_connection(field, ctx) {
+                fields.push(format!(
+                    "{} {}",
+                    field.name,
+                    count_selection(&field_base, ctx)
+                ));
+                continue;
+            }
+
+            // related nodes are stubbed so a kind selects the same way
+            // wherever it appears, and back-references cannot recurse
+            if let Some(peer) = single_relationship_peer(&field_base, ctx) {
+                fields.push(format!(
+                    "{} {{ node {} }}",
+                    field.name,
+                    stub_selection(&peer, ctx, &mut BTreeSet::new())
+                ));
+                continue;
+            }
+
             if ctx.objects.contains(&field_base) {
-                let nested = selection_for_type(&field_base, ctx, stack, depth + 1);
+                let nested = selection_for_type(&field_base, ctx, stack, depth + 1, max_depth);
                 fields.push(format!("{} {}", field.name, nested));
                 continue;
             }
@@ -1291,6 +2526,7 @@ fn rust_type_nonnull(ty: &Type<String>, ctx: &SchemaContext, input: bool, in_lis
         Type::ListType(inner) => format!("Vec<{}>", rust_type_nonnull(inner, ctx, input, true)),
         Type::NonNullType(inner) => rust_type_nonnull(inner, ctx, input, in_list),
         Type::NamedType(name) => match name.as_str() {
+            "DateTime" if ctx.chrono => "crate::types::DateTime".to_string(),
             "String" | "ID" | "DateTime" => "String".to_string(),
             "Int" => "i64".to_string(),
             "Float" => "f64".to_string(),
@@ -1538,7 +2774,7 @@ mod codegen_name_tests {
         let doc = parse_schema::<String>(schema).unwrap();
         let ctx = SchemaContext::new(&doc);
         let mut stack = BTreeSet::new();
-        let sel = selection_for_type("Info", &ctx, &mut stack, 0);
+        let sel = selection_for_type("Info", &ctx, &mut stack, 0, 3);
         assert!(sel.contains("id"));
         assert!(sel.contains("name"));
         assert!(!sel.contains("_updated_at"));
@@ -1681,6 +2917,667 @@ mod codegen_name_tests {
         );
     }
 
+    #[test]
+    fn test_smoke_example_lists_each_model() {
+        let schema = r#"
+            type Query {
+                BuiltinTag(limit: Int, offset: Int): PaginatedBuiltinTag
+                CoreRepository: PaginatedCoreRepository
+            }
+            type Mutation { CoreStandardGroupCreate(name: String): Ok }
+            type PaginatedBuiltinTag { count: Int edges: [EdgedBuiltinTag] }
+            type EdgedBuiltinTag { node: BuiltinTag }
+            type BuiltinTag { id: String }
+            type PaginatedCoreRepository { count: Int edges: [EdgedCoreRepository] }
+            type EdgedCoreRepository { node: CoreRepository }
+            type CoreRepository { id: String }
+            type Ok { ok: Boolean }
+            schema { query: Query mutation: Mutation }
+        "#;
+        let doc = parse_schema::<String>(schema).unwrap();
+        let ctx = SchemaContext::new(&doc);
+        let smoke = render_smoke_example(&ctx, "my_client");
+        assert!(smoke.contains("use my_client::api::Api;"));
+        assert!(smoke.contains("let api = match Api::from_env() {"));
+        assert!(smoke.contains(
+            "api.builtin().tag().list(Some(my_client::api::builtin::BuiltinTagFilters { limit: Some(1), ..Default::default() }), branch)"
+        ));
+        assert!(smoke.contains("api.core().repository().list(None, branch)"));
+        assert!(
+            !smoke.contains("StandardGroup"),
+            "mutation-only models have nothing to list, got:\n{smoke}"
+        );
+    }
+
+    #[test]
+    fn test_api_from_env() {
+        let schema = r#"
+            type Query { BuiltinTag: PaginatedBuiltinTag }
+            type PaginatedBuiltinTag { count: Int edges: [EdgedBuiltinTag] }
+            type EdgedBuiltinTag { node: BuiltinTag }
+            type BuiltinTag { id: String }
+            schema { query: Query }
+        "#;
+        let doc = parse_schema::<String>(schema).unwrap();
+        let ctx = SchemaContext::new(&doc);
+        let api_mod = render_api_mod(&ctx);
+        assert!(api_mod.contains("impl Api<'static> {"));
+        assert!(api_mod.contains("pub fn from_env() -> ::infrahub::Result<Self> {"));
+        assert!(api_mod.contains("Box::leak(Box::new(Client::from_env()?))"));
+    }
+
+    #[test]
+    fn test_paginate_reports_total_count() {
+        let schema = r#"
+            type Query {
+                BuiltinTag(limit: Int, offset: Int): PaginatedBuiltinTag
+                CoreRepository(limit: Int, offset: Int): PaginatedCoreRepository
+            }
+            type PaginatedBuiltinTag { count: Int! edges: [EdgedBuiltinTag] }
+            type EdgedBuiltinTag { node: BuiltinTag }
+            type BuiltinTag { id: String }
+            type PaginatedCoreRepository { edges: [EdgedCoreRepository] }
+            type EdgedCoreRepository { node: CoreRepository }
+            type CoreRepository { id: String }
+        "#;
+        let doc = parse_schema::<String>(schema).unwrap();
+        let ctx = SchemaContext::new(&doc);
+        let modules = render_api_modules(&ctx);
+        assert!(modules["builtin"]
+            .contains(".with_total_count(|(data, _)| Some(data.builtin_tag.count))"));
+        assert!(!modules["core"].contains("with_total_count"));
+        assert!(modules["builtin"].contains("let total_count = Some(data.builtin_tag.count);"));
+        assert!(modules["core"].contains("let total_count: Option<i64> = None;"));
+        assert!(modules["builtin"].contains("Ok(EdgePage::new(items, next).with_info(info))"));
+        assert!(modules["builtin"].contains("let data = response.into_data()?;"));
+        assert!(!modules["builtin"].contains("missing data"));
+    }
+
+    #[test]
+    fn test_read_only_omits_mutations() {
+        let schema = r#"
+            type Query { BuiltinTag(limit: Int): PaginatedBuiltinTag }
+            type Mutation {
+                BuiltinTagCreate(data: BuiltinTagCreateInput!): BuiltinTagCreate
+                BuiltinTagDelete(id: String!): DeleteResult
+            }
+            type PaginatedBuiltinTag { count: Int! edges: [EdgedBuiltinTag] }
+            type EdgedBuiltinTag { node: BuiltinTag }
+            type BuiltinTag { id: String }
+            type BuiltinTagCreate { ok: Boolean object: BuiltinTag }
+            type DeleteResult { ok: Boolean }
+            input BuiltinTagCreateInput { name: String }
+        "#;
+        let implicit = parse_schema::<String>(schema).unwrap();
+        let types_rs = render_types(&SchemaContext::new(&implicit).read_only());
+        assert!(!types_rs.contains("pub struct Mutation"));
+        assert!(!types_rs.contains("pub struct DeleteResult"));
+
+        let schema = format!("{schema} schema {{ query: Query mutation: Mutation }}");
+        let doc = parse_schema::<String>(&schema).unwrap();
+
+        let full = SchemaContext::new(&doc);
+        assert!(render_client(&full).contains("builtin_tag_create"));
+        assert!(render_api_modules(&full)["builtin"].contains("pub async fn create"));
+
+        let ctx = SchemaContext::new(&doc).read_only();
+        let client_rs = render_client(&ctx);
+        let responses_rs = render_responses(&ctx);
+        let types_rs = render_types(&ctx);
+        let builtin_rs = &render_api_modules(&ctx)["builtin"];
+        assert!(!client_rs.contains("mutation"), "got:\n{client_rs}");
+        assert!(!responses_rs.contains("BuiltinTagCreateResponse"));
+        assert!(!types_rs.contains("pub struct BuiltinTagCreate "));
+        assert!(!types_rs.contains("pub struct DeleteResult"));
+        assert!(!types_rs.contains("pub struct Mutation"));
+        assert!(types_rs.contains("pub struct BuiltinTag "));
+        assert!(!builtin_rs.contains("pub async fn create"));
+        assert!(builtin_rs.contains("pub async fn list"));
+    }
+
+    #[test]
+    fn test_mutations_only_keeps_write_path_types() {
+        let schema = r#"
+            type Query { BuiltinTag(limit: Int): PaginatedBuiltinTag }
+            type Mutation {
+                BuiltinTagCreate(data: BuiltinTagCreateInput!): BuiltinTagCreate
+                BranchUpdate(name: String): BranchUpdate
+            }
+            type PaginatedBuiltinTag { count: Int! edges: [EdgedBuiltinTag] }
+            type EdgedBuiltinTag { node: BuiltinTag }
+            type BranchUpdate { ok: Boolean }
+            type BuiltinTag { id: String color: Color }
+            type BuiltinTagCreate { ok: Boolean object: BuiltinTag }
+            input BuiltinTagCreateInput { name: String meta: MetaInput }
+            input MetaInput { source: String }
+            input OrderInput { desc: Boolean }
+            enum Color { RED }
+            enum Unused { A }
+        "#;
+        let doc = parse_schema::<String>(schema).unwrap();
+        let ctx = SchemaContext::new(&doc).mutations_only().unwrap();
+
+        let types_rs = render_types(&ctx);
+        let inputs_rs = render_inputs(&ctx);
+        let responses_rs = render_responses(&ctx);
+        let client_rs = render_client(&ctx);
+        let builtin_rs = &render_api_modules(&ctx)["builtin"];
+        assert!(types_rs.contains("pub struct BuiltinTagCreate "));
+        assert!(types_rs.contains("pub struct BuiltinTag "));
+        assert!(types_rs.contains("pub enum Color"));
+        assert!(!types_rs.contains("PaginatedBuiltinTag"));
+        assert!(!types_rs.contains("pub enum Unused"));
+        assert!(!types_rs.contains("pub struct Query"));
+        assert!(inputs_rs.contains("pub struct MetaInput"));
+        assert!(!inputs_rs.contains("OrderInput"));
+        assert!(responses_rs.contains("BuiltinTagCreateResponse"));
+        assert!(!responses_rs.contains("pub struct BuiltinTagResponse"));
+        assert!(!client_rs.contains("query BuiltinTag"));
+        assert!(builtin_rs.contains("pub async fn create"));
+        assert!(!builtin_rs.contains("pub async fn list"));
+        assert!(
+            !render_api_modules(&ctx).contains_key("branch"),
+            "payloads without `object` get no crud helper"
+        );
+        assert!(client_rs.contains("pub async fn branch_update"));
+
+        let doc = parse_schema::<String>("type Query { a: String }").unwrap();
+        assert!(SchemaContext::new(&doc).mutations_only().is_err());
+    }
+
+    #[test]
+    fn test_kinds_constants() {
+        let schema = r#"
+            type Query {
+                InfraDevice(limit: Int): PaginatedInfraDevice
+                BuiltinIPAddress(limit: Int): PaginatedBuiltinIPAddress
+            }
+            type PaginatedInfraDevice { count: Int! edges: [EdgedInfraDevice] }
+            type EdgedInfraDevice { node: InfraDevice }
+            type InfraDevice { id: String }
+            type PaginatedBuiltinIPAddress { count: Int! edges: [EdgedBuiltinIPAddress] }
+            type EdgedBuiltinIPAddress { node: BuiltinIPAddress }
+            type BuiltinIPAddress { id: String }
+        "#;
+        let doc = parse_schema::<String>(schema).unwrap();
+        let kinds_rs = render_kinds(&SchemaContext::new(&doc));
+        assert!(kinds_rs.contains("pub const INFRA_DEVICE: &str = \"InfraDevice\";"));
+        assert!(kinds_rs.contains("pub const BUILTIN_IP_ADDRESS: &str = \"BuiltinIPAddress\";"));
+        assert!(render_lib().contains("pub mod kinds;"));
+        assert!(kinds_rs.contains("    InfraDevice,\n"));
+        assert!(kinds_rs.contains("Kind::BuiltinIPAddress => \"BuiltinIPAddress\","));
+        assert!(kinds_rs.contains("\"InfraDevice\" => Ok(Kind::InfraDevice),"));
+        assert!(kinds_rs.contains("impl AsRef<str> for Kind"));
+    }
+
+    #[test]
+    fn test_relationship_page_methods() {
+        let schema = r#"
+            type Query {
+                InfraDevice(ids: [ID], offset: Int, limit: Int): PaginatedInfraDevice
+            }
+            type PaginatedInfraDevice { count: Int! edges: [EdgedInfraDevice!]! }
+            type EdgedInfraDevice { node: InfraDevice }
+            type InfraDevice {
+                id: String!
+                interfaces(offset: Int, limit: Int): NestedPaginatedInfraInterface!
+                site: NestedEdgedInfraInterface!
+            }
+            type NestedPaginatedInfraInterface { count: Int! edges: [NestedEdgedInfraInterface!]! }
+            type NestedEdgedInfraInterface { node: InfraInterface }
+            type InfraInterface { id: String! name: String }
+        "#;
+        let doc = parse_schema::<String>(schema).unwrap();
+        let ctx = SchemaContext::new(&doc);
+        let model = collect_models(&ctx).remove("InfraDevice").unwrap();
+        let out = render_model_client(&model, &ctx);
+        assert!(out.contains("pub async fn interfaces_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<InfraInterface, String>>"));
+        assert!(out.contains("query InfraDeviceInterfaces($ids: [ID], $offset: Int, $limit: Int) { InfraDevice(ids: $ids) { edges { node { interfaces(offset: $offset, limit: $limit) { count edges { node { id name } } } } } } }"));
+        assert!(out.contains("total_count: Some(count) };"));
+        assert!(!out.contains("site_page"));
+        assert!(out.contains("node { id interfaces { count } site {"));
+
+        let types = render_types(&ctx);
+        assert!(types.contains(
+            "pub struct NestedPaginatedInfraInterface {\n    pub count: i64,\n    #[serde(default)]\n    pub edges:"
+        ));
+    }
+
+    #[test]
+    fn test_capture_unknown_fields() {
+        let schema = r#"
+            type Query { InfraDevice: PaginatedInfraDevice }
+            type PaginatedInfraDevice { count: Int! edges: [EdgedInfraDevice!]! }
+            type EdgedInfraDevice { node: InfraDevice }
+            type InfraDevice { id: String! extra: String name: TextAttribute }
+            type TextAttribute { value: String }
+        "#;
+        let doc = parse_schema::<String>(schema).unwrap();
+        let mut ctx = SchemaContext::new(&doc);
+        assert!(!render_types(&ctx).contains("serde(flatten)"));
+
+        let config: CodegenConfig =
+            toml::from_str("[types]\ncapture_unknown_fields = true").unwrap();
+        ctx.capture_unknown_fields = config.types.capture_unknown_fields;
+        let types = render_types(&ctx);
+        assert!(types.contains(
+            "    pub extra: Option<String>,\n    pub name: Option<Box<TextAttribute>>,\n    /// fields returned by the server that this schema does not define\n    #[serde(flatten)]\n    pub extra_fields: serde_json::Map<String, serde_json::Value>,\n}"
+        ));
+        assert_eq!(types.matches("serde(flatten)").count(), 1);
+    }
+
+    #[test]
+    fn test_typed_scalars() {
+        let schema = r#"
+            scalar DateTime
+            type Query { IPHost: IPHost }
+            type IPHost { value: String ip: String netmask: String updated_at: DateTime }
+            type IPNetwork { value: String! }
+        "#;
+        let doc = parse_schema::<String>(schema).unwrap();
+        let mut ctx = SchemaContext::new(&doc);
+        let plain = render_types(&ctx);
+        assert!(!plain.contains("pub type"));
+        assert!(plain.contains("    pub value: Option<String>,\n    pub ip: Option<String>,"));
+
+        ctx.chrono = true;
+        ctx.ipnet = true;
+        let types = render_types(&ctx);
+        for (feature, alias, typed) in [
+            ("chrono", "DateTime", "chrono::DateTime<chrono::Utc>"),
+            ("ipnet", "IpNet", "ipnet::IpNet"),
+            ("ipnet", "IpAddr", "std::net::IpAddr"),
+        ] {
+            assert!(types.contains(&format!(
+                "#[cfg(feature = \"{feature}\")]\npub type {alias} = {typed};\n"
+            )));
+            assert!(types.contains(&format!(
+                "#[cfg(not(feature = \"{feature}\"))]\npub type {alias} = String;\n"
+            )));
+        }
+        assert!(types.contains(
+            "pub struct IPHost {\n    pub value: Option<crate::types::IpNet>,\n    pub ip: Option<crate::types::IpAddr>,\n    pub netmask: Option<String>,\n    pub updated_at: Option<crate::types::DateTime>,\n}"
+        ));
+        assert!(types.contains("pub struct IPNetwork {\n    pub value: crate::types::IpNet,\n}"));
+    }
+
+    #[test]
+    fn test_list_with_selection_and_registry() {
+        let schema = r#"
+            type Query { InfraDevice(limit: Int): PaginatedInfraDevice }
+            type PaginatedInfraDevice { count: Int! edges: [EdgedInfraDevice!]! }
+            type EdgedInfraDevice { node: InfraDevice }
+            type InfraDevice { id: String! name: TextAttribute old: String @deprecated }
+            type TextAttribute { value: String }
+        "#;
+        let doc = parse_schema::<String>(schema).unwrap();
+        let ctx = SchemaContext::new(&doc);
+        let model = collect_models(&ctx).remove("InfraDevice").unwrap();
+        let out = render_model_client(&model, &ctx);
+        assert!(out.contains("pub async fn list_with_selection<T: serde::de::DeserializeOwned>(&self, filters: Option<InfraDeviceFilters>, selection: &Selection, request_branch: Option<&str>) -> Result<Vec<T>>"));
+        assert!(out.contains("crate::schema::REGISTRY.validate(\"InfraDevice\", selection)?;"));
+        assert!(out.contains("{ InfraDevice(limit: $limit) { edges { node \"#, &selection.render()?, \" } } }\"].concat()"));
+
+        let registry = render_schema_registry(&ctx);
+        assert!(registry.contains(
+            "    (\"InfraDevice\", &[(\"id\", \"String\"), (\"name\", \"TextAttribute\")]),\n"
+        ));
+        assert!(registry.contains("    (\"TextAttribute\", &[(\"value\", \"String\")]),\n"));
+        assert!(!registry.contains("(\"Query\""));
+        assert!(render_lib().contains("pub mod schema;"));
+    }
+
+    #[test]
+    fn test_selection_depth_config() {
+        let config: CodegenConfig = toml::from_str(
+            r#"
+            [selection]
+            depth = 2
+            namespaces = { infra = 1 }
+            models = { InfraDevice = 5 }
+            "#,
+        )
+        .unwrap();
+        let depth = config.selection;
+        assert_eq!(depth.max_depth("InfraDevice"), 5);
+        assert_eq!(depth.max_depth("PaginatedInfraDevice"), 5);
+        assert_eq!(depth.max_depth("InfraInterface"), 1);
+        assert_eq!(depth.max_depth("CoreAccount"), 2);
+        assert_eq!(SelectionDepth::default().max_depth("CoreAccount"), 3);
+        assert!(toml::from_str::<CodegenConfig>("[selection]\nmax = 1").is_err());
+
+        let schema = r#"
+            type Query { a: A }
+            type A { id: String b: B }
+            type B { id: String c: C }
+            type C { id: String }
+        "#;
+        let doc = parse_schema::<String>(schema).unwrap();
+        let ctx = SchemaContext::new(&doc);
+        let deep = selection_for_type("A", &ctx, &mut BTreeSet::new(), 0, 3);
+        let shallow = selection_for_type("A", &ctx, &mut BTreeSet::new(), 0, 0);
+        assert_eq!(deep, "{ id b { id c { id } } }");
+        assert_eq!(shallow, "{ id b { __typename } }");
+    }
+
+    #[test]
+    fn test_single_relationships_are_stubbed() {
+        let schema = r#"
+            type Query { InfraDevice: PaginatedInfraDevice }
+            type PaginatedInfraDevice { count: Int! edges: [EdgedInfraDevice!]! }
+            type EdgedInfraDevice { node: InfraDevice }
+            type InfraDevice {
+                id: String!
+                display_label: String
+                name: String
+                site: NestedEdgedLocationSite!
+            }
+            type NestedEdgedLocationSite { node: LocationSite properties: Props }
+            type Props { is_protected: Boolean }
+            type LocationSite {
+                id: String!
+                display_label: String
+                name: String
+                shortname: String!
+                devices(offset: Int, limit: Int): NestedPaginatedInfraDevice!
+                primary: NestedEdgedInfraDevice!
+            }
+            type NestedPaginatedInfraDevice { count: Int! edges: [NestedEdgedInfraDevice!]! }
+            type NestedEdgedInfraDevice { node: InfraDevice }
+        "#;
+        let doc = parse_schema::<String>(schema).unwrap();
+        let ctx = SchemaContext::new(&doc);
+        let device = selection_for_type("InfraDevice", &ctx, &mut BTreeSet::new(), 0, 3);
+        assert_eq!(
+            device,
+            "{ id display_label name site { node { id display_label shortname devices { count } primary { __typename } } } }"
+        );
+        // the same kind selects identically as the root of a query
+        let site = selection_for_type("LocationSite", &ctx, &mut BTreeSet::new(), 0, 3);
+        assert!(site.contains("primary { node { id display_label site { __typename } } }"));
+    }
+
+    #[test]
+    fn test_smoke_example_requires_crate_name() {
+        let args = |extra: &[&str]| {
+            let mut args = vec!["infrahub-codegen", "--schema", "s.graphql", "--out", "out"];
+            args.extend_from_slice(extra);
+            parse_args(args.into_iter().map(String::from).collect())
+        };
+        assert!(matches!(
+            args(&["--smoke-example"]),
+            Err(ParseArgsError::Message(_))
+        ));
+        let parsed = args(&["--smoke-example", "--crate-name", "x"])
+            .ok()
+            .unwrap();
+        assert!(parsed.smoke_example);
+    }
+
+    #[test]
+    fn test_render_cargo_toml() {
+        let args = |extra: &[&str]| {
+            let mut args = vec!["infrahub-codegen", "--schema", "s.graphql", "--out", "out"];
+            args.extend_from_slice(extra);
+            parse_args(args.into_iter().map(String::from).collect())
+        };
+        let version = env!("CARGO_PKG_VERSION");
+
+        let parsed = args(&["--crate-name", "ops-client"]).ok().unwrap();
+        assert_eq!(
+            render_cargo_toml(&parsed, "ops-client"),
+            format!(
+                "[package]\nname = \"ops-client\"\nversion = \"{version}\"\nedition = \"2021\"\n\
+                 publish = false\n\n[dependencies]\ninfrahub = {{ version = \"{version}\" }}\n\
+                 serde = {{ version = \"1\", features = [\"derive\"] }}\nserde_json = \"1\"\n"
+            )
+        );
+
+        let parsed = args(&[
+            "--crate-name",
+            "ops-client",
+            "--infrahub-git",
+            "https://github.com/cyberwitchery/infrahub.rs",
+            "--infrahub-rev",
+            "v0.4.0",
+            "--license",
+            "MIT",
+            "--description",
+            "ops \"infra\" client",
+            "--publish",
+            "--feature",
+            "chrono",
+            "--feature",
+            "ipnet",
+            "--feature",
+            "yaml",
+        ])
+        .ok()
+        .unwrap();
+        let cargo = render_cargo_toml(&parsed, "ops-client");
+        let manifest: toml::Table = toml::from_str(&cargo).unwrap();
+        let package = &manifest["package"];
+        assert_eq!(package["license"].as_str(), Some("MIT"));
+        assert_eq!(
+            package["description"].as_str(),
+            Some("ops \"infra\" client")
+        );
+        assert!(package.get("publish").is_none());
+        let infrahub = &manifest["dependencies"]["infrahub"];
+        assert_eq!(infrahub["rev"].as_str(), Some("v0.4.0"));
+        assert!(infrahub.get("version").is_none());
+        for (name, enables) in [
+            ("yaml", "infrahub/yaml"),
+            ("chrono", "dep:chrono"),
+            ("ipnet", "dep:ipnet"),
+        ] {
+            assert_eq!(
+                manifest["features"][name].as_array().unwrap()[0].as_str(),
+                Some(enables)
+            );
+        }
+        for dependency in ["chrono", "ipnet"] {
+            let dependency = &manifest["dependencies"][dependency];
+            assert_eq!(dependency["optional"].as_bool(), Some(true));
+            assert_eq!(
+                dependency["features"].as_array().unwrap()[0].as_str(),
+                Some("serde")
+            );
+        }
+
+        let parsed = args(&[
+            "--crate-name",
+            "x",
+            "--infrahub-path",
+            "..",
+            "--infrahub-version",
+            "0.4",
+        ])
+        .ok()
+        .unwrap();
+        assert!(render_cargo_toml(&parsed, "x")
+            .contains("infrahub = { path = \"..\", version = \"0.4\" }"));
+
+        for invalid in [
+            &["--license", "MIT"][..],
+            &["--crate-name", "x", "--feature", "uuid"],
+            &["--crate-name", "x", "--infrahub-rev", "main"],
+            &[
+                "--crate-name",
+                "x",
+                "--infrahub-path",
+                "..",
+                "--infrahub-git",
+                "g",
+            ],
+        ] {
+            assert!(matches!(args(invalid), Err(ParseArgsError::Message(_))));
+        }
+    }
+
+    #[test]
+    fn test_add_to_workspace() {
+        let root = std::env::temp_dir().join(format!("infrahub-codegen-ws-{}", std::process::id()));
+        let crate_dir = root.join("crates").join("infrahub-ops");
+        fs::create_dir_all(&crate_dir).unwrap();
+        let manifest = root.join("Cargo.toml");
+
+        fs::write(
+            &manifest,
+            "[workspace]\nresolver = \"2\"\nmembers = [\n    \"app\", # main binary\n    \"lib\",\n]\n",
+        )
+        .unwrap();
+        assert!(add_to_workspace(&manifest, &crate_dir).unwrap());
+        assert_eq!(
+            fs::read_to_string(&manifest).unwrap(),
+            "[workspace]\nresolver = \"2\"\nmembers = [\n    \"app\", # main binary\n    \"lib\",\n    \"crates/infrahub-ops\",\n]\n"
+        );
+        // idempotent
+        assert!(!add_to_workspace(&manifest, &crate_dir).unwrap());
+
+        fs::write(&manifest, "[workspace]\nmembers = [\"crates/*\"]\n").unwrap();
+        assert!(!add_to_workspace(&manifest, &crate_dir).unwrap());
+
+        fs::write(&manifest, "[workspace]\n").unwrap();
+        assert!(add_to_workspace(&manifest, &crate_dir).unwrap());
+        assert_eq!(
+            fs::read_to_string(&manifest).unwrap(),
+            "[workspace]\nmembers = [\"crates/infrahub-ops\"]\n"
+        );
+
+        fs::write(&manifest, "[package]\nname = \"app\"\n").unwrap();
+        assert!(add_to_workspace(&manifest, &crate_dir)
+            .unwrap_err()
+            .contains("[workspace]"));
+        assert!(add_to_workspace(&manifest, &std::env::temp_dir())
+            .unwrap_err()
+            .contains("outside the workspace"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn test_schema_cache() {
+        use std::io::{BufRead, BufReader, Write};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let downloads = Arc::new(AtomicUsize::new(0));
+        let counter = downloads.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                reader.read_line(&mut request).unwrap();
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    line.clear();
+                }
+                let body = if request.contains("/api/schema/summary") {
+                    r#"{"main": "hash-1"}"#
+                } else {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    "type Query { ok: Boolean }"
+                };
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nconnection: close\r\ncontent-length: {}\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        let dir =
+            std::env::temp_dir().join(format!("infrahub-schema-cache-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let cache = dir.join("schema.graphql");
+        let args = parse_args(
+            [
+                "infrahub-codegen",
+                "--url",
+                &url,
+                "--schema-cache",
+                cache.to_str().unwrap(),
+                "--out",
+                "out",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        )
+        .ok()
+        .unwrap();
+
+        assert_eq!(load_schema(&args).unwrap(), "type Query { ok: Boolean }");
+        assert_eq!(
+            fs::read_to_string(schema_cache_hash_path(&cache)).unwrap(),
+            "hash-1"
+        );
+        assert_eq!(load_schema(&args).unwrap(), "type Query { ok: Boolean }");
+        assert_eq!(downloads.load(Ordering::SeqCst), 1);
+
+        // a stale hash downloads again
+        fs::write(schema_cache_hash_path(&cache), "hash-0").unwrap();
+        load_schema(&args).unwrap();
+        assert_eq!(downloads.load(Ordering::SeqCst), 2);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(
+            parse_args(
+                [
+                    "infrahub-codegen",
+                    "--schema",
+                    "s.graphql",
+                    "--schema-cache",
+                    "c",
+                    "--out",
+                    "o"
+                ]
+                .into_iter()
+                .map(String::from)
+                .collect()
+            ),
+            Err(ParseArgsError::Message(_))
+        ));
+    }
+
+    #[test]
+    fn test_proxy_args() {
+        let args = |extra: &[&str]| {
+            let mut args = vec!["infrahub-codegen", "--url", "http://a", "--out", "out"];
+            args.extend_from_slice(extra);
+            parse_args(args.into_iter().map(String::from).collect())
+        };
+        let parsed = args(&["--proxy", "socks5h://proxy.internal:1080"])
+            .ok()
+            .unwrap();
+        assert_eq!(
+            parsed.proxy.as_deref(),
+            Some("socks5h://proxy.internal:1080")
+        );
+        assert!(schema_http_client(&parsed).is_ok());
+        let parsed = args(&["--no-proxy"]).ok().unwrap();
+        assert!(parsed.no_proxy);
+        assert!(schema_http_client(&parsed).is_ok());
+        assert!(matches!(
+            args(&["--proxy", "http://p:3128", "--no-proxy"]),
+            Err(ParseArgsError::Message(_))
+        ));
+        let parsed = args(&["--identity", "/nonexistent/client.pem"])
+            .ok()
+            .unwrap();
+        assert!(schema_http_client(&parsed)
+            .unwrap_err()
+            .contains("/nonexistent/client.pem"));
+        let parsed = args(&["--ca-cert", "/nonexistent/ca.pem"]).ok().unwrap();
+        assert!(schema_http_client(&parsed)
+            .unwrap_err()
+            .contains("/nonexistent/ca.pem"));
+    }
+
     #[test]
     fn test_fixed_generic_scalar_maps_to_json_value() {
         let schema = r#"
@@ -0,0 +1,706 @@
+//! run ad-hoc graphql against infrahub
+//!
+//! this binary covers the "curl with auth and branch handling" use case:
+//! it loads the same config files and `INFRAHUB_*` variables as the client,
+//! executes a query from an argument or a file, and prints the result as
+//! json or a table. `branch` and `schema` subcommands drive branch and
+//! schema workflows through the typed client apis.
+//!
+//! command help reference (kept in sync with `infrahub --help`):
+#[doc = concat!("```text\n", include_str!("infrahub-help.txt"), "\n```")]
+pub const CLI_HELP: &str = include_str!("infrahub-help.txt");
+
+use infrahub::{Client, ClientConfig, Variables};
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use std::io::Read;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+struct Args {
+    connection: Connection,
+    command: Command,
+}
+
+/// where the connection settings come from
+#[derive(Debug, Default)]
+struct Connection {
+    config_path: Option<PathBuf>,
+    profile: Option<String>,
+    url: Option<String>,
+    token: Option<String>,
+}
+
+#[derive(Debug)]
+enum Command {
+    Query(QueryArgs),
+    Branch(BranchCommand),
+    Schema(SchemaCommand),
+}
+
+#[derive(Debug)]
+struct QueryArgs {
+    query: Option<String>,
+    file: Option<PathBuf>,
+    vars: Vec<(String, Value)>,
+    variables_path: Option<PathBuf>,
+    branch: Option<String>,
+    format: Format,
+}
+
+#[derive(Debug)]
+enum BranchCommand {
+    List { format: Format },
+    Create { name: String },
+    Delete { name: String },
+    Merge { name: String },
+}
+
+#[derive(Debug)]
+enum SchemaCommand {
+    Fetch {
+        branch: Option<String>,
+        out: Option<PathBuf>,
+    },
+    Load {
+        files: Vec<PathBuf>,
+        branch: Option<String>,
+    },
+    Check {
+        files: Vec<PathBuf>,
+        branch: Option<String>,
+    },
+}
+
+/// command options and positional words, before they are matched to a
+/// command
+#[derive(Debug, Default)]
+struct Options {
+    words: Vec<String>,
+    given: Vec<&'static str>,
+    file: Option<PathBuf>,
+    vars: Vec<(String, Value)>,
+    variables_path: Option<PathBuf>,
+    branch: Option<String>,
+    format: Format,
+    out: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    #[default]
+    Json,
+    Compact,
+    Table,
+}
+
+enum ParseArgsError {
+    Help,
+    Message(String),
+}
+
+fn main() {
+    let args = match parse_args(std::env::args().collect()) {
+        Ok(args) => args,
+        Err(ParseArgsError::Help) => {
+            print!("{CLI_HELP}");
+            return;
+        }
+        Err(ParseArgsError::Message(err)) => {
+            eprintln!("{err}\n\n{CLI_HELP}");
+            std::process::exit(1);
+        }
+    };
+
+    // the flags override the file the same way the variables do; set them
+    // before the runtime starts any threads
+    if let Some(url) = &args.connection.url {
+        std::env::set_var("INFRAHUB_URL", url);
+    }
+    if let Some(token) = &args.connection.token {
+        std::env::set_var("INFRAHUB_TOKEN", token);
+    }
+
+    let runtime = match tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            eprintln!("infrahub: cannot start runtime: {err}");
+            std::process::exit(1);
+        }
+    };
+    if let Err(err) = runtime.block_on(run(args)) {
+        eprintln!("infrahub: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn parse_args(args: Vec<String>) -> Result<Args, ParseArgsError> {
+    let mut connection = Connection::default();
+    let mut options = Options::default();
+
+    let mut iter = args.into_iter().skip(1);
+    while let Some(arg) = iter.next() {
+        let mut value = |name: &'static str| {
+            options.given.push(name);
+            iter.next()
+                .ok_or_else(|| ParseArgsError::Message(format!("{name} needs a value")))
+        };
+        match arg.as_str() {
+            "--config" => connection.config_path = Some(PathBuf::from(value("--config")?)),
+            "--profile" => connection.profile = Some(value("--profile")?),
+            "--url" => connection.url = Some(value("--url")?),
+            "--token" => connection.token = Some(value("--token")?),
+            "--help" | "-h" => return Err(ParseArgsError::Help),
+            "--file" => options.file = Some(PathBuf::from(value("--file")?)),
+            "--var" => {
+                let var = parse_var(&value("--var")?)?;
+                options.vars.push(var);
+            }
+            "--variables" => options.variables_path = Some(PathBuf::from(value("--variables")?)),
+            "--branch" => options.branch = Some(value("--branch")?),
+            "--format" => options.format = parse_format(&value("--format")?)?,
+            "--out" => options.out = Some(PathBuf::from(value("--out")?)),
+            _ if arg.starts_with('-') => {
+                return Err(ParseArgsError::Message(format!("unknown argument: {arg}")))
+            }
+            _ => options.words.push(arg),
+        }
+    }
+    let command = parse_command(options)?;
+    Ok(Args {
+        connection,
+        command,
+    })
+}
+
+/// match the positional words to a command and check that every option
+/// given applies to it
+fn parse_command(options: Options) -> Result<Command, ParseArgsError> {
+    let words: Vec<&str> = options.words.iter().map(String::as_str).collect();
+    let files = |files: &[&str]| files.iter().map(PathBuf::from).collect::<Vec<_>>();
+    let (command, allowed): (Command, &[&str]) = match words.as_slice() {
+        ["query", rest @ ..] => {
+            if let [_, extra, ..] = rest {
+                return Err(ParseArgsError::Message(format!(
+                    "unknown argument: {extra}"
+                )));
+            }
+            let query = rest.first().map(|query| query.to_string());
+            if query.is_some() == options.file.is_some() {
+                return Err(ParseArgsError::Message(
+                    "query needs either a query argument or --file".to_string(),
+                ));
+            }
+            let query = QueryArgs {
+                query,
+                file: options.file.clone(),
+                vars: options.vars.clone(),
+                variables_path: options.variables_path.clone(),
+                branch: options.branch.clone(),
+                format: options.format,
+            };
+            (
+                Command::Query(query),
+                &["--file", "--var", "--variables", "--branch", "--format"],
+            )
+        }
+        ["branch", "list"] => (
+            Command::Branch(BranchCommand::List {
+                format: options.format,
+            }),
+            &["--format"],
+        ),
+        ["branch", "create", name] => (
+            Command::Branch(BranchCommand::Create {
+                name: name.to_string(),
+            }),
+            &[],
+        ),
+        ["branch", "delete", name] => (
+            Command::Branch(BranchCommand::Delete {
+                name: name.to_string(),
+            }),
+            &[],
+        ),
+        ["branch", "merge", name] => (
+            Command::Branch(BranchCommand::Merge {
+                name: name.to_string(),
+            }),
+            &[],
+        ),
+        ["branch", ..] => {
+            return Err(ParseArgsError::Message(
+                "branch expects list, create <name>, delete <name>, or merge <name>".to_string(),
+            ))
+        }
+        ["schema", "fetch"] => (
+            Command::Schema(SchemaCommand::Fetch {
+                branch: options.branch.clone(),
+                out: options.out.clone(),
+            }),
+            &["--branch", "--out"],
+        ),
+        ["schema", "load", paths @ ..] if !paths.is_empty() => (
+            Command::Schema(SchemaCommand::Load {
+                files: files(paths),
+                branch: options.branch.clone(),
+            }),
+            &["--branch"],
+        ),
+        ["schema", "check", paths @ ..] if !paths.is_empty() => (
+            Command::Schema(SchemaCommand::Check {
+                files: files(paths),
+                branch: options.branch.clone(),
+            }),
+            &["--branch"],
+        ),
+        ["schema", ..] => {
+            return Err(ParseArgsError::Message(
+                "schema expects fetch, load <file>..., or check <file>...".to_string(),
+            ))
+        }
+        [] => return Err(ParseArgsError::Message("missing command".to_string())),
+        [other, ..] => return Err(ParseArgsError::Message(format!("unknown command: {other}"))),
+    };
+    if let Some(flag) = options.given.iter().find(|flag| {
+        !allowed.contains(flag) && !["--config", "--profile", "--url", "--token"].contains(flag)
+    }) {
+        let name = words.iter().take(2).copied().collect::<Vec<_>>().join(" ");
+        return Err(ParseArgsError::Message(format!(
+            "{flag} does not apply to {name}"
+        )));
+    }
+    Ok(command)
+}
+
+/// `name=value`, with `value` parsed as json when it is valid json
+fn parse_var(arg: &str) -> Result<(String, Value), ParseArgsError> {
+    let (name, value) = arg.split_once('=').ok_or_else(|| {
+        ParseArgsError::Message(format!("--var expects <name>=<value>, got {arg:?}"))
+    })?;
+    let value = serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string()));
+    Ok((name.to_string(), value))
+}
+
+fn parse_format(value: &str) -> Result<Format, ParseArgsError> {
+    match value {
+        "json" => Ok(Format::Json),
+        "compact" => Ok(Format::Compact),
+        "table" => Ok(Format::Table),
+        _ => Err(ParseArgsError::Message(format!(
+            "unknown format {value:?} (known: json, compact, table)"
+        ))),
+    }
+}
+
+async fn run(args: Args) -> infrahub::Result<()> {
+    let connection = &args.connection;
+    let config = match (&connection.config_path, &connection.profile) {
+        (Some(path), Some(profile)) => ClientConfig::from_file_profile(path, profile)?,
+        (Some(path), None) => ClientConfig::from_file(path)?,
+        (None, Some(profile)) => ClientConfig::from_profile(profile)?,
+        (None, None) => ClientConfig::from_env()?,
+    };
+    let client = Client::new(config)?;
+    match args.command {
+        Command::Query(query) => run_query(&client, query).await,
+        Command::Branch(command) => run_branch(&client, command).await,
+        Command::Schema(command) => run_schema(&client, command).await,
+    }
+}
+
+async fn run_query(client: &Client, args: QueryArgs) -> infrahub::Result<()> {
+    let query = match (&args.query, &args.file) {
+        (Some(query), _) => query.clone(),
+        (None, Some(path)) if path.as_os_str() == "-" => {
+            let mut query = String::new();
+            std::io::stdin().read_to_string(&mut query)?;
+            query
+        }
+        (None, Some(path)) => std::fs::read_to_string(path).map_err(|err| {
+            infrahub::Error::Config(format!("cannot read {}: {err}", path.display()))
+        })?,
+        (None, None) => unreachable!("checked by parse_args"),
+    };
+    let mut variables = match &args.variables_path {
+        Some(path) => Variables::from_file(path)?,
+        None => Variables::new(),
+    };
+    for (name, value) in args.vars {
+        variables = variables.set(name, value);
+    }
+    let data = client
+        .execute_raw(&query, Some(variables.into_value()), args.branch.as_deref())
+        .await?
+        .into_data()?;
+    println!("{}", render(&data, args.format)?);
+    Ok(())
+}
+
+async fn run_branch(client: &Client, command: BranchCommand) -> infrahub::Result<()> {
+    match command {
+        BranchCommand::List { format } => {
+            let branches = serde_json::to_value(client.list_branches().await?)?;
+            println!("{}", render(&branches, format)?);
+        }
+        BranchCommand::Create { name } => {
+            client.create_branch(&name).await?;
+            println!("created branch {name}");
+        }
+        BranchCommand::Delete { name } => {
+            client.delete_branch(&name).await?;
+            println!("deleted branch {name}");
+        }
+        BranchCommand::Merge { name } => {
+            client.merge_branch(&name).await?;
+            println!("merged branch {name}");
+        }
+    }
+    Ok(())
+}
+
+async fn run_schema(client: &Client, command: SchemaCommand) -> infrahub::Result<()> {
+    match command {
+        SchemaCommand::Fetch { branch, out } => {
+            let schema = client.fetch_schema(branch.as_deref()).await?;
+            match out {
+                Some(path) => std::fs::write(&path, schema).map_err(|err| {
+                    infrahub::Error::Config(format!("cannot write {}: {err}", path.display()))
+                })?,
+                None => print!("{schema}"),
+            }
+        }
+        SchemaCommand::Load { files, branch } => {
+            let schemas = read_schema_files(&files)?;
+            let hash = client.load_schema(&schemas, branch.as_deref()).await?;
+            println!("{hash}");
+        }
+        SchemaCommand::Check { files, branch } => {
+            let schemas = read_schema_files(&files)?;
+            let diff = client.check_schema(&schemas, branch.as_deref()).await?;
+            println!("{}", serde_json::to_string_pretty(&diff)?);
+        }
+    }
+    Ok(())
+}
+
+/// read schema documents from json files, or yaml files with the `yaml`
+/// feature
+fn read_schema_files(paths: &[PathBuf]) -> infrahub::Result<Vec<Value>> {
+    paths
+        .iter()
+        .map(|path| {
+            let text = std::fs::read_to_string(path).map_err(|err| {
+                infrahub::Error::Config(format!("cannot read schema {}: {err}", path.display()))
+            })?;
+            let yaml = path
+                .extension()
+                .is_some_and(|ext| ext == "yml" || ext == "yaml");
+            if yaml {
+                #[cfg(feature = "yaml")]
+                return serde_yaml::from_str(&text).map_err(|err| {
+                    infrahub::Error::Config(format!("invalid schema {}: {err}", path.display()))
+                });
+                #[cfg(not(feature = "yaml"))]
+                return Err(infrahub::Error::Config(format!(
+                    "schema {} is yaml; enable the `yaml` feature",
+                    path.display()
+                )));
+            }
+            serde_json::from_str(&text).map_err(|err| {
+                infrahub::Error::Config(format!("invalid schema {}: {err}", path.display()))
+            })
+        })
+        .collect()
+}
+
+fn render(data: &Value, format: Format) -> infrahub::Result<String> {
+    Ok(match format {
+        Format::Json => serde_json::to_string_pretty(data)?,
+        Format::Compact => serde_json::to_string(data)?,
+        Format::Table => render_table(data),
+    })
+}
+
+/// lay out the rows of `data` as aligned columns
+///
+/// objects holding a single object or list are unwrapped until a list or a `{ edges }`
+/// connection is found; its nodes become rows and their fields columns,
+/// sorted by name. anything else is a single row.
+fn render_table(data: &Value) -> String {
+    let rows = table_rows(data);
+    let columns: BTreeSet<&str> = rows
+        .iter()
+        .flat_map(Map::keys)
+        .map(String::as_str)
+        .collect();
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| row.get(*column).map(cell).unwrap_or_default())
+                .collect()
+        })
+        .collect();
+    let widths: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            cells
+                .iter()
+                .map(|row| row[index].chars().count())
+                .chain([column.chars().count()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+    let rule: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+
+    let mut lines = vec![
+        table_line(&columns.into_iter().collect::<Vec<_>>(), &widths),
+        table_line(&rule, &widths),
+    ];
+    lines.extend(cells.iter().map(|row| table_line(row, &widths)));
+    lines.join("\n")
+}
+
+fn table_line<S: AsRef<str>>(values: &[S], widths: &[usize]) -> String {
+    let padded: Vec<String> = values
+        .iter()
+        .zip(widths)
+        .map(|(value, width)| format!("{:<width$}", value.as_ref()))
+        .collect();
+    padded.join("  ").trim_end().to_string()
+}
+
+fn table_rows(data: &Value) -> Vec<Map<String, Value>> {
+    let mut current = data;
+    loop {
+        match current {
+            Value::Object(map)
+                if map.len() == 1
+                    && map
+                        .values()
+                        .all(|value| value.is_object() || value.is_array()) =>
+            {
+                current = map.values().next().unwrap_or(&Value::Null)
+            }
+            Value::Object(map) if map.get("edges").is_some_and(Value::is_array) => {
+                return connection_nodes(current).into_iter().map(row).collect()
+            }
+            Value::Array(items) => return items.iter().map(row).collect(),
+            _ => return vec![row(current)],
+        }
+    }
+}
+
+fn connection_nodes(connection: &Value) -> Vec<&Value> {
+    connection["edges"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|edge| edge.get("node"))
+        .filter(|node| !node.is_null())
+        .collect()
+}
+
+fn row(value: &Value) -> Map<String, Value> {
+    match value {
+        Value::Object(map) => map.clone(),
+        _ => Map::from_iter([("value".to_string(), value.clone())]),
+    }
+}
+
+/// the text of one cell
+///
+/// attributes show their `value`, relationships the `display_label` (or
+/// `id`) of their nodes, and anything else compact json.
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        Value::Bool(_) | Value::Number(_) => value.to_string(),
+        Value::Array(items) => items.iter().map(cell).collect::<Vec<_>>().join(", "),
+        Value::Object(map) => {
+            if let Some(inner) = map.get("value") {
+                cell(inner)
+            } else if map.get("edges").is_some_and(Value::is_array) {
+                let labels: Vec<String> = connection_nodes(value).into_iter().map(label).collect();
+                labels.join(", ")
+            } else if let Some(node) = map.get("node") {
+                label(node)
+            } else {
+                value.to_string()
+            }
+        }
+    }
+}
+
+fn label(node: &Value) -> String {
+    ["display_label", "name", "id"]
+        .iter()
+        .find_map(|key| node.get(key).filter(|value| !value.is_null()))
+        .map(cell)
+        .unwrap_or_else(|| cell(node))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        let args = std::iter::once("infrahub")
+            .chain(args.iter().copied())
+            .map(String::from)
+            .collect();
+        parse_args(args).map_err(|err| match err {
+            ParseArgsError::Help => "help".to_string(),
+            ParseArgsError::Message(message) => message,
+        })
+    }
+
+    #[test]
+    fn test_parse_query_args() {
+        let args = parse(&[
+            "--profile",
+            "prod",
+            "query",
+            "{ Branch { name } }",
+            "--var",
+            "limit=5",
+            "--var",
+            "name=red",
+            "--branch",
+            "b1",
+            "--format",
+            "table",
+        ])
+        .unwrap();
+        assert_eq!(args.connection.profile.as_deref(), Some("prod"));
+        let Command::Query(query) = args.command else {
+            panic!("expected query");
+        };
+        assert_eq!(query.query.as_deref(), Some("{ Branch { name } }"));
+        assert_eq!(
+            query.vars,
+            [
+                ("limit".to_string(), json!(5)),
+                ("name".to_string(), json!("red"))
+            ]
+        );
+        assert_eq!(query.branch.as_deref(), Some("b1"));
+        assert_eq!(query.format, Format::Table);
+
+        let args = parse(&["query", "--file", "-", "--token", "t"]).unwrap();
+        assert_eq!(args.connection.token.as_deref(), Some("t"));
+
+        for (args, expected) in [
+            (vec!["--help"], "help"),
+            (vec![], "missing command"),
+            (vec!["select"], "unknown command"),
+            (vec!["query"], "either a query argument or --file"),
+            (vec!["query", "{ a }", "--file", "q.graphql"], "either"),
+            (vec!["query", "{ a }", "--var", "x"], "<name>=<value>"),
+            (vec!["query", "{ a }", "--format", "xml"], "unknown format"),
+            (vec!["query", "{ a }", "{ b }"], "unknown argument: { b }"),
+            (vec!["query", "{ a }", "--branch"], "--branch needs a value"),
+            (vec!["query", "{ a }", "-x"], "unknown argument: -x"),
+            (vec!["branch", "create"], "branch expects"),
+            (
+                vec!["branch", "list", "--branch", "b1"],
+                "--branch does not apply to branch list",
+            ),
+            (vec!["schema", "load"], "schema expects"),
+            (
+                vec!["schema", "fetch", "--format", "table"],
+                "--format does not apply",
+            ),
+        ] {
+            let err = parse(&args).unwrap_err();
+            assert!(err.contains(expected), "{args:?}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_parse_branch_and_schema_args() {
+        let args = parse(&["branch", "list", "--format", "table"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Command::Branch(BranchCommand::List {
+                format: Format::Table
+            })
+        ));
+        let args = parse(&["--url", "http://x", "branch", "merge", "feature-1"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Command::Branch(BranchCommand::Merge { name }) if name == "feature-1"
+        ));
+        let args = parse(&["schema", "fetch", "--branch", "b1", "--out", "s.graphql"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Command::Schema(SchemaCommand::Fetch { branch: Some(branch), out: Some(_) })
+                if branch == "b1"
+        ));
+        let args = parse(&["schema", "check", "a.json", "b.yml", "--branch", "b1"]).unwrap();
+        let Command::Schema(SchemaCommand::Check { files, branch }) = args.command else {
+            panic!("expected schema check");
+        };
+        assert_eq!(files, [PathBuf::from("a.json"), PathBuf::from("b.yml")]);
+        assert_eq!(branch.as_deref(), Some("b1"));
+    }
+
+    #[test]
+    fn test_read_schema_files() {
+        let dir = std::env::temp_dir().join(format!("infrahub-cli-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("schema.json");
+        std::fs::write(&path, r#"{"version": "1.0", "nodes": []}"#).unwrap();
+        let schemas = read_schema_files(std::slice::from_ref(&path)).unwrap();
+        assert_eq!(schemas[0]["version"], "1.0");
+        std::fs::write(&path, "{").unwrap();
+        let err = read_schema_files(&[path]).unwrap_err();
+        assert!(err.to_string().contains("invalid schema"));
+        assert!(read_schema_files(&[dir.join("missing.json")]).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_render_table() {
+        let data = json!({
+            "BuiltinTag": {
+                "count": 2,
+                "edges": [
+                    { "node": { "id": "t1", "name": { "value": "red" }, "parent": { "node": null } } },
+                    { "node": { "id": "t22", "name": { "value": null }, "parent": { "node": { "display_label": "p" } },
+                        "members": { "edges": [{ "node": { "id": "m1" } }, { "node": { "id": "m2" } }] } } },
+                ]
+            }
+        });
+        assert_eq!(
+            render_table(&data),
+            "id   members  name  parent\n\
+             ---  -------  ----  ------\n\
+             t1            red\n\
+             t22  m1, m2         p"
+        );
+
+        let branches = json!({ "Branch": [{ "name": "main", "is_default": true }] });
+        assert_eq!(
+            render_table(&branches),
+            "is_default  name\n----------  ----\ntrue        main"
+        );
+        assert_eq!(
+            render_table(&json!({ "InfrahubInfo": { "version": "1.2" } })),
+            "version\n-------\n1.2"
+        );
+        assert_eq!(
+            render(&branches, Format::Compact).unwrap(),
+            r#"{"Branch":[{"is_default":true,"name":"main"}]}"#
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! blocking bridge
+//!
+//! run client requests from synchronous code, such as rayon workers or ffi
+//! callbacks, by scheduling them onto an existing tokio runtime and blocking
+//! the calling thread until they finish.
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::graphql::GraphQlResponse;
+use crate::operation::Operation;
+use serde::de::DeserializeOwned;
+use std::future::Future;
+use tokio::runtime::{Handle, RuntimeFlavor};
+
+/// a [`Client`] paired with the runtime its requests run on
+///
+/// every call spawns the request onto the runtime and blocks the calling
+/// thread until it completes. this works from threads outside the runtime,
+/// and from worker threads of a multi-threaded runtime (through
+/// [`tokio::task::block_in_place`]), without the "cannot block within a
+/// runtime" panic of `Runtime::block_on`. blocking a current-thread runtime
+/// from its own thread would deadlock and fails with [`Error::Config`].
+#[derive(Clone)]
+pub struct BlockingHandle {
+    client: Client,
+    runtime: Handle,
+}
+
+impl Client {
+    /// a [`BlockingHandle`] for the tokio runtime this is called in
+    ///
+    /// call it once while inside the runtime, then move the handle to the
+    /// threads that need blocking access. fails with [`Error::Config`] outside
+    /// a runtime; use [`BlockingHandle::new`] to pass one explicitly.
+    pub fn blocking_handle(&self) -> Result<BlockingHandle> {
+        let runtime = Handle::try_current().map_err(|_| {
+            Error::Config("blocking_handle must be called within a tokio runtime".to_string())
+        })?;
+        Ok(BlockingHandle::new(self.clone(), runtime))
+    }
+}
+
+impl BlockingHandle {
+    /// pair `client` with `runtime`
+    pub fn new(client: Client, runtime: Handle) -> Self {
+        Self { client, runtime }
+    }
+
+    /// the wrapped client
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// run `f` on the runtime and block until its future completes
+    ///
+    /// a panic in the future is resumed on the calling thread. a future
+    /// dropped because the runtime shut down fails with [`Error::Shutdown`].
+    pub fn run<T, F, Fut>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(Client) -> Fut,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+        T: Send + 'static,
+    {
+        let in_runtime = match Handle::try_current() {
+            Ok(current) if current.runtime_flavor() == RuntimeFlavor::CurrentThread => {
+                return Err(Error::Config(
+                    "cannot block a current-thread runtime from within it".to_string(),
+                ));
+            }
+            Ok(_) => true,
+            Err(_) => false,
+        };
+
+        let task = self.runtime.spawn(f(self.client.clone()));
+        let joined = if in_runtime {
+            tokio::task::block_in_place(|| self.runtime.block_on(task))
+        } else {
+            self.runtime.block_on(task)
+        };
+        match joined {
+            Ok(result) => result,
+            Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+            Err(_) => Err(Error::Shutdown),
+        }
+    }
+
+    /// blocking [`Client::execute_raw`]
+    pub fn execute_raw(
+        &self,
+        query: &str,
+        variables: Option<serde_json::Value>,
+        branch: Option<&str>,
+    ) -> Result<GraphQlResponse<serde_json::Value>> {
+        self.execute(query, variables, branch)
+    }
+
+    /// blocking [`Client::execute`]
+    pub fn execute<T>(
+        &self,
+        query: &str,
+        variables: Option<serde_json::Value>,
+        branch: Option<&str>,
+    ) -> Result<GraphQlResponse<T>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let query = query.to_string();
+        let branch = branch.map(str::to_string);
+        self.run(
+            move |client| async move { client.execute(&query, variables, branch.as_deref()).await },
+        )
+    }
+
+    /// blocking [`Client::execute_operation`]
+    pub fn execute_operation<O>(
+        &self,
+        variables: Option<serde_json::Value>,
+        branch: Option<&str>,
+    ) -> Result<GraphQlResponse<O::Response>>
+    where
+        O: Operation,
+        O::Response: Send + 'static,
+    {
+        self.execute(O::QUERY, variables, branch)
+    }
+
+    /// blocking [`Client::fetch_schema`]
+    pub fn fetch_schema(&self, branch: Option<&str>) -> Result<String> {
+        let branch = branch.map(str::to_string);
+        self.run(move |client| async move { client.fetch_schema(branch.as_deref()).await })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ClientConfig;
+
+    fn client() -> Client {
+        Client::new(ClientConfig::new("http://localhost:1234", "token")).unwrap()
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_run_from_worker_and_plain_threads() {
+        let handle = client().blocking_handle().unwrap();
+
+        // inside the runtime, on a worker thread
+        assert_eq!(handle.run(|_| async { Ok(1) }).unwrap(), 1);
+
+        // a plain thread, as rayon or an ffi callback would use
+        let threaded = handle.clone();
+        let value = std::thread::spawn(move || threaded.run(|_| async { Ok(2) }))
+            .join()
+            .unwrap()
+            .unwrap();
+        assert_eq!(value, 2);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_run_rejects_current_thread_runtime() {
+        let handle = client().blocking_handle().unwrap();
+        assert!(matches!(
+            handle.run(|_| async { Ok(()) }),
+            Err(Error::Config(_))
+        ));
+    }
+
+    #[test]
+    fn test_blocking_handle_requires_runtime() {
+        assert!(matches!(client().blocking_handle(), Err(Error::Config(_))));
+    }
+}
@@ -0,0 +1,205 @@
+//! branch helpers
+//!
+//! thin wrappers around the `Branch` query and the `BranchCreate`,
+//! `BranchDelete`, and `BranchMerge` mutations, plus fan-out of one query
+//! across several branches.
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::graphql::GraphQlResponse;
+use futures_util::future::join_all;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// a branch as listed by [`Client::list_branches`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BranchInfo {
+    /// branch id
+    pub id: String,
+    /// branch name
+    pub name: String,
+    /// free-form description
+    pub description: Option<String>,
+    /// branch it was created from
+    pub origin_branch: Option<String>,
+    /// time it was created from its origin
+    pub branched_from: Option<String>,
+    /// `OPEN`, `NEED_REBASE`, and so on
+    pub status: String,
+    /// true for the default branch
+    #[serde(deserialize_with = "null_as_false")]
+    pub is_default: bool,
+    /// true if the branch is synced with git repositories
+    #[serde(deserialize_with = "null_as_false")]
+    pub sync_with_git: bool,
+    /// true if the branch changed the schema
+    #[serde(deserialize_with = "null_as_false")]
+    pub has_schema_changes: bool,
+}
+
+/// the outcome on one branch of [`Client::execute_on_branches`]
+#[derive(Debug)]
+pub struct BranchResult<T> {
+    /// branch the query ran against
+    pub branch: String,
+    /// the response, or the error from that branch alone
+    pub result: Result<GraphQlResponse<T>>,
+}
+
+impl Client {
+    /// create a branch and wait until it is ready
+    ///
+    /// the branch is not synced with git.
+    pub async fn create_branch(&self, name: &str) -> Result<()> {
+        let query = "mutation CreateBranch($name: String!) { BranchCreate(data: { name: $name, sync_with_git: false }, wait_until_completion: true) { ok } }";
+        let vars = serde_json::json!({ "name": name });
+        let response = self.execute_raw(query, Some(vars), None).await?;
+        ensure_ok(response.data, "BranchCreate")
+    }
+
+    /// delete a branch and wait until the deletion completes
+    pub async fn delete_branch(&self, name: &str) -> Result<()> {
+        let query = "mutation DeleteBranch($name: String!) { BranchDelete(data: { name: $name }, wait_until_completion: true) { ok } }";
+        let vars = serde_json::json!({ "name": name });
+        let response = self.execute_raw(query, Some(vars), None).await?;
+        ensure_ok(response.data, "BranchDelete")
+    }
+
+    /// list all branches, the default branch included
+    pub async fn list_branches(&self) -> Result<Vec<BranchInfo>> {
+        let query = "query ListBranches { Branch { id name description origin_branch branched_from status is_default sync_with_git has_schema_changes } }";
+        let response = self.execute_raw(query, None, None).await?;
+        let mut data = response.into_data()?;
+        Ok(serde_json::from_value(data["Branch"].take())?)
+    }
+
+    /// merge a branch into the default branch and wait until the merge
+    /// completes
+    pub async fn merge_branch(&self, name: &str) -> Result<()> {
+        let query = "mutation MergeBranch($name: String!) { BranchMerge(data: { name: $name }, wait_until_completion: true) { ok } }";
+        let vars = serde_json::json!({ "name": name });
+        let response = self.execute_raw(query, Some(vars), None).await?;
+        ensure_ok(response.data, "BranchMerge")
+    }
+
+    /// run the same query against every branch in `branches` concurrently
+    ///
+    /// results come back in the order of `branches`. a branch that fails
+    /// does not affect the others; its error is in its own
+    /// [`BranchResult::result`].
+    pub async fn execute_on_branches<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: Option<serde_json::Value>,
+        branches: &[&str],
+    ) -> Vec<BranchResult<T>> {
+        let requests = branches.iter().map(|&branch| {
+            let variables = variables.clone();
+            async move {
+                BranchResult {
+                    branch: branch.to_string(),
+                    result: self.execute(query, variables, Some(branch)).await,
+                }
+            }
+        });
+        join_all(requests).await
+    }
+}
+
+/// graphql `Boolean` fields may be null; read null as false
+fn null_as_false<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<bool, D::Error> {
+    Ok(Option::<bool>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+pub(crate) fn ensure_ok(data: Option<serde_json::Value>, mutation: &str) -> Result<()> {
+    let data = data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+    if data[mutation]["ok"].as_bool() == Some(true) {
+        Ok(())
+    } else {
+        Err(Error::Config(format!("{mutation} did not report ok")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockClient;
+    use serde_json::json;
+
+    #[test]
+    fn test_ensure_ok() {
+        let data = serde_json::json!({ "BranchCreate": { "ok": true } });
+        assert!(ensure_ok(Some(data), "BranchCreate").is_ok());
+
+        let data = serde_json::json!({ "BranchCreate": { "ok": false } });
+        assert!(ensure_ok(Some(data), "BranchCreate").is_err());
+        assert!(ensure_ok(None, "BranchCreate").is_err());
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_list_and_merge_branches() {
+        let mock = MockClient::new();
+        mock.expect("ListBranches").returning(json!({
+            "Branch": [
+                { "id": "b0", "name": "main", "status": "OPEN", "is_default": true },
+                { "id": "b1", "name": "feature-1", "status": "NEED_REBASE",
+                  "origin_branch": "main", "has_schema_changes": true, "sync_with_git": null },
+            ]
+        }));
+        let merge = mock
+            .expect("MergeBranch")
+            .returning(json!({ "BranchMerge": { "ok": true } }));
+
+        let branches = mock.client().list_branches().await.unwrap();
+        assert_eq!(branches.len(), 2);
+        assert!(branches[0].is_default);
+        assert_eq!(branches[1].status, "NEED_REBASE");
+        assert_eq!(branches[1].origin_branch.as_deref(), Some("main"));
+        assert!(branches[1].has_schema_changes && !branches[1].sync_with_git);
+
+        mock.client().merge_branch("feature-1").await.unwrap();
+        assert_eq!(merge.calls(), 1);
+        assert_eq!(mock.calls()[1].variables["name"], "feature-1");
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_execute_on_branches() {
+        let mock = MockClient::new();
+        for (branch, count) in [("main", 3), ("staging", 5)] {
+            mock.expect("Devices")
+                .on_branch(Some(branch))
+                .returning(json!({ "InfraDevice": { "count": count } }));
+        }
+        mock.expect("Devices")
+            .on_branch(Some("dr"))
+            .returning_status(reqwest::StatusCode::NOT_FOUND, "branch not found");
+
+        let query = "query Devices($role: String) { InfraDevice(role__value: $role) { count } }";
+        let results = mock
+            .client()
+            .execute_on_branches::<serde_json::Value>(
+                query,
+                Some(json!({ "role": "edge" })),
+                &["main", "staging", "dr"],
+            )
+            .await;
+
+        let branches: Vec<&str> = results.iter().map(|r| r.branch.as_str()).collect();
+        assert_eq!(branches, ["main", "staging", "dr"]);
+        let count = |i: usize| {
+            let data = results[i].result.as_ref().unwrap().data.as_ref().unwrap();
+            data["InfraDevice"]["count"].as_i64()
+        };
+        assert_eq!((count(0), count(1)), (Some(3), Some(5)));
+        assert!(results[2].result.is_err());
+        assert!(mock
+            .calls()
+            .iter()
+            .all(|call| call.variables["role"] == "edge"));
+    }
+}
@@ -2,18 +2,34 @@
 //!
 //! includes helpers for raw graphql execution, typed responses, and schema fetch.
 
+use crate::auth::{TokenCache, TokenFailoverEvent};
+use crate::compat::{check_version, missing_version, VersionCheck};
 use crate::config::ClientConfig;
 use crate::error::{Error, Result};
-use crate::graphql::GraphQlResponse;
+use crate::flavor::ApiFlavor;
+use crate::graphql::{
+    check_query_limits, check_variables, operation_name, ErrorPolicy, GraphQlResponse,
+};
+use crate::host_policy::PublicResolver;
 use crate::operation::Operation;
+use crate::query_log::{truncate_query, QueryLogEntry};
+use crate::response::{ResponseMeta, SchemaFetch, SchemaStats};
+use crate::secret::scrub;
+use crate::shutdown::{new_lifecycle, Lifecycle};
+use crate::transport::{ReqwestTransport, Transport, TransportRequest, TransportResponse};
 use crate::upload::FileUpload;
-use reqwest::header::{HeaderMap, HeaderValue};
-use reqwest::multipart;
-use reqwest::StatusCode;
+use reqwest::header::{
+    HeaderMap, HeaderValue, ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_TYPE, USER_AGENT,
+};
+use reqwest::{Method, StatusCode};
 use serde::de::DeserializeOwned;
 use std::future::Future;
-use std::sync::Arc;
-use std::time::Duration;
+use std::io::Read;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::OnceCell;
 use tokio::time::sleep;
 use url::Url;
 
@@ -23,12 +39,27 @@ const RETRY_BASE_MS: u64 = 200;
 const RETRY_MAX_JITTER_MS: u64 = 500;
 /// hard ceiling for any single retry delay (prevents unbounded growth)
 const RETRY_MAX_BACKOFF: Duration = Duration::from_secs(30);
+/// header carrying the api token
+const TOKEN_HEADER: &str = "X-INFRAHUB-KEY";
+/// multipart boundaries tried before giving up on an upload that contains all
+/// of them
+const MULTIPART_BOUNDARY_ATTEMPTS: u64 = 256;
 
 /// graphql client for infrahub
 #[derive(Clone)]
 pub struct Client {
     config: Arc<ClientConfig>,
-    http: reqwest::Client,
+    transport: Arc<dyn Transport>,
+    /// headers added to every request; empty when the reqwest client
+    /// already carries them as defaults
+    default_headers: HeaderMap,
+    fallback_key: Option<HeaderValue>,
+    using_fallback: Arc<AtomicBool>,
+    token_cache: Option<Arc<TokenCache>>,
+    negotiated_flavor: Arc<Mutex<Option<ApiFlavor>>>,
+    /// `InfrahubInfo.version`, read once for the version check
+    server_version: Arc<OnceCell<Option<String>>>,
+    pub(crate) lifecycle: Arc<Lifecycle>,
 }
 
 impl Client {
@@ -36,39 +67,119 @@ impl Client {
     pub fn new(config: ClientConfig) -> Result<Self> {
         config.validate()?;
 
-        let http = if let Some(http) = config.http_client.clone() {
-            http
-        } else {
-            let mut headers = HeaderMap::new();
-            headers.insert(
-                "X-INFRAHUB-KEY",
-                HeaderValue::from_str(&config.token).map_err(|err| {
-                    Error::Config(format!("invalid api token header value: {err}"))
-                })?,
-            );
-            headers.extend(config.extra_headers.clone());
-
-            let builder = reqwest::Client::builder()
-                .default_headers(headers)
-                .user_agent(config.user_agent.clone())
-                .timeout(config.timeout)
-                .danger_accept_invalid_certs(!config.verify_ssl);
+        // a prebuilt http client manages its own auth, unless a transport
+        // replaces it
+        let token_cache = match &config.token_source {
+            Some(source) if config.transport.is_some() || config.http_client.is_none() => {
+                Some(Arc::new(TokenCache::new(source.clone())?))
+            }
+            _ => None,
+        };
 
-            let builder = if let Some(customize) = &config.http_client_builder {
-                customize(builder)
+        let (transport, default_headers): (Arc<dyn Transport>, HeaderMap) =
+            if let Some(transport) = config.transport.clone() {
+                let mut headers = static_headers(&config, token_cache.is_none())?;
+                headers.insert(
+                    USER_AGENT,
+                    HeaderValue::from_str(&config.user_agent)
+                        .map_err(|err| Error::Config(format!("invalid user agent: {err}")))?,
+                );
+                (transport, headers)
+            } else if let Some(http) = config.http_client.clone() {
+                (Arc::new(ReqwestTransport::new(http)), HeaderMap::new())
             } else {
-                builder
+                let headers = static_headers(&config, token_cache.is_none())?;
+
+                let builder = reqwest::Client::builder()
+                    .default_headers(headers)
+                    .user_agent(config.user_agent.clone())
+                    .timeout(config.timeout)
+                    .https_only(config.require_https && config.base_url.scheme() == "https");
+
+                let builder = match config.connect_timeout {
+                    Some(timeout) => builder.connect_timeout(timeout),
+                    None => builder,
+                };
+                let builder = match config.read_timeout {
+                    Some(timeout) => builder.read_timeout(timeout),
+                    None => builder,
+                };
+                let builder = match config.pool_idle_timeout {
+                    Some(timeout) => builder.pool_idle_timeout(timeout),
+                    None => builder,
+                };
+                let builder = match config.pool_max_idle_per_host {
+                    Some(max) => builder.pool_max_idle_per_host(max),
+                    None => builder,
+                };
+                let builder = match config.tcp_keepalive {
+                    Some(interval) => builder.tcp_keepalive(interval),
+                    None => builder,
+                };
+
+                let builder = match &config.proxy {
+                    Some(proxy) => builder.proxy(reqwest::Proxy::all(proxy.as_str())?),
+                    None if config.no_proxy => builder.no_proxy(),
+                    None => builder,
+                };
+
+                let builder = apply_tls(builder, &config)?;
+
+                let builder = config
+                    .resolve
+                    .iter()
+                    .fold(builder, |builder, (host, addr)| {
+                        builder.resolve(host, SocketAddr::new(*addr, 0))
+                    });
+
+                let builder = match &config.host_policy {
+                    Some(policy) if policy.blocks_private_ips() => {
+                        builder.dns_resolver(Arc::new(PublicResolver))
+                    }
+                    _ => builder,
+                };
+
+                let builder = if let Some(customize) = &config.http_client_builder {
+                    customize(builder)
+                } else {
+                    builder
+                };
+
+                (
+                    Arc::new(ReqwestTransport::new(builder.build()?)),
+                    HeaderMap::new(),
+                )
             };
 
-            builder.build()?
-        };
+        let fallback_key = config
+            .fallback_token
+            .as_ref()
+            .map(|token| {
+                token.header_value().map_err(|err| {
+                    Error::Config(format!("invalid fallback token header value: {err}"))
+                })
+            })
+            .transpose()?;
 
         Ok(Self {
             config: Arc::new(config),
-            http,
+            transport,
+            default_headers,
+            fallback_key,
+            using_fallback: Arc::new(AtomicBool::new(false)),
+            token_cache,
+            negotiated_flavor: Arc::new(Mutex::new(None)),
+            server_version: Arc::new(OnceCell::new()),
+            lifecycle: new_lifecycle(),
         })
     }
 
+    /// create a client configured from the environment, see
+    /// [`ClientConfig::from_env`]
+    pub fn from_env() -> Result<Self> {
+        Self::new(ClientConfig::from_env()?)
+    }
+
     /// access the client configuration
     pub fn config(&self) -> &ClientConfig {
         &self.config
@@ -84,6 +195,22 @@ impl Client {
         self.execute(query, variables, branch).await
     }
 
+    /// execute a graphql query and keep `data` as unparsed json, retrying on
+    /// transient errors
+    ///
+    /// errors and extensions are parsed as usual, but `data` stays the raw
+    /// response text, so caches and middleware can pass it on without
+    /// building a `Value` tree. deserialize it later with
+    /// [`GraphQlResponse::parse_data`].
+    pub async fn execute_raw_parts(
+        &self,
+        query: &str,
+        variables: Option<serde_json::Value>,
+        branch: Option<&str>,
+    ) -> Result<GraphQlResponse<Box<serde_json::value::RawValue>>> {
+        self.execute(query, variables, branch).await
+    }
+
     /// execute a graphql query and deserialize into a typed response, retrying on transient errors
     pub async fn execute<T: DeserializeOwned>(
         &self,
@@ -91,22 +218,74 @@ impl Client {
         variables: Option<serde_json::Value>,
         branch: Option<&str>,
     ) -> Result<GraphQlResponse<T>> {
+        self.execute_with_meta(query, variables, branch)
+            .await
+            .map(|(response, _)| response)
+    }
+
+    /// execute a graphql query and return the typed response together with
+    /// [`ResponseMeta`] (http status, response headers, and deprecation notices)
+    pub async fn execute_with_meta<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: Option<serde_json::Value>,
+        branch: Option<&str>,
+    ) -> Result<(GraphQlResponse<T>, ResponseMeta)> {
+        self.check_server_version().await?;
+        self.execute_unchecked(query, variables, branch).await
+    }
+
+    /// [`Client::execute_with_meta`] without the server version check
+    async fn execute_unchecked<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: Option<serde_json::Value>,
+        branch: Option<&str>,
+    ) -> Result<(GraphQlResponse<T>, ResponseMeta)> {
+        if self.config.check_variables {
+            check_variables(query, variables.as_ref())?;
+        }
+        self.check_query_limits(query)?;
         let url = self.config.graphql_url(branch)?;
         let body = serde_json::json!({
             "query": query,
             "variables": variables.unwrap_or_else(|| serde_json::json!({})),
         });
-        self.retry_loop(|| {
-            let url = url.clone();
-            let body = body.clone();
-            async move {
-                let response = self.http.post(url).json(&body).send().await?;
-                let status = response.status();
-                let text = response.text().await?;
-                parse_graphql_response(status, text)
-            }
-        })
-        .await
+        let encoded = match &self.config.json_codec {
+            Some(codec) => codec.encode(&body)?,
+            None => serde_json::to_vec(&body)?,
+        };
+        let started = Instant::now();
+        let result = self
+            .retry_loop(|| {
+                let url = url.clone();
+                let encoded = &encoded;
+                async move {
+                    let response = self
+                        .send(|| {
+                            Ok(self
+                                .request(Method::POST, url.clone())
+                                .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
+                                .body(encoded.clone()))
+                        })
+                        .await?;
+                    let status = response.status;
+                    let parsed: GraphQlResponse<T> =
+                        self.parse_response(status, response.text())?;
+                    let meta = ResponseMeta::from_parts(
+                        status,
+                        &response.headers,
+                        parsed.extensions.as_ref(),
+                        operation_name(query),
+                    );
+                    Ok((parsed, meta))
+                }
+            })
+            .await;
+        self.log_query(query, &body["variables"], branch, started, &result);
+        let result = result?;
+        self.report_deprecations(&result.1);
+        Ok(result)
     }
 
     /// execute a generated operation by name, retrying on transient errors
@@ -120,19 +299,163 @@ impl Client {
 
     /// fetch the graphql schema as text
     pub async fn fetch_schema(&self, branch: Option<&str>) -> Result<String> {
+        self.fetch_schema_with_stats(branch)
+            .await
+            .map(|(schema, _)| schema)
+    }
+
+    /// fetch the graphql schema as text together with transfer statistics
+    ///
+    /// the schema is requested with `Accept-Encoding: gzip` and decoded
+    /// transparently; [`SchemaStats`] reports the wire and decoded sizes.
+    pub async fn fetch_schema_with_stats(
+        &self,
+        branch: Option<&str>,
+    ) -> Result<(String, SchemaStats)> {
         let url = self.config.schema_url(branch)?;
         self.retry_loop(|| {
             let url = url.clone();
             async move {
-                let response = self.http.get(url).send().await?;
-                let status = response.status();
-                let text = response.text().await?;
-                parse_schema_response(status, text)
+                let response = self
+                    .send(|| {
+                        Ok(self
+                            .request(Method::GET, url.clone())
+                            .header(ACCEPT_ENCODING, HeaderValue::from_static("gzip")))
+                    })
+                    .await?;
+                let status = response.status;
+                let gzip = response
+                    .headers
+                    .get(CONTENT_ENCODING)
+                    .is_some_and(|value| value.as_bytes().eq_ignore_ascii_case(b"gzip"));
+                let text = decode_body(&response.body, gzip)?;
+                let stats = SchemaStats {
+                    wire_bytes: response.body.len(),
+                    decoded_bytes: text.len(),
+                    compressed: gzip,
+                };
+                parse_schema_response(status, text).map(|schema| (schema, stats))
+            }
+        })
+        .await
+    }
+
+    /// fetch the current schema hash (`main` in `/api/schema/summary`)
+    ///
+    /// the hash changes whenever the schema of the branch changes.
+    pub async fn schema_hash(&self, branch: Option<&str>) -> Result<String> {
+        let url = self.config.schema_summary_url(branch)?;
+        self.retry_loop(|| {
+            let url = url.clone();
+            async move {
+                let response = self
+                    .send(|| Ok(self.request(Method::GET, url.clone())))
+                    .await?;
+                parse_schema_hash(response.status, response.text())
+            }
+        })
+        .await
+    }
+
+    /// load schema documents (`{"version": "1.0", "nodes": [...]}`) through
+    /// `/api/schema/load` and return the resulting schema hash
+    ///
+    /// loading a schema that is already in place leaves the hash unchanged.
+    pub async fn load_schema(
+        &self,
+        schemas: &[serde_json::Value],
+        branch: Option<&str>,
+    ) -> Result<String> {
+        let url = self.config.schema_load_url(branch)?;
+        let body = serde_json::to_vec(&serde_json::json!({ "schemas": schemas }))?;
+        self.retry_loop(|| {
+            let url = url.clone();
+            let body = body.clone();
+            async move {
+                let response = self
+                    .send(|| {
+                        Ok(self
+                            .request(Method::POST, url.clone())
+                            .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
+                            .body(body.clone()))
+                    })
+                    .await?;
+                let text = parse_schema_response(response.status, response.text())?;
+                let update: serde_json::Value = serde_json::from_str(&text)?;
+                update["hash"].as_str().map(str::to_string).ok_or_else(|| {
+                    Error::Config("missing hash in schema load response".to_string())
+                })
+            }
+        })
+        .await
+    }
+
+    /// validate schema documents through `/api/schema/check` without
+    /// loading them, returning the `diff` against the branch's schema
+    ///
+    /// a schema the server rejects fails with [`Error::GraphQl`] carrying the
+    /// status and the server's explanation in the body.
+    pub async fn check_schema(
+        &self,
+        schemas: &[serde_json::Value],
+        branch: Option<&str>,
+    ) -> Result<serde_json::Value> {
+        let url = self.config.schema_check_url(branch)?;
+        let body = serde_json::to_vec(&serde_json::json!({ "schemas": schemas }))?;
+        self.retry_loop(|| {
+            let url = url.clone();
+            let body = body.clone();
+            async move {
+                let response = self
+                    .send(|| {
+                        Ok(self
+                            .request(Method::POST, url.clone())
+                            .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
+                            .body(body.clone()))
+                    })
+                    .await?;
+                let text = parse_schema_response(response.status, response.text())?;
+                let mut result: serde_json::Value = serde_json::from_str(&text)?;
+                Ok(result["diff"].take())
             }
         })
         .await
     }
 
+    /// true if the server answers `/api/config` with a success status
+    ///
+    /// a single attempt without retries, for readiness polling.
+    pub(crate) async fn is_ready(&self) -> bool {
+        let Ok(url) = self.config.api_config_url() else {
+            return false;
+        };
+        match self
+            .send(|| Ok(self.request(Method::GET, url.clone())))
+            .await
+        {
+            Ok(response) => response.status.is_success(),
+            Err(_) => false,
+        }
+    }
+
+    /// fetch the graphql schema only if its hash differs from `known_hash`
+    ///
+    /// the hash is read from `/api/schema/summary` first, so a schema change
+    /// racing the download leaves an older hash and the next call downloads
+    /// again. with `known_hash` of `None` the schema is always downloaded.
+    pub async fn fetch_schema_if_changed(
+        &self,
+        branch: Option<&str>,
+        known_hash: Option<&str>,
+    ) -> Result<SchemaFetch> {
+        let hash = self.schema_hash(branch).await?;
+        if known_hash == Some(hash.as_str()) {
+            return Ok(SchemaFetch::Unchanged { hash });
+        }
+        let schema = self.fetch_schema(branch).await?;
+        Ok(SchemaFetch::Changed { hash, schema })
+    }
+
     /// execute a graphql mutation with file uploads per the
     /// [graphql multipart request spec](https://github.com/jaydenseric/graphql-multipart-request-spec),
     /// retrying on transient errors.
@@ -145,6 +468,8 @@ impl Client {
         files: Vec<(&str, FileUpload)>,
         branch: Option<&str>,
     ) -> Result<GraphQlResponse<T>> {
+        self.check_query_limits(query)?;
+        self.check_server_version().await?;
         let url = self.config.graphql_url(branch)?;
         let owned_files: Vec<(String, FileUpload)> =
             files.into_iter().map(|(k, v)| (k.to_owned(), v)).collect();
@@ -156,19 +481,55 @@ impl Client {
                 .map(|(k, v)| (k.as_str(), v.clone()))
                 .collect();
             async move {
-                let form = build_multipart_form(query, variables, files_for_attempt)?;
-                let response = self.http.post(url).multipart(form).send().await?;
-                let status = response.status();
-                let text = response.text().await?;
-                parse_graphql_response(status, text)
+                let response = self
+                    .send(|| {
+                        let form = build_multipart_form(
+                            query,
+                            variables.clone(),
+                            files_for_attempt.clone(),
+                        )?;
+                        Ok(self
+                            .request(Method::POST, url.clone())
+                            .header(CONTENT_TYPE, form.content_type)
+                            .body(form.body))
+                    })
+                    .await?;
+                self.parse_response(response.status, response.text())
             }
         })
         .await
     }
 
+    /// the rest endpoint layout used for file downloads
+    ///
+    /// the result of [`Client::negotiate_api_flavor`] if it ran, otherwise
+    /// the configured flavor.
+    pub fn api_flavor(&self) -> ApiFlavor {
+        self.negotiated_flavor
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .unwrap_or(self.config.api_flavor)
+    }
+
+    /// detect the rest endpoint layout from the server's infrahub version
+    ///
+    /// queries `InfrahubInfo { version }` and uses the matching flavor for
+    /// later downloads on this client and its clones.
+    pub async fn negotiate_api_flavor(&self) -> Result<ApiFlavor> {
+        let response = self
+            .execute_raw("query { InfrahubInfo { version } }", None, None)
+            .await?;
+        let flavor = ApiFlavor::from_info(response.data.as_ref())?;
+        *self
+            .negotiated_flavor
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(flavor);
+        Ok(flavor)
+    }
+
     /// download a file by node id
     pub async fn download_file(&self, node_id: &str, branch: Option<&str>) -> Result<Vec<u8>> {
-        let url = self.config.file_url(node_id, branch)?;
+        let url = self.config.file_url(self.api_flavor(), node_id, branch)?;
         self.download_bytes(url).await
     }
 
@@ -179,7 +540,9 @@ impl Client {
         hfid: &[&str],
         branch: Option<&str>,
     ) -> Result<Vec<u8>> {
-        let url = self.config.file_by_hfid_url(kind, hfid, branch)?;
+        let url = self
+            .config
+            .file_by_hfid_url(self.api_flavor(), kind, hfid, branch)?;
         self.download_bytes(url).await
     }
 
@@ -189,7 +552,9 @@ impl Client {
         storage_id: &str,
         branch: Option<&str>,
     ) -> Result<Vec<u8>> {
-        let url = self.config.file_by_storage_id_url(storage_id, branch)?;
+        let url = self
+            .config
+            .file_by_storage_id_url(self.api_flavor(), storage_id, branch)?;
         self.download_bytes(url).await
     }
 
@@ -198,18 +563,19 @@ impl Client {
         self.retry_loop(|| {
             let url = url.clone();
             async move {
-                let response = self.http.get(url).send().await?;
-                if !response.status().is_success() {
-                    let status = response.status();
-                    let body = response.text().await?;
+                let response = self
+                    .send(|| Ok(self.request(Method::GET, url.clone())))
+                    .await?;
+                let status = response.status;
+                if !status.is_success() {
                     return Err(Error::GraphQl {
                         status: Some(status.as_u16()),
                         errors: Vec::new(),
-                        body,
+                        body: response.text(),
                         message: format!("file download error: {status}"),
                     });
                 }
-                Ok(response.bytes().await?.to_vec())
+                Ok(response.body)
             }
         })
         .await
@@ -217,26 +583,292 @@ impl Client {
 }
 
 impl Client {
+    /// a request to `url` carrying the default headers
+    fn request(&self, method: Method, url: Url) -> TransportRequest {
+        let mut request = TransportRequest::new(method, url);
+        request.headers = self.default_headers.clone();
+        request
+    }
+
+    /// send a request, failing over to the fallback token once on 401
+    ///
+    /// with a token source, a 401 first reloads the token and retries with it
+    /// if it changed. `build` is called again for each retry, so each attempt
+    /// gets a fresh request.
+    async fn send<F>(&self, build: F) -> Result<TransportResponse>
+    where
+        F: Fn() -> Result<TransportRequest>,
+    {
+        let used_fallback = self.using_fallback.load(Ordering::SeqCst);
+        let token = match &self.token_cache {
+            Some(cache) if !used_fallback => Some(cache.token().await?),
+            _ => None,
+        };
+        let request = self.authorize(build()?, token.clone());
+        let url = request.url.clone();
+        let mut response = self.dispatch(request).await?;
+        if response.status == StatusCode::UNAUTHORIZED && !used_fallback {
+            if let (Some(cache), Some(rejected)) = (&self.token_cache, &token) {
+                if let Some(fresh) = cache.reload_rejected(rejected).await? {
+                    response = self.dispatch(self.authorize(build()?, Some(fresh))).await?;
+                }
+            }
+        }
+        if response.status == StatusCode::UNAUTHORIZED
+            && !used_fallback
+            && self.fail_over(response.status, &url)
+        {
+            return self.dispatch(self.authorize(build()?, None)).await;
+        }
+        Ok(response)
+    }
+
+    /// hand a request to the transport, passing sampled exchanges to the
+    /// wire log
+    async fn dispatch(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let Some(wire_log) = self.config.wire_log.as_ref().filter(|log| log.sample()) else {
+            return self.transport.send(request).await;
+        };
+        let logged = request.clone();
+        let started = Instant::now();
+        let result = self.transport.send(request).await;
+        wire_log.log(
+            &self.config.redactor,
+            &logged,
+            result.as_ref().map_err(ToString::to_string),
+            started.elapsed(),
+        );
+        result
+    }
+
+    /// parse a graphql response with the configured error policy, redacting
+    /// sensitive fields from the body attached to errors
+    fn parse_response<T: DeserializeOwned>(
+        &self,
+        status: StatusCode,
+        text: String,
+    ) -> Result<GraphQlResponse<T>> {
+        let text = self.config.field_filter.apply_to_body(text);
+        let policy = self.config.error_policy;
+        let result = match &self.config.json_codec {
+            Some(codec) => {
+                let parsed = match codec.parser(text.len()) {
+                    Some(parser) => parser.parse(&text),
+                    None => {
+                        let mut bytes = text.as_bytes().to_vec();
+                        codec
+                            .decode(&mut bytes)
+                            .and_then(|value| Ok(serde_json::from_value(value)?))
+                    }
+                };
+                check_graphql_response(status, text, parsed, policy)
+            }
+            None => parse_graphql_response(status, text, policy),
+        };
+        result.map_err(|err| self.sanitize_error(err))
+    }
+
+    /// redact sensitive fields from the body attached to an error, and
+    /// scrub any token the server echoed back into it
+    fn sanitize_error(&self, err: Error) -> Error {
+        match err {
+            Error::GraphQl {
+                status,
+                errors,
+                body,
+                message,
+            } => {
+                let current = self.token_cache.as_ref().map(|cache| cache.current());
+                let tokens = [
+                    Some(self.config.token.expose().as_bytes()),
+                    self.config
+                        .fallback_token
+                        .as_ref()
+                        .map(|token| token.expose().as_bytes()),
+                    current.as_ref().map(HeaderValue::as_bytes),
+                ];
+                let body = self.config.redactor.redact_text(&body);
+                Error::GraphQl {
+                    status,
+                    errors,
+                    body: scrub(&body, tokens.into_iter().flatten()),
+                    message,
+                }
+            }
+            other => other,
+        }
+    }
+
+    /// pass a redacted record of a finished operation to the query log hook,
+    /// and to the slow query hook if it took longer than the threshold
+    fn log_query<T>(
+        &self,
+        query: &str,
+        variables: &serde_json::Value,
+        branch: Option<&str>,
+        started: Instant,
+        result: &Result<T>,
+    ) {
+        let duration = started.elapsed();
+        let slow = self.config.slow_query_hook.is_some()
+            && self
+                .config
+                .slow_query_threshold
+                .is_some_and(|threshold| duration >= threshold);
+        if self.config.query_log_hook.is_none() && !slow {
+            return;
+        }
+        let variables = match variables {
+            serde_json::Value::Object(map) if map.is_empty() => None,
+            variables => Some(self.config.redactor.redact(variables)),
+        };
+        let entry = QueryLogEntry {
+            operation: operation_name(query).map(str::to_string),
+            branch: branch
+                .map(str::to_string)
+                .or_else(|| self.config.default_branch.clone())
+                .filter(|branch| !branch.is_empty()),
+            duration,
+            query: truncate_query(query, self.config.query_log_limit),
+            variables,
+            error: result.as_ref().err().map(ToString::to_string),
+        };
+        if let Some(hook) = &self.config.query_log_hook {
+            hook(&entry);
+        }
+        if let Some(hook) = self.config.slow_query_hook.as_ref().filter(|_| slow) {
+            hook(&entry);
+        }
+    }
+
+    /// check the server version against [`crate::SUPPORTED_VERSIONS`], reading
+    /// it on the first call
+    ///
+    /// in warn mode a mismatch is passed to the version check hook once and
+    /// never fails a request. in strict mode a failure to read the version is
+    /// returned and retried on the next call.
+    async fn check_server_version(&self) -> Result<()> {
+        let mode = self.config.version_check;
+        if mode == VersionCheck::Off {
+            return Ok(());
+        }
+        let version = self
+            .server_version
+            .get_or_try_init(|| async {
+                let response = self
+                    .execute_unchecked::<serde_json::Value>(
+                        "query { InfrahubInfo { version } }",
+                        None,
+                        None,
+                    )
+                    .await;
+                let (version, warning) = match response {
+                    Ok((response, _)) => {
+                        let version = response
+                            .data
+                            .as_ref()
+                            .and_then(|data| data["InfrahubInfo"]["version"].as_str())
+                            .map(str::to_string);
+                        let warning = match &version {
+                            Some(version) => check_version(version).err(),
+                            None => Some(missing_version()),
+                        };
+                        (version, warning)
+                    }
+                    Err(err) if mode == VersionCheck::Strict => return Err(err),
+                    Err(err) => (None, Some(err)),
+                };
+                if let (VersionCheck::Warn, Some(hook), Some(warning)) =
+                    (mode, &self.config.version_check_hook, &warning)
+                {
+                    hook(warning);
+                }
+                Ok(version)
+            })
+            .await?;
+        match (mode, version) {
+            (VersionCheck::Strict, Some(version)) => check_version(version),
+            (VersionCheck::Strict, None) => Err(missing_version()),
+            _ => Ok(()),
+        }
+    }
+
+    /// fail fast if `query` exceeds the configured length or depth limit
+    fn check_query_limits(&self, query: &str) -> Result<()> {
+        check_query_limits(
+            query,
+            self.config.max_query_length,
+            self.config.max_query_depth,
+        )
+    }
+
+    /// pass deprecation notices to the configured hook
+    fn report_deprecations(&self, meta: &ResponseMeta) {
+        if let Some(hook) = &self.config.deprecation_hook {
+            for deprecation in &meta.deprecations {
+                hook(deprecation);
+            }
+        }
+    }
+
+    /// set the token header: the fallback once the client has failed over,
+    /// otherwise `token` from the token source, if any
+    fn authorize(&self, request: TransportRequest, token: Option<HeaderValue>) -> TransportRequest {
+        match (&self.fallback_key, token) {
+            (Some(key), _) if self.using_fallback.load(Ordering::SeqCst) => {
+                request.header(TOKEN_HEADER, key.clone())
+            }
+            (_, Some(token)) => request.header(TOKEN_HEADER, token),
+            _ => request,
+        }
+    }
+
+    /// switch to the fallback token. returns false if none is configured.
+    ///
+    /// the hook fires only for the request that performs the switch, even if
+    /// several concurrent requests were rejected with the primary token.
+    fn fail_over(&self, status: StatusCode, url: &Url) -> bool {
+        if self.fallback_key.is_none() {
+            return false;
+        }
+        if !self.using_fallback.swap(true, Ordering::SeqCst) {
+            if let Some(hook) = &self.config.token_failover_hook {
+                hook(&TokenFailoverEvent {
+                    status: status.as_u16(),
+                    url: url.to_string(),
+                });
+            }
+        }
+        true
+    }
+
     async fn retry_loop<T, F, Fut>(&self, mut operation: F) -> Result<T>
     where
         F: FnMut() -> Fut,
         Fut: Future<Output = Result<T>>,
     {
-        let mut attempts = 0;
-        loop {
-            let result = operation().await;
-            match result {
-                Ok(value) => return Ok(value),
-                Err(err) => {
-                    if attempts >= self.config.max_retries || !err.is_retryable() {
-                        return Err(err);
+        self.lifecycle
+            .track(async {
+                let mut attempts = 0;
+                loop {
+                    let started = Instant::now();
+                    let result = operation()
+                        .await
+                        .map_err(|err| err.with_elapsed(started.elapsed()));
+                    match result {
+                        Ok(value) => return Ok(value),
+                        Err(err) => {
+                            if attempts >= self.config.max_retries || !err.is_retryable() {
+                                return Err(self.sanitize_error(err));
+                            }
+                            attempts += 1;
+                            let delay = Self::retry_delay(attempts);
+                            sleep(delay).await;
+                        }
                     }
-                    attempts += 1;
-                    let delay = Self::retry_delay(attempts);
-                    sleep(delay).await;
                 }
-            }
-        }
+            })
+            .await
     }
 
     fn retry_delay(attempt: u32) -> Duration {
@@ -268,8 +900,21 @@ impl Client {
 fn parse_graphql_response<T: DeserializeOwned>(
     status: StatusCode,
     text: String,
+    policy: ErrorPolicy,
+) -> Result<GraphQlResponse<T>> {
+    let parsed = serde_json::from_str(&text).map_err(Error::from);
+    check_graphql_response(status, text, parsed, policy)
+}
+
+/// turn a parsed response body into a result according to the http status,
+/// graphql errors, and error policy
+fn check_graphql_response<T>(
+    status: StatusCode,
+    text: String,
+    parsed: Result<GraphQlResponse<T>>,
+    policy: ErrorPolicy,
 ) -> Result<GraphQlResponse<T>> {
-    let parsed: GraphQlResponse<T> = match serde_json::from_str(&text) {
+    let mut parsed = match parsed {
         Ok(v) => v,
         Err(json_err) => {
             if !status.is_success() {
@@ -280,9 +925,19 @@ fn parse_graphql_response<T: DeserializeOwned>(
                     message: format!("http {status}: non-JSON response"),
                 });
             }
-            return Err(json_err.into());
+            return Err(json_err);
         }
     };
+    let partial = status.is_success() && parsed.data.is_some();
+    match policy {
+        ErrorPolicy::All if partial => return Ok(parsed),
+        ErrorPolicy::Ignore if partial => {
+            parsed.errors.clear();
+            return Ok(parsed);
+        }
+        _ => {}
+    }
+
     if !parsed.errors.is_empty() {
         let message = parsed
             .errors
@@ -309,6 +964,29 @@ fn parse_graphql_response<T: DeserializeOwned>(
     Ok(parsed)
 }
 
+/// the token and extra headers sent with every request; the token is left
+/// out when `with_token` is false (a token source supplies it per request)
+fn static_headers(config: &ClientConfig, with_token: bool) -> Result<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    if with_token && !config.token.is_empty() {
+        headers.insert(
+            TOKEN_HEADER,
+            config
+                .token
+                .header_value()
+                .map_err(|err| Error::Config(format!("invalid api token header value: {err}")))?,
+        );
+    }
+    headers.extend(config.extra_headers.clone());
+    Ok(headers)
+}
+
+/// an encoded `multipart/form-data` request body
+struct MultipartForm {
+    content_type: HeaderValue,
+    body: Vec<u8>,
+}
+
 /// build a multipart form per the graphql multipart request spec.
 ///
 /// the spec requires three named parts:
@@ -319,7 +997,7 @@ fn build_multipart_form(
     query: &str,
     variables: Option<serde_json::Value>,
     files: Vec<(&str, FileUpload)>,
-) -> Result<multipart::Form> {
+) -> Result<MultipartForm> {
     let mut vars = variables.unwrap_or_else(|| serde_json::json!({}));
     let mut map = serde_json::Map::new();
 
@@ -338,18 +1016,82 @@ fn build_multipart_form(
         "variables": vars,
     });
 
-    let mut form = multipart::Form::new()
-        .text("operations", operations.to_string())
-        .text("map", serde_json::Value::Object(map).to_string());
-
+    let mut parts = vec![
+        (
+            disposition("operations", None),
+            None,
+            operations.to_string().into_bytes(),
+        ),
+        (
+            disposition("map", None),
+            None,
+            serde_json::Value::Object(map).to_string().into_bytes(),
+        ),
+    ];
     for (idx, (_, file)) in files.into_iter().enumerate() {
-        let part = multipart::Part::bytes(file.data)
-            .file_name(file.filename)
-            .mime_str(&file.content_type)?;
-        form = form.part(idx.to_string(), part);
+        let content_type = HeaderValue::from_str(&file.content_type)
+            .map_err(|_| Error::Config(format!("invalid content type: {:?}", file.content_type)))?;
+        parts.push((
+            disposition(&idx.to_string(), Some(&file.filename)),
+            Some(content_type),
+            file.data,
+        ));
+    }
+
+    // pick a boundary that occurs in none of the parts
+    let boundary = (0..MULTIPART_BOUNDARY_ATTEMPTS)
+        .map(|n| format!("infrahub-boundary-{n:016x}"))
+        .find(|boundary| {
+            parts.iter().all(|(_, _, data)| {
+                !data
+                    .windows(boundary.len())
+                    .any(|window| window == boundary.as_bytes())
+            })
+        })
+        .ok_or_else(|| {
+            Error::Config("could not find a multipart boundary absent from the upload".to_string())
+        })?;
+
+    let mut body = Vec::new();
+    for (disposition, content_type, data) in parts {
+        body.extend_from_slice(
+            format!("--{boundary}\r\nContent-Disposition: {disposition}\r\n").as_bytes(),
+        );
+        if let Some(content_type) = content_type {
+            body.extend_from_slice(b"Content-Type: ");
+            body.extend_from_slice(content_type.as_bytes());
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(b"\r\n");
+        body.extend_from_slice(&data);
+        body.extend_from_slice(b"\r\n");
     }
+    body.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
 
-    Ok(form)
+    let content_type = HeaderValue::from_str(&format!("multipart/form-data; boundary={boundary}"))
+        .map_err(|err| Error::Config(format!("invalid multipart content type: {err}")))?;
+    Ok(MultipartForm { content_type, body })
+}
+
+/// a `Content-Disposition` value for a form-data part
+///
+/// quotes and line breaks in the name and filename are percent-encoded, as
+/// browsers do, so they cannot end the value or inject header lines.
+fn disposition(name: &str, filename: Option<&str>) -> String {
+    let escape = |value: &str| {
+        value
+            .replace('"', "%22")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A")
+    };
+    match filename {
+        Some(filename) => format!(
+            "form-data; name=\"{}\"; filename=\"{}\"",
+            escape(name),
+            escape(filename)
+        ),
+        None => format!("form-data; name=\"{}\"", escape(name)),
+    }
 }
 
 fn parse_schema_response(status: StatusCode, text: String) -> Result<String> {
@@ -365,6 +1107,70 @@ fn parse_schema_response(status: StatusCode, text: String) -> Result<String> {
     Ok(text)
 }
 
+/// apply certificate verification, the minimum tls version, the client
+/// identity, and extra root certificates to `builder`
+#[cfg(any(feature = "rustls", feature = "native-tls"))]
+fn apply_tls(
+    builder: reqwest::ClientBuilder,
+    config: &ClientConfig,
+) -> Result<reqwest::ClientBuilder> {
+    let builder = builder.danger_accept_invalid_certs(!config.verify_ssl);
+    let builder = match config.min_tls_version {
+        Some(version) => builder.tls_version_min(version.to_reqwest()),
+        None => builder,
+    };
+    let builder = match &config.client_identity {
+        Some(identity) => builder.identity(identity.to_reqwest()?),
+        None => builder,
+    };
+    let mut roots = Vec::new();
+    for certificate in &config.root_certificates {
+        roots.extend(certificate.to_reqwest()?);
+    }
+    Ok(builder.tls_certs_merge(roots))
+}
+
+/// without a tls backend only plaintext http works; reject tls settings
+/// up front instead of failing on the first request
+#[cfg(not(any(feature = "rustls", feature = "native-tls")))]
+fn apply_tls(
+    builder: reqwest::ClientBuilder,
+    config: &ClientConfig,
+) -> Result<reqwest::ClientBuilder> {
+    let tls = config.base_url.scheme() == "https"
+        || config.min_tls_version.is_some()
+        || config.client_identity.is_some()
+        || !config.root_certificates.is_empty();
+    if tls {
+        return Err(Error::Config(
+            "tls requires the `rustls` or `native-tls` feature".to_string(),
+        ));
+    }
+    Ok(builder)
+}
+
+/// decode a response body, gunzipping it first when `gzip` is set
+fn decode_body(body: &[u8], gzip: bool) -> Result<String> {
+    let bytes = if gzip {
+        let mut decoded = Vec::new();
+        flate2::read::GzDecoder::new(body).read_to_end(&mut decoded)?;
+        decoded
+    } else {
+        body.to_vec()
+    };
+    String::from_utf8(bytes)
+        .map_err(|err| Error::Config(format!("response body is not valid utf-8: {err}")))
+}
+
+fn parse_schema_hash(status: StatusCode, text: String) -> Result<String> {
+    let text = parse_schema_response(status, text)?;
+    let summary: serde_json::Value = serde_json::from_str(&text)?;
+    summary["main"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| Error::Config("missing main hash in schema summary".to_string()))
+}
+
 #[cfg(test)]
 impl Client {
     async fn execute_multipart_with<T: DeserializeOwned, F, Fut>(
@@ -376,13 +1182,13 @@ impl Client {
         send: F,
     ) -> Result<GraphQlResponse<T>>
     where
-        F: FnOnce(Url, multipart::Form) -> Fut,
+        F: FnOnce(Url, MultipartForm) -> Fut,
         Fut: Future<Output = Result<(StatusCode, String)>>,
     {
         let url = self.config.graphql_url(branch)?;
         let form = build_multipart_form(query, variables, files)?;
         let (status, text) = send(url, form).await?;
-        parse_graphql_response(status, text)
+        self.parse_response(status, text)
     }
 
     async fn execute_with<T: DeserializeOwned, F, Fut>(
@@ -403,7 +1209,7 @@ impl Client {
         });
 
         let (status, text) = send(url, body).await?;
-        parse_graphql_response(status, text)
+        self.parse_response(status, text)
     }
 
     async fn fetch_schema_with<F, Fut>(&self, branch: Option<&str>, send: F) -> Result<String>
@@ -430,30 +1236,255 @@ mod tests {
             .expect("test http client");
         Client {
             config: Arc::new(config),
-            http,
+            transport: Arc::new(ReqwestTransport::new(http)),
+            default_headers: HeaderMap::new(),
+            fallback_key: None,
+            using_fallback: Arc::new(AtomicBool::new(false)),
+            token_cache: None,
+            negotiated_flavor: Arc::new(Mutex::new(None)),
+            server_version: Arc::new(OnceCell::new()),
+            lifecycle: new_lifecycle(),
+        }
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_execute_raw_sets_header_and_url() {
+        let config = ClientConfig::new("http://localhost:1234", "test-token");
+        let client = test_client(config);
+        let response = client
+            .execute_with::<serde_json::Value, _, _>(
+                "query { ok }",
+                None,
+                Some("main"),
+                |url, body| async move {
+                    assert_eq!(url.path(), "/graphql/main");
+                    assert_eq!(body["query"], "query { ok }");
+                    Ok((StatusCode::OK, "{\"data\": {\"ok\": true}}".to_string()))
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.data.unwrap()["ok"], true);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_execute_checks_variables_before_sending() {
+        let config = ClientConfig::new("http://127.0.0.1:9", "test-token")
+            .with_max_retries(0)
+            .with_variable_checking(true);
+        let client = test_client(config);
+        let err = client
+            .execute_raw(
+                "query Q($id: ID!) { InfraDevice(ids: [$id]) { count } }",
+                None,
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Config(ref message) if message.contains("$id: ID!")));
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_error_body_is_redacted() {
+        let config = ClientConfig::new("http://localhost:1234", "test-token");
+        let client = test_client(config);
+        let err = client
+            .execute_with::<serde_json::Value, _, _>("query { ok }", None, None, |_, _| async {
+                Ok((
+                    StatusCode::OK,
+                    r#"{"data": {"password": "hunter2"}, "errors": [{"message": "boom"}]}"#
+                        .to_string(),
+                ))
+            })
+            .await
+            .unwrap_err();
+        match err {
+            Error::GraphQl { body, .. } => {
+                assert!(!body.contains("hunter2"));
+                assert!(body.contains(crate::redact::REDACTED));
+            }
+            other => panic!("unexpected error: {other:?}"),
         }
     }
 
     #[cfg_attr(miri, ignore)]
     #[tokio::test]
-    async fn test_execute_raw_sets_header_and_url() {
+    async fn test_echoed_token_is_scrubbed() {
+        let config = ClientConfig::new("http://localhost:1234", "tok-primary")
+            .with_fallback_token("tok-fallback");
+        let client = test_client(config);
+        let err = client
+            .execute_with::<serde_json::Value, _, _>("query { ok }", None, None, |_, _| async {
+                Ok((
+                    StatusCode::BAD_GATEWAY,
+                    "upstream rejected X-INFRAHUB-KEY: tok-primary (fallback tok-fallback)"
+                        .to_string(),
+                ))
+            })
+            .await
+            .unwrap_err();
+        match err {
+            Error::GraphQl { body, .. } => {
+                assert!(!body.contains("tok-"), "token leaked: {body}");
+                assert!(body.contains("X-INFRAHUB-KEY: <redacted>"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_field_filter_strips_response_fields() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Account {
+            name: String,
+            password: Option<String>,
+        }
+
+        let config = ClientConfig::new("http://localhost:1234", "test-token")
+            .with_field_filter(crate::FieldFilter::new().field("password"));
+        let client = test_client(config);
+        let response = client
+            .execute_with::<Account, _, _>("query { account }", None, None, |_, _| async {
+                Ok((
+                    StatusCode::OK,
+                    r#"{"data": {"name": "ops", "password": "hunter2"}}"#.to_string(),
+                ))
+            })
+            .await
+            .unwrap();
+        let account = response.data.unwrap();
+        assert_eq!(account.name, "ops");
+        assert!(account.password.is_none());
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_json_codec_parser_skips_decode() {
+        struct Typed(Arc<std::sync::atomic::AtomicUsize>);
+
+        impl crate::JsonCodec for Typed {
+            fn encode(&self, body: &serde_json::Value) -> Result<Vec<u8>> {
+                Ok(serde_json::to_vec(body)?)
+            }
+
+            fn decode(&self, body: &mut [u8]) -> Result<serde_json::Value> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                Ok(serde_json::from_slice(body)?)
+            }
+
+            fn parser(&self, _len: usize) -> Option<crate::JsonParser> {
+                Some(crate::JsonParser::SerdeJson)
+            }
+        }
+
+        #[derive(Deserialize)]
+        struct Data {
+            ok: bool,
+        }
+
+        let decoded = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let config = ClientConfig::new("http://localhost:1234", "test-token")
+            .with_json_codec(Typed(decoded.clone()));
+        let client = test_client(config);
+        let response = client
+            .execute_with::<Data, _, _>("query { ok }", None, None, |_, _| async {
+                Ok((StatusCode::OK, r#"{"data": {"ok": true}}"#.to_string()))
+            })
+            .await
+            .unwrap();
+        assert!(response.data.unwrap().ok);
+        assert_eq!(decoded.load(Ordering::SeqCst), 0);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_json_codec_parses_responses() {
+        struct Counting(Arc<std::sync::atomic::AtomicUsize>);
+
+        impl crate::JsonCodec for Counting {
+            fn encode(&self, body: &serde_json::Value) -> Result<Vec<u8>> {
+                Ok(serde_json::to_vec(body)?)
+            }
+
+            fn decode(&self, body: &mut [u8]) -> Result<serde_json::Value> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                Ok(serde_json::from_slice(body)?)
+            }
+        }
+
+        let decoded = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let config = ClientConfig::new("http://localhost:1234", "test-token")
+            .with_json_codec(Counting(decoded.clone()));
+        let client = test_client(config);
+        let response = client
+            .execute_with::<serde_json::Value, _, _>("query { ok }", None, None, |_, _| async {
+                Ok((StatusCode::OK, r#"{"data": {"ok": true}}"#.to_string()))
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.data.unwrap()["ok"], true);
+
+        let raw = client
+            .execute_with::<Box<serde_json::value::RawValue>, _, _>(
+                "query { ok }",
+                None,
+                None,
+                |_, _| async { Ok((StatusCode::OK, r#"{"data": {"ok": true}}"#.to_string())) },
+            )
+            .await
+            .unwrap();
+        assert_eq!(raw.data.unwrap().get(), r#"{"ok":true}"#);
+
+        let err = client
+            .execute_with::<serde_json::Value, _, _>("query { ok }", None, None, |_, _| async {
+                Ok((
+                    StatusCode::BAD_GATEWAY,
+                    "<html>bad gateway</html>".to_string(),
+                ))
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::GraphQl {
+                status: Some(502),
+                ..
+            }
+        ));
+        assert_eq!(decoded.load(Ordering::SeqCst), 3);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_execute_raw_parts_defers_data() {
         let config = ClientConfig::new("http://localhost:1234", "test-token");
         let client = test_client(config);
         let response = client
-            .execute_with::<serde_json::Value, _, _>(
+            .execute_with::<Box<serde_json::value::RawValue>, _, _>(
                 "query { ok }",
                 None,
-                Some("main"),
-                |url, body| async move {
-                    assert_eq!(url.path(), "/graphql/main");
-                    assert_eq!(body["query"], "query { ok }");
-                    Ok((StatusCode::OK, "{\"data\": {\"ok\": true}}".to_string()))
+                None,
+                |_, _| async {
+                    Ok((
+                        StatusCode::OK,
+                        r#"{"data": {"ok": true,  "n": 1.50}, "extensions": {"x": 1}}"#.to_string(),
+                    ))
                 },
             )
             .await
             .unwrap();
-
-        assert_eq!(response.data.unwrap()["ok"], true);
+        assert_eq!(response.extensions.as_ref().unwrap()["x"], 1);
+        assert_eq!(
+            response.data.as_ref().unwrap().get(),
+            r#"{"ok": true,  "n": 1.50}"#
+        );
+        let data: serde_json::Value = response.parse_data().unwrap().unwrap();
+        assert_eq!(data["ok"], true);
     }
 
     #[cfg_attr(miri, ignore)]
@@ -595,14 +1626,17 @@ mod tests {
         }
 
         let text = "{\"data\": {\"value\": 9}}".to_string();
-        let parsed = parse_graphql_response::<Data>(StatusCode::OK, text).unwrap();
+        let parsed =
+            parse_graphql_response::<Data>(StatusCode::OK, text, ErrorPolicy::Raise).unwrap();
         assert_eq!(parsed.data.unwrap().value, 9);
     }
 
     #[test]
     fn test_parse_graphql_response_graphql_error() {
         let text = "{\"data\": null, \"errors\": [{\"message\": \"boom\"}]}".to_string();
-        let err = parse_graphql_response::<serde_json::Value>(StatusCode::OK, text).unwrap_err();
+        let err =
+            parse_graphql_response::<serde_json::Value>(StatusCode::OK, text, ErrorPolicy::Raise)
+                .unwrap_err();
         assert!(matches!(
             err,
             Error::GraphQl {
@@ -615,8 +1649,12 @@ mod tests {
     #[test]
     fn test_parse_graphql_response_http_error() {
         let text = "{\"data\": null}".to_string();
-        let err =
-            parse_graphql_response::<serde_json::Value>(StatusCode::BAD_GATEWAY, text).unwrap_err();
+        let err = parse_graphql_response::<serde_json::Value>(
+            StatusCode::BAD_GATEWAY,
+            text,
+            ErrorPolicy::Raise,
+        )
+        .unwrap_err();
         assert!(matches!(
             err,
             Error::GraphQl {
@@ -676,6 +1714,7 @@ mod tests {
         assert_eq!(url.as_str(), "http://localhost:1234/graphql");
     }
 
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
     #[test]
     fn test_new_schemeless_url_gets_https() {
         let config = ClientConfig::new("infrahub.example.com", "token");
@@ -688,6 +1727,7 @@ mod tests {
         let err = parse_graphql_response::<serde_json::Value>(
             StatusCode::OK,
             "not json at all".to_string(),
+            ErrorPolicy::Raise,
         )
         .unwrap_err();
         assert!(matches!(err, Error::Json(_)));
@@ -696,9 +1736,12 @@ mod tests {
     #[test]
     fn test_parse_graphql_response_non_json_error_body() {
         let html = "<html><body><h1>502 Bad Gateway</h1></body></html>".to_string();
-        let err =
-            parse_graphql_response::<serde_json::Value>(StatusCode::BAD_GATEWAY, html.clone())
-                .unwrap_err();
+        let err = parse_graphql_response::<serde_json::Value>(
+            StatusCode::BAD_GATEWAY,
+            html.clone(),
+            ErrorPolicy::Raise,
+        )
+        .unwrap_err();
         match err {
             Error::GraphQl {
                 status,
@@ -717,12 +1760,109 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_decode_body() {
+        use flate2::write::GzEncoder;
+        use std::io::Write;
+
+        let sdl = "type Query { ok: Boolean }\n".repeat(100);
+        let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(sdl.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+        assert!(compressed.len() < sdl.len());
+
+        assert_eq!(decode_body(&compressed, true).unwrap(), sdl);
+        assert_eq!(decode_body(sdl.as_bytes(), false).unwrap(), sdl);
+        assert!(matches!(
+            decode_body(b"not gzip", true).unwrap_err(),
+            Error::Io(_)
+        ));
+        assert!(matches!(
+            decode_body(&[0xff, 0xfe], false).unwrap_err(),
+            Error::Config(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse_schema_hash() {
+        let text = r#"{"main": "abc123", "nodes": {}, "generics": {}}"#.to_string();
+        assert_eq!(parse_schema_hash(StatusCode::OK, text).unwrap(), "abc123");
+
+        let err = parse_schema_hash(StatusCode::OK, "{}".to_string()).unwrap_err();
+        assert!(matches!(err, Error::Config(_)));
+
+        let err = parse_schema_hash(StatusCode::NOT_FOUND, "not found".to_string()).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::GraphQl {
+                status: Some(404),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_graphql_response_error_policy() {
+        let partial = r#"{"data": {"InfraDevice": {"count": 1}, "CoreAccount": null}, "errors": [{"message": "permission denied", "path": ["CoreAccount"]}]}"#;
+
+        let err = parse_graphql_response::<serde_json::Value>(
+            StatusCode::OK,
+            partial.to_string(),
+            ErrorPolicy::Raise,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::GraphQl { .. }));
+
+        let all = parse_graphql_response::<serde_json::Value>(
+            StatusCode::OK,
+            partial.to_string(),
+            ErrorPolicy::All,
+        )
+        .unwrap();
+        assert_eq!(all.data.unwrap()["InfraDevice"]["count"], 1);
+        assert_eq!(all.errors.len(), 1);
+
+        let ignore = parse_graphql_response::<serde_json::Value>(
+            StatusCode::OK,
+            partial.to_string(),
+            ErrorPolicy::Ignore,
+        )
+        .unwrap();
+        assert!(ignore.data.is_some());
+        assert!(!ignore.has_errors());
+    }
+
+    #[test]
+    fn test_parse_graphql_response_error_policy_requires_data() {
+        let text = r#"{"data": null, "errors": [{"message": "boom"}]}"#;
+        for policy in [ErrorPolicy::All, ErrorPolicy::Ignore] {
+            let err = parse_graphql_response::<serde_json::Value>(
+                StatusCode::OK,
+                text.to_string(),
+                policy,
+            )
+            .unwrap_err();
+            assert!(matches!(err, Error::GraphQl { .. }));
+        }
+
+        let text = r#"{"data": {"ok": true}, "errors": [{"message": "boom"}]}"#;
+        let err = parse_graphql_response::<serde_json::Value>(
+            StatusCode::BAD_GATEWAY,
+            text.to_string(),
+            ErrorPolicy::All,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::GraphQl { .. }));
+    }
+
     #[test]
     fn test_parse_graphql_response_error_preserves_message() {
         let text =
             r#"{"data": null, "errors": [{"message": "field 'name' not found on type 'Device'"}]}"#
                 .to_string();
-        let err = parse_graphql_response::<serde_json::Value>(StatusCode::OK, text).unwrap_err();
+        let err =
+            parse_graphql_response::<serde_json::Value>(StatusCode::OK, text, ErrorPolicy::Raise)
+                .unwrap_err();
         match err {
             Error::GraphQl {
                 message, status, ..
@@ -737,8 +1877,73 @@ mod tests {
     #[test]
     fn test_build_multipart_form_with_no_variables() {
         let file = FileUpload::new("test.txt", "text/plain", b"hello".to_vec());
-        let form = build_multipart_form("mutation { upload }", None, vec![("file", file)]);
-        assert!(form.is_ok());
+        let form = build_multipart_form("mutation { upload }", None, vec![("file", file)]).unwrap();
+        let boundary = "infrahub-boundary-0000000000000000";
+        assert_eq!(
+            form.content_type,
+            format!("multipart/form-data; boundary={boundary}").as_str()
+        );
+        let body = String::from_utf8(form.body).unwrap();
+        assert!(body.starts_with(&format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"operations\"\r\n\r\n"
+        )));
+        assert!(body.contains(r#""variables":{"file":null}"#));
+        assert!(body.contains(r#"{"0":["variables.file"]}"#));
+        assert!(body.contains(
+            "name=\"0\"; filename=\"test.txt\"\r\nContent-Type: text/plain\r\n\r\nhello\r\n"
+        ));
+        assert!(body.ends_with(&format!("--{boundary}--\r\n")));
+    }
+
+    #[test]
+    fn test_build_multipart_form_avoids_boundary_in_data() {
+        let data = b"--infrahub-boundary-0000000000000000".to_vec();
+        let file = FileUpload::new("a\"b.txt", "text/plain", data);
+        let form = build_multipart_form("mutation { upload }", None, vec![("file", file)]).unwrap();
+        assert!(form
+            .content_type
+            .to_str()
+            .unwrap()
+            .ends_with("infrahub-boundary-0000000000000001"));
+        let body = String::from_utf8(form.body).unwrap();
+        assert!(body.contains("filename=\"a%22b.txt\""));
+
+        let file = FileUpload::new("a.txt", "text/plain\n", Vec::new());
+        assert!(build_multipart_form("mutation { upload }", None, vec![("file", file)]).is_err());
+    }
+
+    #[test]
+    fn test_build_multipart_form_escapes_names() {
+        let file = FileUpload::new("evil\"\r\nX-Injected: 1.txt", "text/plain", b"x".to_vec());
+        let form =
+            build_multipart_form("mutation { upload }", None, vec![("we\"ird\r\nvar", file)])
+                .unwrap();
+        let body = String::from_utf8(form.body).unwrap();
+        assert!(body.contains("name=\"0\"; filename=\"evil%22%0D%0AX-Injected: 1.txt\"\r\n"));
+        assert!(!body.contains("\r\nX-Injected"));
+        // variable paths only appear json-encoded in the operations and map parts
+        assert!(body.contains(r#"{"0":["variables.we\"ird\r\nvar"]}"#));
+        assert!(body
+            .lines()
+            .filter(|line| line.starts_with("Content-Disposition"))
+            .all(|line| !line.contains("we")));
+
+        assert_eq!(
+            disposition("a\"b\r\n", None),
+            "form-data; name=\"a%22b%0D%0A\""
+        );
+    }
+
+    #[test]
+    fn test_build_multipart_form_boundary_exhausted() {
+        let data = (0..MULTIPART_BOUNDARY_ATTEMPTS)
+            .flat_map(|n| format!("infrahub-boundary-{n:016x}").into_bytes())
+            .collect();
+        let file = FileUpload::new("a.txt", "text/plain", data);
+        let err = build_multipart_form("mutation { upload }", None, vec![("file", file)])
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::Config(_)));
     }
 
     #[test]
@@ -757,8 +1962,11 @@ mod tests {
             "mutation Upload($avatar: Upload!, $document: Upload!) { upload }",
             Some(serde_json::json!({"avatar": "placeholder", "document": "placeholder"})),
             files,
-        );
-        assert!(form.is_ok());
+        )
+        .unwrap();
+        let body = String::from_utf8_lossy(&form.body);
+        assert!(body.contains(r#"{"0":["variables.avatar"],"1":["variables.document"]}"#));
+        assert!(body.contains("name=\"1\"; filename=\"doc.pdf\"\r\nContent-Type: application/pdf"));
     }
 
     #[cfg_attr(miri, ignore)]
@@ -895,6 +2103,399 @@ mod tests {
         assert!(response.data.is_some());
     }
 
+    #[test]
+    fn test_fail_over_switches_token_and_fires_hook_once() {
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = events.clone();
+        let config = ClientConfig::new("http://localhost:1234", "primary")
+            .with_fallback_token("secondary")
+            .with_token_failover_hook(move |event| seen.lock().unwrap().push(event.status));
+        let client = Client::new(config).unwrap();
+        let url = client.config().graphql_url(None).unwrap();
+
+        let request = client.authorize(client.request(Method::GET, url.clone()), None);
+        assert!(request.headers.get(TOKEN_HEADER).is_none());
+
+        assert!(client.fail_over(StatusCode::UNAUTHORIZED, &url));
+        assert!(client.fail_over(StatusCode::UNAUTHORIZED, &url));
+        assert_eq!(*events.lock().unwrap(), vec![401]);
+
+        let request = client.authorize(client.request(Method::GET, url), None);
+        assert_eq!(request.headers.get(TOKEN_HEADER).unwrap(), "secondary");
+    }
+
+    #[test]
+    fn test_report_deprecations_calls_hook() {
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let config = ClientConfig::new("http://localhost:1234", "token")
+            .with_deprecation_hook(move |d| sink.lock().unwrap().push(d.message.clone()));
+        let client = test_client(config);
+
+        let extensions = serde_json::json!({"deprecations": ["a", "b"]});
+        let meta =
+            ResponseMeta::from_parts(StatusCode::OK, &HeaderMap::new(), Some(&extensions), None);
+        client.report_deprecations(&meta);
+        assert_eq!(*seen.lock().unwrap(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_log_query_redacts_variables() {
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let config = ClientConfig::new("http://localhost:1234", "token")
+            .with_default_branch("main")
+            .with_query_log_limit(20)
+            .with_query_log(move |entry| sink.lock().unwrap().push(entry.clone()));
+        let client = test_client(config);
+
+        let variables = serde_json::json!({"name": "a", "password": "hunter2"});
+        let ok: Result<()> = Ok(());
+        client.log_query(
+            "mutation AccountCreate($name: String, $password: String) { x }",
+            &variables,
+            None,
+            Instant::now(),
+            &ok,
+        );
+        let failed: Result<()> = Err(Error::Config("boom".to_string()));
+        client.log_query(
+            "{ x }",
+            &serde_json::json!({}),
+            Some("dev"),
+            Instant::now(),
+            &failed,
+        );
+
+        let entries = seen.lock().unwrap();
+        assert_eq!(entries[0].operation.as_deref(), Some("AccountCreate"));
+        assert_eq!(entries[0].branch.as_deref(), Some("main"));
+        assert_eq!(entries[0].query, "mutation AccountCrea...");
+        assert_eq!(
+            entries[0].variables,
+            Some(serde_json::json!({"name": "a", "password": crate::redact::REDACTED}))
+        );
+        assert!(entries[0].is_ok());
+        assert_eq!(entries[1].branch.as_deref(), Some("dev"));
+        assert_eq!(entries[1].variables, None);
+        assert!(entries[1].error.as_deref().unwrap().contains("boom"));
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_query_limits_fail_before_sending() {
+        // nothing listens on port 9, so a request that was sent fails with Http
+        let config = ClientConfig::new("http://127.0.0.1:9", "token")
+            .with_max_query_length(64)
+            .with_max_query_depth(2)
+            .with_max_retries(0);
+        let client = test_client(config);
+
+        let err = client
+            .execute_raw("query Deep { a { b { c } } }", None, None)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("query Deep selects 3 levels deep"));
+        let long = format!("query Long {{ {} }}", "field ".repeat(20));
+        let err = client.execute_raw(&long, None, None).await.unwrap_err();
+        assert!(matches!(err, Error::Config(_)));
+        let err = client
+            .execute_raw("{ a { b } }", None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Http(_)));
+    }
+
+    #[test]
+    fn test_slow_query_hook() {
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let config = ClientConfig::new("http://localhost:1234", "token")
+            .with_slow_query_threshold(Duration::from_millis(50))
+            .with_slow_query_hook(move |entry| sink.lock().unwrap().push(entry.operation.clone()));
+        let client = test_client(config);
+        let ok: Result<()> = Ok(());
+
+        client.log_query(
+            "query Fast { x }",
+            &serde_json::json!({}),
+            None,
+            Instant::now(),
+            &ok,
+        );
+        let started = Instant::now() - Duration::from_millis(60);
+        client.log_query(
+            "query Slow { x }",
+            &serde_json::json!({}),
+            None,
+            started,
+            &ok,
+        );
+        assert_eq!(*seen.lock().unwrap(), vec![Some("Slow".to_string())]);
+    }
+
+    /// answer every request on a loopback port with 200 if it carries
+    /// `accepted` as its token and 401 otherwise, recording the tokens seen
+    async fn serve_token_check(
+        accepted: &'static str,
+    ) -> (String, Arc<std::sync::Mutex<Vec<String>>>) {
+        use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let tokens = seen.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let tokens = tokens.clone();
+                tokio::spawn(async move {
+                    let (read, mut write) = stream.into_split();
+                    let mut reader = BufReader::new(read);
+                    loop {
+                        let (mut length, mut token) = (0, String::new());
+                        // request line, then headers up to a blank line
+                        let mut line = String::new();
+                        if reader.read_line(&mut line).await.unwrap_or(0) == 0 {
+                            return;
+                        }
+                        loop {
+                            line.clear();
+                            reader.read_line(&mut line).await.unwrap();
+                            let Some((name, value)) = line.trim_end().split_once(": ") else {
+                                break;
+                            };
+                            if name.eq_ignore_ascii_case("content-length") {
+                                length = value.parse().unwrap();
+                            } else if name.eq_ignore_ascii_case(TOKEN_HEADER) {
+                                token = value.to_string();
+                            }
+                        }
+                        let mut body = vec![0; length];
+                        reader.read_exact(&mut body).await.unwrap();
+                        let (status, body) = if token == accepted {
+                            ("200 OK", r#"{"data":{"ok":true}}"#)
+                        } else {
+                            ("401 Unauthorized", r#"{"errors":[{"message":"denied"}]}"#)
+                        };
+                        tokens.lock().unwrap().push(token);
+                        let response = format!(
+                            "HTTP/1.1 {status}\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{body}",
+                            body.len()
+                        );
+                        write.write_all(response.as_bytes()).await.unwrap();
+                    }
+                });
+            }
+        });
+        (url, seen)
+    }
+
+    /// answer GET requests on a loopback port with the body of the first
+    /// route whose path prefix matches, recording the paths requested
+    async fn serve_paths(
+        routes: &'static [(&'static str, &'static str)],
+    ) -> (String, Arc<std::sync::Mutex<Vec<String>>>) {
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let paths = seen.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let paths = paths.clone();
+                tokio::spawn(async move {
+                    let (read, mut write) = stream.into_split();
+                    let mut reader = BufReader::new(read);
+                    loop {
+                        let mut request = String::new();
+                        if reader.read_line(&mut request).await.unwrap_or(0) == 0 {
+                            return;
+                        }
+                        let mut line = String::new();
+                        while reader.read_line(&mut line).await.unwrap_or(0) > 2 {
+                            line.clear();
+                        }
+                        let path = request.split(' ').nth(1).unwrap_or("").to_string();
+                        let body = routes
+                            .iter()
+                            .find(|(prefix, _)| path.starts_with(prefix))
+                            .map_or("", |(_, body)| body);
+                        paths.lock().unwrap().push(path);
+                        let response = format!(
+                            "HTTP/1.1 200 OK\r\ncontent-length: {}\r\n\r\n{body}",
+                            body.len()
+                        );
+                        write.write_all(response.as_bytes()).await.unwrap();
+                    }
+                });
+            }
+        });
+        (url, seen)
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_fetch_schema_if_changed() {
+        let (url, seen) = serve_paths(&[
+            ("/api/schema/summary", r#"{"main": "hash-2"}"#),
+            ("/schema.graphql", "type Query { ok: Boolean }"),
+        ])
+        .await;
+        let client = Client::new(ClientConfig::new(url, "token")).unwrap();
+
+        let fetched = client
+            .fetch_schema_if_changed(None, Some("hash-1"))
+            .await
+            .unwrap();
+        assert_eq!(fetched.hash(), "hash-2");
+        assert_eq!(fetched.schema(), Some("type Query { ok: Boolean }"));
+
+        let unchanged = client
+            .fetch_schema_if_changed(None, Some(fetched.hash()))
+            .await
+            .unwrap();
+        assert_eq!(
+            unchanged,
+            SchemaFetch::Unchanged {
+                hash: "hash-2".to_string()
+            }
+        );
+        let downloads = seen
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|path| path.starts_with("/schema.graphql"))
+            .count();
+        assert_eq!(downloads, 1);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_check_schema() {
+        use crate::transport::{Transport, TransportFuture, TransportRequest, TransportResponse};
+
+        /// accepts schemas with nodes and rejects empty ones
+        struct Checker;
+
+        impl Transport for Checker {
+            fn send(&self, request: TransportRequest) -> TransportFuture<'_> {
+                assert_eq!(request.url.path(), "/api/schema/check");
+                assert_eq!(request.url.query(), Some("branch=b1"));
+                let body: serde_json::Value = serde_json::from_str(&request.body_text()).unwrap();
+                let response = if body["schemas"][0]["nodes"]
+                    .as_array()
+                    .is_some_and(|n| !n.is_empty())
+                {
+                    TransportResponse::new(
+                        StatusCode::ACCEPTED,
+                        r#"{"diff": {"added": {"TestWidget": {}}}}"#,
+                    )
+                } else {
+                    TransportResponse::new(
+                        StatusCode::UNPROCESSABLE_ENTITY,
+                        r#"{"errors": [{"message": "no nodes"}]}"#,
+                    )
+                };
+                Box::pin(async move { Ok(response) })
+            }
+        }
+
+        let config = ClientConfig::new("http://infrahub.test", "token")
+            .with_version_check(VersionCheck::Off)
+            .with_max_retries(0)
+            .with_transport(Checker);
+        let client = Client::new(config).unwrap();
+
+        let schema = serde_json::json!({ "version": "1.0", "nodes": [{ "name": "Widget" }] });
+        let diff = client.check_schema(&[schema], Some("b1")).await.unwrap();
+        assert!(diff["added"]["TestWidget"].is_object());
+
+        let empty = serde_json::json!({ "version": "1.0", "nodes": [] });
+        let err = client.check_schema(&[empty], Some("b1")).await.unwrap_err();
+        assert!(matches!(
+            err,
+            Error::GraphQl {
+                status: Some(422),
+                ..
+            }
+        ));
+        assert!(err.to_string().contains("no nodes"));
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_with_resolve_pins_host() {
+        let (url, seen) = serve_token_check("token").await;
+        let port = url.rsplit(':').next().unwrap();
+        let config = ClientConfig::new(format!("http://infrahub.invalid:{port}"), "token")
+            .with_max_retries(0)
+            .with_version_check(VersionCheck::Off)
+            .with_no_proxy()
+            .with_resolve("Infrahub.Invalid", std::net::Ipv4Addr::LOCALHOST);
+        let client = Client::new(config).unwrap();
+        let response = client
+            .execute_raw("query { ok }", None, None)
+            .await
+            .unwrap();
+        assert_eq!(response.data.unwrap()["ok"], true);
+        assert_eq!(seen.lock().unwrap().len(), 1);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_token_source_reloads_on_unauthorized() {
+        let (url, seen) = serve_token_check("rotated").await;
+        let path =
+            std::env::temp_dir().join(format!("infrahub-client-token-{}", std::process::id()));
+        std::fs::write(&path, "initial").unwrap();
+        let config = ClientConfig::new(url, "")
+            .with_max_retries(0)
+            .with_version_check(VersionCheck::Off)
+            .with_token_source(crate::TokenSource::file(&path));
+        let client = Client::new(config).unwrap();
+
+        std::fs::write(&path, "rotated\n").unwrap();
+        let response = client
+            .execute_raw("query { ok }", None, None)
+            .await
+            .unwrap();
+        assert_eq!(response.data.unwrap()["ok"], true);
+        client
+            .execute_raw("query { ok }", None, None)
+            .await
+            .unwrap();
+        assert_eq!(*seen.lock().unwrap(), ["initial", "rotated", "rotated"]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_api_flavor_prefers_negotiated() {
+        let config =
+            ClientConfig::new("http://localhost:1234", "token").with_api_flavor(ApiFlavor::Legacy);
+        let client = test_client(config);
+        assert_eq!(client.api_flavor(), ApiFlavor::Legacy);
+        let clone = client.clone();
+        *client.negotiated_flavor.lock().unwrap() = Some(ApiFlavor::Current);
+        assert_eq!(clone.api_flavor(), ApiFlavor::Current);
+    }
+
+    #[test]
+    fn test_fail_over_without_fallback_token() {
+        let client = Client::new(ClientConfig::new("http://localhost:1234", "primary")).unwrap();
+        let url = client.config().graphql_url(None).unwrap();
+        assert!(!client.fail_over(StatusCode::UNAUTHORIZED, &url));
+    }
+
+    #[test]
+    fn test_invalid_fallback_token_header() {
+        let config =
+            ClientConfig::new("http://localhost:1234", "token").with_fallback_token("bad\ntoken");
+        let err = Client::new(config).err().expect("expected error");
+        assert!(matches!(err, Error::Config(_)));
+    }
+
     #[test]
     fn test_retry_delay_backoff() {
         assert_eq!(Client::retry_delay(0), Duration::from_millis(0));
@@ -1026,6 +2627,35 @@ mod tests {
         );
     }
 
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_retry_loop_does_not_retry_not_implemented() {
+        let config = ClientConfig::new("http://localhost:1234", "test-token").with_max_retries(3);
+        let client = test_client(config);
+        let call_count = std::sync::Arc::new(std::sync::atomic::AtomicU32::new(0));
+        let count = call_count.clone();
+        let result: Result<String> = client
+            .retry_loop(|| {
+                let count = count.clone();
+                async move {
+                    count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    Err(Error::GraphQl {
+                        status: Some(501),
+                        errors: vec![],
+                        body: String::new(),
+                        message: "not implemented".to_string(),
+                    })
+                }
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(
+            call_count.load(std::sync::atomic::Ordering::SeqCst),
+            1,
+            "501 should not be retried"
+        );
+    }
+
     #[cfg_attr(miri, ignore)]
     #[tokio::test]
     async fn test_retry_loop_disabled_with_zero_retries() {
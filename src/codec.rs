@@ -0,0 +1,228 @@
+//! json codecs
+//!
+//! swap the json implementation used for graphql request and response
+//! bodies, e.g. to parse very large exports with a simd parser.
+
+use crate::error::Result;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// serializer/deserializer pair for graphql bodies
+///
+/// without a codec the client serializes requests with `serde_json` and
+/// parses responses straight into the target type. a codec that names a
+/// built-in [`JsonParser`] keeps that: responses are decoded straight into the
+/// target type by the parser. otherwise responses are decoded into a
+/// [`Value`] by the codec first, then converted to the target type, so the
+/// codec carries the cost of tokenizing the body.
+///
+/// ```
+/// use infrahub::{JsonCodec, Result};
+/// use serde_json::Value;
+///
+/// struct SerdeJson;
+///
+/// impl JsonCodec for SerdeJson {
+///     fn encode(&self, body: &Value) -> Result<Vec<u8>> {
+///         Ok(serde_json::to_vec(body)?)
+///     }
+///
+///     fn decode(&self, body: &mut [u8]) -> Result<Value> {
+///         Ok(serde_json::from_slice(body)?)
+///     }
+/// }
+/// ```
+pub trait JsonCodec: Send + Sync {
+    /// serialize a request body
+    fn encode(&self, body: &Value) -> Result<Vec<u8>>;
+
+    /// parse a response body
+    ///
+    /// the buffer is a private copy, so parsers that work in place (such as
+    /// simd-json) may overwrite it.
+    fn decode(&self, body: &mut [u8]) -> Result<Value>;
+
+    /// the built-in parser for a response body of `len` bytes, if any
+    ///
+    /// when this returns a parser, the body is decoded straight into the
+    /// requested type and [`JsonCodec::decode`] is skipped. the default
+    /// returns `None`, which keeps the [`Value`] path.
+    fn parser(&self, _len: usize) -> Option<JsonParser> {
+        None
+    }
+}
+
+/// a parser the client runs directly on response bodies, decoding into the
+/// requested type without building a [`Value`] first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum JsonParser {
+    /// `serde_json`
+    SerdeJson,
+    /// simd-json, falling back to `serde_json` for bodies or types it
+    /// rejects (such as `RawValue`); requires the `simd-json` feature
+    #[cfg(feature = "simd-json")]
+    SimdJson,
+}
+
+impl JsonParser {
+    /// parse `text` into `T`
+    pub(crate) fn parse<T: DeserializeOwned>(self, text: &str) -> Result<T> {
+        match self {
+            JsonParser::SerdeJson => Ok(serde_json::from_str(text)?),
+            #[cfg(feature = "simd-json")]
+            JsonParser::SimdJson => {
+                // simd-json overwrites its input, and the text is still
+                // needed for error bodies and the fallback
+                let mut bytes = text.as_bytes().to_vec();
+                simd_json::serde::from_slice(&mut bytes)
+                    .or_else(|_| Ok(serde_json::from_str(text)?))
+            }
+        }
+    }
+}
+
+/// default body size from which [`SimdJson`] takes over from `serde_json`
+#[cfg(feature = "simd-json")]
+const DEFAULT_SIMD_THRESHOLD: usize = 16 * 1024;
+
+/// [`JsonCodec`] parsing large response bodies with simd-json
+///
+/// requires the `simd-json` feature. bodies below the threshold (16 KiB by
+/// default) are still parsed by `serde_json`, which is faster for small
+/// inputs; requests are always encoded with `serde_json`. both decode
+/// straight into the requested type (see [`JsonParser`]).
+#[cfg(feature = "simd-json")]
+#[derive(Debug, Clone, Copy)]
+pub struct SimdJson {
+    threshold: usize,
+}
+
+#[cfg(feature = "simd-json")]
+impl SimdJson {
+    /// a codec switching to simd-json from 16 KiB
+    pub fn new() -> Self {
+        Self {
+            threshold: DEFAULT_SIMD_THRESHOLD,
+        }
+    }
+
+    /// switch to simd-json for bodies of at least `bytes`
+    pub fn with_threshold(mut self, bytes: usize) -> Self {
+        self.threshold = bytes;
+        self
+    }
+}
+
+#[cfg(feature = "simd-json")]
+impl Default for SimdJson {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "simd-json")]
+impl JsonCodec for SimdJson {
+    fn encode(&self, body: &Value) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(body)?)
+    }
+
+    fn decode(&self, body: &mut [u8]) -> Result<Value> {
+        if body.len() < self.threshold {
+            return Ok(serde_json::from_slice(body)?);
+        }
+        simd_json::serde::from_slice(body)
+            .map_err(|err| <serde_json::Error as serde::de::Error>::custom(err).into())
+    }
+
+    fn parser(&self, len: usize) -> Option<JsonParser> {
+        if len < self.threshold {
+            Some(JsonParser::SerdeJson)
+        } else {
+            Some(JsonParser::SimdJson)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "simd-json"))]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_simd_json_decode() {
+        let body = json!({
+            "data": { "BuiltinTag": { "count": 1, "edges": [{ "node": { "id": "t1", "name": "é" } }] } },
+            "errors": [],
+        });
+        let encoded = serde_json::to_vec(&body).unwrap();
+        for codec in [SimdJson::new(), SimdJson::new().with_threshold(0)] {
+            let mut bytes = encoded.clone();
+            assert_eq!(codec.decode(&mut bytes).unwrap(), body);
+        }
+
+        let mut invalid = b"{\"data\": ".to_vec();
+        let err = SimdJson::new()
+            .with_threshold(0)
+            .decode(&mut invalid)
+            .unwrap_err();
+        assert!(matches!(err, crate::Error::Json(_)));
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_simd_json_client() {
+        let config = crate::ClientConfig::new("http://infrahub.mock", "mock-token")
+            .with_json_codec(SimdJson::new().with_threshold(0));
+        let mock = crate::testing::MockClient::with_config(config);
+        mock.expect("Tags")
+            .returning(json!({ "BuiltinTag": { "count": 3 } }));
+        let response = mock
+            .client()
+            .execute_raw("query Tags { BuiltinTag { count } }", None, None)
+            .await
+            .unwrap();
+        assert_eq!(response.data.unwrap()["BuiltinTag"]["count"], 3);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_simd_json_typed_and_raw() {
+        #[derive(serde::Deserialize)]
+        struct Tags {
+            #[serde(rename = "BuiltinTag")]
+            tags: TagCount,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct TagCount {
+            count: u32,
+        }
+
+        let codec = SimdJson::new().with_threshold(0);
+        assert_eq!(codec.parser(0), Some(JsonParser::SimdJson));
+        assert_eq!(SimdJson::new().parser(100), Some(JsonParser::SerdeJson));
+
+        let config =
+            crate::ClientConfig::new("http://infrahub.mock", "mock-token").with_json_codec(codec);
+        let mock = crate::testing::MockClient::with_config(config);
+        mock.expect("Tags")
+            .times(2)
+            .returning(json!({ "BuiltinTag": { "count": 3 } }));
+        let query = "query Tags { BuiltinTag { count } }";
+        let typed = mock
+            .client()
+            .execute::<Tags>(query, None, None)
+            .await
+            .unwrap();
+        assert_eq!(typed.data.unwrap().tags.count, 3);
+
+        // simd-json cannot produce a RawValue, so these fall back to serde_json
+        let raw = mock
+            .client()
+            .execute_raw_parts(query, None, None)
+            .await
+            .unwrap();
+        assert_eq!(raw.data.unwrap().get(), r#"{"BuiltinTag":{"count":3}}"#);
+    }
+}
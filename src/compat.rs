@@ -0,0 +1,225 @@
+//! compatibility matrix
+//!
+//! the infrahub release series this crate is tested against (mirroring
+//! `docs/compat.md`), and the runtime check of a server's
+//! `InfrahubInfo.version` against it.
+
+use crate::error::{Error, Result};
+use std::fmt;
+use std::sync::Arc;
+
+/// callback invoked with the outcome of a failed version check in warn mode
+pub(crate) type VersionCheckHook = Arc<dyn Fn(&Error) + Send + Sync>;
+
+/// infrahub release series this crate is tested against
+pub const SUPPORTED_VERSIONS: VersionRange = VersionRange {
+    min: (1, 9),
+    max: (1, 9),
+};
+
+/// an inclusive range of infrahub `major.minor` release series
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VersionRange {
+    /// oldest supported `(major, minor)`
+    pub min: (u32, u32),
+    /// newest supported `(major, minor)`
+    pub max: (u32, u32),
+}
+
+impl VersionRange {
+    /// true if `version` (such as `1.9.8` or `v1.9.0rc1`) falls in the range
+    ///
+    /// returns `None` when the version does not start with `major.minor`.
+    pub fn contains(&self, version: &str) -> Option<bool> {
+        let release = major_minor(version)?;
+        Some(self.min <= release && release <= self.max)
+    }
+}
+
+impl fmt::Display for VersionRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (min_major, min_minor) = self.min;
+        let (max_major, max_minor) = self.max;
+        if self.min == self.max {
+            write!(f, "{min_major}.{min_minor}.x")
+        } else {
+            write!(f, "{min_major}.{min_minor}.x to {max_major}.{max_minor}.x")
+        }
+    }
+}
+
+/// what the client does when the server version is outside
+/// [`SUPPORTED_VERSIONS`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VersionCheck {
+    /// pass the mismatch to the version check hook once and carry on
+    Warn,
+    /// fail requests with [`Error::UnsupportedVersion`]
+    Strict,
+    /// skip the check and the `InfrahubInfo` request behind it
+    #[default]
+    Off,
+}
+
+/// the `(major, minor)` of a version such as `1.8.5` or `v1.10.0rc1`
+pub(crate) fn major_minor(version: &str) -> Option<(u32, u32)> {
+    let version = version.trim().trim_start_matches('v');
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts
+        .next()?
+        .split(|c: char| !c.is_ascii_digit())
+        .next()?
+        .parse()
+        .ok()?;
+    Some((major, minor))
+}
+
+/// check a reported server version against [`SUPPORTED_VERSIONS`]
+///
+/// unsupported and unrecognized versions fail with
+/// [`Error::UnsupportedVersion`].
+pub(crate) fn check_version(version: &str) -> Result<()> {
+    match SUPPORTED_VERSIONS.contains(version) {
+        Some(true) => Ok(()),
+        _ => Err(Error::UnsupportedVersion {
+            version: version.to_string(),
+            supported: SUPPORTED_VERSIONS,
+        }),
+    }
+}
+
+/// the error for a server that did not report its version
+pub(crate) fn missing_version() -> Error {
+    Error::Config("server did not report an infrahub version".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Client;
+    use crate::config::ClientConfig;
+    use crate::transport::{Transport, TransportFuture, TransportRequest, TransportResponse};
+    use reqwest::StatusCode;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_major_minor() {
+        assert_eq!(major_minor("1.9.8"), Some((1, 9)));
+        assert_eq!(major_minor("v1.10.0rc1"), Some((1, 10)));
+        assert_eq!(major_minor("1.7rc1"), Some((1, 7)));
+        assert_eq!(major_minor("main"), None);
+        assert_eq!(major_minor("1"), None);
+    }
+
+    #[test]
+    fn test_version_range() {
+        let range = VersionRange {
+            min: (1, 8),
+            max: (1, 9),
+        };
+        assert_eq!(range.contains("1.8.0"), Some(true));
+        assert_eq!(range.contains("1.9.8"), Some(true));
+        assert_eq!(range.contains("1.10.0"), Some(false));
+        assert_eq!(range.contains("1.7.9"), Some(false));
+        assert_eq!(range.contains("dev"), None);
+        assert_eq!(range.to_string(), "1.8.x to 1.9.x");
+        assert_eq!(SUPPORTED_VERSIONS.to_string(), "1.9.x");
+    }
+
+    #[test]
+    fn test_check_version() {
+        assert!(check_version("1.9.8").is_ok());
+        let err = check_version("1.1.0").unwrap_err();
+        assert!(matches!(err, Error::UnsupportedVersion { .. }));
+        assert_eq!(
+            err.to_string(),
+            "infrahub 1.1.0 is not supported; this client supports 1.9.x"
+        );
+        assert!(check_version("main").is_err());
+    }
+
+    /// reports `version` for `InfrahubInfo` and answers everything else
+    /// with `{"ok": true}`, counting version requests
+    struct Server {
+        version: &'static str,
+        info_requests: Arc<AtomicUsize>,
+    }
+
+    impl Transport for Server {
+        fn send(&self, request: TransportRequest) -> TransportFuture<'_> {
+            let body = if request.body_text().contains("InfrahubInfo") {
+                self.info_requests.fetch_add(1, Ordering::SeqCst);
+                format!(
+                    r#"{{"data": {{"InfrahubInfo": {{"version": "{}"}}}}}}"#,
+                    self.version
+                )
+            } else {
+                r#"{"data": {"ok": true}}"#.to_string()
+            };
+            Box::pin(async move { Ok(TransportResponse::new(StatusCode::OK, body)) })
+        }
+    }
+
+    fn serving(version: &'static str, check: VersionCheck) -> (Client, Arc<AtomicUsize>) {
+        let info_requests = Arc::new(AtomicUsize::new(0));
+        let config = ClientConfig::new("http://localhost:1234", "token")
+            .with_version_check(check)
+            .with_transport(Server {
+                version,
+                info_requests: info_requests.clone(),
+            });
+        (Client::new(config).unwrap(), info_requests)
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_version_checked_once() {
+        let (client, info_requests) = serving("1.9.8", VersionCheck::Strict);
+        for _ in 0..3 {
+            client
+                .execute_raw("query { ok }", None, None)
+                .await
+                .unwrap();
+        }
+        assert_eq!(info_requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_unsupported_version() {
+        let (client, _) = serving("1.1.0", VersionCheck::Strict);
+        let err = client
+            .execute_raw("query { ok }", None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::UnsupportedVersion { ref version, .. } if version == "1.1.0"));
+
+        let warnings = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = warnings.clone();
+        let config = ClientConfig::new("http://localhost:1234", "token")
+            .with_version_check(VersionCheck::Warn)
+            .with_version_check_hook(move |err| sink.lock().unwrap().push(err.to_string()))
+            .with_transport(Server {
+                version: "1.1.0",
+                info_requests: Arc::new(AtomicUsize::new(0)),
+            });
+        let client = Client::new(config).unwrap();
+        for _ in 0..2 {
+            assert!(client.execute_raw("query { ok }", None, None).await.is_ok());
+        }
+        let warnings = warnings.lock().unwrap().clone();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("1.1.0"));
+
+        let (client, info_requests) = serving("1.1.0", VersionCheck::Off);
+        assert!(client.execute_raw("query { ok }", None, None).await.is_ok());
+        assert_eq!(info_requests.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_version_check_off_by_default() {
+        assert_eq!(VersionCheck::default(), VersionCheck::Off);
+    }
+}
@@ -0,0 +1,574 @@
+//! config files
+//!
+//! load a [`ClientConfig`] from a toml or yaml file, merged with environment
+//! overrides, so tools can share one place for connection settings.
+
+use crate::auth::TokenSource;
+use crate::config::ClientConfig;
+use crate::error::{Error, Result};
+use crate::tls::{ClientIdentity, RootCertificate, TlsVersion};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// environment variable naming the config file for `ClientConfig::discover`
+const CONFIG_ENV: &str = "INFRAHUB_CONFIG";
+
+/// url used by `ClientConfig::from_env` when neither a file nor
+/// `INFRAHUB_URL` names one
+const DEFAULT_URL: &str = "http://localhost:8000";
+
+/// connection settings as written in a config file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct FileConfig {
+    url: Option<String>,
+    token: Option<String>,
+    token_env: Option<String>,
+    token_file: Option<PathBuf>,
+    token_command: Option<Vec<String>>,
+    branch: Option<String>,
+    timeout_secs: Option<u64>,
+    connect_timeout_secs: Option<u64>,
+    read_timeout_secs: Option<u64>,
+    max_retries: Option<u32>,
+    proxy: Option<String>,
+    path_prefix: Option<String>,
+    tls: TlsSettings,
+    profiles: BTreeMap<String, FileConfig>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct TlsSettings {
+    verify: Option<bool>,
+    require_https: Option<bool>,
+    min_version: Option<String>,
+    identity: Option<PathBuf>,
+    ca: Option<PathBuf>,
+}
+
+impl ClientConfig {
+    /// load a configuration from a toml or yaml file
+    ///
+    /// the format follows the extension: `.yaml`/`.yml` (requires the `yaml`
+    /// feature), anything else is read as toml. recognized keys are `url`,
+    /// `token`, `token_env` (the name of an environment variable holding the
+    /// token), `token_file` (a file holding the token), or `token_command` (a
+    /// command and its arguments printing the token, see
+    /// [`TokenSource::command`]), `branch`, `timeout_secs`,
+    /// `connect_timeout_secs`, `read_timeout_secs`, `max_retries`, `proxy` (a
+    /// proxy url, or `""` to
+    /// ignore proxy environment variables), `path_prefix` (for instances
+    /// served under a sub-path), and a `tls` table with `verify`,
+    /// `require_https`, `min_version` (`"1.2"` or `"1.3"`), `identity` (a pem
+    /// file with a client certificate and key for mutual tls), and `ca` (a pem
+    /// or der file with extra certificate authorities to trust).
+    ///
+    /// `INFRAHUB_URL`, `INFRAHUB_TOKEN`, `INFRAHUB_BRANCH`, and
+    /// `INFRAHUB_TIMEOUT` (seconds) override the file when set.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let file: FileConfig = read_config_file(path.as_ref())?;
+        file.into_config(&|name| std::env::var(name).ok())
+    }
+
+    /// load the configuration from the first config file found
+    ///
+    /// looks at the path in `INFRAHUB_CONFIG`, then `infrahubctl.toml` in the
+    /// working directory, then `config.toml`, `config.yml`, and `config.yaml`
+    /// in `~/.infrahub`. see [`ClientConfig::from_file`] for the format.
+    pub fn discover() -> Result<Self> {
+        let path = discover_path(&|name| std::env::var(name).ok())?;
+        Self::from_file(path)
+    }
+
+    /// load the configuration from the environment
+    ///
+    /// like [`ClientConfig::discover`] when a config file is found; without
+    /// one, `INFRAHUB_URL` (default `http://localhost:8000`),
+    /// `INFRAHUB_TOKEN` (required), `INFRAHUB_BRANCH`, and `INFRAHUB_TIMEOUT`
+    /// alone make up the configuration.
+    pub fn from_env() -> Result<Self> {
+        from_env_with(&|name| std::env::var(name).ok())
+    }
+}
+
+impl ClientConfig {
+    /// load a named profile from the discovered config file
+    ///
+    /// profiles are `[profiles.<name>]` tables holding the same keys as the
+    /// top level; keys a profile leaves out fall back to the top-level values.
+    /// see [`ClientConfig::discover`] for where the file is looked up.
+    pub fn from_profile(name: &str) -> Result<Self> {
+        let path = discover_path(&|name| std::env::var(name).ok())?;
+        Self::from_file_profile(path, name)
+    }
+
+    /// load a named profile from a config file
+    pub fn from_file_profile(path: impl AsRef<Path>, name: &str) -> Result<Self> {
+        let file: FileConfig = read_config_file(path.as_ref())?;
+        file.profile(name)?
+            .into_config(&|name| std::env::var(name).ok())
+    }
+}
+
+impl FileConfig {
+    /// the settings of profile `name`, layered over the top-level settings
+    pub(crate) fn profile(mut self, name: &str) -> Result<FileConfig> {
+        let profile = self.profiles.remove(name).ok_or_else(|| {
+            let known: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+            Error::Config(format!(
+                "unknown config profile {name:?} (available: {})",
+                known.join(", ")
+            ))
+        })?;
+        if !profile.profiles.is_empty() {
+            return Err(Error::Config(format!(
+                "config profile {name:?} cannot define nested profiles"
+            )));
+        }
+        // any token setting in the profile replaces all inherited ones
+        let (token, token_env, token_file, token_command) = if profile.token.is_some()
+            || profile.token_env.is_some()
+            || profile.token_file.is_some()
+            || profile.token_command.is_some()
+        {
+            (
+                profile.token,
+                profile.token_env,
+                profile.token_file,
+                profile.token_command,
+            )
+        } else {
+            (
+                self.token,
+                self.token_env,
+                self.token_file,
+                self.token_command,
+            )
+        };
+        Ok(FileConfig {
+            url: profile.url.or(self.url),
+            token,
+            token_env,
+            token_file,
+            token_command,
+            branch: profile.branch.or(self.branch),
+            timeout_secs: profile.timeout_secs.or(self.timeout_secs),
+            connect_timeout_secs: profile.connect_timeout_secs.or(self.connect_timeout_secs),
+            read_timeout_secs: profile.read_timeout_secs.or(self.read_timeout_secs),
+            max_retries: profile.max_retries.or(self.max_retries),
+            proxy: profile.proxy.or(self.proxy),
+            path_prefix: profile.path_prefix.or(self.path_prefix),
+            tls: TlsSettings {
+                verify: profile.tls.verify.or(self.tls.verify),
+                require_https: profile.tls.require_https.or(self.tls.require_https),
+                min_version: profile.tls.min_version.or(self.tls.min_version),
+                identity: profile.tls.identity.or(self.tls.identity),
+                ca: profile.tls.ca.or(self.tls.ca),
+            },
+            profiles: BTreeMap::new(),
+        })
+    }
+
+    /// build a client configuration, letting `env` override file values
+    pub(crate) fn into_config(self, env: &dyn Fn(&str) -> Option<String>) -> Result<ClientConfig> {
+        let env = |name: &str| env(name).filter(|value| !value.is_empty());
+
+        let url = env("INFRAHUB_URL")
+            .or(self.url)
+            .ok_or_else(|| Error::Config("config file has no url".to_string()))?;
+        let source = match (self.token_file, self.token_command) {
+            (Some(_), Some(_)) => {
+                return Err(Error::Config(
+                    "token_file and token_command are mutually exclusive".to_string(),
+                ))
+            }
+            (Some(path), None) => Some(TokenSource::file(path)),
+            (None, Some(command)) => {
+                let (program, args) = command
+                    .split_first()
+                    .ok_or_else(|| Error::Config("token_command is empty".to_string()))?;
+                Some(TokenSource::command(program.clone(), args.to_vec()))
+            }
+            (None, None) => None,
+        };
+        let token = match (env("INFRAHUB_TOKEN"), self.token_env, self.token) {
+            (Some(token), _, _) => token,
+            (None, Some(name), _) => env(&name).ok_or_else(|| {
+                Error::Config(format!("token environment variable {name} is not set"))
+            })?,
+            (None, None, Some(token)) => token,
+            (None, None, None) if source.is_some() => String::new(),
+            (None, None, None) => {
+                return Err(Error::Config("config file has no token".to_string()))
+            }
+        };
+        let source = source.filter(|_| token.is_empty());
+
+        let mut config = ClientConfig::new(url, token);
+        if let Some(source) = source {
+            config = config.with_token_source(source);
+        }
+        if let Some(branch) = env("INFRAHUB_BRANCH").or(self.branch) {
+            config = config.with_default_branch(branch);
+        }
+        let timeout = match env("INFRAHUB_TIMEOUT") {
+            Some(secs) => Some(secs.parse::<u64>().map_err(|_| {
+                Error::Config(format!("INFRAHUB_TIMEOUT must be whole seconds: {secs:?}"))
+            })?),
+            None => self.timeout_secs,
+        };
+        if let Some(secs) = timeout {
+            config = config.with_timeout(Duration::from_secs(secs));
+        }
+        if let Some(secs) = self.connect_timeout_secs {
+            config = config.with_connect_timeout(Duration::from_secs(secs));
+        }
+        if let Some(secs) = self.read_timeout_secs {
+            config = config.with_read_timeout(Duration::from_secs(secs));
+        }
+        if let Some(retries) = self.max_retries {
+            config = config.with_max_retries(retries);
+        }
+        match self.proxy.as_deref() {
+            Some("") => config = config.with_no_proxy(),
+            Some(proxy) => config = config.with_proxy(proxy),
+            None => {}
+        }
+        if let Some(prefix) = self.path_prefix {
+            config = config.with_path_prefix(prefix);
+        }
+        if let Some(verify) = self.tls.verify {
+            config = config.with_ssl_verification(verify);
+        }
+        if let Some(require) = self.tls.require_https {
+            config = config.with_require_https(require);
+        }
+        if let Some(version) = self.tls.min_version {
+            config = config.with_min_tls_version(parse_tls_version(&version)?);
+        }
+        if let Some(path) = self.tls.identity {
+            config = config.with_client_identity(ClientIdentity::from_pem_file(path)?);
+        }
+        if let Some(path) = self.tls.ca {
+            config = config.with_root_certificate(RootCertificate::from_file(path)?);
+        }
+        Ok(config)
+    }
+}
+
+/// parse a config file by extension
+pub(crate) fn read_config_file<T>(path: &Path) -> Result<T>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    let text = std::fs::read_to_string(path)?;
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    match extension.as_str() {
+        "yaml" | "yml" => parse_yaml(&text),
+        _ => toml::from_str(&text)
+            .map_err(|err| Error::Config(format!("invalid config file {}: {err}", path.display()))),
+    }
+}
+
+#[cfg(feature = "yaml")]
+fn parse_yaml<T>(text: &str) -> Result<T>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    serde_yaml::from_str(text).map_err(|err| Error::Config(format!("invalid yaml: {err}")))
+}
+
+#[cfg(not(feature = "yaml"))]
+fn parse_yaml<T>(_text: &str) -> Result<T> {
+    Err(Error::Config(
+        "yaml config files require the `yaml` feature".to_string(),
+    ))
+}
+
+fn parse_tls_version(version: &str) -> Result<TlsVersion> {
+    match version
+        .trim()
+        .trim_start_matches("tls")
+        .trim_start_matches("TLS")
+    {
+        "1.2" => Ok(TlsVersion::V1_2),
+        "1.3" => Ok(TlsVersion::V1_3),
+        _ => Err(Error::Config(format!(
+            "unsupported tls version: {version:?} (expected \"1.2\" or \"1.3\")"
+        ))),
+    }
+}
+
+/// [`ClientConfig::from_env`] reading variables through `env`
+fn from_env_with(env: &dyn Fn(&str) -> Option<String>) -> Result<ClientConfig> {
+    let file = match discover_path(env) {
+        Ok(path) => read_config_file(&path)?,
+        Err(_) => {
+            if env("INFRAHUB_TOKEN").is_none_or(|token| token.is_empty()) {
+                return Err(Error::Config("INFRAHUB_TOKEN is not set".to_string()));
+            }
+            FileConfig {
+                url: Some(DEFAULT_URL.to_string()),
+                ..FileConfig::default()
+            }
+        }
+    };
+    file.into_config(env)
+}
+
+fn discover_path(env: &dyn Fn(&str) -> Option<String>) -> Result<PathBuf> {
+    if let Some(path) = env(CONFIG_ENV).filter(|path| !path.is_empty()) {
+        return Ok(PathBuf::from(path));
+    }
+    let mut candidates = vec![PathBuf::from("infrahubctl.toml")];
+    if let Some(home) = env("HOME").or_else(|| env("USERPROFILE")) {
+        let dir = Path::new(&home).join(".infrahub");
+        for name in ["config.toml", "config.yml", "config.yaml"] {
+            candidates.push(dir.join(name));
+        }
+    }
+    candidates
+        .into_iter()
+        .find(|path| path.is_file())
+        .ok_or_else(|| Error::Config("no infrahub config file found".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_toml_config() {
+        let file: FileConfig = toml::from_str(
+            r#"
+            url = "https://infrahub.example.com"
+            token_env = "PROD_TOKEN"
+            branch = "main"
+            timeout_secs = 10
+            connect_timeout_secs = 2
+            read_timeout_secs = 5
+            max_retries = 1
+            proxy = "http://proxy.internal:3128"
+            path_prefix = "/infrahub"
+
+            [tls]
+            verify = false
+            require_https = true
+            min_version = "1.3"
+            "#,
+        )
+        .unwrap();
+        let config = file.into_config(&env(&[("PROD_TOKEN", "secret")])).unwrap();
+        assert_eq!(config.base_url.as_str(), "https://infrahub.example.com/");
+        assert_eq!(config.token, "secret");
+        assert_eq!(config.default_branch.as_deref(), Some("main"));
+        assert_eq!(config.timeout, Duration::from_secs(10));
+        assert_eq!(config.connect_timeout, Some(Duration::from_secs(2)));
+        assert_eq!(config.read_timeout, Some(Duration::from_secs(5)));
+        assert_eq!(config.max_retries, 1);
+        assert_eq!(config.proxy.as_deref(), Some("http://proxy.internal:3128"));
+        assert_eq!(
+            config.graphql_url(None).unwrap().as_str(),
+            "https://infrahub.example.com/infrahub/graphql/main"
+        );
+        assert!(!config.verify_ssl);
+        assert!(config.require_https);
+        assert_eq!(config.min_tls_version, Some(TlsVersion::V1_3));
+    }
+
+    #[test]
+    fn test_env_overrides() {
+        let file: FileConfig =
+            toml::from_str("url = \"https://a.example.com\"\ntoken = \"file\"\nbranch = \"main\"")
+                .unwrap();
+        let config = file
+            .clone()
+            .into_config(&env(&[
+                ("INFRAHUB_URL", "https://b.example.com"),
+                ("INFRAHUB_TOKEN", "env"),
+                ("INFRAHUB_BRANCH", "dev"),
+                ("INFRAHUB_TIMEOUT", "5"),
+            ]))
+            .unwrap();
+        assert_eq!(config.base_url.host_str(), Some("b.example.com"));
+        assert_eq!(config.token, "env");
+        assert_eq!(config.default_branch.as_deref(), Some("dev"));
+        assert_eq!(config.timeout, Duration::from_secs(5));
+
+        let config = file.clone().into_config(&env(&[])).unwrap();
+        assert_eq!(config.token, "file");
+        assert!(file
+            .into_config(&env(&[("INFRAHUB_TIMEOUT", "soon")]))
+            .is_err());
+    }
+
+    #[test]
+    fn test_token_sources() {
+        let file: FileConfig = toml::from_str(
+            r#"
+            url = "https://a"
+            token_file = "/run/secrets/infrahub-token"
+
+            [profiles.ci]
+            token_command = ["vault", "read", "-field=token", "kv/infrahub"]
+
+            [profiles.local]
+            token = "dev"
+            "#,
+        )
+        .unwrap();
+        let config = file.clone().into_config(&env(&[])).unwrap();
+        assert!(config.token.is_empty());
+        assert!(format!("{:?}", config.token_source).contains("infrahub-token"));
+        // an explicit token still wins, e.g. from INFRAHUB_TOKEN
+        let overridden = file
+            .clone()
+            .into_config(&env(&[("INFRAHUB_TOKEN", "x")]))
+            .unwrap();
+        assert_eq!(overridden.token, "x");
+        assert!(overridden.token_source.is_none());
+
+        let ci = file
+            .clone()
+            .profile("ci")
+            .unwrap()
+            .into_config(&env(&[]))
+            .unwrap();
+        assert!(format!("{:?}", ci.token_source).contains("vault"));
+        let local = file
+            .profile("local")
+            .unwrap()
+            .into_config(&env(&[]))
+            .unwrap();
+        assert_eq!(local.token, "dev");
+        assert!(local.token_source.is_none());
+
+        let both: FileConfig =
+            toml::from_str("url = \"https://a\"\ntoken_file = \"t\"\ntoken_command = [\"c\"]")
+                .unwrap();
+        assert!(both.into_config(&env(&[])).is_err());
+        let empty: FileConfig = toml::from_str("url = \"https://a\"\ntoken_command = []").unwrap();
+        assert!(empty.into_config(&env(&[])).is_err());
+    }
+
+    #[test]
+    fn test_config_errors() {
+        let missing_token: FileConfig = toml::from_str("url = \"https://a\"").unwrap();
+        assert!(missing_token.into_config(&env(&[])).is_err());
+        let unset_env: FileConfig =
+            toml::from_str("url = \"https://a\"\ntoken_env = \"NOPE\"").unwrap();
+        assert!(unset_env.into_config(&env(&[])).is_err());
+        assert!(toml::from_str::<FileConfig>("password = \"x\"").is_err());
+        let missing_ca: FileConfig = toml::from_str(
+            "url = \"https://a\"\ntoken = \"t\"\n[tls]\nca = \"/nonexistent/ca.pem\"",
+        )
+        .unwrap();
+        assert!(missing_ca.into_config(&env(&[])).is_err());
+        assert!(parse_tls_version("1.1").is_err());
+        assert_eq!(parse_tls_version("tls1.2").unwrap(), TlsVersion::V1_2);
+    }
+
+    #[test]
+    fn test_profiles() {
+        let file: FileConfig = toml::from_str(
+            r#"
+            token_env = "INFRAHUB_PROD_TOKEN"
+            timeout_secs = 30
+
+            [profiles.prod]
+            url = "https://infrahub.example.com"
+
+            [profiles.lab]
+            url = "http://lab.internal:8000"
+            token = "lab"
+            branch = "lab"
+            proxy = ""
+
+            [profiles.lab.tls]
+            verify = false
+            "#,
+        )
+        .unwrap();
+
+        let prod = file
+            .clone()
+            .profile("prod")
+            .unwrap()
+            .into_config(&env(&[("INFRAHUB_PROD_TOKEN", "secret")]))
+            .unwrap();
+        assert_eq!(prod.base_url.host_str(), Some("infrahub.example.com"));
+        assert_eq!(prod.token, "secret");
+        assert_eq!(prod.timeout, Duration::from_secs(30));
+
+        let lab = file
+            .clone()
+            .profile("lab")
+            .unwrap()
+            .into_config(&env(&[]))
+            .unwrap();
+        assert!(lab.no_proxy);
+        assert_eq!(lab.token, "lab");
+        assert_eq!(lab.default_branch.as_deref(), Some("lab"));
+        assert_eq!(lab.timeout, Duration::from_secs(30));
+        assert!(!lab.verify_ssl);
+
+        let err = file.profile("staging").unwrap_err();
+        assert!(err.to_string().contains("lab, prod"));
+        let nested: FileConfig =
+            toml::from_str("[profiles.a.profiles.b]\nurl = \"https://b\"").unwrap();
+        assert!(nested.profile("a").is_err());
+    }
+
+    #[test]
+    fn test_from_env_without_file() {
+        let config =
+            from_env_with(&env(&[("INFRAHUB_TOKEN", "t"), ("INFRAHUB_BRANCH", "dev")])).unwrap();
+        assert_eq!(config.base_url.as_str(), "http://localhost:8000/");
+        assert_eq!(config.token, "t");
+        assert_eq!(config.default_branch.as_deref(), Some("dev"));
+
+        let config = from_env_with(&env(&[
+            ("INFRAHUB_URL", "https://infrahub.example.com"),
+            ("INFRAHUB_TOKEN", "t"),
+        ]))
+        .unwrap();
+        assert_eq!(config.base_url.host_str(), Some("infrahub.example.com"));
+
+        let err = from_env_with(&env(&[("INFRAHUB_TOKEN", "")])).unwrap_err();
+        assert!(err.to_string().contains("INFRAHUB_TOKEN"));
+    }
+
+    #[test]
+    fn test_from_file_and_discover() {
+        let dir = std::env::temp_dir().join(format!("infrahub-config-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join(".infrahub")).unwrap();
+        let path = dir.join(".infrahub").join("config.toml");
+        std::fs::write(&path, "url = \"https://a.example.com\"\ntoken = \"t\"\n").unwrap();
+
+        let home = dir.to_string_lossy().to_string();
+        assert_eq!(discover_path(&env(&[("HOME", &home)])).unwrap(), path);
+        assert_eq!(
+            discover_path(&env(&[(CONFIG_ENV, "/etc/infrahub.toml")])).unwrap(),
+            PathBuf::from("/etc/infrahub.toml")
+        );
+        let file: FileConfig = read_config_file(&path).unwrap();
+        assert_eq!(file.token.as_deref(), Some("t"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
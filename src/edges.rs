@@ -0,0 +1,302 @@
+//! edges and node unwrapping
+//!
+//! serde helpers that flatten infrahub's connection shapes for handwritten
+//! typed queries: [`Edges`] reads `{ edges: [{ node }] }` into its nodes,
+//! [`Paginated`] also keeps the `count`, and [`deserialize_edges`] and
+//! [`deserialize_node`] do the same for plain struct fields.
+//!
+//! ```
+//! use infrahub::{Edges, Paginated};
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Tag {
+//!     id: String,
+//! }
+//!
+//! #[derive(Deserialize)]
+//! struct Device {
+//!     id: String,
+//!     tags: Edges<Tag>,
+//! }
+//!
+//! #[derive(Deserialize)]
+//! struct Devices {
+//!     #[serde(rename = "InfraDevice")]
+//!     devices: Paginated<Device>,
+//! }
+//!
+//! let data: Devices = serde_json::from_str(
+//!     r#"{"InfraDevice": {"count": 7, "edges": [
+//!         {"node": {"id": "d1", "tags": {"edges": [{"node": {"id": "t1"}}]}}}
+//!     ]}}"#,
+//! )
+//! .unwrap();
+//! assert_eq!(data.devices.count, 7);
+//! assert_eq!(data.devices.nodes[0].tags[0].id, "t1");
+//! ```
+
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::ops::{Deref, DerefMut};
+
+/// the wire shape of one edge; null nodes are skipped
+#[derive(Deserialize)]
+struct Edge<T> {
+    node: Option<T>,
+}
+
+#[derive(Serialize)]
+struct EdgeRef<'a, T> {
+    node: &'a T,
+}
+
+#[derive(Deserialize)]
+#[serde(bound = "T: Deserialize<'de>")]
+struct Connection<T> {
+    #[serde(default)]
+    count: Option<usize>,
+    #[serde(default = "Vec::new")]
+    edges: Vec<Edge<T>>,
+}
+
+impl<T> Connection<T> {
+    fn into_nodes(self) -> Vec<T> {
+        self.edges
+            .into_iter()
+            .filter_map(|edge| edge.node)
+            .collect()
+    }
+}
+
+/// the nodes of a `{ edges: [{ node }] }` connection
+///
+/// derefs to `Vec<T>` and serializes back to the same shape.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edges<T>(pub Vec<T>);
+
+impl<T> Edges<T> {
+    /// the nodes
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> Default for Edges<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T> Deref for Edges<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Edges<T> {
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        &mut self.0
+    }
+}
+
+impl<T> From<Vec<T>> for Edges<T> {
+    fn from(nodes: Vec<T>) -> Self {
+        Self(nodes)
+    }
+}
+
+impl<T> IntoIterator for Edges<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Edges<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Edges<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Connection::deserialize(deserializer).map(|connection| Self(connection.into_nodes()))
+    }
+}
+
+impl<T: Serialize> Serialize for Edges<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let edges: Vec<EdgeRef<'_, T>> = self.0.iter().map(|node| EdgeRef { node }).collect();
+        let mut state = serializer.serialize_struct("Edges", 1)?;
+        state.serialize_field("edges", &edges)?;
+        state.end()
+    }
+}
+
+/// a `{ count, edges: [{ node }] }` connection
+///
+/// `count` is the server's total, which can exceed `nodes.len()` when the
+/// query asked for one page; without a `count` in the response it is the
+/// number of nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paginated<T> {
+    /// total number of matching nodes
+    pub count: usize,
+    /// the nodes of this page
+    pub nodes: Vec<T>,
+}
+
+impl<T> Default for Paginated<T> {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            nodes: Vec::new(),
+        }
+    }
+}
+
+impl<T> IntoIterator for Paginated<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.nodes.into_iter()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Paginated<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let connection = Connection::deserialize(deserializer)?;
+        let count = connection.count;
+        let nodes = connection.into_nodes();
+        Ok(Self {
+            count: count.unwrap_or(nodes.len()),
+            nodes,
+        })
+    }
+}
+
+impl<T: Serialize> Serialize for Paginated<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let edges: Vec<EdgeRef<'_, T>> = self.nodes.iter().map(|node| EdgeRef { node }).collect();
+        let mut state = serializer.serialize_struct("Paginated", 2)?;
+        state.serialize_field("count", &self.count)?;
+        state.serialize_field("edges", &edges)?;
+        state.end()
+    }
+}
+
+/// `deserialize_with` helper reading `{ edges: [{ node }] }` into a `Vec<T>`
+///
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Device {
+///     #[serde(deserialize_with = "infrahub::deserialize_edges")]
+///     interfaces: Vec<serde_json::Value>,
+/// }
+/// ```
+pub fn deserialize_edges<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Edges::deserialize(deserializer).map(Edges::into_inner)
+}
+
+/// `deserialize_with` helper reading a cardinality-one relationship
+/// `{ node }` into an `Option<T>`
+///
+/// a null relationship or node gives `None`.
+pub fn deserialize_node<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let edge: Option<Edge<T>> = Option::deserialize(deserializer)?;
+    Ok(edge.and_then(|edge| edge.node))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Tag {
+        id: String,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Device {
+        #[serde(deserialize_with = "deserialize_edges")]
+        interfaces: Vec<Tag>,
+        #[serde(default, deserialize_with = "deserialize_node")]
+        site: Option<Tag>,
+        tags: Edges<Tag>,
+    }
+
+    #[test]
+    fn test_edges_and_nodes() {
+        let device: Device = serde_json::from_value(json!({
+            "interfaces": { "count": 2, "edges": [{ "node": { "id": "i1" } }, { "node": null }] },
+            "site": { "node": { "id": "s1" } },
+            "tags": { "edges": [] },
+        }))
+        .unwrap();
+        assert_eq!(device.interfaces, vec![Tag { id: "i1".into() }]);
+        assert_eq!(device.site, Some(Tag { id: "s1".into() }));
+        assert!(device.tags.is_empty());
+
+        let device: Device = serde_json::from_value(json!({
+            "interfaces": {},
+            "site": null,
+            "tags": { "edges": [{ "node": { "id": "t1" } }] },
+        }))
+        .unwrap();
+        assert!(device.interfaces.is_empty());
+        assert_eq!(device.site, None);
+        let ids: Vec<&str> = device.tags.iter().map(|tag| tag.id.as_str()).collect();
+        assert_eq!(ids, ["t1"]);
+
+        let invalid: Result<Device, _> =
+            serde_json::from_value(json!({ "interfaces": "i1", "tags": {} }));
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn test_paginated() {
+        let page: Paginated<String> = serde_json::from_value(json!({
+            "count": 10,
+            "edges": [{ "node": "a" }, { "node": "b" }],
+        }))
+        .unwrap();
+        assert_eq!(page.count, 10);
+        assert_eq!(page.nodes, ["a", "b"]);
+
+        let uncounted: Paginated<String> =
+            serde_json::from_value(json!({ "edges": [{ "node": "a" }] })).unwrap();
+        assert_eq!(uncounted.count, 1);
+
+        assert_eq!(
+            serde_json::to_value(&page).unwrap(),
+            json!({ "count": 10, "edges": [{ "node": "a" }, { "node": "b" }] })
+        );
+        assert_eq!(
+            serde_json::to_value(Edges(vec![1, 2])).unwrap(),
+            json!({ "edges": [{ "node": 1 }, { "node": 2 }] })
+        );
+        let round: Edges<i32> =
+            serde_json::from_value(json!({ "edges": [{ "node": 3 }] })).unwrap();
+        assert_eq!(round.into_inner(), [3]);
+    }
+}
@@ -2,11 +2,10 @@
 //!
 //! structured errors for config, http, json, and graphql responses.
 
-use crate::graphql::GraphQlError;
+use crate::compat::VersionRange;
+use crate::graphql::{GraphQlError, InfrahubError};
 use std::fmt;
-
-/// graphql response status codes considered transient and worth retrying
-const RETRYABLE_STATUSES: &[u16] = &[429, 500, 502, 503, 504];
+use std::time::Duration;
 
 /// library result type
 pub type Result<T> = std::result::Result<T, Error>;
@@ -20,13 +19,40 @@ pub enum Error {
     #[error("http error: {0}")]
     Http(#[from] reqwest::Error),
 
+    #[error("request timed out during {stage} after {elapsed:?}")]
+    Timeout {
+        /// time spent on the attempt before it timed out
+        elapsed: Duration,
+        /// what the request was doing when it timed out
+        stage: TimeoutStage,
+        /// underlying http error
+        #[source]
+        source: reqwest::Error,
+    },
+
     #[error("url error: {0}")]
     Url(#[from] url::ParseError),
 
     #[error("json error: {0}")]
     Json(#[from] serde_json::Error),
 
-    #[error("graphql error: {message}")]
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("client is shutting down")]
+    Shutdown,
+
+    #[error("infrahub {version} is not supported; this client supports {supported}")]
+    UnsupportedVersion {
+        /// version reported by the server
+        version: String,
+        /// release series this client supports
+        supported: VersionRange,
+    },
+
+    /// displayed as the message followed by one line per entry (with its
+    /// path, locations, and extension code) and the start of the body
+    #[error("graphql error: {message}{}", GraphQlDetails(.errors, .body))]
     GraphQl {
         /// http status if available
         status: Option<u16>,
@@ -39,47 +65,228 @@ pub enum Error {
     },
 }
 
-impl Error {
-    /// true if the error looks like an auth failure
-    pub fn is_auth_error(&self) -> bool {
+/// the phase of a request that timed out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeoutStage {
+    /// establishing the connection (dns, tcp, tls)
+    Connect,
+    /// waiting for or reading the response
+    Read,
+}
+
+impl fmt::Display for TimeoutStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TimeoutStage::Connect => "connect",
+            TimeoutStage::Read => "read",
+        })
+    }
+}
+
+/// coarse classification of an [`Error`], for retry and reporting decisions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// network-level or i/o failure (connection refused, reset, dns, body
+    /// read, file read)
+    Transport,
+    /// the request timed out
+    Timeout,
+    /// the server rejected the credentials (401, 403)
+    Auth,
+    /// the server is throttling requests (429)
+    RateLimit,
+    /// the server failed to handle the request (5xx)
+    Server,
+    /// the server rejected the graphql document or its variables
+    GraphQlValidation,
+    /// the request could not be built or the response did not match the
+    /// expected type; retrying will not help
+    ClientBug,
+    /// the request was refused or cancelled because the client is shutting down
+    Cancelled,
+}
+
+impl ErrorKind {
+    /// true if errors of this kind are transient and worth retrying
+    pub fn is_retryable(self) -> bool {
         matches!(
             self,
-            Error::GraphQl {
-                status: Some(401 | 403),
-                ..
-            }
-        ) || matches!(self, Error::Http(err) if err.status() == Some(reqwest::StatusCode::UNAUTHORIZED))
+            ErrorKind::Transport | ErrorKind::Timeout | ErrorKind::RateLimit | ErrorKind::Server
+        )
     }
 
-    /// true if the error is transient and the request may succeed on retry
-    ///
-    /// auth errors, config errors, and parse errors are permanent.
-    /// server errors (5xx), rate limits (429), and network errors are retryable.
-    pub fn is_retryable(&self) -> bool {
+    /// classify an http status code, if it carries enough information
+    fn from_status(status: u16) -> Option<Self> {
+        match status {
+            401 | 403 => Some(ErrorKind::Auth),
+            429 => Some(ErrorKind::RateLimit),
+            // other 5xx (501, 505, 511, ...) are not transient
+            500 | 502 | 503 | 504 => Some(ErrorKind::Server),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ErrorKind::Transport => "transport",
+            ErrorKind::Timeout => "timeout",
+            ErrorKind::Auth => "auth",
+            ErrorKind::RateLimit => "rate-limit",
+            ErrorKind::Server => "server",
+            ErrorKind::GraphQlValidation => "graphql-validation",
+            ErrorKind::ClientBug => "client-bug",
+            ErrorKind::Cancelled => "cancelled",
+        };
+        f.write_str(name)
+    }
+}
+
+impl Error {
+    /// classify the error
+    pub fn kind(&self) -> ErrorKind {
         match self {
-            Error::Config(_) | Error::Url(_) | Error::Json(_) => false,
+            Error::Config(_)
+            | Error::Url(_)
+            | Error::Json(_)
+            | Error::UnsupportedVersion { .. } => ErrorKind::ClientBug,
+            // i/o failures come from the environment and are often transient
+            Error::Io(_) => ErrorKind::Transport,
+            Error::Shutdown => ErrorKind::Cancelled,
+            Error::Timeout { .. } => ErrorKind::Timeout,
             Error::Http(err) => {
-                if err.is_timeout() || err.is_connect() {
-                    return true;
+                if err.is_timeout() {
+                    return ErrorKind::Timeout;
+                }
+                if err.is_builder() || err.is_redirect() {
+                    return ErrorKind::ClientBug;
                 }
                 match err.status() {
                     Some(status) => {
-                        status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                        ErrorKind::from_status(status.as_u16()).unwrap_or(ErrorKind::ClientBug)
                     }
                     // no status usually means a network-level failure
-                    None => true,
+                    None => ErrorKind::Transport,
                 }
             }
-            Error::GraphQl { status, .. } => {
-                status.is_some_and(|s| RETRYABLE_STATUSES.contains(&s))
+            Error::GraphQl { status, errors, .. } => {
+                match status.and_then(ErrorKind::from_status) {
+                    Some(kind) => kind,
+                    None if errors.is_empty() && status.is_some_and(|s| s >= 400) => {
+                        ErrorKind::ClientBug
+                    }
+                    None => ErrorKind::GraphQlValidation,
+                }
+            }
+        }
+    }
+
+    /// turn an http timeout into [`Error::Timeout`], recording how long the
+    /// attempt ran; other errors are returned unchanged
+    pub(crate) fn with_elapsed(self, elapsed: Duration) -> Self {
+        match self {
+            Error::Http(source) if source.is_timeout() => Error::Timeout {
+                elapsed,
+                stage: if source.is_connect() {
+                    TimeoutStage::Connect
+                } else {
+                    TimeoutStage::Read
+                },
+                source,
+            },
+            other => other,
+        }
+    }
+
+    /// typed infrahub errors parsed from each graphql error's extensions
+    ///
+    /// empty for non-graphql errors and for entries without extensions.
+    pub fn infrahub_errors(&self) -> Vec<InfrahubError> {
+        match self {
+            Error::GraphQl { errors, .. } => errors
+                .iter()
+                .filter_map(GraphQlError::infrahub_error)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// true if the error looks like an auth failure
+    pub fn is_auth_error(&self) -> bool {
+        self.kind() == ErrorKind::Auth
+    }
+
+    /// true if the error is transient and the request may succeed on retry
+    ///
+    /// auth errors, config errors, and parse errors are permanent.
+    /// server errors (5xx), rate limits (429), and network errors are retryable.
+    /// see [`Error::kind`] for the full classification.
+    pub fn is_retryable(&self) -> bool {
+        self.kind().is_retryable()
+    }
+}
+
+/// maximum length of the response body shown by [`Error`]'s display
+const DISPLAY_BODY_LIMIT: usize = 512;
+
+/// the lines after the first of an [`Error::GraphQl`] display
+struct GraphQlDetails<'a>(&'a [GraphQlError], &'a str);
+
+impl fmt::Display for GraphQlDetails<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let GraphQlDetails(errors, body) = self;
+        for error in errors.iter() {
+            write!(f, "\n  - {error}")?;
+        }
+        let body = body.trim();
+        if !body.is_empty() {
+            let mut cut = body.len().min(DISPLAY_BODY_LIMIT);
+            while !body.is_char_boundary(cut) {
+                cut -= 1;
             }
+            let more = if cut < body.len() { "..." } else { "" };
+            write!(f, "\n  body: {}{more}", &body[..cut])?;
         }
+        Ok(())
     }
 }
 
+/// the message, then the response path, query locations, and extension
+/// code when present: `denied at secret.value (1:9) [PERMISSION_DENIED]`
 impl fmt::Display for GraphQlError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.message)
+        f.write_str(&self.message)?;
+        if !self.path.is_empty() {
+            let path: Vec<String> = self
+                .path
+                .iter()
+                .map(|segment| match segment {
+                    serde_json::Value::String(name) => name.clone(),
+                    other => other.to_string(),
+                })
+                .collect();
+            write!(f, " at {}", path.join("."))?;
+        }
+        if !self.locations.is_empty() {
+            let locations: Vec<String> = self
+                .locations
+                .iter()
+                .map(|location| format!("{}:{}", location.line, location.column))
+                .collect();
+            write!(f, " ({})", locations.join(", "))?;
+        }
+        let code = self.extensions.as_ref().and_then(|extensions| {
+            extensions
+                .get("code")
+                .or_else(|| extensions.get("error_code"))
+        });
+        match code {
+            Some(serde_json::Value::String(code)) => write!(f, " [{code}]")?,
+            Some(serde_json::Value::Number(code)) => write!(f, " [{code}]")?,
+            _ => {}
+        }
+        Ok(())
     }
 }
 
@@ -87,6 +294,50 @@ impl fmt::Display for GraphQlError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_graphql_error_display() {
+        let errors: Vec<GraphQlError> = serde_json::from_value(serde_json::json!([
+            {
+                "message": "Cannot query field 'nme'",
+                "locations": [{ "line": 1, "column": 9 }],
+            },
+            {
+                "message": "denied",
+                "path": ["BuiltinTag", "edges", 0, "node", "secret"],
+                "extensions": { "code": "PERMISSION_DENIED" },
+            },
+            { "message": "gone", "extensions": { "code": 404 } },
+        ]))
+        .unwrap();
+        let err = Error::GraphQl {
+            status: Some(200),
+            errors,
+            body: format!("{{\"errors\": \"{}\"}}", "é".repeat(400)),
+            message: "Cannot query field 'nme'".to_string(),
+        };
+        let text = err.to_string();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "graphql error: Cannot query field 'nme'");
+        assert_eq!(lines[1], "  - Cannot query field 'nme' (1:9)");
+        assert_eq!(
+            lines[2],
+            "  - denied at BuiltinTag.edges.0.node.secret [PERMISSION_DENIED]"
+        );
+        assert_eq!(lines[3], "  - gone [404]");
+        assert!(lines[4].starts_with("  body: {\"errors\": \"é"));
+        assert!(lines[4].ends_with("é..."));
+        assert!(lines[4].len() <= "  body: ".len() + DISPLAY_BODY_LIMIT + 3);
+        assert_eq!(lines.len(), 5);
+
+        let bare = Error::GraphQl {
+            status: None,
+            errors: Vec::new(),
+            body: String::new(),
+            message: "missing".to_string(),
+        };
+        assert_eq!(bare.to_string(), "graphql error: missing");
+    }
+
     #[test]
     fn test_is_auth_error() {
         let err = Error::GraphQl {
@@ -157,6 +408,124 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_kind_from_graphql_status() {
+        let kind = |status: Option<u16>, errors: Vec<GraphQlError>| {
+            Error::GraphQl {
+                status,
+                errors,
+                body: String::new(),
+                message: "x".to_string(),
+            }
+            .kind()
+        };
+        let validation = || {
+            vec![GraphQlError {
+                message: "Cannot query field 'nme'".to_string(),
+                locations: vec![],
+                path: vec![],
+                extensions: None,
+            }]
+        };
+
+        assert_eq!(kind(Some(401), vec![]), ErrorKind::Auth);
+        assert_eq!(kind(Some(403), validation()), ErrorKind::Auth);
+        assert_eq!(kind(Some(429), vec![]), ErrorKind::RateLimit);
+        assert_eq!(kind(Some(503), vec![]), ErrorKind::Server);
+        assert_eq!(kind(Some(200), validation()), ErrorKind::GraphQlValidation);
+        assert_eq!(kind(Some(400), validation()), ErrorKind::GraphQlValidation);
+        assert_eq!(kind(None, validation()), ErrorKind::GraphQlValidation);
+        assert_eq!(kind(Some(404), vec![]), ErrorKind::ClientBug);
+        assert_eq!(kind(Some(501), vec![]), ErrorKind::ClientBug);
+        assert_eq!(kind(Some(505), vec![]), ErrorKind::ClientBug);
+    }
+
+    #[test]
+    fn test_kind_client_bug_for_local_errors() {
+        assert_eq!(Error::Config("bad".into()).kind(), ErrorKind::ClientBug);
+        let json_err: Error = serde_json::from_str::<serde_json::Value>("!!!")
+            .unwrap_err()
+            .into();
+        assert_eq!(json_err.kind(), ErrorKind::ClientBug);
+    }
+
+    #[test]
+    fn test_error_kind_retryable_and_display() {
+        assert!(ErrorKind::Transport.is_retryable());
+        assert!(ErrorKind::Timeout.is_retryable());
+        assert!(ErrorKind::RateLimit.is_retryable());
+        assert!(ErrorKind::Server.is_retryable());
+        assert!(!ErrorKind::Auth.is_retryable());
+        assert!(!ErrorKind::GraphQlValidation.is_retryable());
+        assert!(!ErrorKind::ClientBug.is_retryable());
+        assert!(!ErrorKind::Cancelled.is_retryable());
+        assert_eq!(Error::Shutdown.kind(), ErrorKind::Cancelled);
+        let io = Error::from(std::io::Error::from(std::io::ErrorKind::ConnectionReset));
+        assert_eq!(io.kind(), ErrorKind::Transport);
+        assert!(io.is_retryable());
+        assert_eq!(ErrorKind::RateLimit.to_string(), "rate-limit");
+        assert_eq!(
+            ErrorKind::GraphQlValidation.to_string(),
+            "graphql-validation"
+        );
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_with_elapsed_maps_timeouts() {
+        // a listener that accepts but never answers forces a read timeout
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_millis(50))
+            .build()
+            .unwrap();
+        let err: Error = http.get(url).send().await.unwrap_err().into();
+
+        let err = err.with_elapsed(Duration::from_millis(60));
+        match &err {
+            Error::Timeout { elapsed, stage, .. } => {
+                assert_eq!(*elapsed, Duration::from_millis(60));
+                assert_eq!(*stage, TimeoutStage::Read);
+            }
+            other => panic!("expected timeout, got {other:?}"),
+        }
+        assert_eq!(err.kind(), ErrorKind::Timeout);
+        assert!(err.is_retryable());
+        assert!(err
+            .to_string()
+            .starts_with("request timed out during read after"));
+        drop(listener);
+
+        let config = Error::Config("x".into()).with_elapsed(Duration::ZERO);
+        assert!(matches!(config, Error::Config(_)));
+    }
+
+    #[test]
+    fn test_infrahub_errors_from_graphql_error() {
+        let err = Error::GraphQl {
+            status: Some(200),
+            errors: vec![
+                GraphQlError {
+                    message: "not found".to_string(),
+                    locations: vec![],
+                    path: vec![],
+                    extensions: Some(serde_json::json!({"code": 404})),
+                },
+                GraphQlError {
+                    message: "plain".to_string(),
+                    locations: vec![],
+                    path: vec![],
+                    extensions: None,
+                },
+            ],
+            body: String::new(),
+            message: "not found".to_string(),
+        };
+        assert_eq!(err.infrahub_errors().len(), 1);
+        assert!(Error::Config("x".into()).infrahub_errors().is_empty());
+    }
+
     #[test]
     fn test_is_not_retryable_config_url_json() {
         let config_err = Error::Config("bad".into());
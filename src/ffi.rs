@@ -0,0 +1,225 @@
+//! c ffi
+//!
+//! a thin `extern "C"` surface for non-rust tooling: create a client, run a
+//! query and get the response back as a json string, and free what was
+//! handed out. each client owns a small tokio runtime that its calls block
+//! on, so callers need no async support. see `include/infrahub.h`.
+//!
+//! strings passed in must be nul-terminated utf-8. strings handed out are
+//! owned by the caller and must be released with [`infrahub_string_free`].
+
+use crate::client::Client;
+use crate::config::ClientConfig;
+use crate::error::{Error, Result};
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+/// opaque client handle returned by [`infrahub_client_new`]
+pub struct InfrahubClient {
+    client: Client,
+    runtime: tokio::runtime::Runtime,
+}
+
+/// create a client for `base_url` authenticating with `token`
+///
+/// returns null on failure; if `error` is not null it then receives an error
+/// message to release with [`infrahub_string_free`].
+///
+/// # Safety
+///
+/// `base_url` and `token` must be null or valid nul-terminated strings, and
+/// `error` must be null or valid for a pointer write.
+#[no_mangle]
+pub unsafe extern "C" fn infrahub_client_new(
+    base_url: *const c_char,
+    token: *const c_char,
+    error: *mut *mut c_char,
+) -> *mut InfrahubClient {
+    let created = catch_unwind(|| {
+        let base_url = read_str(base_url, "base_url")?;
+        let token = read_str(token, "token")?;
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()?;
+        let client = {
+            let _entered = runtime.enter();
+            Client::new(ClientConfig::new(base_url, token))?
+        };
+        Ok(InfrahubClient { client, runtime })
+    })
+    .unwrap_or_else(|_| Err(Error::Config("panic while creating client".to_string())));
+
+    match created {
+        Ok(client) => Box::into_raw(Box::new(client)),
+        Err(err) => {
+            if !error.is_null() {
+                *error = into_c_string(err.to_string());
+            }
+            ptr::null_mut()
+        }
+    }
+}
+
+/// run a graphql query and return the response as a json string
+///
+/// `variables_json` (a json object) and `branch` may be null. the result is
+/// the graphql response (`{"data": ..., "errors": ...}`) on success, or
+/// `{"error": {"kind": ..., "message": ...}}` on failure; it is never null
+/// and must be released with [`infrahub_string_free`].
+///
+/// # Safety
+///
+/// `client` must be null or a live pointer from [`infrahub_client_new`], and
+/// the string arguments must be null or valid nul-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn infrahub_execute(
+    client: *const InfrahubClient,
+    query: *const c_char,
+    variables_json: *const c_char,
+    branch: *const c_char,
+) -> *mut c_char {
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let client = client
+            .as_ref()
+            .ok_or_else(|| Error::Config("client is null".to_string()))?;
+        let query = read_str(query, "query")?;
+        let variables = match read_opt_str(variables_json, "variables_json")? {
+            Some(text) => Some(serde_json::from_str(text)?),
+            None => None,
+        };
+        let branch = read_opt_str(branch, "branch")?;
+        let response = client
+            .runtime
+            .block_on(client.client.execute_raw(query, variables, branch))?;
+        Ok(serde_json::to_string(&response)?)
+    }))
+    .unwrap_or_else(|_| Err(Error::Config("panic while executing query".to_string())));
+
+    into_c_string(match result {
+        Ok(json) => json,
+        Err(err) => error_json(&err),
+    })
+}
+
+/// release a string returned by this library
+///
+/// # Safety
+///
+/// `value` must be null or a string returned by this library that has not
+/// been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn infrahub_string_free(value: *mut c_char) {
+    if !value.is_null() {
+        drop(CString::from_raw(value));
+    }
+}
+
+/// release a client and shut down its runtime
+///
+/// # Safety
+///
+/// `client` must be null or a pointer from [`infrahub_client_new`] that has
+/// not been freed yet, with no call on it still running.
+#[no_mangle]
+pub unsafe extern "C" fn infrahub_client_free(client: *mut InfrahubClient) {
+    if !client.is_null() {
+        drop(Box::from_raw(client));
+    }
+}
+
+/// read a required string argument
+unsafe fn read_str<'a>(value: *const c_char, name: &str) -> Result<&'a str> {
+    read_opt_str(value, name)?.ok_or_else(|| Error::Config(format!("{name} is null")))
+}
+
+/// read an optional string argument
+unsafe fn read_opt_str<'a>(value: *const c_char, name: &str) -> Result<Option<&'a str>> {
+    if value.is_null() {
+        return Ok(None);
+    }
+    CStr::from_ptr(value)
+        .to_str()
+        .map(Some)
+        .map_err(|_| Error::Config(format!("{name} is not valid utf-8")))
+}
+
+/// the `{"error": ...}` document returned for a failed call
+fn error_json(err: &Error) -> String {
+    serde_json::json!({
+        "error": {
+            "kind": err.kind().to_string(),
+            "message": err.to_string(),
+        }
+    })
+    .to_string()
+}
+
+/// hand a string to the caller, dropping interior nul bytes
+fn into_c_string(value: String) -> *mut c_char {
+    let value = CString::new(value).unwrap_or_else(|err| {
+        let mut bytes = err.into_vec();
+        bytes.retain(|byte| *byte != 0);
+        CString::new(bytes).unwrap_or_default()
+    });
+    value.into_raw()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn take(value: *mut c_char) -> serde_json::Value {
+        let text = unsafe { CStr::from_ptr(value) }
+            .to_str()
+            .unwrap()
+            .to_string();
+        unsafe { infrahub_string_free(value) };
+        serde_json::from_str(&text).unwrap()
+    }
+
+    #[test]
+    fn test_client_new_reports_errors() {
+        let url = CString::new("ftp://example.com").unwrap();
+        let token = CString::new("token").unwrap();
+        let mut error = ptr::null_mut();
+        let client = unsafe { infrahub_client_new(url.as_ptr(), token.as_ptr(), &mut error) };
+        assert!(client.is_null());
+        let message = unsafe { CStr::from_ptr(error) }
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert!(message.contains("scheme"));
+        unsafe { infrahub_string_free(error) };
+
+        let client = unsafe { infrahub_client_new(ptr::null(), token.as_ptr(), ptr::null_mut()) };
+        assert!(client.is_null());
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn test_execute_returns_error_json() {
+        let url = CString::new("http://127.0.0.1:9").unwrap();
+        let token = CString::new("token").unwrap();
+        let client = unsafe { infrahub_client_new(url.as_ptr(), token.as_ptr(), ptr::null_mut()) };
+        assert!(!client.is_null());
+
+        let query = CString::new("{ InfrahubInfo { version } }").unwrap();
+        let variables = CString::new("not json").unwrap();
+        let response = take(unsafe {
+            infrahub_execute(client, query.as_ptr(), variables.as_ptr(), ptr::null())
+        });
+        assert_eq!(response["error"]["kind"], "client-bug");
+
+        let response = take(unsafe {
+            infrahub_execute(ptr::null(), query.as_ptr(), ptr::null(), ptr::null())
+        });
+        assert!(response["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("client is null"));
+
+        unsafe { infrahub_client_free(client) };
+    }
+}
@@ -0,0 +1,149 @@
+//! field filtering
+//!
+//! strip configured fields from every response before it is deserialized,
+//! so sensitive attributes never reach application code, caches, or logs.
+//! platforms that embed the client for several teams can enforce one policy
+//! in the config instead of trusting every caller.
+
+use serde_json::Value;
+
+/// removes matching fields from response data
+///
+/// fields match by exact name or by a case-insensitive substring pattern,
+/// at any depth. stripped fields are absent from the result, so typed
+/// responses must declare them as `Option<T>` or `#[serde(default)]`.
+///
+/// ```
+/// use infrahub::FieldFilter;
+///
+/// let filter = FieldFilter::new().field("password").pattern("secret");
+/// let mut data = serde_json::json!({ "name": "ops", "password": "x", "client_secret": "y" });
+/// assert_eq!(filter.apply(&mut data), 2);
+/// assert_eq!(data, serde_json::json!({ "name": "ops" }));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FieldFilter {
+    fields: Vec<String>,
+    patterns: Vec<String>,
+}
+
+impl FieldFilter {
+    /// create a filter that strips nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// strip fields named exactly `name`
+    pub fn field(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        if !name.is_empty() && !self.fields.contains(&name) {
+            self.fields.push(name);
+        }
+        self
+    }
+
+    /// strip fields whose names contain `pattern`, ignoring case
+    pub fn pattern(mut self, pattern: impl AsRef<str>) -> Self {
+        let pattern = pattern.as_ref().to_ascii_lowercase();
+        if !pattern.is_empty() && !self.patterns.contains(&pattern) {
+            self.patterns.push(pattern);
+        }
+        self
+    }
+
+    /// true if the filter strips nothing
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.patterns.is_empty()
+    }
+
+    /// true if fields named `name` are stripped
+    pub fn strips(&self, name: &str) -> bool {
+        if self.fields.iter().any(|field| field == name) {
+            return true;
+        }
+        let name = name.to_ascii_lowercase();
+        self.patterns
+            .iter()
+            .any(|pattern| name.contains(pattern.as_str()))
+    }
+
+    /// strip matching fields from `value`, returning how many were removed
+    pub fn apply(&self, value: &mut Value) -> usize {
+        match value {
+            Value::Object(map) => {
+                let before = map.len();
+                map.retain(|name, _| !self.strips(name));
+                let removed = before - map.len();
+                removed + map.values_mut().map(|item| self.apply(item)).sum::<usize>()
+            }
+            Value::Array(items) => items.iter_mut().map(|item| self.apply(item)).sum(),
+            _ => 0,
+        }
+    }
+
+    /// strip matching fields from the `data` of a graphql response body
+    ///
+    /// text that is not json, or has nothing to strip, is returned unchanged.
+    pub(crate) fn apply_to_body(&self, text: String) -> String {
+        if self.is_empty() {
+            return text;
+        }
+        let Ok(mut body) = serde_json::from_str::<Value>(&text) else {
+            return text;
+        };
+        let removed = body.get_mut("data").map_or(0, |data| self.apply(data));
+        if removed > 0 {
+            body.to_string()
+        } else {
+            text
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_apply_nested() {
+        let filter = FieldFilter::new().field("password").pattern("Secret");
+        let mut value = json!({
+            "CoreAccount": {
+                "edges": [
+                    { "node": { "name": { "value": "ops" }, "password": { "value": "x" } } },
+                    { "node": { "name": { "value": "ci" }, "api_secret": "y", "Password": "z" } }
+                ]
+            }
+        });
+        assert_eq!(filter.apply(&mut value), 2);
+        assert_eq!(
+            value,
+            json!({
+                "CoreAccount": {
+                    "edges": [
+                        { "node": { "name": { "value": "ops" } } },
+                        { "node": { "name": { "value": "ci" }, "Password": "z" } }
+                    ]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_apply_to_body() {
+        let filter = FieldFilter::new().field("token");
+        let body = r#"{"data":{"token":"abc","ok":true},"extensions":{"token":1}}"#.to_string();
+        let filtered: Value = serde_json::from_str(&filter.apply_to_body(body)).unwrap();
+        assert_eq!(
+            filtered,
+            json!({ "data": { "ok": true }, "extensions": { "token": 1 } })
+        );
+
+        let untouched = r#"{ "data": { "ok": true } }"#.to_string();
+        assert_eq!(filter.apply_to_body(untouched.clone()), untouched);
+        assert_eq!(filter.apply_to_body("<html>".to_string()), "<html>");
+        assert!(FieldFilter::new().is_empty());
+        assert!(!FieldFilter::new().field("").pattern("").strips(""));
+    }
+}
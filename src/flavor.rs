@@ -0,0 +1,74 @@
+//! api flavors
+//!
+//! rest endpoint layouts that differ between infrahub releases. graphql
+//! (`/graphql/{branch}`), schema (`/schema.graphql`), and schema summary
+//! (`/api/schema/summary`) paths are shared by every supported release;
+//! file downloads are not.
+
+use crate::compat::major_minor;
+use crate::error::{Error, Result};
+
+/// rest endpoint layout of an infrahub release
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ApiFlavor {
+    /// infrahub 1.8 and later: downloads under `/api/files`
+    #[default]
+    Current,
+    /// infrahub before 1.8: storage objects under `/api/storage/object`,
+    /// no downloads by node id or hfid
+    Legacy,
+}
+
+impl ApiFlavor {
+    /// the flavor for an infrahub version such as `1.8.5` or `v1.1.0`
+    ///
+    /// returns `None` when the version does not start with `major.minor`.
+    pub fn for_version(version: &str) -> Option<Self> {
+        if major_minor(version)? >= (1, 8) {
+            Some(ApiFlavor::Current)
+        } else {
+            Some(ApiFlavor::Legacy)
+        }
+    }
+
+    /// read the flavor from an `InfrahubInfo { version }` response
+    pub(crate) fn from_info(data: Option<&serde_json::Value>) -> Result<Self> {
+        let version = data
+            .and_then(|data| data["InfrahubInfo"]["version"].as_str())
+            .ok_or_else(|| Error::Config("server did not report an infrahub version".into()))?;
+        Self::for_version(version)
+            .ok_or_else(|| Error::Config(format!("unrecognized infrahub version: {version}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_for_version() {
+        assert_eq!(ApiFlavor::for_version("1.8.5"), Some(ApiFlavor::Current));
+        assert_eq!(ApiFlavor::for_version("v1.10.0"), Some(ApiFlavor::Current));
+        assert_eq!(ApiFlavor::for_version("2.0"), Some(ApiFlavor::Current));
+        assert_eq!(ApiFlavor::for_version("1.1.0"), Some(ApiFlavor::Legacy));
+        assert_eq!(ApiFlavor::for_version("1.7rc1"), Some(ApiFlavor::Legacy));
+        assert_eq!(ApiFlavor::for_version("dev"), None);
+        assert_eq!(ApiFlavor::for_version("1"), None);
+    }
+
+    #[test]
+    fn test_from_info() {
+        let data = json!({ "InfrahubInfo": { "version": "1.1.0" } });
+        assert_eq!(
+            ApiFlavor::from_info(Some(&data)).unwrap(),
+            ApiFlavor::Legacy
+        );
+        assert!(matches!(ApiFlavor::from_info(None), Err(Error::Config(_))));
+        let data = json!({ "InfrahubInfo": { "version": "main" } });
+        assert!(matches!(
+            ApiFlavor::from_info(Some(&data)),
+            Err(Error::Config(_))
+        ));
+    }
+}
@@ -0,0 +1,142 @@
+//! generator helpers
+//!
+//! trigger `CoreGeneratorDefinition` runs through the
+//! `CoreGeneratorDefinitionRun` mutation and follow the resulting tasks
+//! through the `InfrahubTask` query.
+//!
+//! ```no_run
+//! use infrahub::{Client, ClientConfig};
+//!
+//! # async fn example() -> infrahub::Result<()> {
+//! let client = Client::new(ClientConfig::new("http://localhost:8000", "token"))?;
+//! let definition = client
+//!     .generator_definition_id("backbone_links", None)
+//!     .await?
+//!     .expect("definition exists");
+//! let task = client.run_generator(&definition, &["device-1"], None).await?;
+//! if let Some(status) = client.task(&task).await? {
+//!     println!("{}: {:?}", status.title, status.state);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use serde::Deserialize;
+use serde_json::json;
+
+/// an infrahub task, as returned by [`Client::task`]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TaskStatus {
+    /// task id
+    pub id: String,
+    /// human readable title
+    pub title: String,
+    /// `PENDING`, `RUNNING`, `COMPLETED`, `FAILED`, and so on
+    pub state: Option<String>,
+    /// `success`, `failure`, or `unknown` while running
+    pub conclusion: String,
+    /// progress between 0.0 and 1.0, if reported
+    pub progress: Option<f64>,
+}
+
+impl Client {
+    /// look up a generator definition id by name
+    pub async fn generator_definition_id(
+        &self,
+        name: &str,
+        branch: Option<&str>,
+    ) -> Result<Option<String>> {
+        let query = "query GeneratorDefinitionId($name: String!) { CoreGeneratorDefinition(name__value: $name) { edges { node { id } } } }";
+        let vars = json!({ "name": name });
+        let response = self.execute_raw(query, Some(vars), branch).await?;
+        let data = response.into_data()?;
+        Ok(data["CoreGeneratorDefinition"]["edges"][0]["node"]["id"]
+            .as_str()
+            .map(str::to_string))
+    }
+
+    /// run a generator definition for the target node ids, returning the id
+    /// of the task that runs it
+    ///
+    /// with no targets the generator runs for every member of the
+    /// definition's target group. the call returns once the task is
+    /// scheduled; follow it with [`Client::task`].
+    pub async fn run_generator(
+        &self,
+        definition: &str,
+        targets: &[&str],
+        branch: Option<&str>,
+    ) -> Result<String> {
+        let query = "mutation RunGenerator($data: GeneratorDefinitionRequestRunInput!) { CoreGeneratorDefinitionRun(data: $data, wait_until_completion: false) { ok task { id } } }";
+        let mut data = json!({ "id": definition });
+        if !targets.is_empty() {
+            data["nodes"] = json!(targets);
+        }
+        let response = self
+            .execute_raw(query, Some(json!({ "data": data })), branch)
+            .await?;
+        let data = response.into_data()?;
+        let payload = &data["CoreGeneratorDefinitionRun"];
+        match (payload["ok"].as_bool(), payload["task"]["id"].as_str()) {
+            (Some(true), Some(task)) => Ok(task.to_string()),
+            _ => Err(Error::Config(
+                "CoreGeneratorDefinitionRun did not return a task".to_string(),
+            )),
+        }
+    }
+
+    /// look up a task by id
+    pub async fn task(&self, id: &str) -> Result<Option<TaskStatus>> {
+        let query = "query Task($ids: [String]) { InfrahubTask(ids: $ids) { edges { node { id title state conclusion progress } } } }";
+        let response = self
+            .execute_raw(query, Some(json!({ "ids": [id] })), None)
+            .await?;
+        let data = response.into_data()?;
+        let node = &data["InfrahubTask"]["edges"][0]["node"];
+        if node.is_null() {
+            return Ok(None);
+        }
+        Ok(Some(TaskStatus::deserialize(node)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockClient;
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_run_generator_and_task() {
+        let mock = MockClient::new();
+        mock.expect("RunGenerator")
+            .with_variables(json!({ "data": { "id": "gd-1", "nodes": ["d1", "d2"] } }))
+            .returning(json!({
+                "CoreGeneratorDefinitionRun": { "ok": true, "task": { "id": "task-1" } }
+            }));
+        mock.expect("RunGenerator")
+            .with_variables(json!({ "data": { "id": "gd-2" } }))
+            .returning(json!({ "CoreGeneratorDefinitionRun": { "ok": false, "task": null } }));
+        mock.expect("Task").returning(json!({
+            "InfrahubTask": { "edges": [{ "node": {
+                "id": "task-1", "title": "Run generator backbone_links",
+                "state": "RUNNING", "conclusion": "unknown", "progress": null
+            } }] }
+        }));
+
+        let client = mock.client();
+        let task = client
+            .run_generator("gd-1", &["d1", "d2"], Some("b1"))
+            .await
+            .unwrap();
+        assert_eq!(task, "task-1");
+        assert_eq!(mock.calls()[0].branch.as_deref(), Some("b1"));
+        assert!(client.run_generator("gd-2", &[], None).await.is_err());
+
+        let status = client.task("task-1").await.unwrap().unwrap();
+        assert_eq!(status.state.as_deref(), Some("RUNNING"));
+        assert_eq!(status.progress, None);
+    }
+}
@@ -2,7 +2,13 @@
 //!
 //! wrappers for graphql responses and errors.
 
+use crate::error::{Error, Result};
+use graphql_parser::query::{Selection, SelectionSet};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use serde_json::Value;
+use std::collections::HashMap;
 
 /// graphql response wrapper
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,6 +18,9 @@ pub struct GraphQlResponse<T> {
     /// graphql errors array
     #[serde(default)]
     pub errors: Vec<GraphQlError>,
+    /// optional top-level extensions payload (e.g. deprecation notices)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<serde_json::Value>,
 }
 
 impl<T> GraphQlResponse<T> {
@@ -19,6 +28,97 @@ impl<T> GraphQlResponse<T> {
     pub fn has_errors(&self) -> bool {
         !self.errors.is_empty()
     }
+
+    /// the response data, failing on graphql errors or missing data
+    ///
+    /// errors become [`Error::GraphQl`] with the first message and no
+    /// status or body; a response without data fails with
+    /// [`Error::Config`]. useful with [`ErrorPolicy::All`], where the client
+    /// hands back responses carrying both.
+    pub fn into_data(self) -> Result<T> {
+        if let Some(first) = self.errors.first() {
+            let message = first.message.clone();
+            return Err(Error::GraphQl {
+                status: None,
+                errors: self.errors,
+                body: String::new(),
+                message,
+            });
+        }
+        self.data
+            .ok_or_else(|| Error::Config("missing data".to_string()))
+    }
+}
+
+/// typed lookups into untyped response data
+///
+/// paths are dot-separated object keys and array indexes, such as
+/// `BuiltinTag.edges.0.node.name.value`; the empty path is the value itself.
+pub trait ResponseExt {
+    /// the value at `path`, if any
+    fn lookup(&self, path: &str) -> Option<&Value>;
+
+    /// the value at `path` deserialized into `T`
+    ///
+    /// fails with [`Error::Config`] naming the path if it is missing or
+    /// null.
+    fn require<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let value = self
+            .lookup(path)
+            .filter(|value| !value.is_null())
+            .ok_or_else(|| Error::Config(format!("missing data at {path}")))?;
+        Ok(T::deserialize(value)?)
+    }
+}
+
+impl ResponseExt for Value {
+    fn lookup(&self, path: &str) -> Option<&Value> {
+        if path.is_empty() {
+            return Some(self);
+        }
+        path.split('.')
+            .try_fold(self, |value, segment| match value {
+                Value::Object(map) => map.get(segment),
+                Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+                _ => None,
+            })
+    }
+}
+
+impl ResponseExt for GraphQlResponse<Value> {
+    fn lookup(&self, path: &str) -> Option<&Value> {
+        self.data.as_ref()?.lookup(path)
+    }
+}
+
+impl GraphQlResponse<Box<RawValue>> {
+    /// deserialize the unparsed `data` into `T`
+    ///
+    /// see [`crate::Client::execute_raw_parts`]. returns `None` if the
+    /// response carried no data.
+    pub fn parse_data<T: DeserializeOwned>(&self) -> Result<Option<T>> {
+        self.data
+            .as_deref()
+            .map(|data| serde_json::from_str(data.get()))
+            .transpose()
+            .map_err(Error::from)
+    }
+}
+
+/// how graphql `errors` entries in an otherwise successful response are handled
+///
+/// infrahub can return both `data` and `errors`, for example when one field is
+/// denied by permissions. the policy only applies when `data` is present; a
+/// response with errors and no data is always an error.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// convert any error entry into [`crate::Error::GraphQl`] (default)
+    #[default]
+    Raise,
+    /// return the partial response with both `data` and `errors` populated
+    All,
+    /// return the partial response and drop the error entries
+    Ignore,
 }
 
 /// graphql error entry
@@ -37,6 +137,264 @@ pub struct GraphQlError {
     pub extensions: Option<serde_json::Value>,
 }
 
+impl GraphQlError {
+    /// parse the infrahub-specific `extensions` payload into a typed error
+    ///
+    /// returns `None` when the error carries no extensions. unrecognized codes
+    /// map to [`InfrahubError::Other`].
+    pub fn infrahub_error(&self) -> Option<InfrahubError> {
+        let extensions = self.extensions.as_ref()?.as_object()?;
+        let text = |keys: &[&str]| {
+            keys.iter()
+                .find_map(|key| extensions.get(*key))
+                .and_then(|value| match value {
+                    serde_json::Value::String(s) => Some(s.clone()),
+                    serde_json::Value::Number(n) => Some(n.to_string()),
+                    _ => None,
+                })
+        };
+        let code = text(&["code", "error_code"]);
+        let normalized = code.as_deref().unwrap_or_default().to_ascii_uppercase();
+
+        let error = match normalized.as_str() {
+            "404" => InfrahubError::NodeNotFound {
+                kind: text(&["kind", "node_kind"]),
+                identifier: text(&["identifier", "node_id", "id"]),
+            },
+            "400" | "422" => InfrahubError::ValidationError {
+                field: text(&["field", "attribute", "attribute_name"]),
+            },
+            "401" | "403" => InfrahubError::PermissionDenied,
+            c if c.contains("NOT_FOUND") => InfrahubError::NodeNotFound {
+                kind: text(&["kind", "node_kind"]),
+                identifier: text(&["identifier", "node_id", "id"]),
+            },
+            c if c.contains("VALIDATION") => InfrahubError::ValidationError {
+                field: text(&["field", "attribute", "attribute_name"]),
+            },
+            c if c.contains("PERMISSION") || c.contains("FORBIDDEN") || c.contains("UNAUTH") => {
+                InfrahubError::PermissionDenied
+            }
+            _ => InfrahubError::Other { code },
+        };
+        Some(error)
+    }
+}
+
+/// typed view of an infrahub graphql error extension
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InfrahubError {
+    /// the referenced node does not exist (code `404` / `*_NOT_FOUND`)
+    NodeNotFound {
+        /// node kind, if reported
+        kind: Option<String>,
+        /// node id or other identifier, if reported
+        identifier: Option<String>,
+    },
+    /// input failed validation (code `400`/`422` / `*VALIDATION*`)
+    ValidationError {
+        /// attribute or field that failed validation, if reported
+        field: Option<String>,
+    },
+    /// the account lacks permission for the operation (code `401`/`403`)
+    PermissionDenied,
+    /// any other extension code
+    Other {
+        /// raw code, if present
+        code: Option<String>,
+    },
+}
+
+/// extract the operation name from a graphql document, if it declares one
+///
+/// returns the name of the first `query`, `mutation`, or `subscription`
+/// definition, skipping leading fragment definitions. anonymous operations and
+/// shorthand `{ ... }` documents yield `None`.
+pub(crate) fn operation_name(query: &str) -> Option<&str> {
+    let mut rest = query;
+    loop {
+        let trimmed = rest.trim_start();
+        if trimmed.starts_with("fragment") {
+            rest = skip_block(trimmed)?;
+            continue;
+        }
+        let keyword = ["query", "mutation", "subscription"]
+            .into_iter()
+            .find(|kw| trimmed.starts_with(kw));
+        match keyword {
+            Some(kw) => {
+                let after = trimmed[kw.len()..].trim_start();
+                let end = after
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(after.len());
+                return if end == 0 { None } else { Some(&after[..end]) };
+            }
+            None => {
+                // skip leading comments; anything else means no named operation
+                let comment = trimmed.strip_prefix('#')?;
+                rest = comment.split_once('\n').map(|(_, tail)| tail)?;
+            }
+        }
+    }
+}
+
+/// return the text after the first balanced `{ ... }` block
+fn skip_block(text: &str) -> Option<&str> {
+    let start = text.find('{')?;
+    let mut depth = 0usize;
+    for (index, c) in text[start..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&text[start + index + 1..]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// check that every required variable of the first operation in `query` is
+/// present and non-null in `variables`
+///
+/// a variable is required when its type is non-null and it has no default.
+/// documents that fail to parse are left for the server to report.
+pub(crate) fn check_variables(query: &str, variables: Option<&serde_json::Value>) -> Result<()> {
+    use graphql_parser::query::{Definition, OperationDefinition, Type};
+
+    let Ok(document) = graphql_parser::query::parse_query::<&str>(query) else {
+        return Ok(());
+    };
+    let Some((name, definitions)) = document.definitions.iter().find_map(|def| match def {
+        Definition::Operation(OperationDefinition::Query(op)) => {
+            Some((op.name, &op.variable_definitions))
+        }
+        Definition::Operation(OperationDefinition::Mutation(op)) => {
+            Some((op.name, &op.variable_definitions))
+        }
+        Definition::Operation(OperationDefinition::Subscription(op)) => {
+            Some((op.name, &op.variable_definitions))
+        }
+        _ => None,
+    }) else {
+        return Ok(());
+    };
+
+    let missing: Vec<String> = definitions
+        .iter()
+        .filter(|def| matches!(def.var_type, Type::NonNullType(_)) && def.default_value.is_none())
+        .filter(|def| {
+            variables
+                .and_then(|vars| vars.get(def.name))
+                .is_none_or(serde_json::Value::is_null)
+        })
+        .map(|def| format!("${}: {}", def.name, def.var_type))
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    Err(Error::Config(format!(
+        "missing required variables for operation {}: {}",
+        name.unwrap_or("<anonymous>"),
+        missing.join(", ")
+    )))
+}
+
+/// check `query` against the configured length and selection depth limits
+///
+/// length is measured in bytes. depth counts nested field selections, so
+/// `{ a { b } }` is two deep; fragment spreads are expanded and inline
+/// fragments add no level. documents that fail to parse are only checked for
+/// length.
+pub(crate) fn check_query_limits(
+    query: &str,
+    max_length: Option<usize>,
+    max_depth: Option<usize>,
+) -> Result<()> {
+    use graphql_parser::query::{Definition, OperationDefinition};
+
+    let name = || operation_name(query).unwrap_or("<anonymous>");
+    if let Some(limit) = max_length {
+        if query.len() > limit {
+            return Err(Error::Config(format!(
+                "query {} is {} bytes, over the limit of {limit}",
+                name(),
+                query.len()
+            )));
+        }
+    }
+
+    let Some(limit) = max_depth else {
+        return Ok(());
+    };
+    let Ok(document) = graphql_parser::query::parse_query::<&str>(query) else {
+        return Ok(());
+    };
+    let fragments: HashMap<&str, &SelectionSet<&str>> = document
+        .definitions
+        .iter()
+        .filter_map(|def| match def {
+            Definition::Fragment(fragment) => Some((fragment.name, &fragment.selection_set)),
+            _ => None,
+        })
+        .collect();
+    let depth = document
+        .definitions
+        .iter()
+        .filter_map(|def| match def {
+            Definition::Operation(OperationDefinition::Query(op)) => Some(&op.selection_set),
+            Definition::Operation(OperationDefinition::Mutation(op)) => Some(&op.selection_set),
+            Definition::Operation(OperationDefinition::Subscription(op)) => Some(&op.selection_set),
+            Definition::Operation(OperationDefinition::SelectionSet(set)) => Some(set),
+            Definition::Fragment(_) => None,
+        })
+        .map(|set| selection_depth(set, &fragments, &mut Vec::new()))
+        .max()
+        .unwrap_or(0);
+    if depth > limit {
+        return Err(Error::Config(format!(
+            "query {} selects {depth} levels deep, over the limit of {limit}",
+            name()
+        )));
+    }
+    Ok(())
+}
+
+/// deepest field nesting below `set`, expanding fragment spreads once per path
+fn selection_depth<'a>(
+    set: &'a SelectionSet<'a, &'a str>,
+    fragments: &HashMap<&'a str, &'a SelectionSet<'a, &'a str>>,
+    visiting: &mut Vec<&'a str>,
+) -> usize {
+    set.items
+        .iter()
+        .map(|item| match item {
+            Selection::Field(field) => {
+                1 + selection_depth(&field.selection_set, fragments, visiting)
+            }
+            Selection::InlineFragment(inline) => {
+                selection_depth(&inline.selection_set, fragments, visiting)
+            }
+            Selection::FragmentSpread(spread) => {
+                let name = spread.fragment_name;
+                match fragments.get(name) {
+                    Some(fragment) if !visiting.contains(&name) => {
+                        visiting.push(name);
+                        let depth = selection_depth(fragment, fragments, visiting);
+                        visiting.pop();
+                        depth
+                    }
+                    _ => 0,
+                }
+            }
+        })
+        .max()
+        .unwrap_or(0)
+}
+
 /// graphql error location
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GraphQlLocation {
@@ -50,11 +408,146 @@ pub struct GraphQlLocation {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_into_data() {
+        let ok: GraphQlResponse<Value> =
+            serde_json::from_value(serde_json::json!({ "data": { "ok": true } })).unwrap();
+        assert_eq!(ok.into_data().unwrap()["ok"], true);
+
+        let partial: GraphQlResponse<Value> = serde_json::from_value(serde_json::json!({
+            "data": { "ok": true },
+            "errors": [{ "message": "denied", "path": ["secret"] }],
+        }))
+        .unwrap();
+        match partial.into_data().unwrap_err() {
+            Error::GraphQl {
+                status,
+                errors,
+                message,
+                ..
+            } => {
+                assert_eq!(status, None);
+                assert_eq!(errors.len(), 1);
+                assert_eq!(message, "denied");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+
+        let empty: GraphQlResponse<Value> =
+            serde_json::from_value(serde_json::json!({ "data": null })).unwrap();
+        assert!(matches!(empty.into_data(), Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn test_response_ext_require() {
+        let response: GraphQlResponse<Value> = serde_json::from_value(serde_json::json!({
+            "data": {
+                "BuiltinTag": {
+                    "count": 2,
+                    "edges": [{ "node": { "id": "t1", "name": { "value": "red" } } }],
+                },
+                "gone": null,
+            },
+        }))
+        .unwrap();
+        assert_eq!(response.require::<u64>("BuiltinTag.count").unwrap(), 2);
+        assert_eq!(
+            response
+                .require::<String>("BuiltinTag.edges.0.node.name.value")
+                .unwrap(),
+            "red"
+        );
+        assert!(response.lookup("BuiltinTag.edges.1").is_none());
+        assert!(response.lookup("BuiltinTag.count.x").is_none());
+        assert_eq!(response.lookup("").unwrap()["gone"], Value::Null);
+
+        let err = response.require::<String>("gone").unwrap_err();
+        assert!(err.to_string().contains("missing data at gone"));
+        assert!(matches!(
+            response.require::<String>("BuiltinTag.count"),
+            Err(Error::Json(_))
+        ));
+        let data = response.into_data().unwrap();
+        assert_eq!(
+            data.require::<String>("BuiltinTag.edges.0.node.id")
+                .unwrap(),
+            "t1"
+        );
+    }
+
+    #[test]
+    fn test_parse_raw_data() {
+        let response: GraphQlResponse<Box<RawValue>> = serde_json::from_str(
+            r#"{"data": {"BuiltinTag": {"count": 2}}, "extensions": {"a": 1}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            response.data.as_ref().unwrap().get(),
+            r#"{"BuiltinTag": {"count": 2}}"#
+        );
+        let data: serde_json::Value = response.parse_data().unwrap().unwrap();
+        assert_eq!(data["BuiltinTag"]["count"], 2);
+        assert!(response.parse_data::<Vec<u8>>().is_err());
+
+        let empty: GraphQlResponse<Box<RawValue>> =
+            serde_json::from_str(r#"{"data": null, "errors": []}"#).unwrap();
+        assert!(empty.parse_data::<serde_json::Value>().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_check_variables() {
+        let query = "query GetDevice($id: ID!, $limit: Int!, $offset: Int = 0, $name: String, $first: Int! = 10) { InfraDevice(ids: [$id]) { count } }";
+        let vars = serde_json::json!({ "id": "d1", "limit": 5 });
+        assert!(check_variables(query, Some(&vars)).is_ok());
+
+        let vars = serde_json::json!({ "id": "d1", "limit": null });
+        let err = check_variables(query, Some(&vars)).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("GetDevice"));
+        assert!(message.contains("$limit: Int!"));
+        assert!(!message.contains("$id"));
+
+        let err = check_variables(query, None).unwrap_err();
+        assert!(err.to_string().contains("$id: ID!, $limit: Int!"));
+    }
+
+    #[test]
+    fn test_check_variables_skips_unparseable_and_shorthand() {
+        assert!(check_variables("{ InfrahubInfo { version } }", None).is_ok());
+        assert!(check_variables("query Broken($id: ID!) {", None).is_ok());
+        assert!(check_variables(
+            "mutation ($data: DeleteInput!) { BuiltinTagDelete(data: $data) { ok } }",
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_check_query_limits() {
+        let query = "query Deep { a { b { c } } d }";
+        assert!(check_query_limits(query, Some(100), Some(3)).is_ok());
+        let err = check_query_limits(query, Some(10), None).unwrap_err();
+        assert!(err.to_string().contains("query Deep is 30 bytes"));
+        let err = check_query_limits(query, None, Some(2)).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("3 levels deep, over the limit of 2"));
+
+        let fragments = "query F { a { ...Nested ... on X { b } } } \
+            fragment Nested on A { c { d } } fragment Loop on A { e { ...Loop } }";
+        assert!(check_query_limits(fragments, None, Some(3)).is_ok());
+        assert!(check_query_limits(fragments, None, Some(2)).is_err());
+        let looping = "{ a { ...Loop } } fragment Loop on A { e { ...Loop } }";
+        assert!(check_query_limits(looping, None, Some(2)).is_ok());
+        assert!(check_query_limits("{ a {", None, Some(0)).is_ok());
+    }
+
     #[test]
     fn test_has_errors() {
         let ok: GraphQlResponse<serde_json::Value> = GraphQlResponse {
             data: Some(serde_json::json!({"ok": true})),
             errors: vec![],
+            extensions: None,
         };
         assert!(!ok.has_errors());
 
@@ -66,7 +559,115 @@ mod tests {
                 path: vec![],
                 extensions: None,
             }],
+            extensions: None,
         };
         assert!(err.has_errors());
     }
+
+    #[test]
+    fn test_extensions_deserialized() {
+        let response: GraphQlResponse<serde_json::Value> =
+            serde_json::from_str(r#"{"data": {"ok": true}, "extensions": {"deprecations": []}}"#)
+                .unwrap();
+        assert!(response.extensions.is_some());
+
+        let response: GraphQlResponse<serde_json::Value> =
+            serde_json::from_str(r#"{"data": {"ok": true}}"#).unwrap();
+        assert!(response.extensions.is_none());
+    }
+
+    fn error_with(extensions: serde_json::Value) -> GraphQlError {
+        GraphQlError {
+            message: "boom".to_string(),
+            locations: vec![],
+            path: vec![],
+            extensions: Some(extensions),
+        }
+    }
+
+    #[test]
+    fn test_infrahub_error_numeric_codes() {
+        let err =
+            error_with(serde_json::json!({"code": 404, "kind": "InfraDevice", "node_id": "abc"}));
+        assert_eq!(
+            err.infrahub_error(),
+            Some(InfrahubError::NodeNotFound {
+                kind: Some("InfraDevice".to_string()),
+                identifier: Some("abc".to_string()),
+            })
+        );
+
+        let err = error_with(serde_json::json!({"code": 422, "attribute_name": "name"}));
+        assert_eq!(
+            err.infrahub_error(),
+            Some(InfrahubError::ValidationError {
+                field: Some("name".to_string())
+            })
+        );
+
+        let err = error_with(serde_json::json!({"code": 403}));
+        assert_eq!(err.infrahub_error(), Some(InfrahubError::PermissionDenied));
+    }
+
+    #[test]
+    fn test_infrahub_error_string_codes() {
+        let err = error_with(serde_json::json!({"code": "NODE_NOT_FOUND"}));
+        assert!(matches!(
+            err.infrahub_error(),
+            Some(InfrahubError::NodeNotFound { .. })
+        ));
+        let err = error_with(serde_json::json!({"code": "validation_error", "field": "asn"}));
+        assert_eq!(
+            err.infrahub_error(),
+            Some(InfrahubError::ValidationError {
+                field: Some("asn".to_string())
+            })
+        );
+        let err = error_with(serde_json::json!({"code": "PERMISSION_DENIED"}));
+        assert_eq!(err.infrahub_error(), Some(InfrahubError::PermissionDenied));
+        let err = error_with(serde_json::json!({"code": 500}));
+        assert_eq!(
+            err.infrahub_error(),
+            Some(InfrahubError::Other {
+                code: Some("500".to_string())
+            })
+        );
+    }
+
+    #[test]
+    fn test_infrahub_error_without_extensions() {
+        let err = GraphQlError {
+            message: "boom".to_string(),
+            locations: vec![],
+            path: vec![],
+            extensions: None,
+        };
+        assert_eq!(err.infrahub_error(), None);
+    }
+
+    #[test]
+    fn test_operation_name() {
+        assert_eq!(
+            operation_name("query GetDevice($id: ID!) { x }"),
+            Some("GetDevice")
+        );
+        assert_eq!(
+            operation_name("mutation TagCreate { x }"),
+            Some("TagCreate")
+        );
+        assert_eq!(
+            operation_name("  # comment\nquery Named { x }"),
+            Some("Named")
+        );
+        assert_eq!(operation_name("query { x }"), None);
+        assert_eq!(operation_name("query($id: ID) { x }"), None);
+        assert_eq!(operation_name("{ InfrahubInfo { version } }"), None);
+        assert_eq!(
+            operation_name(
+                "fragment Name on InfraDevice { name { value } }\nquery Devices { InfraDevice { edges { node { ...Name } } } }"
+            ),
+            Some("Devices")
+        );
+        assert_eq!(operation_name("fragment Broken on X { a"), None);
+    }
 }
@@ -0,0 +1,232 @@
+//! group helpers
+//!
+//! [`Client::groups`] wraps the `RelationshipAdd` and `RelationshipRemove`
+//! mutations on the `members` and `subscribers` relationships of
+//! `CoreStandardGroup` and the other `CoreGroup` kinds, and queries them
+//! back. artifact and generator definitions target the members of a group.
+//!
+//! ```no_run
+//! use infrahub::{Client, ClientConfig};
+//!
+//! # async fn example() -> infrahub::Result<()> {
+//! let client = Client::new(ClientConfig::new("http://localhost:8000", "token"))?;
+//! let groups = client.groups();
+//! let group = match groups.id("edge_devices", None).await? {
+//!     Some(id) => id,
+//!     None => groups.create("edge_devices", None).await?,
+//! };
+//! groups.add_members(&group, &["device-1", "device-2"], None).await?;
+//! for member in groups.members(&group, None).await? {
+//!     println!("{} {}", member.kind, member.id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::account::related;
+use crate::branch::ensure_ok;
+use crate::client::Client;
+use crate::error::{Error, Result};
+use serde::Deserialize;
+use serde_json::json;
+
+/// group operations, returned by [`Client::groups`]
+#[derive(Clone, Copy)]
+pub struct Groups<'a> {
+    client: &'a Client,
+}
+
+/// a member or subscriber of a group
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GroupMember {
+    /// node id
+    pub id: String,
+    /// node kind
+    #[serde(rename = "__typename")]
+    pub kind: String,
+    /// display label, if the kind defines one
+    pub display_label: Option<String>,
+}
+
+impl Client {
+    /// membership and subscriber operations on groups
+    pub fn groups(&self) -> Groups<'_> {
+        Groups { client: self }
+    }
+}
+
+impl Groups<'_> {
+    /// look up a group id by name
+    pub async fn id(&self, name: &str, branch: Option<&str>) -> Result<Option<String>> {
+        let query = "query GroupId($name: String!) { CoreGroup(name__value: $name) { edges { node { id } } } }";
+        let vars = json!({ "name": name });
+        let response = self.client.execute_raw(query, Some(vars), branch).await?;
+        let data = response.into_data()?;
+        Ok(data["CoreGroup"]["edges"][0]["node"]["id"]
+            .as_str()
+            .map(str::to_string))
+    }
+
+    /// create an empty `CoreStandardGroup`, returning its id
+    pub async fn create(&self, name: &str, branch: Option<&str>) -> Result<String> {
+        self.client
+            .create_node(
+                "CoreStandardGroup",
+                json!({ "name": { "value": name } }),
+                branch,
+            )
+            .await
+    }
+
+    /// add nodes to a group, keeping existing members
+    pub async fn add_members(
+        &self,
+        group: &str,
+        members: &[&str],
+        branch: Option<&str>,
+    ) -> Result<()> {
+        self.edit(group, "members", "RelationshipAdd", members, branch)
+            .await
+    }
+
+    /// remove nodes from a group
+    pub async fn remove_members(
+        &self,
+        group: &str,
+        members: &[&str],
+        branch: Option<&str>,
+    ) -> Result<()> {
+        self.edit(group, "members", "RelationshipRemove", members, branch)
+            .await
+    }
+
+    /// add subscribers to a group, keeping existing ones
+    pub async fn add_subscribers(
+        &self,
+        group: &str,
+        subscribers: &[&str],
+        branch: Option<&str>,
+    ) -> Result<()> {
+        self.edit(group, "subscribers", "RelationshipAdd", subscribers, branch)
+            .await
+    }
+
+    /// remove subscribers from a group
+    pub async fn remove_subscribers(
+        &self,
+        group: &str,
+        subscribers: &[&str],
+        branch: Option<&str>,
+    ) -> Result<()> {
+        self.edit(
+            group,
+            "subscribers",
+            "RelationshipRemove",
+            subscribers,
+            branch,
+        )
+        .await
+    }
+
+    /// list the members of a group
+    pub async fn members(&self, group: &str, branch: Option<&str>) -> Result<Vec<GroupMember>> {
+        self.peers(group, "members", branch).await
+    }
+
+    /// list the subscribers of a group
+    pub async fn subscribers(&self, group: &str, branch: Option<&str>) -> Result<Vec<GroupMember>> {
+        self.peers(group, "subscribers", branch).await
+    }
+
+    async fn edit(
+        &self,
+        group: &str,
+        relationship: &str,
+        mutation: &str,
+        nodes: &[&str],
+        branch: Option<&str>,
+    ) -> Result<()> {
+        let query = format!(
+            "mutation EditGroup($data: RelationshipNodesInput!) {{ {mutation}(data: $data) {{ ok }} }}"
+        );
+        let vars = json!({
+            "data": { "id": group, "name": relationship, "nodes": related(nodes) }
+        });
+        let response = self.client.execute_raw(&query, Some(vars), branch).await?;
+        ensure_ok(response.data, mutation)
+    }
+
+    async fn peers(
+        &self,
+        group: &str,
+        relationship: &str,
+        branch: Option<&str>,
+    ) -> Result<Vec<GroupMember>> {
+        let query = format!(
+            "query GroupPeers($ids: [ID]) {{ CoreGroup(ids: $ids) {{ edges {{ node {{ {relationship} {{ edges {{ node {{ id __typename display_label }} }} }} }} }} }} }}"
+        );
+        let response = self
+            .client
+            .execute_raw(&query, Some(json!({ "ids": [group] })), branch)
+            .await?;
+        let data = response.into_data()?;
+        let node = &data["CoreGroup"]["edges"][0]["node"];
+        if node.is_null() {
+            return Err(Error::Config(format!("group {group} not found")));
+        }
+        match node[relationship]["edges"].as_array() {
+            Some(edges) => edges
+                .iter()
+                .map(|edge| Ok(GroupMember::deserialize(&edge["node"])?))
+                .collect(),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockClient;
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_group_membership() {
+        let mock = MockClient::new();
+        mock.expect("EditGroup")
+            .with_variables(json!({
+                "data": { "id": "g1", "name": "members", "nodes": [{ "id": "d1" }, { "id": "d2" }] }
+            }))
+            .returning(json!({ "RelationshipAdd": { "ok": true } }));
+        mock.expect("EditGroup")
+            .with_variables(json!({
+                "data": { "id": "g1", "name": "subscribers", "nodes": [{ "id": "a1" }] }
+            }))
+            .returning(json!({ "RelationshipRemove": { "ok": true } }));
+        mock.expect("GroupPeers").times(1).returning(json!({
+            "CoreGroup": { "edges": [{ "node": { "members": { "edges": [
+                { "node": { "id": "d1", "__typename": "InfraDevice", "display_label": "spine1" } }
+            ] } } }] }
+        }));
+        mock.expect("GroupPeers")
+            .returning(json!({ "CoreGroup": { "edges": [] } }));
+
+        let client = mock.client();
+        let groups = client.groups();
+        groups
+            .add_members("g1", &["d1", "d2"], Some("b1"))
+            .await
+            .unwrap();
+        groups
+            .remove_subscribers("g1", &["a1"], None)
+            .await
+            .unwrap();
+        assert_eq!(mock.calls()[0].branch.as_deref(), Some("b1"));
+
+        let members = groups.members("g1", None).await.unwrap();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].kind, "InfraDevice");
+        assert_eq!(members[0].display_label.as_deref(), Some("spine1"));
+        assert!(groups.members("missing", None).await.is_err());
+    }
+}
@@ -0,0 +1,226 @@
+//! host policy
+//!
+//! restricts which hosts a client may talk to, for services that accept
+//! infrahub urls from users and must not be usable as an ssrf vector.
+
+use crate::error::{Error, Result};
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use url::{Host, Url};
+
+/// allowlist, denylist, and private address blocking for base urls
+///
+/// host patterns are either an exact host name or ip (`infrahub.example.com`)
+/// or a wildcard matching any subdomain (`*.example.com`), compared
+/// case-insensitively. the denylist wins over the allowlist, and an empty
+/// allowlist allows every host that is not denied.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HostPolicy {
+    allow: Vec<String>,
+    deny: Vec<String>,
+    block_private: bool,
+}
+
+impl HostPolicy {
+    /// create a policy that allows every host
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// allow hosts matching `pattern`; once any pattern is allowed, all other
+    /// hosts are rejected
+    pub fn allow(mut self, pattern: impl AsRef<str>) -> Self {
+        self.allow.push(normalize(pattern.as_ref()));
+        self
+    }
+
+    /// reject hosts matching `pattern`
+    pub fn deny(mut self, pattern: impl AsRef<str>) -> Self {
+        self.deny.push(normalize(pattern.as_ref()));
+        self
+    }
+
+    /// reject loopback, private, link-local, and unspecified addresses
+    ///
+    /// applies to ip literals and `localhost` in the base url and, for clients
+    /// built by [`crate::Client::new`], to every address a host name resolves
+    /// to at connect time.
+    pub fn block_private_ips(mut self, block: bool) -> Self {
+        self.block_private = block;
+        self
+    }
+
+    /// true if private addresses are blocked
+    pub fn blocks_private_ips(&self) -> bool {
+        self.block_private
+    }
+
+    /// check that `url` is permitted by this policy
+    pub fn check(&self, url: &Url) -> Result<()> {
+        let host = match url.host() {
+            Some(host) => host,
+            None => return Err(Error::Config(format!("url has no host: {url}"))),
+        };
+        let name = match &host {
+            Host::Domain(domain) => domain.trim_end_matches('.').to_ascii_lowercase(),
+            Host::Ipv4(ip) => ip.to_string(),
+            Host::Ipv6(ip) => ip.to_string(),
+        };
+
+        if self.deny.iter().any(|pattern| matches(pattern, &name)) {
+            return Err(Error::Config(format!("host {name} is denied")));
+        }
+        if !self.allow.is_empty() && !self.allow.iter().any(|pattern| matches(pattern, &name)) {
+            return Err(Error::Config(format!("host {name} is not allowed")));
+        }
+        if self.block_private {
+            let private = match host {
+                Host::Domain(_) => name == "localhost" || name.ends_with(".localhost"),
+                Host::Ipv4(ip) => is_private(IpAddr::V4(ip)),
+                Host::Ipv6(ip) => is_private(IpAddr::V6(ip)),
+            };
+            if private {
+                return Err(Error::Config(format!("host {name} is a private address")));
+            }
+        }
+        Ok(())
+    }
+
+    /// check that `host`, pinned to `addr` by a static dns override, does
+    /// not bypass private address blocking
+    pub(crate) fn check_resolved(&self, host: &str, addr: IpAddr) -> Result<()> {
+        if self.block_private && is_private(addr) {
+            return Err(Error::Config(format!(
+                "host {host} is pinned to private address {addr}"
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn normalize(pattern: &str) -> String {
+    pattern
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .trim_end_matches('.')
+        .to_ascii_lowercase()
+}
+
+fn matches(pattern: &str, host: &str) -> bool {
+    match pattern.strip_prefix("*.") {
+        Some(suffix) => host
+            .strip_suffix(suffix)
+            .is_some_and(|prefix| prefix.ends_with('.')),
+        None => pattern == host,
+    }
+}
+
+/// true for addresses that reach the local host or a private network
+fn is_private(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_private_v4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_private_v4(mapped),
+            None => is_private_v6(ip),
+        },
+    }
+}
+
+fn is_private_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        // shared address space (rfc 6598)
+        || (a == 100 && (64..128).contains(&b))
+}
+
+fn is_private_v6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    ip.is_loopback()
+        || ip.is_unspecified()
+        // unique local (fc00::/7)
+        || (first & 0xfe00) == 0xfc00
+        // link-local (fe80::/10)
+        || (first & 0xffc0) == 0xfe80
+}
+
+/// dns resolver that drops private addresses, so a public host name cannot be
+/// pointed at an internal service
+#[derive(Debug)]
+pub(crate) struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0))
+                .await?
+                .filter(|addr| !is_private(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(format!("host {host} resolves only to private addresses").into());
+            }
+            let addrs: Addrs = Box::new(addrs.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(policy: &HostPolicy, url: &str) -> bool {
+        policy.check(&Url::parse(url).unwrap()).is_ok()
+    }
+
+    #[test]
+    fn test_allow_and_deny() {
+        let policy = HostPolicy::new()
+            .allow("*.example.com")
+            .allow("infrahub.internal")
+            .deny("admin.example.com");
+        assert!(check(&policy, "https://infrahub.example.com"));
+        assert!(check(&policy, "https://a.b.EXAMPLE.com."));
+        assert!(check(&policy, "http://infrahub.internal:8000"));
+        assert!(!check(&policy, "https://example.com"));
+        assert!(!check(&policy, "https://badexample.com"));
+        assert!(!check(&policy, "https://admin.example.com"));
+        assert!(check(&HostPolicy::new(), "http://127.0.0.1"));
+    }
+
+    #[test]
+    fn test_block_private() {
+        let policy = HostPolicy::new().block_private_ips(true);
+        assert!(check(&policy, "https://infrahub.example.com"));
+        assert!(check(&policy, "https://8.8.8.8"));
+        for url in [
+            "http://localhost:8000",
+            "http://127.0.0.1",
+            "http://10.1.2.3",
+            "http://172.16.0.1",
+            "http://192.168.1.1",
+            "http://169.254.169.254",
+            "http://100.64.0.1",
+            "http://0.0.0.0",
+            "http://[::1]",
+            "http://[fd00::1]",
+            "http://[fe80::1]",
+            "http://[::ffff:10.0.0.1]",
+        ] {
+            assert!(!check(&policy, url), "{url} should be blocked");
+        }
+    }
+
+    #[test]
+    fn test_ip_patterns() {
+        let policy = HostPolicy::new().deny("[::1]").deny("10.0.0.1");
+        assert!(!check(&policy, "http://[::1]"));
+        assert!(!check(&policy, "http://10.0.0.1"));
+        assert!(check(&policy, "http://10.0.0.2"));
+    }
+}
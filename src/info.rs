@@ -0,0 +1,86 @@
+//! server info
+//!
+//! the built-in `InfrahubInfo` query. it is written against the
+//! [`Operation`] trait the same way generated operations are, so it doubles
+//! as the reference for hand-written typed operations.
+//!
+//! ```no_run
+//! use infrahub::{Client, ClientConfig};
+//!
+//! # async fn example() -> infrahub::Result<()> {
+//! let client = Client::new(ClientConfig::new("http://localhost:8000", "token"))?;
+//! let info = client.info().await?;
+//! println!("infrahub {} ({})", info.version, info.deployment_id);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::operation::Operation;
+use serde::{Deserialize, Serialize};
+
+/// the `InfrahubInfo` operation
+#[derive(Debug, Clone, Copy)]
+pub struct InfrahubInfoQuery;
+
+impl Operation for InfrahubInfoQuery {
+    const NAME: &'static str = "InfrahubInfo";
+    const QUERY: &'static str = "query InfrahubInfo { InfrahubInfo { deployment_id version } }";
+    type Response = InfrahubInfoData;
+}
+
+/// response payload of [`InfrahubInfoQuery`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InfrahubInfoData {
+    /// the `InfrahubInfo` field
+    #[serde(rename = "InfrahubInfo")]
+    pub info: InfrahubInfo,
+}
+
+/// deployment details reported by the server
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InfrahubInfo {
+    /// unique id of the infrahub deployment
+    pub deployment_id: String,
+    /// infrahub release, e.g. `1.9.8`
+    pub version: String,
+}
+
+impl Client {
+    /// fetch the server's deployment id and version
+    pub async fn info(&self) -> Result<InfrahubInfo> {
+        let response = self
+            .execute_operation::<InfrahubInfoQuery>(None, None)
+            .await?;
+        response
+            .data
+            .map(|data| data.info)
+            .ok_or_else(|| Error::Config("InfrahubInfo returned no data".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockClient;
+    use serde_json::json;
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_info() {
+        let mock = MockClient::new();
+        mock.expect(InfrahubInfoQuery::NAME)
+            .returning(json!({ "InfrahubInfo": { "deployment_id": "d-1", "version": "1.9.8" } }));
+        let info = mock.client().info().await.unwrap();
+        assert_eq!(
+            info,
+            InfrahubInfo {
+                deployment_id: "d-1".to_string(),
+                version: "1.9.8".to_string(),
+            }
+        );
+        assert!(InfrahubInfoQuery::check_variables(None).is_ok());
+        mock.verify();
+    }
+}
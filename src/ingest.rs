@@ -0,0 +1,354 @@
+//! inventory ingestion
+//!
+//! map records from an external inventory (netbox exports, librenms rows,
+//! napalm facts, ...) onto infrahub create or upsert inputs, and run the
+//! mutations. enabled with the `ingest` feature.
+//!
+//! a [`RecordMapper`] turns one json record into a mutation input. implement
+//! it by hand, e.g. by filling a generated `*CreateInput` and converting it
+//! with `serde_json::to_value`, or describe the mapping in toml with
+//! [`FieldMapping`]:
+//!
+//! ```toml
+//! kind = "InfraDevice"
+//! mode = "upsert"
+//!
+//! [fields]
+//! name = "hostname"
+//! description = { from = "facts.model", default = "unknown" }
+//! serial = { from = "facts.serial_number", transform = "uppercase" }
+//! status = { value = "active" }
+//! site = { from = "site", peer = true }
+//! ```
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// mutation used for each record
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IngestMode {
+    /// `<Kind>Create`
+    #[default]
+    Create,
+    /// `<Kind>Upsert`, matching existing nodes by their hfid
+    Upsert,
+}
+
+/// maps external records onto mutation inputs for one kind
+pub trait RecordMapper {
+    /// the node kind created for each record
+    fn kind(&self) -> &str;
+
+    /// whether records are created or upserted
+    fn mode(&self) -> IngestMode {
+        IngestMode::Create
+    }
+
+    /// the mutation `data` input for a record, or `None` to skip it
+    fn map(&self, record: &Value) -> Result<Option<Value>>;
+}
+
+/// a [`RecordMapper`] defined by field rules
+///
+/// each rule fills one input field from a dotted path in the record
+/// (`facts.serial_number`, `interfaces.0.name`), or from a constant. plain
+/// values become `{ "value": .. }` attributes; `peer = true` fields become
+/// related nodes looked up by hfid.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FieldMapping {
+    kind: String,
+    #[serde(default)]
+    mode: IngestMode,
+    #[serde(default)]
+    fields: BTreeMap<String, FieldRule>,
+}
+
+/// how one input field is filled
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum FieldRule {
+    /// shorthand for `{ from = "<path>" }`
+    Path(String),
+    Rule(Rule),
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Rule {
+    /// dotted path in the record
+    from: Option<String>,
+    /// constant value, used when `from` is not set
+    value: Option<Value>,
+    /// value used when the path is missing or null
+    default: Option<Value>,
+    /// skip the record when the field has no value
+    #[serde(default)]
+    required: bool,
+    /// a related node referenced by hfid instead of an attribute
+    #[serde(default)]
+    peer: bool,
+    /// string transform applied to the value
+    transform: Option<Transform>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Transform {
+    Lowercase,
+    Uppercase,
+    Trim,
+}
+
+impl FieldMapping {
+    /// parse mapping rules from toml
+    pub fn from_toml(text: &str) -> Result<Self> {
+        let mapping: Self = toml::from_str(text)
+            .map_err(|err| Error::Config(format!("invalid field mapping: {err}")))?;
+        mapping.validate()?;
+        Ok(mapping)
+    }
+
+    /// read mapping rules from a toml file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|err| {
+            Error::Config(format!(
+                "failed to read field mapping {}: {err}",
+                path.display()
+            ))
+        })?;
+        Self::from_toml(&text)
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_kind(&self.kind)?;
+        for (field, rule) in &self.fields {
+            if let FieldRule::Rule(rule) = rule {
+                if rule.from.is_none() && rule.value.is_none() {
+                    return Err(Error::Config(format!(
+                        "field mapping for {field} needs `from` or `value`"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RecordMapper for FieldMapping {
+    fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    fn mode(&self) -> IngestMode {
+        self.mode
+    }
+
+    fn map(&self, record: &Value) -> Result<Option<Value>> {
+        let mut input = Map::new();
+        for (field, rule) in &self.fields {
+            let rule = match rule {
+                FieldRule::Path(path) => Rule {
+                    from: Some(path.clone()),
+                    ..Rule::default()
+                },
+                FieldRule::Rule(rule) => rule.clone(),
+            };
+            let value = match &rule.from {
+                Some(path) => lookup(record, path).cloned(),
+                None => rule.value.clone(),
+            }
+            .filter(|value| !value.is_null())
+            .or(rule.default);
+            let Some(value) = value else {
+                if rule.required {
+                    return Ok(None);
+                }
+                continue;
+            };
+            let value = match (rule.transform, value) {
+                (Some(transform), Value::String(text)) => Value::String(transform.apply(&text)),
+                (_, value) => value,
+            };
+            let value = if rule.peer {
+                match value {
+                    Value::Array(hfid) => json!({ "hfid": hfid }),
+                    value => json!({ "hfid": [value] }),
+                }
+            } else {
+                json!({ "value": value })
+            };
+            input.insert(field.clone(), value);
+        }
+        Ok(Some(Value::Object(input)))
+    }
+}
+
+impl Transform {
+    fn apply(self, text: &str) -> String {
+        match self {
+            Transform::Lowercase => text.to_lowercase(),
+            Transform::Uppercase => text.to_uppercase(),
+            Transform::Trim => text.trim().to_string(),
+        }
+    }
+}
+
+/// outcome of [`Client::ingest`]
+#[derive(Debug, Default)]
+pub struct IngestReport {
+    /// ids of created or upserted nodes, in record order
+    pub ids: Vec<String>,
+    /// records the mapper skipped
+    pub skipped: usize,
+    /// records that failed to map or write, by position in the input
+    pub failed: Vec<(usize, Error)>,
+}
+
+impl Client {
+    /// map each record with `mapper` and create or upsert the resulting node
+    ///
+    /// records are written one at a time; a failing record is reported in
+    /// [`IngestReport::failed`] and does not stop the run. only a client
+    /// shutdown ends it early.
+    pub async fn ingest<M, I>(
+        &self,
+        mapper: &M,
+        records: I,
+        branch: Option<&str>,
+    ) -> Result<IngestReport>
+    where
+        M: RecordMapper + ?Sized,
+        I: IntoIterator<Item = Value>,
+    {
+        let kind = mapper.kind();
+        check_kind(kind)?;
+        let mutation = match mapper.mode() {
+            IngestMode::Create => format!("{kind}Create"),
+            IngestMode::Upsert => format!("{kind}Upsert"),
+        };
+        let query = format!(
+            "mutation Ingest($data: {mutation}Input!) {{ {mutation}(data: $data) {{ ok object {{ id }} }} }}"
+        );
+
+        let mut report = IngestReport::default();
+        for (index, record) in records.into_iter().enumerate() {
+            let data = match mapper.map(&record) {
+                Ok(Some(data)) => data,
+                Ok(None) => {
+                    report.skipped += 1;
+                    continue;
+                }
+                Err(err) => {
+                    report.failed.push((index, err));
+                    continue;
+                }
+            };
+            let result = self
+                .execute_raw(&query, Some(json!({ "data": data })), branch)
+                .await
+                .and_then(|response| {
+                    response
+                        .data
+                        .as_ref()
+                        .and_then(|data| data[&mutation]["object"]["id"].as_str())
+                        .map(str::to_string)
+                        .ok_or_else(|| Error::Config(format!("{mutation} did not return an id")))
+                });
+            match result {
+                Ok(id) => report.ids.push(id),
+                Err(Error::Shutdown) => return Err(Error::Shutdown),
+                Err(err) => report.failed.push((index, err)),
+            }
+        }
+        Ok(report)
+    }
+}
+
+/// reject kinds that are not graphql names, since they are spliced into the query
+fn check_kind(kind: &str) -> Result<()> {
+    let valid = kind.starts_with(|c: char| c.is_ascii_alphabetic())
+        && kind.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::Config(format!("invalid node kind: {kind:?}")))
+    }
+}
+
+/// follow a dotted path through objects and arrays
+fn lookup<'a>(record: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(record, |value, segment| match value {
+            Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+            value => value.get(segment),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAPPING: &str = r#"
+kind = "InfraDevice"
+mode = "upsert"
+
+[fields]
+name = "hostname"
+description = { from = "facts.model", default = "unknown" }
+serial = { from = "facts.serial_number", transform = "uppercase", required = true }
+status = { value = "active" }
+site = { from = "site", peer = true }
+primary = { from = "interfaces.0.name" }
+"#;
+
+    #[test]
+    fn test_field_mapping() {
+        let mapping = FieldMapping::from_toml(MAPPING).unwrap();
+        assert_eq!(mapping.kind(), "InfraDevice");
+        assert_eq!(mapping.mode(), IngestMode::Upsert);
+
+        let record = json!({
+            "hostname": "edge-01",
+            "site": "ams1",
+            "facts": { "model": null, "serial_number": "abc123" },
+            "interfaces": [{ "name": "eth0" }],
+        });
+        assert_eq!(
+            mapping.map(&record).unwrap().unwrap(),
+            json!({
+                "name": { "value": "edge-01" },
+                "description": { "value": "unknown" },
+                "serial": { "value": "ABC123" },
+                "status": { "value": "active" },
+                "site": { "hfid": ["ams1"] },
+                "primary": { "value": "eth0" },
+            })
+        );
+
+        // required field missing: skipped
+        let record = json!({ "hostname": "edge-02" });
+        assert!(mapping.map(&record).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_field_mapping_errors() {
+        assert!(FieldMapping::from_toml("kind = \"Infra Device\"").is_err());
+        assert!(FieldMapping::from_toml("kind = \"X\"\n[fields]\nname = { default = 1 }").is_err());
+        assert!(FieldMapping::from_toml("kind = \"X\"\nunknown = 1").is_err());
+        assert!(FieldMapping::from_toml("kind = \"X\"\nmode = \"merge\"").is_err());
+    }
+
+    #[test]
+    fn test_check_kind() {
+        assert!(check_kind("InfraDevice").is_ok());
+        assert!(check_kind("").is_err());
+        assert!(check_kind("Infra(Device)").is_err());
+    }
+}
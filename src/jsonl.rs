@@ -0,0 +1,197 @@
+//! bulk export and import as json lines
+//!
+//! stream every node of a kind to newline-delimited json, one node per line,
+//! and replay such a file onto a branch as upserts. unlike snapshots, a page
+//! is written as soon as it arrives and lines are read as they are upserted,
+//! so neither side holds the whole kind in memory.
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::nodes::{node_to_input, nodes_query, validate_kind, NODE_PAGE_SIZE};
+use futures_util::stream::{self, StreamExt};
+use serde_json::Value;
+use std::io::{BufRead, Write};
+use std::pin::pin;
+
+/// one line of an import that could not be upserted
+#[derive(Debug)]
+pub struct ImportFailure {
+    /// 1-based line number in the input
+    pub line: usize,
+    /// the upsert error
+    pub error: Error,
+}
+
+/// summary of [`Client::import_kind`]
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    /// number of nodes upserted
+    pub upserted: usize,
+    /// lines whose upsert failed, in line order
+    pub failed: Vec<ImportFailure>,
+}
+
+impl ImportReport {
+    /// true if every line was upserted
+    pub fn is_clean(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+impl Client {
+    /// write every node of `kind` on `branch` to `writer` as json lines
+    ///
+    /// `selection` is the node selection set as for [`Client::query_nodes`];
+    /// `id` is always selected. each page is written as soon as it is fetched.
+    /// returns the number of nodes written.
+    pub async fn export_kind(
+        &self,
+        kind: impl AsRef<str>,
+        selection: &str,
+        branch: Option<&str>,
+        mut writer: impl Write,
+    ) -> Result<usize> {
+        let kind = kind.as_ref();
+        validate_kind(kind)?;
+        let query = nodes_query(kind, selection);
+        let mut paginator = self.paginate_offset(&query, kind, None, NODE_PAGE_SIZE, branch);
+        let mut written = 0;
+        while let Some(nodes) = paginator.next_page().await? {
+            for node in &nodes {
+                serde_json::to_writer(&mut writer, node)?;
+                writer.write_all(b"\n")?;
+            }
+            written += nodes.len();
+        }
+        writer.flush()?;
+        Ok(written)
+    }
+
+    /// replay json lines written by [`Client::export_kind`] onto `branch` as
+    /// `{kind}Upsert` mutations, at most `concurrency` at a time
+    ///
+    /// each line is converted with [`node_to_input`], so node ids are kept.
+    /// blank lines are skipped. a failed upsert is recorded in the report and
+    /// does not stop the import; a read error or a line that is not json
+    /// does, after the upserts already in flight finish.
+    pub async fn import_kind(
+        &self,
+        kind: impl AsRef<str>,
+        branch: Option<&str>,
+        reader: impl BufRead,
+        concurrency: usize,
+    ) -> Result<ImportReport> {
+        let kind = kind.as_ref();
+        validate_kind(kind)?;
+        let upserts = stream::iter(reader.lines().enumerate())
+            .map(|(index, line)| async move {
+                let outcome = self.import_line(kind, branch, line?).await?;
+                Ok::<_, Error>(outcome.map(|result| (index + 1, result)))
+            })
+            .buffered(concurrency.max(1));
+        let mut upserts = pin!(upserts);
+
+        let mut report = ImportReport::default();
+        while let Some(outcome) = upserts.next().await {
+            match outcome? {
+                Some((_, Ok(_))) => report.upserted += 1,
+                Some((line, Err(error))) => report.failed.push(ImportFailure { line, error }),
+                None => {}
+            }
+        }
+        Ok(report)
+    }
+
+    /// upsert one json line; `None` for a blank line, and the outer error
+    /// for a line that is not json
+    async fn import_line(
+        &self,
+        kind: &str,
+        branch: Option<&str>,
+        line: String,
+    ) -> Result<Option<Result<String>>> {
+        if line.trim().is_empty() {
+            return Ok(None);
+        }
+        let node: Value = serde_json::from_str(&line)?;
+        Ok(Some(
+            self.upsert_node(kind, node_to_input(&node), branch).await,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockClient;
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_export_kind_writes_json_lines() {
+        let mock = MockClient::new();
+        mock.expect("Nodes").returning(json!({
+            "BuiltinTag": {
+                "count": 2,
+                "edges": [
+                    { "node": { "id": "t1", "name": { "value": "red" } } },
+                    { "node": { "id": "t2", "name": { "value": "blue" } } },
+                ]
+            }
+        }));
+
+        let mut out = Vec::new();
+        let written = mock
+            .client()
+            .export_kind("BuiltinTag", "name { value }", Some("b1"), &mut out)
+            .await
+            .unwrap();
+        assert_eq!(written, 2);
+        let lines: Vec<Value> = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines[1], json!({ "id": "t2", "name": { "value": "blue" } }));
+        assert_eq!(mock.calls()[0].branch.as_deref(), Some("b1"));
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_import_kind_upserts_and_reports_failures() {
+        let mock = MockClient::new();
+        mock.expect("Upsert")
+            .with_variables(json!({ "data": { "id": "t2", "name": { "value": "blue" } } }))
+            .returning_status(StatusCode::UNPROCESSABLE_ENTITY, "invalid");
+        let ok = mock.expect("Upsert").returning(json!({
+            "BuiltinTagUpsert": { "ok": true, "object": { "id": "t1" } }
+        }));
+
+        let input = concat!(
+            r#"{"id": "t1", "name": {"value": "red", "is_protected": false}}"#,
+            "\n\n",
+            r#"{"id": "t2", "name": {"value": "blue"}}"#,
+            "\n",
+            r#"{"id": "t3", "name": {"value": "green"}}"#,
+            "\n",
+        );
+        let report = mock
+            .client()
+            .import_kind("BuiltinTag", None, input.as_bytes(), 2)
+            .await
+            .unwrap();
+        assert_eq!(report.upserted, 2);
+        assert_eq!(ok.calls(), 2);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].line, 3);
+        assert!(!report.is_clean());
+
+        let err = mock
+            .client()
+            .import_kind("BuiltinTag", None, "{".as_bytes(), 2)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Json(_)));
+    }
+}
@@ -0,0 +1,176 @@
+//! keepalive
+//!
+//! a background task that runs a trivial query on an interval, keeping pooled
+//! connections and token sessions warm, and publishes the outcome on a watch
+//! channel for readiness probes.
+
+use crate::client::Client;
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// shortest allowed probe interval
+const MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+/// server health as last observed by a [`Keepalive`] task
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Health {
+    /// no probe has completed yet
+    Unknown,
+    /// the last probe succeeded
+    Healthy {
+        /// round trip time of the probe
+        latency: Duration,
+    },
+    /// the last probe failed
+    Unhealthy {
+        /// probes failed in a row
+        failures: u32,
+        /// error of the last probe
+        error: String,
+    },
+}
+
+impl Health {
+    /// true if the last probe succeeded
+    pub fn is_healthy(&self) -> bool {
+        matches!(self, Health::Healthy { .. })
+    }
+}
+
+/// handle to a keepalive task, see [`Client::spawn_keepalive`]
+///
+/// the task stops when the handle is dropped or the client shuts down.
+#[derive(Debug)]
+pub struct Keepalive {
+    status: watch::Receiver<Health>,
+    task: JoinHandle<()>,
+}
+
+impl Client {
+    /// probe the server every `interval` (at least one second) in a
+    /// background task
+    ///
+    /// each probe fetches [`Client::info`], which also keeps idle connections
+    /// open and refreshes the token from a token source. the first probe runs
+    /// immediately. must be called within a tokio runtime.
+    pub fn spawn_keepalive(&self, interval: Duration) -> Keepalive {
+        let (sender, status) = watch::channel(Health::Unknown);
+        let client = self.clone();
+        let task = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval.max(MIN_INTERVAL));
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            let mut failures = 0;
+            loop {
+                ticker.tick().await;
+                let started = Instant::now();
+                let health = match client.info().await {
+                    Ok(_) => {
+                        failures = 0;
+                        Health::Healthy {
+                            latency: started.elapsed(),
+                        }
+                    }
+                    Err(err) => {
+                        failures += 1;
+                        Health::Unhealthy {
+                            failures,
+                            error: err.to_string(),
+                        }
+                    }
+                };
+                let shut_down =
+                    matches!(&health, Health::Unhealthy { .. }) && client.is_shut_down();
+                sender.send_replace(health);
+                if shut_down {
+                    break;
+                }
+            }
+        });
+        Keepalive { status, task }
+    }
+}
+
+impl Keepalive {
+    /// the health of the last probe
+    pub fn health(&self) -> Health {
+        self.status.borrow().clone()
+    }
+
+    /// a receiver notified after every probe, for readiness checks
+    pub fn subscribe(&self) -> watch::Receiver<Health> {
+        self.status.clone()
+    }
+
+    /// true once the task has stopped
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// stop the task; dropping the handle does the same
+    pub fn stop(self) {}
+}
+
+impl Drop for Keepalive {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::info::InfrahubInfoQuery;
+    use crate::operation::Operation;
+    use crate::testing::MockClient;
+    use serde_json::json;
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_keepalive_reports_health() {
+        let mock = MockClient::new();
+        mock.expect(InfrahubInfoQuery::NAME)
+            .times(1)
+            .returning(json!({ "InfrahubInfo": { "deployment_id": "d", "version": "1.9.8" } }));
+
+        let keepalive = mock.client().spawn_keepalive(Duration::ZERO);
+        let mut status = keepalive.subscribe();
+        let health = status
+            .wait_for(|health| *health != Health::Unknown)
+            .await
+            .unwrap();
+        assert!(health.is_healthy());
+        drop(health);
+
+        // the second probe finds no expectation left and fails
+        let health = status
+            .wait_for(|health| !health.is_healthy())
+            .await
+            .unwrap()
+            .clone();
+        assert!(matches!(health, Health::Unhealthy { failures: 1, .. }));
+        assert_eq!(keepalive.health(), health);
+        keepalive.stop();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_keepalive_stops_on_shutdown() {
+        let mock = MockClient::new();
+        mock.client().shutdown(Duration::ZERO).await;
+        let keepalive = mock.client().spawn_keepalive(Duration::from_secs(60));
+        let mut status = keepalive.subscribe();
+        status
+            .wait_for(|health| !health.is_healthy() && *health != Health::Unknown)
+            .await
+            .unwrap();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !keepalive.is_finished() {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .unwrap();
+    }
+}
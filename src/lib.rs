@@ -12,8 +12,11 @@
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! let client = Client::new(ClientConfig::new("http://localhost:8000", "token"))?;
+//! let info = client.info().await?;
+//! println!("connected to infrahub {}", info.version);
+//!
 //! let response = client
-//!     .execute_raw("{ InfrahubInfo { deployment_id version } }", None, None)
+//!     .execute_raw("{ Branch { id name } }", None, None)
 //!     .await?;
 //! println!("{:?}", response.data);
 //! # Ok(())
@@ -24,19 +27,145 @@
 //!
 //! use `infrahub-codegen` to generate a schema-specific crate, then use it
 //! alongside this base client.
+//!
+//! ## features
+//!
+//! | feature | default | enables |
+//! | --- | --- | --- |
+//! | `rustls` | yes | tls through rustls |
+//! | `native-tls` | no | tls through the platform library |
+//! | `http2` | no | http/2 negotiation |
+//! | `socks` | no | `socks5://` and `socks5h://` proxies |
+//! | `system-proxy` | no | proxy settings from the operating system |
+//! | `charset` | no | non-utf-8 response decoding in reqwest |
+//! | `codegen` | no | the `infrahub-codegen` binary (blocking reqwest, `toml_edit`) |
+//! | `macros` | no | the compile-time checked `query!` macro |
+//! | `simd-json` | no | the `SimdJson` response codec |
+//! | `yaml` | no | yaml config files, variables, and schema fixtures |
+//! | `ingest` | no | inventory ingestion |
+//! | `async-iterator` | no | `AsyncIterator` paginators (nightly) |
+//! | `ffi` | no | the c abi |
+//!
+//! the default build is a lean http/1.1 client over rustls; the reqwest
+//! extras are opt-in. settings that need a missing feature fail with
+//! [`Error::Config`] when the client is built rather than at request time.
 
+#![cfg_attr(feature = "async-iterator", feature(async_iterator))]
+
+mod account;
+mod auth;
+mod batch;
+mod blocking;
+mod branch;
 mod client;
+mod codec;
+mod compat;
 mod config;
+mod config_file;
+mod edges;
 mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod field_filter;
+mod flavor;
+mod generator;
 mod graphql;
+mod group;
+mod host_policy;
+mod info;
+#[cfg(feature = "ingest")]
+mod ingest;
+mod jsonl;
+mod keepalive;
+mod node_diff;
+mod nodes;
 mod operation;
 mod pagination;
+mod pool;
+mod profile;
+mod query;
+mod query_log;
+mod redact;
+mod response;
+mod secret;
+mod seed;
+mod selection;
+mod shutdown;
+mod snapshot;
+pub mod testing;
+mod tls;
+mod tracking;
+mod transport;
 mod upload;
+mod variables;
+mod vcr;
+mod watch;
+mod webhook;
+mod wire_log;
 
+pub use account::{
+    Account, AccountPermissions, ApiToken, CreatedApiToken, GlobalPermission, NewAccount,
+    ObjectPermission,
+};
+pub use auth::{TokenFailoverEvent, TokenSource};
+pub use batch::{Batch, BatchKey, BatchMutation, BatchResults, MutationBatch};
+pub use blocking::BlockingHandle;
+pub use branch::{BranchInfo, BranchResult};
 pub use client::Client;
+#[cfg(feature = "simd-json")]
+pub use codec::SimdJson;
+pub use codec::{JsonCodec, JsonParser};
+pub use compat::{VersionCheck, VersionRange, SUPPORTED_VERSIONS};
 pub use config::ClientConfig;
-pub use error::{Error, Result};
-pub use graphql::{GraphQlError, GraphQlLocation, GraphQlResponse};
+pub use edges::{deserialize_edges, deserialize_node, Edges, Paginated};
+pub use error::{Error, ErrorKind, Result, TimeoutStage};
+pub use field_filter::FieldFilter;
+pub use flavor::ApiFlavor;
+pub use generator::TaskStatus;
+pub use graphql::{
+    ErrorPolicy, GraphQlError, GraphQlLocation, GraphQlResponse, InfrahubError, ResponseExt,
+};
+pub use group::{GroupMember, Groups};
+pub use host_policy::HostPolicy;
+pub use info::{InfrahubInfo, InfrahubInfoData, InfrahubInfoQuery};
+#[cfg(feature = "ingest")]
+pub use ingest::{FieldMapping, IngestMode, IngestReport, RecordMapper};
+pub use jsonl::{ImportFailure, ImportReport};
+pub use keepalive::{Health, Keepalive};
+pub use node_diff::{AttributeChange, NodeDiff, NodeRef, RelationshipChange};
+pub use nodes::node_to_input;
 pub use operation::Operation;
-pub use pagination::{BoxExtract, BoxFetch, BoxFutureResult, DynPaginator, EdgePage, Paginator};
+pub use pagination::{
+    paginate_list, AdaptiveLimit, BoxExtract, BoxFetch, BoxFutureResult, BoxOffsetFetch,
+    Checkpoint, DynOffsetPaginator, DynPaginator, EdgePage, EdgePageInfo, ItemStream, MapItems,
+    OffsetPage, OffsetPaginator, Pages, PaginationSpec, Paginator, Progress,
+};
+pub use pool::{AllocatedNumber, AllocatedResource, PoolAllocation, ResourceAllocation};
+pub use profile::Profile;
+pub use query::{Fragment, NodeQuery, Query};
+pub use query_log::QueryLogEntry;
+pub use redact::{Redactor, REDACTED};
+pub use response::{Deprecation, DeprecationSource, ResponseMeta, SchemaFetch, SchemaStats};
+pub use seed::{Seed, SeededBranch};
+pub use selection::{SchemaRegistry, Selection};
+pub use shutdown::ShutdownReport;
+pub use snapshot::{RestoreReport, SnapshotEntry, SnapshotManifest, SnapshotSpec};
+pub use tls::{ClientIdentity, RootCertificate, TlsVersion};
+pub use tracking::{Tracker, TrackingReport};
+pub use transport::{
+    ReqwestTransport, Transport, TransportFuture, TransportRequest, TransportResponse,
+};
 pub use upload::FileUpload;
+pub use variables::Variables;
+pub use watch::{SchemaChange, SchemaWatcher};
+pub use webhook::Webhook;
+pub use wire_log::{WireLog, WireLogEntry};
+
+#[cfg(feature = "macros")]
+pub use infrahub_macros::query;
+
+/// paths used by macro expansions; not public api
+#[doc(hidden)]
+pub mod __private {
+    pub use serde_json;
+}
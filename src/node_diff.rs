@@ -0,0 +1,289 @@
+//! node diffs between branches
+//!
+//! fetch one node on two branches and compare it field by field, using the
+//! same attribute and relationship shapes as [`crate::node_to_input`]. this
+//! answers "what would merging this branch change on this node" without a
+//! branch diff from the server.
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::nodes::validate_kind;
+use futures_util::future::try_join;
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+
+/// keys left out of a comparison: the id itself and server-computed keys
+const SKIPPED_KEYS: &[&str] = &["id", "__typename", "display_label", "hfid"];
+
+/// how [`Client::compare_node`] finds the node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeRef {
+    /// the node id
+    Id(String),
+    /// the human-friendly id, one value per hfid component
+    Hfid(Vec<String>),
+}
+
+impl From<&str> for NodeRef {
+    fn from(id: &str) -> Self {
+        NodeRef::Id(id.to_string())
+    }
+}
+
+impl From<String> for NodeRef {
+    fn from(id: String) -> Self {
+        NodeRef::Id(id)
+    }
+}
+
+impl From<&[&str]> for NodeRef {
+    fn from(hfid: &[&str]) -> Self {
+        NodeRef::Hfid(hfid.iter().map(|part| part.to_string()).collect())
+    }
+}
+
+impl<const N: usize> From<[&str; N]> for NodeRef {
+    fn from(hfid: [&str; N]) -> Self {
+        NodeRef::from(&hfid[..])
+    }
+}
+
+/// an attribute whose selected fields differ between the two branches
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeChange {
+    /// attribute name
+    pub name: String,
+    /// the attribute on branch a, `Null` if missing
+    pub a: Value,
+    /// the attribute on branch b, `Null` if missing
+    pub b: Value,
+}
+
+/// a relationship whose peers differ between the two branches
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RelationshipChange {
+    /// relationship name
+    pub name: String,
+    /// peer ids on branch b but not on branch a
+    pub added: Vec<String>,
+    /// peer ids on branch a but not on branch b
+    pub removed: Vec<String>,
+}
+
+/// difference of one node between two branches, read from a to b
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NodeDiff {
+    /// node id, from whichever branch has the node
+    pub id: Option<String>,
+    /// true if the node exists on branch a
+    pub in_a: bool,
+    /// true if the node exists on branch b
+    pub in_b: bool,
+    /// changed attributes, sorted by name
+    pub attributes: Vec<AttributeChange>,
+    /// changed relationships, sorted by name
+    pub relationships: Vec<RelationshipChange>,
+}
+
+impl NodeDiff {
+    /// true if the node is the same on both branches
+    pub fn is_empty(&self) -> bool {
+        self.in_a == self.in_b && self.attributes.is_empty() && self.relationships.is_empty()
+    }
+}
+
+impl Client {
+    /// fetch a node of `kind` on `branch_a` and `branch_b` and diff it
+    ///
+    /// `selection` is the node selection set as for [`Client::query_nodes`].
+    /// attributes should select `value` (and any properties worth
+    /// comparing), relationships their peer ids, e.g.
+    /// `"name { value } site { node { id } } tags { edges { node { id } } }"`.
+    /// a node missing on one branch compares against an empty node.
+    pub async fn compare_node(
+        &self,
+        kind: impl AsRef<str>,
+        node: impl Into<NodeRef>,
+        selection: &str,
+        branch_a: &str,
+        branch_b: &str,
+    ) -> Result<NodeDiff> {
+        let kind = kind.as_ref();
+        validate_kind(kind)?;
+        let node = node.into();
+        let (a, b) = try_join(
+            self.fetch_node(kind, &node, selection, branch_a),
+            self.fetch_node(kind, &node, selection, branch_b),
+        )
+        .await?;
+        Ok(diff_nodes(a.as_ref(), b.as_ref()))
+    }
+
+    async fn fetch_node(
+        &self,
+        kind: &str,
+        node: &NodeRef,
+        selection: &str,
+        branch: &str,
+    ) -> Result<Option<Value>> {
+        let (argument, graphql_type, variables) = match node {
+            NodeRef::Id(id) => ("ids", "[ID]", serde_json::json!({ "ids": [id] })),
+            NodeRef::Hfid(hfid) => ("hfid", "[String]", serde_json::json!({ "hfid": hfid })),
+        };
+        let query = format!(
+            "query Node(${argument}: {graphql_type}) {{ {kind}({argument}: ${argument}) {{ edges {{ node {{ id {selection} }} }} }} }}"
+        );
+        let response = self
+            .execute_raw(&query, Some(variables), Some(branch))
+            .await?;
+        let data = response.into_data()?;
+        let edges = data[kind]["edges"]
+            .as_array()
+            .ok_or_else(|| Error::Config(format!("missing edges in {kind} payload")))?;
+        Ok(edges
+            .first()
+            .map(|edge| edge["node"].clone())
+            .filter(|node| !node.is_null()))
+    }
+}
+
+/// diff two queried nodes; `None` compares as an empty node
+fn diff_nodes(a: Option<&Value>, b: Option<&Value>) -> NodeDiff {
+    let empty = Map::new();
+    let fields_a = a.and_then(Value::as_object).unwrap_or(&empty);
+    let fields_b = b.and_then(Value::as_object).unwrap_or(&empty);
+    let mut diff = NodeDiff {
+        id: a
+            .or(b)
+            .and_then(|node| node["id"].as_str())
+            .map(str::to_string),
+        in_a: a.is_some(),
+        in_b: b.is_some(),
+        ..NodeDiff::default()
+    };
+
+    let names: BTreeSet<&String> = fields_a.keys().chain(fields_b.keys()).collect();
+    for name in names {
+        if SKIPPED_KEYS.contains(&name.as_str()) {
+            continue;
+        }
+        let field_a = fields_a.get(name).unwrap_or(&Value::Null);
+        let field_b = fields_b.get(name).unwrap_or(&Value::Null);
+        match (peer_ids(field_a), peer_ids(field_b)) {
+            (None, None) if field_a != field_b => diff.attributes.push(AttributeChange {
+                name: name.clone(),
+                a: field_a.clone(),
+                b: field_b.clone(),
+            }),
+            (None, None) => {}
+            (peers_a, peers_b) => {
+                let peers_a = peers_a.unwrap_or_default();
+                let peers_b = peers_b.unwrap_or_default();
+                if peers_a != peers_b {
+                    diff.relationships.push(RelationshipChange {
+                        name: name.clone(),
+                        added: peers_b.difference(&peers_a).cloned().collect(),
+                        removed: peers_a.difference(&peers_b).cloned().collect(),
+                    });
+                }
+            }
+        }
+    }
+    diff
+}
+
+/// peer ids of a relationship field (`{ node }` or `{ edges }`), or `None`
+/// for anything else
+fn peer_ids(field: &Value) -> Option<BTreeSet<String>> {
+    let field = field.as_object()?;
+    let peer_id = |node: &Value| node["id"].as_str().map(str::to_string);
+    if let Some(node) = field.get("node") {
+        return Some(peer_id(node).into_iter().collect());
+    }
+    let edges = field.get("edges")?.as_array()?;
+    Some(
+        edges
+            .iter()
+            .filter_map(|edge| peer_id(&edge["node"]))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockClient;
+    use serde_json::json;
+
+    #[test]
+    fn test_diff_nodes() {
+        let a = json!({
+            "id": "d1",
+            "display_label": "edge-01",
+            "name": { "value": "edge-01" },
+            "role": { "value": "edge" },
+            "site": { "node": { "id": "ams" } },
+            "platform": { "node": null },
+            "tags": { "edges": [{ "node": { "id": "t1" } }, { "node": { "id": "t2" } }] }
+        });
+        let b = json!({
+            "id": "d1",
+            "display_label": "edge-01 (renamed)",
+            "name": { "value": "edge-01" },
+            "role": { "value": "core" },
+            "site": { "node": { "id": "fra" } },
+            "platform": { "node": null },
+            "tags": { "edges": [{ "node": { "id": "t2" } }, { "node": { "id": "t3" } }] }
+        });
+        let diff = diff_nodes(Some(&a), Some(&b));
+        assert_eq!(diff.id.as_deref(), Some("d1"));
+        assert_eq!(
+            diff.attributes,
+            [AttributeChange {
+                name: "role".to_string(),
+                a: json!({ "value": "edge" }),
+                b: json!({ "value": "core" }),
+            }]
+        );
+        assert_eq!(diff.relationships.len(), 2);
+        assert_eq!(diff.relationships[0].name, "site");
+        assert_eq!(diff.relationships[0].added, ["fra"]);
+        assert_eq!(diff.relationships[0].removed, ["ams"]);
+        assert_eq!(diff.relationships[1].added, ["t3"]);
+        assert_eq!(diff.relationships[1].removed, ["t1"]);
+
+        assert!(diff_nodes(Some(&a), Some(&a)).is_empty());
+        let created = diff_nodes(None, Some(&b));
+        assert!(!created.in_a && created.in_b && !created.is_empty());
+        assert_eq!(created.attributes.len(), 2);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_compare_node_by_hfid() {
+        let mock = MockClient::new();
+        mock.expect("Node").on_branch(Some("main")).returning(json!({
+            "InfraDevice": { "edges": [{ "node": { "id": "d1", "name": { "value": "edge-01" } } }] }
+        }));
+        mock.expect("Node")
+            .on_branch(Some("feature"))
+            .returning(json!({ "InfraDevice": { "edges": [] } }));
+
+        let diff = mock
+            .client()
+            .compare_node(
+                "InfraDevice",
+                ["edge-01"],
+                "name { value }",
+                "main",
+                "feature",
+            )
+            .await
+            .unwrap();
+        assert!(diff.in_a && !diff.in_b);
+        assert_eq!(diff.attributes[0].b, Value::Null);
+        let calls = mock.calls();
+        assert_eq!(calls[0].variables, json!({ "hfid": ["edge-01"] }));
+        assert!(calls[0].query.contains("InfraDevice(hfid: $hfid)"));
+    }
+}
@@ -0,0 +1,213 @@
+//! dynamic node helpers
+//!
+//! schema-agnostic helpers that address nodes by kind name. generated crates
+//! provide typed equivalents; these exist for tooling that only learns the
+//! kinds it handles at runtime.
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use serde_json::{Map, Value};
+
+/// page size used when walking all nodes of a kind
+pub(crate) const NODE_PAGE_SIZE: i64 = 100;
+
+/// keys in a queried node that are computed by the server and cannot be written back
+const READ_ONLY_KEYS: &[&str] = &["__typename", "display_label", "hfid"];
+
+impl Client {
+    /// fetch every node of `kind`, selecting `id` plus the given selection
+    ///
+    /// `selection` is the body of the node selection set, e.g.
+    /// `"name { value } tags { edges { node { id } } }"`. nodes are fetched
+    /// with `offset`/`limit` until the reported `count` is reached.
+    pub async fn query_nodes(
+        &self,
+        kind: impl AsRef<str>,
+        selection: &str,
+        branch: Option<&str>,
+    ) -> Result<Vec<Value>> {
+        let kind = kind.as_ref();
+        validate_kind(kind)?;
+        let query = nodes_query(kind, selection);
+
+        self.paginate_offset(&query, kind, None, NODE_PAGE_SIZE, branch)
+            .collect_all()
+            .await
+    }
+
+    /// create a node of `kind` from a mutation input object
+    ///
+    /// returns the id of the created node.
+    pub async fn create_node(
+        &self,
+        kind: impl AsRef<str>,
+        data: Value,
+        branch: Option<&str>,
+    ) -> Result<String> {
+        self.mutate_node(kind.as_ref(), "Create", data, branch)
+            .await
+    }
+
+    /// create or update a node of `kind` from a mutation input object
+    ///
+    /// returns the id of the upserted node.
+    pub async fn upsert_node(
+        &self,
+        kind: impl AsRef<str>,
+        data: Value,
+        branch: Option<&str>,
+    ) -> Result<String> {
+        self.mutate_node(kind.as_ref(), "Upsert", data, branch)
+            .await
+    }
+
+    async fn mutate_node(
+        &self,
+        kind: &str,
+        action: &str,
+        data: Value,
+        branch: Option<&str>,
+    ) -> Result<String> {
+        validate_kind(kind)?;
+        let mutation = format!("{kind}{action}");
+        let query = format!(
+            "mutation {action}($data: {mutation}Input!) {{ {mutation}(data: $data) {{ ok object {{ id }} }} }}"
+        );
+        let vars = serde_json::json!({ "data": data });
+        let response = self.execute_raw(&query, Some(vars), branch).await?;
+        let data = response.into_data()?;
+        data[&mutation]["object"]["id"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| Error::Config(format!("missing object id in {mutation} payload")))
+    }
+}
+
+/// reject kind names that are not plain graphql identifiers, since they are
+/// interpolated into the query document
+pub(crate) fn validate_kind(kind: &str) -> Result<()> {
+    let mut chars = kind.chars();
+    let valid_start = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+    if valid_start && chars.all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        Ok(())
+    } else {
+        Err(Error::Config(format!("invalid node kind: {kind:?}")))
+    }
+}
+
+/// offset/limit query over all nodes of `kind`, selecting `id` plus `selection`
+pub(crate) fn nodes_query(kind: &str, selection: &str) -> String {
+    format!(
+        "query Nodes($offset: Int, $limit: Int) {{ {kind}(offset: $offset, limit: $limit) {{ count edges {{ node {{ id {selection} }} }} }} }}"
+    )
+}
+
+/// read `{ count, edges: [{ node }] }` into the count and the node list
+pub(crate) fn extract_connection(connection: &Value) -> Result<(i64, Vec<Value>)> {
+    let edges = connection["edges"]
+        .as_array()
+        .ok_or_else(|| Error::Config("missing edges in connection".to_string()))?;
+    let nodes: Vec<Value> = edges
+        .iter()
+        .filter_map(|edge| edge.get("node"))
+        .filter(|node| !node.is_null())
+        .cloned()
+        .collect();
+    let count = connection["count"].as_i64().unwrap_or(nodes.len() as i64);
+    Ok((count, nodes))
+}
+
+/// convert a queried node into the matching create/upsert input shape
+///
+/// - attributes `{ value, ... }` become `{ value }`
+/// - cardinality-one relationships `{ node: { id } }` become `{ id }`
+/// - cardinality-many relationships `{ edges: [{ node: { id } }] }` become `[{ id }]`
+/// - server-computed keys (`__typename`, `display_label`, `hfid`) are dropped
+pub fn node_to_input(node: &Value) -> Value {
+    let Some(obj) = node.as_object() else {
+        return node.clone();
+    };
+    let mut input = Map::new();
+    for (key, value) in obj {
+        if READ_ONLY_KEYS.contains(&key.as_str()) {
+            continue;
+        }
+        let converted = match value {
+            Value::Object(field) if field.contains_key("value") => {
+                serde_json::json!({ "value": field["value"] })
+            }
+            Value::Object(field) if field.contains_key("node") => match field["node"].get("id") {
+                Some(id) => serde_json::json!({ "id": id }),
+                None => continue,
+            },
+            Value::Object(field) if field.contains_key("edges") => {
+                let peers: Vec<Value> = field["edges"]
+                    .as_array()
+                    .map(Vec::as_slice)
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|edge| edge["node"].get("id"))
+                    .map(|id| serde_json::json!({ "id": id }))
+                    .collect();
+                Value::Array(peers)
+            }
+            other => other.clone(),
+        };
+        input.insert(key.clone(), converted);
+    }
+    Value::Object(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_kind() {
+        assert!(validate_kind("InfraDevice").is_ok());
+        assert!(validate_kind("_Private1").is_ok());
+        assert!(validate_kind("").is_err());
+        assert!(validate_kind("1Device").is_err());
+        assert!(validate_kind("Infra Device").is_err());
+        assert!(validate_kind("Device{ evil }").is_err());
+    }
+
+    #[test]
+    fn test_extract_connection() {
+        let connection = serde_json::json!({
+            "count": 5,
+            "edges": [{"node": {"id": "a"}}, {"node": null}, {"node": {"id": "b"}}]
+        });
+        let (count, nodes) = extract_connection(&connection).unwrap();
+        assert_eq!(count, 5);
+        assert_eq!(nodes.len(), 2);
+
+        assert!(extract_connection(&serde_json::json!({"count": 1})).is_err());
+    }
+
+    #[test]
+    fn test_node_to_input() {
+        let node = serde_json::json!({
+            "id": "dev-1",
+            "__typename": "InfraDevice",
+            "display_label": "edge-01",
+            "hfid": ["edge-01"],
+            "name": {"value": "edge-01", "is_protected": false},
+            "site": {"node": {"id": "site-1", "display_label": "ams"}},
+            "platform": {"node": null},
+            "tags": {"count": 2, "edges": [{"node": {"id": "t1"}}, {"node": {"id": "t2"}}]}
+        });
+        let input = node_to_input(&node);
+        assert_eq!(
+            input,
+            serde_json::json!({
+                "id": "dev-1",
+                "name": {"value": "edge-01"},
+                "site": {"id": "site-1"},
+                "tags": [{"id": "t1"}, {"id": "t2"}]
+            })
+        );
+    }
+}
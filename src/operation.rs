@@ -2,12 +2,45 @@
 //!
 //! operation trait implemented by generated types.
 
+use crate::error::Result;
+use crate::graphql::check_variables;
 use serde::de::DeserializeOwned;
 
 /// graphql operation contract for generated types
 pub trait Operation {
+    /// operation name as declared in [`Operation::QUERY`]
+    const NAME: &'static str;
     /// graphql query or mutation string
     const QUERY: &'static str;
     /// response payload type
     type Response: DeserializeOwned;
+
+    /// check that all required (non-null, no default) variables are present
+    ///
+    /// returns [`crate::Error::Config`] naming the missing variables.
+    fn check_variables(variables: Option<&serde_json::Value>) -> Result<()> {
+        check_variables(Self::QUERY, variables)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct GetTag;
+
+    impl Operation for GetTag {
+        const NAME: &'static str = "GetTag";
+        const QUERY: &'static str =
+            "query GetTag($name: String!) { BuiltinTag(name__value: $name) { count } }";
+        type Response = serde_json::Value;
+    }
+
+    #[test]
+    fn test_operation_check_variables() {
+        assert_eq!(GetTag::NAME, "GetTag");
+        assert!(GetTag::check_variables(Some(&serde_json::json!({ "name": "red" }))).is_ok());
+        let err = GetTag::check_variables(None).unwrap_err();
+        assert!(matches!(err, crate::Error::Config(_)));
+    }
 }
@@ -1,18 +1,184 @@
 //! pagination helpers
 //!
-//! generic paginator for connection-style graphql results.
+//! generic paginator for connection-style graphql results, plus an
+//! offset/limit paginator for infrahub's `count`-reporting connections.
 
-use crate::error::Result;
+use crate::client::Client;
+use crate::error::{Error, ErrorKind, Result};
+use crate::nodes::extract_connection;
+use futures_util::stream::{self, FuturesOrdered, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::VecDeque;
 use std::future::Future;
 use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
 
 /// a single page of connection results
+///
+/// build pages with [`EdgePage::new`]; the struct is non-exhaustive so
+/// metadata can be added without breaking callers.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct EdgePage<T, C> {
     /// node payloads for this page
     pub nodes: Vec<T>,
     /// next cursor (if any)
     pub next_cursor: Option<C>,
+    /// connection metadata reported with this page
+    pub info: EdgePageInfo,
+}
+
+impl<T, C> EdgePage<T, C> {
+    /// a page with no connection metadata
+    pub fn new(nodes: Vec<T>, next_cursor: Option<C>) -> Self {
+        Self {
+            nodes,
+            next_cursor,
+            info: EdgePageInfo::default(),
+        }
+    }
+
+    /// attach connection metadata
+    pub fn with_info(mut self, info: EdgePageInfo) -> Self {
+        self.info = info;
+        self
+    }
+}
+
+impl<T, C> Default for EdgePage<T, C> {
+    fn default() -> Self {
+        Self::new(Vec::new(), None)
+    }
+}
+
+/// `page_info` and `count` of a connection, as far as the server reported them
+///
+/// [`Paginator`] stops after a page with `has_next_page: Some(false)` instead
+/// of requesting a trailing empty page, and reports `total_count` as the
+/// progress total.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EdgePageInfo {
+    /// whether more pages follow
+    pub has_next_page: Option<bool>,
+    /// cursor of the last edge on this page
+    pub end_cursor: Option<String>,
+    /// total number of items in the connection
+    pub total_count: Option<i64>,
+}
+
+impl EdgePageInfo {
+    /// read `count` and `page_info` (or `pageInfo`) from a connection object
+    ///
+    /// both snake_case and camelCase page info fields are accepted; fields
+    /// the connection did not select stay `None`.
+    pub fn from_connection(connection: &Value) -> Self {
+        let page_info = connection
+            .get("page_info")
+            .or_else(|| connection.get("pageInfo"))
+            .unwrap_or(&Value::Null);
+        let field = |snake: &str, camel: &str| {
+            page_info
+                .get(snake)
+                .or_else(|| page_info.get(camel))
+                .filter(|value| !value.is_null())
+        };
+        Self {
+            has_next_page: field("has_next_page", "hasNextPage").and_then(Value::as_bool),
+            end_cursor: field("end_cursor", "endCursor")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            total_count: connection.get("count").and_then(Value::as_i64),
+        }
+    }
+}
+
+/// pagination progress, passed to progress callbacks after every page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// pages fetched so far
+    pub pages: usize,
+    /// items fetched so far
+    pub fetched: usize,
+    /// total number of items, if the server reported it
+    pub total: Option<i64>,
+}
+
+impl Progress {
+    /// fraction of items fetched, between 0.0 and 1.0, if the total is known
+    pub fn fraction(&self) -> Option<f64> {
+        match self.total {
+            Some(total) if total > 0 => Some((self.fetched as f64 / total as f64).min(1.0)),
+            Some(_) => Some(1.0),
+            None => None,
+        }
+    }
+}
+
+/// serializable paginator position, for resuming an interrupted export
+///
+/// take one with `checkpoint()` after each page, persist it, and pass it to
+/// `resume()` on a freshly built paginator to continue after the last page
+/// that was handed out. for [`OffsetPaginator`] the cursor is the offset.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checkpoint<C> {
+    /// cursor of the next page (`None` before the first page or when done)
+    pub cursor: Option<C>,
+    /// true once the last page has been fetched
+    pub done: bool,
+    /// pages fetched so far
+    pub pages: usize,
+    /// items fetched so far
+    pub fetched: usize,
+    /// total number of items, if the server reported it
+    pub total: Option<i64>,
+}
+
+/// progress callback registered with `on_progress`
+type ProgressHook = Box<dyn FnMut(Progress) + Send>;
+
+/// tracks fetched items and notifies the progress callback
+#[derive(Default)]
+struct ProgressState {
+    pages: usize,
+    fetched: usize,
+    total: Option<i64>,
+    hook: Option<ProgressHook>,
+}
+
+impl ProgressState {
+    fn checkpoint<C>(&self, cursor: Option<C>, done: bool) -> Checkpoint<C> {
+        Checkpoint {
+            cursor,
+            done,
+            pages: self.pages,
+            fetched: self.fetched,
+            total: self.total,
+        }
+    }
+
+    fn restore<C>(&mut self, checkpoint: &Checkpoint<C>) {
+        self.pages = checkpoint.pages;
+        self.fetched = checkpoint.fetched;
+        self.total = checkpoint.total;
+    }
+
+    fn record(&mut self, items: usize, total: Option<i64>) {
+        self.pages += 1;
+        self.fetched += items;
+        if total.is_some() {
+            self.total = total;
+        }
+        let progress = Progress {
+            pages: self.pages,
+            fetched: self.fetched,
+            total: self.total,
+        };
+        if let Some(hook) = &mut self.hook {
+            hook(progress);
+        }
+    }
 }
 
 /// generic paginator for connection-style data
@@ -27,6 +193,8 @@ where
     extract: Extract,
     cursor: Option<C>,
     done: bool,
+    count: Option<fn(&R) -> Option<i64>>,
+    progress: ProgressState,
     _phantom: std::marker::PhantomData<(T, R)>,
 }
 
@@ -57,10 +225,48 @@ where
             extract,
             cursor: None,
             done: false,
+            count: None,
+            progress: ProgressState::default(),
             _phantom: std::marker::PhantomData,
         }
     }
 
+    /// read the total item count from each raw page response
+    pub fn with_total_count(mut self, count: fn(&R) -> Option<i64>) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// call `hook` after every fetched page
+    pub fn on_progress(mut self, hook: impl FnMut(Progress) + Send + 'static) -> Self {
+        self.progress.hook = Some(Box::new(hook));
+        self
+    }
+
+    /// total item count, once a page has reported it (see [`Paginator::with_total_count`])
+    pub fn total_count(&self) -> Option<i64> {
+        self.progress.total
+    }
+
+    /// number of items fetched so far
+    pub fn fetched_so_far(&self) -> usize {
+        self.progress.fetched
+    }
+
+    /// current position, see [`Checkpoint`]
+    pub fn checkpoint(&self) -> Checkpoint<C> {
+        self.progress.checkpoint(self.cursor.clone(), self.done)
+    }
+
+    /// continue from a checkpoint taken from an earlier paginator over the
+    /// same query
+    pub fn resume(mut self, checkpoint: Checkpoint<C>) -> Self {
+        self.progress.restore(&checkpoint);
+        self.done = checkpoint.done;
+        self.cursor = checkpoint.cursor;
+        self
+    }
+
     /// fetch the next page of results
     pub async fn next_page(&mut self) -> Result<Option<Vec<T>>> {
         if self.done {
@@ -68,15 +274,298 @@ where
         }
 
         let response = (self.fetch)(self.cursor.clone()).await?;
+        let total = self.count.and_then(|count| count(&response));
         let page = (self.extract)(response)?;
+        let total = total.or(page.info.total_count);
         self.cursor = page.next_cursor.clone();
-        if self.cursor.is_none() {
+        if self.cursor.is_none() || page.info.has_next_page == Some(false) {
+            self.done = true;
+        }
+
+        self.progress.record(page.nodes.len(), total);
+        Ok(Some(page.nodes))
+    }
+
+    /// fetch all pages and return a single collection
+    pub async fn collect_all(mut self) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        while let Some(page) = self.next_page().await? {
+            items.extend(page);
+        }
+        Ok(items)
+    }
+}
+
+/// wrap an offset/limit `list` call in a paginator.
+///
+/// `list` receives `(offset, limit)` and returns one page of items. the
+/// paginator starts at offset 0, advances by the number of items returned, and
+/// stops as soon as a page comes back shorter than `limit`, so no trailing
+/// empty request is made for partial pages. a `limit` below 1 is treated as 1.
+///
+/// this bridges generated `list(filters, branch)` helpers, whose filters expose
+/// `offset`/`limit`, without repeating the offset math at each call site.
+pub fn paginate_list<'a, T, F, Fut>(
+    limit: i64,
+    mut list: F,
+) -> DynPaginator<'a, T, i64, (Vec<T>, i64)>
+where
+    T: 'a,
+    F: FnMut(i64, i64) -> Fut + 'a,
+    Fut: Future<Output = Result<Vec<T>>> + 'a,
+{
+    let limit = limit.max(1);
+    let fetch: BoxFetch<'a, i64, (Vec<T>, i64)> = Box::new(move |cursor: Option<i64>| {
+        let offset = cursor.unwrap_or(0);
+        let page = list(offset, limit);
+        Box::pin(async move { Ok((page.await?, offset)) })
+    });
+    let extract: BoxExtract<'a, T, i64, (Vec<T>, i64)> =
+        Box::new(move |(items, offset): (Vec<T>, i64)| {
+            let next_cursor = if (items.len() as i64) < limit {
+                None
+            } else {
+                Some(offset + items.len() as i64)
+            };
+            Ok(EdgePage::new(items, next_cursor))
+        });
+    Paginator::new(fetch, extract)
+}
+
+/// a single page of an offset/limit connection
+#[derive(Debug, Clone)]
+pub struct OffsetPage<T> {
+    /// node payloads for this page
+    pub nodes: Vec<T>,
+    /// total number of matching nodes reported by the server
+    pub count: i64,
+}
+
+/// bounds for adaptive page sizing, see [`OffsetPaginator::with_adaptive_limit`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdaptiveLimit {
+    /// smallest page size to request
+    pub min: i64,
+    /// largest page size to request
+    pub max: i64,
+    /// response time to aim for per page
+    pub target: Duration,
+}
+
+impl AdaptiveLimit {
+    /// create bounds; `min` is at least 1 and `max` at least `min`
+    pub fn new(min: i64, max: i64, target: Duration) -> Self {
+        let min = min.max(1);
+        Self {
+            min,
+            max: max.max(min),
+            target,
+        }
+    }
+
+    /// page size to request after a page of `items` took `elapsed`
+    ///
+    /// aims for `target` at the observed time per item, so large nodes get
+    /// smaller pages than small ones. the size changes by at most a factor of
+    /// two per page.
+    fn next_limit(&self, limit: i64, items: usize, elapsed: Duration) -> i64 {
+        if items == 0 {
+            return limit;
+        }
+        let per_item = elapsed.as_secs_f64() / items as f64;
+        let ideal = if per_item > 0.0 {
+            (self.target.as_secs_f64() / per_item) as i64
+        } else {
+            limit.saturating_mul(2)
+        };
+        ideal
+            .clamp(limit / 2, limit.saturating_mul(2))
+            .clamp(self.min, self.max)
+    }
+}
+
+/// boxed fetch callback used by [`DynOffsetPaginator`]
+pub type BoxOffsetFetch<'a, T> =
+    Box<dyn FnMut(i64, i64) -> BoxFutureResult<'a, OffsetPage<T>> + 'a>;
+
+/// type-erased offset paginator, as returned by [`Client::paginate_offset`]
+pub type DynOffsetPaginator<'a, T> =
+    OffsetPaginator<T, BoxOffsetFetch<'a, T>, BoxFutureResult<'a, OffsetPage<T>>>;
+
+/// paginator for infrahub `offset`/`limit` connections
+///
+/// `fetch` receives `(offset, limit)` and returns one page together with the
+/// total `count`. the paginator advances the offset by the number of nodes
+/// returned and stops once the offset reaches `count`, or on an empty page.
+///
+/// with [`OffsetPaginator::with_concurrency`], pages after the first are
+/// requested several at a time and still yielded in offset order.
+pub struct OffsetPaginator<T, Fetch, Fut>
+where
+    Fetch: FnMut(i64, i64) -> Fut,
+    Fut: Future<Output = Result<OffsetPage<T>>>,
+{
+    fetch: Fetch,
+    limit: i64,
+    offset: i64,
+    done: bool,
+    progress: ProgressState,
+    concurrency: usize,
+    next_offset: i64,
+    pending: FuturesOrdered<Fut>,
+    adaptive: Option<AdaptiveLimit>,
+}
+
+impl<T, Fetch, Fut> OffsetPaginator<T, Fetch, Fut>
+where
+    Fetch: FnMut(i64, i64) -> Fut,
+    Fut: Future<Output = Result<OffsetPage<T>>>,
+{
+    /// create a new paginator; a `limit` below 1 is treated as 1
+    pub fn new(limit: i64, fetch: Fetch) -> Self {
+        Self {
+            fetch,
+            limit: limit.max(1),
+            offset: 0,
+            done: false,
+            progress: ProgressState::default(),
+            concurrency: 1,
+            next_offset: 0,
+            pending: FuturesOrdered::new(),
+            adaptive: None,
+        }
+    }
+
+    /// fetch up to `pages` pages concurrently once the total count is known
+    ///
+    /// the first page is always fetched alone to learn `count`; later pages
+    /// are requested at fixed `limit` steps up to that count. a value below 1
+    /// is treated as 1, which is the default serial behavior.
+    pub fn with_concurrency(mut self, pages: usize) -> Self {
+        self.concurrency = pages.max(1);
+        self
+    }
+
+    /// grow or shrink the page size within `bounds` based on response times
+    ///
+    /// the initial limit is clamped into the bounds. after every serially
+    /// fetched page the limit is adjusted toward `bounds.target`, and a page
+    /// that fails with [`ErrorKind::Timeout`] is retried at half the size
+    /// until `bounds.min` is reached. pages fetched with
+    /// [`OffsetPaginator::with_concurrency`] keep the limit in effect when
+    /// concurrent fetching started.
+    pub fn with_adaptive_limit(mut self, bounds: AdaptiveLimit) -> Self {
+        self.limit = self.limit.clamp(bounds.min, bounds.max);
+        self.adaptive = Some(bounds);
+        self
+    }
+
+    /// call `hook` after every fetched page
+    pub fn on_progress(mut self, hook: impl FnMut(Progress) + Send + 'static) -> Self {
+        self.progress.hook = Some(Box::new(hook));
+        self
+    }
+
+    /// page size of the next request
+    pub fn limit(&self) -> i64 {
+        self.limit
+    }
+
+    /// total count reported by the last page, if any page was fetched
+    pub fn total_count(&self) -> Option<i64> {
+        self.progress.total
+    }
+
+    /// number of items fetched so far
+    pub fn fetched_so_far(&self) -> usize {
+        self.progress.fetched
+    }
+
+    /// offset of the next page
+    pub fn offset(&self) -> i64 {
+        self.offset
+    }
+
+    /// current position, see [`Checkpoint`]
+    ///
+    /// the cursor is the offset just past the last page handed out; pages
+    /// fetched ahead with [`OffsetPaginator::with_concurrency`] but not yet
+    /// returned are fetched again after resuming.
+    pub fn checkpoint(&self) -> Checkpoint<i64> {
+        let cursor = (self.progress.pages > 0 && !self.done).then_some(self.offset);
+        self.progress.checkpoint(cursor, self.done)
+    }
+
+    /// continue from a checkpoint taken from an earlier paginator over the
+    /// same query
+    pub fn resume(mut self, checkpoint: Checkpoint<i64>) -> Self {
+        self.progress.restore(&checkpoint);
+        self.done = checkpoint.done;
+        self.offset = checkpoint.cursor.unwrap_or(0);
+        self.next_offset = self.offset;
+        self
+    }
+
+    /// fetch the next page of results
+    pub async fn next_page(&mut self) -> Result<Option<Vec<T>>> {
+        if self.done {
+            return Ok(None);
+        }
+        if let (true, Some(count)) = (self.concurrency > 1, self.progress.total) {
+            return self.next_page_concurrent(count).await;
+        }
+
+        let page = loop {
+            let started = Instant::now();
+            match (self.fetch)(self.offset, self.limit).await {
+                Ok(page) => {
+                    if let Some(adaptive) = self.adaptive {
+                        self.limit =
+                            adaptive.next_limit(self.limit, page.nodes.len(), started.elapsed());
+                    }
+                    break page;
+                }
+                Err(err) => match self.adaptive {
+                    Some(adaptive)
+                        if err.kind() == ErrorKind::Timeout && self.limit > adaptive.min =>
+                    {
+                        self.limit = (self.limit / 2).max(adaptive.min);
+                    }
+                    _ => return Err(err),
+                },
+            }
+        };
+        self.offset += page.nodes.len() as i64;
+        self.next_offset = self.offset;
+        if page.nodes.is_empty() || self.offset >= page.count {
             self.done = true;
         }
 
+        self.progress.record(page.nodes.len(), Some(page.count));
         Ok(Some(page.nodes))
     }
 
+    async fn next_page_concurrent(&mut self, count: i64) -> Result<Option<Vec<T>>> {
+        while self.pending.len() < self.concurrency && self.next_offset < count {
+            self.pending
+                .push_back((self.fetch)(self.next_offset, self.limit));
+            self.next_offset += self.limit;
+        }
+
+        match self.pending.next().await {
+            Some(page) => {
+                let page = page?;
+                self.offset += page.nodes.len() as i64;
+                self.progress.record(page.nodes.len(), Some(page.count));
+                Ok(Some(page.nodes))
+            }
+            None => {
+                self.done = true;
+                Ok(None)
+            }
+        }
+    }
+
     /// fetch all pages and return a single collection
     pub async fn collect_all(mut self) -> Result<Vec<T>> {
         let mut items = Vec::new();
@@ -87,6 +576,375 @@ where
     }
 }
 
+/// page-at-a-time consumption shared by the paginators in this module
+///
+/// the combinators hand out one page at a time, so processing a large
+/// export never holds more than a page in memory. `collect_all` is still
+/// there for when buffering everything is fine.
+pub trait Pages {
+    /// item type of each page
+    type Item;
+
+    /// fetch the next page of results, or `None` once exhausted
+    fn next_page(&mut self) -> impl Future<Output = Result<Option<Vec<Self::Item>>>>;
+
+    /// call `f` with every page, stopping at the first error
+    fn for_each_page<F, Fut>(mut self, mut f: F) -> impl Future<Output = Result<()>>
+    where
+        Self: Sized,
+        F: FnMut(Vec<Self::Item>) -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        async move {
+            while let Some(page) = self.next_page().await? {
+                f(page).await?;
+            }
+            Ok(())
+        }
+    }
+
+    /// call `f` with every item, stopping at the first error
+    ///
+    /// no further pages are fetched once `f` fails.
+    fn try_for_each_item<F, Fut>(mut self, mut f: F) -> impl Future<Output = Result<()>>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        async move {
+            while let Some(page) = self.next_page().await? {
+                for item in page {
+                    f(item).await?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// map every item with `f` as its page is fetched
+    fn map_items<U, F>(self, f: F) -> MapItems<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> U,
+    {
+        MapItems { inner: self, f }
+    }
+
+    /// yield items one at a time as a [`Stream`]
+    ///
+    /// pages are fetched lazily as the stream is polled. an error is yielded
+    /// once and ends the stream.
+    fn into_stream<'a>(self) -> ItemStream<'a, Self::Item>
+    where
+        Self: Sized + 'a,
+        Self::Item: 'a,
+    {
+        let state = (self, VecDeque::new(), false);
+        let items = stream::unfold(state, |(mut pages, mut buffered, mut done)| async move {
+            loop {
+                if let Some(item) = buffered.pop_front() {
+                    return Some((Ok(item), (pages, buffered, done)));
+                }
+                if done {
+                    return None;
+                }
+                match pages.next_page().await {
+                    Ok(Some(page)) => buffered.extend(page),
+                    Ok(None) => done = true,
+                    Err(err) => return Some((Err(err), (pages, buffered, true))),
+                }
+            }
+        });
+        ItemStream {
+            inner: Box::pin(items),
+        }
+    }
+
+    /// fetch all pages and return a single collection
+    fn collect_all(mut self) -> impl Future<Output = Result<Vec<Self::Item>>>
+    where
+        Self: Sized,
+    {
+        async move {
+            let mut items = Vec::new();
+            while let Some(page) = self.next_page().await? {
+                items.extend(page);
+            }
+            Ok(items)
+        }
+    }
+}
+
+impl<T, C, R, Fetch, Fut, Extract> Pages for Paginator<T, C, R, Fetch, Fut, Extract>
+where
+    C: Clone,
+    Fetch: FnMut(Option<C>) -> Fut,
+    Fut: Future<Output = Result<R>>,
+    Extract: FnMut(R) -> Result<EdgePage<T, C>>,
+{
+    type Item = T;
+
+    fn next_page(&mut self) -> impl Future<Output = Result<Option<Vec<T>>>> {
+        Paginator::next_page(self)
+    }
+}
+
+impl<T, Fetch, Fut> Pages for OffsetPaginator<T, Fetch, Fut>
+where
+    Fetch: FnMut(i64, i64) -> Fut,
+    Fut: Future<Output = Result<OffsetPage<T>>>,
+{
+    type Item = T;
+
+    fn next_page(&mut self) -> impl Future<Output = Result<Option<Vec<T>>>> {
+        OffsetPaginator::next_page(self)
+    }
+}
+
+/// paginator adapter returned by [`Pages::map_items`]
+pub struct MapItems<P, F> {
+    inner: P,
+    f: F,
+}
+
+impl<P, F> MapItems<P, F> {
+    /// the wrapped paginator, e.g. to take a [`Checkpoint`]
+    pub fn get_ref(&self) -> &P {
+        &self.inner
+    }
+
+    /// unwrap the paginator, dropping the mapping
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<P, U, F> Pages for MapItems<P, F>
+where
+    P: Pages,
+    F: FnMut(P::Item) -> U,
+{
+    type Item = U;
+
+    async fn next_page(&mut self) -> Result<Option<Vec<U>>> {
+        let page = self.inner.next_page().await?;
+        Ok(page.map(|items| items.into_iter().map(&mut self.f).collect()))
+    }
+}
+
+/// item stream returned by [`Pages::into_stream`]
+///
+/// implements [`Stream`], and with the `async-iterator` feature (nightly
+/// only) also `core::async_iter::AsyncIterator`.
+pub struct ItemStream<'a, T> {
+    inner: Pin<Box<dyn Stream<Item = Result<T>> + 'a>>,
+}
+
+impl<T> Stream for ItemStream<'_, T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<T>>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+#[cfg(feature = "async-iterator")]
+impl<T> core::async_iter::AsyncIterator for ItemStream<'_, T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<T>>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl Client {
+    /// page through the offset/limit connection at `data.<field>` of `query`
+    ///
+    /// `query` must declare `$offset: Int` and `$limit: Int` and pass them to
+    /// `field`, which must select `count` and `edges { node { ... } }`. the
+    /// paginator sets `offset` and `limit` in `variables` for every page.
+    pub fn paginate_offset<'a>(
+        &'a self,
+        query: &'a str,
+        field: &'a str,
+        variables: Option<Value>,
+        limit: i64,
+        branch: Option<&'a str>,
+    ) -> DynOffsetPaginator<'a, Value> {
+        let fetch: BoxOffsetFetch<'a, Value> = Box::new(move |offset, limit| {
+            let variables = variables.clone();
+            Box::pin(async move {
+                let variables = with_offset(variables, offset, limit)?;
+                let response = self.execute_raw(query, Some(variables), branch).await?;
+                let data = response.into_data()?;
+                let (count, nodes) = extract_connection(&data[field])?;
+                Ok(OffsetPage { nodes, count })
+            })
+        });
+        OffsetPaginator::new(limit, fetch)
+    }
+}
+
+/// where [`Client::paginate_raw`] finds nodes and cursors
+///
+/// paths are dot-separated and relative to the response `data`. a path that
+/// reaches a list continues into each of its items, so `devices.edges.node`
+/// yields every node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaginationSpec {
+    /// path to the nodes of a page, e.g. `devices.edges.node`
+    pub nodes_path: String,
+    /// path to the next cursor, e.g. `devices.pageInfo.endCursor`
+    pub cursor_path: String,
+    /// query variable that receives the cursor, `after` by default
+    pub cursor_variable: String,
+}
+
+impl PaginationSpec {
+    /// a spec passing the cursor as `$after`
+    pub fn new(nodes_path: impl Into<String>, cursor_path: impl Into<String>) -> Self {
+        Self {
+            nodes_path: nodes_path.into(),
+            cursor_path: cursor_path.into(),
+            cursor_variable: "after".to_string(),
+        }
+    }
+
+    /// pass the cursor in `name` instead of `after`
+    pub fn with_cursor_variable(mut self, name: impl Into<String>) -> Self {
+        self.cursor_variable = name.into();
+        self
+    }
+}
+
+impl Client {
+    /// page through a cursor connection of a raw query
+    ///
+    /// `query` must declare the cursor variable of `spec` (`$after` unless
+    /// changed) and pass it to the connection; the cursor of the previous page
+    /// is set there for every page after the first. pagination stops when the
+    /// cursor is missing or null, or when the object holding it has
+    /// `hasNextPage: false`.
+    pub fn paginate_raw<'a>(
+        &'a self,
+        query: &'a str,
+        variables: Option<Value>,
+        branch: Option<&'a str>,
+        spec: PaginationSpec,
+    ) -> DynPaginator<'a, Value, String, Value> {
+        let cursor_variable = spec.cursor_variable.clone();
+        let fetch: BoxFetch<'a, String, Value> = Box::new(move |cursor: Option<String>| {
+            let variables = with_cursor(variables.clone(), &cursor_variable, cursor);
+            Box::pin(async move {
+                let response = self.execute_raw(query, Some(variables?), branch).await?;
+                response.into_data()
+            })
+        });
+        let extract: BoxExtract<'a, Value, String, Value> = Box::new(move |data: Value| {
+            Ok(cursor_page(&data, &spec.cursor_path, &spec.nodes_path))
+        });
+        Paginator::new(fetch, extract)
+    }
+}
+
+impl<'a> DynPaginator<'a, Value, String, Value> {
+    /// page through a cursor connection of a raw query, passing the cursor
+    /// as `$after`
+    ///
+    /// shorthand for [`Client::paginate_raw`] with
+    /// [`PaginationSpec::new(nodes_path, cursor_path)`](PaginationSpec::new).
+    pub fn from_query(
+        client: &'a Client,
+        query: &'a str,
+        variables: Option<Value>,
+        branch: Option<&'a str>,
+        cursor_path: &'a str,
+        nodes_path: &'a str,
+    ) -> Self {
+        client.paginate_raw(
+            query,
+            variables,
+            branch,
+            PaginationSpec::new(nodes_path, cursor_path),
+        )
+    }
+}
+
+/// read one page of a cursor connection from response data
+fn cursor_page(data: &Value, cursor_path: &str, nodes_path: &str) -> EdgePage<Value, String> {
+    let nodes = select_path(data, nodes_path);
+    let (parent, _) = cursor_path.rsplit_once('.').unwrap_or(("", cursor_path));
+    let has_next_page = select_path(data, parent)
+        .first()
+        .and_then(|info| info.get("hasNextPage"))
+        .and_then(Value::as_bool);
+    let end_cursor = select_path(data, cursor_path)
+        .into_iter()
+        .find_map(|cursor| match cursor {
+            Value::String(cursor) => Some(cursor),
+            Value::Number(cursor) => Some(cursor.to_string()),
+            _ => None,
+        });
+    let next_cursor = end_cursor.clone().filter(|_| has_next_page != Some(false));
+    EdgePage::new(nodes, next_cursor).with_info(EdgePageInfo {
+        has_next_page,
+        end_cursor,
+        total_count: None,
+    })
+}
+
+/// values at a dot-separated path, flattening lists along the way
+fn select_path(value: &Value, path: &str) -> Vec<Value> {
+    let mut out = Vec::new();
+    let segments: Vec<&str> = path.split('.').filter(|s| !s.is_empty()).collect();
+    collect_path(value, &segments, &mut out);
+    out
+}
+
+fn collect_path(value: &Value, segments: &[&str], out: &mut Vec<Value>) {
+    match (value, segments.split_first()) {
+        (Value::Array(items), _) => items
+            .iter()
+            .for_each(|item| collect_path(item, segments, out)),
+        (Value::Null, _) => {}
+        (_, None) => out.push(value.clone()),
+        (Value::Object(map), Some((first, rest))) => {
+            if let Some(child) = map.get(*first) {
+                collect_path(child, rest, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// set the cursor variable `name` in a variables object
+fn with_cursor(variables: Option<Value>, name: &str, cursor: Option<String>) -> Result<Value> {
+    let mut variables = variables_object(variables)?;
+    if let Some(cursor) = cursor {
+        variables.insert(name.to_string(), Value::from(cursor));
+    }
+    Ok(Value::Object(variables))
+}
+
+/// set `offset` and `limit` in a variables object
+fn with_offset(variables: Option<Value>, offset: i64, limit: i64) -> Result<Value> {
+    let mut variables = variables_object(variables)?;
+    variables.insert("offset".to_string(), Value::from(offset));
+    variables.insert("limit".to_string(), Value::from(limit));
+    Ok(Value::Object(variables))
+}
+
+fn variables_object(variables: Option<Value>) -> Result<serde_json::Map<String, Value>> {
+    match variables {
+        Some(Value::Object(map)) => Ok(map),
+        None | Some(Value::Null) => Ok(serde_json::Map::new()),
+        Some(_) => Err(Error::Config(
+            "graphql variables must be an object".to_string(),
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,15 +962,9 @@ mod tests {
                 let mut count = state.lock().unwrap();
                 *count += 1;
                 if cursor.is_none() {
-                    Ok(EdgePage {
-                        nodes: vec![1, 2],
-                        next_cursor: Some("next".to_string()),
-                    })
+                    Ok(EdgePage::new(vec![1, 2], Some("next".to_string())))
                 } else {
-                    Ok(EdgePage {
-                        nodes: vec![3],
-                        next_cursor: None,
-                    })
+                    Ok(EdgePage::new(vec![3], None))
                 }
             }
         };
@@ -129,18 +981,9 @@ mod tests {
     #[tokio::test]
     async fn test_next_page_multi_page() {
         let pages = vec![
-            EdgePage {
-                nodes: vec![1, 2],
-                next_cursor: Some("cur1".to_string()),
-            },
-            EdgePage {
-                nodes: vec![3],
-                next_cursor: Some("cur2".to_string()),
-            },
-            EdgePage {
-                nodes: vec![4, 5],
-                next_cursor: None,
-            },
+            EdgePage::new(vec![1, 2], Some("cur1".to_string())),
+            EdgePage::new(vec![3], Some("cur2".to_string())),
+            EdgePage::new(vec![4, 5], None),
         ];
         let pages = Arc::new(Mutex::new(pages.into_iter()));
 
@@ -171,12 +1014,7 @@ mod tests {
     #[cfg_attr(miri, ignore)]
     #[tokio::test]
     async fn test_next_page_empty_first_page() {
-        let fetch = |_: Option<()>| async {
-            Ok(EdgePage::<i32, ()> {
-                nodes: vec![],
-                next_cursor: None,
-            })
-        };
+        let fetch = |_: Option<()>| async { Ok(EdgePage::<i32, ()>::new(vec![], None)) };
         let extract = |page: EdgePage<i32, ()>| Ok(page);
 
         let mut paginator = Paginator::new(fetch, extract);
@@ -187,13 +1025,431 @@ mod tests {
 
     #[cfg_attr(miri, ignore)]
     #[tokio::test]
-    async fn test_pagination_next_page_done() {
-        let fetch = |_: Option<()>| async {
-            Ok(EdgePage::<i32, ()> {
-                nodes: vec![42],
-                next_cursor: None,
+    async fn test_paginate_list_offset_math() {
+        let data: Vec<i32> = (0..7).collect();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let seen = calls.clone();
+        let paginator = paginate_list(3, move |offset, limit| {
+            seen.lock().unwrap().push((offset, limit));
+            let page: Vec<i32> = data
+                .iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .copied()
+                .collect();
+            async move { Ok(page) }
+        });
+        let items = paginator.collect_all().await.unwrap();
+        assert_eq!(items, (0..7).collect::<Vec<_>>());
+        assert_eq!(*calls.lock().unwrap(), vec![(0, 3), (3, 3), (6, 3)]);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_paginate_list_exact_multiple_stops_on_empty_page() {
+        let calls = Arc::new(Mutex::new(0));
+        let seen = calls.clone();
+        let paginator = paginate_list(0, move |offset, limit| {
+            *seen.lock().unwrap() += 1;
+            assert_eq!(limit, 1);
+            let page = if offset < 2 { vec![offset] } else { vec![] };
+            async move { Ok(page) }
+        });
+        let items = paginator.collect_all().await.unwrap();
+        assert_eq!(items, vec![0, 1]);
+        assert_eq!(*calls.lock().unwrap(), 3);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_offset_paginator_stops_at_count() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let seen = calls.clone();
+        let mut paginator = OffsetPaginator::new(2, move |offset, limit| {
+            seen.lock().unwrap().push((offset, limit));
+            let nodes: Vec<i64> = (offset..(offset + limit).min(4)).collect();
+            async move { Ok(OffsetPage { nodes, count: 4 }) }
+        });
+        assert_eq!(paginator.total_count(), None);
+        assert_eq!(paginator.next_page().await.unwrap().unwrap(), vec![0, 1]);
+        assert_eq!(paginator.total_count(), Some(4));
+        assert_eq!(paginator.fetched_so_far(), 2);
+        assert_eq!(paginator.offset(), 2);
+        assert_eq!(paginator.next_page().await.unwrap().unwrap(), vec![2, 3]);
+        assert!(paginator.next_page().await.unwrap().is_none());
+        assert_eq!(*calls.lock().unwrap(), vec![(0, 2), (2, 2)]);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_offset_paginator_stops_on_empty_page() {
+        let paginator = OffsetPaginator::new(0, |offset, limit| {
+            assert_eq!(limit, 1);
+            let nodes = if offset == 0 { vec![0] } else { vec![] };
+            async move { Ok(OffsetPage { nodes, count: 10 }) }
+        });
+        assert_eq!(paginator.collect_all().await.unwrap(), vec![0]);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_offset_paginator_concurrent_in_order() {
+        use std::time::Duration;
+
+        let active = Arc::new(Mutex::new((0usize, 0usize)));
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let (seen, tracked) = (calls.clone(), active.clone());
+        let paginator = OffsetPaginator::new(10, move |offset, limit| {
+            seen.lock().unwrap().push(offset);
+            let active = tracked.clone();
+            async move {
+                {
+                    let mut active = active.lock().unwrap();
+                    active.0 += 1;
+                    active.1 = active.1.max(active.0);
+                }
+                // later pages finish first to exercise reordering
+                tokio::time::sleep(Duration::from_millis(50 - offset as u64 / 2)).await;
+                active.lock().unwrap().0 -= 1;
+                let nodes: Vec<i64> = (offset..(offset + limit).min(95)).collect();
+                Ok(OffsetPage { nodes, count: 95 })
+            }
+        })
+        .with_concurrency(3);
+
+        let items = paginator.collect_all().await.unwrap();
+        assert_eq!(items, (0..95).collect::<Vec<_>>());
+        assert_eq!(
+            *calls.lock().unwrap(),
+            (0..10).map(|page| page * 10).collect::<Vec<_>>()
+        );
+        assert_eq!(active.lock().unwrap().1, 3);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_paginator_resume_from_checkpoint() {
+        let pages = |cursor: Option<u32>| async move {
+            let start = cursor.unwrap_or(0);
+            Ok(EdgePage::new(vec![start], (start < 3).then_some(start + 1)))
+        };
+        let extract = |page: EdgePage<u32, u32>| Ok(page);
+
+        let mut first = Paginator::new(pages, extract);
+        assert_eq!(first.next_page().await.unwrap().unwrap(), vec![0]);
+        assert_eq!(first.next_page().await.unwrap().unwrap(), vec![1]);
+        let saved = serde_json::to_string(&first.checkpoint()).unwrap();
+        drop(first);
+
+        let checkpoint: Checkpoint<u32> = serde_json::from_str(&saved).unwrap();
+        assert_eq!(checkpoint.cursor, Some(2));
+        let resumed = Paginator::new(pages, extract).resume(checkpoint);
+        assert_eq!(resumed.fetched_so_far(), 2);
+        assert_eq!(resumed.collect_all().await.unwrap(), vec![2, 3]);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_offset_paginator_resume_from_checkpoint() {
+        let fetch = |offset: i64, limit: i64| {
+            let nodes: Vec<i64> = (offset..(offset + limit).min(7)).collect();
+            async move { Ok(OffsetPage { nodes, count: 7 }) }
+        };
+
+        let fresh = OffsetPaginator::new(3, fetch);
+        assert_eq!(fresh.checkpoint().cursor, None);
+
+        let mut first = OffsetPaginator::new(3, fetch).with_concurrency(2);
+        first.next_page().await.unwrap();
+        first.next_page().await.unwrap();
+        let checkpoint = first.checkpoint();
+        assert_eq!(checkpoint.cursor, Some(6));
+        assert_eq!(checkpoint.total, Some(7));
+
+        let mut resumed = OffsetPaginator::new(3, fetch).resume(checkpoint);
+        assert_eq!(resumed.next_page().await.unwrap().unwrap(), vec![6]);
+        assert!(resumed.next_page().await.unwrap().is_none());
+        let done = resumed.checkpoint();
+        assert!(done.done);
+        assert_eq!(done.fetched, 7);
+
+        let mut finished = OffsetPaginator::new(3, fetch).resume(done);
+        assert!(finished.next_page().await.unwrap().is_none());
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_for_each_page_and_map_items() {
+        let fetch = |offset: i64, limit: i64| {
+            let nodes: Vec<i64> = (offset..(offset + limit).min(5)).collect();
+            async move { Ok(OffsetPage { nodes, count: 5 }) }
+        };
+
+        let mut sizes = Vec::new();
+        OffsetPaginator::new(2, fetch)
+            .for_each_page(|page| {
+                sizes.push(page.len());
+                async { Ok(()) }
             })
+            .await
+            .unwrap();
+        assert_eq!(sizes, vec![2, 2, 1]);
+
+        let mapped = OffsetPaginator::new(2, fetch).map_items(|n| n * 10);
+        assert_eq!(mapped.get_ref().offset(), 0);
+        let items = Pages::collect_all(mapped).await.unwrap();
+        assert_eq!(items, vec![0, 10, 20, 30, 40]);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_into_stream_yields_items_then_error() {
+        let fetch = |offset: i64, limit: i64| {
+            let nodes: Vec<i64> = (offset..(offset + limit).min(5)).collect();
+            async move { Ok(OffsetPage { nodes, count: 5 }) }
         };
+        let items: Vec<i64> = OffsetPaginator::new(2, fetch)
+            .into_stream()
+            .map(|item| item.unwrap())
+            .collect()
+            .await;
+        assert_eq!(items, vec![0, 1, 2, 3, 4]);
+
+        let failing = |offset: i64, _limit: i64| async move {
+            if offset == 0 {
+                Ok(OffsetPage {
+                    nodes: vec![1, 2],
+                    count: 5,
+                })
+            } else {
+                Err(Error::Config("boom".to_string()))
+            }
+        };
+        let results: Vec<Result<i64>> = OffsetPaginator::new(2, failing)
+            .into_stream()
+            .collect()
+            .await;
+        assert_eq!(results.len(), 3);
+        assert!(results[2].is_err());
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_try_for_each_item_stops_on_error() {
+        let fetched = Arc::new(Mutex::new(Vec::new()));
+        let seen = fetched.clone();
+        let fetch = move |cursor: Option<u32>| {
+            let start = cursor.unwrap_or(0);
+            seen.lock().unwrap().push(start);
+            async move {
+                Ok(EdgePage::new(
+                    vec![start, start + 1],
+                    (start < 6).then_some(start + 2),
+                ))
+            }
+        };
+        let extract = |page: EdgePage<u32, u32>| Ok(page);
+
+        let mut items = Vec::new();
+        let err = Paginator::new(fetch, extract)
+            .try_for_each_item(|item| {
+                items.push(item);
+                async move {
+                    if item == 2 {
+                        Err(Error::Config("stop".to_string()))
+                    } else {
+                        Ok(())
+                    }
+                }
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Config(_)));
+        assert_eq!(items, vec![0, 1, 2]);
+        assert_eq!(*fetched.lock().unwrap(), vec![0, 2]);
+    }
+
+    #[test]
+    fn test_adaptive_next_limit() {
+        let bounds = AdaptiveLimit::new(10, 200, Duration::from_millis(100));
+        // 1ms per item aims for 100 items, but grows at most twofold
+        assert_eq!(bounds.next_limit(20, 20, Duration::from_millis(20)), 40);
+        assert_eq!(bounds.next_limit(80, 80, Duration::from_millis(80)), 100);
+        // slow pages shrink, at most by half and never below min
+        assert_eq!(bounds.next_limit(100, 100, Duration::from_millis(1000)), 50);
+        assert_eq!(bounds.next_limit(15, 15, Duration::from_secs(10)), 10);
+        assert_eq!(bounds.next_limit(150, 150, Duration::ZERO), 200);
+        assert_eq!(bounds.next_limit(30, 0, Duration::from_secs(1)), 30);
+        assert_eq!(AdaptiveLimit::new(0, -5, Duration::ZERO).max, 1);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_offset_paginator_adaptive_grows() {
+        let limits = Arc::new(Mutex::new(Vec::new()));
+        let seen = limits.clone();
+        let paginator = OffsetPaginator::new(1, move |offset, limit| {
+            seen.lock().unwrap().push(limit);
+            let nodes: Vec<i64> = (offset..(offset + limit).min(30)).collect();
+            async move { Ok(OffsetPage { nodes, count: 30 }) }
+        })
+        .with_adaptive_limit(AdaptiveLimit::new(2, 8, Duration::from_secs(60)));
+        assert_eq!(paginator.limit(), 2);
+        let items = paginator.collect_all().await.unwrap();
+        assert_eq!(items, (0..30).collect::<Vec<_>>());
+        assert_eq!(*limits.lock().unwrap(), vec![2, 4, 8, 8, 8]);
+    }
+
+    #[test]
+    fn test_with_offset() {
+        let vars = with_offset(Some(serde_json::json!({ "name": "x" })), 5, 10).unwrap();
+        assert_eq!(
+            vars,
+            serde_json::json!({ "name": "x", "offset": 5, "limit": 10 })
+        );
+        assert_eq!(
+            with_offset(None, 0, 1).unwrap(),
+            serde_json::json!({ "offset": 0, "limit": 1 })
+        );
+        assert!(with_offset(Some(serde_json::json!([1])), 0, 1).is_err());
+    }
+
+    #[test]
+    fn test_cursor_page_paths() {
+        let data = serde_json::json!({
+            "devices": {
+                "pageInfo": { "endCursor": "c2", "hasNextPage": true },
+                "edges": [{ "node": { "id": "a" } }, { "node": { "id": "b" } }]
+            }
+        });
+        let page = cursor_page(&data, "devices.pageInfo.endCursor", "devices.edges.node");
+        assert_eq!(
+            page.nodes,
+            vec![
+                serde_json::json!({ "id": "a" }),
+                serde_json::json!({ "id": "b" })
+            ]
+        );
+        assert_eq!(page.next_cursor.as_deref(), Some("c2"));
+
+        let last = serde_json::json!({
+            "devices": { "pageInfo": { "endCursor": "c3", "hasNextPage": false }, "edges": [] }
+        });
+        let page = cursor_page(&last, "devices.pageInfo.endCursor", "devices.edges.node");
+        assert!(page.nodes.is_empty());
+        assert!(page.next_cursor.is_none());
+
+        let flat = serde_json::json!({ "items": [1, 2], "next": null });
+        let page = cursor_page(&flat, "next", "items");
+        assert_eq!(page.nodes, vec![Value::from(1), Value::from(2)]);
+        assert!(page.next_cursor.is_none());
+
+        assert_eq!(
+            with_cursor(None, "after", Some("c1".to_string())).unwrap(),
+            serde_json::json!({ "after": "c1" })
+        );
+        assert_eq!(
+            with_cursor(None, "after", None).unwrap(),
+            serde_json::json!({})
+        );
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_paginate_raw_cursor_variable() {
+        use crate::testing::MockClient;
+        use serde_json::json;
+
+        let mock = MockClient::new();
+        mock.expect("Devices")
+            .with_variables(json!({ "first": 2 }))
+            .returning(json!({
+                "devices": { "next": "c1", "items": [{ "id": "a" }, { "id": "b" }] }
+            }));
+        mock.expect("Devices")
+            .with_variables(json!({ "first": 2, "cursor": "c1" }))
+            .returning(json!({ "devices": { "next": null, "items": [{ "id": "c" }] } }));
+
+        let query = "query Devices($first: Int, $cursor: String) { devices(first: $first, cursor: $cursor) { next items { id } } }";
+        let spec =
+            PaginationSpec::new("devices.items", "devices.next").with_cursor_variable("cursor");
+        let nodes = mock
+            .client()
+            .paginate_raw(query, Some(json!({ "first": 2 })), Some("b1"), spec)
+            .collect_all()
+            .await
+            .unwrap();
+        let ids: Vec<&str> = nodes.iter().filter_map(|n| n["id"].as_str()).collect();
+        assert_eq!(ids, ["a", "b", "c"]);
+        mock.verify();
+        assert!(mock
+            .calls()
+            .iter()
+            .all(|call| call.branch.as_deref() == Some("b1")));
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_paginator_progress() {
+        let fetch = |cursor: Option<u32>| async move {
+            let start = cursor.unwrap_or(0);
+            Ok((vec![start, start + 1], 5_i64))
+        };
+        let extract = |(nodes, _): (Vec<u32>, i64)| {
+            let next_cursor = (nodes[0] < 2).then(|| nodes[0] + 2);
+            Ok(EdgePage::new(nodes, next_cursor))
+        };
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let hook_seen = seen.clone();
+        let mut paginator = Paginator::new(fetch, extract)
+            .with_total_count(|(_, total)| Some(*total))
+            .on_progress(move |progress| hook_seen.lock().unwrap().push(progress));
+
+        assert_eq!(paginator.total_count(), None);
+        while paginator.next_page().await.unwrap().is_some() {}
+        assert_eq!(paginator.total_count(), Some(5));
+        assert_eq!(paginator.fetched_so_far(), 4);
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 2);
+        assert_eq!(
+            seen[1],
+            Progress {
+                pages: 2,
+                fetched: 4,
+                total: Some(5)
+            }
+        );
+        assert_eq!(seen[0].fraction(), Some(0.4));
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_offset_paginator_progress() {
+        let fractions = Arc::new(Mutex::new(Vec::new()));
+        let seen = fractions.clone();
+        let paginator = OffsetPaginator::new(3, |offset, limit| {
+            let nodes: Vec<i64> = (offset..(offset + limit).min(6)).collect();
+            async move { Ok(OffsetPage { nodes, count: 6 }) }
+        })
+        .on_progress(move |progress| seen.lock().unwrap().push(progress.fraction()));
+        assert_eq!(paginator.collect_all().await.unwrap().len(), 6);
+        assert_eq!(*fractions.lock().unwrap(), vec![Some(0.5), Some(1.0)]);
+        assert_eq!(
+            Progress {
+                pages: 0,
+                fetched: 0,
+                total: None
+            }
+            .fraction(),
+            None
+        );
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_pagination_next_page_done() {
+        let fetch = |_: Option<()>| async { Ok(EdgePage::<i32, ()>::new(vec![42], None)) };
         let extract = |page: EdgePage<i32, ()>| Ok(page);
 
         let mut paginator = Paginator::new(fetch, extract);
@@ -202,4 +1458,59 @@ mod tests {
         let none = paginator.next_page().await.unwrap();
         assert!(none.is_none());
     }
+
+    #[test]
+    fn test_edge_page_info_from_connection() {
+        let info = EdgePageInfo::from_connection(&serde_json::json!({
+            "count": 12,
+            "page_info": { "has_next_page": true, "end_cursor": "c5" },
+            "edges": []
+        }));
+        assert_eq!(
+            info,
+            EdgePageInfo {
+                has_next_page: Some(true),
+                end_cursor: Some("c5".to_string()),
+                total_count: Some(12),
+            }
+        );
+        let camel = EdgePageInfo::from_connection(&serde_json::json!({
+            "pageInfo": { "hasNextPage": false, "endCursor": null }
+        }));
+        assert_eq!(camel.has_next_page, Some(false));
+        assert_eq!(camel.end_cursor, None);
+        assert_eq!(camel.total_count, None);
+
+        let page = EdgePage::<u32, String>::default();
+        assert!(page.nodes.is_empty());
+        assert_eq!(page.next_cursor, None);
+        assert_eq!(page.info, EdgePageInfo::default());
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_paginator_stops_on_has_next_page_false() {
+        let calls = Arc::new(Mutex::new(0));
+        let seen = calls.clone();
+        let fetch = move |cursor: Option<u32>| {
+            *seen.lock().unwrap() += 1;
+            async move {
+                let start = cursor.unwrap_or(0);
+                let info = EdgePageInfo {
+                    has_next_page: Some(start == 0),
+                    end_cursor: Some((start + 2).to_string()),
+                    total_count: Some(4),
+                };
+                Ok(EdgePage::new(vec![start, start + 1], Some(start + 2)).with_info(info))
+            }
+        };
+        let extract = |page: EdgePage<u32, u32>| Ok(page);
+
+        let mut paginator = Paginator::new(fetch, extract);
+        assert_eq!(paginator.next_page().await.unwrap().unwrap(), [0, 1]);
+        assert_eq!(paginator.total_count(), Some(4));
+        assert_eq!(paginator.next_page().await.unwrap().unwrap(), [2, 3]);
+        assert!(paginator.next_page().await.unwrap().is_none());
+        assert_eq!(*calls.lock().unwrap(), 2);
+    }
 }
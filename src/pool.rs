@@ -0,0 +1,400 @@
+//! resource pool helpers
+//!
+//! typed wrappers around the `InfrahubIPAddressPoolGetResource` and
+//! `InfrahubIPPrefixPoolGetResource` mutations, which allocate the next
+//! free address or prefix from a `CoreIPAddressPool` or `CoreIPPrefixPool`,
+//! and around `from_pool` attribute inputs, which take a number from a
+//! `CoreNumberPool` while creating the node that holds it.
+//!
+//! allocations with an identifier are idempotent: asking the same pool again
+//! with the same identifier returns the resource allocated the first time.
+//!
+//! ```no_run
+//! use infrahub::{Client, ClientConfig, PoolAllocation};
+//!
+//! # async fn example() -> infrahub::Result<()> {
+//! let client = Client::new(ClientConfig::new("http://localhost:8000", "token"))?;
+//! let allocation = PoolAllocation::new()
+//!     .with_identifier("device-1:loopback0")
+//!     .with_data("description", "loopback0");
+//! let address = client
+//!     .allocate_ip_address("pool-1", &allocation, None)
+//!     .await?;
+//! println!("{} {}", address.id, address.display_label);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::branch::ensure_ok;
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::nodes::{validate_kind, NODE_PAGE_SIZE};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+/// options for a pool allocation
+#[derive(Debug, Clone, Default)]
+pub struct PoolAllocation {
+    identifier: Option<String>,
+    prefix_length: Option<u8>,
+    kind: Option<String>,
+    member_type: Option<String>,
+    data: Map<String, Value>,
+}
+
+impl PoolAllocation {
+    /// an allocation with the pool's defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// reuse the resource already allocated under `identifier`, if any
+    pub fn with_identifier(mut self, identifier: impl Into<String>) -> Self {
+        self.identifier = Some(identifier.into());
+        self
+    }
+
+    /// override the pool's default prefix length
+    pub fn with_prefix_length(mut self, prefix_length: u8) -> Self {
+        self.prefix_length = Some(prefix_length);
+        self
+    }
+
+    /// kind of the node to create, e.g. `IpamIPAddress` or `IpamIPPrefix`
+    pub fn with_kind(mut self, kind: impl Into<String>) -> Self {
+        self.kind = Some(kind.into());
+        self
+    }
+
+    /// member type of an allocated prefix, `prefix` or `address`
+    ///
+    /// ignored for address pools.
+    pub fn with_member_type(mut self, member_type: impl Into<String>) -> Self {
+        self.member_type = Some(member_type.into());
+        self
+    }
+
+    /// set a field on the allocated node
+    pub fn with_data(mut self, field: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.data.insert(field.into(), value.into());
+        self
+    }
+
+    fn to_input(&self, pool: &str, kind_field: &str) -> Value {
+        let mut input = Map::new();
+        input.insert("id".to_string(), json!(pool));
+        if let Some(identifier) = &self.identifier {
+            input.insert("identifier".to_string(), json!(identifier));
+        }
+        if let Some(prefix_length) = self.prefix_length {
+            input.insert("prefix_length".to_string(), json!(prefix_length));
+        }
+        if let Some(kind) = &self.kind {
+            input.insert(kind_field.to_string(), json!(kind));
+        }
+        if !self.data.is_empty() {
+            input.insert("data".to_string(), Value::Object(self.data.clone()));
+        }
+        Value::Object(input)
+    }
+}
+
+/// a node allocated from a resource pool
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AllocatedResource {
+    /// node id
+    pub id: String,
+    /// node kind, e.g. `IpamIPAddress`
+    pub kind: String,
+    /// the identifier the resource was allocated under, if any
+    pub identifier: Option<String>,
+    /// display label, usually the address or prefix
+    pub display_label: String,
+}
+
+/// a number allocated from a `CoreNumberPool`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllocatedNumber {
+    /// id of the node holding the number
+    pub node_id: String,
+    /// the allocated number
+    pub value: i64,
+}
+
+/// a resource held from a pool, as listed by [`Client::pool_allocations`]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ResourceAllocation {
+    /// id of the allocated node
+    pub id: String,
+    /// kind of the allocated node
+    pub kind: String,
+    /// branch the allocation was made on
+    pub branch: String,
+    /// the identifier the resource was allocated under, if any
+    pub identifier: Option<String>,
+}
+
+impl Client {
+    /// allocate the next free address from a `CoreIPAddressPool`
+    pub async fn allocate_ip_address(
+        &self,
+        pool: &str,
+        allocation: &PoolAllocation,
+        branch: Option<&str>,
+    ) -> Result<AllocatedResource> {
+        let query = "mutation AllocateIpAddress($data: IPAddressPoolGetResourceInput!) { InfrahubIPAddressPoolGetResource(data: $data) { ok node { id kind identifier display_label } } }";
+        let vars = json!({ "data": allocation.to_input(pool, "address_type") });
+        let response = self.execute_raw(query, Some(vars), branch).await?;
+        allocated(response.data, "InfrahubIPAddressPoolGetResource")
+    }
+
+    /// allocate the next free prefix from a `CoreIPPrefixPool`
+    pub async fn allocate_ip_prefix(
+        &self,
+        pool: &str,
+        allocation: &PoolAllocation,
+        branch: Option<&str>,
+    ) -> Result<AllocatedResource> {
+        let query = "mutation AllocateIpPrefix($data: IPPrefixPoolGetResourceInput!) { InfrahubIPPrefixPoolGetResource(data: $data) { ok node { id kind identifier display_label } } }";
+        let mut input = allocation.to_input(pool, "prefix_type");
+        if let Some(member_type) = &allocation.member_type {
+            input["member_type"] = json!(member_type);
+        }
+        let response = self
+            .execute_raw(query, Some(json!({ "data": input })), branch)
+            .await?;
+        allocated(response.data, "InfrahubIPPrefixPoolGetResource")
+    }
+
+    /// create a node of `kind` whose `attribute` is the next free number of a
+    /// `CoreNumberPool`
+    ///
+    /// `data` holds the other fields of the `<kind>Create` input. with an
+    /// `identifier`, a number already allocated under it is reused.
+    pub async fn allocate_number(
+        &self,
+        kind: impl AsRef<str>,
+        attribute: &str,
+        pool: &str,
+        identifier: Option<&str>,
+        data: Value,
+        branch: Option<&str>,
+    ) -> Result<AllocatedNumber> {
+        let kind = kind.as_ref();
+        validate_kind(kind)?;
+        validate_kind(attribute)
+            .map_err(|_| Error::Config(format!("invalid attribute name: {attribute:?}")))?;
+        let mut input = match data {
+            Value::Object(map) => map,
+            Value::Null => Map::new(),
+            _ => return Err(Error::Config("node data must be an object".to_string())),
+        };
+        input.insert(attribute.to_string(), from_pool(pool, identifier));
+
+        let mutation = format!("{kind}Create");
+        let query = format!(
+            "mutation AllocateNumber($data: {mutation}Input!) {{ {mutation}(data: $data) {{ ok object {{ id {attribute} {{ value }} }} }} }}"
+        );
+        let vars = json!({ "data": input });
+        let response = self.execute_raw(&query, Some(vars), branch).await?;
+        let data = response.into_data()?;
+        let object = &data[&mutation]["object"];
+        match (object["id"].as_str(), object[attribute]["value"].as_i64()) {
+            (Some(id), Some(value)) => Ok(AllocatedNumber {
+                node_id: id.to_string(),
+                value,
+            }),
+            _ => Err(Error::Config(format!(
+                "{mutation} did not return an allocated {attribute}"
+            ))),
+        }
+    }
+
+    /// release a number by deleting the node of `kind` that holds it
+    ///
+    /// infrahub has no separate release mutation: a number goes back to its
+    /// pool once no node holds it.
+    pub async fn release_number(
+        &self,
+        kind: impl AsRef<str>,
+        node_id: &str,
+        branch: Option<&str>,
+    ) -> Result<()> {
+        let kind = kind.as_ref();
+        validate_kind(kind)?;
+        let mutation = format!("{kind}Delete");
+        let query = format!(
+            "mutation ReleaseNumber($id: String!) {{ {mutation}(data: {{ id: $id }}) {{ ok }} }}"
+        );
+        let response = self
+            .execute_raw(&query, Some(json!({ "id": node_id })), branch)
+            .await?;
+        ensure_ok(response.data, &mutation)
+    }
+
+    /// list the resources allocated from `pool` out of `resource`
+    ///
+    /// for an ip pool `resource` is one of its prefixes; for a
+    /// `CoreNumberPool` pass the pool id again.
+    pub async fn pool_allocations(
+        &self,
+        pool: &str,
+        resource: &str,
+        branch: Option<&str>,
+    ) -> Result<Vec<ResourceAllocation>> {
+        let query = "query PoolAllocations($pool: String!, $resource: String!, $offset: Int, $limit: Int) { InfrahubResourcePoolAllocated(pool_id: $pool, resource_id: $resource, offset: $offset, limit: $limit) { count edges { node { id kind branch identifier } } } }";
+        let vars = json!({ "pool": pool, "resource": resource });
+        let nodes = self
+            .paginate_offset(
+                query,
+                "InfrahubResourcePoolAllocated",
+                Some(vars),
+                NODE_PAGE_SIZE,
+                branch,
+            )
+            .collect_all()
+            .await?;
+        nodes
+            .iter()
+            .map(|node| Ok(ResourceAllocation::deserialize(node)?))
+            .collect()
+    }
+}
+
+/// a `from_pool` attribute input
+fn from_pool(pool: &str, identifier: Option<&str>) -> Value {
+    let mut source = json!({ "id": pool });
+    if let Some(identifier) = identifier {
+        source["identifier"] = json!(identifier);
+    }
+    json!({ "from_pool": source })
+}
+
+/// the node returned by a pool allocation mutation
+fn allocated(data: Option<Value>, mutation: &str) -> Result<AllocatedResource> {
+    let data = data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+    let node = &data[mutation]["node"];
+    if data[mutation]["ok"].as_bool() != Some(true) || node.is_null() {
+        return Err(Error::Config(format!("{mutation} did not return a node")));
+    }
+    Ok(AllocatedResource::deserialize(node)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockClient;
+
+    #[test]
+    fn test_allocation_input() {
+        let allocation = PoolAllocation::new();
+        assert_eq!(
+            allocation.to_input("p1", "address_type"),
+            json!({ "id": "p1" })
+        );
+
+        let allocation = PoolAllocation::new()
+            .with_identifier("dev-1")
+            .with_prefix_length(31)
+            .with_kind("IpamIPAddress")
+            .with_data("description", "uplink");
+        assert_eq!(
+            allocation.to_input("p1", "address_type"),
+            json!({
+                "id": "p1",
+                "identifier": "dev-1",
+                "prefix_length": 31,
+                "address_type": "IpamIPAddress",
+                "data": { "description": "uplink" },
+            })
+        );
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_allocate_ip_prefix() {
+        let mock = MockClient::new();
+        mock.expect("AllocateIpPrefix")
+            .with_variables(json!({ "data": {
+                "id": "pool-1", "identifier": "site-a", "member_type": "address"
+            } }))
+            .returning(json!({
+                "InfrahubIPPrefixPoolGetResource": { "ok": true, "node": {
+                    "id": "pfx-1", "kind": "IpamIPPrefix",
+                    "identifier": "site-a", "display_label": "10.0.0.0/24"
+                } }
+            }));
+        mock.expect("AllocateIpAddress").returning(json!({
+            "InfrahubIPAddressPoolGetResource": { "ok": false, "node": null }
+        }));
+
+        let client = mock.client();
+        let allocation = PoolAllocation::new()
+            .with_identifier("site-a")
+            .with_member_type("address");
+        let prefix = client
+            .allocate_ip_prefix("pool-1", &allocation, Some("b1"))
+            .await
+            .unwrap();
+        assert_eq!(prefix.display_label, "10.0.0.0/24");
+        assert_eq!(prefix.identifier.as_deref(), Some("site-a"));
+        assert_eq!(mock.calls()[0].branch.as_deref(), Some("b1"));
+
+        let address = client
+            .allocate_ip_address("pool-2", &PoolAllocation::new(), None)
+            .await;
+        assert!(address.is_err());
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_number_pool() {
+        let mock = MockClient::new();
+        mock.expect("AllocateNumber")
+            .with_variables(json!({ "data": {
+                "name": { "value": "users" },
+                "vlan_id": { "from_pool": { "id": "np-1", "identifier": "site-a:users" } }
+            } }))
+            .returning(json!({
+                "IpamVLANCreate": { "ok": true, "object": { "id": "v1", "vlan_id": { "value": 100 } } }
+            }));
+        mock.expect("PoolAllocations")
+            .with_variables(
+                json!({ "pool": "np-1", "resource": "np-1", "offset": 0, "limit": 100 }),
+            )
+            .returning(json!({
+                "InfrahubResourcePoolAllocated": { "count": 1, "edges": [{ "node": {
+                    "id": "v1", "kind": "IpamVLAN", "branch": "main", "identifier": "site-a:users"
+                } }] }
+            }));
+        mock.expect("ReleaseNumber")
+            .with_variables(json!({ "id": "v1" }))
+            .returning(json!({ "IpamVLANDelete": { "ok": true } }));
+
+        let client = mock.client();
+        let data = json!({ "name": { "value": "users" } });
+        let number = client
+            .allocate_number(
+                "IpamVLAN",
+                "vlan_id",
+                "np-1",
+                Some("site-a:users"),
+                data,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(number.node_id, "v1");
+        assert_eq!(number.value, 100);
+
+        let allocations = client.pool_allocations("np-1", "np-1", None).await.unwrap();
+        assert_eq!(allocations.len(), 1);
+        assert_eq!(allocations[0].identifier.as_deref(), Some("site-a:users"));
+
+        client.release_number("IpamVLAN", "v1", None).await.unwrap();
+        assert!(client
+            .allocate_number("IpamVLAN", "vlan id", "np-1", None, json!(null), None)
+            .await
+            .is_err());
+    }
+}
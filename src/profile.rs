@@ -0,0 +1,183 @@
+//! profile helpers
+//!
+//! list the `Profile<Kind>` nodes available for a kind and attach or detach
+//! them through a node's `profiles` relationship. attributes a node leaves
+//! unset take their values from its highest priority profile.
+//!
+//! ```no_run
+//! use infrahub::{Client, ClientConfig};
+//!
+//! # async fn example() -> infrahub::Result<()> {
+//! let client = Client::new(ClientConfig::new("http://localhost:8000", "token"))?;
+//! let profiles = client.profiles("InfraInterfaceL3", None).await?;
+//! let uplink = profiles
+//!     .iter()
+//!     .find(|profile| profile.name == "uplink")
+//!     .expect("profile exists");
+//! client
+//!     .attach_profiles("interface-1", &[uplink.id.as_str()], None)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::account::related;
+use crate::branch::ensure_ok;
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::nodes::validate_kind;
+use serde_json::{json, Value};
+
+/// selection of the fields every profile carries
+const PROFILE_SELECTION: &str = "profile_name { value } profile_priority { value }";
+
+/// a `CoreProfile` node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    /// node id
+    pub id: String,
+    /// profile name
+    pub name: String,
+    /// priority, lower wins when several profiles set an attribute
+    pub priority: Option<i64>,
+}
+
+impl Profile {
+    fn from_node(node: &Value) -> Result<Self> {
+        match (node["id"].as_str(), node["profile_name"]["value"].as_str()) {
+            (Some(id), Some(name)) => Ok(Self {
+                id: id.to_string(),
+                name: name.to_string(),
+                priority: node["profile_priority"]["value"].as_i64(),
+            }),
+            _ => Err(Error::Config(
+                "profile is missing its id or profile_name".to_string(),
+            )),
+        }
+    }
+}
+
+impl Client {
+    /// list the profiles available for nodes of `kind`
+    pub async fn profiles(
+        &self,
+        kind: impl AsRef<str>,
+        branch: Option<&str>,
+    ) -> Result<Vec<Profile>> {
+        let kind = kind.as_ref();
+        validate_kind(kind)?;
+        let nodes = self
+            .query_nodes(format!("Profile{kind}"), PROFILE_SELECTION, branch)
+            .await?;
+        nodes.iter().map(Profile::from_node).collect()
+    }
+
+    /// list the profiles attached to a node of `kind`
+    ///
+    /// returns an empty list if the node does not exist.
+    pub async fn node_profiles(
+        &self,
+        kind: impl AsRef<str>,
+        node_id: &str,
+        branch: Option<&str>,
+    ) -> Result<Vec<Profile>> {
+        let kind = kind.as_ref();
+        validate_kind(kind)?;
+        let query = format!(
+            "query NodeProfiles($ids: [ID]) {{ {kind}(ids: $ids) {{ edges {{ node {{ profiles {{ edges {{ node {{ id ... on CoreProfile {{ {PROFILE_SELECTION} }} }} }} }} }} }} }} }}"
+        );
+        let response = self
+            .execute_raw(&query, Some(json!({ "ids": [node_id] })), branch)
+            .await?;
+        let data = response.into_data()?;
+        match data[kind]["edges"][0]["node"]["profiles"]["edges"].as_array() {
+            Some(edges) => edges
+                .iter()
+                .map(|edge| Profile::from_node(&edge["node"]))
+                .collect(),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// attach profiles to a node, keeping the ones already attached
+    pub async fn attach_profiles(
+        &self,
+        node_id: &str,
+        profiles: &[&str],
+        branch: Option<&str>,
+    ) -> Result<()> {
+        let query = "mutation AttachProfiles($data: RelationshipNodesInput!) { RelationshipAdd(data: $data) { ok } }";
+        let vars = json!({
+            "data": { "id": node_id, "name": "profiles", "nodes": related(profiles) }
+        });
+        let response = self.execute_raw(query, Some(vars), branch).await?;
+        ensure_ok(response.data, "RelationshipAdd")
+    }
+
+    /// detach profiles from a node
+    pub async fn detach_profiles(
+        &self,
+        node_id: &str,
+        profiles: &[&str],
+        branch: Option<&str>,
+    ) -> Result<()> {
+        let query = "mutation DetachProfiles($data: RelationshipNodesInput!) { RelationshipRemove(data: $data) { ok } }";
+        let vars = json!({
+            "data": { "id": node_id, "name": "profiles", "nodes": related(profiles) }
+        });
+        let response = self.execute_raw(query, Some(vars), branch).await?;
+        ensure_ok(response.data, "RelationshipRemove")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockClient;
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_profiles() {
+        let mock = MockClient::new();
+        mock.expect("Nodes").returning(json!({
+            "ProfileInfraDevice": { "count": 2, "edges": [
+                { "node": { "id": "p1", "profile_name": { "value": "edge" },
+                            "profile_priority": { "value": 1000 } } },
+                { "node": { "id": "p2", "profile_name": { "value": "core" },
+                            "profile_priority": { "value": null } } }
+            ] }
+        }));
+        mock.expect("NodeProfiles")
+            .with_variables(json!({ "ids": ["d1"] }))
+            .returning(json!({
+                "InfraDevice": { "edges": [{ "node": { "profiles": { "edges": [
+                    { "node": { "id": "p1", "profile_name": { "value": "edge" },
+                                "profile_priority": { "value": 1000 } } }
+                ] } } }] }
+            }));
+        mock.expect("AttachProfiles")
+            .with_variables(json!({
+                "data": { "id": "d1", "name": "profiles", "nodes": [{ "id": "p2" }] }
+            }))
+            .returning(json!({ "RelationshipAdd": { "ok": true } }));
+        mock.expect("DetachProfiles")
+            .returning(json!({ "RelationshipRemove": { "ok": false } }));
+
+        let client = mock.client();
+        let profiles = client.profiles("InfraDevice", None).await.unwrap();
+        assert_eq!(profiles.len(), 2);
+        assert_eq!(profiles[0].priority, Some(1000));
+        assert_eq!(profiles[1].name, "core");
+        assert_eq!(profiles[1].priority, None);
+
+        let attached = client
+            .node_profiles("InfraDevice", "d1", None)
+            .await
+            .unwrap();
+        assert_eq!(attached, vec![profiles[0].clone()]);
+
+        client.attach_profiles("d1", &["p2"], None).await.unwrap();
+        assert!(client.detach_profiles("d1", &["p1"], None).await.is_err());
+        assert!(client.profiles("Infra Device", None).await.is_err());
+    }
+}
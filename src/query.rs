@@ -0,0 +1,500 @@
+//! dynamic query builder
+//!
+//! compose node queries at runtime (filters, aliases, pagination, variables,
+//! selections, and named fragments) and render them as graphql documents.
+//! useful when the selection set is only known at runtime and codegen cannot
+//! anticipate it.
+//!
+//! ```
+//! use infrahub::Query;
+//!
+//! let query = Query::node("InfraDevice")
+//!     .filter("role__value", "edge")
+//!     .select(["name { value }", "site { node { id } }"])
+//!     .limit(50)
+//!     .render()
+//!     .unwrap();
+//! assert_eq!(
+//!     query,
+//!     "query { InfraDevice(role__value: \"edge\", limit: 50) { count edges { node { id name { value } site { node { id } } } } } }"
+//! );
+//! ```
+
+use crate::error::{Error, Result};
+use serde_json::Value;
+
+/// a graphql query document made of one or more node queries
+#[derive(Debug, Clone, Default)]
+pub struct Query {
+    name: Option<String>,
+    variables: Vec<(String, String)>,
+    nodes: Vec<NodeQuery>,
+    fragments: Vec<Fragment>,
+}
+
+/// a named fragment, defined once and spread into any number of selections
+///
+/// ```
+/// use infrahub::{Fragment, Query};
+///
+/// let device = Fragment::new("DeviceFields", "InfraDevice", "name { value } role { value }");
+/// let query = Query::new()
+///     .with(Query::node("InfraDevice").alias("edge").filter("role__value", "edge").select_fragment(&device))
+///     .with(Query::node("InfraDevice").alias("core").filter("role__value", "core").select_fragment(&device))
+///     .render()
+///     .unwrap();
+/// assert_eq!(query.matches("fragment DeviceFields").count(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fragment {
+    name: String,
+    on: String,
+    selection: String,
+}
+
+impl Fragment {
+    /// define `fragment <name> on <on> { <selection> }`
+    pub fn new(
+        name: impl Into<String>,
+        on: impl Into<String>,
+        selection: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            on: on.into(),
+            selection: selection.into(),
+        }
+    }
+
+    /// fragment name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// spread syntax for use inside a selection, e.g. `...DeviceFields`
+    pub fn spread(&self) -> String {
+        format!("...{}", self.name)
+    }
+
+    /// render the fragment definition
+    ///
+    /// append it to a hand-written document to use the fragment with
+    /// [`crate::Client::execute`].
+    pub fn render(&self) -> Result<String> {
+        check_name(&self.name)?;
+        check_name(&self.on)?;
+        Ok(format!(
+            "fragment {} on {} {{ {} }}",
+            self.name,
+            self.on,
+            self.selection.trim()
+        ))
+    }
+}
+
+/// a query over the nodes of one kind, rendered as
+/// `alias: Kind(args) { count edges { node { id ... } } }`
+#[derive(Debug, Clone)]
+pub struct NodeQuery {
+    kind: String,
+    alias: Option<String>,
+    args: Vec<(String, Arg)>,
+    variables: Vec<(String, String)>,
+    fields: Vec<String>,
+    fragments: Vec<Fragment>,
+    count: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Arg {
+    Literal(Value),
+    Variable(String),
+}
+
+impl Query {
+    /// create an empty anonymous query
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// start a single-node query over `kind`
+    pub fn node(kind: impl Into<String>) -> NodeQuery {
+        NodeQuery {
+            kind: kind.into(),
+            alias: None,
+            args: Vec::new(),
+            variables: Vec::new(),
+            fields: Vec::new(),
+            fragments: Vec::new(),
+            count: true,
+        }
+    }
+
+    /// set the operation name
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// declare a variable, e.g. `variable("limit", "Int!")`
+    pub fn variable(mut self, name: impl Into<String>, ty: impl Into<String>) -> Self {
+        self.variables.push((name.into(), ty.into()));
+        self
+    }
+
+    /// add a node query as another root field
+    pub fn with(mut self, node: NodeQuery) -> Self {
+        self.nodes.push(node);
+        self
+    }
+
+    /// include a fragment definition in the document
+    ///
+    /// fragments spread through [`NodeQuery::select_fragment`] are included
+    /// automatically; use this for fragments referenced from raw selections
+    /// or from other fragments.
+    pub fn fragment(mut self, fragment: Fragment) -> Self {
+        self.fragments.push(fragment);
+        self
+    }
+
+    /// render the graphql document
+    ///
+    /// names are validated and the rendered document is parsed before it is
+    /// returned, so selection typos surface as [`Error::Config`].
+    pub fn render(&self) -> Result<String> {
+        if self.nodes.is_empty() {
+            return Err(Error::Config("query has no root fields".to_string()));
+        }
+
+        let mut variables: Vec<&(String, String)> = Vec::new();
+        for declared in self
+            .variables
+            .iter()
+            .chain(self.nodes.iter().flat_map(|node| node.variables.iter()))
+        {
+            match variables.iter().find(|(name, _)| *name == declared.0) {
+                Some((_, ty)) if *ty != declared.1 => {
+                    return Err(Error::Config(format!(
+                        "variable ${} declared as both {ty} and {}",
+                        declared.0, declared.1
+                    )));
+                }
+                Some(_) => {}
+                None => variables.push(declared),
+            }
+        }
+
+        let mut out = String::from("query");
+        if let Some(name) = &self.name {
+            check_name(name)?;
+            out.push(' ');
+            out.push_str(name);
+        }
+        if !variables.is_empty() {
+            let declared = variables
+                .iter()
+                .map(|(name, ty)| {
+                    check_name(name)?;
+                    Ok(format!("${name}: {ty}"))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            out.push('(');
+            out.push_str(&declared.join(", "));
+            out.push(')');
+        }
+        out.push_str(" {");
+        for node in &self.nodes {
+            out.push(' ');
+            node.render_field(&mut out)?;
+        }
+        out.push_str(" }");
+
+        let mut fragments: Vec<&Fragment> = Vec::new();
+        for fragment in self
+            .fragments
+            .iter()
+            .chain(self.nodes.iter().flat_map(|node| node.fragments.iter()))
+        {
+            match fragments.iter().find(|f| f.name == fragment.name) {
+                Some(existing) if *existing != fragment => {
+                    return Err(Error::Config(format!(
+                        "fragment {} defined more than once with different selections",
+                        fragment.name
+                    )));
+                }
+                Some(_) => {}
+                None => fragments.push(fragment),
+            }
+        }
+        for fragment in fragments {
+            out.push(' ');
+            out.push_str(&fragment.render()?);
+        }
+
+        graphql_parser::query::parse_query::<&str>(&out)
+            .map_err(|err| Error::Config(format!("invalid generated query: {err}")))?;
+        Ok(out)
+    }
+}
+
+impl NodeQuery {
+    /// render under an alias instead of the kind name
+    pub fn alias(mut self, alias: impl Into<String>) -> Self {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    /// add a filter argument with a literal value, e.g. `filter("name__value", "edge-01")`
+    pub fn filter(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.args.push((name.into(), Arg::Literal(value.into())));
+        self
+    }
+
+    /// add a filter argument bound to a query variable, declaring it with `ty`
+    ///
+    /// e.g. `filter_var("name__value", "name", "String")` renders
+    /// `name__value: $name` and declares `$name: String`.
+    pub fn filter_var(
+        mut self,
+        name: impl Into<String>,
+        variable: impl Into<String>,
+        ty: impl Into<String>,
+    ) -> Self {
+        let variable = variable.into();
+        self.variables.push((variable.clone(), ty.into()));
+        self.args.push((name.into(), Arg::Variable(variable)));
+        self
+    }
+
+    /// limit the number of returned nodes
+    pub fn limit(self, limit: i64) -> Self {
+        self.filter("limit", limit)
+    }
+
+    /// skip the first `offset` nodes
+    pub fn offset(self, offset: i64) -> Self {
+        self.filter("offset", offset)
+    }
+
+    /// add fields to the node selection; `id` is always selected
+    pub fn select<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.fields.extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    /// spread a fragment into the node selection; its definition is added to
+    /// the rendered document
+    pub fn select_fragment(mut self, fragment: &Fragment) -> Self {
+        self.fields.push(fragment.spread());
+        self.fragments.push(fragment.clone());
+        self
+    }
+
+    /// include the total `count` (default: true)
+    pub fn count(mut self, count: bool) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// wrap into a [`Query`] with this node as its only root field
+    pub fn into_query(self) -> Query {
+        Query::new().with(self)
+    }
+
+    /// render as a standalone graphql document
+    pub fn render(&self) -> Result<String> {
+        self.clone().into_query().render()
+    }
+
+    fn render_field(&self, out: &mut String) -> Result<()> {
+        check_name(&self.kind)?;
+        if let Some(alias) = &self.alias {
+            check_name(alias)?;
+            out.push_str(alias);
+            out.push_str(": ");
+        }
+        out.push_str(&self.kind);
+        if !self.args.is_empty() {
+            let args = self
+                .args
+                .iter()
+                .map(|(name, arg)| {
+                    check_name(name)?;
+                    let value = match arg {
+                        Arg::Literal(value) => render_literal(value)?,
+                        Arg::Variable(variable) => format!("${variable}"),
+                    };
+                    Ok(format!("{name}: {value}"))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            out.push('(');
+            out.push_str(&args.join(", "));
+            out.push(')');
+        }
+        out.push_str(" {");
+        if self.count {
+            out.push_str(" count");
+        }
+        out.push_str(" edges { node { id");
+        for field in &self.fields {
+            out.push(' ');
+            out.push_str(field.trim());
+        }
+        out.push_str(" } } }");
+        Ok(())
+    }
+}
+
+/// render a json value as a graphql input literal
+fn render_literal(value: &Value) -> Result<String> {
+    Ok(match value {
+        Value::Array(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(render_literal)
+                .collect::<Result<Vec<_>>>()?
+                .join(", ")
+        ),
+        Value::Object(map) => format!(
+            "{{{}}}",
+            map.iter()
+                .map(|(key, item)| {
+                    check_name(key)?;
+                    Ok(format!("{key}: {}", render_literal(item)?))
+                })
+                .collect::<Result<Vec<_>>>()?
+                .join(", ")
+        ),
+        // json scalars (null, booleans, numbers, escaped strings) are valid graphql literals
+        scalar => scalar.to_string(),
+    })
+}
+
+/// graphql names: `[_A-Za-z][_0-9A-Za-z]*`
+pub(crate) fn check_name(name: &str) -> Result<()> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::Config(format!("invalid graphql name: {name:?}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_node_query() {
+        let query = Query::node("InfraDevice")
+            .alias("edge_devices")
+            .filter("role__value", "edge")
+            .filter("ids", serde_json::json!(["a", "b"]))
+            .select(["name { value }"])
+            .count(false)
+            .render()
+            .unwrap();
+        assert_eq!(
+            query,
+            "query { edge_devices: InfraDevice(role__value: \"edge\", ids: [\"a\", \"b\"]) { edges { node { id name { value } } } } }"
+        );
+    }
+
+    #[test]
+    fn test_render_multiple_roots_with_variables() {
+        let query = Query::new()
+            .named("Inventory")
+            .variable("limit", "Int")
+            .with(
+                Query::node("InfraDevice")
+                    .filter_var("name__value", "name", "String")
+                    .filter_var("limit", "limit", "Int"),
+            )
+            .with(Query::node("LocationSite").filter_var("limit", "limit", "Int"))
+            .render()
+            .unwrap();
+        assert_eq!(
+            query,
+            "query Inventory($limit: Int, $name: String) { InfraDevice(name__value: $name, limit: $limit) { count edges { node { id } } } LocationSite(limit: $limit) { count edges { node { id } } } }"
+        );
+    }
+
+    #[test]
+    fn test_render_escapes_strings() {
+        let query = Query::node("BuiltinTag")
+            .filter("name__value", "say \"hi\"\n")
+            .render()
+            .unwrap();
+        assert!(query.contains(r#"name__value: "say \"hi\"\n""#));
+    }
+
+    #[test]
+    fn test_render_rejects_invalid_input() {
+        assert!(Query::new().render().is_err());
+        assert!(Query::node("Infra Device").render().is_err());
+        assert!(Query::node("InfraDevice").alias("1x").render().is_err());
+        assert!(Query::node("InfraDevice")
+            .filter("bad name", 1)
+            .render()
+            .is_err());
+        assert!(Query::node("InfraDevice")
+            .select(["name { value"])
+            .render()
+            .is_err());
+        let conflict = Query::new()
+            .variable("limit", "Int")
+            .with(Query::node("InfraDevice").filter_var("limit", "limit", "Int!"));
+        assert!(conflict.render().is_err());
+    }
+
+    #[test]
+    fn test_render_fragments() {
+        let name = Fragment::new("Named", "InfraDevice", "name { value }");
+        let query = Query::new()
+            .with(Query::node("InfraDevice").alias("a").select_fragment(&name))
+            .with(Query::node("InfraDevice").alias("b").select_fragment(&name))
+            .render()
+            .unwrap();
+        assert_eq!(
+            query,
+            "query { a: InfraDevice { count edges { node { id ...Named } } } b: InfraDevice { count edges { node { id ...Named } } } } fragment Named on InfraDevice { name { value } }"
+        );
+
+        let nested = Fragment::new("Site", "LocationSite", "name { value }");
+        let query = Query::new()
+            .fragment(nested.clone())
+            .with(Query::node("InfraDevice").select(["site { node { ...Site } }"]))
+            .render()
+            .unwrap();
+        assert!(query.ends_with("fragment Site on LocationSite { name { value } }"));
+        assert_eq!(nested.spread(), "...Site");
+    }
+
+    #[test]
+    fn test_render_rejects_conflicting_fragments() {
+        let a = Fragment::new("F", "InfraDevice", "name { value }");
+        let b = Fragment::new("F", "InfraDevice", "role { value }");
+        let query = Query::new()
+            .with(Query::node("InfraDevice").alias("a").select_fragment(&a))
+            .with(Query::node("InfraDevice").alias("b").select_fragment(&b));
+        assert!(query.render().is_err());
+        assert!(Fragment::new("F", "bad type", "id").render().is_err());
+    }
+
+    #[test]
+    fn test_render_literal_object() {
+        let literal = render_literal(&serde_json::json!({ "value": 1, "flags": [true, null] }));
+        assert_eq!(literal.unwrap(), "{flags: [true, null], value: 1}");
+        assert!(render_literal(&serde_json::json!({ "bad key": 1 })).is_err());
+    }
+}
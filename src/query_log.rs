@@ -0,0 +1,66 @@
+//! query logging
+//!
+//! opt-in hooks receiving a [`QueryLogEntry`] per executed graphql operation,
+//! or only for operations slower than a threshold. variables are passed
+//! through the configured [`crate::Redactor`] so logs never carry credentials.
+
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// callback invoked after each graphql operation
+pub(crate) type QueryLogHook = Arc<dyn Fn(&QueryLogEntry) + Send + Sync>;
+
+/// default maximum length of the logged query text, in characters
+pub(crate) const DEFAULT_QUERY_LOG_LIMIT: usize = 1024;
+
+/// one executed graphql operation
+#[derive(Debug, Clone)]
+pub struct QueryLogEntry {
+    /// operation name, if the query is named
+    pub operation: Option<String>,
+    /// branch the query ran against (explicit or the default branch)
+    pub branch: Option<String>,
+    /// time spent, including retries
+    pub duration: Duration,
+    /// query text with whitespace collapsed, truncated to the configured limit
+    pub query: String,
+    /// variables with sensitive fields redacted
+    pub variables: Option<Value>,
+    /// error message if the operation failed
+    pub error: Option<String>,
+}
+
+impl QueryLogEntry {
+    /// true if the operation succeeded
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// collapse whitespace runs and cut `query` to at most `limit` characters,
+/// marking a cut with a trailing `...`
+pub(crate) fn truncate_query(query: &str, limit: usize) -> String {
+    let collapsed = query.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= limit {
+        return collapsed;
+    }
+    let mut truncated: String = collapsed.chars().take(limit).collect();
+    truncated.push_str("...");
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_query() {
+        assert_eq!(
+            truncate_query("query Q {\n  a\n  b\n}", 100),
+            "query Q { a b }"
+        );
+        assert_eq!(truncate_query("query Q { a b }", 7), "query Q...");
+        assert_eq!(truncate_query("{ ä }", 3), "{ ä...");
+    }
+}
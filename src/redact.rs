@@ -0,0 +1,198 @@
+//! redaction of sensitive values
+//!
+//! field names matching a configured pattern have their values replaced
+//! before variables or response bodies are logged, dumped, or attached to
+//! errors.
+
+use serde_json::Value;
+
+/// replacement for redacted values
+pub const REDACTED: &str = "<redacted>";
+
+/// field name patterns redacted by default
+const DEFAULT_PATTERNS: &[&str] = &[
+    "password",
+    "passphrase",
+    "secret",
+    "token",
+    "api_key",
+    "private_key",
+    "credential",
+];
+
+/// redacts values of fields whose names contain a sensitive pattern
+///
+/// matching is a case-insensitive substring match on the field name, so the
+/// `token` pattern also covers `api_token` and `TokenValue`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redactor {
+    patterns: Vec<String>,
+}
+
+impl Default for Redactor {
+    fn default() -> Self {
+        Self::new(DEFAULT_PATTERNS.iter().copied())
+    }
+}
+
+impl Redactor {
+    /// create a redactor with exactly the given patterns
+    pub fn new<I, S>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            patterns: patterns
+                .into_iter()
+                .map(|pattern| pattern.as_ref().to_ascii_lowercase())
+                .filter(|pattern| !pattern.is_empty())
+                .collect(),
+        }
+    }
+
+    /// a redactor that never redacts
+    pub fn disabled() -> Self {
+        Self {
+            patterns: Vec::new(),
+        }
+    }
+
+    /// add a pattern
+    pub fn with_pattern(mut self, pattern: impl AsRef<str>) -> Self {
+        let pattern = pattern.as_ref().to_ascii_lowercase();
+        if !pattern.is_empty() && !self.patterns.contains(&pattern) {
+            self.patterns.push(pattern);
+        }
+        self
+    }
+
+    /// configured patterns (lowercase)
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    /// true if values of `field` are redacted
+    pub fn is_sensitive(&self, field: &str) -> bool {
+        let field = field.to_ascii_lowercase();
+        self.patterns
+            .iter()
+            .any(|pattern| field.contains(pattern.as_str()))
+    }
+
+    /// return a copy of `value` with sensitive fields replaced by [`REDACTED`]
+    ///
+    /// a sensitive field holding an attribute object such as
+    /// `{ "value": "...", "is_protected": true }` keeps its shape; only the
+    /// `value` is replaced.
+    pub fn redact(&self, value: &Value) -> Value {
+        let mut value = value.clone();
+        self.redact_in_place(&mut value);
+        value
+    }
+
+    /// redact a json text
+    ///
+    /// text that is not json, or has nothing to redact, is returned unchanged.
+    pub fn redact_text(&self, text: &str) -> String {
+        if self.patterns.is_empty() {
+            return text.to_string();
+        }
+        match serde_json::from_str::<Value>(text) {
+            Ok(original) => {
+                let redacted = self.redact(&original);
+                if redacted == original {
+                    text.to_string()
+                } else {
+                    redacted.to_string()
+                }
+            }
+            Err(_) => text.to_string(),
+        }
+    }
+
+    fn redact_in_place(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (field, item) in map.iter_mut() {
+                    if self.is_sensitive(field) {
+                        match item {
+                            Value::Object(attr) if attr.contains_key("value") => {
+                                attr.insert("value".to_string(), Value::from(REDACTED));
+                            }
+                            Value::Null => {}
+                            _ => *item = Value::from(REDACTED),
+                        }
+                    } else {
+                        self.redact_in_place(item);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.redact_in_place(item)),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_patterns() {
+        let redactor = Redactor::default();
+        assert!(redactor.is_sensitive("password"));
+        assert!(redactor.is_sensitive("API_TOKEN"));
+        assert!(redactor.is_sensitive("client_secret"));
+        assert!(!redactor.is_sensitive("name"));
+    }
+
+    #[test]
+    fn test_redact_nested() {
+        let redactor = Redactor::default().with_pattern("community");
+        let value = serde_json::json!({
+            "data": {
+                "name": { "value": "edge-01" },
+                "password": { "value": "hunter2", "is_protected": true },
+                "snmp_community": "public",
+                "accounts": [{ "login": "ops", "api_token": "abc" }],
+                "secret": null
+            }
+        });
+        assert_eq!(
+            redactor.redact(&value),
+            serde_json::json!({
+                "data": {
+                    "name": { "value": "edge-01" },
+                    "password": { "value": REDACTED, "is_protected": true },
+                    "snmp_community": REDACTED,
+                    "accounts": [{ "login": "ops", "api_token": REDACTED }],
+                    "secret": null
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_redact_text() {
+        let redactor = Redactor::default();
+        let text = redactor.redact_text(r#"{"token": "abc", "ok": true}"#);
+        assert!(!text.contains("abc"));
+        assert_eq!(redactor.redact_text("{ \"ok\": true }"), "{ \"ok\": true }");
+        assert_eq!(
+            redactor.redact_text("<html>token</html>"),
+            "<html>token</html>"
+        );
+        assert_eq!(
+            Redactor::disabled().redact_text(r#"{"token":"abc"}"#),
+            r#"{"token":"abc"}"#
+        );
+    }
+
+    #[test]
+    fn test_custom_patterns() {
+        let redactor = Redactor::new(["pin", ""]);
+        assert_eq!(redactor.patterns(), ["pin".to_string()]);
+        assert!(!redactor.is_sensitive("password"));
+    }
+}
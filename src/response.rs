@@ -0,0 +1,302 @@
+//! response metadata
+//!
+//! data carried alongside a [`crate::GraphQlResponse`] that is not part of the
+//! graphql payload itself, such as the http status, response headers, and
+//! deprecation notices.
+
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// callback invoked for each deprecation notice
+pub(crate) type DeprecationHook = Arc<dyn Fn(&Deprecation) + Send + Sync>;
+
+/// where a deprecation notice was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeprecationSource {
+    /// an http response header (`Deprecation`, `Sunset`, or a `299` `Warning`)
+    Header,
+    /// the top-level graphql `extensions` object
+    Extension,
+}
+
+/// a deprecation notice reported by the server
+#[derive(Debug, Clone)]
+pub struct Deprecation {
+    /// where the notice was found
+    pub source: DeprecationSource,
+    /// human-readable description
+    pub message: String,
+    /// graphql response path of the deprecated field, if provided
+    pub path: Vec<Value>,
+    /// sunset date advertised by the server, if any
+    pub sunset: Option<String>,
+    /// name of the operation that triggered the notice, if the query is named
+    pub operation: Option<String>,
+}
+
+/// transfer statistics for a schema fetch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchemaStats {
+    /// body size as received over the wire
+    pub wire_bytes: usize,
+    /// schema size after decoding
+    pub decoded_bytes: usize,
+    /// true if the server sent the schema gzip-compressed
+    pub compressed: bool,
+}
+
+/// result of [`crate::Client::fetch_schema_if_changed`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaFetch {
+    /// the hash matched the known one; the schema was not downloaded
+    Unchanged {
+        /// current schema hash
+        hash: String,
+    },
+    /// the schema was downloaded
+    Changed {
+        /// schema hash read before the download
+        hash: String,
+        /// schema sdl
+        schema: String,
+    },
+}
+
+impl SchemaFetch {
+    /// the current schema hash, to pass as `known_hash` next time
+    pub fn hash(&self) -> &str {
+        match self {
+            SchemaFetch::Unchanged { hash } | SchemaFetch::Changed { hash, .. } => hash,
+        }
+    }
+
+    /// the downloaded schema, if it changed
+    pub fn schema(&self) -> Option<&str> {
+        match self {
+            SchemaFetch::Unchanged { .. } => None,
+            SchemaFetch::Changed { schema, .. } => Some(schema),
+        }
+    }
+}
+
+/// metadata about a graphql response
+#[derive(Debug, Clone, Default)]
+pub struct ResponseMeta {
+    /// http status code of the response
+    pub status: u16,
+    /// http response headers
+    pub headers: HeaderMap,
+    /// deprecation notices found in headers and extensions
+    pub deprecations: Vec<Deprecation>,
+}
+
+impl ResponseMeta {
+    /// collect metadata from the http status, response headers, and graphql extensions
+    pub(crate) fn from_parts(
+        status: StatusCode,
+        headers: &HeaderMap,
+        extensions: Option<&Value>,
+        operation: Option<&str>,
+    ) -> Self {
+        let mut deprecations = header_deprecations(headers);
+        if let Some(extensions) = extensions {
+            deprecations.extend(extension_deprecations(extensions));
+        }
+        for deprecation in &mut deprecations {
+            deprecation.operation = operation.map(str::to_string);
+        }
+        Self {
+            status: status.as_u16(),
+            headers: headers.clone(),
+            deprecations,
+        }
+    }
+
+    /// value of a response header, if present and valid utf-8
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+
+    /// server-side request id (`x-request-id`), useful when reporting issues
+    pub fn request_id(&self) -> Option<&str> {
+        self.header("x-request-id")
+    }
+
+    /// true if the server reported any deprecation notice
+    pub fn has_deprecations(&self) -> bool {
+        !self.deprecations.is_empty()
+    }
+}
+
+fn header_deprecations(headers: &HeaderMap) -> Vec<Deprecation> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    let sunset = header("sunset");
+    let mut out = Vec::new();
+
+    if let Some(value) = header("deprecation") {
+        out.push(Deprecation {
+            source: DeprecationSource::Header,
+            message: format!("endpoint deprecated ({value})"),
+            path: Vec::new(),
+            sunset: sunset.clone(),
+            operation: None,
+        });
+    }
+
+    for value in headers.get_all("warning") {
+        let Ok(value) = value.to_str() else { continue };
+        // rfc 7234 warning: `299 <agent> "<text>"`; 299 means miscellaneous persistent warning
+        let mut parts = value.splitn(3, ' ');
+        if parts.next() != Some("299") {
+            continue;
+        }
+        let text = parts.nth(1).unwrap_or_default();
+        out.push(Deprecation {
+            source: DeprecationSource::Header,
+            message: text.trim().trim_matches('"').to_string(),
+            path: Vec::new(),
+            sunset: sunset.clone(),
+            operation: None,
+        });
+    }
+
+    if out.is_empty() {
+        if let Some(sunset) = sunset {
+            out.push(Deprecation {
+                source: DeprecationSource::Header,
+                message: "endpoint scheduled for removal".to_string(),
+                path: Vec::new(),
+                sunset: Some(sunset),
+                operation: None,
+            });
+        }
+    }
+
+    out
+}
+
+/// read `extensions.deprecations` and `extensions.warnings`, each either a list
+/// of strings or a list of `{ message, path }` objects
+fn extension_deprecations(extensions: &Value) -> Vec<Deprecation> {
+    let mut out = Vec::new();
+    for key in ["deprecations", "warnings"] {
+        let Some(entries) = extensions.get(key).and_then(Value::as_array) else {
+            continue;
+        };
+        for entry in entries {
+            let (message, path) = match entry {
+                Value::String(message) => (message.clone(), Vec::new()),
+                Value::Object(obj) => {
+                    let Some(message) = obj.get("message").and_then(Value::as_str) else {
+                        continue;
+                    };
+                    let path = obj
+                        .get("path")
+                        .and_then(Value::as_array)
+                        .cloned()
+                        .unwrap_or_default();
+                    (message.to_string(), path)
+                }
+                _ => continue,
+            };
+            out.push(Deprecation {
+                source: DeprecationSource::Extension,
+                message,
+                path,
+                sunset: None,
+                operation: None,
+            });
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_no_deprecations() {
+        let meta = ResponseMeta::from_parts(StatusCode::OK, &HeaderMap::new(), None, Some("Q"));
+        assert!(!meta.has_deprecations());
+    }
+
+    #[test]
+    fn test_status_and_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-request-id", HeaderValue::from_static("req-42"));
+        headers.insert("cache-control", HeaderValue::from_static("no-store"));
+        let meta = ResponseMeta::from_parts(StatusCode::ACCEPTED, &headers, None, None);
+        assert_eq!(meta.status, 202);
+        assert_eq!(meta.request_id(), Some("req-42"));
+        assert_eq!(meta.header("Cache-Control"), Some("no-store"));
+        assert_eq!(meta.header("etag"), None);
+    }
+
+    #[test]
+    fn test_header_deprecations() {
+        let mut headers = HeaderMap::new();
+        headers.insert("deprecation", HeaderValue::from_static("true"));
+        headers.insert(
+            "sunset",
+            HeaderValue::from_static("Wed, 11 Nov 2026 23:59:59 GMT"),
+        );
+        headers.append(
+            "warning",
+            HeaderValue::from_static("299 infrahub \"field _updated_at is deprecated\""),
+        );
+        headers.append(
+            "warning",
+            HeaderValue::from_static("110 proxy \"response is stale\""),
+        );
+
+        let meta = ResponseMeta::from_parts(StatusCode::OK, &headers, None, Some("GetDevices"));
+        assert_eq!(meta.deprecations.len(), 2);
+        assert_eq!(
+            meta.deprecations[1].message,
+            "field _updated_at is deprecated"
+        );
+        assert!(meta.deprecations.iter().all(|d| d.sunset.as_deref()
+            == Some("Wed, 11 Nov 2026 23:59:59 GMT")
+            && d.operation.as_deref() == Some("GetDevices")
+            && d.source == DeprecationSource::Header));
+    }
+
+    #[test]
+    fn test_sunset_only_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "sunset",
+            HeaderValue::from_static("Wed, 11 Nov 2026 23:59:59 GMT"),
+        );
+        let meta = ResponseMeta::from_parts(StatusCode::OK, &headers, None, None);
+        assert_eq!(meta.deprecations.len(), 1);
+    }
+
+    #[test]
+    fn test_extension_deprecations() {
+        let extensions = serde_json::json!({
+            "deprecations": [
+                {"message": "use node_metadata", "path": ["InfraDevice", "edges", 0]},
+                "plain string notice",
+                {"no_message": true}
+            ],
+            "warnings": ["query uses deprecated argument"]
+        });
+        let meta =
+            ResponseMeta::from_parts(StatusCode::OK, &HeaderMap::new(), Some(&extensions), None);
+        assert_eq!(meta.deprecations.len(), 3);
+        assert_eq!(meta.deprecations[0].message, "use node_metadata");
+        assert_eq!(meta.deprecations[0].path.len(), 3);
+        assert_eq!(meta.deprecations[1].message, "plain string notice");
+        assert_eq!(meta.deprecations[2].source, DeprecationSource::Extension);
+    }
+}
@@ -0,0 +1,94 @@
+//! secret values
+//!
+//! api tokens are kept in a [`Secret`], which wipes its buffer on drop and
+//! never prints its contents.
+
+use crate::redact::REDACTED;
+use reqwest::header::{HeaderValue, InvalidHeaderValue};
+use std::fmt;
+use zeroize::Zeroizing;
+
+/// a string that is zeroed when dropped and redacted from debug output
+#[derive(Clone, Default)]
+pub(crate) struct Secret(Zeroizing<String>);
+
+impl Secret {
+    pub(crate) fn new(value: impl Into<String>) -> Self {
+        Self(Zeroizing::new(value.into()))
+    }
+
+    /// the secret value
+    pub(crate) fn expose(&self) -> &str {
+        &self.0
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// the value as a header marked sensitive, so http debug output hides it
+    pub(crate) fn header_value(&self) -> Result<HeaderValue, InvalidHeaderValue> {
+        sensitive_header(self.expose())
+    }
+}
+
+/// a header value marked sensitive
+pub(crate) fn sensitive_header(value: &str) -> Result<HeaderValue, InvalidHeaderValue> {
+    let mut value = HeaderValue::from_str(value)?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(REDACTED, f)
+    }
+}
+
+impl PartialEq<&str> for Secret {
+    fn eq(&self, other: &&str) -> bool {
+        self.expose() == *other
+    }
+}
+
+/// replace every occurrence of the given secrets in `text`
+///
+/// catches tokens a server or proxy echoes back in an error body, which the
+/// field-based [`crate::Redactor`] cannot see in non-json text.
+pub(crate) fn scrub<'a>(text: &str, secrets: impl IntoIterator<Item = &'a [u8]>) -> String {
+    let mut text = text.to_string();
+    for secret in secrets {
+        let Ok(secret) = std::str::from_utf8(secret) else {
+            continue;
+        };
+        if !secret.is_empty() && text.contains(secret) {
+            text = text.replace(secret, REDACTED);
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret() {
+        let secret = Secret::new("hunter2");
+        assert_eq!(secret.expose(), "hunter2");
+        assert_eq!(secret, "hunter2");
+        assert!(!format!("{secret:?}").contains("hunter2"));
+        assert!(Secret::default().is_empty());
+        assert!(secret.header_value().unwrap().is_sensitive());
+        assert!(Secret::new("bad\ntoken").header_value().is_err());
+    }
+
+    #[test]
+    fn test_scrub() {
+        let body = r#"{"headers": {"x-infrahub-key": "abc123"}, "echo": "abc123"}"#;
+        let scrubbed = scrub(body, [b"abc123".as_slice(), b"".as_slice()]);
+        assert!(!scrubbed.contains("abc123"));
+        assert_eq!(scrubbed.matches(REDACTED).count(), 2);
+        assert_eq!(scrub("plain", [b"other".as_slice()]), "plain");
+    }
+}
@@ -0,0 +1,308 @@
+//! seed data for test environments
+//!
+//! declare a small set of nodes and relationships, create them on a throwaway
+//! branch, and delete the branch afterwards.
+//!
+//! ```no_run
+//! use infrahub::{Client, ClientConfig, Seed};
+//!
+//! # async fn example() -> infrahub::Result<()> {
+//! let client = Client::new(ClientConfig::new("http://localhost:8000", "token"))?;
+//! let seeded = Seed::new()
+//!     .node("BuiltinTag", serde_json::json!({ "name": "red" }))
+//!     .node("InfraDevice", serde_json::json!({ "name": "edge-01", "type": "router" }))
+//!     .relate_many("edge-01", "tags", ["red"])
+//!     .apply(&client, "test-seed")
+//!     .await?;
+//!
+//! let _device_id = seeded.id("edge-01").unwrap();
+//! // ... run tests against seeded.branch() ...
+//! seeded.teardown(&client).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// declarative set of test nodes
+#[derive(Debug, Clone, Default)]
+pub struct Seed {
+    nodes: Vec<SeedNode>,
+    relations: Vec<SeedRelation>,
+}
+
+#[derive(Debug, Clone)]
+struct SeedNode {
+    key: String,
+    kind: String,
+    attrs: Map<String, Value>,
+}
+
+#[derive(Debug, Clone)]
+struct SeedRelation {
+    from: String,
+    name: String,
+    peers: Vec<String>,
+    many: bool,
+}
+
+impl Seed {
+    /// create an empty seed
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// add a node of `kind`
+    ///
+    /// `attrs` is a json object. plain values are wrapped as `{ "value": .. }`;
+    /// objects are passed through unchanged. the node is referenced by its
+    /// `name` attribute in [`Seed::relate`], or by `"<Kind>#<n>"` (the n-th
+    /// node added, counting from 0) if it has no name.
+    pub fn node(mut self, kind: impl Into<String>, attrs: Value) -> Self {
+        let kind = kind.into();
+        let attrs = match attrs {
+            Value::Object(map) => map
+                .into_iter()
+                .map(|(field, value)| match value {
+                    Value::Object(_) => (field, value),
+                    value => (field, serde_json::json!({ "value": value })),
+                })
+                .collect(),
+            _ => Map::new(),
+        };
+        let key = match attrs.get("name").and_then(|name| name["value"].as_str()) {
+            Some(name) => name.to_string(),
+            None => format!("{kind}#{}", self.nodes.len()),
+        };
+        self.nodes.push(SeedNode { key, kind, attrs });
+        self
+    }
+
+    /// set a cardinality-one relationship from one seeded node to another
+    pub fn relate(
+        mut self,
+        from: impl Into<String>,
+        relationship: impl Into<String>,
+        to: impl Into<String>,
+    ) -> Self {
+        self.relations.push(SeedRelation {
+            from: from.into(),
+            name: relationship.into(),
+            peers: vec![to.into()],
+            many: false,
+        });
+        self
+    }
+
+    /// set a cardinality-many relationship from one seeded node to others
+    pub fn relate_many<I, S>(
+        mut self,
+        from: impl Into<String>,
+        relationship: impl Into<String>,
+        to: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.relations.push(SeedRelation {
+            from: from.into(),
+            name: relationship.into(),
+            peers: to.into_iter().map(Into::into).collect(),
+            many: true,
+        });
+        self
+    }
+
+    /// create `branch` and every seeded node on it
+    ///
+    /// nodes are created after the nodes they point at. if any step fails
+    /// the branch is deleted before the error is returned.
+    pub async fn apply(&self, client: &Client, branch: &str) -> Result<SeededBranch> {
+        let order = self.plan()?;
+        client.create_branch(branch).await?;
+
+        let mut ids = HashMap::new();
+        for index in order {
+            let node = &self.nodes[index];
+            let input = self.input_for(node, &ids);
+            match client.create_node(&node.kind, input, Some(branch)).await {
+                Ok(id) => {
+                    ids.insert(node.key.clone(), id);
+                }
+                Err(err) => {
+                    // best effort: the original error is more useful than a cleanup failure
+                    let _ = client.delete_branch(branch).await;
+                    return Err(err);
+                }
+            }
+        }
+
+        Ok(SeededBranch {
+            branch: branch.to_string(),
+            ids,
+        })
+    }
+
+    /// validate references and return node indices in creation order
+    fn plan(&self) -> Result<Vec<usize>> {
+        let mut index_of = HashMap::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            if index_of.insert(node.key.as_str(), index).is_some() {
+                return Err(Error::Config(format!("duplicate seed node: {}", node.key)));
+            }
+        }
+
+        let mut deps: Vec<Vec<usize>> = vec![Vec::new(); self.nodes.len()];
+        for relation in &self.relations {
+            let from = *index_of
+                .get(relation.from.as_str())
+                .ok_or_else(|| Error::Config(format!("unknown seed node: {}", relation.from)))?;
+            for peer in &relation.peers {
+                let to = *index_of
+                    .get(peer.as_str())
+                    .ok_or_else(|| Error::Config(format!("unknown seed node: {peer}")))?;
+                deps[from].push(to);
+            }
+        }
+
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut placed = vec![false; self.nodes.len()];
+        while order.len() < self.nodes.len() {
+            let ready = (0..self.nodes.len())
+                .find(|&i| !placed[i] && deps[i].iter().all(|&dep| placed[dep]))
+                .ok_or_else(|| Error::Config("seed relationships form a cycle".to_string()))?;
+            placed[ready] = true;
+            order.push(ready);
+        }
+        Ok(order)
+    }
+
+    fn input_for(&self, node: &SeedNode, ids: &HashMap<String, String>) -> Value {
+        let mut input = node.attrs.clone();
+        for relation in self.relations.iter().filter(|r| r.from == node.key) {
+            let peers: Vec<Value> = relation
+                .peers
+                .iter()
+                .filter_map(|peer| ids.get(peer))
+                .map(|id| serde_json::json!({ "id": id }))
+                .collect();
+            let value = if relation.many {
+                Value::Array(peers)
+            } else {
+                peers.into_iter().next().unwrap_or(Value::Null)
+            };
+            input.insert(relation.name.clone(), value);
+        }
+        Value::Object(input)
+    }
+}
+
+/// seeded nodes on a throwaway branch
+#[derive(Debug, Clone)]
+pub struct SeededBranch {
+    branch: String,
+    ids: HashMap<String, String>,
+}
+
+impl SeededBranch {
+    /// name of the branch holding the seeded nodes
+    pub fn branch(&self) -> &str {
+        &self.branch
+    }
+
+    /// id of a seeded node by its key
+    pub fn id(&self, key: &str) -> Option<&str> {
+        self.ids.get(key).map(String::as_str)
+    }
+
+    /// delete the branch and everything created on it
+    pub async fn teardown(self, client: &Client) -> Result<()> {
+        client.delete_branch(&self.branch).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_wraps_plain_values() {
+        let seed = Seed::new().node(
+            "InfraDevice",
+            serde_json::json!({ "name": "edge-01", "status": { "value": "active", "is_protected": true } }),
+        );
+        let node = &seed.nodes[0];
+        assert_eq!(node.key, "edge-01");
+        assert_eq!(
+            node.attrs["name"],
+            serde_json::json!({ "value": "edge-01" })
+        );
+        assert_eq!(node.attrs["status"]["is_protected"], true);
+    }
+
+    #[test]
+    fn test_unnamed_node_key() {
+        let seed = Seed::new()
+            .node("BuiltinTag", serde_json::json!({ "name": "red" }))
+            .node("InfraInterface", serde_json::json!({ "mtu": 1500 }));
+        assert_eq!(seed.nodes[1].key, "InfraInterface#1");
+    }
+
+    #[test]
+    fn test_plan_orders_dependencies_first() {
+        let seed = Seed::new()
+            .node("InfraDevice", serde_json::json!({ "name": "edge-01" }))
+            .node("LocationSite", serde_json::json!({ "name": "ams" }))
+            .node("BuiltinTag", serde_json::json!({ "name": "red" }))
+            .relate("edge-01", "site", "ams")
+            .relate_many("edge-01", "tags", ["red"]);
+        assert_eq!(seed.plan().unwrap(), vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn test_plan_rejects_bad_graphs() {
+        let unknown = Seed::new()
+            .node("InfraDevice", serde_json::json!({ "name": "edge-01" }))
+            .relate("edge-01", "site", "nowhere");
+        assert!(unknown.plan().is_err());
+
+        let duplicate = Seed::new()
+            .node("BuiltinTag", serde_json::json!({ "name": "red" }))
+            .node("BuiltinTag", serde_json::json!({ "name": "red" }));
+        assert!(duplicate.plan().is_err());
+
+        let cycle = Seed::new()
+            .node("InfraDevice", serde_json::json!({ "name": "a" }))
+            .node("InfraDevice", serde_json::json!({ "name": "b" }))
+            .relate("a", "peer", "b")
+            .relate("b", "peer", "a");
+        assert!(cycle.plan().is_err());
+    }
+
+    #[test]
+    fn test_input_for_resolves_ids() {
+        let seed = Seed::new()
+            .node("BuiltinTag", serde_json::json!({ "name": "red" }))
+            .node("LocationSite", serde_json::json!({ "name": "ams" }))
+            .node("InfraDevice", serde_json::json!({ "name": "edge-01" }))
+            .relate("edge-01", "site", "ams")
+            .relate_many("edge-01", "tags", ["red"]);
+        let ids = HashMap::from([
+            ("red".to_string(), "t1".to_string()),
+            ("ams".to_string(), "s1".to_string()),
+        ]);
+        let input = seed.input_for(&seed.nodes[2], &ids);
+        assert_eq!(
+            input,
+            serde_json::json!({
+                "name": { "value": "edge-01" },
+                "site": { "id": "s1" },
+                "tags": [{ "id": "t1" }]
+            })
+        );
+    }
+}
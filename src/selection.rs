@@ -0,0 +1,217 @@
+//! runtime selections
+//!
+//! build a node selection set at runtime and check it against the schema
+//! registry emitted by `infrahub-codegen`, so generated clients can return
+//! exactly the fields a caller asks for without regeneration.
+//!
+//! ```
+//! use infrahub::{SchemaRegistry, Selection};
+//!
+//! static REGISTRY: SchemaRegistry = SchemaRegistry::new(&[
+//!     ("InfraDevice", &[("id", "String"), ("name", "TextAttribute")]),
+//!     ("TextAttribute", &[("value", "String")]),
+//! ]);
+//!
+//! let selection = Selection::new()
+//!     .field("id")
+//!     .nested("name", Selection::new().field("value"));
+//! REGISTRY.validate("InfraDevice", &selection).unwrap();
+//! assert_eq!(selection.render().unwrap(), "{ id name { value } }");
+//! ```
+
+use crate::error::{Error, Result};
+use crate::query::check_name;
+
+/// a graphql selection set built at runtime
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selection {
+    fields: Vec<(String, Option<Selection>)>,
+}
+
+impl Selection {
+    /// create an empty selection
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// select a scalar field
+    pub fn field(mut self, name: impl Into<String>) -> Self {
+        self.fields.push((name.into(), None));
+        self
+    }
+
+    /// select several scalar fields
+    pub fn fields<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.fields
+            .extend(names.into_iter().map(|name| (name.into(), None)));
+        self
+    }
+
+    /// select an object field with its own selection
+    pub fn nested(mut self, name: impl Into<String>, selection: Selection) -> Self {
+        self.fields.push((name.into(), Some(selection)));
+        self
+    }
+
+    /// true if no fields are selected
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// render as `{ field nested { field } }`
+    pub fn render(&self) -> Result<String> {
+        let mut out = String::new();
+        self.render_into(&mut out)?;
+        Ok(out)
+    }
+
+    fn render_into(&self, out: &mut String) -> Result<()> {
+        if self.fields.is_empty() {
+            return Err(Error::Config("empty selection".to_string()));
+        }
+        out.push('{');
+        for (name, nested) in &self.fields {
+            check_name(name)?;
+            out.push(' ');
+            out.push_str(name);
+            if let Some(nested) = nested {
+                out.push(' ');
+                nested.render_into(out)?;
+            }
+        }
+        out.push_str(" }");
+        Ok(())
+    }
+}
+
+/// object and interface fields of a schema, as emitted by `infrahub-codegen`
+///
+/// each type maps to its fields and their base type names. field types that
+/// are not listed themselves are treated as scalars or enums.
+#[derive(Debug, Clone, Copy)]
+pub struct SchemaRegistry {
+    types: &'static [(&'static str, &'static [(&'static str, &'static str)])],
+}
+
+impl SchemaRegistry {
+    /// wrap a static type table
+    pub const fn new(
+        types: &'static [(&'static str, &'static [(&'static str, &'static str)])],
+    ) -> Self {
+        Self { types }
+    }
+
+    /// the fields of an object or interface type
+    pub fn fields(&self, type_name: &str) -> Option<&'static [(&'static str, &'static str)]> {
+        self.types
+            .iter()
+            .find(|(name, _)| *name == type_name)
+            .map(|(_, fields)| *fields)
+    }
+
+    /// the base type name of `type_name.field`
+    pub fn field_type(&self, type_name: &str, field: &str) -> Option<&'static str> {
+        self.fields(type_name)?
+            .iter()
+            .find(|(name, _)| *name == field)
+            .map(|(_, ty)| *ty)
+    }
+
+    /// check that `selection` only names fields of `type_name`, nests exactly
+    /// the object fields, and is not empty anywhere
+    pub fn validate(&self, type_name: &str, selection: &Selection) -> Result<()> {
+        if self.fields(type_name).is_none() {
+            return Err(Error::Config(format!("unknown type: {type_name}")));
+        }
+        if selection.is_empty() {
+            return Err(Error::Config(format!("empty selection on {type_name}")));
+        }
+        for (name, nested) in &selection.fields {
+            if name == "__typename" && nested.is_none() {
+                continue;
+            }
+            let field_type = self
+                .field_type(type_name, name)
+                .ok_or_else(|| Error::Config(format!("unknown field {name:?} on {type_name}")))?;
+            let is_object = self.fields(field_type).is_some();
+            match nested {
+                Some(nested) if is_object => self.validate(field_type, nested)?,
+                Some(_) => {
+                    return Err(Error::Config(format!(
+                        "field {name:?} on {type_name} is a {field_type} and takes no selection"
+                    )))
+                }
+                None if is_object => {
+                    return Err(Error::Config(format!(
+                        "field {name:?} on {type_name} is a {field_type} and needs a selection"
+                    )))
+                }
+                None => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static REGISTRY: SchemaRegistry = SchemaRegistry::new(&[
+        (
+            "InfraDevice",
+            &[
+                ("id", "String"),
+                ("name", "TextAttribute"),
+                ("site", "NestedEdgedLocationSite"),
+            ],
+        ),
+        ("NestedEdgedLocationSite", &[("node", "LocationSite")]),
+        (
+            "LocationSite",
+            &[("id", "String"), ("name", "TextAttribute")],
+        ),
+        ("TextAttribute", &[("value", "String")]),
+    ]);
+
+    #[test]
+    fn test_render_selection() {
+        let selection = Selection::new().fields(["id", "__typename"]).nested(
+            "site",
+            Selection::new().nested("node", Selection::new().field("id")),
+        );
+        assert_eq!(
+            selection.render().unwrap(),
+            "{ id __typename site { node { id } } }"
+        );
+        assert!(Selection::new().render().is_err());
+        assert!(Selection::new().field("name { value }").render().is_err());
+    }
+
+    #[test]
+    fn test_validate_selection() {
+        let valid = Selection::new()
+            .field("id")
+            .nested("name", Selection::new().field("value"))
+            .nested(
+                "site",
+                Selection::new().nested("node", Selection::new().fields(["id", "__typename"])),
+            );
+        REGISTRY.validate("InfraDevice", &valid).unwrap();
+
+        let unknown = Selection::new().field("serial");
+        let err = REGISTRY.validate("InfraDevice", &unknown).unwrap_err();
+        assert!(err.to_string().contains("unknown field \"serial\""));
+        let missing_nested = Selection::new().field("name");
+        assert!(REGISTRY.validate("InfraDevice", &missing_nested).is_err());
+        let scalar_nested = Selection::new().nested("id", Selection::new().field("value"));
+        assert!(REGISTRY.validate("InfraDevice", &scalar_nested).is_err());
+        let empty_nested = Selection::new().nested("name", Selection::new());
+        assert!(REGISTRY.validate("InfraDevice", &empty_nested).is_err());
+        assert!(REGISTRY.validate("InfraRack", &valid).is_err());
+    }
+}
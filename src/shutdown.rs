@@ -0,0 +1,187 @@
+//! graceful shutdown
+//!
+//! track in-flight requests so a service can stop issuing new ones, let the
+//! running ones finish within a grace period, and cancel whatever is left.
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{watch, Notify};
+use tokio::time::{timeout_at, Instant};
+
+/// outcome of [`Client::shutdown`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShutdownReport {
+    /// requests still in flight at the deadline, which were cancelled
+    pub cancelled: usize,
+}
+
+impl ShutdownReport {
+    /// true if every in-flight request finished within the grace period
+    pub fn is_clean(&self) -> bool {
+        self.cancelled == 0
+    }
+}
+
+/// shared request lifecycle state of a client and its clones
+#[derive(Debug)]
+pub(crate) struct Lifecycle {
+    closed: AtomicBool,
+    in_flight: AtomicUsize,
+    idle: Notify,
+    cancel: watch::Sender<bool>,
+}
+
+impl Default for Lifecycle {
+    fn default() -> Self {
+        Self {
+            closed: AtomicBool::new(false),
+            in_flight: AtomicUsize::new(0),
+            idle: Notify::new(),
+            cancel: watch::Sender::new(false),
+        }
+    }
+}
+
+/// marks one request as in flight until dropped
+struct InFlight<'a>(&'a Lifecycle);
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        if self.0.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.0.idle.notify_waiters();
+        }
+    }
+}
+
+impl Lifecycle {
+    /// run `request` as an in-flight request
+    ///
+    /// fails with [`Error::Shutdown`] if the client is shut down, or if the
+    /// request is cancelled because the grace period ran out.
+    pub(crate) async fn track<T, Fut>(&self, request: Fut) -> Result<T>
+    where
+        Fut: Future<Output = Result<T>>,
+    {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        let _guard = InFlight(self);
+        if self.closed.load(Ordering::SeqCst) {
+            return Err(Error::Shutdown);
+        }
+
+        let mut cancel = self.cancel.subscribe();
+        tokio::select! {
+            result = request => result,
+            _ = cancel.wait_for(|cancelled| *cancelled) => Err(Error::Shutdown),
+        }
+    }
+
+    async fn shutdown(&self, grace: Duration) -> ShutdownReport {
+        self.closed.store(true, Ordering::SeqCst);
+        let deadline = Instant::now() + grace;
+        loop {
+            let idle = self.idle.notified();
+            let in_flight = self.in_flight.load(Ordering::SeqCst);
+            if in_flight == 0 {
+                return ShutdownReport { cancelled: 0 };
+            }
+            if timeout_at(deadline, idle).await.is_err() {
+                let cancelled = self.in_flight.load(Ordering::SeqCst);
+                self.cancel.send_replace(true);
+                return ShutdownReport { cancelled };
+            }
+        }
+    }
+}
+
+impl Client {
+    /// stop accepting requests, wait up to `grace` for in-flight ones, and
+    /// cancel the rest
+    ///
+    /// applies to this client and all of its clones. requests started after
+    /// the call, and requests cancelled at the deadline, fail with
+    /// [`Error::Shutdown`]. shutdown is permanent.
+    pub async fn shutdown(&self, grace: Duration) -> ShutdownReport {
+        self.lifecycle.shutdown(grace).await
+    }
+
+    /// true once [`Client::shutdown`] has been called
+    pub fn is_shut_down(&self) -> bool {
+        self.lifecycle.closed.load(Ordering::SeqCst)
+    }
+
+    /// number of requests currently in flight
+    pub fn in_flight(&self) -> usize {
+        self.lifecycle.in_flight.load(Ordering::SeqCst)
+    }
+}
+
+/// shared lifecycle handle for a new client
+pub(crate) fn new_lifecycle() -> Arc<Lifecycle> {
+    Arc::new(Lifecycle::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_shutdown_rejects_new_requests() {
+        let lifecycle = Lifecycle::default();
+        let report = lifecycle.shutdown(Duration::from_millis(10)).await;
+        assert!(report.is_clean());
+        let err = lifecycle.track(async { Ok(()) }).await.unwrap_err();
+        assert!(matches!(err, Error::Shutdown));
+        assert_eq!(lifecycle.in_flight.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_waits_for_in_flight() {
+        let lifecycle = Arc::new(Lifecycle::default());
+        let running = {
+            let lifecycle = lifecycle.clone();
+            tokio::spawn(async move {
+                lifecycle
+                    .track(async {
+                        tokio::time::sleep(Duration::from_millis(20)).await;
+                        Ok(7)
+                    })
+                    .await
+            })
+        };
+        while lifecycle.in_flight.load(Ordering::SeqCst) == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        let report = lifecycle.shutdown(Duration::from_secs(5)).await;
+        assert!(report.is_clean());
+        assert_eq!(running.await.unwrap().unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_cancels_after_grace() {
+        let lifecycle = Arc::new(Lifecycle::default());
+        let running = {
+            let lifecycle = lifecycle.clone();
+            tokio::spawn(async move {
+                lifecycle
+                    .track(async {
+                        tokio::time::sleep(Duration::from_secs(60)).await;
+                        Ok(())
+                    })
+                    .await
+            })
+        };
+        while lifecycle.in_flight.load(Ordering::SeqCst) == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        let report = lifecycle.shutdown(Duration::from_millis(10)).await;
+        assert_eq!(report.cancelled, 1);
+        let err = running.await.unwrap().unwrap_err();
+        assert!(matches!(err, Error::Shutdown));
+    }
+}
@@ -0,0 +1,252 @@
+//! subgraph snapshots
+//!
+//! export selected kinds to a directory of json files with a manifest, and
+//! replay them onto a branch as upserts. intended as lightweight backup and
+//! seed tooling for labs and tests, not as a full database dump.
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::nodes::node_to_input;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+/// manifest file name inside a snapshot directory
+const MANIFEST_FILE: &str = "manifest.json";
+/// current manifest format version
+const MANIFEST_VERSION: u32 = 1;
+
+/// which kinds (and which fields of each) a snapshot contains
+#[derive(Debug, Clone, Default)]
+pub struct SnapshotSpec {
+    kinds: Vec<SnapshotKind>,
+}
+
+#[derive(Debug, Clone)]
+struct SnapshotKind {
+    kind: String,
+    selection: String,
+}
+
+impl SnapshotSpec {
+    /// create an empty spec
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// include a kind with the given node selection
+    ///
+    /// `id` is always selected. relationships should select peer ids, e.g.
+    /// `"name { value } site { node { id } } tags { edges { node { id } } }"`.
+    /// kinds are restored in the order they are added, so add referenced
+    /// kinds before the kinds that point at them.
+    pub fn kind(mut self, kind: impl Into<String>, selection: impl Into<String>) -> Self {
+        self.kinds.push(SnapshotKind {
+            kind: kind.into(),
+            selection: selection.into(),
+        });
+        self
+    }
+}
+
+/// snapshot manifest (`manifest.json`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    /// manifest format version
+    pub version: u32,
+    /// branch the snapshot was taken from, if not the default
+    pub branch: Option<String>,
+    /// exported kinds, in restore order
+    pub kinds: Vec<SnapshotEntry>,
+}
+
+/// one exported kind in a [`SnapshotManifest`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotEntry {
+    /// node kind
+    pub kind: String,
+    /// file name relative to the snapshot directory
+    pub file: String,
+    /// number of exported nodes
+    pub count: usize,
+}
+
+/// summary of a snapshot restore
+#[derive(Debug, Clone, Default)]
+pub struct RestoreReport {
+    /// number of nodes upserted per kind, in restore order
+    pub restored: Vec<(String, usize)>,
+}
+
+impl RestoreReport {
+    /// total number of nodes upserted
+    pub fn total(&self) -> usize {
+        self.restored.iter().map(|(_, count)| count).sum()
+    }
+}
+
+impl Client {
+    /// export the kinds in `spec` from `branch` into `dir`
+    ///
+    /// writes one `<Kind>.json` array per kind plus `manifest.json`. the
+    /// directory is created if missing; existing files are overwritten.
+    pub async fn export_snapshot(
+        &self,
+        spec: &SnapshotSpec,
+        branch: Option<&str>,
+        dir: impl AsRef<Path>,
+    ) -> Result<SnapshotManifest> {
+        let mut exported = Vec::new();
+        for entry in &spec.kinds {
+            let nodes = self
+                .query_nodes(&entry.kind, &entry.selection, branch)
+                .await?;
+            exported.push((entry.kind.clone(), nodes));
+        }
+        write_snapshot(dir.as_ref(), branch, exported)
+    }
+
+    /// replay a snapshot from `dir` onto `branch` as upserts
+    ///
+    /// node ids are preserved, so relationships between restored nodes resolve
+    /// as long as referenced kinds appear earlier in the manifest.
+    pub async fn restore_snapshot(
+        &self,
+        dir: impl AsRef<Path>,
+        branch: Option<&str>,
+    ) -> Result<RestoreReport> {
+        let (manifest, nodes_by_kind) = read_snapshot(dir.as_ref())?;
+        let mut report = RestoreReport::default();
+        for (entry, nodes) in manifest.kinds.iter().zip(nodes_by_kind) {
+            for node in &nodes {
+                self.upsert_node(&entry.kind, node_to_input(node), branch)
+                    .await?;
+            }
+            report.restored.push((entry.kind.clone(), nodes.len()));
+        }
+        Ok(report)
+    }
+}
+
+fn write_snapshot(
+    dir: &Path,
+    branch: Option<&str>,
+    exported: Vec<(String, Vec<Value>)>,
+) -> Result<SnapshotManifest> {
+    fs::create_dir_all(dir)?;
+    let mut kinds = Vec::new();
+    for (kind, nodes) in exported {
+        let file = format!("{kind}.json");
+        fs::write(dir.join(&file), serde_json::to_vec_pretty(&nodes)?)?;
+        kinds.push(SnapshotEntry {
+            kind,
+            file,
+            count: nodes.len(),
+        });
+    }
+    let manifest = SnapshotManifest {
+        version: MANIFEST_VERSION,
+        branch: branch.map(str::to_string),
+        kinds,
+    };
+    fs::write(
+        dir.join(MANIFEST_FILE),
+        serde_json::to_vec_pretty(&manifest)?,
+    )?;
+    Ok(manifest)
+}
+
+fn read_snapshot(dir: &Path) -> Result<(SnapshotManifest, Vec<Vec<Value>>)> {
+    let manifest: SnapshotManifest = serde_json::from_slice(&fs::read(dir.join(MANIFEST_FILE))?)?;
+    if manifest.version != MANIFEST_VERSION {
+        return Err(Error::Config(format!(
+            "unsupported snapshot manifest version: {}",
+            manifest.version
+        )));
+    }
+    let mut nodes_by_kind = Vec::new();
+    for entry in &manifest.kinds {
+        if Path::new(&entry.file).components().count() != 1 {
+            return Err(Error::Config(format!(
+                "snapshot file must be a plain file name: {}",
+                entry.file
+            )));
+        }
+        let nodes: Vec<Value> = serde_json::from_slice(&fs::read(dir.join(&entry.file))?)?;
+        nodes_by_kind.push(nodes);
+    }
+    Ok((manifest, nodes_by_kind))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("infrahub-snapshot-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let dir = temp_dir("roundtrip");
+        let tags = vec![serde_json::json!({"id": "t1", "name": {"value": "red"}})];
+        let devices = vec![
+            serde_json::json!({"id": "d1", "tags": {"edges": [{"node": {"id": "t1"}}]}}),
+            serde_json::json!({"id": "d2", "tags": {"edges": []}}),
+        ];
+        let manifest = write_snapshot(
+            &dir,
+            Some("lab"),
+            vec![
+                ("BuiltinTag".to_string(), tags.clone()),
+                ("InfraDevice".to_string(), devices.clone()),
+            ],
+        )
+        .unwrap();
+        assert_eq!(manifest.kinds[1].count, 2);
+
+        let (read, nodes) = read_snapshot(&dir).unwrap();
+        assert_eq!(read.branch.as_deref(), Some("lab"));
+        assert_eq!(read.kinds[0].kind, "BuiltinTag");
+        assert_eq!(read.kinds[0].file, "BuiltinTag.json");
+        assert_eq!(nodes, vec![tags, devices]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn test_read_snapshot_rejects_path_traversal() {
+        let dir = temp_dir("traversal");
+        fs::create_dir_all(&dir).unwrap();
+        let manifest = serde_json::json!({
+            "version": 1,
+            "branch": null,
+            "kinds": [{"kind": "BuiltinTag", "file": "../escape.json", "count": 0}]
+        });
+        fs::write(dir.join(MANIFEST_FILE), manifest.to_string()).unwrap();
+        let err = read_snapshot(&dir).unwrap_err();
+        assert!(matches!(err, Error::Config(_)));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn test_read_snapshot_missing_manifest() {
+        let dir = temp_dir("missing");
+        let err = read_snapshot(&dir).unwrap_err();
+        assert!(matches!(err, Error::Io(_)));
+    }
+
+    #[test]
+    fn test_restore_report_total() {
+        let report = RestoreReport {
+            restored: vec![("A".to_string(), 2), ("B".to_string(), 3)],
+        };
+        assert_eq!(report.total(), 5);
+    }
+}
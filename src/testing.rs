@@ -0,0 +1,506 @@
+//! test doubles
+//!
+//! [`MockTransport`] answers graphql requests with canned responses
+//! programmed per operation and records every call, so downstream crates can
+//! unit test code built on [`Client`] without a server. [`MockClient`] bundles
+//! one with a client configured to use it. [`RecordingTransport`] captures
+//! real traffic to a [`Cassette`] file and [`ReplayTransport`] serves it back.
+//! [`fixtures`] builds realistic response payloads, and [`harness`] sets up
+//! a live server for integration tests.
+//!
+//! ```
+//! use infrahub::testing::MockClient;
+//! use serde_json::json;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> infrahub::Result<()> {
+//! let mock = MockClient::new();
+//! mock.expect("DeviceList")
+//!     .times(1)
+//!     .returning(json!({ "DcimDevice": { "count": 0, "edges": [] } }));
+//!
+//! let response = mock
+//!     .client()
+//!     .execute_raw("query DeviceList { DcimDevice { count edges { node { id } } } }", None, None)
+//!     .await?;
+//! assert_eq!(response.data.unwrap()["DcimDevice"]["count"], 0);
+//! mock.verify();
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::Client;
+use crate::compat::VersionCheck;
+use crate::config::ClientConfig;
+use crate::error::{Error, Result};
+use crate::graphql::{operation_name, GraphQlResponse};
+use crate::transport::{Transport, TransportFuture, TransportRequest, TransportResponse};
+use reqwest::header::{HeaderValue, CONTENT_TYPE};
+use reqwest::StatusCode;
+use serde_json::Value;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+pub mod fixtures;
+pub mod harness;
+
+pub use crate::vcr::{
+    Cassette, Interaction, RecordedBody, RecordedRequest, RecordedResponse, RecordingTransport,
+    ReplayTransport,
+};
+
+/// a graphql request received by a [`MockTransport`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockCall {
+    /// operation name, or the first root field of an anonymous operation
+    pub operation: Option<String>,
+    /// query text
+    pub query: String,
+    /// variables sent with the query (`{}` when none were given)
+    pub variables: Value,
+    /// branch from the request path, if any
+    pub branch: Option<String>,
+}
+
+/// a transport serving canned graphql responses
+///
+/// requests are matched against expectations in the order they were
+/// registered; the first matching expectation that has calls left answers.
+/// a request no expectation matches fails with [`Error::Config`] and is
+/// reported by [`MockTransport::verify`]. clones share expectations and
+/// recorded calls.
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    expectations: Vec<Registered>,
+    calls: Vec<MockCall>,
+    unexpected: Vec<MockCall>,
+}
+
+struct Registered {
+    expectation: Expectation<'static>,
+    response: TransportResponse,
+    calls: usize,
+}
+
+/// an expected operation, registered on its transport by one of the
+/// `returning*` methods
+#[must_use = "an expectation is only registered by a `returning*` method"]
+pub struct Expectation<'a> {
+    transport: Option<&'a MockTransport>,
+    operation: String,
+    variables: Option<Value>,
+    branch: Option<Option<String>>,
+    times: Option<usize>,
+}
+
+impl MockTransport {
+    /// a transport without expectations
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// expect an operation, matched by operation name or, for anonymous
+    /// operations, by the first root field
+    pub fn expect(&self, operation: impl Into<String>) -> Expectation<'_> {
+        Expectation {
+            transport: Some(self),
+            operation: operation.into(),
+            variables: None,
+            branch: None,
+            times: None,
+        }
+    }
+
+    /// every graphql request received so far, expected or not
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.state().calls.clone()
+    }
+
+    /// the recorded calls of `operation`
+    pub fn calls_to(&self, operation: &str) -> Vec<MockCall> {
+        self.state()
+            .calls
+            .iter()
+            .filter(|call| call.operation.as_deref() == Some(operation))
+            .cloned()
+            .collect()
+    }
+
+    /// panic unless `operation` was called exactly `times` times
+    #[track_caller]
+    pub fn assert_called(&self, operation: &str, times: usize) {
+        let calls = self.calls_to(operation).len();
+        assert_eq!(
+            calls, times,
+            "expected {operation} to be called {times} times, got {calls}"
+        );
+    }
+
+    /// panic if a request matched no expectation, or an expectation was not
+    /// met: called exactly its `times`, or at least once without a limit
+    #[track_caller]
+    pub fn verify(&self) {
+        let state = self.state();
+        let mut problems = Vec::new();
+        for call in &state.unexpected {
+            problems.push(format!(
+                "unexpected call to {}",
+                call.operation.as_deref().unwrap_or("<unnamed>")
+            ));
+        }
+        for registered in &state.expectations {
+            let expectation = &registered.expectation;
+            let met = match expectation.times {
+                Some(times) => registered.calls == times,
+                None => registered.calls > 0,
+            };
+            if !met {
+                problems.push(format!(
+                    "{} was called {} times, expected {}",
+                    expectation.operation,
+                    registered.calls,
+                    expectation
+                        .times
+                        .map_or("at least once".to_string(), |times| times.to_string()),
+                ));
+            }
+        }
+        assert!(problems.is_empty(), "mock: {}", problems.join("; "));
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// record `request` and pick the response of the first matching
+    /// expectation
+    fn answer(&self, request: &TransportRequest) -> Result<TransportResponse> {
+        let body: Value = serde_json::from_slice(request.body.as_deref().unwrap_or_default())
+            .map_err(|_| {
+                Error::Config(format!(
+                    "mock transport only serves graphql requests, got {} {}",
+                    request.method, request.url
+                ))
+            })?;
+        let query = body["query"].as_str().unwrap_or_default().to_string();
+        let call = MockCall {
+            operation: operation_name(&query)
+                .or_else(|| root_field(&query))
+                .map(str::to_string),
+            variables: body["variables"].clone(),
+            branch: request
+                .url
+                .path()
+                .rsplit_once("/graphql/")
+                .map(|(_, branch)| branch.to_string()),
+            query,
+        };
+
+        let mut state = self.state();
+        state.calls.push(call.clone());
+        let registered = state.expectations.iter_mut().find(|registered| {
+            registered.expectation.matches(&call)
+                && registered
+                    .expectation
+                    .times
+                    .is_none_or(|times| registered.calls < times)
+        });
+        match registered {
+            Some(registered) => {
+                registered.calls += 1;
+                Ok(registered.response.clone())
+            }
+            None => {
+                let operation = call.operation.clone();
+                state.unexpected.push(call);
+                Err(Error::Config(format!(
+                    "mock transport has no expectation for {}",
+                    operation.as_deref().unwrap_or("<unnamed>")
+                )))
+            }
+        }
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(&self, request: TransportRequest) -> TransportFuture<'_> {
+        let response = self.answer(&request);
+        Box::pin(async move { response })
+    }
+}
+
+impl Expectation<'_> {
+    /// only match calls with exactly these variables
+    pub fn with_variables(mut self, variables: Value) -> Self {
+        self.variables = Some(variables);
+        self
+    }
+
+    /// only match calls against `branch`; `None` matches the default branch
+    /// path (`/graphql`)
+    pub fn on_branch(mut self, branch: Option<&str>) -> Self {
+        self.branch = Some(branch.map(str::to_string));
+        self
+    }
+
+    /// answer at most `times` calls, and expect exactly that many in
+    /// [`MockTransport::verify`]
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = Some(times);
+        self
+    }
+
+    /// answer with `{"data": data}`
+    pub fn returning(self, data: Value) -> MockHandle {
+        self.returning_response(GraphQlResponse {
+            data: Some(data),
+            errors: Vec::new(),
+            extensions: None,
+        })
+    }
+
+    /// answer with a full graphql response, e.g. one carrying errors
+    pub fn returning_response(self, response: GraphQlResponse<Value>) -> MockHandle {
+        let body = serde_json::to_vec(&response).expect("graphql responses serialize");
+        self.returning_status(StatusCode::OK, body)
+    }
+
+    /// answer with a raw http status and body
+    pub fn returning_status(self, status: StatusCode, body: impl Into<Vec<u8>>) -> MockHandle {
+        let response = TransportResponse::new(status, body)
+            .header(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        let transport = self.transport.expect("expectation is registered once");
+        let expectation = Expectation {
+            transport: None,
+            operation: self.operation,
+            variables: self.variables,
+            branch: self.branch,
+            times: self.times,
+        };
+        let mut state = transport.state();
+        state.expectations.push(Registered {
+            expectation,
+            response,
+            calls: 0,
+        });
+        MockHandle {
+            state: transport.state.clone(),
+            index: state.expectations.len() - 1,
+        }
+    }
+
+    fn matches(&self, call: &MockCall) -> bool {
+        call.operation.as_deref() == Some(self.operation.as_str())
+            && self
+                .variables
+                .as_ref()
+                .is_none_or(|variables| *variables == call.variables)
+            && self
+                .branch
+                .as_ref()
+                .is_none_or(|branch| *branch == call.branch)
+    }
+}
+
+/// a registered expectation, for checking its call count
+#[derive(Clone)]
+pub struct MockHandle {
+    state: Arc<Mutex<State>>,
+    index: usize,
+}
+
+impl MockHandle {
+    /// how many calls this expectation has answered
+    pub fn calls(&self) -> usize {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .expectations[self.index]
+            .calls
+    }
+}
+
+/// a [`Client`] wired to a [`MockTransport`]
+///
+/// the client has retries and the server version check turned off, so each
+/// call maps to exactly one expectation.
+#[derive(Clone)]
+pub struct MockClient {
+    transport: MockTransport,
+    client: Client,
+}
+
+impl MockClient {
+    /// a mock client for `http://infrahub.mock`
+    pub fn new() -> Self {
+        Self::with_config(ClientConfig::new("http://infrahub.mock", "mock-token"))
+    }
+
+    /// a mock client built from `config`, with its transport replaced
+    ///
+    /// panics if the config is invalid.
+    pub fn with_config(config: ClientConfig) -> Self {
+        let transport = MockTransport::new();
+        let config = config
+            .with_transport(transport.clone())
+            .with_max_retries(0)
+            .with_version_check(VersionCheck::Off);
+        let client = Client::new(config).expect("valid mock client config");
+        Self { transport, client }
+    }
+
+    /// the client sending to the mock
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// the underlying transport
+    pub fn transport(&self) -> &MockTransport {
+        &self.transport
+    }
+
+    /// see [`MockTransport::expect`]
+    pub fn expect(&self, operation: impl Into<String>) -> Expectation<'_> {
+        self.transport.expect(operation)
+    }
+
+    /// see [`MockTransport::calls`]
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.transport.calls()
+    }
+
+    /// see [`MockTransport::assert_called`]
+    #[track_caller]
+    pub fn assert_called(&self, operation: &str, times: usize) {
+        self.transport.assert_called(operation, times);
+    }
+
+    /// see [`MockTransport::verify`]
+    #[track_caller]
+    pub fn verify(&self) {
+        self.transport.verify();
+    }
+}
+
+impl Default for MockClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// the first root field of an anonymous operation, skipping an alias
+fn root_field(query: &str) -> Option<&str> {
+    let selection = query[query.find('{')? + 1..].trim_start();
+    let end = selection
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(selection.len());
+    let (name, rest) = selection.split_at(end);
+    match rest.trim_start().strip_prefix(':') {
+        Some(aliased) => {
+            let aliased = aliased.trim_start();
+            let end = aliased
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(aliased.len());
+            Some(&aliased[..end]).filter(|name| !name.is_empty())
+        }
+        None => Some(name).filter(|name| !name.is_empty()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_root_field() {
+        assert_eq!(
+            root_field("query { InfrahubInfo { version } }"),
+            Some("InfrahubInfo")
+        );
+        assert_eq!(
+            root_field("{ info: InfrahubInfo { version } }"),
+            Some("InfrahubInfo")
+        );
+        assert_eq!(root_field("{ }"), None);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_mock_client_matches_expectations() {
+        let mock = MockClient::new();
+        let created = mock
+            .expect("DeviceCreate")
+            .with_variables(json!({"name": "spine1"}))
+            .on_branch(Some("feature"))
+            .times(1)
+            .returning(json!({"DcimDeviceCreate": {"ok": true}}));
+        mock.expect("InfrahubInfo")
+            .returning(json!({"InfrahubInfo": {"version": "1.9.8"}}));
+
+        let query = "mutation DeviceCreate($name: String!) { DcimDeviceCreate(data: {name: {value: $name}}) { ok } }";
+        let response = mock
+            .client()
+            .execute_raw(query, Some(json!({"name": "spine1"})), Some("feature"))
+            .await
+            .unwrap();
+        assert_eq!(response.data.unwrap()["DcimDeviceCreate"]["ok"], true);
+        assert_eq!(created.calls(), 1);
+
+        let info = mock
+            .client()
+            .execute_raw("query { InfrahubInfo { version } }", None, None)
+            .await
+            .unwrap();
+        assert_eq!(info.data.unwrap()["InfrahubInfo"]["version"], "1.9.8");
+
+        // the expectation is used up
+        let err = mock
+            .client()
+            .execute_raw(query, Some(json!({"name": "spine1"})), Some("feature"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Config(_)));
+
+        mock.assert_called("DeviceCreate", 2);
+        let calls = mock.calls();
+        assert_eq!(calls[0].branch.as_deref(), Some("feature"));
+        assert_eq!(calls[1].branch, None);
+        let transport = mock.transport().clone();
+        let verified = std::panic::catch_unwind(move || transport.verify());
+        assert!(verified.is_err());
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_mock_returning_errors_and_status() {
+        let mock = MockClient::new();
+        mock.expect("Broken").returning_response(GraphQlResponse {
+            data: None,
+            errors: serde_json::from_value(json!([{"message": "boom"}])).unwrap(),
+            extensions: None,
+        });
+        mock.expect("Down")
+            .returning_status(StatusCode::SERVICE_UNAVAILABLE, "unavailable");
+
+        let err = mock
+            .client()
+            .execute_raw("query Broken { x }", None, None)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("boom"));
+        let err = mock
+            .client()
+            .execute_raw("query Down { x }", None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), crate::ErrorKind::Server);
+        mock.verify();
+    }
+}
@@ -0,0 +1,240 @@
+//! canned payloads
+//!
+//! builders for the json shapes infrahub returns: nodes with attribute
+//! objects, paginated `count`/`edges` connections, mutation payloads with
+//! `ok` and `object`, and error envelopes. pair them with
+//! [`super::MockTransport`] instead of hand-writing response strings.
+//!
+//! ```
+//! use infrahub::testing::{fixtures, MockClient};
+//! use serde_json::json;
+//!
+//! let mock = MockClient::new();
+//! mock.expect("Nodes").returning(fixtures::root(
+//!     "DcimDevice",
+//!     fixtures::page(
+//!         [fixtures::node("DcimDevice", "d1", json!({ "name": "spine1" }))],
+//!         1,
+//!     ),
+//! ));
+//! mock.expect("DcimDeviceCreate").returning(fixtures::mutation(
+//!     "DcimDeviceCreate",
+//!     fixtures::node("DcimDevice", "d2", json!({ "name": "leaf1" })),
+//! ));
+//! ```
+
+use crate::graphql::{GraphQlError, GraphQlResponse};
+use serde_json::{json, Map, Value};
+
+/// a node of `kind` with `id`
+///
+/// scalar and list values in the `attributes` object are wrapped as
+/// attribute objects (`{"value": ...}`); object values, such as
+/// [`related`] or [`related_many`] payloads, are kept as given.
+pub fn node(kind: &str, id: &str, attributes: Value) -> Value {
+    let mut node = Map::new();
+    node.insert("id".to_string(), json!(id));
+    node.insert("__typename".to_string(), json!(kind));
+    if let Value::Object(attributes) = attributes {
+        for (name, value) in attributes {
+            let value = match value {
+                Value::Object(_) => value,
+                value => json!({ "value": value }),
+            };
+            node.insert(name, value);
+        }
+    }
+    Value::Object(node)
+}
+
+/// a cardinality-one relationship to the node `id` of `kind`
+pub fn related(kind: &str, id: &str) -> Value {
+    json!({ "node": { "id": id, "__typename": kind } })
+}
+
+/// a cardinality-many relationship to `(kind, id)` peers
+pub fn related_many<'a>(peers: impl IntoIterator<Item = (&'a str, &'a str)>) -> Value {
+    edges(
+        peers
+            .into_iter()
+            .map(|(kind, id)| json!({ "id": id, "__typename": kind })),
+    )
+}
+
+/// a connection holding all of `nodes`: `{"count": n, "edges": [...]}`
+pub fn edges(nodes: impl IntoIterator<Item = Value>) -> Value {
+    let edges: Vec<Value> = nodes
+        .into_iter()
+        .map(|node| json!({ "node": node }))
+        .collect();
+    json!({ "count": edges.len(), "edges": edges })
+}
+
+/// one page of an offset-paginated connection with `count` nodes in total
+pub fn page(nodes: impl IntoIterator<Item = Value>, count: usize) -> Value {
+    let mut page = edges(nodes);
+    page["count"] = json!(count);
+    page
+}
+
+/// one page of a cursor-paginated connection, with `pageInfo`
+pub fn cursor_page(
+    nodes: impl IntoIterator<Item = Value>,
+    end_cursor: Option<&str>,
+    has_next_page: bool,
+) -> Value {
+    let mut page = edges(nodes);
+    page["pageInfo"] = json!({ "endCursor": end_cursor, "hasNextPage": has_next_page });
+    page
+}
+
+/// response data with `value` under the root field `field`
+pub fn root(field: &str, value: Value) -> Value {
+    json!({ field: value })
+}
+
+/// data of a successful mutation `name` returning `object`
+pub fn mutation(name: &str, object: Value) -> Value {
+    json!({ name: { "ok": true, "object": object } })
+}
+
+/// data of a mutation `name` reporting `ok: false`
+pub fn mutation_failed(name: &str) -> Value {
+    json!({ name: { "ok": false, "object": null } })
+}
+
+/// a graphql error with `message`
+pub fn error(message: &str) -> GraphQlError {
+    GraphQlError {
+        message: message.to_string(),
+        locations: Vec::new(),
+        path: Vec::new(),
+        extensions: None,
+    }
+}
+
+/// a graphql error with `message` and an extension `code`
+pub fn error_with_code(message: &str, code: &str) -> GraphQlError {
+    GraphQlError {
+        extensions: Some(json!({ "code": code })),
+        ..error(message)
+    }
+}
+
+/// the error infrahub returns for a missing node
+pub fn not_found(kind: &str, id: &str) -> GraphQlError {
+    GraphQlError {
+        extensions: Some(json!({ "code": 404, "kind": kind, "identifier": id })),
+        ..error(&format!(
+            "Unable to find the node {id} / {kind} in the database."
+        ))
+    }
+}
+
+/// the error infrahub returns for an invalid input field
+pub fn validation_error(field: &str, message: &str) -> GraphQlError {
+    GraphQlError {
+        extensions: Some(json!({ "code": 422, "field": field })),
+        ..error(message)
+    }
+}
+
+/// a successful response with `data`
+pub fn response(data: Value) -> GraphQlResponse<Value> {
+    GraphQlResponse {
+        data: Some(data),
+        errors: Vec::new(),
+        extensions: None,
+    }
+}
+
+/// an error envelope: no data and the given errors
+pub fn error_response(errors: impl IntoIterator<Item = GraphQlError>) -> GraphQlResponse<Value> {
+    GraphQlResponse {
+        data: None,
+        errors: errors.into_iter().collect(),
+        extensions: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphql::InfrahubError;
+    use crate::testing::MockClient;
+
+    #[test]
+    fn test_node_and_relationships() {
+        let device = node(
+            "DcimDevice",
+            "d1",
+            json!({
+                "name": "spine1",
+                "tags": ["a"],
+                "site": related("LocationSite", "s1"),
+                "interfaces": related_many([("DcimInterface", "i1"), ("DcimInterface", "i2")]),
+            }),
+        );
+        assert_eq!(device["id"], "d1");
+        assert_eq!(device["__typename"], "DcimDevice");
+        assert_eq!(device["name"]["value"], "spine1");
+        assert_eq!(device["tags"]["value"], json!(["a"]));
+        assert_eq!(device["site"]["node"]["id"], "s1");
+        assert_eq!(device["interfaces"]["count"], 2);
+        assert_eq!(device["interfaces"]["edges"][1]["node"]["id"], "i2");
+    }
+
+    #[test]
+    fn test_pages_and_mutations() {
+        let first = cursor_page([json!({ "id": "a" })], Some("c1"), true);
+        assert_eq!(first["pageInfo"]["endCursor"], "c1");
+        assert_eq!(first["edges"][0]["node"]["id"], "a");
+        assert_eq!(page([], 10)["count"], 10);
+        assert_eq!(
+            mutation("TagCreate", json!({"id": "t"}))["TagCreate"]["ok"],
+            true
+        );
+        assert_eq!(mutation_failed("TagCreate")["TagCreate"]["ok"], false);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            not_found("DcimDevice", "d1").infrahub_error(),
+            Some(InfrahubError::NodeNotFound {
+                kind: Some("DcimDevice".to_string()),
+                identifier: Some("d1".to_string()),
+            })
+        );
+        assert_eq!(
+            validation_error("name", "name is required").infrahub_error(),
+            Some(InfrahubError::ValidationError {
+                field: Some("name".to_string()),
+            })
+        );
+        assert_eq!(
+            error_with_code("nope", "403").infrahub_error(),
+            Some(InfrahubError::PermissionDenied)
+        );
+        assert!(error_response([error("boom")]).data.is_none());
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_fixtures_drive_helpers() {
+        let mock = MockClient::new();
+        let nodes = [
+            node("BuiltinTag", "t1", json!({ "name": "red" })),
+            node("BuiltinTag", "t2", json!({ "name": "blue" })),
+        ];
+        mock.expect("Nodes")
+            .returning_response(response(root("BuiltinTag", page(nodes, 2))));
+        let tags = mock
+            .client()
+            .query_nodes("BuiltinTag", "name { value }", None)
+            .await
+            .unwrap();
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[1]["name"]["value"], "blue");
+    }
+}
@@ -0,0 +1,443 @@
+//! integration test harness
+//!
+//! [`Harness`] attaches to a running infrahub, or boots one with
+//! `docker compose`, waits until it answers, loads schema fixtures, and
+//! hands back a configured [`Client`].
+//!
+//! ```no_run
+//! use infrahub::testing::harness::Harness;
+//!
+//! # async fn run() -> infrahub::Result<()> {
+//! // skip when no server is configured
+//! let Some(builder) = Harness::from_env() else {
+//!     return Ok(());
+//! };
+//! let harness = builder
+//!     .with_schema_file("tests/fixtures/schema.json")
+//!     .start()
+//!     .await?;
+//! let info = harness.client().info().await?;
+//! println!("testing against infrahub {}", info.version);
+//! harness.stop().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::Client;
+use crate::config::ClientConfig;
+use crate::error::{Error, Result};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+/// default time to wait for the server to answer
+const DEFAULT_READY_TIMEOUT: Duration = Duration::from_secs(300);
+/// default pause between readiness probes
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// default `docker compose` project name
+const DEFAULT_PROJECT: &str = "infrahub-rs-test";
+
+/// a configured client for a ready infrahub, see the [module docs](self)
+pub struct Harness {
+    client: Client,
+    compose: Option<Compose>,
+}
+
+/// settings for [`HarnessBuilder::start`]
+pub struct HarnessBuilder {
+    url: String,
+    token: String,
+    branch: Option<String>,
+    compose_file: Option<PathBuf>,
+    project: String,
+    infrahub_version: Option<String>,
+    ready_timeout: Duration,
+    poll_interval: Duration,
+    schemas: Vec<Schema>,
+    configure: Option<Box<dyn FnOnce(ClientConfig) -> ClientConfig + Send>>,
+}
+
+/// a `docker compose` project started by the harness
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Compose {
+    file: PathBuf,
+    project: String,
+    version: Option<String>,
+}
+
+enum Schema {
+    Value(Value),
+    File(PathBuf),
+}
+
+impl Harness {
+    /// attach to the infrahub at `url` using `token`
+    pub fn attach(url: impl Into<String>, token: impl Into<String>) -> HarnessBuilder {
+        HarnessBuilder {
+            url: url.into(),
+            token: token.into(),
+            branch: None,
+            compose_file: None,
+            project: DEFAULT_PROJECT.to_string(),
+            infrahub_version: None,
+            ready_timeout: DEFAULT_READY_TIMEOUT,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            schemas: Vec::new(),
+            configure: None,
+        }
+    }
+
+    /// attach to the infrahub named by `INFRAHUB_URL` (default
+    /// `http://localhost:8000`), `INFRAHUB_TOKEN`, and `INFRAHUB_BRANCH`
+    ///
+    /// returns `None` when `INFRAHUB_TOKEN` is unset, so tests can skip.
+    pub fn from_env() -> Option<HarnessBuilder> {
+        let token = std::env::var("INFRAHUB_TOKEN").ok()?;
+        let url =
+            std::env::var("INFRAHUB_URL").unwrap_or_else(|_| "http://localhost:8000".to_string());
+        let mut builder = Self::attach(url, token);
+        builder.branch = std::env::var("INFRAHUB_BRANCH").ok();
+        Some(builder)
+    }
+
+    /// the client for the server
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// stop the containers if the harness booted them
+    ///
+    /// dropping the harness leaves them running for the next test run.
+    pub async fn stop(self) -> Result<()> {
+        match self.compose {
+            Some(compose) => compose.run(&["down", "--volumes"], "").await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl HarnessBuilder {
+    /// boot infrahub with `docker compose -f file up -d` before waiting for
+    /// it, passing the token as `INFRAHUB_INITIAL_ADMIN_TOKEN`
+    ///
+    /// use the upstream compose file of the release under test.
+    pub fn with_compose_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.compose_file = Some(file.into());
+        self
+    }
+
+    /// set the compose project name; default: `infrahub-rs-test`
+    ///
+    /// only used with [`HarnessBuilder::with_compose_file`], in either order.
+    pub fn with_project_name(mut self, project: impl Into<String>) -> Self {
+        self.project = project.into();
+        self
+    }
+
+    /// set the image tag the compose file starts, as `VERSION` and
+    /// `INFRAHUB_IMAGE_TAG`
+    ///
+    /// only used with [`HarnessBuilder::with_compose_file`], in either order.
+    pub fn with_infrahub_version(mut self, version: impl Into<String>) -> Self {
+        self.infrahub_version = Some(version.into());
+        self
+    }
+
+    /// give up waiting for the server after `timeout`; default: five minutes
+    pub fn with_ready_timeout(mut self, timeout: Duration) -> Self {
+        self.ready_timeout = timeout;
+        self
+    }
+
+    /// pause `interval` between readiness probes; default: two seconds
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// load schema fixtures on this branch instead of the default branch
+    pub fn with_branch(mut self, branch: impl Into<String>) -> Self {
+        self.branch = Some(branch.into());
+        self
+    }
+
+    /// load a schema document once the server is ready
+    pub fn with_schema(mut self, schema: Value) -> Self {
+        self.schemas.push(Schema::Value(schema));
+        self
+    }
+
+    /// load a schema file once the server is ready
+    ///
+    /// json files are always accepted; `.yml` and `.yaml` files need the
+    /// `yaml` feature.
+    pub fn with_schema_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.schemas.push(Schema::File(path.into()));
+        self
+    }
+
+    /// adjust the client config before the client is built
+    pub fn with_config<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(ClientConfig) -> ClientConfig + Send + 'static,
+    {
+        self.configure = Some(Box::new(f));
+        self
+    }
+
+    /// boot the server if configured, wait until it is ready, load the
+    /// schema fixtures, and return the harness
+    pub async fn start(self) -> Result<Harness> {
+        let compose = self.compose();
+        let schemas = self
+            .schemas
+            .into_iter()
+            .map(Schema::load)
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut config = ClientConfig::new(self.url, self.token.clone());
+        if let Some(branch) = &self.branch {
+            config = config.with_default_branch(branch.clone());
+        }
+        if let Some(configure) = self.configure {
+            config = configure(config);
+        }
+        let client = Client::new(config)?;
+
+        if let Some(compose) = &compose {
+            compose.run(&["up", "--detach"], &self.token).await?;
+        }
+        wait_until_ready(&client, self.ready_timeout, self.poll_interval).await?;
+        if !schemas.is_empty() {
+            client.load_schema(&schemas, None).await?;
+        }
+        Ok(Harness { client, compose })
+    }
+
+    /// the compose project to boot, if a compose file was set
+    fn compose(&self) -> Option<Compose> {
+        self.compose_file.as_ref().map(|file| Compose {
+            file: file.clone(),
+            project: self.project.clone(),
+            version: self.infrahub_version.clone(),
+        })
+    }
+}
+
+impl std::fmt::Debug for Harness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Harness")
+            .field("compose", &self.compose)
+            .finish_non_exhaustive()
+    }
+}
+
+impl std::fmt::Debug for HarnessBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HarnessBuilder")
+            .field("url", &self.url)
+            .field("branch", &self.branch)
+            .field("compose", &self.compose())
+            .field("ready_timeout", &self.ready_timeout)
+            .field("poll_interval", &self.poll_interval)
+            .field("schemas", &self.schemas.len())
+            .finish()
+    }
+}
+
+impl Compose {
+    /// run `docker compose` with `args` on a blocking thread
+    async fn run(&self, args: &[&str], token: &str) -> Result<()> {
+        let mut command = Command::new("docker");
+        command
+            .arg("compose")
+            .arg("--file")
+            .arg(&self.file)
+            .arg("--project-name")
+            .arg(&self.project)
+            .args(args);
+        if !token.is_empty() {
+            command.env("INFRAHUB_INITIAL_ADMIN_TOKEN", token);
+        }
+        if let Some(version) = &self.version {
+            command
+                .env("VERSION", version)
+                .env("INFRAHUB_IMAGE_TAG", version);
+        }
+        let output = tokio::task::spawn_blocking(move || command.output())
+            .await
+            .map_err(|err| Error::Config(format!("docker compose did not finish: {err}")))??;
+        if output.status.success() {
+            return Ok(());
+        }
+        Err(Error::Config(format!(
+            "docker compose {} failed ({}): {}",
+            args.join(" "),
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+impl Schema {
+    fn load(self) -> Result<Value> {
+        match self {
+            Schema::Value(value) => Ok(value),
+            Schema::File(path) => read_schema_file(&path),
+        }
+    }
+}
+
+fn read_schema_file(path: &Path) -> Result<Value> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| Error::Config(format!("cannot read schema {}: {err}", path.display())))?;
+    let yaml = path
+        .extension()
+        .is_some_and(|ext| ext == "yml" || ext == "yaml");
+    if yaml {
+        #[cfg(feature = "yaml")]
+        return serde_yaml::from_str(&text)
+            .map_err(|err| Error::Config(format!("invalid schema {}: {err}", path.display())));
+        #[cfg(not(feature = "yaml"))]
+        return Err(Error::Config(format!(
+            "schema {} is yaml; enable the `yaml` feature",
+            path.display()
+        )));
+    }
+    serde_json::from_str(&text)
+        .map_err(|err| Error::Config(format!("invalid schema {}: {err}", path.display())))
+}
+
+/// poll `/api/config` until it succeeds or `timeout` passes
+async fn wait_until_ready(client: &Client, timeout: Duration, interval: Duration) -> Result<()> {
+    let started = Instant::now();
+    loop {
+        if client.is_ready().await {
+            return Ok(());
+        }
+        if started.elapsed() >= timeout {
+            return Err(Error::Config(format!(
+                "infrahub did not become ready within {timeout:?}"
+            )));
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compat::VersionCheck;
+    use crate::transport::{Transport, TransportFuture, TransportRequest, TransportResponse};
+    use reqwest::StatusCode;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    /// fails readiness `booting` times, then serves config and schema loads
+    #[derive(Default)]
+    struct Server {
+        booting: usize,
+        probes: AtomicUsize,
+        loaded: Mutex<Vec<Value>>,
+    }
+
+    impl Transport for Arc<Server> {
+        fn send(&self, request: TransportRequest) -> TransportFuture<'_> {
+            let response = match request.url.path() {
+                "/api/config" if self.probes.fetch_add(1, Ordering::SeqCst) < self.booting => {
+                    TransportResponse::new(StatusCode::SERVICE_UNAVAILABLE, "")
+                }
+                "/api/config" => TransportResponse::new(StatusCode::OK, "{}"),
+                "/api/schema/load" => {
+                    let body: Value = serde_json::from_str(&request.body_text()).unwrap();
+                    self.loaded.lock().unwrap().push(body);
+                    TransportResponse::new(StatusCode::ACCEPTED, r#"{"hash": "h1"}"#)
+                }
+                _ => TransportResponse::new(StatusCode::NOT_FOUND, ""),
+            };
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    fn attach(server: &Arc<Server>) -> HarnessBuilder {
+        let transport = server.clone();
+        Harness::attach("http://infrahub.test", "token")
+            .with_poll_interval(Duration::from_millis(1))
+            .with_config(move |config| {
+                config
+                    .with_version_check(VersionCheck::Off)
+                    .with_max_retries(0)
+                    .with_transport(transport)
+            })
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_waits_and_loads_schema() {
+        let server = Arc::new(Server {
+            booting: 2,
+            ..Server::default()
+        });
+        let schema = serde_json::json!({ "version": "1.0", "nodes": [] });
+        let harness = attach(&server)
+            .with_schema(schema.clone())
+            .start()
+            .await
+            .unwrap();
+        assert_eq!(server.probes.load(Ordering::SeqCst), 3);
+        assert_eq!(
+            *server.loaded.lock().unwrap(),
+            vec![serde_json::json!({ "schemas": [schema] })]
+        );
+        harness.stop().await.unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_ready_timeout() {
+        let server = Arc::new(Server {
+            booting: usize::MAX,
+            ..Server::default()
+        });
+        let err = attach(&server)
+            .with_ready_timeout(Duration::from_millis(20))
+            .start()
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("did not become ready"));
+    }
+
+    #[test]
+    fn test_compose_settings_in_any_order() {
+        let before = Harness::attach("http://infrahub.test", "token")
+            .with_project_name("ci")
+            .with_infrahub_version("1.9.0")
+            .with_compose_file("compose.yml");
+        let after = Harness::attach("http://infrahub.test", "token")
+            .with_compose_file("compose.yml")
+            .with_infrahub_version("1.9.0")
+            .with_project_name("ci");
+        let expected = Compose {
+            file: PathBuf::from("compose.yml"),
+            project: "ci".to_string(),
+            version: Some("1.9.0".to_string()),
+        };
+        assert_eq!(before.compose(), Some(expected.clone()));
+        assert_eq!(after.compose(), Some(expected));
+
+        let attached = Harness::attach("http://infrahub.test", "token").with_project_name("ci");
+        assert_eq!(attached.compose(), None);
+    }
+
+    #[test]
+    fn test_schema_file() {
+        let dir = std::env::temp_dir().join(format!("infrahub-harness-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("schema.json");
+        std::fs::write(&path, r#"{"version": "1.0"}"#).unwrap();
+        assert_eq!(read_schema_file(&path).unwrap()["version"], "1.0");
+        assert!(read_schema_file(&dir.join("missing.json")).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,215 @@
+//! tracking mode for idempotent sync runs
+//!
+//! a [`Tracker`] records every node a run creates or upserts. when the run
+//! finishes, members of the tracking group that the run did not write again
+//! are deleted, and the group is brought up to date with what it did write.
+//! running the same sync twice leaves the data unchanged; dropping an object
+//! from the source deletes it on the next run.
+//!
+//! ```no_run
+//! use infrahub::{Client, ClientConfig};
+//! use serde_json::json;
+//!
+//! # async fn example() -> infrahub::Result<()> {
+//! let client = Client::new(ClientConfig::new("http://localhost:8000", "token"))?;
+//! let tracker = client.start_tracking("sync-tags", None);
+//! for name in ["red", "blue"] {
+//!     tracker
+//!         .upsert("BuiltinTag", json!({ "name": { "value": name } }))
+//!         .await?;
+//! }
+//! let report = tracker.finish().await?;
+//! println!("kept {}, deleted {}", report.tracked, report.deleted.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::batch::{BatchMutation, MutationBatch};
+use crate::client::Client;
+use crate::error::{Error, Result};
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+
+/// records the nodes written during a sync run, see [`Client::start_tracking`]
+///
+/// methods take `&self`, so writes can run concurrently.
+pub struct Tracker<'a> {
+    client: &'a Client,
+    group: String,
+    branch: Option<String>,
+    touched: Mutex<BTreeSet<String>>,
+}
+
+/// outcome of [`Tracker::finish`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrackingReport {
+    /// id of the tracking group
+    pub group: String,
+    /// number of nodes written during the run
+    pub tracked: usize,
+    /// ids of the nodes deleted because the run did not write them
+    pub deleted: Vec<String>,
+}
+
+impl Client {
+    /// start a tracked run whose nodes are kept in the `CoreStandardGroup`
+    /// named `group`, created on first use
+    pub fn start_tracking(&self, group: impl Into<String>, branch: Option<&str>) -> Tracker<'_> {
+        Tracker {
+            client: self,
+            group: group.into(),
+            branch: branch.map(str::to_string),
+            touched: Mutex::new(BTreeSet::new()),
+        }
+    }
+}
+
+impl Tracker<'_> {
+    /// create a node of `kind` and track it
+    pub async fn create(&self, kind: impl AsRef<str>, data: Value) -> Result<String> {
+        let id = self
+            .client
+            .create_node(kind, data, self.branch.as_deref())
+            .await?;
+        self.track(&id);
+        Ok(id)
+    }
+
+    /// create or update a node of `kind` and track it
+    pub async fn upsert(&self, kind: impl AsRef<str>, data: Value) -> Result<String> {
+        let id = self
+            .client
+            .upsert_node(kind, data, self.branch.as_deref())
+            .await?;
+        self.track(&id);
+        Ok(id)
+    }
+
+    /// track a node written some other way, e.g. through a [`crate::Batch`]
+    pub fn track(&self, id: &str) {
+        self.touched_ids().insert(id.to_string());
+    }
+
+    /// number of nodes tracked so far
+    pub fn len(&self) -> usize {
+        self.touched_ids().len()
+    }
+
+    /// true if no node was tracked yet
+    pub fn is_empty(&self) -> bool {
+        self.touched_ids().is_empty()
+    }
+
+    /// delete the group members this run did not write and add the ones it
+    /// did
+    ///
+    /// stale members are deleted in one request before the group changes,
+    /// so if a delete fails the group still lists them and the next run
+    /// tries again.
+    pub async fn finish(self) -> Result<TrackingReport> {
+        let branch = self.branch.as_deref();
+        let touched = self
+            .touched
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let groups = self.client.groups();
+        let group = match groups.id(&self.group, branch).await? {
+            Some(id) => id,
+            None => groups.create(&self.group, branch).await?,
+        };
+
+        let members = groups.members(&group, branch).await?;
+        let previous: BTreeSet<&str> = members.iter().map(|member| member.id.as_str()).collect();
+        let stale: Vec<_> = members
+            .iter()
+            .filter(|member| !touched.contains(&member.id))
+            .collect();
+
+        let mut batch = MutationBatch::new();
+        let keys: Vec<_> = stale
+            .iter()
+            .map(|member| batch.push::<Value>(BatchMutation::delete(&member.kind, &member.id)))
+            .collect();
+        let results = self.client.execute_batch(&batch, branch).await?;
+        for (key, member) in keys.into_iter().zip(&stale) {
+            if results.get(key)?["ok"].as_bool() != Some(true) {
+                return Err(Error::Config(format!(
+                    "{}Delete did not report ok for {}",
+                    member.kind, member.id
+                )));
+            }
+        }
+
+        let added: Vec<&str> = touched
+            .iter()
+            .map(String::as_str)
+            .filter(|id| !previous.contains(id))
+            .collect();
+        if !added.is_empty() {
+            groups.add_members(&group, &added, branch).await?;
+        }
+
+        Ok(TrackingReport {
+            group,
+            tracked: touched.len(),
+            deleted: stale.iter().map(|member| member.id.clone()).collect(),
+        })
+    }
+
+    fn touched_ids(&self) -> std::sync::MutexGuard<'_, BTreeSet<String>> {
+        self.touched
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::MockClient;
+    use serde_json::json;
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_tracking_run() {
+        let mock = MockClient::new();
+        mock.expect("Upsert").returning(json!({
+            "BuiltinTagUpsert": { "ok": true, "object": { "id": "t1" } }
+        }));
+        mock.expect("GroupId")
+            .with_variables(json!({ "name": "sync" }))
+            .returning(json!({ "CoreGroup": { "edges": [{ "node": { "id": "g1" } }] } }));
+        mock.expect("GroupPeers").returning(json!({
+            "CoreGroup": { "edges": [{ "node": { "members": { "edges": [
+                { "node": { "id": "t1", "__typename": "BuiltinTag", "display_label": "red" } },
+                { "node": { "id": "t0", "__typename": "BuiltinTag", "display_label": "old" } }
+            ] } } }] }
+        }));
+        mock.expect("Batch")
+            .with_variables(json!({ "m0_data": { "id": "t0" } }))
+            .returning(json!({ "m0": { "ok": true } }));
+        mock.expect("EditGroup")
+            .with_variables(json!({
+                "data": { "id": "g1", "name": "members", "nodes": [{ "id": "t2" }] }
+            }))
+            .returning(json!({ "RelationshipAdd": { "ok": true } }));
+
+        let client = mock.client();
+        let tracker = client.start_tracking("sync", Some("b1"));
+        tracker
+            .upsert("BuiltinTag", json!({ "name": { "value": "red" } }))
+            .await
+            .unwrap();
+        tracker.track("t2");
+        assert_eq!(tracker.len(), 2);
+
+        let report = tracker.finish().await.unwrap();
+        assert_eq!(report.group, "g1");
+        assert_eq!(report.tracked, 2);
+        assert_eq!(report.deleted, vec!["t0".to_string()]);
+        assert!(mock
+            .calls()
+            .iter()
+            .all(|call| call.branch.as_deref() == Some("b1")));
+    }
+}
@@ -0,0 +1,235 @@
+//! http transport
+//!
+//! the [`Transport`] trait is the seam between [`crate::Client`] and the
+//! network. the client builds fully formed requests (url, headers, body) and
+//! hands them to the transport; retries, token handling, and response parsing
+//! stay in the client. [`ReqwestTransport`] is the default. plug in another
+//! with [`crate::ClientConfig::with_transport`], e.g. to serve canned
+//! responses in unit tests.
+
+use crate::error::Result;
+use reqwest::header::{HeaderMap, HeaderValue, IntoHeaderName};
+use reqwest::{Method, StatusCode};
+use std::future::Future;
+use std::pin::Pin;
+use url::Url;
+
+/// boxed future returned by [`Transport::send`]
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = Result<TransportResponse>> + Send + 'a>>;
+
+/// sends http requests on behalf of a [`crate::Client`]
+///
+/// implementations only move bytes: a non-2xx status is a successful send and
+/// must be returned as a response, not an error. errors are for requests
+/// that got no response at all (connection refused, timeouts).
+pub trait Transport: Send + Sync {
+    /// send `request` and return the complete response
+    fn send(&self, request: TransportRequest) -> TransportFuture<'_>;
+}
+
+/// a request built by the client
+#[derive(Debug, Clone)]
+pub struct TransportRequest {
+    /// http method
+    pub method: Method,
+    /// full request url
+    pub url: Url,
+    /// request headers, including the api token
+    pub headers: HeaderMap,
+    /// request body, if any
+    pub body: Option<Vec<u8>>,
+}
+
+impl TransportRequest {
+    /// a request without headers or body
+    pub fn new(method: Method, url: Url) -> Self {
+        Self {
+            method,
+            url,
+            headers: HeaderMap::new(),
+            body: None,
+        }
+    }
+
+    /// set a header, replacing any previous value
+    pub fn header(mut self, name: impl IntoHeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// set the body
+    pub fn body(mut self, body: Vec<u8>) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// the body as text, for inspecting requests in tests
+    pub fn body_text(&self) -> String {
+        String::from_utf8_lossy(self.body.as_deref().unwrap_or_default()).into_owned()
+    }
+}
+
+/// a complete http response
+#[derive(Debug, Clone)]
+pub struct TransportResponse {
+    /// http status
+    pub status: StatusCode,
+    /// response headers
+    pub headers: HeaderMap,
+    /// response body
+    pub body: Vec<u8>,
+}
+
+impl TransportResponse {
+    /// a response with `status` and `body` and no headers
+    pub fn new(status: StatusCode, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+
+    /// set a header, replacing any previous value
+    pub fn header(mut self, name: impl IntoHeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// the body as text, replacing invalid utf-8
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+/// the default transport, sending requests with a [`reqwest::Client`]
+///
+/// the client built from a [`crate::ClientConfig`] carries its tls, proxy,
+/// timeout, and default header settings; a custom transport is responsible
+/// for those itself.
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    http: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// send requests with `http`
+    pub fn new(http: reqwest::Client) -> Self {
+        Self { http }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: TransportRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let mut builder = self
+                .http
+                .request(request.method, request.url)
+                .headers(request.headers);
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+            let response = builder.send().await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?.to_vec();
+            Ok(TransportResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Client;
+    use crate::config::ClientConfig;
+    use reqwest::header::HeaderName;
+    use std::sync::{Arc, Mutex};
+
+    /// records requests and answers each with the same canned response
+    struct Canned {
+        requests: Arc<Mutex<Vec<TransportRequest>>>,
+        response: TransportResponse,
+    }
+
+    impl Transport for Canned {
+        fn send(&self, request: TransportRequest) -> TransportFuture<'_> {
+            self.requests.lock().unwrap().push(request);
+            Box::pin(async move { Ok(self.response.clone()) })
+        }
+    }
+
+    fn canned(
+        config: ClientConfig,
+        response: TransportResponse,
+    ) -> (Client, Arc<Mutex<Vec<TransportRequest>>>) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let transport = Canned {
+            requests: requests.clone(),
+            response,
+        };
+        (
+            Client::new(config.with_transport(transport)).unwrap(),
+            requests,
+        )
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_execute_through_custom_transport() {
+        let config = ClientConfig::new("http://localhost:1234", "secret-token")
+            .with_version_check(crate::VersionCheck::Off)
+            .with_user_agent("infrahub-test")
+            .with_header(
+                HeaderName::from_static("x-trace"),
+                HeaderValue::from_static("abc"),
+            );
+        let (client, requests) = canned(
+            config,
+            TransportResponse::new(StatusCode::OK, r#"{"data": {"ok": true}}"#),
+        );
+
+        let response = client
+            .execute_raw("query { ok }", None, Some("main"))
+            .await
+            .unwrap();
+        assert_eq!(response.data.unwrap()["ok"], true);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(request.url.path(), "/graphql/main");
+        assert_eq!(request.headers["X-INFRAHUB-KEY"], "secret-token");
+        assert_eq!(request.headers["user-agent"], "infrahub-test");
+        assert_eq!(request.headers["x-trace"], "abc");
+        assert_eq!(request.headers["content-type"], "application/json");
+        let body: serde_json::Value = serde_json::from_str(&request.body_text()).unwrap();
+        assert_eq!(body["query"], "query { ok }");
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_custom_transport_errors_and_statuses() {
+        let (client, requests) = canned(
+            ClientConfig::new("http://localhost:1234", "").with_max_retries(0),
+            TransportResponse::new(StatusCode::NOT_FOUND, "missing"),
+        );
+        let err = client.fetch_schema(None).await.unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::GraphQl {
+                status: Some(404),
+                ..
+            }
+        ));
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].method, Method::GET);
+        assert_eq!(requests[0].headers["accept-encoding"], "gzip");
+        assert!(requests[0].headers.get("X-INFRAHUB-KEY").is_none());
+    }
+}
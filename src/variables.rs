@@ -0,0 +1,254 @@
+//! graphql variables files
+//!
+//! load query variables from json (or yaml, with the `yaml` feature) files and
+//! interpolate `${NAME}` references from the environment, so parameterized
+//! queries can live next to operator playbooks.
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::graphql::GraphQlResponse;
+use serde_json::{Map, Value};
+use std::path::Path;
+
+/// graphql variables object
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Variables(Map<String, Value>);
+
+impl Variables {
+    /// create an empty variables object
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// load variables from a `.json`, `.yaml`, or `.yml` file and interpolate
+    /// environment references
+    ///
+    /// string values may reference environment variables as `${NAME}` or
+    /// `${NAME:-default}`; `$${` produces a literal `${`. a reference to an
+    /// unset variable without a default is an error. yaml files require the
+    /// `yaml` feature.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let value = match extension.as_str() {
+            "yaml" | "yml" => parse_yaml(&text)?,
+            _ => serde_json::from_str(&text)?,
+        };
+        let value = interpolate(value, &|name| std::env::var(name).ok())?;
+        Self::from_value(value)
+    }
+
+    /// build variables from a json object
+    pub fn from_value(value: Value) -> Result<Self> {
+        match value {
+            Value::Object(map) => Ok(Self(map)),
+            Value::Null => Ok(Self::new()),
+            _ => Err(Error::Config(
+                "graphql variables must be an object".to_string(),
+            )),
+        }
+    }
+
+    /// set a variable, replacing any previous value
+    pub fn set(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.0.insert(name.into(), value.into());
+        self
+    }
+
+    /// get a variable by name
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.0.get(name)
+    }
+
+    /// convert into the json value sent as `variables`
+    pub fn into_value(self) -> Value {
+        Value::Object(self.0)
+    }
+}
+
+impl From<Variables> for Value {
+    fn from(variables: Variables) -> Self {
+        variables.into_value()
+    }
+}
+
+impl Client {
+    /// execute the graphql document stored at `query_path`
+    ///
+    /// convenient together with [`Variables::from_file`] for reusable,
+    /// parameterized queries kept on disk.
+    pub async fn execute_file(
+        &self,
+        query_path: impl AsRef<Path>,
+        variables: Option<Variables>,
+        branch: Option<&str>,
+    ) -> Result<GraphQlResponse<Value>> {
+        let query = std::fs::read_to_string(query_path)?;
+        self.execute_raw(&query, variables.map(Variables::into_value), branch)
+            .await
+    }
+}
+
+#[cfg(feature = "yaml")]
+fn parse_yaml(text: &str) -> Result<Value> {
+    serde_yaml::from_str(text).map_err(|err| Error::Config(format!("invalid yaml: {err}")))
+}
+
+#[cfg(not(feature = "yaml"))]
+fn parse_yaml(_text: &str) -> Result<Value> {
+    Err(Error::Config(
+        "yaml variables files require the `yaml` feature".to_string(),
+    ))
+}
+
+/// replace `${NAME}` and `${NAME:-default}` in every string value
+fn interpolate(value: Value, lookup: &dyn Fn(&str) -> Option<String>) -> Result<Value> {
+    Ok(match value {
+        Value::String(text) => Value::String(interpolate_str(&text, lookup)?),
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(|item| interpolate(item, lookup))
+                .collect::<Result<_>>()?,
+        ),
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, item)| Ok((key, interpolate(item, lookup)?)))
+                .collect::<Result<_>>()?,
+        ),
+        other => other,
+    })
+}
+
+fn interpolate_str(text: &str, lookup: &dyn Fn(&str) -> Option<String>) -> Result<String> {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('$') {
+        out.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        if let Some(escaped) = tail.strip_prefix("$${") {
+            out.push_str("${");
+            rest = escaped;
+            continue;
+        }
+        let Some(body) = tail.strip_prefix("${") else {
+            out.push('$');
+            rest = &tail[1..];
+            continue;
+        };
+        let end = body
+            .find('}')
+            .ok_or_else(|| Error::Config(format!("unterminated variable reference in {text:?}")))?;
+        let reference = &body[..end];
+        let (name, default) = match reference.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (reference, None),
+        };
+        let value = lookup(name)
+            .or_else(|| default.map(str::to_string))
+            .ok_or_else(|| Error::Config(format!("environment variable {name} is not set")))?;
+        out.push_str(&value);
+        rest = &body[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "DEVICE_NAME" => Some("edge-01".to_string()),
+            "SITE" => Some("ams".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_interpolate_str() {
+        assert_eq!(
+            interpolate_str("${DEVICE_NAME}.${SITE}", &lookup).unwrap(),
+            "edge-01.ams"
+        );
+        assert_eq!(
+            interpolate_str("${MISSING:-fallback}", &lookup).unwrap(),
+            "fallback"
+        );
+        assert_eq!(
+            interpolate_str("cost $5 $${X}", &lookup).unwrap(),
+            "cost $5 ${X}"
+        );
+        assert!(interpolate_str("${MISSING}", &lookup).is_err());
+        assert!(interpolate_str("${DEVICE_NAME", &lookup).is_err());
+    }
+
+    #[test]
+    fn test_interpolate_nested_values() {
+        let value = serde_json::json!({
+            "name": "${DEVICE_NAME}",
+            "limit": 10,
+            "filters": { "site": ["${SITE}"] }
+        });
+        let value = interpolate(value, &lookup).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "name": "edge-01",
+                "limit": 10,
+                "filters": { "site": ["ams"] }
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_value() {
+        let vars = Variables::from_value(serde_json::json!({ "a": 1 }))
+            .unwrap()
+            .set("b", "two");
+        assert_eq!(vars.get("a"), Some(&serde_json::json!(1)));
+        assert_eq!(Value::from(vars), serde_json::json!({ "a": 1, "b": "two" }));
+        assert!(Variables::from_value(serde_json::json!([1])).is_err());
+        assert_eq!(
+            Variables::from_value(Value::Null).unwrap(),
+            Variables::new()
+        );
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn test_from_file_json() {
+        let path = std::env::temp_dir().join(format!("infrahub-vars-{}.json", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"{ "limit": 5, "name": "${INFRAHUB_RS_TEST_UNSET:-x}" }"#,
+        )
+        .unwrap();
+        let vars = Variables::from_file(&path).unwrap();
+        assert_eq!(
+            vars.into_value(),
+            serde_json::json!({ "limit": 5, "name": "x" })
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "yaml")]
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn test_from_file_yaml() {
+        let path = std::env::temp_dir().join(format!("infrahub-vars-{}.yaml", std::process::id()));
+        std::fs::write(&path, "limit: 5\nnames:\n  - a\n  - b\n").unwrap();
+        let vars = Variables::from_file(&path).unwrap();
+        assert_eq!(
+            vars.into_value(),
+            serde_json::json!({ "limit": 5, "names": ["a", "b"] })
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,425 @@
+//! record and replay
+//!
+//! [`RecordingTransport`] wraps a real transport and saves every request and
+//! response pair to a json cassette file; [`ReplayTransport`] serves a
+//! cassette back without a server. credentials are scrubbed before anything
+//! is written: sensitive headers are replaced, tokens sent by the client are
+//! removed from bodies, and json fields matching the default
+//! [`crate::Redactor`] patterns are redacted. exported from
+//! [`crate::testing`].
+
+use crate::error::{Error, Result};
+use crate::redact::{Redactor, REDACTED};
+use crate::secret::scrub;
+use crate::transport::{Transport, TransportFuture, TransportRequest, TransportResponse};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// headers whose values never reach a cassette
+pub(crate) const SENSITIVE_HEADERS: &[&str] =
+    &["x-infrahub-key", "authorization", "cookie", "set-cookie"];
+
+/// recorded http traffic
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Cassette {
+    /// request and response pairs in the order they happened
+    pub interactions: Vec<Interaction>,
+}
+
+/// one recorded request and its response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// the request as sent
+    pub request: RecordedRequest,
+    /// the response as received
+    pub response: RecordedResponse,
+}
+
+/// a recorded request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// http method
+    pub method: String,
+    /// full request url
+    pub url: String,
+    /// header names and values, sensitive values replaced
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// request body
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<RecordedBody>,
+}
+
+/// a recorded response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// http status code
+    pub status: u16,
+    /// header names and values, sensitive values replaced
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// response body
+    pub body: RecordedBody,
+}
+
+/// a recorded body: utf-8 text, or raw bytes otherwise
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RecordedBody {
+    /// a utf-8 body
+    Text(String),
+    /// a binary body
+    Bytes(Vec<u8>),
+}
+
+impl RecordedBody {
+    fn new(bytes: &[u8]) -> Self {
+        match std::str::from_utf8(bytes) {
+            Ok(text) => RecordedBody::Text(text.to_string()),
+            Err(_) => RecordedBody::Bytes(bytes.to_vec()),
+        }
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        match self {
+            RecordedBody::Text(text) => text.into_bytes(),
+            RecordedBody::Bytes(bytes) => bytes,
+        }
+    }
+}
+
+impl Cassette {
+    /// read a cassette from a json file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// write the cassette to a json file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let text = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, text + "\n")?;
+        Ok(())
+    }
+}
+
+/// a transport that forwards requests and records them to a cassette file
+///
+/// the file is rewritten after every interaction, so it is complete even if
+/// the test panics part way. clones share one cassette.
+#[derive(Clone)]
+pub struct RecordingTransport {
+    inner: Arc<dyn Transport>,
+    path: PathBuf,
+    cassette: Arc<Mutex<Cassette>>,
+    redactor: Redactor,
+}
+
+impl RecordingTransport {
+    /// record the traffic of `inner` to `path`, replacing any existing file
+    pub fn new(inner: impl Transport + 'static, path: impl Into<PathBuf>) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            path: path.into(),
+            cassette: Arc::new(Mutex::new(Cassette::default())),
+            redactor: Redactor::default(),
+        }
+    }
+
+    /// redact json body fields with `redactor` instead of the default
+    /// patterns; replay with the same redactor
+    pub fn with_redactor(mut self, redactor: Redactor) -> Self {
+        self.redactor = redactor;
+        self
+    }
+
+    /// the interactions recorded so far
+    pub fn cassette(&self) -> Cassette {
+        lock(&self.cassette).clone()
+    }
+
+    /// scrub a request and response pair
+    fn interaction(&self, request: &TransportRequest, response: &TransportResponse) -> Interaction {
+        Interaction {
+            request: RecordedRequest {
+                method: request.method.to_string(),
+                url: request.url.to_string(),
+                headers: record_headers(&request.headers),
+                body: request
+                    .body
+                    .as_deref()
+                    .map(|body| scrub_body(&self.redactor, request, body)),
+            },
+            response: RecordedResponse {
+                status: response.status.as_u16(),
+                headers: record_headers(&response.headers),
+                body: scrub_body(&self.redactor, request, &response.body),
+            },
+        }
+    }
+}
+
+impl Transport for RecordingTransport {
+    fn send(&self, request: TransportRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let response = self.inner.send(request.clone()).await?;
+            let interaction = self.interaction(&request, &response);
+            let mut cassette = lock(&self.cassette);
+            cassette.interactions.push(interaction);
+            cassette.save(&self.path)?;
+            Ok(response)
+        })
+    }
+}
+
+/// a transport serving the responses of a cassette
+///
+/// a request is answered by the first unused interaction with the same
+/// method, url, and body (scrubbed the way it was when recorded); repeated
+/// identical requests get their recorded responses in order. a request with
+/// no match fails with [`Error::Config`].
+#[derive(Clone)]
+pub struct ReplayTransport {
+    interactions: Arc<Mutex<Vec<(Interaction, bool)>>>,
+    redactor: Redactor,
+}
+
+impl ReplayTransport {
+    /// replay `cassette`
+    pub fn new(cassette: Cassette) -> Self {
+        Self {
+            interactions: Arc::new(Mutex::new(
+                cassette
+                    .interactions
+                    .into_iter()
+                    .map(|interaction| (interaction, false))
+                    .collect(),
+            )),
+            redactor: Redactor::default(),
+        }
+    }
+
+    /// scrub request bodies with `redactor`, matching a recording made with
+    /// [`RecordingTransport::with_redactor`]
+    pub fn with_redactor(mut self, redactor: Redactor) -> Self {
+        self.redactor = redactor;
+        self
+    }
+
+    /// replay the cassette stored at `path`
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self::new(Cassette::load(path)?))
+    }
+
+    /// number of interactions not replayed yet
+    pub fn remaining(&self) -> usize {
+        lock(&self.interactions)
+            .iter()
+            .filter(|(_, used)| !used)
+            .count()
+    }
+
+    fn answer(&self, request: &TransportRequest) -> Result<TransportResponse> {
+        let body = request
+            .body
+            .as_deref()
+            .map(|body| scrub_body(&self.redactor, request, body));
+        let mut interactions = lock(&self.interactions);
+        let (interaction, used) = interactions
+            .iter_mut()
+            .find(|(interaction, used)| {
+                !used
+                    && interaction.request.method == request.method.as_str()
+                    && interaction.request.url == request.url.as_str()
+                    && interaction.request.body == body
+            })
+            .ok_or_else(|| {
+                Error::Config(format!(
+                    "no recorded interaction for {} {}",
+                    request.method, request.url
+                ))
+            })?;
+        *used = true;
+        let recorded = &interaction.response;
+        let status = StatusCode::from_u16(recorded.status)
+            .map_err(|_| Error::Config(format!("invalid recorded status {}", recorded.status)))?;
+        let mut response = TransportResponse::new(status, recorded.body.clone().into_bytes());
+        for (name, value) in &recorded.headers {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                response.headers.append(name, value);
+            }
+        }
+        Ok(response)
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn send(&self, request: TransportRequest) -> TransportFuture<'_> {
+        let response = self.answer(&request);
+        Box::pin(async move { response })
+    }
+}
+
+/// a body with the credentials sent in `request` scrubbed and sensitive json
+/// fields redacted
+fn scrub_body(redactor: &Redactor, request: &TransportRequest, body: &[u8]) -> RecordedBody {
+    match RecordedBody::new(body) {
+        RecordedBody::Text(text) => {
+            let secrets = SENSITIVE_HEADERS
+                .iter()
+                .flat_map(|name| request.headers.get_all(*name))
+                .map(HeaderValue::as_bytes);
+            RecordedBody::Text(redactor.redact_text(&scrub(&text, secrets)))
+        }
+        bytes => bytes,
+    }
+}
+
+/// header pairs with sensitive values replaced by [`REDACTED`]
+pub(crate) fn record_headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if value.is_sensitive() || SENSITIVE_HEADERS.contains(&name.as_str()) {
+                REDACTED.to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (name.as_str().to_string(), value)
+        })
+        .collect()
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Client;
+    use crate::compat::VersionCheck;
+    use crate::config::ClientConfig;
+    use reqwest::Method;
+    use url::Url;
+
+    fn request(method: Method, url: &str, body: &str) -> TransportRequest {
+        TransportRequest::new(method, Url::parse(url).unwrap()).body(body.as_bytes().to_vec())
+    }
+
+    /// echoes the request token into its response, like a misbehaving proxy
+    struct Echo;
+
+    impl Transport for Echo {
+        fn send(&self, request: TransportRequest) -> TransportFuture<'_> {
+            let token = request
+                .headers
+                .get("x-infrahub-key")
+                .map(|value| value.to_str().unwrap().to_string())
+                .unwrap_or_default();
+            let body =
+                format!(r#"{{"data": {{"Echo": {{"api_token": "tok-new", "seen": "{token}"}}}}}}"#);
+            Box::pin(async move { Ok(TransportResponse::new(StatusCode::OK, body)) })
+        }
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        let path =
+            std::env::temp_dir().join(format!("infrahub-cassette-{}.json", std::process::id()));
+        let query = "query Echo { Echo { api_token seen } }";
+
+        let recorder = RecordingTransport::new(Echo, &path);
+        let config = ClientConfig::new("http://localhost:1234", "secret-token")
+            .with_version_check(VersionCheck::Off)
+            .with_transport(recorder.clone());
+        let recorded = Client::new(config)
+            .unwrap()
+            .execute_raw(query, None, None)
+            .await
+            .unwrap();
+        assert_eq!(recorded.data.unwrap()["Echo"]["seen"], "secret-token");
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(!text.contains("secret-token"));
+        assert!(!text.contains("tok-new"));
+        assert_eq!(recorder.cassette().interactions.len(), 1);
+
+        let replay = ReplayTransport::from_file(&path).unwrap();
+        let config = ClientConfig::new("http://localhost:1234", "other-token")
+            .with_version_check(VersionCheck::Off)
+            .with_max_retries(0)
+            .with_transport(replay.clone());
+        let client = Client::new(config).unwrap();
+        let replayed = client.execute_raw(query, None, None).await.unwrap();
+        assert_eq!(replayed.data.unwrap()["Echo"]["seen"], REDACTED);
+        assert_eq!(replay.remaining(), 0);
+
+        let err = client.execute_raw(query, None, None).await.unwrap_err();
+        assert!(matches!(err, Error::Config(_)));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_replay_matches_method_url_and_body() {
+        let interaction = |body: &str, answer: &str| Interaction {
+            request: RecordedRequest {
+                method: "POST".to_string(),
+                url: "http://localhost:1234/graphql".to_string(),
+                headers: Vec::new(),
+                body: Some(RecordedBody::Text(body.to_string())),
+            },
+            response: RecordedResponse {
+                status: 200,
+                headers: vec![("content-type".to_string(), "application/json".to_string())],
+                body: RecordedBody::Text(answer.to_string()),
+            },
+        };
+        let replay = ReplayTransport::new(Cassette {
+            interactions: vec![
+                interaction("a", "1"),
+                interaction("b", "2"),
+                interaction("a", "3"),
+            ],
+        });
+        let url = "http://localhost:1234/graphql";
+        assert_eq!(
+            replay
+                .answer(&request(Method::POST, url, "a"))
+                .unwrap()
+                .text(),
+            "1"
+        );
+        assert_eq!(
+            replay
+                .answer(&request(Method::POST, url, "a"))
+                .unwrap()
+                .text(),
+            "3"
+        );
+        let response = replay.answer(&request(Method::POST, url, "b")).unwrap();
+        assert_eq!(response.headers["content-type"], "application/json");
+        assert!(replay.answer(&request(Method::GET, url, "b")).is_err());
+    }
+
+    #[test]
+    fn test_recorded_body_round_trip() {
+        let binary = RecordedBody::new(&[0xff, 0x00]);
+        assert_eq!(binary, RecordedBody::Bytes(vec![0xff, 0x00]));
+        let json = serde_json::to_string(&binary).unwrap();
+        assert_eq!(serde_json::from_str::<RecordedBody>(&json).unwrap(), binary);
+        assert_eq!(
+            RecordedBody::new(b"ok"),
+            RecordedBody::Text("ok".to_string())
+        );
+    }
+}
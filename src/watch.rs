@@ -0,0 +1,140 @@
+//! schema change watcher
+//!
+//! polls the schema hash of a branch and reports when it changes, so
+//! long-running services can regenerate code or invalidate cached schemas.
+
+use crate::client::Client;
+use crate::error::Result;
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// shortest allowed polling interval
+const MIN_INTERVAL: Duration = Duration::from_secs(1);
+/// polling backs off to at most this multiple of the interval while throttled
+const MAX_BACKOFF_FACTOR: u32 = 8;
+
+/// a schema change observed by [`SchemaWatcher`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaChange {
+    /// branch being watched (`None` for the default branch)
+    pub branch: Option<String>,
+    /// schema hash before the change
+    pub previous: String,
+    /// schema hash after the change
+    pub current: String,
+}
+
+/// polls the schema hash of a branch, see [`Client::watch_schema`]
+#[derive(Clone)]
+pub struct SchemaWatcher {
+    client: Client,
+    branch: Option<String>,
+    interval: Duration,
+    delay: Duration,
+    polled: bool,
+    last_hash: Option<String>,
+}
+
+impl Client {
+    /// watch the schema of `branch`, polling every `interval` (at least one second)
+    ///
+    /// call [`SchemaWatcher::next`] in a loop to receive changes. while the
+    /// server is throttling or unavailable the watcher backs off up to eight
+    /// times the interval and keeps polling.
+    pub fn watch_schema(&self, branch: Option<&str>, interval: Duration) -> SchemaWatcher {
+        let interval = interval.max(MIN_INTERVAL);
+        SchemaWatcher {
+            client: self.clone(),
+            branch: branch.map(str::to_string),
+            interval,
+            delay: interval,
+            polled: false,
+            last_hash: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for SchemaWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SchemaWatcher")
+            .field("branch", &self.branch)
+            .field("interval", &self.interval)
+            .field("delay", &self.delay)
+            .field("last_hash", &self.last_hash)
+            .finish()
+    }
+}
+
+impl SchemaWatcher {
+    /// last schema hash seen, if any poll has succeeded yet
+    pub fn current_hash(&self) -> Option<&str> {
+        self.last_hash.as_deref()
+    }
+
+    /// wait for the next schema change
+    ///
+    /// the first successful poll only records the baseline hash. transient
+    /// errors (see [`crate::Error::is_retryable`]) are absorbed with backoff;
+    /// other errors are returned, and the watcher can be polled again afterwards.
+    pub async fn next(&mut self) -> Result<SchemaChange> {
+        loop {
+            if self.polled {
+                sleep(self.delay).await;
+            }
+            self.polled = true;
+
+            match self.client.schema_hash(self.branch.as_deref()).await {
+                Ok(hash) => {
+                    self.delay = self.interval;
+                    match self.last_hash.replace(hash.clone()) {
+                        Some(previous) if previous != hash => {
+                            return Ok(SchemaChange {
+                                branch: self.branch.clone(),
+                                previous,
+                                current: hash,
+                            });
+                        }
+                        _ => {}
+                    }
+                }
+                Err(err) if err.is_retryable() => {
+                    self.delay = backoff(self.delay, self.interval);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+/// double the delay, capped at `MAX_BACKOFF_FACTOR` times the interval
+fn backoff(delay: Duration, interval: Duration) -> Duration {
+    delay.saturating_mul(2).min(interval * MAX_BACKOFF_FACTOR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ClientConfig;
+
+    #[test]
+    fn test_backoff_caps() {
+        let interval = Duration::from_secs(5);
+        let mut delay = interval;
+        for _ in 0..10 {
+            delay = backoff(delay, interval);
+        }
+        assert_eq!(delay, Duration::from_secs(40));
+        assert_eq!(backoff(interval, interval), Duration::from_secs(10));
+    }
+
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    #[test]
+    fn test_watch_schema_clamps_interval() {
+        let client =
+            Client::new(ClientConfig::new("https://infrahub.example.com", "token")).unwrap();
+        let watcher = client.watch_schema(Some("main"), Duration::ZERO);
+        assert_eq!(watcher.interval, MIN_INTERVAL);
+        assert_eq!(watcher.branch.as_deref(), Some("main"));
+        assert!(watcher.current_hash().is_none());
+    }
+}
@@ -0,0 +1,212 @@
+//! webhook helpers
+//!
+//! typed wrappers around the `CoreStandardWebhook` and `CoreCustomWebhook`
+//! mutations, so webhooks can be provisioned as code. a standard webhook
+//! posts the event as is, signed with its shared key; a custom webhook runs
+//! the event through a `CoreTransformPython` first.
+//!
+//! ```no_run
+//! use infrahub::{Client, ClientConfig, Webhook};
+//!
+//! # async fn example() -> infrahub::Result<()> {
+//! let client = Client::new(ClientConfig::new("http://localhost:8000", "token"))?;
+//! let webhook = Webhook::standard("netbox-sync", "https://sync.example.com/hook")
+//!     .with_event_type("infrahub.node.updated")
+//!     .with_node_kind("InfraDevice")
+//!     .with_shared_key("secret");
+//! let id = client.upsert_webhook(&webhook).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::branch::ensure_ok;
+use crate::client::Client;
+use crate::error::Result;
+use serde_json::{json, Map, Value};
+
+/// a `CoreStandardWebhook` or `CoreCustomWebhook` to create or update
+#[derive(Clone)]
+pub struct Webhook {
+    kind: &'static str,
+    name: String,
+    attrs: Map<String, Value>,
+}
+
+impl Webhook {
+    /// a `CoreStandardWebhook` named `name` posting to `url`
+    pub fn standard(name: impl Into<String>, url: impl Into<String>) -> Self {
+        Self::new("CoreStandardWebhook", name.into(), url.into())
+    }
+
+    /// a `CoreCustomWebhook` named `name` posting to `url`
+    pub fn custom(name: impl Into<String>, url: impl Into<String>) -> Self {
+        Self::new("CoreCustomWebhook", name.into(), url.into())
+    }
+
+    fn new(kind: &'static str, name: String, url: String) -> Self {
+        let mut attrs = Map::new();
+        attrs.insert("url".to_string(), json!({ "value": url }));
+        Self { kind, name, attrs }
+    }
+
+    /// the webhook kind, `CoreStandardWebhook` or `CoreCustomWebhook`
+    pub fn kind(&self) -> &str {
+        self.kind
+    }
+
+    /// set the description
+    pub fn with_description(self, description: impl Into<String>) -> Self {
+        self.with_attribute("description", json!(description.into()))
+    }
+
+    /// only send events of this type, e.g. `infrahub.node.created`; the
+    /// server default is `all`
+    pub fn with_event_type(self, event_type: impl Into<String>) -> Self {
+        self.with_attribute("event_type", json!(event_type.into()))
+    }
+
+    /// only send events from these branches: `all_branches`,
+    /// `default_branch`, or `other_branches`
+    pub fn with_branch_scope(self, branch_scope: impl Into<String>) -> Self {
+        self.with_attribute("branch_scope", json!(branch_scope.into()))
+    }
+
+    /// only send node events for this kind
+    pub fn with_node_kind(self, node_kind: impl Into<String>) -> Self {
+        self.with_attribute("node_kind", json!(node_kind.into()))
+    }
+
+    /// whether to validate the receiver's tls certificate
+    pub fn with_validate_certificates(self, validate: bool) -> Self {
+        self.with_attribute("validate_certificates", json!(validate))
+    }
+
+    /// sign requests with this key; only used by standard webhooks
+    pub fn with_shared_key(self, shared_key: impl Into<String>) -> Self {
+        self.with_attribute("shared_key", json!(shared_key.into()))
+    }
+
+    /// transform events with the `CoreTransformPython` of this id; only used
+    /// by custom webhooks
+    pub fn with_transformation(mut self, transformation: impl Into<String>) -> Self {
+        self.attrs.insert(
+            "transformation".to_string(),
+            json!({ "id": transformation.into() }),
+        );
+        self
+    }
+
+    fn with_attribute(mut self, field: &str, value: Value) -> Self {
+        self.attrs
+            .insert(field.to_string(), json!({ "value": value }));
+        self
+    }
+
+    fn to_input(&self) -> Value {
+        let mut input = self.attrs.clone();
+        input.insert("name".to_string(), json!({ "value": self.name }));
+        Value::Object(input)
+    }
+}
+
+impl std::fmt::Debug for Webhook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut attrs = self.attrs.clone();
+        if attrs.contains_key("shared_key") {
+            attrs.insert(
+                "shared_key".to_string(),
+                json!({ "value": crate::redact::REDACTED }),
+            );
+        }
+        f.debug_struct("Webhook")
+            .field("kind", &self.kind)
+            .field("name", &self.name)
+            .field("attrs", &attrs)
+            .finish()
+    }
+}
+
+impl Client {
+    /// create a webhook, returning its id
+    pub async fn create_webhook(&self, webhook: &Webhook) -> Result<String> {
+        self.create_node(webhook.kind, webhook.to_input(), None)
+            .await
+    }
+
+    /// create a webhook or update the one with the same name, returning its id
+    pub async fn upsert_webhook(&self, webhook: &Webhook) -> Result<String> {
+        self.upsert_node(webhook.kind, webhook.to_input(), None)
+            .await
+    }
+
+    /// overwrite the fields set on `webhook` for the webhook with this id
+    pub async fn update_webhook(&self, id: &str, webhook: &Webhook) -> Result<()> {
+        let mutation = format!("{}Update", webhook.kind);
+        let query = format!(
+            "mutation UpdateWebhook($data: {mutation}Input!) {{ {mutation}(data: $data) {{ ok }} }}"
+        );
+        let mut data = webhook.to_input();
+        data["id"] = json!(id);
+        let response = self
+            .execute_raw(&query, Some(json!({ "data": data })), None)
+            .await?;
+        ensure_ok(response.data, &mutation)
+    }
+
+    /// look up a webhook id by name, for either webhook kind
+    pub async fn webhook_id(&self, name: &str) -> Result<Option<String>> {
+        let query = "query WebhookId($name: String!) { CoreWebhook(name__value: $name) { edges { node { id } } } }";
+        let vars = json!({ "name": name });
+        let response = self.execute_raw(query, Some(vars), None).await?;
+        let data = response.into_data()?;
+        Ok(data["CoreWebhook"]["edges"][0]["node"]["id"]
+            .as_str()
+            .map(str::to_string))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockClient;
+
+    #[test]
+    fn test_webhook_input() {
+        let webhook = Webhook::custom("sync", "https://example.com/hook")
+            .with_event_type("infrahub.node.created")
+            .with_validate_certificates(false)
+            .with_transformation("t1");
+        assert_eq!(webhook.kind(), "CoreCustomWebhook");
+        assert_eq!(
+            webhook.to_input(),
+            json!({
+                "name": { "value": "sync" },
+                "url": { "value": "https://example.com/hook" },
+                "event_type": { "value": "infrahub.node.created" },
+                "validate_certificates": { "value": false },
+                "transformation": { "id": "t1" },
+            })
+        );
+
+        let webhook =
+            Webhook::standard("sync", "https://example.com/hook").with_shared_key("s3cr3t");
+        assert!(!format!("{webhook:?}").contains("s3cr3t"));
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_update_webhook() {
+        let mock = MockClient::new();
+        mock.expect("UpdateWebhook")
+            .with_variables(json!({ "data": {
+                "id": "w1",
+                "name": { "value": "sync" },
+                "url": { "value": "https://example.com/v2" },
+            } }))
+            .returning(json!({ "CoreStandardWebhookUpdate": { "ok": true } }));
+
+        let client = mock.client();
+        let webhook = Webhook::standard("sync", "https://example.com/v2");
+        client.update_webhook("w1", &webhook).await.unwrap();
+    }
+}
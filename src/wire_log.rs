@@ -0,0 +1,305 @@
+//! wire logging
+//!
+//! a [`WireLog`] passes every sampled http exchange, bodies included, to a
+//! sink. the handle is shared: flip it on, change the sample rate, or turn it
+//! off again at runtime through any clone, without rebuilding the client.
+//! credentials are scrubbed the same way as for recorded cassettes.
+
+use crate::redact::Redactor;
+use crate::secret::scrub;
+use crate::transport::{TransportRequest, TransportResponse};
+use crate::vcr::{record_headers, SENSITIVE_HEADERS};
+use reqwest::header::HeaderValue;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// callback receiving sampled exchanges
+type WireLogSink = Arc<dyn Fn(&WireLogEntry) + Send + Sync>;
+
+/// default maximum length of a logged body, in bytes
+const DEFAULT_BODY_LIMIT: usize = 64 * 1024;
+
+/// one http exchange with its bodies
+#[derive(Debug, Clone)]
+pub struct WireLogEntry {
+    /// request method
+    pub method: String,
+    /// request url
+    pub url: String,
+    /// request headers, credentials replaced by [`crate::REDACTED`]
+    pub request_headers: Vec<(String, String)>,
+    /// request body, scrubbed and truncated
+    pub request_body: String,
+    /// response status, if a response arrived
+    pub status: Option<u16>,
+    /// response headers, credentials replaced by [`crate::REDACTED`]
+    pub response_headers: Vec<(String, String)>,
+    /// response body, scrubbed and truncated
+    pub response_body: String,
+    /// time from sending the request to receiving the full response
+    pub duration: Duration,
+    /// transport error, if the request failed without a response
+    pub error: Option<String>,
+}
+
+/// runtime-togglable wire logging, see [`crate::ClientConfig::with_wire_log`]
+///
+/// clones share their state. a new wire log is disabled and samples every
+/// request once enabled.
+#[derive(Clone)]
+pub struct WireLog {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    enabled: AtomicBool,
+    sample_rate: AtomicU64,
+    counter: AtomicU64,
+    body_limit: usize,
+    sink: WireLogSink,
+}
+
+impl WireLog {
+    /// a disabled wire log passing entries to `sink`
+    pub fn new<F>(sink: F) -> Self
+    where
+        F: Fn(&WireLogEntry) + Send + Sync + 'static,
+    {
+        Self {
+            inner: Arc::new(Inner {
+                enabled: AtomicBool::new(false),
+                sample_rate: AtomicU64::new(1),
+                counter: AtomicU64::new(0),
+                body_limit: DEFAULT_BODY_LIMIT,
+                sink: Arc::new(sink),
+            }),
+        }
+    }
+
+    /// a disabled wire log writing one line per exchange to stderr
+    pub fn stderr() -> Self {
+        Self::new(|entry| {
+            let status = entry
+                .status
+                .map_or_else(|| "error".to_string(), |status| status.to_string());
+            eprintln!(
+                "infrahub: wire {} {} -> {} in {:?}\n  request: {}\n  response: {}",
+                entry.method,
+                entry.url,
+                status,
+                entry.duration,
+                entry.request_body,
+                entry.error.as_deref().unwrap_or(&entry.response_body),
+            );
+        })
+    }
+
+    /// start enabled (or disabled)
+    pub fn with_enabled(self, enabled: bool) -> Self {
+        self.set_enabled(enabled);
+        self
+    }
+
+    /// log one in `n` requests; 0 is treated as 1
+    pub fn with_sample_rate(self, n: u64) -> Self {
+        self.set_sample_rate(n);
+        self
+    }
+
+    /// cut logged bodies to `bytes`, marking a cut with a trailing `...`
+    ///
+    /// default: 64 KiB. must be set before the handle is cloned.
+    pub fn with_body_limit(mut self, bytes: usize) -> Self {
+        if let Some(inner) = Arc::get_mut(&mut self.inner) {
+            inner.body_limit = bytes;
+        }
+        self
+    }
+
+    /// turn logging on or off for every clone of this handle
+    pub fn set_enabled(&self, enabled: bool) {
+        self.inner.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// true if logging is on
+    pub fn is_enabled(&self) -> bool {
+        self.inner.enabled.load(Ordering::Relaxed)
+    }
+
+    /// log one in `n` requests from now on; 0 is treated as 1
+    pub fn set_sample_rate(&self, n: u64) {
+        self.inner.sample_rate.store(n.max(1), Ordering::Relaxed);
+    }
+
+    /// the current sample rate
+    pub fn sample_rate(&self) -> u64 {
+        self.inner.sample_rate.load(Ordering::Relaxed)
+    }
+
+    /// true if the next request should be logged
+    pub(crate) fn sample(&self) -> bool {
+        if !self.is_enabled() {
+            return false;
+        }
+        let n = self.inner.counter.fetch_add(1, Ordering::Relaxed);
+        n.is_multiple_of(self.sample_rate())
+    }
+
+    /// scrub and pass one exchange to the sink
+    pub(crate) fn log(
+        &self,
+        redactor: &Redactor,
+        request: &TransportRequest,
+        result: Result<&TransportResponse, String>,
+        duration: Duration,
+    ) {
+        let body = |bytes: &[u8]| self.body(redactor, request, bytes);
+        let (status, response_headers, response_body, error) = match result {
+            Ok(response) => (
+                Some(response.status.as_u16()),
+                record_headers(&response.headers),
+                body(&response.body),
+                None,
+            ),
+            Err(error) => (None, Vec::new(), String::new(), Some(error)),
+        };
+        (self.inner.sink)(&WireLogEntry {
+            method: request.method.to_string(),
+            url: request.url.to_string(),
+            request_headers: record_headers(&request.headers),
+            request_body: request.body.as_deref().map(body).unwrap_or_default(),
+            status,
+            response_headers,
+            response_body,
+            duration,
+            error,
+        });
+    }
+
+    /// `bytes` as text with the request's credentials scrubbed, sensitive
+    /// json fields redacted, and cut to the body limit
+    fn body(&self, redactor: &Redactor, request: &TransportRequest, bytes: &[u8]) -> String {
+        let secrets = SENSITIVE_HEADERS
+            .iter()
+            .flat_map(|name| request.headers.get_all(*name))
+            .map(HeaderValue::as_bytes);
+        let text = scrub(&String::from_utf8_lossy(bytes), secrets);
+        let mut text = redactor.redact_text(&text);
+        let limit = self.inner.body_limit;
+        if text.len() > limit {
+            let mut cut = limit;
+            while !text.is_char_boundary(cut) {
+                cut -= 1;
+            }
+            text.truncate(cut);
+            text.push_str("...");
+        }
+        text
+    }
+}
+
+impl std::fmt::Debug for WireLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WireLog")
+            .field("enabled", &self.is_enabled())
+            .field("sample_rate", &self.sample_rate())
+            .field("body_limit", &self.inner.body_limit)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Client;
+    use crate::config::ClientConfig;
+    use crate::transport::{Transport, TransportFuture};
+    use reqwest::StatusCode;
+    use std::sync::Mutex;
+
+    /// answers every request with a body echoing a token and a password
+    struct Echo;
+
+    impl Transport for Echo {
+        fn send(&self, _request: TransportRequest) -> TransportFuture<'_> {
+            Box::pin(async {
+                Ok(TransportResponse::new(
+                    StatusCode::OK,
+                    r#"{"data": {"ok": "secret-token", "password": "hunter2"}}"#,
+                ))
+            })
+        }
+    }
+
+    fn logged() -> (WireLog, Arc<Mutex<Vec<WireLogEntry>>>) {
+        let entries = Arc::new(Mutex::new(Vec::new()));
+        let sink = entries.clone();
+        let wire_log = WireLog::new(move |entry| sink.lock().unwrap().push(entry.clone()));
+        (wire_log, entries)
+    }
+
+    #[test]
+    fn test_sampling() {
+        let (wire_log, _) = logged();
+        assert!(!wire_log.sample());
+        wire_log.set_enabled(true);
+        wire_log.set_sample_rate(3);
+        let sampled = (0..9).filter(|_| wire_log.sample()).count();
+        assert_eq!(sampled, 3);
+        wire_log.set_sample_rate(0);
+        assert_eq!(wire_log.sample_rate(), 1);
+    }
+
+    #[test]
+    fn test_body_limit() {
+        let (wire_log, _) = logged();
+        let wire_log = wire_log.with_body_limit(2);
+        let request =
+            TransportRequest::new(reqwest::Method::POST, "http://localhost".parse().unwrap());
+        assert_eq!(
+            wire_log.body(&Redactor::disabled(), &request, "héllo".as_bytes()),
+            "h..."
+        );
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn test_wire_log_toggles_at_runtime() {
+        let (wire_log, entries) = logged();
+        let config = ClientConfig::new("http://localhost:1234", "secret-token")
+            .with_version_check(crate::VersionCheck::Off)
+            .with_transport(Echo)
+            .with_wire_log(wire_log.clone());
+        let client = Client::new(config).unwrap();
+
+        client
+            .execute_raw("query { ok }", None, None)
+            .await
+            .unwrap();
+        assert!(entries.lock().unwrap().is_empty());
+
+        wire_log.set_enabled(true);
+        client
+            .execute_raw("query { ok }", None, None)
+            .await
+            .unwrap();
+        let entry = entries.lock().unwrap().pop().unwrap();
+        assert_eq!(entry.method, "POST");
+        assert_eq!(entry.status, Some(200));
+        assert!(entry.request_body.contains("query { ok }"));
+        assert!(!entry.response_body.contains("secret-token"));
+        assert!(!entry.response_body.contains("hunter2"));
+        assert!(entry
+            .request_headers
+            .iter()
+            .any(|(name, value)| name == "x-infrahub-key" && value == crate::REDACTED));
+
+        wire_log.set_enabled(false);
+        client
+            .execute_raw("query { ok }", None, None)
+            .await
+            .unwrap();
+        assert!(entries.lock().unwrap().is_empty());
+    }
+}
@@ -2,7 +2,7 @@
 
 #![allow(non_snake_case, unused_imports, unused_assignments, clippy::field_reassign_with_default)]
 
-use infrahub::{BoxExtract, BoxFetch, BoxFutureResult, Client, DynPaginator, EdgePage, Error, Result};
+use infrahub::{BoxExtract, BoxFetch, BoxFutureResult, Client, DynPaginator, EdgePage, EdgePageInfo, Error, Result, Selection};
 use serde_json::Value;
 
 use crate::inputs::*;
@@ -648,7 +648,7 @@ impl<'a> BuiltinIPAddressClient<'a> {
         let vars = filters.map(|f| f.to_vars()).transpose()?.unwrap_or_else(|| Value::Object(serde_json::Map::new()));
         let query = r#"query BuiltinIPAddress($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $address__value: String, $address__values: [String], $address__isnull: Boolean, $address__source__id: ID, $address__owner__id: ID, $address__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $include_available: Boolean, $kinds: [String!], $ip_namespace__ids: [ID], $ip_namespace__isnull: Boolean, $ip_namespace__display_label__value: String, $ip_namespace__display_label__values: [String], $ip_namespace__display_label__isnull: Boolean, $ip_namespace__description__value: String, $ip_namespace__description__values: [String], $ip_namespace__description__source__id: ID, $ip_namespace__description__owner__id: ID, $ip_namespace__description__is_protected: Boolean, $ip_namespace__name__value: String, $ip_namespace__name__values: [String], $ip_namespace__name__source__id: ID, $ip_namespace__name__owner__id: ID, $ip_namespace__name__is_protected: Boolean, $ip_prefix__ids: [ID], $ip_prefix__isnull: Boolean, $ip_prefix__display_label__value: String, $ip_prefix__display_label__values: [String], $ip_prefix__display_label__isnull: Boolean, $ip_prefix__netmask__value: String, $ip_prefix__netmask__values: [String], $ip_prefix__netmask__source__id: ID, $ip_prefix__netmask__owner__id: ID, $ip_prefix__netmask__is_protected: Boolean, $ip_prefix__hostmask__value: String, $ip_prefix__hostmask__values: [String], $ip_prefix__hostmask__source__id: ID, $ip_prefix__hostmask__owner__id: ID, $ip_prefix__hostmask__is_protected: Boolean, $ip_prefix__is_top_level__value: Boolean, $ip_prefix__is_top_level__values: [Boolean], $ip_prefix__is_top_level__source__id: ID, $ip_prefix__is_top_level__owner__id: ID, $ip_prefix__is_top_level__is_protected: Boolean, $ip_prefix__utilization__value: BigInt, $ip_prefix__utilization__values: [BigInt], $ip_prefix__utilization__source__id: ID, $ip_prefix__utilization__owner__id: ID, $ip_prefix__utilization__is_protected: Boolean, $ip_prefix__is_pool__value: Boolean, $ip_prefix__is_pool__values: [Boolean], $ip_prefix__is_pool__source__id: ID, $ip_prefix__is_pool__owner__id: ID, $ip_prefix__is_pool__is_protected: Boolean, $ip_prefix__broadcast_address__value: String, $ip_prefix__broadcast_address__values: [String], $ip_prefix__broadcast_address__source__id: ID, $ip_prefix__broadcast_address__owner__id: ID, $ip_prefix__broadcast_address__is_protected: Boolean, $ip_prefix__member_type__value: String, $ip_prefix__member_type__values: [String], $ip_prefix__member_type__source__id: ID, $ip_prefix__member_type__owner__id: ID, $ip_prefix__member_type__is_protected: Boolean, $ip_prefix__network_address__value: String, $ip_prefix__network_address__values: [String], $ip_prefix__network_address__source__id: ID, $ip_prefix__network_address__owner__id: ID, $ip_prefix__network_address__is_protected: Boolean, $ip_prefix__prefix__value: String, $ip_prefix__prefix__values: [String], $ip_prefix__prefix__source__id: ID, $ip_prefix__prefix__owner__id: ID, $ip_prefix__prefix__is_protected: Boolean, $ip_prefix__description__value: String, $ip_prefix__description__values: [String], $ip_prefix__description__source__id: ID, $ip_prefix__description__owner__id: ID, $ip_prefix__description__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String], $profiles__ids: [ID], $profiles__isnull: Boolean, $profiles__display_label__value: String, $profiles__display_label__values: [String], $profiles__display_label__isnull: Boolean, $profiles__profile_name__value: String, $profiles__profile_name__values: [String], $profiles__profile_name__source__id: ID, $profiles__profile_name__owner__id: ID, $profiles__profile_name__is_protected: Boolean, $profiles__profile_priority__value: BigInt, $profiles__profile_priority__values: [BigInt], $profiles__profile_priority__source__id: ID, $profiles__profile_priority__owner__id: ID, $profiles__profile_priority__is_protected: Boolean) { BuiltinIPAddress(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, address__value: $address__value, address__values: $address__values, address__isnull: $address__isnull, address__source__id: $address__source__id, address__owner__id: $address__owner__id, address__is_protected: $address__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, include_available: $include_available, kinds: $kinds, ip_namespace__ids: $ip_namespace__ids, ip_namespace__isnull: $ip_namespace__isnull, ip_namespace__display_label__value: $ip_namespace__display_label__value, ip_namespace__display_label__values: $ip_namespace__display_label__values, ip_namespace__display_label__isnull: $ip_namespace__display_label__isnull, ip_namespace__description__value: $ip_namespace__description__value, ip_namespace__description__values: $ip_namespace__description__values, ip_namespace__description__source__id: $ip_namespace__description__source__id, ip_namespace__description__owner__id: $ip_namespace__description__owner__id, ip_namespace__description__is_protected: $ip_namespace__description__is_protected, ip_namespace__name__value: $ip_namespace__name__value, ip_namespace__name__values: $ip_namespace__name__values, ip_namespace__name__source__id: $ip_namespace__name__source__id, ip_namespace__name__owner__id: $ip_namespace__name__owner__id, ip_namespace__name__is_protected: $ip_namespace__name__is_protected, ip_prefix__ids: $ip_prefix__ids, ip_prefix__isnull: $ip_prefix__isnull, ip_prefix__display_label__value: $ip_prefix__display_label__value, ip_prefix__display_label__values: $ip_prefix__display_label__values, ip_prefix__display_label__isnull: $ip_prefix__display_label__isnull, ip_prefix__netmask__value: $ip_prefix__netmask__value, ip_prefix__netmask__values: $ip_prefix__netmask__values, ip_prefix__netmask__source__id: $ip_prefix__netmask__source__id, ip_prefix__netmask__owner__id: $ip_prefix__netmask__owner__id, ip_prefix__netmask__is_protected: $ip_prefix__netmask__is_protected, ip_prefix__hostmask__value: $ip_prefix__hostmask__value, ip_prefix__hostmask__values: $ip_prefix__hostmask__values, ip_prefix__hostmask__source__id: $ip_prefix__hostmask__source__id, ip_prefix__hostmask__owner__id: $ip_prefix__hostmask__owner__id, ip_prefix__hostmask__is_protected: $ip_prefix__hostmask__is_protected, ip_prefix__is_top_level__value: $ip_prefix__is_top_level__value, ip_prefix__is_top_level__values: $ip_prefix__is_top_level__values, ip_prefix__is_top_level__source__id: $ip_prefix__is_top_level__source__id, ip_prefix__is_top_level__owner__id: $ip_prefix__is_top_level__owner__id, ip_prefix__is_top_level__is_protected: $ip_prefix__is_top_level__is_protected, ip_prefix__utilization__value: $ip_prefix__utilization__value, ip_prefix__utilization__values: $ip_prefix__utilization__values, ip_prefix__utilization__source__id: $ip_prefix__utilization__source__id, ip_prefix__utilization__owner__id: $ip_prefix__utilization__owner__id, ip_prefix__utilization__is_protected: $ip_prefix__utilization__is_protected, ip_prefix__is_pool__value: $ip_prefix__is_pool__value, ip_prefix__is_pool__values: $ip_prefix__is_pool__values, ip_prefix__is_pool__source__id: $ip_prefix__is_pool__source__id, ip_prefix__is_pool__owner__id: $ip_prefix__is_pool__owner__id, ip_prefix__is_pool__is_protected: $ip_prefix__is_pool__is_protected, ip_prefix__broadcast_address__value: $ip_prefix__broadcast_address__value, ip_prefix__broadcast_address__values: $ip_prefix__broadcast_address__values, ip_prefix__broadcast_address__source__id: $ip_prefix__broadcast_address__source__id, ip_prefix__broadcast_address__owner__id: $ip_prefix__broadcast_address__owner__id, ip_prefix__broadcast_address__is_protected: $ip_prefix__broadcast_address__is_protected, ip_prefix__member_type__value: $ip_prefix__member_type__value, ip_prefix__member_type__values: $ip_prefix__member_type__values, ip_prefix__member_type__source__id: $ip_prefix__member_type__source__id, ip_prefix__member_type__owner__id: $ip_prefix__member_type__owner__id, ip_prefix__member_type__is_protected: $ip_prefix__member_type__is_protected, ip_prefix__network_address__value: $ip_prefix__network_address__value, ip_prefix__network_address__values: $ip_prefix__network_address__values, ip_prefix__network_address__source__id: $ip_prefix__network_address__source__id, ip_prefix__network_address__owner__id: $ip_prefix__network_address__owner__id, ip_prefix__network_address__is_protected: $ip_prefix__network_address__is_protected, ip_prefix__prefix__value: $ip_prefix__prefix__value, ip_prefix__prefix__values: $ip_prefix__prefix__values, ip_prefix__prefix__source__id: $ip_prefix__prefix__source__id, ip_prefix__prefix__owner__id: $ip_prefix__prefix__owner__id, ip_prefix__prefix__is_protected: $ip_prefix__prefix__is_protected, ip_prefix__description__value: $ip_prefix__description__value, ip_prefix__description__values: $ip_prefix__description__values, ip_prefix__description__source__id: $ip_prefix__description__source__id, ip_prefix__description__owner__id: $ip_prefix__description__owner__id, ip_prefix__description__is_protected: $ip_prefix__description__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values, profiles__ids: $profiles__ids, profiles__isnull: $profiles__isnull, profiles__display_label__value: $profiles__display_label__value, profiles__display_label__values: $profiles__display_label__values, profiles__display_label__isnull: $profiles__display_label__isnull, profiles__profile_name__value: $profiles__profile_name__value, profiles__profile_name__values: $profiles__profile_name__values, profiles__profile_name__source__id: $profiles__profile_name__source__id, profiles__profile_name__owner__id: $profiles__profile_name__owner__id, profiles__profile_name__is_protected: $profiles__profile_name__is_protected, profiles__profile_priority__value: $profiles__profile_priority__value, profiles__profile_priority__values: $profiles__profile_priority__values, profiles__profile_priority__source__id: $profiles__profile_priority__source__id, profiles__profile_priority__owner__id: $profiles__profile_priority__owner__id, profiles__profile_priority__is_protected: $profiles__profile_priority__is_protected) { count edges { node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
         let response = self.client.execute::<BuiltinIPAddressResponse>(query, Some(vars), request_branch).await?;
-        let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+        let data = response.into_data()?;
         let mut items = Vec::new();
         for edge in data.builtin_ip_address.edges {
             if let Some(node) = edge.node {
@@ -676,11 +676,12 @@ impl<'a> BuiltinIPAddressClient<'a> {
             Box::pin(async move {
             let vars = page_filters.to_vars()?;
                 let response = client.execute::<BuiltinIPAddressResponse>(query, Some(vars), branch.as_deref()).await?;
-                let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+                let data = response.into_data()?;
                 Ok((data, current_offset))
             })
         });
         let extract: BoxExtract<'a, serde_json::Value, String, (BuiltinIPAddressResponse, i64)> = Box::new(move |(data, current_offset): (BuiltinIPAddressResponse, i64)| -> Result<EdgePage<serde_json::Value, String>> {
+            let total_count = Some(data.builtin_ip_address.count);
             let mut items = Vec::new();
             let mut next: Option<String> = None;
             for edge in data.builtin_ip_address.edges {
@@ -691,9 +692,12 @@ impl<'a> BuiltinIPAddressClient<'a> {
             if !items.is_empty() {
                 next = Some((current_offset + items.len() as i64).to_string());
             }
-            Ok(EdgePage { nodes: items, next_cursor: next })
+            let has_next_page = total_count.map(|total| current_offset + (items.len() as i64) < total);
+            let end_cursor = None;
+            let info = EdgePageInfo { has_next_page, end_cursor, total_count };
+            Ok(EdgePage::new(items, next).with_info(info))
         });
-        infrahub::Paginator::new(fetch, extract)
+        infrahub::Paginator::new(fetch, extract).with_total_count(|(data, _)| Some(data.builtin_ip_address.count))
     }
 
     pub async fn get_by_id(&self, id: impl Into<String>, request_branch: Option<&str>) -> Result<Option<serde_json::Value>> {
@@ -1317,7 +1321,7 @@ impl<'a> BuiltinIPNamespaceClient<'a> {
         let vars = filters.map(|f| f.to_vars()).transpose()?.unwrap_or_else(|| Value::Object(serde_json::Map::new()));
         let query = r#"query BuiltinIPNamespace($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $name__value: String, $name__values: [String], $name__isnull: Boolean, $name__source__id: ID, $name__owner__id: ID, $name__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $ip_prefixes__ids: [ID], $ip_prefixes__isnull: Boolean, $ip_prefixes__display_label__value: String, $ip_prefixes__display_label__values: [String], $ip_prefixes__display_label__isnull: Boolean, $ip_prefixes__netmask__value: String, $ip_prefixes__netmask__values: [String], $ip_prefixes__netmask__source__id: ID, $ip_prefixes__netmask__owner__id: ID, $ip_prefixes__netmask__is_protected: Boolean, $ip_prefixes__hostmask__value: String, $ip_prefixes__hostmask__values: [String], $ip_prefixes__hostmask__source__id: ID, $ip_prefixes__hostmask__owner__id: ID, $ip_prefixes__hostmask__is_protected: Boolean, $ip_prefixes__is_top_level__value: Boolean, $ip_prefixes__is_top_level__values: [Boolean], $ip_prefixes__is_top_level__source__id: ID, $ip_prefixes__is_top_level__owner__id: ID, $ip_prefixes__is_top_level__is_protected: Boolean, $ip_prefixes__utilization__value: BigInt, $ip_prefixes__utilization__values: [BigInt], $ip_prefixes__utilization__source__id: ID, $ip_prefixes__utilization__owner__id: ID, $ip_prefixes__utilization__is_protected: Boolean, $ip_prefixes__is_pool__value: Boolean, $ip_prefixes__is_pool__values: [Boolean], $ip_prefixes__is_pool__source__id: ID, $ip_prefixes__is_pool__owner__id: ID, $ip_prefixes__is_pool__is_protected: Boolean, $ip_prefixes__broadcast_address__value: String, $ip_prefixes__broadcast_address__values: [String], $ip_prefixes__broadcast_address__source__id: ID, $ip_prefixes__broadcast_address__owner__id: ID, $ip_prefixes__broadcast_address__is_protected: Boolean, $ip_prefixes__member_type__value: String, $ip_prefixes__member_type__values: [String], $ip_prefixes__member_type__source__id: ID, $ip_prefixes__member_type__owner__id: ID, $ip_prefixes__member_type__is_protected: Boolean, $ip_prefixes__network_address__value: String, $ip_prefixes__network_address__values: [String], $ip_prefixes__network_address__source__id: ID, $ip_prefixes__network_address__owner__id: ID, $ip_prefixes__network_address__is_protected: Boolean, $ip_prefixes__prefix__value: String, $ip_prefixes__prefix__values: [String], $ip_prefixes__prefix__source__id: ID, $ip_prefixes__prefix__owner__id: ID, $ip_prefixes__prefix__is_protected: Boolean, $ip_prefixes__description__value: String, $ip_prefixes__description__values: [String], $ip_prefixes__description__source__id: ID, $ip_prefixes__description__owner__id: ID, $ip_prefixes__description__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String], $ip_addresses__ids: [ID], $ip_addresses__isnull: Boolean, $ip_addresses__display_label__value: String, $ip_addresses__display_label__values: [String], $ip_addresses__display_label__isnull: Boolean, $ip_addresses__address__value: String, $ip_addresses__address__values: [String], $ip_addresses__address__source__id: ID, $ip_addresses__address__owner__id: ID, $ip_addresses__address__is_protected: Boolean, $ip_addresses__description__value: String, $ip_addresses__description__values: [String], $ip_addresses__description__source__id: ID, $ip_addresses__description__owner__id: ID, $ip_addresses__description__is_protected: Boolean, $profiles__ids: [ID], $profiles__isnull: Boolean, $profiles__display_label__value: String, $profiles__display_label__values: [String], $profiles__display_label__isnull: Boolean, $profiles__profile_name__value: String, $profiles__profile_name__values: [String], $profiles__profile_name__source__id: ID, $profiles__profile_name__owner__id: ID, $profiles__profile_name__is_protected: Boolean, $profiles__profile_priority__value: BigInt, $profiles__profile_priority__values: [BigInt], $profiles__profile_priority__source__id: ID, $profiles__profile_priority__owner__id: ID, $profiles__profile_priority__is_protected: Boolean) { BuiltinIPNamespace(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, name__value: $name__value, name__values: $name__values, name__isnull: $name__isnull, name__source__id: $name__source__id, name__owner__id: $name__owner__id, name__is_protected: $name__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, ip_prefixes__ids: $ip_prefixes__ids, ip_prefixes__isnull: $ip_prefixes__isnull, ip_prefixes__display_label__value: $ip_prefixes__display_label__value, ip_prefixes__display_label__values: $ip_prefixes__display_label__values, ip_prefixes__display_label__isnull: $ip_prefixes__display_label__isnull, ip_prefixes__netmask__value: $ip_prefixes__netmask__value, ip_prefixes__netmask__values: $ip_prefixes__netmask__values, ip_prefixes__netmask__source__id: $ip_prefixes__netmask__source__id, ip_prefixes__netmask__owner__id: $ip_prefixes__netmask__owner__id, ip_prefixes__netmask__is_protected: $ip_prefixes__netmask__is_protected, ip_prefixes__hostmask__value: $ip_prefixes__hostmask__value, ip_prefixes__hostmask__values: $ip_prefixes__hostmask__values, ip_prefixes__hostmask__source__id: $ip_prefixes__hostmask__source__id, ip_prefixes__hostmask__owner__id: $ip_prefixes__hostmask__owner__id, ip_prefixes__hostmask__is_protected: $ip_prefixes__hostmask__is_protected, ip_prefixes__is_top_level__value: $ip_prefixes__is_top_level__value, ip_prefixes__is_top_level__values: $ip_prefixes__is_top_level__values, ip_prefixes__is_top_level__source__id: $ip_prefixes__is_top_level__source__id, ip_prefixes__is_top_level__owner__id: $ip_prefixes__is_top_level__owner__id, ip_prefixes__is_top_level__is_protected: $ip_prefixes__is_top_level__is_protected, ip_prefixes__utilization__value: $ip_prefixes__utilization__value, ip_prefixes__utilization__values: $ip_prefixes__utilization__values, ip_prefixes__utilization__source__id: $ip_prefixes__utilization__source__id, ip_prefixes__utilization__owner__id: $ip_prefixes__utilization__owner__id, ip_prefixes__utilization__is_protected: $ip_prefixes__utilization__is_protected, ip_prefixes__is_pool__value: $ip_prefixes__is_pool__value, ip_prefixes__is_pool__values: $ip_prefixes__is_pool__values, ip_prefixes__is_pool__source__id: $ip_prefixes__is_pool__source__id, ip_prefixes__is_pool__owner__id: $ip_prefixes__is_pool__owner__id, ip_prefixes__is_pool__is_protected: $ip_prefixes__is_pool__is_protected, ip_prefixes__broadcast_address__value: $ip_prefixes__broadcast_address__value, ip_prefixes__broadcast_address__values: $ip_prefixes__broadcast_address__values, ip_prefixes__broadcast_address__source__id: $ip_prefixes__broadcast_address__source__id, ip_prefixes__broadcast_address__owner__id: $ip_prefixes__broadcast_address__owner__id, ip_prefixes__broadcast_address__is_protected: $ip_prefixes__broadcast_address__is_protected, ip_prefixes__member_type__value: $ip_prefixes__member_type__value, ip_prefixes__member_type__values: $ip_prefixes__member_type__values, ip_prefixes__member_type__source__id: $ip_prefixes__member_type__source__id, ip_prefixes__member_type__owner__id: $ip_prefixes__member_type__owner__id, ip_prefixes__member_type__is_protected: $ip_prefixes__member_type__is_protected, ip_prefixes__network_address__value: $ip_prefixes__network_address__value, ip_prefixes__network_address__values: $ip_prefixes__network_address__values, ip_prefixes__network_address__source__id: $ip_prefixes__network_address__source__id, ip_prefixes__network_address__owner__id: $ip_prefixes__network_address__owner__id, ip_prefixes__network_address__is_protected: $ip_prefixes__network_address__is_protected, ip_prefixes__prefix__value: $ip_prefixes__prefix__value, ip_prefixes__prefix__values: $ip_prefixes__prefix__values, ip_prefixes__prefix__source__id: $ip_prefixes__prefix__source__id, ip_prefixes__prefix__owner__id: $ip_prefixes__prefix__owner__id, ip_prefixes__prefix__is_protected: $ip_prefixes__prefix__is_protected, ip_prefixes__description__value: $ip_prefixes__description__value, ip_prefixes__description__values: $ip_prefixes__description__values, ip_prefixes__description__source__id: $ip_prefixes__description__source__id, ip_prefixes__description__owner__id: $ip_prefixes__description__owner__id, ip_prefixes__description__is_protected: $ip_prefixes__description__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values, ip_addresses__ids: $ip_addresses__ids, ip_addresses__isnull: $ip_addresses__isnull, ip_addresses__display_label__value: $ip_addresses__display_label__value, ip_addresses__display_label__values: $ip_addresses__display_label__values, ip_addresses__display_label__isnull: $ip_addresses__display_label__isnull, ip_addresses__address__value: $ip_addresses__address__value, ip_addresses__address__values: $ip_addresses__address__values, ip_addresses__address__source__id: $ip_addresses__address__source__id, ip_addresses__address__owner__id: $ip_addresses__address__owner__id, ip_addresses__address__is_protected: $ip_addresses__address__is_protected, ip_addresses__description__value: $ip_addresses__description__value, ip_addresses__description__values: $ip_addresses__description__values, ip_addresses__description__source__id: $ip_addresses__description__source__id, ip_addresses__description__owner__id: $ip_addresses__description__owner__id, ip_addresses__description__is_protected: $ip_addresses__description__is_protected, profiles__ids: $profiles__ids, profiles__isnull: $profiles__isnull, profiles__display_label__value: $profiles__display_label__value, profiles__display_label__values: $profiles__display_label__values, profiles__display_label__isnull: $profiles__display_label__isnull, profiles__profile_name__value: $profiles__profile_name__value, profiles__profile_name__values: $profiles__profile_name__values, profiles__profile_name__source__id: $profiles__profile_name__source__id, profiles__profile_name__owner__id: $profiles__profile_name__owner__id, profiles__profile_name__is_protected: $profiles__profile_name__is_protected, profiles__profile_priority__value: $profiles__profile_priority__value, profiles__profile_priority__values: $profiles__profile_priority__values, profiles__profile_priority__source__id: $profiles__profile_priority__source__id, profiles__profile_priority__owner__id: $profiles__profile_priority__owner__id, profiles__profile_priority__is_protected: $profiles__profile_priority__is_protected) { count edges { node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
         let response = self.client.execute::<BuiltinIPNamespaceResponse>(query, Some(vars), request_branch).await?;
-        let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+        let data = response.into_data()?;
         let mut items = Vec::new();
         for edge in data.builtin_ip_namespace.edges {
             if let Some(node) = edge.node {
@@ -1345,11 +1349,12 @@ impl<'a> BuiltinIPNamespaceClient<'a> {
             Box::pin(async move {
             let vars = page_filters.to_vars()?;
                 let response = client.execute::<BuiltinIPNamespaceResponse>(query, Some(vars), branch.as_deref()).await?;
-                let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+                let data = response.into_data()?;
                 Ok((data, current_offset))
             })
         });
         let extract: BoxExtract<'a, serde_json::Value, String, (BuiltinIPNamespaceResponse, i64)> = Box::new(move |(data, current_offset): (BuiltinIPNamespaceResponse, i64)| -> Result<EdgePage<serde_json::Value, String>> {
+            let total_count = Some(data.builtin_ip_namespace.count);
             let mut items = Vec::new();
             let mut next: Option<String> = None;
             for edge in data.builtin_ip_namespace.edges {
@@ -1360,9 +1365,12 @@ impl<'a> BuiltinIPNamespaceClient<'a> {
             if !items.is_empty() {
                 next = Some((current_offset + items.len() as i64).to_string());
             }
-            Ok(EdgePage { nodes: items, next_cursor: next })
+            let has_next_page = total_count.map(|total| current_offset + (items.len() as i64) < total);
+            let end_cursor = None;
+            let info = EdgePageInfo { has_next_page, end_cursor, total_count };
+            Ok(EdgePage::new(items, next).with_info(info))
         });
-        infrahub::Paginator::new(fetch, extract)
+        infrahub::Paginator::new(fetch, extract).with_total_count(|(data, _)| Some(data.builtin_ip_namespace.count))
     }
 
     pub async fn get_by_id(&self, id: impl Into<String>, request_branch: Option<&str>) -> Result<Option<serde_json::Value>> {
@@ -2562,7 +2570,7 @@ impl<'a> BuiltinIPPrefixClient<'a> {
         let vars = filters.map(|f| f.to_vars()).transpose()?.unwrap_or_else(|| Value::Object(serde_json::Map::new()));
         let query = r#"query BuiltinIPPrefix($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $netmask__value: String, $netmask__values: [String], $netmask__isnull: Boolean, $netmask__source__id: ID, $netmask__owner__id: ID, $netmask__is_protected: Boolean, $hostmask__value: String, $hostmask__values: [String], $hostmask__isnull: Boolean, $hostmask__source__id: ID, $hostmask__owner__id: ID, $hostmask__is_protected: Boolean, $is_top_level__value: Boolean, $is_top_level__values: [Boolean], $is_top_level__isnull: Boolean, $is_top_level__source__id: ID, $is_top_level__owner__id: ID, $is_top_level__is_protected: Boolean, $utilization__value: BigInt, $utilization__values: [BigInt], $utilization__isnull: Boolean, $utilization__source__id: ID, $utilization__owner__id: ID, $utilization__is_protected: Boolean, $is_pool__value: Boolean, $is_pool__values: [Boolean], $is_pool__isnull: Boolean, $is_pool__source__id: ID, $is_pool__owner__id: ID, $is_pool__is_protected: Boolean, $broadcast_address__value: String, $broadcast_address__values: [String], $broadcast_address__isnull: Boolean, $broadcast_address__source__id: ID, $broadcast_address__owner__id: ID, $broadcast_address__is_protected: Boolean, $member_type__value: String, $member_type__values: [String], $member_type__isnull: Boolean, $member_type__source__id: ID, $member_type__owner__id: ID, $member_type__is_protected: Boolean, $network_address__value: String, $network_address__values: [String], $network_address__isnull: Boolean, $network_address__source__id: ID, $network_address__owner__id: ID, $network_address__is_protected: Boolean, $prefix__value: String, $prefix__values: [String], $prefix__isnull: Boolean, $prefix__source__id: ID, $prefix__owner__id: ID, $prefix__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $include_available: Boolean, $kinds: [String!], $ip_namespace__ids: [ID], $ip_namespace__isnull: Boolean, $ip_namespace__display_label__value: String, $ip_namespace__display_label__values: [String], $ip_namespace__display_label__isnull: Boolean, $ip_namespace__description__value: String, $ip_namespace__description__values: [String], $ip_namespace__description__source__id: ID, $ip_namespace__description__owner__id: ID, $ip_namespace__description__is_protected: Boolean, $ip_namespace__name__value: String, $ip_namespace__name__values: [String], $ip_namespace__name__source__id: ID, $ip_namespace__name__owner__id: ID, $ip_namespace__name__is_protected: Boolean, $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String], $ip_addresses__ids: [ID], $ip_addresses__isnull: Boolean, $ip_addresses__display_label__value: String, $ip_addresses__display_label__values: [String], $ip_addresses__display_label__isnull: Boolean, $ip_addresses__address__value: String, $ip_addresses__address__values: [String], $ip_addresses__address__source__id: ID, $ip_addresses__address__owner__id: ID, $ip_addresses__address__is_protected: Boolean, $ip_addresses__description__value: String, $ip_addresses__description__values: [String], $ip_addresses__description__source__id: ID, $ip_addresses__description__owner__id: ID, $ip_addresses__description__is_protected: Boolean, $children__ids: [ID], $children__isnull: Boolean, $children__display_label__value: String, $children__display_label__values: [String], $children__display_label__isnull: Boolean, $children__netmask__value: String, $children__netmask__values: [String], $children__netmask__source__id: ID, $children__netmask__owner__id: ID, $children__netmask__is_protected: Boolean, $children__hostmask__value: String, $children__hostmask__values: [String], $children__hostmask__source__id: ID, $children__hostmask__owner__id: ID, $children__hostmask__is_protected: Boolean, $children__is_top_level__value: Boolean, $children__is_top_level__values: [Boolean], $children__is_top_level__source__id: ID, $children__is_top_level__owner__id: ID, $children__is_top_level__is_protected: Boolean, $children__utilization__value: BigInt, $children__utilization__values: [BigInt], $children__utilization__source__id: ID, $children__utilization__owner__id: ID, $children__utilization__is_protected: Boolean, $children__is_pool__value: Boolean, $children__is_pool__values: [Boolean], $children__is_pool__source__id: ID, $children__is_pool__owner__id: ID, $children__is_pool__is_protected: Boolean, $children__broadcast_address__value: String, $children__broadcast_address__values: [String], $children__broadcast_address__source__id: ID, $children__broadcast_address__owner__id: ID, $children__broadcast_address__is_protected: Boolean, $children__member_type__value: String, $children__member_type__values: [String], $children__member_type__source__id: ID, $children__member_type__owner__id: ID, $children__member_type__is_protected: Boolean, $children__network_address__value: String, $children__network_address__values: [String], $children__network_address__source__id: ID, $children__network_address__owner__id: ID, $children__network_address__is_protected: Boolean, $children__prefix__value: String, $children__prefix__values: [String], $children__prefix__source__id: ID, $children__prefix__owner__id: ID, $children__prefix__is_protected: Boolean, $children__description__value: String, $children__description__values: [String], $children__description__source__id: ID, $children__description__owner__id: ID, $children__description__is_protected: Boolean, $resource_pool__ids: [ID], $resource_pool__isnull: Boolean, $resource_pool__display_label__value: String, $resource_pool__display_label__values: [String], $resource_pool__display_label__isnull: Boolean, $resource_pool__default_prefix_length__value: BigInt, $resource_pool__default_prefix_length__values: [BigInt], $resource_pool__default_prefix_length__source__id: ID, $resource_pool__default_prefix_length__owner__id: ID, $resource_pool__default_prefix_length__is_protected: Boolean, $resource_pool__default_address_type__value: String, $resource_pool__default_address_type__values: [String], $resource_pool__default_address_type__source__id: ID, $resource_pool__default_address_type__owner__id: ID, $resource_pool__default_address_type__is_protected: Boolean, $resource_pool__name__value: String, $resource_pool__name__values: [String], $resource_pool__name__source__id: ID, $resource_pool__name__owner__id: ID, $resource_pool__name__is_protected: Boolean, $resource_pool__description__value: String, $resource_pool__description__values: [String], $resource_pool__description__source__id: ID, $resource_pool__description__owner__id: ID, $resource_pool__description__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $parent__ids: [ID], $parent__isnull: Boolean, $parent__display_label__value: String, $parent__display_label__values: [String], $parent__display_label__isnull: Boolean, $parent__netmask__value: String, $parent__netmask__values: [String], $parent__netmask__source__id: ID, $parent__netmask__owner__id: ID, $parent__netmask__is_protected: Boolean, $parent__hostmask__value: String, $parent__hostmask__values: [String], $parent__hostmask__source__id: ID, $parent__hostmask__owner__id: ID, $parent__hostmask__is_protected: Boolean, $parent__is_top_level__value: Boolean, $parent__is_top_level__values: [Boolean], $parent__is_top_level__source__id: ID, $parent__is_top_level__owner__id: ID, $parent__is_top_level__is_protected: Boolean, $parent__utilization__value: BigInt, $parent__utilization__values: [BigInt], $parent__utilization__source__id: ID, $parent__utilization__owner__id: ID, $parent__utilization__is_protected: Boolean, $parent__is_pool__value: Boolean, $parent__is_pool__values: [Boolean], $parent__is_pool__source__id: ID, $parent__is_pool__owner__id: ID, $parent__is_pool__is_protected: Boolean, $parent__broadcast_address__value: String, $parent__broadcast_address__values: [String], $parent__broadcast_address__source__id: ID, $parent__broadcast_address__owner__id: ID, $parent__broadcast_address__is_protected: Boolean, $parent__member_type__value: String, $parent__member_type__values: [String], $parent__member_type__source__id: ID, $parent__member_type__owner__id: ID, $parent__member_type__is_protected: Boolean, $parent__network_address__value: String, $parent__network_address__values: [String], $parent__network_address__source__id: ID, $parent__network_address__owner__id: ID, $parent__network_address__is_protected: Boolean, $parent__prefix__value: String, $parent__prefix__values: [String], $parent__prefix__source__id: ID, $parent__prefix__owner__id: ID, $parent__prefix__is_protected: Boolean, $parent__description__value: String, $parent__description__values: [String], $parent__description__source__id: ID, $parent__description__owner__id: ID, $parent__description__is_protected: Boolean, $profiles__ids: [ID], $profiles__isnull: Boolean, $profiles__display_label__value: String, $profiles__display_label__values: [String], $profiles__display_label__isnull: Boolean, $profiles__profile_name__value: String, $profiles__profile_name__values: [String], $profiles__profile_name__source__id: ID, $profiles__profile_name__owner__id: ID, $profiles__profile_name__is_protected: Boolean, $profiles__profile_priority__value: BigInt, $profiles__profile_priority__values: [BigInt], $profiles__profile_priority__source__id: ID, $profiles__profile_priority__owner__id: ID, $profiles__profile_priority__is_protected: Boolean) { BuiltinIPPrefix(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, netmask__value: $netmask__value, netmask__values: $netmask__values, netmask__isnull: $netmask__isnull, netmask__source__id: $netmask__source__id, netmask__owner__id: $netmask__owner__id, netmask__is_protected: $netmask__is_protected, hostmask__value: $hostmask__value, hostmask__values: $hostmask__values, hostmask__isnull: $hostmask__isnull, hostmask__source__id: $hostmask__source__id, hostmask__owner__id: $hostmask__owner__id, hostmask__is_protected: $hostmask__is_protected, is_top_level__value: $is_top_level__value, is_top_level__values: $is_top_level__values, is_top_level__isnull: $is_top_level__isnull, is_top_level__source__id: $is_top_level__source__id, is_top_level__owner__id: $is_top_level__owner__id, is_top_level__is_protected: $is_top_level__is_protected, utilization__value: $utilization__value, utilization__values: $utilization__values, utilization__isnull: $utilization__isnull, utilization__source__id: $utilization__source__id, utilization__owner__id: $utilization__owner__id, utilization__is_protected: $utilization__is_protected, is_pool__value: $is_pool__value, is_pool__values: $is_pool__values, is_pool__isnull: $is_pool__isnull, is_pool__source__id: $is_pool__source__id, is_pool__owner__id: $is_pool__owner__id, is_pool__is_protected: $is_pool__is_protected, broadcast_address__value: $broadcast_address__value, broadcast_address__values: $broadcast_address__values, broadcast_address__isnull: $broadcast_address__isnull, broadcast_address__source__id: $broadcast_address__source__id, broadcast_address__owner__id: $broadcast_address__owner__id, broadcast_address__is_protected: $broadcast_address__is_protected, member_type__value: $member_type__value, member_type__values: $member_type__values, member_type__isnull: $member_type__isnull, member_type__source__id: $member_type__source__id, member_type__owner__id: $member_type__owner__id, member_type__is_protected: $member_type__is_protected, network_address__value: $network_address__value, network_address__values: $network_address__values, network_address__isnull: $network_address__isnull, network_address__source__id: $network_address__source__id, network_address__owner__id: $network_address__owner__id, network_address__is_protected: $network_address__is_protected, prefix__value: $prefix__value, prefix__values: $prefix__values, prefix__isnull: $prefix__isnull, prefix__source__id: $prefix__source__id, prefix__owner__id: $prefix__owner__id, prefix__is_protected: $prefix__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, include_available: $include_available, kinds: $kinds, ip_namespace__ids: $ip_namespace__ids, ip_namespace__isnull: $ip_namespace__isnull, ip_namespace__display_label__value: $ip_namespace__display_label__value, ip_namespace__display_label__values: $ip_namespace__display_label__values, ip_namespace__display_label__isnull: $ip_namespace__display_label__isnull, ip_namespace__description__value: $ip_namespace__description__value, ip_namespace__description__values: $ip_namespace__description__values, ip_namespace__description__source__id: $ip_namespace__description__source__id, ip_namespace__description__owner__id: $ip_namespace__description__owner__id, ip_namespace__description__is_protected: $ip_namespace__description__is_protected, ip_namespace__name__value: $ip_namespace__name__value, ip_namespace__name__values: $ip_namespace__name__values, ip_namespace__name__source__id: $ip_namespace__name__source__id, ip_namespace__name__owner__id: $ip_namespace__name__owner__id, ip_namespace__name__is_protected: $ip_namespace__name__is_protected, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values, ip_addresses__ids: $ip_addresses__ids, ip_addresses__isnull: $ip_addresses__isnull, ip_addresses__display_label__value: $ip_addresses__display_label__value, ip_addresses__display_label__values: $ip_addresses__display_label__values, ip_addresses__display_label__isnull: $ip_addresses__display_label__isnull, ip_addresses__address__value: $ip_addresses__address__value, ip_addresses__address__values: $ip_addresses__address__values, ip_addresses__address__source__id: $ip_addresses__address__source__id, ip_addresses__address__owner__id: $ip_addresses__address__owner__id, ip_addresses__address__is_protected: $ip_addresses__address__is_protected, ip_addresses__description__value: $ip_addresses__description__value, ip_addresses__description__values: $ip_addresses__description__values, ip_addresses__description__source__id: $ip_addresses__description__source__id, ip_addresses__description__owner__id: $ip_addresses__description__owner__id, ip_addresses__description__is_protected: $ip_addresses__description__is_protected, children__ids: $children__ids, children__isnull: $children__isnull, children__display_label__value: $children__display_label__value, children__display_label__values: $children__display_label__values, children__display_label__isnull: $children__display_label__isnull, children__netmask__value: $children__netmask__value, children__netmask__values: $children__netmask__values, children__netmask__source__id: $children__netmask__source__id, children__netmask__owner__id: $children__netmask__owner__id, children__netmask__is_protected: $children__netmask__is_protected, children__hostmask__value: $children__hostmask__value, children__hostmask__values: $children__hostmask__values, children__hostmask__source__id: $children__hostmask__source__id, children__hostmask__owner__id: $children__hostmask__owner__id, children__hostmask__is_protected: $children__hostmask__is_protected, children__is_top_level__value: $children__is_top_level__value, children__is_top_level__values: $children__is_top_level__values, children__is_top_level__source__id: $children__is_top_level__source__id, children__is_top_level__owner__id: $children__is_top_level__owner__id, children__is_top_level__is_protected: $children__is_top_level__is_protected, children__utilization__value: $children__utilization__value, children__utilization__values: $children__utilization__values, children__utilization__source__id: $children__utilization__source__id, children__utilization__owner__id: $children__utilization__owner__id, children__utilization__is_protected: $children__utilization__is_protected, children__is_pool__value: $children__is_pool__value, children__is_pool__values: $children__is_pool__values, children__is_pool__source__id: $children__is_pool__source__id, children__is_pool__owner__id: $children__is_pool__owner__id, children__is_pool__is_protected: $children__is_pool__is_protected, children__broadcast_address__value: $children__broadcast_address__value, children__broadcast_address__values: $children__broadcast_address__values, children__broadcast_address__source__id: $children__broadcast_address__source__id, children__broadcast_address__owner__id: $children__broadcast_address__owner__id, children__broadcast_address__is_protected: $children__broadcast_address__is_protected, children__member_type__value: $children__member_type__value, children__member_type__values: $children__member_type__values, children__member_type__source__id: $children__member_type__source__id, children__member_type__owner__id: $children__member_type__owner__id, children__member_type__is_protected: $children__member_type__is_protected, children__network_address__value: $children__network_address__value, children__network_address__values: $children__network_address__values, children__network_address__source__id: $children__network_address__source__id, children__network_address__owner__id: $children__network_address__owner__id, children__network_address__is_protected: $children__network_address__is_protected, children__prefix__value: $children__prefix__value, children__prefix__values: $children__prefix__values, children__prefix__source__id: $children__prefix__source__id, children__prefix__owner__id: $children__prefix__owner__id, children__prefix__is_protected: $children__prefix__is_protected, children__description__value: $children__description__value, children__description__values: $children__description__values, children__description__source__id: $children__description__source__id, children__description__owner__id: $children__description__owner__id, children__description__is_protected: $children__description__is_protected, resource_pool__ids: $resource_pool__ids, resource_pool__isnull: $resource_pool__isnull, resource_pool__display_label__value: $resource_pool__display_label__value, resource_pool__display_label__values: $resource_pool__display_label__values, resource_pool__display_label__isnull: $resource_pool__display_label__isnull, resource_pool__default_prefix_length__value: $resource_pool__default_prefix_length__value, resource_pool__default_prefix_length__values: $resource_pool__default_prefix_length__values, resource_pool__default_prefix_length__source__id: $resource_pool__default_prefix_length__source__id, resource_pool__default_prefix_length__owner__id: $resource_pool__default_prefix_length__owner__id, resource_pool__default_prefix_length__is_protected: $resource_pool__default_prefix_length__is_protected, resource_pool__default_address_type__value: $resource_pool__default_address_type__value, resource_pool__default_address_type__values: $resource_pool__default_address_type__values, resource_pool__default_address_type__source__id: $resource_pool__default_address_type__source__id, resource_pool__default_address_type__owner__id: $resource_pool__default_address_type__owner__id, resource_pool__default_address_type__is_protected: $resource_pool__default_address_type__is_protected, resource_pool__name__value: $resource_pool__name__value, resource_pool__name__values: $resource_pool__name__values, resource_pool__name__source__id: $resource_pool__name__source__id, resource_pool__name__owner__id: $resource_pool__name__owner__id, resource_pool__name__is_protected: $resource_pool__name__is_protected, resource_pool__description__value: $resource_pool__description__value, resource_pool__description__values: $resource_pool__description__values, resource_pool__description__source__id: $resource_pool__description__source__id, resource_pool__description__owner__id: $resource_pool__description__owner__id, resource_pool__description__is_protected: $resource_pool__description__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, parent__ids: $parent__ids, parent__isnull: $parent__isnull, parent__display_label__value: $parent__display_label__value, parent__display_label__values: $parent__display_label__values, parent__display_label__isnull: $parent__display_label__isnull, parent__netmask__value: $parent__netmask__value, parent__netmask__values: $parent__netmask__values, parent__netmask__source__id: $parent__netmask__source__id, parent__netmask__owner__id: $parent__netmask__owner__id, parent__netmask__is_protected: $parent__netmask__is_protected, parent__hostmask__value: $parent__hostmask__value, parent__hostmask__values: $parent__hostmask__values, parent__hostmask__source__id: $parent__hostmask__source__id, parent__hostmask__owner__id: $parent__hostmask__owner__id, parent__hostmask__is_protected: $parent__hostmask__is_protected, parent__is_top_level__value: $parent__is_top_level__value, parent__is_top_level__values: $parent__is_top_level__values, parent__is_top_level__source__id: $parent__is_top_level__source__id, parent__is_top_level__owner__id: $parent__is_top_level__owner__id, parent__is_top_level__is_protected: $parent__is_top_level__is_protected, parent__utilization__value: $parent__utilization__value, parent__utilization__values: $parent__utilization__values, parent__utilization__source__id: $parent__utilization__source__id, parent__utilization__owner__id: $parent__utilization__owner__id, parent__utilization__is_protected: $parent__utilization__is_protected, parent__is_pool__value: $parent__is_pool__value, parent__is_pool__values: $parent__is_pool__values, parent__is_pool__source__id: $parent__is_pool__source__id, parent__is_pool__owner__id: $parent__is_pool__owner__id, parent__is_pool__is_protected: $parent__is_pool__is_protected, parent__broadcast_address__value: $parent__broadcast_address__value, parent__broadcast_address__values: $parent__broadcast_address__values, parent__broadcast_address__source__id: $parent__broadcast_address__source__id, parent__broadcast_address__owner__id: $parent__broadcast_address__owner__id, parent__broadcast_address__is_protected: $parent__broadcast_address__is_protected, parent__member_type__value: $parent__member_type__value, parent__member_type__values: $parent__member_type__values, parent__member_type__source__id: $parent__member_type__source__id, parent__member_type__owner__id: $parent__member_type__owner__id, parent__member_type__is_protected: $parent__member_type__is_protected, parent__network_address__value: $parent__network_address__value, parent__network_address__values: $parent__network_address__values, parent__network_address__source__id: $parent__network_address__source__id, parent__network_address__owner__id: $parent__network_address__owner__id, parent__network_address__is_protected: $parent__network_address__is_protected, parent__prefix__value: $parent__prefix__value, parent__prefix__values: $parent__prefix__values, parent__prefix__source__id: $parent__prefix__source__id, parent__prefix__owner__id: $parent__prefix__owner__id, parent__prefix__is_protected: $parent__prefix__is_protected, parent__description__value: $parent__description__value, parent__description__values: $parent__description__values, parent__description__source__id: $parent__description__source__id, parent__description__owner__id: $parent__description__owner__id, parent__description__is_protected: $parent__description__is_protected, profiles__ids: $profiles__ids, profiles__isnull: $profiles__isnull, profiles__display_label__value: $profiles__display_label__value, profiles__display_label__values: $profiles__display_label__values, profiles__display_label__isnull: $profiles__display_label__isnull, profiles__profile_name__value: $profiles__profile_name__value, profiles__profile_name__values: $profiles__profile_name__values, profiles__profile_name__source__id: $profiles__profile_name__source__id, profiles__profile_name__owner__id: $profiles__profile_name__owner__id, profiles__profile_name__is_protected: $profiles__profile_name__is_protected, profiles__profile_priority__value: $profiles__profile_priority__value, profiles__profile_priority__values: $profiles__profile_priority__values, profiles__profile_priority__source__id: $profiles__profile_priority__source__id, profiles__profile_priority__owner__id: $profiles__profile_priority__owner__id, profiles__profile_priority__is_protected: $profiles__profile_priority__is_protected) { count edges { node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
         let response = self.client.execute::<BuiltinIPPrefixResponse>(query, Some(vars), request_branch).await?;
-        let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+        let data = response.into_data()?;
         let mut items = Vec::new();
         for edge in data.builtin_ip_prefix.edges {
             if let Some(node) = edge.node {
@@ -2590,11 +2598,12 @@ impl<'a> BuiltinIPPrefixClient<'a> {
             Box::pin(async move {
             let vars = page_filters.to_vars()?;
                 let response = client.execute::<BuiltinIPPrefixResponse>(query, Some(vars), branch.as_deref()).await?;
-                let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+                let data = response.into_data()?;
                 Ok((data, current_offset))
             })
         });
         let extract: BoxExtract<'a, serde_json::Value, String, (BuiltinIPPrefixResponse, i64)> = Box::new(move |(data, current_offset): (BuiltinIPPrefixResponse, i64)| -> Result<EdgePage<serde_json::Value, String>> {
+            let total_count = Some(data.builtin_ip_prefix.count);
             let mut items = Vec::new();
             let mut next: Option<String> = None;
             for edge in data.builtin_ip_prefix.edges {
@@ -2605,9 +2614,12 @@ impl<'a> BuiltinIPPrefixClient<'a> {
             if !items.is_empty() {
                 next = Some((current_offset + items.len() as i64).to_string());
             }
-            Ok(EdgePage { nodes: items, next_cursor: next })
+            let has_next_page = total_count.map(|total| current_offset + (items.len() as i64) < total);
+            let end_cursor = None;
+            let info = EdgePageInfo { has_next_page, end_cursor, total_count };
+            Ok(EdgePage::new(items, next).with_info(info))
         });
-        infrahub::Paginator::new(fetch, extract)
+        infrahub::Paginator::new(fetch, extract).with_total_count(|(data, _)| Some(data.builtin_ip_prefix.count))
     }
 
     pub async fn get_by_id(&self, id: impl Into<String>, request_branch: Option<&str>) -> Result<Option<serde_json::Value>> {
@@ -2949,9 +2961,9 @@ impl<'a> BuiltinTagClient<'a> {
 
     pub async fn list(&self, filters: Option<BuiltinTagFilters>, request_branch: Option<&str>) -> Result<Vec<BuiltinTag>> {
         let vars = filters.map(|f| f.to_vars()).transpose()?.unwrap_or_else(|| Value::Object(serde_json::Map::new()));
-        let query = r#"query BuiltinTag($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $name__value: String, $name__values: [String], $name__isnull: Boolean, $name__source__id: ID, $name__owner__id: ID, $name__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String], $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $profiles__ids: [ID], $profiles__isnull: Boolean, $profiles__display_label__value: String, $profiles__display_label__values: [String], $profiles__display_label__isnull: Boolean, $profiles__profile_name__value: String, $profiles__profile_name__values: [String], $profiles__profile_name__source__id: ID, $profiles__profile_name__owner__id: ID, $profiles__profile_name__is_protected: Boolean, $profiles__profile_priority__value: BigInt, $profiles__profile_priority__values: [BigInt], $profiles__profile_priority__source__id: ID, $profiles__profile_priority__owner__id: ID, $profiles__profile_priority__is_protected: Boolean) { BuiltinTag(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, name__value: $name__value, name__values: $name__values, name__isnull: $name__isnull, name__source__id: $name__source__id, name__owner__id: $name__owner__id, name__is_protected: $name__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, profiles__ids: $profiles__ids, profiles__isnull: $profiles__isnull, profiles__display_label__value: $profiles__display_label__value, profiles__display_label__values: $profiles__display_label__values, profiles__display_label__isnull: $profiles__display_label__isnull, profiles__profile_name__value: $profiles__profile_name__value, profiles__profile_name__values: $profiles__profile_name__values, profiles__profile_name__source__id: $profiles__profile_name__source__id, profiles__profile_name__owner__id: $profiles__profile_name__owner__id, profiles__profile_name__is_protected: $profiles__profile_name__is_protected, profiles__profile_priority__value: $profiles__profile_priority__value, profiles__profile_priority__values: $profiles__profile_priority__values, profiles__profile_priority__source__id: $profiles__profile_priority__source__id, profiles__profile_priority__owner__id: $profiles__profile_priority__owner__id, profiles__profile_priority__is_protected: $profiles__profile_priority__is_protected) { count edges { node { id hfid display_label name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } subscriber_of_groups { count edges { __typename } } member_of_groups { count edges { __typename } } profiles { count edges { __typename } } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
+        let query = r#"query BuiltinTag($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $name__value: String, $name__values: [String], $name__isnull: Boolean, $name__source__id: ID, $name__owner__id: ID, $name__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String], $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $profiles__ids: [ID], $profiles__isnull: Boolean, $profiles__display_label__value: String, $profiles__display_label__values: [String], $profiles__display_label__isnull: Boolean, $profiles__profile_name__value: String, $profiles__profile_name__values: [String], $profiles__profile_name__source__id: ID, $profiles__profile_name__owner__id: ID, $profiles__profile_name__is_protected: Boolean, $profiles__profile_priority__value: BigInt, $profiles__profile_priority__values: [BigInt], $profiles__profile_priority__source__id: ID, $profiles__profile_priority__owner__id: ID, $profiles__profile_priority__is_protected: Boolean) { BuiltinTag(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, name__value: $name__value, name__values: $name__values, name__isnull: $name__isnull, name__source__id: $name__source__id, name__owner__id: $name__owner__id, name__is_protected: $name__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, profiles__ids: $profiles__ids, profiles__isnull: $profiles__isnull, profiles__display_label__value: $profiles__display_label__value, profiles__display_label__values: $profiles__display_label__values, profiles__display_label__isnull: $profiles__display_label__isnull, profiles__profile_name__value: $profiles__profile_name__value, profiles__profile_name__values: $profiles__profile_name__values, profiles__profile_name__source__id: $profiles__profile_name__source__id, profiles__profile_name__owner__id: $profiles__profile_name__owner__id, profiles__profile_name__is_protected: $profiles__profile_name__is_protected, profiles__profile_priority__value: $profiles__profile_priority__value, profiles__profile_priority__values: $profiles__profile_priority__values, profiles__profile_priority__source__id: $profiles__profile_priority__source__id, profiles__profile_priority__owner__id: $profiles__profile_priority__owner__id, profiles__profile_priority__is_protected: $profiles__profile_priority__is_protected) { count edges { node { id hfid display_label name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } subscriber_of_groups { count } member_of_groups { count } profiles { count } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
         let response = self.client.execute::<BuiltinTagResponse>(query, Some(vars), request_branch).await?;
-        let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+        let data = response.into_data()?;
         let mut items = Vec::new();
         for edge in data.builtin_tag.edges {
             if let Some(node) = edge.node {
@@ -2961,11 +2973,31 @@ impl<'a> BuiltinTagClient<'a> {
         Ok(items)
     }
 
+    pub async fn list_with_selection<T: serde::de::DeserializeOwned>(&self, filters: Option<BuiltinTagFilters>, selection: &Selection, request_branch: Option<&str>) -> Result<Vec<T>> {
+        crate::schema::REGISTRY.validate("BuiltinTag", selection)?;
+        let vars = filters.map(|f| f.to_vars()).transpose()?.unwrap_or_else(|| Value::Object(serde_json::Map::new()));
+        let query = [r#"query BuiltinTag($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $name__value: String, $name__values: [String], $name__isnull: Boolean, $name__source__id: ID, $name__owner__id: ID, $name__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String], $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $profiles__ids: [ID], $profiles__isnull: Boolean, $profiles__display_label__value: String, $profiles__display_label__values: [String], $profiles__display_label__isnull: Boolean, $profiles__profile_name__value: String, $profiles__profile_name__values: [String], $profiles__profile_name__source__id: ID, $profiles__profile_name__owner__id: ID, $profiles__profile_name__is_protected: Boolean, $profiles__profile_priority__value: BigInt, $profiles__profile_priority__values: [BigInt], $profiles__profile_priority__source__id: ID, $profiles__profile_priority__owner__id: ID, $profiles__profile_priority__is_protected: Boolean) { BuiltinTag(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, name__value: $name__value, name__values: $name__values, name__isnull: $name__isnull, name__source__id: $name__source__id, name__owner__id: $name__owner__id, name__is_protected: $name__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, profiles__ids: $profiles__ids, profiles__isnull: $profiles__isnull, profiles__display_label__value: $profiles__display_label__value, profiles__display_label__values: $profiles__display_label__values, profiles__display_label__isnull: $profiles__display_label__isnull, profiles__profile_name__value: $profiles__profile_name__value, profiles__profile_name__values: $profiles__profile_name__values, profiles__profile_name__source__id: $profiles__profile_name__source__id, profiles__profile_name__owner__id: $profiles__profile_name__owner__id, profiles__profile_name__is_protected: $profiles__profile_name__is_protected, profiles__profile_priority__value: $profiles__profile_priority__value, profiles__profile_priority__values: $profiles__profile_priority__values, profiles__profile_priority__source__id: $profiles__profile_priority__source__id, profiles__profile_priority__owner__id: $profiles__profile_priority__owner__id, profiles__profile_priority__is_protected: $profiles__profile_priority__is_protected) { edges { node "#, &selection.render()?, " } } }"].concat();
+        let response = self.client.execute_raw(&query, Some(vars), request_branch).await?;
+        let mut data = response.into_data()?;
+        let edges = match data.get_mut("BuiltinTag").and_then(|v| v.get_mut("edges")).map(Value::take) {
+            Some(Value::Array(edges)) => edges,
+            _ => Vec::new(),
+        };
+        let mut items = Vec::new();
+        for mut edge in edges {
+            match edge.get_mut("node").map(Value::take) {
+                Some(Value::Null) | None => {}
+                Some(node) => items.push(serde_json::from_value(node)?),
+            }
+        }
+        Ok(items)
+    }
+
     pub fn paginate(&self, filters: Option<BuiltinTagFilters>, request_branch: Option<&str>) -> DynPaginator<'a, BuiltinTag, String, (BuiltinTagResponse, i64)> {
         let client = self.client;
         let base_filters = filters.unwrap_or_default();
         let request_branch = request_branch.map(|b| b.to_string());
-        let query = r#"query BuiltinTag($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $name__value: String, $name__values: [String], $name__isnull: Boolean, $name__source__id: ID, $name__owner__id: ID, $name__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String], $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $profiles__ids: [ID], $profiles__isnull: Boolean, $profiles__display_label__value: String, $profiles__display_label__values: [String], $profiles__display_label__isnull: Boolean, $profiles__profile_name__value: String, $profiles__profile_name__values: [String], $profiles__profile_name__source__id: ID, $profiles__profile_name__owner__id: ID, $profiles__profile_name__is_protected: Boolean, $profiles__profile_priority__value: BigInt, $profiles__profile_priority__values: [BigInt], $profiles__profile_priority__source__id: ID, $profiles__profile_priority__owner__id: ID, $profiles__profile_priority__is_protected: Boolean) { BuiltinTag(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, name__value: $name__value, name__values: $name__values, name__isnull: $name__isnull, name__source__id: $name__source__id, name__owner__id: $name__owner__id, name__is_protected: $name__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, profiles__ids: $profiles__ids, profiles__isnull: $profiles__isnull, profiles__display_label__value: $profiles__display_label__value, profiles__display_label__values: $profiles__display_label__values, profiles__display_label__isnull: $profiles__display_label__isnull, profiles__profile_name__value: $profiles__profile_name__value, profiles__profile_name__values: $profiles__profile_name__values, profiles__profile_name__source__id: $profiles__profile_name__source__id, profiles__profile_name__owner__id: $profiles__profile_name__owner__id, profiles__profile_name__is_protected: $profiles__profile_name__is_protected, profiles__profile_priority__value: $profiles__profile_priority__value, profiles__profile_priority__values: $profiles__profile_priority__values, profiles__profile_priority__source__id: $profiles__profile_priority__source__id, profiles__profile_priority__owner__id: $profiles__profile_priority__owner__id, profiles__profile_priority__is_protected: $profiles__profile_priority__is_protected) { count edges { node { id hfid display_label name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } subscriber_of_groups { count edges { __typename } } member_of_groups { count edges { __typename } } profiles { count edges { __typename } } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
+        let query = r#"query BuiltinTag($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $name__value: String, $name__values: [String], $name__isnull: Boolean, $name__source__id: ID, $name__owner__id: ID, $name__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String], $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $profiles__ids: [ID], $profiles__isnull: Boolean, $profiles__display_label__value: String, $profiles__display_label__values: [String], $profiles__display_label__isnull: Boolean, $profiles__profile_name__value: String, $profiles__profile_name__values: [String], $profiles__profile_name__source__id: ID, $profiles__profile_name__owner__id: ID, $profiles__profile_name__is_protected: Boolean, $profiles__profile_priority__value: BigInt, $profiles__profile_priority__values: [BigInt], $profiles__profile_priority__source__id: ID, $profiles__profile_priority__owner__id: ID, $profiles__profile_priority__is_protected: Boolean) { BuiltinTag(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, name__value: $name__value, name__values: $name__values, name__isnull: $name__isnull, name__source__id: $name__source__id, name__owner__id: $name__owner__id, name__is_protected: $name__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, profiles__ids: $profiles__ids, profiles__isnull: $profiles__isnull, profiles__display_label__value: $profiles__display_label__value, profiles__display_label__values: $profiles__display_label__values, profiles__display_label__isnull: $profiles__display_label__isnull, profiles__profile_name__value: $profiles__profile_name__value, profiles__profile_name__values: $profiles__profile_name__values, profiles__profile_name__source__id: $profiles__profile_name__source__id, profiles__profile_name__owner__id: $profiles__profile_name__owner__id, profiles__profile_name__is_protected: $profiles__profile_name__is_protected, profiles__profile_priority__value: $profiles__profile_priority__value, profiles__profile_priority__values: $profiles__profile_priority__values, profiles__profile_priority__source__id: $profiles__profile_priority__source__id, profiles__profile_priority__owner__id: $profiles__profile_priority__owner__id, profiles__profile_priority__is_protected: $profiles__profile_priority__is_protected) { count edges { node { id hfid display_label name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } subscriber_of_groups { count } member_of_groups { count } profiles { count } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
         let fetch: BoxFetch<'a, String, (BuiltinTagResponse, i64)> = Box::new(move |cursor: Option<String>| -> BoxFutureResult<'a, (BuiltinTagResponse, i64)> {
             let mut page_filters = base_filters.clone();
             let branch = request_branch.clone();
@@ -2979,11 +3011,12 @@ impl<'a> BuiltinTagClient<'a> {
             Box::pin(async move {
             let vars = page_filters.to_vars()?;
                 let response = client.execute::<BuiltinTagResponse>(query, Some(vars), branch.as_deref()).await?;
-                let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+                let data = response.into_data()?;
                 Ok((data, current_offset))
             })
         });
         let extract: BoxExtract<'a, BuiltinTag, String, (BuiltinTagResponse, i64)> = Box::new(move |(data, current_offset): (BuiltinTagResponse, i64)| -> Result<EdgePage<BuiltinTag, String>> {
+            let total_count = Some(data.builtin_tag.count);
             let mut items = Vec::new();
             let mut next: Option<String> = None;
             for edge in data.builtin_tag.edges {
@@ -2994,9 +3027,12 @@ impl<'a> BuiltinTagClient<'a> {
             if !items.is_empty() {
                 next = Some((current_offset + items.len() as i64).to_string());
             }
-            Ok(EdgePage { nodes: items, next_cursor: next })
+            let has_next_page = total_count.map(|total| current_offset + (items.len() as i64) < total);
+            let end_cursor = None;
+            let info = EdgePageInfo { has_next_page, end_cursor, total_count };
+            Ok(EdgePage::new(items, next).with_info(info))
         });
-        infrahub::Paginator::new(fetch, extract)
+        infrahub::Paginator::new(fetch, extract).with_total_count(|(data, _)| Some(data.builtin_tag.count))
     }
 
     pub async fn get_by_id(&self, id: impl Into<String>, request_branch: Option<&str>) -> Result<Option<BuiltinTag>> {
@@ -3006,5 +3042,77 @@ impl<'a> BuiltinTagClient<'a> {
         Ok(items.pop())
     }
 
+    pub async fn subscriber_of_groups_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<serde_json::Value, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query BuiltinTagSubscriberOfGroups($ids: [ID], $offset: Int, $limit: Int) { BuiltinTag(ids: $ids) { edges { node { subscriber_of_groups(offset: $offset, limit: $limit) { count edges { node { id __typename } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["BuiltinTag"]["edges"][0]["node"]["subscriber_of_groups"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("BuiltinTag {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
+    pub async fn member_of_groups_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<serde_json::Value, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query BuiltinTagMemberOfGroups($ids: [ID], $offset: Int, $limit: Int) { BuiltinTag(ids: $ids) { edges { node { member_of_groups(offset: $offset, limit: $limit) { count edges { node { id __typename } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["BuiltinTag"]["edges"][0]["node"]["member_of_groups"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("BuiltinTag {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
+    pub async fn profiles_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<serde_json::Value, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query BuiltinTagProfiles($ids: [ID], $offset: Int, $limit: Int) { BuiltinTag(ids: $ids) { edges { node { profiles(offset: $offset, limit: $limit) { count edges { node { id __typename } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["BuiltinTag"]["edges"][0]["node"]["profiles"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("BuiltinTag {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
 }
 
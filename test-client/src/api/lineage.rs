@@ -2,7 +2,7 @@
 
 #![allow(non_snake_case, unused_imports, unused_assignments, clippy::field_reassign_with_default)]
 
-use infrahub::{BoxExtract, BoxFetch, BoxFutureResult, Client, DynPaginator, EdgePage, Error, Result};
+use infrahub::{BoxExtract, BoxFetch, BoxFutureResult, Client, DynPaginator, EdgePage, EdgePageInfo, Error, Result, Selection};
 use serde_json::Value;
 
 use crate::inputs::*;
@@ -130,7 +130,7 @@ impl<'a> LineageOwnerClient<'a> {
         let vars = filters.map(|f| f.to_vars()).transpose()?.unwrap_or_else(|| Value::Object(serde_json::Map::new()));
         let query = r#"query LineageOwner($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime) { LineageOwner(offset: $offset, limit: $limit, order: $order, ids: $ids, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after) { count edges { node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
         let response = self.client.execute::<LineageOwnerResponse>(query, Some(vars), request_branch).await?;
-        let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+        let data = response.into_data()?;
         let mut items = Vec::new();
         for edge in data.lineage_owner.edges {
             if let Some(node) = edge.node {
@@ -158,11 +158,12 @@ impl<'a> LineageOwnerClient<'a> {
             Box::pin(async move {
             let vars = page_filters.to_vars()?;
                 let response = client.execute::<LineageOwnerResponse>(query, Some(vars), branch.as_deref()).await?;
-                let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+                let data = response.into_data()?;
                 Ok((data, current_offset))
             })
         });
         let extract: BoxExtract<'a, serde_json::Value, String, (LineageOwnerResponse, i64)> = Box::new(move |(data, current_offset): (LineageOwnerResponse, i64)| -> Result<EdgePage<serde_json::Value, String>> {
+            let total_count = Some(data.lineage_owner.count);
             let mut items = Vec::new();
             let mut next: Option<String> = None;
             for edge in data.lineage_owner.edges {
@@ -173,9 +174,12 @@ impl<'a> LineageOwnerClient<'a> {
             if !items.is_empty() {
                 next = Some((current_offset + items.len() as i64).to_string());
             }
-            Ok(EdgePage { nodes: items, next_cursor: next })
+            let has_next_page = total_count.map(|total| current_offset + (items.len() as i64) < total);
+            let end_cursor = None;
+            let info = EdgePageInfo { has_next_page, end_cursor, total_count };
+            Ok(EdgePage::new(items, next).with_info(info))
         });
-        infrahub::Paginator::new(fetch, extract)
+        infrahub::Paginator::new(fetch, extract).with_total_count(|(data, _)| Some(data.lineage_owner.count))
     }
 
     pub async fn get_by_id(&self, id: impl Into<String>, request_branch: Option<&str>) -> Result<Option<serde_json::Value>> {
@@ -291,7 +295,7 @@ impl<'a> LineageSourceClient<'a> {
         let vars = filters.map(|f| f.to_vars()).transpose()?.unwrap_or_else(|| Value::Object(serde_json::Map::new()));
         let query = r#"query LineageSource($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime) { LineageSource(offset: $offset, limit: $limit, order: $order, ids: $ids, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after) { count edges { node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
         let response = self.client.execute::<LineageSourceResponse>(query, Some(vars), request_branch).await?;
-        let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+        let data = response.into_data()?;
         let mut items = Vec::new();
         for edge in data.lineage_source.edges {
             if let Some(node) = edge.node {
@@ -319,11 +323,12 @@ impl<'a> LineageSourceClient<'a> {
             Box::pin(async move {
             let vars = page_filters.to_vars()?;
                 let response = client.execute::<LineageSourceResponse>(query, Some(vars), branch.as_deref()).await?;
-                let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+                let data = response.into_data()?;
                 Ok((data, current_offset))
             })
         });
         let extract: BoxExtract<'a, serde_json::Value, String, (LineageSourceResponse, i64)> = Box::new(move |(data, current_offset): (LineageSourceResponse, i64)| -> Result<EdgePage<serde_json::Value, String>> {
+            let total_count = Some(data.lineage_source.count);
             let mut items = Vec::new();
             let mut next: Option<String> = None;
             for edge in data.lineage_source.edges {
@@ -334,9 +339,12 @@ impl<'a> LineageSourceClient<'a> {
             if !items.is_empty() {
                 next = Some((current_offset + items.len() as i64).to_string());
             }
-            Ok(EdgePage { nodes: items, next_cursor: next })
+            let has_next_page = total_count.map(|total| current_offset + (items.len() as i64) < total);
+            let end_cursor = None;
+            let info = EdgePageInfo { has_next_page, end_cursor, total_count };
+            Ok(EdgePage::new(items, next).with_info(info))
         });
-        infrahub::Paginator::new(fetch, extract)
+        infrahub::Paginator::new(fetch, extract).with_total_count(|(data, _)| Some(data.lineage_source.count))
     }
 
     pub async fn get_by_id(&self, id: impl Into<String>, request_branch: Option<&str>) -> Result<Option<serde_json::Value>> {
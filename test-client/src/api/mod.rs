@@ -1,6 +1,6 @@
 //! generated ergonomic api
 
-use infrahub::Client;
+use ::infrahub::Client;
 
 pub mod builtin;
 pub mod core;
@@ -22,6 +22,16 @@ impl ApiClient for Client {
     }
 }
 
+impl Api<'static> {
+    /// an api over [`Client::from_env`], for examples and scripts
+    ///
+    /// the client is leaked and lives until the process exits.
+    pub fn from_env() -> ::infrahub::Result<Self> {
+        let client: &'static Client = Box::leak(Box::new(Client::from_env()?));
+        Ok(client.api())
+    }
+}
+
 impl<'a> Api<'a> {
     pub fn builtin(&self) -> builtin::BuiltinApi<'a> {
         builtin::BuiltinApi::new(self.client)
@@ -2,7 +2,7 @@
 
 #![allow(non_snake_case, unused_imports, unused_assignments, clippy::field_reassign_with_default)]
 
-use infrahub::{BoxExtract, BoxFetch, BoxFutureResult, Client, DynPaginator, EdgePage, Error, Result};
+use infrahub::{BoxExtract, BoxFetch, BoxFutureResult, Client, DynPaginator, EdgePage, EdgePageInfo, Error, Result, Selection};
 use serde_json::Value;
 
 use crate::inputs::*;
@@ -470,9 +470,9 @@ impl<'a> ProfileBuiltinIPAddressClient<'a> {
 
     pub async fn list(&self, filters: Option<ProfileBuiltinIPAddressFilters>, request_branch: Option<&str>) -> Result<Vec<ProfileBuiltinIPAddress>> {
         let vars = filters.map(|f| f.to_vars()).transpose()?.unwrap_or_else(|| Value::Object(serde_json::Map::new()));
-        let query = r#"query ProfileBuiltinIPAddress($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $address__value: String, $address__values: [String], $address__isnull: Boolean, $address__source__id: ID, $address__owner__id: ID, $address__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__address__value: String, $related_nodes__address__values: [String], $related_nodes__address__source__id: ID, $related_nodes__address__owner__id: ID, $related_nodes__address__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $ip_namespace__ids: [ID], $ip_namespace__isnull: Boolean, $ip_namespace__display_label__value: String, $ip_namespace__display_label__values: [String], $ip_namespace__display_label__isnull: Boolean, $ip_namespace__description__value: String, $ip_namespace__description__values: [String], $ip_namespace__description__source__id: ID, $ip_namespace__description__owner__id: ID, $ip_namespace__description__is_protected: Boolean, $ip_namespace__name__value: String, $ip_namespace__name__values: [String], $ip_namespace__name__source__id: ID, $ip_namespace__name__owner__id: ID, $ip_namespace__name__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileBuiltinIPAddress(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, address__value: $address__value, address__values: $address__values, address__isnull: $address__isnull, address__source__id: $address__source__id, address__owner__id: $address__owner__id, address__is_protected: $address__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__address__value: $related_nodes__address__value, related_nodes__address__values: $related_nodes__address__values, related_nodes__address__source__id: $related_nodes__address__source__id, related_nodes__address__owner__id: $related_nodes__address__owner__id, related_nodes__address__is_protected: $related_nodes__address__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, ip_namespace__ids: $ip_namespace__ids, ip_namespace__isnull: $ip_namespace__isnull, ip_namespace__display_label__value: $ip_namespace__display_label__value, ip_namespace__display_label__values: $ip_namespace__display_label__values, ip_namespace__display_label__isnull: $ip_namespace__display_label__isnull, ip_namespace__description__value: $ip_namespace__description__value, ip_namespace__description__values: $ip_namespace__description__values, ip_namespace__description__source__id: $ip_namespace__description__source__id, ip_namespace__description__owner__id: $ip_namespace__description__owner__id, ip_namespace__description__is_protected: $ip_namespace__description__is_protected, ip_namespace__name__value: $ip_namespace__name__value, ip_namespace__name__values: $ip_namespace__name__values, ip_namespace__name__source__id: $ip_namespace__name__source__id, ip_namespace__name__owner__id: $ip_namespace__name__owner__id, ip_namespace__name__is_protected: $ip_namespace__name__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { count edges { node { id hfid display_label profile_name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } profile_priority { is_default is_protected updated_at id is_from_profile permissions { __typename } value } address { is_default is_protected updated_at id is_from_profile permissions { __typename } value ip hostmask netmask prefixlen version with_hostmask with_netmask } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } related_nodes { count edges { __typename } } ip_namespace { node_metadata { __typename } properties { __typename } relationship_metadata { __typename } } member_of_groups { count edges { __typename } } subscriber_of_groups { count edges { __typename } } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
+        let query = r#"query ProfileBuiltinIPAddress($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $address__value: String, $address__values: [String], $address__isnull: Boolean, $address__source__id: ID, $address__owner__id: ID, $address__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__address__value: String, $related_nodes__address__values: [String], $related_nodes__address__source__id: ID, $related_nodes__address__owner__id: ID, $related_nodes__address__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $ip_namespace__ids: [ID], $ip_namespace__isnull: Boolean, $ip_namespace__display_label__value: String, $ip_namespace__display_label__values: [String], $ip_namespace__display_label__isnull: Boolean, $ip_namespace__description__value: String, $ip_namespace__description__values: [String], $ip_namespace__description__source__id: ID, $ip_namespace__description__owner__id: ID, $ip_namespace__description__is_protected: Boolean, $ip_namespace__name__value: String, $ip_namespace__name__values: [String], $ip_namespace__name__source__id: ID, $ip_namespace__name__owner__id: ID, $ip_namespace__name__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileBuiltinIPAddress(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, address__value: $address__value, address__values: $address__values, address__isnull: $address__isnull, address__source__id: $address__source__id, address__owner__id: $address__owner__id, address__is_protected: $address__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__address__value: $related_nodes__address__value, related_nodes__address__values: $related_nodes__address__values, related_nodes__address__source__id: $related_nodes__address__source__id, related_nodes__address__owner__id: $related_nodes__address__owner__id, related_nodes__address__is_protected: $related_nodes__address__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, ip_namespace__ids: $ip_namespace__ids, ip_namespace__isnull: $ip_namespace__isnull, ip_namespace__display_label__value: $ip_namespace__display_label__value, ip_namespace__display_label__values: $ip_namespace__display_label__values, ip_namespace__display_label__isnull: $ip_namespace__display_label__isnull, ip_namespace__description__value: $ip_namespace__description__value, ip_namespace__description__values: $ip_namespace__description__values, ip_namespace__description__source__id: $ip_namespace__description__source__id, ip_namespace__description__owner__id: $ip_namespace__description__owner__id, ip_namespace__description__is_protected: $ip_namespace__description__is_protected, ip_namespace__name__value: $ip_namespace__name__value, ip_namespace__name__values: $ip_namespace__name__values, ip_namespace__name__source__id: $ip_namespace__name__source__id, ip_namespace__name__owner__id: $ip_namespace__name__owner__id, ip_namespace__name__is_protected: $ip_namespace__name__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { count edges { node { id hfid display_label profile_name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } profile_priority { is_default is_protected updated_at id is_from_profile permissions { __typename } value } address { is_default is_protected updated_at id is_from_profile permissions { __typename } value ip hostmask netmask prefixlen version with_hostmask with_netmask } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } related_nodes { count } ip_namespace { node { id display_label ip_prefixes { count } member_of_groups { count } subscriber_of_groups { count } ip_addresses { count } profiles { count } } } member_of_groups { count } subscriber_of_groups { count } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
         let response = self.client.execute::<ProfileBuiltinIPAddressResponse>(query, Some(vars), request_branch).await?;
-        let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+        let data = response.into_data()?;
         let mut items = Vec::new();
         for edge in data.profile_builtin_ip_address.edges {
             if let Some(node) = edge.node {
@@ -482,11 +482,31 @@ impl<'a> ProfileBuiltinIPAddressClient<'a> {
         Ok(items)
     }
 
+    pub async fn list_with_selection<T: serde::de::DeserializeOwned>(&self, filters: Option<ProfileBuiltinIPAddressFilters>, selection: &Selection, request_branch: Option<&str>) -> Result<Vec<T>> {
+        crate::schema::REGISTRY.validate("ProfileBuiltinIPAddress", selection)?;
+        let vars = filters.map(|f| f.to_vars()).transpose()?.unwrap_or_else(|| Value::Object(serde_json::Map::new()));
+        let query = [r#"query ProfileBuiltinIPAddress($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $address__value: String, $address__values: [String], $address__isnull: Boolean, $address__source__id: ID, $address__owner__id: ID, $address__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__address__value: String, $related_nodes__address__values: [String], $related_nodes__address__source__id: ID, $related_nodes__address__owner__id: ID, $related_nodes__address__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $ip_namespace__ids: [ID], $ip_namespace__isnull: Boolean, $ip_namespace__display_label__value: String, $ip_namespace__display_label__values: [String], $ip_namespace__display_label__isnull: Boolean, $ip_namespace__description__value: String, $ip_namespace__description__values: [String], $ip_namespace__description__source__id: ID, $ip_namespace__description__owner__id: ID, $ip_namespace__description__is_protected: Boolean, $ip_namespace__name__value: String, $ip_namespace__name__values: [String], $ip_namespace__name__source__id: ID, $ip_namespace__name__owner__id: ID, $ip_namespace__name__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileBuiltinIPAddress(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, address__value: $address__value, address__values: $address__values, address__isnull: $address__isnull, address__source__id: $address__source__id, address__owner__id: $address__owner__id, address__is_protected: $address__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__address__value: $related_nodes__address__value, related_nodes__address__values: $related_nodes__address__values, related_nodes__address__source__id: $related_nodes__address__source__id, related_nodes__address__owner__id: $related_nodes__address__owner__id, related_nodes__address__is_protected: $related_nodes__address__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, ip_namespace__ids: $ip_namespace__ids, ip_namespace__isnull: $ip_namespace__isnull, ip_namespace__display_label__value: $ip_namespace__display_label__value, ip_namespace__display_label__values: $ip_namespace__display_label__values, ip_namespace__display_label__isnull: $ip_namespace__display_label__isnull, ip_namespace__description__value: $ip_namespace__description__value, ip_namespace__description__values: $ip_namespace__description__values, ip_namespace__description__source__id: $ip_namespace__description__source__id, ip_namespace__description__owner__id: $ip_namespace__description__owner__id, ip_namespace__description__is_protected: $ip_namespace__description__is_protected, ip_namespace__name__value: $ip_namespace__name__value, ip_namespace__name__values: $ip_namespace__name__values, ip_namespace__name__source__id: $ip_namespace__name__source__id, ip_namespace__name__owner__id: $ip_namespace__name__owner__id, ip_namespace__name__is_protected: $ip_namespace__name__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { edges { node "#, &selection.render()?, " } } }"].concat();
+        let response = self.client.execute_raw(&query, Some(vars), request_branch).await?;
+        let mut data = response.into_data()?;
+        let edges = match data.get_mut("ProfileBuiltinIPAddress").and_then(|v| v.get_mut("edges")).map(Value::take) {
+            Some(Value::Array(edges)) => edges,
+            _ => Vec::new(),
+        };
+        let mut items = Vec::new();
+        for mut edge in edges {
+            match edge.get_mut("node").map(Value::take) {
+                Some(Value::Null) | None => {}
+                Some(node) => items.push(serde_json::from_value(node)?),
+            }
+        }
+        Ok(items)
+    }
+
     pub fn paginate(&self, filters: Option<ProfileBuiltinIPAddressFilters>, request_branch: Option<&str>) -> DynPaginator<'a, ProfileBuiltinIPAddress, String, (ProfileBuiltinIPAddressResponse, i64)> {
         let client = self.client;
         let base_filters = filters.unwrap_or_default();
         let request_branch = request_branch.map(|b| b.to_string());
-        let query = r#"query ProfileBuiltinIPAddress($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $address__value: String, $address__values: [String], $address__isnull: Boolean, $address__source__id: ID, $address__owner__id: ID, $address__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__address__value: String, $related_nodes__address__values: [String], $related_nodes__address__source__id: ID, $related_nodes__address__owner__id: ID, $related_nodes__address__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $ip_namespace__ids: [ID], $ip_namespace__isnull: Boolean, $ip_namespace__display_label__value: String, $ip_namespace__display_label__values: [String], $ip_namespace__display_label__isnull: Boolean, $ip_namespace__description__value: String, $ip_namespace__description__values: [String], $ip_namespace__description__source__id: ID, $ip_namespace__description__owner__id: ID, $ip_namespace__description__is_protected: Boolean, $ip_namespace__name__value: String, $ip_namespace__name__values: [String], $ip_namespace__name__source__id: ID, $ip_namespace__name__owner__id: ID, $ip_namespace__name__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileBuiltinIPAddress(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, address__value: $address__value, address__values: $address__values, address__isnull: $address__isnull, address__source__id: $address__source__id, address__owner__id: $address__owner__id, address__is_protected: $address__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__address__value: $related_nodes__address__value, related_nodes__address__values: $related_nodes__address__values, related_nodes__address__source__id: $related_nodes__address__source__id, related_nodes__address__owner__id: $related_nodes__address__owner__id, related_nodes__address__is_protected: $related_nodes__address__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, ip_namespace__ids: $ip_namespace__ids, ip_namespace__isnull: $ip_namespace__isnull, ip_namespace__display_label__value: $ip_namespace__display_label__value, ip_namespace__display_label__values: $ip_namespace__display_label__values, ip_namespace__display_label__isnull: $ip_namespace__display_label__isnull, ip_namespace__description__value: $ip_namespace__description__value, ip_namespace__description__values: $ip_namespace__description__values, ip_namespace__description__source__id: $ip_namespace__description__source__id, ip_namespace__description__owner__id: $ip_namespace__description__owner__id, ip_namespace__description__is_protected: $ip_namespace__description__is_protected, ip_namespace__name__value: $ip_namespace__name__value, ip_namespace__name__values: $ip_namespace__name__values, ip_namespace__name__source__id: $ip_namespace__name__source__id, ip_namespace__name__owner__id: $ip_namespace__name__owner__id, ip_namespace__name__is_protected: $ip_namespace__name__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { count edges { node { id hfid display_label profile_name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } profile_priority { is_default is_protected updated_at id is_from_profile permissions { __typename } value } address { is_default is_protected updated_at id is_from_profile permissions { __typename } value ip hostmask netmask prefixlen version with_hostmask with_netmask } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } related_nodes { count edges { __typename } } ip_namespace { node_metadata { __typename } properties { __typename } relationship_metadata { __typename } } member_of_groups { count edges { __typename } } subscriber_of_groups { count edges { __typename } } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
+        let query = r#"query ProfileBuiltinIPAddress($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $address__value: String, $address__values: [String], $address__isnull: Boolean, $address__source__id: ID, $address__owner__id: ID, $address__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__address__value: String, $related_nodes__address__values: [String], $related_nodes__address__source__id: ID, $related_nodes__address__owner__id: ID, $related_nodes__address__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $ip_namespace__ids: [ID], $ip_namespace__isnull: Boolean, $ip_namespace__display_label__value: String, $ip_namespace__display_label__values: [String], $ip_namespace__display_label__isnull: Boolean, $ip_namespace__description__value: String, $ip_namespace__description__values: [String], $ip_namespace__description__source__id: ID, $ip_namespace__description__owner__id: ID, $ip_namespace__description__is_protected: Boolean, $ip_namespace__name__value: String, $ip_namespace__name__values: [String], $ip_namespace__name__source__id: ID, $ip_namespace__name__owner__id: ID, $ip_namespace__name__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileBuiltinIPAddress(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, address__value: $address__value, address__values: $address__values, address__isnull: $address__isnull, address__source__id: $address__source__id, address__owner__id: $address__owner__id, address__is_protected: $address__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__address__value: $related_nodes__address__value, related_nodes__address__values: $related_nodes__address__values, related_nodes__address__source__id: $related_nodes__address__source__id, related_nodes__address__owner__id: $related_nodes__address__owner__id, related_nodes__address__is_protected: $related_nodes__address__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, ip_namespace__ids: $ip_namespace__ids, ip_namespace__isnull: $ip_namespace__isnull, ip_namespace__display_label__value: $ip_namespace__display_label__value, ip_namespace__display_label__values: $ip_namespace__display_label__values, ip_namespace__display_label__isnull: $ip_namespace__display_label__isnull, ip_namespace__description__value: $ip_namespace__description__value, ip_namespace__description__values: $ip_namespace__description__values, ip_namespace__description__source__id: $ip_namespace__description__source__id, ip_namespace__description__owner__id: $ip_namespace__description__owner__id, ip_namespace__description__is_protected: $ip_namespace__description__is_protected, ip_namespace__name__value: $ip_namespace__name__value, ip_namespace__name__values: $ip_namespace__name__values, ip_namespace__name__source__id: $ip_namespace__name__source__id, ip_namespace__name__owner__id: $ip_namespace__name__owner__id, ip_namespace__name__is_protected: $ip_namespace__name__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { count edges { node { id hfid display_label profile_name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } profile_priority { is_default is_protected updated_at id is_from_profile permissions { __typename } value } address { is_default is_protected updated_at id is_from_profile permissions { __typename } value ip hostmask netmask prefixlen version with_hostmask with_netmask } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } related_nodes { count } ip_namespace { node { id display_label ip_prefixes { count } member_of_groups { count } subscriber_of_groups { count } ip_addresses { count } profiles { count } } } member_of_groups { count } subscriber_of_groups { count } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
         let fetch: BoxFetch<'a, String, (ProfileBuiltinIPAddressResponse, i64)> = Box::new(move |cursor: Option<String>| -> BoxFutureResult<'a, (ProfileBuiltinIPAddressResponse, i64)> {
             let mut page_filters = base_filters.clone();
             let branch = request_branch.clone();
@@ -500,11 +520,12 @@ impl<'a> ProfileBuiltinIPAddressClient<'a> {
             Box::pin(async move {
             let vars = page_filters.to_vars()?;
                 let response = client.execute::<ProfileBuiltinIPAddressResponse>(query, Some(vars), branch.as_deref()).await?;
-                let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+                let data = response.into_data()?;
                 Ok((data, current_offset))
             })
         });
         let extract: BoxExtract<'a, ProfileBuiltinIPAddress, String, (ProfileBuiltinIPAddressResponse, i64)> = Box::new(move |(data, current_offset): (ProfileBuiltinIPAddressResponse, i64)| -> Result<EdgePage<ProfileBuiltinIPAddress, String>> {
+            let total_count = Some(data.profile_builtin_ip_address.count);
             let mut items = Vec::new();
             let mut next: Option<String> = None;
             for edge in data.profile_builtin_ip_address.edges {
@@ -515,9 +536,12 @@ impl<'a> ProfileBuiltinIPAddressClient<'a> {
             if !items.is_empty() {
                 next = Some((current_offset + items.len() as i64).to_string());
             }
-            Ok(EdgePage { nodes: items, next_cursor: next })
+            let has_next_page = total_count.map(|total| current_offset + (items.len() as i64) < total);
+            let end_cursor = None;
+            let info = EdgePageInfo { has_next_page, end_cursor, total_count };
+            Ok(EdgePage::new(items, next).with_info(info))
         });
-        infrahub::Paginator::new(fetch, extract)
+        infrahub::Paginator::new(fetch, extract).with_total_count(|(data, _)| Some(data.profile_builtin_ip_address.count))
     }
 
     pub async fn get_by_id(&self, id: impl Into<String>, request_branch: Option<&str>) -> Result<Option<ProfileBuiltinIPAddress>> {
@@ -527,6 +551,78 @@ impl<'a> ProfileBuiltinIPAddressClient<'a> {
         Ok(items.pop())
     }
 
+    pub async fn related_nodes_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<serde_json::Value, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query ProfileBuiltinIPAddressRelatedNodes($ids: [ID], $offset: Int, $limit: Int) { ProfileBuiltinIPAddress(ids: $ids) { edges { node { related_nodes(offset: $offset, limit: $limit) { count edges { node { id __typename } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["ProfileBuiltinIPAddress"]["edges"][0]["node"]["related_nodes"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("ProfileBuiltinIPAddress {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
+    pub async fn member_of_groups_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<serde_json::Value, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query ProfileBuiltinIPAddressMemberOfGroups($ids: [ID], $offset: Int, $limit: Int) { ProfileBuiltinIPAddress(ids: $ids) { edges { node { member_of_groups(offset: $offset, limit: $limit) { count edges { node { id __typename } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["ProfileBuiltinIPAddress"]["edges"][0]["node"]["member_of_groups"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("ProfileBuiltinIPAddress {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
+    pub async fn subscriber_of_groups_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<serde_json::Value, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query ProfileBuiltinIPAddressSubscriberOfGroups($ids: [ID], $offset: Int, $limit: Int) { ProfileBuiltinIPAddress(ids: $ids) { edges { node { subscriber_of_groups(offset: $offset, limit: $limit) { count edges { node { id __typename } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["ProfileBuiltinIPAddress"]["edges"][0]["node"]["subscriber_of_groups"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("ProfileBuiltinIPAddress {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
 }
 
 #[derive(Debug, Clone, Default)]
@@ -1175,9 +1271,9 @@ impl<'a> ProfileBuiltinIPPrefixClient<'a> {
 
     pub async fn list(&self, filters: Option<ProfileBuiltinIPPrefixFilters>, request_branch: Option<&str>) -> Result<Vec<ProfileBuiltinIPPrefix>> {
         let vars = filters.map(|f| f.to_vars()).transpose()?.unwrap_or_else(|| Value::Object(serde_json::Map::new()));
-        let query = r#"query ProfileBuiltinIPPrefix($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $is_pool__value: Boolean, $is_pool__values: [Boolean], $is_pool__isnull: Boolean, $is_pool__source__id: ID, $is_pool__owner__id: ID, $is_pool__is_protected: Boolean, $member_type__value: String, $member_type__values: [String], $member_type__isnull: Boolean, $member_type__source__id: ID, $member_type__owner__id: ID, $member_type__is_protected: Boolean, $prefix__value: String, $prefix__values: [String], $prefix__isnull: Boolean, $prefix__source__id: ID, $prefix__owner__id: ID, $prefix__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__netmask__value: String, $related_nodes__netmask__values: [String], $related_nodes__netmask__source__id: ID, $related_nodes__netmask__owner__id: ID, $related_nodes__netmask__is_protected: Boolean, $related_nodes__hostmask__value: String, $related_nodes__hostmask__values: [String], $related_nodes__hostmask__source__id: ID, $related_nodes__hostmask__owner__id: ID, $related_nodes__hostmask__is_protected: Boolean, $related_nodes__is_top_level__value: Boolean, $related_nodes__is_top_level__values: [Boolean], $related_nodes__is_top_level__source__id: ID, $related_nodes__is_top_level__owner__id: ID, $related_nodes__is_top_level__is_protected: Boolean, $related_nodes__utilization__value: BigInt, $related_nodes__utilization__values: [BigInt], $related_nodes__utilization__source__id: ID, $related_nodes__utilization__owner__id: ID, $related_nodes__utilization__is_protected: Boolean, $related_nodes__is_pool__value: Boolean, $related_nodes__is_pool__values: [Boolean], $related_nodes__is_pool__source__id: ID, $related_nodes__is_pool__owner__id: ID, $related_nodes__is_pool__is_protected: Boolean, $related_nodes__broadcast_address__value: String, $related_nodes__broadcast_address__values: [String], $related_nodes__broadcast_address__source__id: ID, $related_nodes__broadcast_address__owner__id: ID, $related_nodes__broadcast_address__is_protected: Boolean, $related_nodes__member_type__value: String, $related_nodes__member_type__values: [String], $related_nodes__member_type__source__id: ID, $related_nodes__member_type__owner__id: ID, $related_nodes__member_type__is_protected: Boolean, $related_nodes__network_address__value: String, $related_nodes__network_address__values: [String], $related_nodes__network_address__source__id: ID, $related_nodes__network_address__owner__id: ID, $related_nodes__network_address__is_protected: Boolean, $related_nodes__prefix__value: String, $related_nodes__prefix__values: [String], $related_nodes__prefix__source__id: ID, $related_nodes__prefix__owner__id: ID, $related_nodes__prefix__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $ip_namespace__ids: [ID], $ip_namespace__isnull: Boolean, $ip_namespace__display_label__value: String, $ip_namespace__display_label__values: [String], $ip_namespace__display_label__isnull: Boolean, $ip_namespace__description__value: String, $ip_namespace__description__values: [String], $ip_namespace__description__source__id: ID, $ip_namespace__description__owner__id: ID, $ip_namespace__description__is_protected: Boolean, $ip_namespace__name__value: String, $ip_namespace__name__values: [String], $ip_namespace__name__source__id: ID, $ip_namespace__name__owner__id: ID, $ip_namespace__name__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileBuiltinIPPrefix(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, is_pool__value: $is_pool__value, is_pool__values: $is_pool__values, is_pool__isnull: $is_pool__isnull, is_pool__source__id: $is_pool__source__id, is_pool__owner__id: $is_pool__owner__id, is_pool__is_protected: $is_pool__is_protected, member_type__value: $member_type__value, member_type__values: $member_type__values, member_type__isnull: $member_type__isnull, member_type__source__id: $member_type__source__id, member_type__owner__id: $member_type__owner__id, member_type__is_protected: $member_type__is_protected, prefix__value: $prefix__value, prefix__values: $prefix__values, prefix__isnull: $prefix__isnull, prefix__source__id: $prefix__source__id, prefix__owner__id: $prefix__owner__id, prefix__is_protected: $prefix__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__netmask__value: $related_nodes__netmask__value, related_nodes__netmask__values: $related_nodes__netmask__values, related_nodes__netmask__source__id: $related_nodes__netmask__source__id, related_nodes__netmask__owner__id: $related_nodes__netmask__owner__id, related_nodes__netmask__is_protected: $related_nodes__netmask__is_protected, related_nodes__hostmask__value: $related_nodes__hostmask__value, related_nodes__hostmask__values: $related_nodes__hostmask__values, related_nodes__hostmask__source__id: $related_nodes__hostmask__source__id, related_nodes__hostmask__owner__id: $related_nodes__hostmask__owner__id, related_nodes__hostmask__is_protected: $related_nodes__hostmask__is_protected, related_nodes__is_top_level__value: $related_nodes__is_top_level__value, related_nodes__is_top_level__values: $related_nodes__is_top_level__values, related_nodes__is_top_level__source__id: $related_nodes__is_top_level__source__id, related_nodes__is_top_level__owner__id: $related_nodes__is_top_level__owner__id, related_nodes__is_top_level__is_protected: $related_nodes__is_top_level__is_protected, related_nodes__utilization__value: $related_nodes__utilization__value, related_nodes__utilization__values: $related_nodes__utilization__values, related_nodes__utilization__source__id: $related_nodes__utilization__source__id, related_nodes__utilization__owner__id: $related_nodes__utilization__owner__id, related_nodes__utilization__is_protected: $related_nodes__utilization__is_protected, related_nodes__is_pool__value: $related_nodes__is_pool__value, related_nodes__is_pool__values: $related_nodes__is_pool__values, related_nodes__is_pool__source__id: $related_nodes__is_pool__source__id, related_nodes__is_pool__owner__id: $related_nodes__is_pool__owner__id, related_nodes__is_pool__is_protected: $related_nodes__is_pool__is_protected, related_nodes__broadcast_address__value: $related_nodes__broadcast_address__value, related_nodes__broadcast_address__values: $related_nodes__broadcast_address__values, related_nodes__broadcast_address__source__id: $related_nodes__broadcast_address__source__id, related_nodes__broadcast_address__owner__id: $related_nodes__broadcast_address__owner__id, related_nodes__broadcast_address__is_protected: $related_nodes__broadcast_address__is_protected, related_nodes__member_type__value: $related_nodes__member_type__value, related_nodes__member_type__values: $related_nodes__member_type__values, related_nodes__member_type__source__id: $related_nodes__member_type__source__id, related_nodes__member_type__owner__id: $related_nodes__member_type__owner__id, related_nodes__member_type__is_protected: $related_nodes__member_type__is_protected, related_nodes__network_address__value: $related_nodes__network_address__value, related_nodes__network_address__values: $related_nodes__network_address__values, related_nodes__network_address__source__id: $related_nodes__network_address__source__id, related_nodes__network_address__owner__id: $related_nodes__network_address__owner__id, related_nodes__network_address__is_protected: $related_nodes__network_address__is_protected, related_nodes__prefix__value: $related_nodes__prefix__value, related_nodes__prefix__values: $related_nodes__prefix__values, related_nodes__prefix__source__id: $related_nodes__prefix__source__id, related_nodes__prefix__owner__id: $related_nodes__prefix__owner__id, related_nodes__prefix__is_protected: $related_nodes__prefix__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, ip_namespace__ids: $ip_namespace__ids, ip_namespace__isnull: $ip_namespace__isnull, ip_namespace__display_label__value: $ip_namespace__display_label__value, ip_namespace__display_label__values: $ip_namespace__display_label__values, ip_namespace__display_label__isnull: $ip_namespace__display_label__isnull, ip_namespace__description__value: $ip_namespace__description__value, ip_namespace__description__values: $ip_namespace__description__values, ip_namespace__description__source__id: $ip_namespace__description__source__id, ip_namespace__description__owner__id: $ip_namespace__description__owner__id, ip_namespace__description__is_protected: $ip_namespace__description__is_protected, ip_namespace__name__value: $ip_namespace__name__value, ip_namespace__name__values: $ip_namespace__name__values, ip_namespace__name__source__id: $ip_namespace__name__source__id, ip_namespace__name__owner__id: $ip_namespace__name__owner__id, ip_namespace__name__is_protected: $ip_namespace__name__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { count edges { node { id hfid display_label profile_name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } profile_priority { is_default is_protected updated_at id is_from_profile permissions { __typename } value } is_pool { is_default is_protected updated_at id is_from_profile permissions { __typename } value } member_type { is_default is_protected updated_at value label color description id is_from_profile permissions { __typename } } prefix { is_default is_protected updated_at id is_from_profile permissions { __typename } value broadcast_address hostmask netmask prefixlen num_addresses version with_hostmask with_netmask } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } related_nodes { count edges { __typename } } ip_namespace { node_metadata { __typename } properties { __typename } relationship_metadata { __typename } } member_of_groups { count edges { __typename } } subscriber_of_groups { count edges { __typename } } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
+        let query = r#"query ProfileBuiltinIPPrefix($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $is_pool__value: Boolean, $is_pool__values: [Boolean], $is_pool__isnull: Boolean, $is_pool__source__id: ID, $is_pool__owner__id: ID, $is_pool__is_protected: Boolean, $member_type__value: String, $member_type__values: [String], $member_type__isnull: Boolean, $member_type__source__id: ID, $member_type__owner__id: ID, $member_type__is_protected: Boolean, $prefix__value: String, $prefix__values: [String], $prefix__isnull: Boolean, $prefix__source__id: ID, $prefix__owner__id: ID, $prefix__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__netmask__value: String, $related_nodes__netmask__values: [String], $related_nodes__netmask__source__id: ID, $related_nodes__netmask__owner__id: ID, $related_nodes__netmask__is_protected: Boolean, $related_nodes__hostmask__value: String, $related_nodes__hostmask__values: [String], $related_nodes__hostmask__source__id: ID, $related_nodes__hostmask__owner__id: ID, $related_nodes__hostmask__is_protected: Boolean, $related_nodes__is_top_level__value: Boolean, $related_nodes__is_top_level__values: [Boolean], $related_nodes__is_top_level__source__id: ID, $related_nodes__is_top_level__owner__id: ID, $related_nodes__is_top_level__is_protected: Boolean, $related_nodes__utilization__value: BigInt, $related_nodes__utilization__values: [BigInt], $related_nodes__utilization__source__id: ID, $related_nodes__utilization__owner__id: ID, $related_nodes__utilization__is_protected: Boolean, $related_nodes__is_pool__value: Boolean, $related_nodes__is_pool__values: [Boolean], $related_nodes__is_pool__source__id: ID, $related_nodes__is_pool__owner__id: ID, $related_nodes__is_pool__is_protected: Boolean, $related_nodes__broadcast_address__value: String, $related_nodes__broadcast_address__values: [String], $related_nodes__broadcast_address__source__id: ID, $related_nodes__broadcast_address__owner__id: ID, $related_nodes__broadcast_address__is_protected: Boolean, $related_nodes__member_type__value: String, $related_nodes__member_type__values: [String], $related_nodes__member_type__source__id: ID, $related_nodes__member_type__owner__id: ID, $related_nodes__member_type__is_protected: Boolean, $related_nodes__network_address__value: String, $related_nodes__network_address__values: [String], $related_nodes__network_address__source__id: ID, $related_nodes__network_address__owner__id: ID, $related_nodes__network_address__is_protected: Boolean, $related_nodes__prefix__value: String, $related_nodes__prefix__values: [String], $related_nodes__prefix__source__id: ID, $related_nodes__prefix__owner__id: ID, $related_nodes__prefix__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $ip_namespace__ids: [ID], $ip_namespace__isnull: Boolean, $ip_namespace__display_label__value: String, $ip_namespace__display_label__values: [String], $ip_namespace__display_label__isnull: Boolean, $ip_namespace__description__value: String, $ip_namespace__description__values: [String], $ip_namespace__description__source__id: ID, $ip_namespace__description__owner__id: ID, $ip_namespace__description__is_protected: Boolean, $ip_namespace__name__value: String, $ip_namespace__name__values: [String], $ip_namespace__name__source__id: ID, $ip_namespace__name__owner__id: ID, $ip_namespace__name__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileBuiltinIPPrefix(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, is_pool__value: $is_pool__value, is_pool__values: $is_pool__values, is_pool__isnull: $is_pool__isnull, is_pool__source__id: $is_pool__source__id, is_pool__owner__id: $is_pool__owner__id, is_pool__is_protected: $is_pool__is_protected, member_type__value: $member_type__value, member_type__values: $member_type__values, member_type__isnull: $member_type__isnull, member_type__source__id: $member_type__source__id, member_type__owner__id: $member_type__owner__id, member_type__is_protected: $member_type__is_protected, prefix__value: $prefix__value, prefix__values: $prefix__values, prefix__isnull: $prefix__isnull, prefix__source__id: $prefix__source__id, prefix__owner__id: $prefix__owner__id, prefix__is_protected: $prefix__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__netmask__value: $related_nodes__netmask__value, related_nodes__netmask__values: $related_nodes__netmask__values, related_nodes__netmask__source__id: $related_nodes__netmask__source__id, related_nodes__netmask__owner__id: $related_nodes__netmask__owner__id, related_nodes__netmask__is_protected: $related_nodes__netmask__is_protected, related_nodes__hostmask__value: $related_nodes__hostmask__value, related_nodes__hostmask__values: $related_nodes__hostmask__values, related_nodes__hostmask__source__id: $related_nodes__hostmask__source__id, related_nodes__hostmask__owner__id: $related_nodes__hostmask__owner__id, related_nodes__hostmask__is_protected: $related_nodes__hostmask__is_protected, related_nodes__is_top_level__value: $related_nodes__is_top_level__value, related_nodes__is_top_level__values: $related_nodes__is_top_level__values, related_nodes__is_top_level__source__id: $related_nodes__is_top_level__source__id, related_nodes__is_top_level__owner__id: $related_nodes__is_top_level__owner__id, related_nodes__is_top_level__is_protected: $related_nodes__is_top_level__is_protected, related_nodes__utilization__value: $related_nodes__utilization__value, related_nodes__utilization__values: $related_nodes__utilization__values, related_nodes__utilization__source__id: $related_nodes__utilization__source__id, related_nodes__utilization__owner__id: $related_nodes__utilization__owner__id, related_nodes__utilization__is_protected: $related_nodes__utilization__is_protected, related_nodes__is_pool__value: $related_nodes__is_pool__value, related_nodes__is_pool__values: $related_nodes__is_pool__values, related_nodes__is_pool__source__id: $related_nodes__is_pool__source__id, related_nodes__is_pool__owner__id: $related_nodes__is_pool__owner__id, related_nodes__is_pool__is_protected: $related_nodes__is_pool__is_protected, related_nodes__broadcast_address__value: $related_nodes__broadcast_address__value, related_nodes__broadcast_address__values: $related_nodes__broadcast_address__values, related_nodes__broadcast_address__source__id: $related_nodes__broadcast_address__source__id, related_nodes__broadcast_address__owner__id: $related_nodes__broadcast_address__owner__id, related_nodes__broadcast_address__is_protected: $related_nodes__broadcast_address__is_protected, related_nodes__member_type__value: $related_nodes__member_type__value, related_nodes__member_type__values: $related_nodes__member_type__values, related_nodes__member_type__source__id: $related_nodes__member_type__source__id, related_nodes__member_type__owner__id: $related_nodes__member_type__owner__id, related_nodes__member_type__is_protected: $related_nodes__member_type__is_protected, related_nodes__network_address__value: $related_nodes__network_address__value, related_nodes__network_address__values: $related_nodes__network_address__values, related_nodes__network_address__source__id: $related_nodes__network_address__source__id, related_nodes__network_address__owner__id: $related_nodes__network_address__owner__id, related_nodes__network_address__is_protected: $related_nodes__network_address__is_protected, related_nodes__prefix__value: $related_nodes__prefix__value, related_nodes__prefix__values: $related_nodes__prefix__values, related_nodes__prefix__source__id: $related_nodes__prefix__source__id, related_nodes__prefix__owner__id: $related_nodes__prefix__owner__id, related_nodes__prefix__is_protected: $related_nodes__prefix__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, ip_namespace__ids: $ip_namespace__ids, ip_namespace__isnull: $ip_namespace__isnull, ip_namespace__display_label__value: $ip_namespace__display_label__value, ip_namespace__display_label__values: $ip_namespace__display_label__values, ip_namespace__display_label__isnull: $ip_namespace__display_label__isnull, ip_namespace__description__value: $ip_namespace__description__value, ip_namespace__description__values: $ip_namespace__description__values, ip_namespace__description__source__id: $ip_namespace__description__source__id, ip_namespace__description__owner__id: $ip_namespace__description__owner__id, ip_namespace__description__is_protected: $ip_namespace__description__is_protected, ip_namespace__name__value: $ip_namespace__name__value, ip_namespace__name__values: $ip_namespace__name__values, ip_namespace__name__source__id: $ip_namespace__name__source__id, ip_namespace__name__owner__id: $ip_namespace__name__owner__id, ip_namespace__name__is_protected: $ip_namespace__name__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { count edges { node { id hfid display_label profile_name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } profile_priority { is_default is_protected updated_at id is_from_profile permissions { __typename } value } is_pool { is_default is_protected updated_at id is_from_profile permissions { __typename } value } member_type { is_default is_protected updated_at value label color description id is_from_profile permissions { __typename } } prefix { is_default is_protected updated_at id is_from_profile permissions { __typename } value broadcast_address hostmask netmask prefixlen num_addresses version with_hostmask with_netmask } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } related_nodes { count } ip_namespace { node { id display_label ip_prefixes { count } member_of_groups { count } subscriber_of_groups { count } ip_addresses { count } profiles { count } } } member_of_groups { count } subscriber_of_groups { count } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
         let response = self.client.execute::<ProfileBuiltinIPPrefixResponse>(query, Some(vars), request_branch).await?;
-        let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+        let data = response.into_data()?;
         let mut items = Vec::new();
         for edge in data.profile_builtin_ip_prefix.edges {
             if let Some(node) = edge.node {
@@ -1187,11 +1283,31 @@ impl<'a> ProfileBuiltinIPPrefixClient<'a> {
         Ok(items)
     }
 
+    pub async fn list_with_selection<T: serde::de::DeserializeOwned>(&self, filters: Option<ProfileBuiltinIPPrefixFilters>, selection: &Selection, request_branch: Option<&str>) -> Result<Vec<T>> {
+        crate::schema::REGISTRY.validate("ProfileBuiltinIPPrefix", selection)?;
+        let vars = filters.map(|f| f.to_vars()).transpose()?.unwrap_or_else(|| Value::Object(serde_json::Map::new()));
+        let query = [r#"query ProfileBuiltinIPPrefix($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $is_pool__value: Boolean, $is_pool__values: [Boolean], $is_pool__isnull: Boolean, $is_pool__source__id: ID, $is_pool__owner__id: ID, $is_pool__is_protected: Boolean, $member_type__value: String, $member_type__values: [String], $member_type__isnull: Boolean, $member_type__source__id: ID, $member_type__owner__id: ID, $member_type__is_protected: Boolean, $prefix__value: String, $prefix__values: [String], $prefix__isnull: Boolean, $prefix__source__id: ID, $prefix__owner__id: ID, $prefix__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__netmask__value: String, $related_nodes__netmask__values: [String], $related_nodes__netmask__source__id: ID, $related_nodes__netmask__owner__id: ID, $related_nodes__netmask__is_protected: Boolean, $related_nodes__hostmask__value: String, $related_nodes__hostmask__values: [String], $related_nodes__hostmask__source__id: ID, $related_nodes__hostmask__owner__id: ID, $related_nodes__hostmask__is_protected: Boolean, $related_nodes__is_top_level__value: Boolean, $related_nodes__is_top_level__values: [Boolean], $related_nodes__is_top_level__source__id: ID, $related_nodes__is_top_level__owner__id: ID, $related_nodes__is_top_level__is_protected: Boolean, $related_nodes__utilization__value: BigInt, $related_nodes__utilization__values: [BigInt], $related_nodes__utilization__source__id: ID, $related_nodes__utilization__owner__id: ID, $related_nodes__utilization__is_protected: Boolean, $related_nodes__is_pool__value: Boolean, $related_nodes__is_pool__values: [Boolean], $related_nodes__is_pool__source__id: ID, $related_nodes__is_pool__owner__id: ID, $related_nodes__is_pool__is_protected: Boolean, $related_nodes__broadcast_address__value: String, $related_nodes__broadcast_address__values: [String], $related_nodes__broadcast_address__source__id: ID, $related_nodes__broadcast_address__owner__id: ID, $related_nodes__broadcast_address__is_protected: Boolean, $related_nodes__member_type__value: String, $related_nodes__member_type__values: [String], $related_nodes__member_type__source__id: ID, $related_nodes__member_type__owner__id: ID, $related_nodes__member_type__is_protected: Boolean, $related_nodes__network_address__value: String, $related_nodes__network_address__values: [String], $related_nodes__network_address__source__id: ID, $related_nodes__network_address__owner__id: ID, $related_nodes__network_address__is_protected: Boolean, $related_nodes__prefix__value: String, $related_nodes__prefix__values: [String], $related_nodes__prefix__source__id: ID, $related_nodes__prefix__owner__id: ID, $related_nodes__prefix__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $ip_namespace__ids: [ID], $ip_namespace__isnull: Boolean, $ip_namespace__display_label__value: String, $ip_namespace__display_label__values: [String], $ip_namespace__display_label__isnull: Boolean, $ip_namespace__description__value: String, $ip_namespace__description__values: [String], $ip_namespace__description__source__id: ID, $ip_namespace__description__owner__id: ID, $ip_namespace__description__is_protected: Boolean, $ip_namespace__name__value: String, $ip_namespace__name__values: [String], $ip_namespace__name__source__id: ID, $ip_namespace__name__owner__id: ID, $ip_namespace__name__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileBuiltinIPPrefix(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, is_pool__value: $is_pool__value, is_pool__values: $is_pool__values, is_pool__isnull: $is_pool__isnull, is_pool__source__id: $is_pool__source__id, is_pool__owner__id: $is_pool__owner__id, is_pool__is_protected: $is_pool__is_protected, member_type__value: $member_type__value, member_type__values: $member_type__values, member_type__isnull: $member_type__isnull, member_type__source__id: $member_type__source__id, member_type__owner__id: $member_type__owner__id, member_type__is_protected: $member_type__is_protected, prefix__value: $prefix__value, prefix__values: $prefix__values, prefix__isnull: $prefix__isnull, prefix__source__id: $prefix__source__id, prefix__owner__id: $prefix__owner__id, prefix__is_protected: $prefix__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__netmask__value: $related_nodes__netmask__value, related_nodes__netmask__values: $related_nodes__netmask__values, related_nodes__netmask__source__id: $related_nodes__netmask__source__id, related_nodes__netmask__owner__id: $related_nodes__netmask__owner__id, related_nodes__netmask__is_protected: $related_nodes__netmask__is_protected, related_nodes__hostmask__value: $related_nodes__hostmask__value, related_nodes__hostmask__values: $related_nodes__hostmask__values, related_nodes__hostmask__source__id: $related_nodes__hostmask__source__id, related_nodes__hostmask__owner__id: $related_nodes__hostmask__owner__id, related_nodes__hostmask__is_protected: $related_nodes__hostmask__is_protected, related_nodes__is_top_level__value: $related_nodes__is_top_level__value, related_nodes__is_top_level__values: $related_nodes__is_top_level__values, related_nodes__is_top_level__source__id: $related_nodes__is_top_level__source__id, related_nodes__is_top_level__owner__id: $related_nodes__is_top_level__owner__id, related_nodes__is_top_level__is_protected: $related_nodes__is_top_level__is_protected, related_nodes__utilization__value: $related_nodes__utilization__value, related_nodes__utilization__values: $related_nodes__utilization__values, related_nodes__utilization__source__id: $related_nodes__utilization__source__id, related_nodes__utilization__owner__id: $related_nodes__utilization__owner__id, related_nodes__utilization__is_protected: $related_nodes__utilization__is_protected, related_nodes__is_pool__value: $related_nodes__is_pool__value, related_nodes__is_pool__values: $related_nodes__is_pool__values, related_nodes__is_pool__source__id: $related_nodes__is_pool__source__id, related_nodes__is_pool__owner__id: $related_nodes__is_pool__owner__id, related_nodes__is_pool__is_protected: $related_nodes__is_pool__is_protected, related_nodes__broadcast_address__value: $related_nodes__broadcast_address__value, related_nodes__broadcast_address__values: $related_nodes__broadcast_address__values, related_nodes__broadcast_address__source__id: $related_nodes__broadcast_address__source__id, related_nodes__broadcast_address__owner__id: $related_nodes__broadcast_address__owner__id, related_nodes__broadcast_address__is_protected: $related_nodes__broadcast_address__is_protected, related_nodes__member_type__value: $related_nodes__member_type__value, related_nodes__member_type__values: $related_nodes__member_type__values, related_nodes__member_type__source__id: $related_nodes__member_type__source__id, related_nodes__member_type__owner__id: $related_nodes__member_type__owner__id, related_nodes__member_type__is_protected: $related_nodes__member_type__is_protected, related_nodes__network_address__value: $related_nodes__network_address__value, related_nodes__network_address__values: $related_nodes__network_address__values, related_nodes__network_address__source__id: $related_nodes__network_address__source__id, related_nodes__network_address__owner__id: $related_nodes__network_address__owner__id, related_nodes__network_address__is_protected: $related_nodes__network_address__is_protected, related_nodes__prefix__value: $related_nodes__prefix__value, related_nodes__prefix__values: $related_nodes__prefix__values, related_nodes__prefix__source__id: $related_nodes__prefix__source__id, related_nodes__prefix__owner__id: $related_nodes__prefix__owner__id, related_nodes__prefix__is_protected: $related_nodes__prefix__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, ip_namespace__ids: $ip_namespace__ids, ip_namespace__isnull: $ip_namespace__isnull, ip_namespace__display_label__value: $ip_namespace__display_label__value, ip_namespace__display_label__values: $ip_namespace__display_label__values, ip_namespace__display_label__isnull: $ip_namespace__display_label__isnull, ip_namespace__description__value: $ip_namespace__description__value, ip_namespace__description__values: $ip_namespace__description__values, ip_namespace__description__source__id: $ip_namespace__description__source__id, ip_namespace__description__owner__id: $ip_namespace__description__owner__id, ip_namespace__description__is_protected: $ip_namespace__description__is_protected, ip_namespace__name__value: $ip_namespace__name__value, ip_namespace__name__values: $ip_namespace__name__values, ip_namespace__name__source__id: $ip_namespace__name__source__id, ip_namespace__name__owner__id: $ip_namespace__name__owner__id, ip_namespace__name__is_protected: $ip_namespace__name__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { edges { node "#, &selection.render()?, " } } }"].concat();
+        let response = self.client.execute_raw(&query, Some(vars), request_branch).await?;
+        let mut data = response.into_data()?;
+        let edges = match data.get_mut("ProfileBuiltinIPPrefix").and_then(|v| v.get_mut("edges")).map(Value::take) {
+            Some(Value::Array(edges)) => edges,
+            _ => Vec::new(),
+        };
+        let mut items = Vec::new();
+        for mut edge in edges {
+            match edge.get_mut("node").map(Value::take) {
+                Some(Value::Null) | None => {}
+                Some(node) => items.push(serde_json::from_value(node)?),
+            }
+        }
+        Ok(items)
+    }
+
     pub fn paginate(&self, filters: Option<ProfileBuiltinIPPrefixFilters>, request_branch: Option<&str>) -> DynPaginator<'a, ProfileBuiltinIPPrefix, String, (ProfileBuiltinIPPrefixResponse, i64)> {
         let client = self.client;
         let base_filters = filters.unwrap_or_default();
         let request_branch = request_branch.map(|b| b.to_string());
-        let query = r#"query ProfileBuiltinIPPrefix($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $is_pool__value: Boolean, $is_pool__values: [Boolean], $is_pool__isnull: Boolean, $is_pool__source__id: ID, $is_pool__owner__id: ID, $is_pool__is_protected: Boolean, $member_type__value: String, $member_type__values: [String], $member_type__isnull: Boolean, $member_type__source__id: ID, $member_type__owner__id: ID, $member_type__is_protected: Boolean, $prefix__value: String, $prefix__values: [String], $prefix__isnull: Boolean, $prefix__source__id: ID, $prefix__owner__id: ID, $prefix__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__netmask__value: String, $related_nodes__netmask__values: [String], $related_nodes__netmask__source__id: ID, $related_nodes__netmask__owner__id: ID, $related_nodes__netmask__is_protected: Boolean, $related_nodes__hostmask__value: String, $related_nodes__hostmask__values: [String], $related_nodes__hostmask__source__id: ID, $related_nodes__hostmask__owner__id: ID, $related_nodes__hostmask__is_protected: Boolean, $related_nodes__is_top_level__value: Boolean, $related_nodes__is_top_level__values: [Boolean], $related_nodes__is_top_level__source__id: ID, $related_nodes__is_top_level__owner__id: ID, $related_nodes__is_top_level__is_protected: Boolean, $related_nodes__utilization__value: BigInt, $related_nodes__utilization__values: [BigInt], $related_nodes__utilization__source__id: ID, $related_nodes__utilization__owner__id: ID, $related_nodes__utilization__is_protected: Boolean, $related_nodes__is_pool__value: Boolean, $related_nodes__is_pool__values: [Boolean], $related_nodes__is_pool__source__id: ID, $related_nodes__is_pool__owner__id: ID, $related_nodes__is_pool__is_protected: Boolean, $related_nodes__broadcast_address__value: String, $related_nodes__broadcast_address__values: [String], $related_nodes__broadcast_address__source__id: ID, $related_nodes__broadcast_address__owner__id: ID, $related_nodes__broadcast_address__is_protected: Boolean, $related_nodes__member_type__value: String, $related_nodes__member_type__values: [String], $related_nodes__member_type__source__id: ID, $related_nodes__member_type__owner__id: ID, $related_nodes__member_type__is_protected: Boolean, $related_nodes__network_address__value: String, $related_nodes__network_address__values: [String], $related_nodes__network_address__source__id: ID, $related_nodes__network_address__owner__id: ID, $related_nodes__network_address__is_protected: Boolean, $related_nodes__prefix__value: String, $related_nodes__prefix__values: [String], $related_nodes__prefix__source__id: ID, $related_nodes__prefix__owner__id: ID, $related_nodes__prefix__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $ip_namespace__ids: [ID], $ip_namespace__isnull: Boolean, $ip_namespace__display_label__value: String, $ip_namespace__display_label__values: [String], $ip_namespace__display_label__isnull: Boolean, $ip_namespace__description__value: String, $ip_namespace__description__values: [String], $ip_namespace__description__source__id: ID, $ip_namespace__description__owner__id: ID, $ip_namespace__description__is_protected: Boolean, $ip_namespace__name__value: String, $ip_namespace__name__values: [String], $ip_namespace__name__source__id: ID, $ip_namespace__name__owner__id: ID, $ip_namespace__name__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileBuiltinIPPrefix(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, is_pool__value: $is_pool__value, is_pool__values: $is_pool__values, is_pool__isnull: $is_pool__isnull, is_pool__source__id: $is_pool__source__id, is_pool__owner__id: $is_pool__owner__id, is_pool__is_protected: $is_pool__is_protected, member_type__value: $member_type__value, member_type__values: $member_type__values, member_type__isnull: $member_type__isnull, member_type__source__id: $member_type__source__id, member_type__owner__id: $member_type__owner__id, member_type__is_protected: $member_type__is_protected, prefix__value: $prefix__value, prefix__values: $prefix__values, prefix__isnull: $prefix__isnull, prefix__source__id: $prefix__source__id, prefix__owner__id: $prefix__owner__id, prefix__is_protected: $prefix__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__netmask__value: $related_nodes__netmask__value, related_nodes__netmask__values: $related_nodes__netmask__values, related_nodes__netmask__source__id: $related_nodes__netmask__source__id, related_nodes__netmask__owner__id: $related_nodes__netmask__owner__id, related_nodes__netmask__is_protected: $related_nodes__netmask__is_protected, related_nodes__hostmask__value: $related_nodes__hostmask__value, related_nodes__hostmask__values: $related_nodes__hostmask__values, related_nodes__hostmask__source__id: $related_nodes__hostmask__source__id, related_nodes__hostmask__owner__id: $related_nodes__hostmask__owner__id, related_nodes__hostmask__is_protected: $related_nodes__hostmask__is_protected, related_nodes__is_top_level__value: $related_nodes__is_top_level__value, related_nodes__is_top_level__values: $related_nodes__is_top_level__values, related_nodes__is_top_level__source__id: $related_nodes__is_top_level__source__id, related_nodes__is_top_level__owner__id: $related_nodes__is_top_level__owner__id, related_nodes__is_top_level__is_protected: $related_nodes__is_top_level__is_protected, related_nodes__utilization__value: $related_nodes__utilization__value, related_nodes__utilization__values: $related_nodes__utilization__values, related_nodes__utilization__source__id: $related_nodes__utilization__source__id, related_nodes__utilization__owner__id: $related_nodes__utilization__owner__id, related_nodes__utilization__is_protected: $related_nodes__utilization__is_protected, related_nodes__is_pool__value: $related_nodes__is_pool__value, related_nodes__is_pool__values: $related_nodes__is_pool__values, related_nodes__is_pool__source__id: $related_nodes__is_pool__source__id, related_nodes__is_pool__owner__id: $related_nodes__is_pool__owner__id, related_nodes__is_pool__is_protected: $related_nodes__is_pool__is_protected, related_nodes__broadcast_address__value: $related_nodes__broadcast_address__value, related_nodes__broadcast_address__values: $related_nodes__broadcast_address__values, related_nodes__broadcast_address__source__id: $related_nodes__broadcast_address__source__id, related_nodes__broadcast_address__owner__id: $related_nodes__broadcast_address__owner__id, related_nodes__broadcast_address__is_protected: $related_nodes__broadcast_address__is_protected, related_nodes__member_type__value: $related_nodes__member_type__value, related_nodes__member_type__values: $related_nodes__member_type__values, related_nodes__member_type__source__id: $related_nodes__member_type__source__id, related_nodes__member_type__owner__id: $related_nodes__member_type__owner__id, related_nodes__member_type__is_protected: $related_nodes__member_type__is_protected, related_nodes__network_address__value: $related_nodes__network_address__value, related_nodes__network_address__values: $related_nodes__network_address__values, related_nodes__network_address__source__id: $related_nodes__network_address__source__id, related_nodes__network_address__owner__id: $related_nodes__network_address__owner__id, related_nodes__network_address__is_protected: $related_nodes__network_address__is_protected, related_nodes__prefix__value: $related_nodes__prefix__value, related_nodes__prefix__values: $related_nodes__prefix__values, related_nodes__prefix__source__id: $related_nodes__prefix__source__id, related_nodes__prefix__owner__id: $related_nodes__prefix__owner__id, related_nodes__prefix__is_protected: $related_nodes__prefix__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, ip_namespace__ids: $ip_namespace__ids, ip_namespace__isnull: $ip_namespace__isnull, ip_namespace__display_label__value: $ip_namespace__display_label__value, ip_namespace__display_label__values: $ip_namespace__display_label__values, ip_namespace__display_label__isnull: $ip_namespace__display_label__isnull, ip_namespace__description__value: $ip_namespace__description__value, ip_namespace__description__values: $ip_namespace__description__values, ip_namespace__description__source__id: $ip_namespace__description__source__id, ip_namespace__description__owner__id: $ip_namespace__description__owner__id, ip_namespace__description__is_protected: $ip_namespace__description__is_protected, ip_namespace__name__value: $ip_namespace__name__value, ip_namespace__name__values: $ip_namespace__name__values, ip_namespace__name__source__id: $ip_namespace__name__source__id, ip_namespace__name__owner__id: $ip_namespace__name__owner__id, ip_namespace__name__is_protected: $ip_namespace__name__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { count edges { node { id hfid display_label profile_name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } profile_priority { is_default is_protected updated_at id is_from_profile permissions { __typename } value } is_pool { is_default is_protected updated_at id is_from_profile permissions { __typename } value } member_type { is_default is_protected updated_at value label color description id is_from_profile permissions { __typename } } prefix { is_default is_protected updated_at id is_from_profile permissions { __typename } value broadcast_address hostmask netmask prefixlen num_addresses version with_hostmask with_netmask } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } related_nodes { count edges { __typename } } ip_namespace { node_metadata { __typename } properties { __typename } relationship_metadata { __typename } } member_of_groups { count edges { __typename } } subscriber_of_groups { count edges { __typename } } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
+        let query = r#"query ProfileBuiltinIPPrefix($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $is_pool__value: Boolean, $is_pool__values: [Boolean], $is_pool__isnull: Boolean, $is_pool__source__id: ID, $is_pool__owner__id: ID, $is_pool__is_protected: Boolean, $member_type__value: String, $member_type__values: [String], $member_type__isnull: Boolean, $member_type__source__id: ID, $member_type__owner__id: ID, $member_type__is_protected: Boolean, $prefix__value: String, $prefix__values: [String], $prefix__isnull: Boolean, $prefix__source__id: ID, $prefix__owner__id: ID, $prefix__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__netmask__value: String, $related_nodes__netmask__values: [String], $related_nodes__netmask__source__id: ID, $related_nodes__netmask__owner__id: ID, $related_nodes__netmask__is_protected: Boolean, $related_nodes__hostmask__value: String, $related_nodes__hostmask__values: [String], $related_nodes__hostmask__source__id: ID, $related_nodes__hostmask__owner__id: ID, $related_nodes__hostmask__is_protected: Boolean, $related_nodes__is_top_level__value: Boolean, $related_nodes__is_top_level__values: [Boolean], $related_nodes__is_top_level__source__id: ID, $related_nodes__is_top_level__owner__id: ID, $related_nodes__is_top_level__is_protected: Boolean, $related_nodes__utilization__value: BigInt, $related_nodes__utilization__values: [BigInt], $related_nodes__utilization__source__id: ID, $related_nodes__utilization__owner__id: ID, $related_nodes__utilization__is_protected: Boolean, $related_nodes__is_pool__value: Boolean, $related_nodes__is_pool__values: [Boolean], $related_nodes__is_pool__source__id: ID, $related_nodes__is_pool__owner__id: ID, $related_nodes__is_pool__is_protected: Boolean, $related_nodes__broadcast_address__value: String, $related_nodes__broadcast_address__values: [String], $related_nodes__broadcast_address__source__id: ID, $related_nodes__broadcast_address__owner__id: ID, $related_nodes__broadcast_address__is_protected: Boolean, $related_nodes__member_type__value: String, $related_nodes__member_type__values: [String], $related_nodes__member_type__source__id: ID, $related_nodes__member_type__owner__id: ID, $related_nodes__member_type__is_protected: Boolean, $related_nodes__network_address__value: String, $related_nodes__network_address__values: [String], $related_nodes__network_address__source__id: ID, $related_nodes__network_address__owner__id: ID, $related_nodes__network_address__is_protected: Boolean, $related_nodes__prefix__value: String, $related_nodes__prefix__values: [String], $related_nodes__prefix__source__id: ID, $related_nodes__prefix__owner__id: ID, $related_nodes__prefix__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $ip_namespace__ids: [ID], $ip_namespace__isnull: Boolean, $ip_namespace__display_label__value: String, $ip_namespace__display_label__values: [String], $ip_namespace__display_label__isnull: Boolean, $ip_namespace__description__value: String, $ip_namespace__description__values: [String], $ip_namespace__description__source__id: ID, $ip_namespace__description__owner__id: ID, $ip_namespace__description__is_protected: Boolean, $ip_namespace__name__value: String, $ip_namespace__name__values: [String], $ip_namespace__name__source__id: ID, $ip_namespace__name__owner__id: ID, $ip_namespace__name__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileBuiltinIPPrefix(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, is_pool__value: $is_pool__value, is_pool__values: $is_pool__values, is_pool__isnull: $is_pool__isnull, is_pool__source__id: $is_pool__source__id, is_pool__owner__id: $is_pool__owner__id, is_pool__is_protected: $is_pool__is_protected, member_type__value: $member_type__value, member_type__values: $member_type__values, member_type__isnull: $member_type__isnull, member_type__source__id: $member_type__source__id, member_type__owner__id: $member_type__owner__id, member_type__is_protected: $member_type__is_protected, prefix__value: $prefix__value, prefix__values: $prefix__values, prefix__isnull: $prefix__isnull, prefix__source__id: $prefix__source__id, prefix__owner__id: $prefix__owner__id, prefix__is_protected: $prefix__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__netmask__value: $related_nodes__netmask__value, related_nodes__netmask__values: $related_nodes__netmask__values, related_nodes__netmask__source__id: $related_nodes__netmask__source__id, related_nodes__netmask__owner__id: $related_nodes__netmask__owner__id, related_nodes__netmask__is_protected: $related_nodes__netmask__is_protected, related_nodes__hostmask__value: $related_nodes__hostmask__value, related_nodes__hostmask__values: $related_nodes__hostmask__values, related_nodes__hostmask__source__id: $related_nodes__hostmask__source__id, related_nodes__hostmask__owner__id: $related_nodes__hostmask__owner__id, related_nodes__hostmask__is_protected: $related_nodes__hostmask__is_protected, related_nodes__is_top_level__value: $related_nodes__is_top_level__value, related_nodes__is_top_level__values: $related_nodes__is_top_level__values, related_nodes__is_top_level__source__id: $related_nodes__is_top_level__source__id, related_nodes__is_top_level__owner__id: $related_nodes__is_top_level__owner__id, related_nodes__is_top_level__is_protected: $related_nodes__is_top_level__is_protected, related_nodes__utilization__value: $related_nodes__utilization__value, related_nodes__utilization__values: $related_nodes__utilization__values, related_nodes__utilization__source__id: $related_nodes__utilization__source__id, related_nodes__utilization__owner__id: $related_nodes__utilization__owner__id, related_nodes__utilization__is_protected: $related_nodes__utilization__is_protected, related_nodes__is_pool__value: $related_nodes__is_pool__value, related_nodes__is_pool__values: $related_nodes__is_pool__values, related_nodes__is_pool__source__id: $related_nodes__is_pool__source__id, related_nodes__is_pool__owner__id: $related_nodes__is_pool__owner__id, related_nodes__is_pool__is_protected: $related_nodes__is_pool__is_protected, related_nodes__broadcast_address__value: $related_nodes__broadcast_address__value, related_nodes__broadcast_address__values: $related_nodes__broadcast_address__values, related_nodes__broadcast_address__source__id: $related_nodes__broadcast_address__source__id, related_nodes__broadcast_address__owner__id: $related_nodes__broadcast_address__owner__id, related_nodes__broadcast_address__is_protected: $related_nodes__broadcast_address__is_protected, related_nodes__member_type__value: $related_nodes__member_type__value, related_nodes__member_type__values: $related_nodes__member_type__values, related_nodes__member_type__source__id: $related_nodes__member_type__source__id, related_nodes__member_type__owner__id: $related_nodes__member_type__owner__id, related_nodes__member_type__is_protected: $related_nodes__member_type__is_protected, related_nodes__network_address__value: $related_nodes__network_address__value, related_nodes__network_address__values: $related_nodes__network_address__values, related_nodes__network_address__source__id: $related_nodes__network_address__source__id, related_nodes__network_address__owner__id: $related_nodes__network_address__owner__id, related_nodes__network_address__is_protected: $related_nodes__network_address__is_protected, related_nodes__prefix__value: $related_nodes__prefix__value, related_nodes__prefix__values: $related_nodes__prefix__values, related_nodes__prefix__source__id: $related_nodes__prefix__source__id, related_nodes__prefix__owner__id: $related_nodes__prefix__owner__id, related_nodes__prefix__is_protected: $related_nodes__prefix__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, ip_namespace__ids: $ip_namespace__ids, ip_namespace__isnull: $ip_namespace__isnull, ip_namespace__display_label__value: $ip_namespace__display_label__value, ip_namespace__display_label__values: $ip_namespace__display_label__values, ip_namespace__display_label__isnull: $ip_namespace__display_label__isnull, ip_namespace__description__value: $ip_namespace__description__value, ip_namespace__description__values: $ip_namespace__description__values, ip_namespace__description__source__id: $ip_namespace__description__source__id, ip_namespace__description__owner__id: $ip_namespace__description__owner__id, ip_namespace__description__is_protected: $ip_namespace__description__is_protected, ip_namespace__name__value: $ip_namespace__name__value, ip_namespace__name__values: $ip_namespace__name__values, ip_namespace__name__source__id: $ip_namespace__name__source__id, ip_namespace__name__owner__id: $ip_namespace__name__owner__id, ip_namespace__name__is_protected: $ip_namespace__name__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { count edges { node { id hfid display_label profile_name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } profile_priority { is_default is_protected updated_at id is_from_profile permissions { __typename } value } is_pool { is_default is_protected updated_at id is_from_profile permissions { __typename } value } member_type { is_default is_protected updated_at value label color description id is_from_profile permissions { __typename } } prefix { is_default is_protected updated_at id is_from_profile permissions { __typename } value broadcast_address hostmask netmask prefixlen num_addresses version with_hostmask with_netmask } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } related_nodes { count } ip_namespace { node { id display_label ip_prefixes { count } member_of_groups { count } subscriber_of_groups { count } ip_addresses { count } profiles { count } } } member_of_groups { count } subscriber_of_groups { count } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
         let fetch: BoxFetch<'a, String, (ProfileBuiltinIPPrefixResponse, i64)> = Box::new(move |cursor: Option<String>| -> BoxFutureResult<'a, (ProfileBuiltinIPPrefixResponse, i64)> {
             let mut page_filters = base_filters.clone();
             let branch = request_branch.clone();
@@ -1205,11 +1321,12 @@ impl<'a> ProfileBuiltinIPPrefixClient<'a> {
             Box::pin(async move {
             let vars = page_filters.to_vars()?;
                 let response = client.execute::<ProfileBuiltinIPPrefixResponse>(query, Some(vars), branch.as_deref()).await?;
-                let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+                let data = response.into_data()?;
                 Ok((data, current_offset))
             })
         });
         let extract: BoxExtract<'a, ProfileBuiltinIPPrefix, String, (ProfileBuiltinIPPrefixResponse, i64)> = Box::new(move |(data, current_offset): (ProfileBuiltinIPPrefixResponse, i64)| -> Result<EdgePage<ProfileBuiltinIPPrefix, String>> {
+            let total_count = Some(data.profile_builtin_ip_prefix.count);
             let mut items = Vec::new();
             let mut next: Option<String> = None;
             for edge in data.profile_builtin_ip_prefix.edges {
@@ -1220,9 +1337,12 @@ impl<'a> ProfileBuiltinIPPrefixClient<'a> {
             if !items.is_empty() {
                 next = Some((current_offset + items.len() as i64).to_string());
             }
-            Ok(EdgePage { nodes: items, next_cursor: next })
+            let has_next_page = total_count.map(|total| current_offset + (items.len() as i64) < total);
+            let end_cursor = None;
+            let info = EdgePageInfo { has_next_page, end_cursor, total_count };
+            Ok(EdgePage::new(items, next).with_info(info))
         });
-        infrahub::Paginator::new(fetch, extract)
+        infrahub::Paginator::new(fetch, extract).with_total_count(|(data, _)| Some(data.profile_builtin_ip_prefix.count))
     }
 
     pub async fn get_by_id(&self, id: impl Into<String>, request_branch: Option<&str>) -> Result<Option<ProfileBuiltinIPPrefix>> {
@@ -1232,6 +1352,78 @@ impl<'a> ProfileBuiltinIPPrefixClient<'a> {
         Ok(items.pop())
     }
 
+    pub async fn related_nodes_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<serde_json::Value, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query ProfileBuiltinIPPrefixRelatedNodes($ids: [ID], $offset: Int, $limit: Int) { ProfileBuiltinIPPrefix(ids: $ids) { edges { node { related_nodes(offset: $offset, limit: $limit) { count edges { node { id __typename } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["ProfileBuiltinIPPrefix"]["edges"][0]["node"]["related_nodes"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("ProfileBuiltinIPPrefix {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
+    pub async fn member_of_groups_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<serde_json::Value, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query ProfileBuiltinIPPrefixMemberOfGroups($ids: [ID], $offset: Int, $limit: Int) { ProfileBuiltinIPPrefix(ids: $ids) { edges { node { member_of_groups(offset: $offset, limit: $limit) { count edges { node { id __typename } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["ProfileBuiltinIPPrefix"]["edges"][0]["node"]["member_of_groups"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("ProfileBuiltinIPPrefix {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
+    pub async fn subscriber_of_groups_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<serde_json::Value, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query ProfileBuiltinIPPrefixSubscriberOfGroups($ids: [ID], $offset: Int, $limit: Int) { ProfileBuiltinIPPrefix(ids: $ids) { edges { node { subscriber_of_groups(offset: $offset, limit: $limit) { count edges { node { id __typename } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["ProfileBuiltinIPPrefix"]["edges"][0]["node"]["subscriber_of_groups"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("ProfileBuiltinIPPrefix {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
 }
 
 #[derive(Debug, Clone, Default)]
@@ -1588,9 +1780,9 @@ impl<'a> ProfileBuiltinTagClient<'a> {
 
     pub async fn list(&self, filters: Option<ProfileBuiltinTagFilters>, request_branch: Option<&str>) -> Result<Vec<ProfileBuiltinTag>> {
         let vars = filters.map(|f| f.to_vars()).transpose()?.unwrap_or_else(|| Value::Object(serde_json::Map::new()));
-        let query = r#"query ProfileBuiltinTag($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__name__value: String, $related_nodes__name__values: [String], $related_nodes__name__source__id: ID, $related_nodes__name__owner__id: ID, $related_nodes__name__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileBuiltinTag(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__name__value: $related_nodes__name__value, related_nodes__name__values: $related_nodes__name__values, related_nodes__name__source__id: $related_nodes__name__source__id, related_nodes__name__owner__id: $related_nodes__name__owner__id, related_nodes__name__is_protected: $related_nodes__name__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { count edges { node { id hfid display_label profile_name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } profile_priority { is_default is_protected updated_at id is_from_profile permissions { __typename } value } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } related_nodes { count edges { __typename } permissions { __typename } } member_of_groups { count edges { __typename } } subscriber_of_groups { count edges { __typename } } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
+        let query = r#"query ProfileBuiltinTag($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__name__value: String, $related_nodes__name__values: [String], $related_nodes__name__source__id: ID, $related_nodes__name__owner__id: ID, $related_nodes__name__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileBuiltinTag(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__name__value: $related_nodes__name__value, related_nodes__name__values: $related_nodes__name__values, related_nodes__name__source__id: $related_nodes__name__source__id, related_nodes__name__owner__id: $related_nodes__name__owner__id, related_nodes__name__is_protected: $related_nodes__name__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { count edges { node { id hfid display_label profile_name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } profile_priority { is_default is_protected updated_at id is_from_profile permissions { __typename } value } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } related_nodes { count permissions { count } } member_of_groups { count } subscriber_of_groups { count } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
         let response = self.client.execute::<ProfileBuiltinTagResponse>(query, Some(vars), request_branch).await?;
-        let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+        let data = response.into_data()?;
         let mut items = Vec::new();
         for edge in data.profile_builtin_tag.edges {
             if let Some(node) = edge.node {
@@ -1600,11 +1792,31 @@ impl<'a> ProfileBuiltinTagClient<'a> {
         Ok(items)
     }
 
+    pub async fn list_with_selection<T: serde::de::DeserializeOwned>(&self, filters: Option<ProfileBuiltinTagFilters>, selection: &Selection, request_branch: Option<&str>) -> Result<Vec<T>> {
+        crate::schema::REGISTRY.validate("ProfileBuiltinTag", selection)?;
+        let vars = filters.map(|f| f.to_vars()).transpose()?.unwrap_or_else(|| Value::Object(serde_json::Map::new()));
+        let query = [r#"query ProfileBuiltinTag($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__name__value: String, $related_nodes__name__values: [String], $related_nodes__name__source__id: ID, $related_nodes__name__owner__id: ID, $related_nodes__name__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileBuiltinTag(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__name__value: $related_nodes__name__value, related_nodes__name__values: $related_nodes__name__values, related_nodes__name__source__id: $related_nodes__name__source__id, related_nodes__name__owner__id: $related_nodes__name__owner__id, related_nodes__name__is_protected: $related_nodes__name__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { edges { node "#, &selection.render()?, " } } }"].concat();
+        let response = self.client.execute_raw(&query, Some(vars), request_branch).await?;
+        let mut data = response.into_data()?;
+        let edges = match data.get_mut("ProfileBuiltinTag").and_then(|v| v.get_mut("edges")).map(Value::take) {
+            Some(Value::Array(edges)) => edges,
+            _ => Vec::new(),
+        };
+        let mut items = Vec::new();
+        for mut edge in edges {
+            match edge.get_mut("node").map(Value::take) {
+                Some(Value::Null) | None => {}
+                Some(node) => items.push(serde_json::from_value(node)?),
+            }
+        }
+        Ok(items)
+    }
+
     pub fn paginate(&self, filters: Option<ProfileBuiltinTagFilters>, request_branch: Option<&str>) -> DynPaginator<'a, ProfileBuiltinTag, String, (ProfileBuiltinTagResponse, i64)> {
         let client = self.client;
         let base_filters = filters.unwrap_or_default();
         let request_branch = request_branch.map(|b| b.to_string());
-        let query = r#"query ProfileBuiltinTag($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__name__value: String, $related_nodes__name__values: [String], $related_nodes__name__source__id: ID, $related_nodes__name__owner__id: ID, $related_nodes__name__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileBuiltinTag(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__name__value: $related_nodes__name__value, related_nodes__name__values: $related_nodes__name__values, related_nodes__name__source__id: $related_nodes__name__source__id, related_nodes__name__owner__id: $related_nodes__name__owner__id, related_nodes__name__is_protected: $related_nodes__name__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { count edges { node { id hfid display_label profile_name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } profile_priority { is_default is_protected updated_at id is_from_profile permissions { __typename } value } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } related_nodes { count edges { __typename } permissions { __typename } } member_of_groups { count edges { __typename } } subscriber_of_groups { count edges { __typename } } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
+        let query = r#"query ProfileBuiltinTag($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__name__value: String, $related_nodes__name__values: [String], $related_nodes__name__source__id: ID, $related_nodes__name__owner__id: ID, $related_nodes__name__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileBuiltinTag(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__name__value: $related_nodes__name__value, related_nodes__name__values: $related_nodes__name__values, related_nodes__name__source__id: $related_nodes__name__source__id, related_nodes__name__owner__id: $related_nodes__name__owner__id, related_nodes__name__is_protected: $related_nodes__name__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { count edges { node { id hfid display_label profile_name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } profile_priority { is_default is_protected updated_at id is_from_profile permissions { __typename } value } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } related_nodes { count permissions { count } } member_of_groups { count } subscriber_of_groups { count } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
         let fetch: BoxFetch<'a, String, (ProfileBuiltinTagResponse, i64)> = Box::new(move |cursor: Option<String>| -> BoxFutureResult<'a, (ProfileBuiltinTagResponse, i64)> {
             let mut page_filters = base_filters.clone();
             let branch = request_branch.clone();
@@ -1618,11 +1830,12 @@ impl<'a> ProfileBuiltinTagClient<'a> {
             Box::pin(async move {
             let vars = page_filters.to_vars()?;
                 let response = client.execute::<ProfileBuiltinTagResponse>(query, Some(vars), branch.as_deref()).await?;
-                let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+                let data = response.into_data()?;
                 Ok((data, current_offset))
             })
         });
         let extract: BoxExtract<'a, ProfileBuiltinTag, String, (ProfileBuiltinTagResponse, i64)> = Box::new(move |(data, current_offset): (ProfileBuiltinTagResponse, i64)| -> Result<EdgePage<ProfileBuiltinTag, String>> {
+            let total_count = Some(data.profile_builtin_tag.count);
             let mut items = Vec::new();
             let mut next: Option<String> = None;
             for edge in data.profile_builtin_tag.edges {
@@ -1633,9 +1846,12 @@ impl<'a> ProfileBuiltinTagClient<'a> {
             if !items.is_empty() {
                 next = Some((current_offset + items.len() as i64).to_string());
             }
-            Ok(EdgePage { nodes: items, next_cursor: next })
+            let has_next_page = total_count.map(|total| current_offset + (items.len() as i64) < total);
+            let end_cursor = None;
+            let info = EdgePageInfo { has_next_page, end_cursor, total_count };
+            Ok(EdgePage::new(items, next).with_info(info))
         });
-        infrahub::Paginator::new(fetch, extract)
+        infrahub::Paginator::new(fetch, extract).with_total_count(|(data, _)| Some(data.profile_builtin_tag.count))
     }
 
     pub async fn get_by_id(&self, id: impl Into<String>, request_branch: Option<&str>) -> Result<Option<ProfileBuiltinTag>> {
@@ -1645,6 +1861,78 @@ impl<'a> ProfileBuiltinTagClient<'a> {
         Ok(items.pop())
     }
 
+    pub async fn related_nodes_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<BuiltinTag, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query ProfileBuiltinTagRelatedNodes($ids: [ID], $offset: Int, $limit: Int) { ProfileBuiltinTag(ids: $ids) { edges { node { related_nodes(offset: $offset, limit: $limit) { count edges { node { id hfid display_label name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } subscriber_of_groups { count } member_of_groups { count } profiles { count } } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["ProfileBuiltinTag"]["edges"][0]["node"]["related_nodes"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("ProfileBuiltinTag {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
+    pub async fn member_of_groups_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<serde_json::Value, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query ProfileBuiltinTagMemberOfGroups($ids: [ID], $offset: Int, $limit: Int) { ProfileBuiltinTag(ids: $ids) { edges { node { member_of_groups(offset: $offset, limit: $limit) { count edges { node { id __typename } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["ProfileBuiltinTag"]["edges"][0]["node"]["member_of_groups"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("ProfileBuiltinTag {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
+    pub async fn subscriber_of_groups_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<serde_json::Value, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query ProfileBuiltinTagSubscriberOfGroups($ids: [ID], $offset: Int, $limit: Int) { ProfileBuiltinTag(ids: $ids) { edges { node { subscriber_of_groups(offset: $offset, limit: $limit) { count edges { node { id __typename } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["ProfileBuiltinTag"]["edges"][0]["node"]["subscriber_of_groups"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("ProfileBuiltinTag {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
 }
 
 #[derive(Debug, Clone, Default)]
@@ -2301,9 +2589,9 @@ impl<'a> ProfileIpamNamespaceClient<'a> {
 
     pub async fn list(&self, filters: Option<ProfileIpamNamespaceFilters>, request_branch: Option<&str>) -> Result<Vec<ProfileIpamNamespace>> {
         let vars = filters.map(|f| f.to_vars()).transpose()?.unwrap_or_else(|| Value::Object(serde_json::Map::new()));
-        let query = r#"query ProfileIpamNamespace($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__default__value: Boolean, $related_nodes__default__values: [Boolean], $related_nodes__default__source__id: ID, $related_nodes__default__owner__id: ID, $related_nodes__default__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $related_nodes__name__value: String, $related_nodes__name__values: [String], $related_nodes__name__source__id: ID, $related_nodes__name__owner__id: ID, $related_nodes__name__is_protected: Boolean, $ip_prefixes__ids: [ID], $ip_prefixes__isnull: Boolean, $ip_prefixes__display_label__value: String, $ip_prefixes__display_label__values: [String], $ip_prefixes__display_label__isnull: Boolean, $ip_prefixes__netmask__value: String, $ip_prefixes__netmask__values: [String], $ip_prefixes__netmask__source__id: ID, $ip_prefixes__netmask__owner__id: ID, $ip_prefixes__netmask__is_protected: Boolean, $ip_prefixes__hostmask__value: String, $ip_prefixes__hostmask__values: [String], $ip_prefixes__hostmask__source__id: ID, $ip_prefixes__hostmask__owner__id: ID, $ip_prefixes__hostmask__is_protected: Boolean, $ip_prefixes__is_top_level__value: Boolean, $ip_prefixes__is_top_level__values: [Boolean], $ip_prefixes__is_top_level__source__id: ID, $ip_prefixes__is_top_level__owner__id: ID, $ip_prefixes__is_top_level__is_protected: Boolean, $ip_prefixes__utilization__value: BigInt, $ip_prefixes__utilization__values: [BigInt], $ip_prefixes__utilization__source__id: ID, $ip_prefixes__utilization__owner__id: ID, $ip_prefixes__utilization__is_protected: Boolean, $ip_prefixes__is_pool__value: Boolean, $ip_prefixes__is_pool__values: [Boolean], $ip_prefixes__is_pool__source__id: ID, $ip_prefixes__is_pool__owner__id: ID, $ip_prefixes__is_pool__is_protected: Boolean, $ip_prefixes__broadcast_address__value: String, $ip_prefixes__broadcast_address__values: [String], $ip_prefixes__broadcast_address__source__id: ID, $ip_prefixes__broadcast_address__owner__id: ID, $ip_prefixes__broadcast_address__is_protected: Boolean, $ip_prefixes__member_type__value: String, $ip_prefixes__member_type__values: [String], $ip_prefixes__member_type__source__id: ID, $ip_prefixes__member_type__owner__id: ID, $ip_prefixes__member_type__is_protected: Boolean, $ip_prefixes__network_address__value: String, $ip_prefixes__network_address__values: [String], $ip_prefixes__network_address__source__id: ID, $ip_prefixes__network_address__owner__id: ID, $ip_prefixes__network_address__is_protected: Boolean, $ip_prefixes__prefix__value: String, $ip_prefixes__prefix__values: [String], $ip_prefixes__prefix__source__id: ID, $ip_prefixes__prefix__owner__id: ID, $ip_prefixes__prefix__is_protected: Boolean, $ip_prefixes__description__value: String, $ip_prefixes__description__values: [String], $ip_prefixes__description__source__id: ID, $ip_prefixes__description__owner__id: ID, $ip_prefixes__description__is_protected: Boolean, $ip_addresses__ids: [ID], $ip_addresses__isnull: Boolean, $ip_addresses__display_label__value: String, $ip_addresses__display_label__values: [String], $ip_addresses__display_label__isnull: Boolean, $ip_addresses__address__value: String, $ip_addresses__address__values: [String], $ip_addresses__address__source__id: ID, $ip_addresses__address__owner__id: ID, $ip_addresses__address__is_protected: Boolean, $ip_addresses__description__value: String, $ip_addresses__description__values: [String], $ip_addresses__description__source__id: ID, $ip_addresses__description__owner__id: ID, $ip_addresses__description__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileIpamNamespace(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__default__value: $related_nodes__default__value, related_nodes__default__values: $related_nodes__default__values, related_nodes__default__source__id: $related_nodes__default__source__id, related_nodes__default__owner__id: $related_nodes__default__owner__id, related_nodes__default__is_protected: $related_nodes__default__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, related_nodes__name__value: $related_nodes__name__value, related_nodes__name__values: $related_nodes__name__values, related_nodes__name__source__id: $related_nodes__name__source__id, related_nodes__name__owner__id: $related_nodes__name__owner__id, related_nodes__name__is_protected: $related_nodes__name__is_protected, ip_prefixes__ids: $ip_prefixes__ids, ip_prefixes__isnull: $ip_prefixes__isnull, ip_prefixes__display_label__value: $ip_prefixes__display_label__value, ip_prefixes__display_label__values: $ip_prefixes__display_label__values, ip_prefixes__display_label__isnull: $ip_prefixes__display_label__isnull, ip_prefixes__netmask__value: $ip_prefixes__netmask__value, ip_prefixes__netmask__values: $ip_prefixes__netmask__values, ip_prefixes__netmask__source__id: $ip_prefixes__netmask__source__id, ip_prefixes__netmask__owner__id: $ip_prefixes__netmask__owner__id, ip_prefixes__netmask__is_protected: $ip_prefixes__netmask__is_protected, ip_prefixes__hostmask__value: $ip_prefixes__hostmask__value, ip_prefixes__hostmask__values: $ip_prefixes__hostmask__values, ip_prefixes__hostmask__source__id: $ip_prefixes__hostmask__source__id, ip_prefixes__hostmask__owner__id: $ip_prefixes__hostmask__owner__id, ip_prefixes__hostmask__is_protected: $ip_prefixes__hostmask__is_protected, ip_prefixes__is_top_level__value: $ip_prefixes__is_top_level__value, ip_prefixes__is_top_level__values: $ip_prefixes__is_top_level__values, ip_prefixes__is_top_level__source__id: $ip_prefixes__is_top_level__source__id, ip_prefixes__is_top_level__owner__id: $ip_prefixes__is_top_level__owner__id, ip_prefixes__is_top_level__is_protected: $ip_prefixes__is_top_level__is_protected, ip_prefixes__utilization__value: $ip_prefixes__utilization__value, ip_prefixes__utilization__values: $ip_prefixes__utilization__values, ip_prefixes__utilization__source__id: $ip_prefixes__utilization__source__id, ip_prefixes__utilization__owner__id: $ip_prefixes__utilization__owner__id, ip_prefixes__utilization__is_protected: $ip_prefixes__utilization__is_protected, ip_prefixes__is_pool__value: $ip_prefixes__is_pool__value, ip_prefixes__is_pool__values: $ip_prefixes__is_pool__values, ip_prefixes__is_pool__source__id: $ip_prefixes__is_pool__source__id, ip_prefixes__is_pool__owner__id: $ip_prefixes__is_pool__owner__id, ip_prefixes__is_pool__is_protected: $ip_prefixes__is_pool__is_protected, ip_prefixes__broadcast_address__value: $ip_prefixes__broadcast_address__value, ip_prefixes__broadcast_address__values: $ip_prefixes__broadcast_address__values, ip_prefixes__broadcast_address__source__id: $ip_prefixes__broadcast_address__source__id, ip_prefixes__broadcast_address__owner__id: $ip_prefixes__broadcast_address__owner__id, ip_prefixes__broadcast_address__is_protected: $ip_prefixes__broadcast_address__is_protected, ip_prefixes__member_type__value: $ip_prefixes__member_type__value, ip_prefixes__member_type__values: $ip_prefixes__member_type__values, ip_prefixes__member_type__source__id: $ip_prefixes__member_type__source__id, ip_prefixes__member_type__owner__id: $ip_prefixes__member_type__owner__id, ip_prefixes__member_type__is_protected: $ip_prefixes__member_type__is_protected, ip_prefixes__network_address__value: $ip_prefixes__network_address__value, ip_prefixes__network_address__values: $ip_prefixes__network_address__values, ip_prefixes__network_address__source__id: $ip_prefixes__network_address__source__id, ip_prefixes__network_address__owner__id: $ip_prefixes__network_address__owner__id, ip_prefixes__network_address__is_protected: $ip_prefixes__network_address__is_protected, ip_prefixes__prefix__value: $ip_prefixes__prefix__value, ip_prefixes__prefix__values: $ip_prefixes__prefix__values, ip_prefixes__prefix__source__id: $ip_prefixes__prefix__source__id, ip_prefixes__prefix__owner__id: $ip_prefixes__prefix__owner__id, ip_prefixes__prefix__is_protected: $ip_prefixes__prefix__is_protected, ip_prefixes__description__value: $ip_prefixes__description__value, ip_prefixes__description__values: $ip_prefixes__description__values, ip_prefixes__description__source__id: $ip_prefixes__description__source__id, ip_prefixes__description__owner__id: $ip_prefixes__description__owner__id, ip_prefixes__description__is_protected: $ip_prefixes__description__is_protected, ip_addresses__ids: $ip_addresses__ids, ip_addresses__isnull: $ip_addresses__isnull, ip_addresses__display_label__value: $ip_addresses__display_label__value, ip_addresses__display_label__values: $ip_addresses__display_label__values, ip_addresses__display_label__isnull: $ip_addresses__display_label__isnull, ip_addresses__address__value: $ip_addresses__address__value, ip_addresses__address__values: $ip_addresses__address__values, ip_addresses__address__source__id: $ip_addresses__address__source__id, ip_addresses__address__owner__id: $ip_addresses__address__owner__id, ip_addresses__address__is_protected: $ip_addresses__address__is_protected, ip_addresses__description__value: $ip_addresses__description__value, ip_addresses__description__values: $ip_addresses__description__values, ip_addresses__description__source__id: $ip_addresses__description__source__id, ip_addresses__description__owner__id: $ip_addresses__description__owner__id, ip_addresses__description__is_protected: $ip_addresses__description__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { count edges { node { id hfid display_label profile_name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } profile_priority { is_default is_protected updated_at id is_from_profile permissions { __typename } value } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } related_nodes { count edges { __typename } permissions { __typename } } ip_prefixes { count edges { __typename } } ip_addresses { count edges { __typename } } member_of_groups { count edges { __typename } } subscriber_of_groups { count edges { __typename } } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
+        let query = r#"query ProfileIpamNamespace($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__default__value: Boolean, $related_nodes__default__values: [Boolean], $related_nodes__default__source__id: ID, $related_nodes__default__owner__id: ID, $related_nodes__default__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $related_nodes__name__value: String, $related_nodes__name__values: [String], $related_nodes__name__source__id: ID, $related_nodes__name__owner__id: ID, $related_nodes__name__is_protected: Boolean, $ip_prefixes__ids: [ID], $ip_prefixes__isnull: Boolean, $ip_prefixes__display_label__value: String, $ip_prefixes__display_label__values: [String], $ip_prefixes__display_label__isnull: Boolean, $ip_prefixes__netmask__value: String, $ip_prefixes__netmask__values: [String], $ip_prefixes__netmask__source__id: ID, $ip_prefixes__netmask__owner__id: ID, $ip_prefixes__netmask__is_protected: Boolean, $ip_prefixes__hostmask__value: String, $ip_prefixes__hostmask__values: [String], $ip_prefixes__hostmask__source__id: ID, $ip_prefixes__hostmask__owner__id: ID, $ip_prefixes__hostmask__is_protected: Boolean, $ip_prefixes__is_top_level__value: Boolean, $ip_prefixes__is_top_level__values: [Boolean], $ip_prefixes__is_top_level__source__id: ID, $ip_prefixes__is_top_level__owner__id: ID, $ip_prefixes__is_top_level__is_protected: Boolean, $ip_prefixes__utilization__value: BigInt, $ip_prefixes__utilization__values: [BigInt], $ip_prefixes__utilization__source__id: ID, $ip_prefixes__utilization__owner__id: ID, $ip_prefixes__utilization__is_protected: Boolean, $ip_prefixes__is_pool__value: Boolean, $ip_prefixes__is_pool__values: [Boolean], $ip_prefixes__is_pool__source__id: ID, $ip_prefixes__is_pool__owner__id: ID, $ip_prefixes__is_pool__is_protected: Boolean, $ip_prefixes__broadcast_address__value: String, $ip_prefixes__broadcast_address__values: [String], $ip_prefixes__broadcast_address__source__id: ID, $ip_prefixes__broadcast_address__owner__id: ID, $ip_prefixes__broadcast_address__is_protected: Boolean, $ip_prefixes__member_type__value: String, $ip_prefixes__member_type__values: [String], $ip_prefixes__member_type__source__id: ID, $ip_prefixes__member_type__owner__id: ID, $ip_prefixes__member_type__is_protected: Boolean, $ip_prefixes__network_address__value: String, $ip_prefixes__network_address__values: [String], $ip_prefixes__network_address__source__id: ID, $ip_prefixes__network_address__owner__id: ID, $ip_prefixes__network_address__is_protected: Boolean, $ip_prefixes__prefix__value: String, $ip_prefixes__prefix__values: [String], $ip_prefixes__prefix__source__id: ID, $ip_prefixes__prefix__owner__id: ID, $ip_prefixes__prefix__is_protected: Boolean, $ip_prefixes__description__value: String, $ip_prefixes__description__values: [String], $ip_prefixes__description__source__id: ID, $ip_prefixes__description__owner__id: ID, $ip_prefixes__description__is_protected: Boolean, $ip_addresses__ids: [ID], $ip_addresses__isnull: Boolean, $ip_addresses__display_label__value: String, $ip_addresses__display_label__values: [String], $ip_addresses__display_label__isnull: Boolean, $ip_addresses__address__value: String, $ip_addresses__address__values: [String], $ip_addresses__address__source__id: ID, $ip_addresses__address__owner__id: ID, $ip_addresses__address__is_protected: Boolean, $ip_addresses__description__value: String, $ip_addresses__description__values: [String], $ip_addresses__description__source__id: ID, $ip_addresses__description__owner__id: ID, $ip_addresses__description__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileIpamNamespace(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__default__value: $related_nodes__default__value, related_nodes__default__values: $related_nodes__default__values, related_nodes__default__source__id: $related_nodes__default__source__id, related_nodes__default__owner__id: $related_nodes__default__owner__id, related_nodes__default__is_protected: $related_nodes__default__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, related_nodes__name__value: $related_nodes__name__value, related_nodes__name__values: $related_nodes__name__values, related_nodes__name__source__id: $related_nodes__name__source__id, related_nodes__name__owner__id: $related_nodes__name__owner__id, related_nodes__name__is_protected: $related_nodes__name__is_protected, ip_prefixes__ids: $ip_prefixes__ids, ip_prefixes__isnull: $ip_prefixes__isnull, ip_prefixes__display_label__value: $ip_prefixes__display_label__value, ip_prefixes__display_label__values: $ip_prefixes__display_label__values, ip_prefixes__display_label__isnull: $ip_prefixes__display_label__isnull, ip_prefixes__netmask__value: $ip_prefixes__netmask__value, ip_prefixes__netmask__values: $ip_prefixes__netmask__values, ip_prefixes__netmask__source__id: $ip_prefixes__netmask__source__id, ip_prefixes__netmask__owner__id: $ip_prefixes__netmask__owner__id, ip_prefixes__netmask__is_protected: $ip_prefixes__netmask__is_protected, ip_prefixes__hostmask__value: $ip_prefixes__hostmask__value, ip_prefixes__hostmask__values: $ip_prefixes__hostmask__values, ip_prefixes__hostmask__source__id: $ip_prefixes__hostmask__source__id, ip_prefixes__hostmask__owner__id: $ip_prefixes__hostmask__owner__id, ip_prefixes__hostmask__is_protected: $ip_prefixes__hostmask__is_protected, ip_prefixes__is_top_level__value: $ip_prefixes__is_top_level__value, ip_prefixes__is_top_level__values: $ip_prefixes__is_top_level__values, ip_prefixes__is_top_level__source__id: $ip_prefixes__is_top_level__source__id, ip_prefixes__is_top_level__owner__id: $ip_prefixes__is_top_level__owner__id, ip_prefixes__is_top_level__is_protected: $ip_prefixes__is_top_level__is_protected, ip_prefixes__utilization__value: $ip_prefixes__utilization__value, ip_prefixes__utilization__values: $ip_prefixes__utilization__values, ip_prefixes__utilization__source__id: $ip_prefixes__utilization__source__id, ip_prefixes__utilization__owner__id: $ip_prefixes__utilization__owner__id, ip_prefixes__utilization__is_protected: $ip_prefixes__utilization__is_protected, ip_prefixes__is_pool__value: $ip_prefixes__is_pool__value, ip_prefixes__is_pool__values: $ip_prefixes__is_pool__values, ip_prefixes__is_pool__source__id: $ip_prefixes__is_pool__source__id, ip_prefixes__is_pool__owner__id: $ip_prefixes__is_pool__owner__id, ip_prefixes__is_pool__is_protected: $ip_prefixes__is_pool__is_protected, ip_prefixes__broadcast_address__value: $ip_prefixes__broadcast_address__value, ip_prefixes__broadcast_address__values: $ip_prefixes__broadcast_address__values, ip_prefixes__broadcast_address__source__id: $ip_prefixes__broadcast_address__source__id, ip_prefixes__broadcast_address__owner__id: $ip_prefixes__broadcast_address__owner__id, ip_prefixes__broadcast_address__is_protected: $ip_prefixes__broadcast_address__is_protected, ip_prefixes__member_type__value: $ip_prefixes__member_type__value, ip_prefixes__member_type__values: $ip_prefixes__member_type__values, ip_prefixes__member_type__source__id: $ip_prefixes__member_type__source__id, ip_prefixes__member_type__owner__id: $ip_prefixes__member_type__owner__id, ip_prefixes__member_type__is_protected: $ip_prefixes__member_type__is_protected, ip_prefixes__network_address__value: $ip_prefixes__network_address__value, ip_prefixes__network_address__values: $ip_prefixes__network_address__values, ip_prefixes__network_address__source__id: $ip_prefixes__network_address__source__id, ip_prefixes__network_address__owner__id: $ip_prefixes__network_address__owner__id, ip_prefixes__network_address__is_protected: $ip_prefixes__network_address__is_protected, ip_prefixes__prefix__value: $ip_prefixes__prefix__value, ip_prefixes__prefix__values: $ip_prefixes__prefix__values, ip_prefixes__prefix__source__id: $ip_prefixes__prefix__source__id, ip_prefixes__prefix__owner__id: $ip_prefixes__prefix__owner__id, ip_prefixes__prefix__is_protected: $ip_prefixes__prefix__is_protected, ip_prefixes__description__value: $ip_prefixes__description__value, ip_prefixes__description__values: $ip_prefixes__description__values, ip_prefixes__description__source__id: $ip_prefixes__description__source__id, ip_prefixes__description__owner__id: $ip_prefixes__description__owner__id, ip_prefixes__description__is_protected: $ip_prefixes__description__is_protected, ip_addresses__ids: $ip_addresses__ids, ip_addresses__isnull: $ip_addresses__isnull, ip_addresses__display_label__value: $ip_addresses__display_label__value, ip_addresses__display_label__values: $ip_addresses__display_label__values, ip_addresses__display_label__isnull: $ip_addresses__display_label__isnull, ip_addresses__address__value: $ip_addresses__address__value, ip_addresses__address__values: $ip_addresses__address__values, ip_addresses__address__source__id: $ip_addresses__address__source__id, ip_addresses__address__owner__id: $ip_addresses__address__owner__id, ip_addresses__address__is_protected: $ip_addresses__address__is_protected, ip_addresses__description__value: $ip_addresses__description__value, ip_addresses__description__values: $ip_addresses__description__values, ip_addresses__description__source__id: $ip_addresses__description__source__id, ip_addresses__description__owner__id: $ip_addresses__description__owner__id, ip_addresses__description__is_protected: $ip_addresses__description__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { count edges { node { id hfid display_label profile_name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } profile_priority { is_default is_protected updated_at id is_from_profile permissions { __typename } value } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } related_nodes { count permissions { count } } ip_prefixes { count } ip_addresses { count } member_of_groups { count } subscriber_of_groups { count } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
         let response = self.client.execute::<ProfileIpamNamespaceResponse>(query, Some(vars), request_branch).await?;
-        let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+        let data = response.into_data()?;
         let mut items = Vec::new();
         for edge in data.profile_ipam_namespace.edges {
             if let Some(node) = edge.node {
@@ -2313,11 +2601,31 @@ impl<'a> ProfileIpamNamespaceClient<'a> {
         Ok(items)
     }
 
+    pub async fn list_with_selection<T: serde::de::DeserializeOwned>(&self, filters: Option<ProfileIpamNamespaceFilters>, selection: &Selection, request_branch: Option<&str>) -> Result<Vec<T>> {
+        crate::schema::REGISTRY.validate("ProfileIpamNamespace", selection)?;
+        let vars = filters.map(|f| f.to_vars()).transpose()?.unwrap_or_else(|| Value::Object(serde_json::Map::new()));
+        let query = [r#"query ProfileIpamNamespace($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__default__value: Boolean, $related_nodes__default__values: [Boolean], $related_nodes__default__source__id: ID, $related_nodes__default__owner__id: ID, $related_nodes__default__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $related_nodes__name__value: String, $related_nodes__name__values: [String], $related_nodes__name__source__id: ID, $related_nodes__name__owner__id: ID, $related_nodes__name__is_protected: Boolean, $ip_prefixes__ids: [ID], $ip_prefixes__isnull: Boolean, $ip_prefixes__display_label__value: String, $ip_prefixes__display_label__values: [String], $ip_prefixes__display_label__isnull: Boolean, $ip_prefixes__netmask__value: String, $ip_prefixes__netmask__values: [String], $ip_prefixes__netmask__source__id: ID, $ip_prefixes__netmask__owner__id: ID, $ip_prefixes__netmask__is_protected: Boolean, $ip_prefixes__hostmask__value: String, $ip_prefixes__hostmask__values: [String], $ip_prefixes__hostmask__source__id: ID, $ip_prefixes__hostmask__owner__id: ID, $ip_prefixes__hostmask__is_protected: Boolean, $ip_prefixes__is_top_level__value: Boolean, $ip_prefixes__is_top_level__values: [Boolean], $ip_prefixes__is_top_level__source__id: ID, $ip_prefixes__is_top_level__owner__id: ID, $ip_prefixes__is_top_level__is_protected: Boolean, $ip_prefixes__utilization__value: BigInt, $ip_prefixes__utilization__values: [BigInt], $ip_prefixes__utilization__source__id: ID, $ip_prefixes__utilization__owner__id: ID, $ip_prefixes__utilization__is_protected: Boolean, $ip_prefixes__is_pool__value: Boolean, $ip_prefixes__is_pool__values: [Boolean], $ip_prefixes__is_pool__source__id: ID, $ip_prefixes__is_pool__owner__id: ID, $ip_prefixes__is_pool__is_protected: Boolean, $ip_prefixes__broadcast_address__value: String, $ip_prefixes__broadcast_address__values: [String], $ip_prefixes__broadcast_address__source__id: ID, $ip_prefixes__broadcast_address__owner__id: ID, $ip_prefixes__broadcast_address__is_protected: Boolean, $ip_prefixes__member_type__value: String, $ip_prefixes__member_type__values: [String], $ip_prefixes__member_type__source__id: ID, $ip_prefixes__member_type__owner__id: ID, $ip_prefixes__member_type__is_protected: Boolean, $ip_prefixes__network_address__value: String, $ip_prefixes__network_address__values: [String], $ip_prefixes__network_address__source__id: ID, $ip_prefixes__network_address__owner__id: ID, $ip_prefixes__network_address__is_protected: Boolean, $ip_prefixes__prefix__value: String, $ip_prefixes__prefix__values: [String], $ip_prefixes__prefix__source__id: ID, $ip_prefixes__prefix__owner__id: ID, $ip_prefixes__prefix__is_protected: Boolean, $ip_prefixes__description__value: String, $ip_prefixes__description__values: [String], $ip_prefixes__description__source__id: ID, $ip_prefixes__description__owner__id: ID, $ip_prefixes__description__is_protected: Boolean, $ip_addresses__ids: [ID], $ip_addresses__isnull: Boolean, $ip_addresses__display_label__value: String, $ip_addresses__display_label__values: [String], $ip_addresses__display_label__isnull: Boolean, $ip_addresses__address__value: String, $ip_addresses__address__values: [String], $ip_addresses__address__source__id: ID, $ip_addresses__address__owner__id: ID, $ip_addresses__address__is_protected: Boolean, $ip_addresses__description__value: String, $ip_addresses__description__values: [String], $ip_addresses__description__source__id: ID, $ip_addresses__description__owner__id: ID, $ip_addresses__description__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileIpamNamespace(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__default__value: $related_nodes__default__value, related_nodes__default__values: $related_nodes__default__values, related_nodes__default__source__id: $related_nodes__default__source__id, related_nodes__default__owner__id: $related_nodes__default__owner__id, related_nodes__default__is_protected: $related_nodes__default__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, related_nodes__name__value: $related_nodes__name__value, related_nodes__name__values: $related_nodes__name__values, related_nodes__name__source__id: $related_nodes__name__source__id, related_nodes__name__owner__id: $related_nodes__name__owner__id, related_nodes__name__is_protected: $related_nodes__name__is_protected, ip_prefixes__ids: $ip_prefixes__ids, ip_prefixes__isnull: $ip_prefixes__isnull, ip_prefixes__display_label__value: $ip_prefixes__display_label__value, ip_prefixes__display_label__values: $ip_prefixes__display_label__values, ip_prefixes__display_label__isnull: $ip_prefixes__display_label__isnull, ip_prefixes__netmask__value: $ip_prefixes__netmask__value, ip_prefixes__netmask__values: $ip_prefixes__netmask__values, ip_prefixes__netmask__source__id: $ip_prefixes__netmask__source__id, ip_prefixes__netmask__owner__id: $ip_prefixes__netmask__owner__id, ip_prefixes__netmask__is_protected: $ip_prefixes__netmask__is_protected, ip_prefixes__hostmask__value: $ip_prefixes__hostmask__value, ip_prefixes__hostmask__values: $ip_prefixes__hostmask__values, ip_prefixes__hostmask__source__id: $ip_prefixes__hostmask__source__id, ip_prefixes__hostmask__owner__id: $ip_prefixes__hostmask__owner__id, ip_prefixes__hostmask__is_protected: $ip_prefixes__hostmask__is_protected, ip_prefixes__is_top_level__value: $ip_prefixes__is_top_level__value, ip_prefixes__is_top_level__values: $ip_prefixes__is_top_level__values, ip_prefixes__is_top_level__source__id: $ip_prefixes__is_top_level__source__id, ip_prefixes__is_top_level__owner__id: $ip_prefixes__is_top_level__owner__id, ip_prefixes__is_top_level__is_protected: $ip_prefixes__is_top_level__is_protected, ip_prefixes__utilization__value: $ip_prefixes__utilization__value, ip_prefixes__utilization__values: $ip_prefixes__utilization__values, ip_prefixes__utilization__source__id: $ip_prefixes__utilization__source__id, ip_prefixes__utilization__owner__id: $ip_prefixes__utilization__owner__id, ip_prefixes__utilization__is_protected: $ip_prefixes__utilization__is_protected, ip_prefixes__is_pool__value: $ip_prefixes__is_pool__value, ip_prefixes__is_pool__values: $ip_prefixes__is_pool__values, ip_prefixes__is_pool__source__id: $ip_prefixes__is_pool__source__id, ip_prefixes__is_pool__owner__id: $ip_prefixes__is_pool__owner__id, ip_prefixes__is_pool__is_protected: $ip_prefixes__is_pool__is_protected, ip_prefixes__broadcast_address__value: $ip_prefixes__broadcast_address__value, ip_prefixes__broadcast_address__values: $ip_prefixes__broadcast_address__values, ip_prefixes__broadcast_address__source__id: $ip_prefixes__broadcast_address__source__id, ip_prefixes__broadcast_address__owner__id: $ip_prefixes__broadcast_address__owner__id, ip_prefixes__broadcast_address__is_protected: $ip_prefixes__broadcast_address__is_protected, ip_prefixes__member_type__value: $ip_prefixes__member_type__value, ip_prefixes__member_type__values: $ip_prefixes__member_type__values, ip_prefixes__member_type__source__id: $ip_prefixes__member_type__source__id, ip_prefixes__member_type__owner__id: $ip_prefixes__member_type__owner__id, ip_prefixes__member_type__is_protected: $ip_prefixes__member_type__is_protected, ip_prefixes__network_address__value: $ip_prefixes__network_address__value, ip_prefixes__network_address__values: $ip_prefixes__network_address__values, ip_prefixes__network_address__source__id: $ip_prefixes__network_address__source__id, ip_prefixes__network_address__owner__id: $ip_prefixes__network_address__owner__id, ip_prefixes__network_address__is_protected: $ip_prefixes__network_address__is_protected, ip_prefixes__prefix__value: $ip_prefixes__prefix__value, ip_prefixes__prefix__values: $ip_prefixes__prefix__values, ip_prefixes__prefix__source__id: $ip_prefixes__prefix__source__id, ip_prefixes__prefix__owner__id: $ip_prefixes__prefix__owner__id, ip_prefixes__prefix__is_protected: $ip_prefixes__prefix__is_protected, ip_prefixes__description__value: $ip_prefixes__description__value, ip_prefixes__description__values: $ip_prefixes__description__values, ip_prefixes__description__source__id: $ip_prefixes__description__source__id, ip_prefixes__description__owner__id: $ip_prefixes__description__owner__id, ip_prefixes__description__is_protected: $ip_prefixes__description__is_protected, ip_addresses__ids: $ip_addresses__ids, ip_addresses__isnull: $ip_addresses__isnull, ip_addresses__display_label__value: $ip_addresses__display_label__value, ip_addresses__display_label__values: $ip_addresses__display_label__values, ip_addresses__display_label__isnull: $ip_addresses__display_label__isnull, ip_addresses__address__value: $ip_addresses__address__value, ip_addresses__address__values: $ip_addresses__address__values, ip_addresses__address__source__id: $ip_addresses__address__source__id, ip_addresses__address__owner__id: $ip_addresses__address__owner__id, ip_addresses__address__is_protected: $ip_addresses__address__is_protected, ip_addresses__description__value: $ip_addresses__description__value, ip_addresses__description__values: $ip_addresses__description__values, ip_addresses__description__source__id: $ip_addresses__description__source__id, ip_addresses__description__owner__id: $ip_addresses__description__owner__id, ip_addresses__description__is_protected: $ip_addresses__description__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { edges { node "#, &selection.render()?, " } } }"].concat();
+        let response = self.client.execute_raw(&query, Some(vars), request_branch).await?;
+        let mut data = response.into_data()?;
+        let edges = match data.get_mut("ProfileIpamNamespace").and_then(|v| v.get_mut("edges")).map(Value::take) {
+            Some(Value::Array(edges)) => edges,
+            _ => Vec::new(),
+        };
+        let mut items = Vec::new();
+        for mut edge in edges {
+            match edge.get_mut("node").map(Value::take) {
+                Some(Value::Null) | None => {}
+                Some(node) => items.push(serde_json::from_value(node)?),
+            }
+        }
+        Ok(items)
+    }
+
     pub fn paginate(&self, filters: Option<ProfileIpamNamespaceFilters>, request_branch: Option<&str>) -> DynPaginator<'a, ProfileIpamNamespace, String, (ProfileIpamNamespaceResponse, i64)> {
         let client = self.client;
         let base_filters = filters.unwrap_or_default();
         let request_branch = request_branch.map(|b| b.to_string());
-        let query = r#"query ProfileIpamNamespace($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__default__value: Boolean, $related_nodes__default__values: [Boolean], $related_nodes__default__source__id: ID, $related_nodes__default__owner__id: ID, $related_nodes__default__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $related_nodes__name__value: String, $related_nodes__name__values: [String], $related_nodes__name__source__id: ID, $related_nodes__name__owner__id: ID, $related_nodes__name__is_protected: Boolean, $ip_prefixes__ids: [ID], $ip_prefixes__isnull: Boolean, $ip_prefixes__display_label__value: String, $ip_prefixes__display_label__values: [String], $ip_prefixes__display_label__isnull: Boolean, $ip_prefixes__netmask__value: String, $ip_prefixes__netmask__values: [String], $ip_prefixes__netmask__source__id: ID, $ip_prefixes__netmask__owner__id: ID, $ip_prefixes__netmask__is_protected: Boolean, $ip_prefixes__hostmask__value: String, $ip_prefixes__hostmask__values: [String], $ip_prefixes__hostmask__source__id: ID, $ip_prefixes__hostmask__owner__id: ID, $ip_prefixes__hostmask__is_protected: Boolean, $ip_prefixes__is_top_level__value: Boolean, $ip_prefixes__is_top_level__values: [Boolean], $ip_prefixes__is_top_level__source__id: ID, $ip_prefixes__is_top_level__owner__id: ID, $ip_prefixes__is_top_level__is_protected: Boolean, $ip_prefixes__utilization__value: BigInt, $ip_prefixes__utilization__values: [BigInt], $ip_prefixes__utilization__source__id: ID, $ip_prefixes__utilization__owner__id: ID, $ip_prefixes__utilization__is_protected: Boolean, $ip_prefixes__is_pool__value: Boolean, $ip_prefixes__is_pool__values: [Boolean], $ip_prefixes__is_pool__source__id: ID, $ip_prefixes__is_pool__owner__id: ID, $ip_prefixes__is_pool__is_protected: Boolean, $ip_prefixes__broadcast_address__value: String, $ip_prefixes__broadcast_address__values: [String], $ip_prefixes__broadcast_address__source__id: ID, $ip_prefixes__broadcast_address__owner__id: ID, $ip_prefixes__broadcast_address__is_protected: Boolean, $ip_prefixes__member_type__value: String, $ip_prefixes__member_type__values: [String], $ip_prefixes__member_type__source__id: ID, $ip_prefixes__member_type__owner__id: ID, $ip_prefixes__member_type__is_protected: Boolean, $ip_prefixes__network_address__value: String, $ip_prefixes__network_address__values: [String], $ip_prefixes__network_address__source__id: ID, $ip_prefixes__network_address__owner__id: ID, $ip_prefixes__network_address__is_protected: Boolean, $ip_prefixes__prefix__value: String, $ip_prefixes__prefix__values: [String], $ip_prefixes__prefix__source__id: ID, $ip_prefixes__prefix__owner__id: ID, $ip_prefixes__prefix__is_protected: Boolean, $ip_prefixes__description__value: String, $ip_prefixes__description__values: [String], $ip_prefixes__description__source__id: ID, $ip_prefixes__description__owner__id: ID, $ip_prefixes__description__is_protected: Boolean, $ip_addresses__ids: [ID], $ip_addresses__isnull: Boolean, $ip_addresses__display_label__value: String, $ip_addresses__display_label__values: [String], $ip_addresses__display_label__isnull: Boolean, $ip_addresses__address__value: String, $ip_addresses__address__values: [String], $ip_addresses__address__source__id: ID, $ip_addresses__address__owner__id: ID, $ip_addresses__address__is_protected: Boolean, $ip_addresses__description__value: String, $ip_addresses__description__values: [String], $ip_addresses__description__source__id: ID, $ip_addresses__description__owner__id: ID, $ip_addresses__description__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileIpamNamespace(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__default__value: $related_nodes__default__value, related_nodes__default__values: $related_nodes__default__values, related_nodes__default__source__id: $related_nodes__default__source__id, related_nodes__default__owner__id: $related_nodes__default__owner__id, related_nodes__default__is_protected: $related_nodes__default__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, related_nodes__name__value: $related_nodes__name__value, related_nodes__name__values: $related_nodes__name__values, related_nodes__name__source__id: $related_nodes__name__source__id, related_nodes__name__owner__id: $related_nodes__name__owner__id, related_nodes__name__is_protected: $related_nodes__name__is_protected, ip_prefixes__ids: $ip_prefixes__ids, ip_prefixes__isnull: $ip_prefixes__isnull, ip_prefixes__display_label__value: $ip_prefixes__display_label__value, ip_prefixes__display_label__values: $ip_prefixes__display_label__values, ip_prefixes__display_label__isnull: $ip_prefixes__display_label__isnull, ip_prefixes__netmask__value: $ip_prefixes__netmask__value, ip_prefixes__netmask__values: $ip_prefixes__netmask__values, ip_prefixes__netmask__source__id: $ip_prefixes__netmask__source__id, ip_prefixes__netmask__owner__id: $ip_prefixes__netmask__owner__id, ip_prefixes__netmask__is_protected: $ip_prefixes__netmask__is_protected, ip_prefixes__hostmask__value: $ip_prefixes__hostmask__value, ip_prefixes__hostmask__values: $ip_prefixes__hostmask__values, ip_prefixes__hostmask__source__id: $ip_prefixes__hostmask__source__id, ip_prefixes__hostmask__owner__id: $ip_prefixes__hostmask__owner__id, ip_prefixes__hostmask__is_protected: $ip_prefixes__hostmask__is_protected, ip_prefixes__is_top_level__value: $ip_prefixes__is_top_level__value, ip_prefixes__is_top_level__values: $ip_prefixes__is_top_level__values, ip_prefixes__is_top_level__source__id: $ip_prefixes__is_top_level__source__id, ip_prefixes__is_top_level__owner__id: $ip_prefixes__is_top_level__owner__id, ip_prefixes__is_top_level__is_protected: $ip_prefixes__is_top_level__is_protected, ip_prefixes__utilization__value: $ip_prefixes__utilization__value, ip_prefixes__utilization__values: $ip_prefixes__utilization__values, ip_prefixes__utilization__source__id: $ip_prefixes__utilization__source__id, ip_prefixes__utilization__owner__id: $ip_prefixes__utilization__owner__id, ip_prefixes__utilization__is_protected: $ip_prefixes__utilization__is_protected, ip_prefixes__is_pool__value: $ip_prefixes__is_pool__value, ip_prefixes__is_pool__values: $ip_prefixes__is_pool__values, ip_prefixes__is_pool__source__id: $ip_prefixes__is_pool__source__id, ip_prefixes__is_pool__owner__id: $ip_prefixes__is_pool__owner__id, ip_prefixes__is_pool__is_protected: $ip_prefixes__is_pool__is_protected, ip_prefixes__broadcast_address__value: $ip_prefixes__broadcast_address__value, ip_prefixes__broadcast_address__values: $ip_prefixes__broadcast_address__values, ip_prefixes__broadcast_address__source__id: $ip_prefixes__broadcast_address__source__id, ip_prefixes__broadcast_address__owner__id: $ip_prefixes__broadcast_address__owner__id, ip_prefixes__broadcast_address__is_protected: $ip_prefixes__broadcast_address__is_protected, ip_prefixes__member_type__value: $ip_prefixes__member_type__value, ip_prefixes__member_type__values: $ip_prefixes__member_type__values, ip_prefixes__member_type__source__id: $ip_prefixes__member_type__source__id, ip_prefixes__member_type__owner__id: $ip_prefixes__member_type__owner__id, ip_prefixes__member_type__is_protected: $ip_prefixes__member_type__is_protected, ip_prefixes__network_address__value: $ip_prefixes__network_address__value, ip_prefixes__network_address__values: $ip_prefixes__network_address__values, ip_prefixes__network_address__source__id: $ip_prefixes__network_address__source__id, ip_prefixes__network_address__owner__id: $ip_prefixes__network_address__owner__id, ip_prefixes__network_address__is_protected: $ip_prefixes__network_address__is_protected, ip_prefixes__prefix__value: $ip_prefixes__prefix__value, ip_prefixes__prefix__values: $ip_prefixes__prefix__values, ip_prefixes__prefix__source__id: $ip_prefixes__prefix__source__id, ip_prefixes__prefix__owner__id: $ip_prefixes__prefix__owner__id, ip_prefixes__prefix__is_protected: $ip_prefixes__prefix__is_protected, ip_prefixes__description__value: $ip_prefixes__description__value, ip_prefixes__description__values: $ip_prefixes__description__values, ip_prefixes__description__source__id: $ip_prefixes__description__source__id, ip_prefixes__description__owner__id: $ip_prefixes__description__owner__id, ip_prefixes__description__is_protected: $ip_prefixes__description__is_protected, ip_addresses__ids: $ip_addresses__ids, ip_addresses__isnull: $ip_addresses__isnull, ip_addresses__display_label__value: $ip_addresses__display_label__value, ip_addresses__display_label__values: $ip_addresses__display_label__values, ip_addresses__display_label__isnull: $ip_addresses__display_label__isnull, ip_addresses__address__value: $ip_addresses__address__value, ip_addresses__address__values: $ip_addresses__address__values, ip_addresses__address__source__id: $ip_addresses__address__source__id, ip_addresses__address__owner__id: $ip_addresses__address__owner__id, ip_addresses__address__is_protected: $ip_addresses__address__is_protected, ip_addresses__description__value: $ip_addresses__description__value, ip_addresses__description__values: $ip_addresses__description__values, ip_addresses__description__source__id: $ip_addresses__description__source__id, ip_addresses__description__owner__id: $ip_addresses__description__owner__id, ip_addresses__description__is_protected: $ip_addresses__description__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { count edges { node { id hfid display_label profile_name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } profile_priority { is_default is_protected updated_at id is_from_profile permissions { __typename } value } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } related_nodes { count edges { __typename } permissions { __typename } } ip_prefixes { count edges { __typename } } ip_addresses { count edges { __typename } } member_of_groups { count edges { __typename } } subscriber_of_groups { count edges { __typename } } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
+        let query = r#"query ProfileIpamNamespace($offset: Int, $limit: Int, $order: OrderInput, $ids: [ID], $display_label__value: String, $display_label__values: [String], $display_label__isnull: Boolean, $hfid: [String], $profile_name__value: String, $profile_name__values: [String], $profile_name__isnull: Boolean, $profile_name__source__id: ID, $profile_name__owner__id: ID, $profile_name__is_protected: Boolean, $profile_priority__value: BigInt, $profile_priority__values: [BigInt], $profile_priority__isnull: Boolean, $profile_priority__source__id: ID, $profile_priority__owner__id: ID, $profile_priority__is_protected: Boolean, $description__value: String, $description__values: [String], $description__isnull: Boolean, $description__source__id: ID, $description__owner__id: ID, $description__is_protected: Boolean, $any__value: String, $any__values: [String], $any__source__id: ID, $any__owner__id: ID, $any__is_protected: Boolean, $partial_match: Boolean, $node_metadata__created_by__id: ID, $node_metadata__created_by__ids: [ID], $node_metadata__updated_by__id: ID, $node_metadata__updated_by__ids: [ID], $node_metadata__created_at: DateTime, $node_metadata__created_at__before: DateTime, $node_metadata__created_at__after: DateTime, $node_metadata__updated_at: DateTime, $node_metadata__updated_at__before: DateTime, $node_metadata__updated_at__after: DateTime, $related_nodes__ids: [ID], $related_nodes__isnull: Boolean, $related_nodes__display_label__value: String, $related_nodes__display_label__values: [String], $related_nodes__display_label__isnull: Boolean, $related_nodes__default__value: Boolean, $related_nodes__default__values: [Boolean], $related_nodes__default__source__id: ID, $related_nodes__default__owner__id: ID, $related_nodes__default__is_protected: Boolean, $related_nodes__description__value: String, $related_nodes__description__values: [String], $related_nodes__description__source__id: ID, $related_nodes__description__owner__id: ID, $related_nodes__description__is_protected: Boolean, $related_nodes__name__value: String, $related_nodes__name__values: [String], $related_nodes__name__source__id: ID, $related_nodes__name__owner__id: ID, $related_nodes__name__is_protected: Boolean, $ip_prefixes__ids: [ID], $ip_prefixes__isnull: Boolean, $ip_prefixes__display_label__value: String, $ip_prefixes__display_label__values: [String], $ip_prefixes__display_label__isnull: Boolean, $ip_prefixes__netmask__value: String, $ip_prefixes__netmask__values: [String], $ip_prefixes__netmask__source__id: ID, $ip_prefixes__netmask__owner__id: ID, $ip_prefixes__netmask__is_protected: Boolean, $ip_prefixes__hostmask__value: String, $ip_prefixes__hostmask__values: [String], $ip_prefixes__hostmask__source__id: ID, $ip_prefixes__hostmask__owner__id: ID, $ip_prefixes__hostmask__is_protected: Boolean, $ip_prefixes__is_top_level__value: Boolean, $ip_prefixes__is_top_level__values: [Boolean], $ip_prefixes__is_top_level__source__id: ID, $ip_prefixes__is_top_level__owner__id: ID, $ip_prefixes__is_top_level__is_protected: Boolean, $ip_prefixes__utilization__value: BigInt, $ip_prefixes__utilization__values: [BigInt], $ip_prefixes__utilization__source__id: ID, $ip_prefixes__utilization__owner__id: ID, $ip_prefixes__utilization__is_protected: Boolean, $ip_prefixes__is_pool__value: Boolean, $ip_prefixes__is_pool__values: [Boolean], $ip_prefixes__is_pool__source__id: ID, $ip_prefixes__is_pool__owner__id: ID, $ip_prefixes__is_pool__is_protected: Boolean, $ip_prefixes__broadcast_address__value: String, $ip_prefixes__broadcast_address__values: [String], $ip_prefixes__broadcast_address__source__id: ID, $ip_prefixes__broadcast_address__owner__id: ID, $ip_prefixes__broadcast_address__is_protected: Boolean, $ip_prefixes__member_type__value: String, $ip_prefixes__member_type__values: [String], $ip_prefixes__member_type__source__id: ID, $ip_prefixes__member_type__owner__id: ID, $ip_prefixes__member_type__is_protected: Boolean, $ip_prefixes__network_address__value: String, $ip_prefixes__network_address__values: [String], $ip_prefixes__network_address__source__id: ID, $ip_prefixes__network_address__owner__id: ID, $ip_prefixes__network_address__is_protected: Boolean, $ip_prefixes__prefix__value: String, $ip_prefixes__prefix__values: [String], $ip_prefixes__prefix__source__id: ID, $ip_prefixes__prefix__owner__id: ID, $ip_prefixes__prefix__is_protected: Boolean, $ip_prefixes__description__value: String, $ip_prefixes__description__values: [String], $ip_prefixes__description__source__id: ID, $ip_prefixes__description__owner__id: ID, $ip_prefixes__description__is_protected: Boolean, $ip_addresses__ids: [ID], $ip_addresses__isnull: Boolean, $ip_addresses__display_label__value: String, $ip_addresses__display_label__values: [String], $ip_addresses__display_label__isnull: Boolean, $ip_addresses__address__value: String, $ip_addresses__address__values: [String], $ip_addresses__address__source__id: ID, $ip_addresses__address__owner__id: ID, $ip_addresses__address__is_protected: Boolean, $ip_addresses__description__value: String, $ip_addresses__description__values: [String], $ip_addresses__description__source__id: ID, $ip_addresses__description__owner__id: ID, $ip_addresses__description__is_protected: Boolean, $member_of_groups__ids: [ID], $member_of_groups__isnull: Boolean, $member_of_groups__display_label__value: String, $member_of_groups__display_label__values: [String], $member_of_groups__display_label__isnull: Boolean, $member_of_groups__label__value: String, $member_of_groups__label__values: [String], $member_of_groups__group_type__value: String, $member_of_groups__group_type__values: [String], $member_of_groups__description__value: String, $member_of_groups__description__values: [String], $member_of_groups__name__value: String, $member_of_groups__name__values: [String], $subscriber_of_groups__ids: [ID], $subscriber_of_groups__isnull: Boolean, $subscriber_of_groups__display_label__value: String, $subscriber_of_groups__display_label__values: [String], $subscriber_of_groups__display_label__isnull: Boolean, $subscriber_of_groups__label__value: String, $subscriber_of_groups__label__values: [String], $subscriber_of_groups__group_type__value: String, $subscriber_of_groups__group_type__values: [String], $subscriber_of_groups__description__value: String, $subscriber_of_groups__description__values: [String], $subscriber_of_groups__name__value: String, $subscriber_of_groups__name__values: [String]) { ProfileIpamNamespace(offset: $offset, limit: $limit, order: $order, ids: $ids, display_label__value: $display_label__value, display_label__values: $display_label__values, display_label__isnull: $display_label__isnull, hfid: $hfid, profile_name__value: $profile_name__value, profile_name__values: $profile_name__values, profile_name__isnull: $profile_name__isnull, profile_name__source__id: $profile_name__source__id, profile_name__owner__id: $profile_name__owner__id, profile_name__is_protected: $profile_name__is_protected, profile_priority__value: $profile_priority__value, profile_priority__values: $profile_priority__values, profile_priority__isnull: $profile_priority__isnull, profile_priority__source__id: $profile_priority__source__id, profile_priority__owner__id: $profile_priority__owner__id, profile_priority__is_protected: $profile_priority__is_protected, description__value: $description__value, description__values: $description__values, description__isnull: $description__isnull, description__source__id: $description__source__id, description__owner__id: $description__owner__id, description__is_protected: $description__is_protected, any__value: $any__value, any__values: $any__values, any__source__id: $any__source__id, any__owner__id: $any__owner__id, any__is_protected: $any__is_protected, partial_match: $partial_match, node_metadata__created_by__id: $node_metadata__created_by__id, node_metadata__created_by__ids: $node_metadata__created_by__ids, node_metadata__updated_by__id: $node_metadata__updated_by__id, node_metadata__updated_by__ids: $node_metadata__updated_by__ids, node_metadata__created_at: $node_metadata__created_at, node_metadata__created_at__before: $node_metadata__created_at__before, node_metadata__created_at__after: $node_metadata__created_at__after, node_metadata__updated_at: $node_metadata__updated_at, node_metadata__updated_at__before: $node_metadata__updated_at__before, node_metadata__updated_at__after: $node_metadata__updated_at__after, related_nodes__ids: $related_nodes__ids, related_nodes__isnull: $related_nodes__isnull, related_nodes__display_label__value: $related_nodes__display_label__value, related_nodes__display_label__values: $related_nodes__display_label__values, related_nodes__display_label__isnull: $related_nodes__display_label__isnull, related_nodes__default__value: $related_nodes__default__value, related_nodes__default__values: $related_nodes__default__values, related_nodes__default__source__id: $related_nodes__default__source__id, related_nodes__default__owner__id: $related_nodes__default__owner__id, related_nodes__default__is_protected: $related_nodes__default__is_protected, related_nodes__description__value: $related_nodes__description__value, related_nodes__description__values: $related_nodes__description__values, related_nodes__description__source__id: $related_nodes__description__source__id, related_nodes__description__owner__id: $related_nodes__description__owner__id, related_nodes__description__is_protected: $related_nodes__description__is_protected, related_nodes__name__value: $related_nodes__name__value, related_nodes__name__values: $related_nodes__name__values, related_nodes__name__source__id: $related_nodes__name__source__id, related_nodes__name__owner__id: $related_nodes__name__owner__id, related_nodes__name__is_protected: $related_nodes__name__is_protected, ip_prefixes__ids: $ip_prefixes__ids, ip_prefixes__isnull: $ip_prefixes__isnull, ip_prefixes__display_label__value: $ip_prefixes__display_label__value, ip_prefixes__display_label__values: $ip_prefixes__display_label__values, ip_prefixes__display_label__isnull: $ip_prefixes__display_label__isnull, ip_prefixes__netmask__value: $ip_prefixes__netmask__value, ip_prefixes__netmask__values: $ip_prefixes__netmask__values, ip_prefixes__netmask__source__id: $ip_prefixes__netmask__source__id, ip_prefixes__netmask__owner__id: $ip_prefixes__netmask__owner__id, ip_prefixes__netmask__is_protected: $ip_prefixes__netmask__is_protected, ip_prefixes__hostmask__value: $ip_prefixes__hostmask__value, ip_prefixes__hostmask__values: $ip_prefixes__hostmask__values, ip_prefixes__hostmask__source__id: $ip_prefixes__hostmask__source__id, ip_prefixes__hostmask__owner__id: $ip_prefixes__hostmask__owner__id, ip_prefixes__hostmask__is_protected: $ip_prefixes__hostmask__is_protected, ip_prefixes__is_top_level__value: $ip_prefixes__is_top_level__value, ip_prefixes__is_top_level__values: $ip_prefixes__is_top_level__values, ip_prefixes__is_top_level__source__id: $ip_prefixes__is_top_level__source__id, ip_prefixes__is_top_level__owner__id: $ip_prefixes__is_top_level__owner__id, ip_prefixes__is_top_level__is_protected: $ip_prefixes__is_top_level__is_protected, ip_prefixes__utilization__value: $ip_prefixes__utilization__value, ip_prefixes__utilization__values: $ip_prefixes__utilization__values, ip_prefixes__utilization__source__id: $ip_prefixes__utilization__source__id, ip_prefixes__utilization__owner__id: $ip_prefixes__utilization__owner__id, ip_prefixes__utilization__is_protected: $ip_prefixes__utilization__is_protected, ip_prefixes__is_pool__value: $ip_prefixes__is_pool__value, ip_prefixes__is_pool__values: $ip_prefixes__is_pool__values, ip_prefixes__is_pool__source__id: $ip_prefixes__is_pool__source__id, ip_prefixes__is_pool__owner__id: $ip_prefixes__is_pool__owner__id, ip_prefixes__is_pool__is_protected: $ip_prefixes__is_pool__is_protected, ip_prefixes__broadcast_address__value: $ip_prefixes__broadcast_address__value, ip_prefixes__broadcast_address__values: $ip_prefixes__broadcast_address__values, ip_prefixes__broadcast_address__source__id: $ip_prefixes__broadcast_address__source__id, ip_prefixes__broadcast_address__owner__id: $ip_prefixes__broadcast_address__owner__id, ip_prefixes__broadcast_address__is_protected: $ip_prefixes__broadcast_address__is_protected, ip_prefixes__member_type__value: $ip_prefixes__member_type__value, ip_prefixes__member_type__values: $ip_prefixes__member_type__values, ip_prefixes__member_type__source__id: $ip_prefixes__member_type__source__id, ip_prefixes__member_type__owner__id: $ip_prefixes__member_type__owner__id, ip_prefixes__member_type__is_protected: $ip_prefixes__member_type__is_protected, ip_prefixes__network_address__value: $ip_prefixes__network_address__value, ip_prefixes__network_address__values: $ip_prefixes__network_address__values, ip_prefixes__network_address__source__id: $ip_prefixes__network_address__source__id, ip_prefixes__network_address__owner__id: $ip_prefixes__network_address__owner__id, ip_prefixes__network_address__is_protected: $ip_prefixes__network_address__is_protected, ip_prefixes__prefix__value: $ip_prefixes__prefix__value, ip_prefixes__prefix__values: $ip_prefixes__prefix__values, ip_prefixes__prefix__source__id: $ip_prefixes__prefix__source__id, ip_prefixes__prefix__owner__id: $ip_prefixes__prefix__owner__id, ip_prefixes__prefix__is_protected: $ip_prefixes__prefix__is_protected, ip_prefixes__description__value: $ip_prefixes__description__value, ip_prefixes__description__values: $ip_prefixes__description__values, ip_prefixes__description__source__id: $ip_prefixes__description__source__id, ip_prefixes__description__owner__id: $ip_prefixes__description__owner__id, ip_prefixes__description__is_protected: $ip_prefixes__description__is_protected, ip_addresses__ids: $ip_addresses__ids, ip_addresses__isnull: $ip_addresses__isnull, ip_addresses__display_label__value: $ip_addresses__display_label__value, ip_addresses__display_label__values: $ip_addresses__display_label__values, ip_addresses__display_label__isnull: $ip_addresses__display_label__isnull, ip_addresses__address__value: $ip_addresses__address__value, ip_addresses__address__values: $ip_addresses__address__values, ip_addresses__address__source__id: $ip_addresses__address__source__id, ip_addresses__address__owner__id: $ip_addresses__address__owner__id, ip_addresses__address__is_protected: $ip_addresses__address__is_protected, ip_addresses__description__value: $ip_addresses__description__value, ip_addresses__description__values: $ip_addresses__description__values, ip_addresses__description__source__id: $ip_addresses__description__source__id, ip_addresses__description__owner__id: $ip_addresses__description__owner__id, ip_addresses__description__is_protected: $ip_addresses__description__is_protected, member_of_groups__ids: $member_of_groups__ids, member_of_groups__isnull: $member_of_groups__isnull, member_of_groups__display_label__value: $member_of_groups__display_label__value, member_of_groups__display_label__values: $member_of_groups__display_label__values, member_of_groups__display_label__isnull: $member_of_groups__display_label__isnull, member_of_groups__label__value: $member_of_groups__label__value, member_of_groups__label__values: $member_of_groups__label__values, member_of_groups__group_type__value: $member_of_groups__group_type__value, member_of_groups__group_type__values: $member_of_groups__group_type__values, member_of_groups__description__value: $member_of_groups__description__value, member_of_groups__description__values: $member_of_groups__description__values, member_of_groups__name__value: $member_of_groups__name__value, member_of_groups__name__values: $member_of_groups__name__values, subscriber_of_groups__ids: $subscriber_of_groups__ids, subscriber_of_groups__isnull: $subscriber_of_groups__isnull, subscriber_of_groups__display_label__value: $subscriber_of_groups__display_label__value, subscriber_of_groups__display_label__values: $subscriber_of_groups__display_label__values, subscriber_of_groups__display_label__isnull: $subscriber_of_groups__display_label__isnull, subscriber_of_groups__label__value: $subscriber_of_groups__label__value, subscriber_of_groups__label__values: $subscriber_of_groups__label__values, subscriber_of_groups__group_type__value: $subscriber_of_groups__group_type__value, subscriber_of_groups__group_type__values: $subscriber_of_groups__group_type__values, subscriber_of_groups__description__value: $subscriber_of_groups__description__value, subscriber_of_groups__description__values: $subscriber_of_groups__description__values, subscriber_of_groups__name__value: $subscriber_of_groups__name__value, subscriber_of_groups__name__values: $subscriber_of_groups__name__values) { count edges { node { id hfid display_label profile_name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } profile_priority { is_default is_protected updated_at id is_from_profile permissions { __typename } value } description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } related_nodes { count permissions { count } } ip_prefixes { count } ip_addresses { count } member_of_groups { count } subscriber_of_groups { count } } node_metadata { created_at updated_at } } permissions { count edges { node { kind view create update delete } } } } }"#;
         let fetch: BoxFetch<'a, String, (ProfileIpamNamespaceResponse, i64)> = Box::new(move |cursor: Option<String>| -> BoxFutureResult<'a, (ProfileIpamNamespaceResponse, i64)> {
             let mut page_filters = base_filters.clone();
             let branch = request_branch.clone();
@@ -2331,11 +2639,12 @@ impl<'a> ProfileIpamNamespaceClient<'a> {
             Box::pin(async move {
             let vars = page_filters.to_vars()?;
                 let response = client.execute::<ProfileIpamNamespaceResponse>(query, Some(vars), branch.as_deref()).await?;
-                let data = response.data.ok_or_else(|| Error::Config("missing data".to_string()))?;
+                let data = response.into_data()?;
                 Ok((data, current_offset))
             })
         });
         let extract: BoxExtract<'a, ProfileIpamNamespace, String, (ProfileIpamNamespaceResponse, i64)> = Box::new(move |(data, current_offset): (ProfileIpamNamespaceResponse, i64)| -> Result<EdgePage<ProfileIpamNamespace, String>> {
+            let total_count = Some(data.profile_ipam_namespace.count);
             let mut items = Vec::new();
             let mut next: Option<String> = None;
             for edge in data.profile_ipam_namespace.edges {
@@ -2346,9 +2655,12 @@ impl<'a> ProfileIpamNamespaceClient<'a> {
             if !items.is_empty() {
                 next = Some((current_offset + items.len() as i64).to_string());
             }
-            Ok(EdgePage { nodes: items, next_cursor: next })
+            let has_next_page = total_count.map(|total| current_offset + (items.len() as i64) < total);
+            let end_cursor = None;
+            let info = EdgePageInfo { has_next_page, end_cursor, total_count };
+            Ok(EdgePage::new(items, next).with_info(info))
         });
-        infrahub::Paginator::new(fetch, extract)
+        infrahub::Paginator::new(fetch, extract).with_total_count(|(data, _)| Some(data.profile_ipam_namespace.count))
     }
 
     pub async fn get_by_id(&self, id: impl Into<String>, request_branch: Option<&str>) -> Result<Option<ProfileIpamNamespace>> {
@@ -2358,5 +2670,125 @@ impl<'a> ProfileIpamNamespaceClient<'a> {
         Ok(items.pop())
     }
 
+    pub async fn related_nodes_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<IpamNamespace, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query ProfileIpamNamespaceRelatedNodes($ids: [ID], $offset: Int, $limit: Int) { ProfileIpamNamespace(ids: $ids) { edges { node { related_nodes(offset: $offset, limit: $limit) { count edges { node { id hfid display_label description { is_default is_protected updated_at id is_from_profile permissions { __typename } value } name { is_default is_protected updated_at id is_from_profile permissions { __typename } value } default { is_default is_protected updated_at id is_from_profile permissions { __typename } value } member_of_groups { count } subscriber_of_groups { count } ip_prefixes { count } ip_addresses { count } profiles { count } } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["ProfileIpamNamespace"]["edges"][0]["node"]["related_nodes"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("ProfileIpamNamespace {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
+    pub async fn ip_prefixes_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<serde_json::Value, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query ProfileIpamNamespaceIpPrefixes($ids: [ID], $offset: Int, $limit: Int) { ProfileIpamNamespace(ids: $ids) { edges { node { ip_prefixes(offset: $offset, limit: $limit) { count edges { node { id __typename } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["ProfileIpamNamespace"]["edges"][0]["node"]["ip_prefixes"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("ProfileIpamNamespace {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
+    pub async fn ip_addresses_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<serde_json::Value, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query ProfileIpamNamespaceIpAddresses($ids: [ID], $offset: Int, $limit: Int) { ProfileIpamNamespace(ids: $ids) { edges { node { ip_addresses(offset: $offset, limit: $limit) { count edges { node { id __typename } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["ProfileIpamNamespace"]["edges"][0]["node"]["ip_addresses"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("ProfileIpamNamespace {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
+    pub async fn member_of_groups_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<serde_json::Value, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query ProfileIpamNamespaceMemberOfGroups($ids: [ID], $offset: Int, $limit: Int) { ProfileIpamNamespace(ids: $ids) { edges { node { member_of_groups(offset: $offset, limit: $limit) { count edges { node { id __typename } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["ProfileIpamNamespace"]["edges"][0]["node"]["member_of_groups"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("ProfileIpamNamespace {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
+    pub async fn subscriber_of_groups_page(&self, id: impl Into<String>, cursor: Option<String>, limit: i64, request_branch: Option<&str>) -> Result<EdgePage<serde_json::Value, String>> {
+        let id = id.into();
+        let offset = cursor.as_deref().and_then(|c| c.parse::<i64>().ok()).unwrap_or(0);
+        let query = r#"query ProfileIpamNamespaceSubscriberOfGroups($ids: [ID], $offset: Int, $limit: Int) { ProfileIpamNamespace(ids: $ids) { edges { node { subscriber_of_groups(offset: $offset, limit: $limit) { count edges { node { id __typename } } } } } } }"#;
+        let vars = serde_json::json!({ "ids": [id], "offset": offset, "limit": limit.max(1) });
+        let response = self.client.execute_raw(query, Some(vars), request_branch).await?;
+        let data = response.into_data()?;
+        let connection = &data["ProfileIpamNamespace"]["edges"][0]["node"]["subscriber_of_groups"];
+        if connection.is_null() {
+            return Err(Error::Config(format!("ProfileIpamNamespace {id} not found")));
+        }
+        let count = connection["count"].as_i64().unwrap_or(0);
+        let mut nodes = Vec::new();
+        for edge in connection["edges"].as_array().into_iter().flatten() {
+            if !edge["node"].is_null() {
+                nodes.push(serde_json::from_value(edge["node"].clone())?);
+            }
+        }
+        let next = offset + nodes.len() as i64;
+        let next_cursor = (!nodes.is_empty() && next < count).then(|| next.to_string());
+        let info = EdgePageInfo { has_next_page: Some(next_cursor.is_some()), end_cursor: None, total_count: Some(count) };
+        Ok(EdgePage::new(nodes, next_cursor).with_info(info))
+    }
+
 }
 
@@ -0,0 +1,503 @@
+//! generated node kind names
+//!
+//! use these with the dynamic helpers on `infrahub::Client`, such as
+//! `query_nodes` and `create_node`, instead of string literals.
+
+pub const BUILTIN_IP_ADDRESS: &str = "BuiltinIPAddress";
+pub const BUILTIN_IP_NAMESPACE: &str = "BuiltinIPNamespace";
+pub const BUILTIN_IP_PREFIX: &str = "BuiltinIPPrefix";
+pub const BUILTIN_TAG: &str = "BuiltinTag";
+pub const CORE_ACCOUNT: &str = "CoreAccount";
+pub const CORE_ACCOUNT_GROUP: &str = "CoreAccountGroup";
+pub const CORE_ACCOUNT_ROLE: &str = "CoreAccountRole";
+pub const CORE_ACTION: &str = "CoreAction";
+pub const CORE_ARTIFACT: &str = "CoreArtifact";
+pub const CORE_ARTIFACT_CHECK: &str = "CoreArtifactCheck";
+pub const CORE_ARTIFACT_DEFINITION: &str = "CoreArtifactDefinition";
+pub const CORE_ARTIFACT_TARGET: &str = "CoreArtifactTarget";
+pub const CORE_ARTIFACT_THREAD: &str = "CoreArtifactThread";
+pub const CORE_ARTIFACT_VALIDATOR: &str = "CoreArtifactValidator";
+pub const CORE_BASE_PERMISSION: &str = "CoreBasePermission";
+pub const CORE_CHANGE_COMMENT: &str = "CoreChangeComment";
+pub const CORE_CHANGE_THREAD: &str = "CoreChangeThread";
+pub const CORE_CHECK: &str = "CoreCheck";
+pub const CORE_CHECK_DEFINITION: &str = "CoreCheckDefinition";
+pub const CORE_COMMENT: &str = "CoreComment";
+pub const CORE_CREDENTIAL: &str = "CoreCredential";
+pub const CORE_CUSTOM_WEBHOOK: &str = "CoreCustomWebhook";
+pub const CORE_DATA_CHECK: &str = "CoreDataCheck";
+pub const CORE_DATA_VALIDATOR: &str = "CoreDataValidator";
+pub const CORE_ENV_KEY_VALUE: &str = "CoreEnvKeyValue";
+pub const CORE_FILE_CHECK: &str = "CoreFileCheck";
+pub const CORE_FILE_OBJECT: &str = "CoreFileObject";
+pub const CORE_FILE_THREAD: &str = "CoreFileThread";
+pub const CORE_GENERATOR_ACTION: &str = "CoreGeneratorAction";
+pub const CORE_GENERATOR_AWARE_GROUP: &str = "CoreGeneratorAwareGroup";
+pub const CORE_GENERATOR_CHECK: &str = "CoreGeneratorCheck";
+pub const CORE_GENERATOR_DEFINITION: &str = "CoreGeneratorDefinition";
+pub const CORE_GENERATOR_GROUP: &str = "CoreGeneratorGroup";
+pub const CORE_GENERATOR_INSTANCE: &str = "CoreGeneratorInstance";
+pub const CORE_GENERATOR_VALIDATOR: &str = "CoreGeneratorValidator";
+pub const CORE_GENERIC_ACCOUNT: &str = "CoreGenericAccount";
+pub const CORE_GENERIC_REPOSITORY: &str = "CoreGenericRepository";
+pub const CORE_GLOBAL_PERMISSION: &str = "CoreGlobalPermission";
+pub const CORE_GRAPH_QL_QUERY: &str = "CoreGraphQLQuery";
+pub const CORE_GRAPH_QL_QUERY_GROUP: &str = "CoreGraphQLQueryGroup";
+pub const CORE_GROUP: &str = "CoreGroup";
+pub const CORE_GROUP_ACTION: &str = "CoreGroupAction";
+pub const CORE_GROUP_TRIGGER_RULE: &str = "CoreGroupTriggerRule";
+pub const CORE_IP_ADDRESS_POOL: &str = "CoreIPAddressPool";
+pub const CORE_IP_PREFIX_POOL: &str = "CoreIPPrefixPool";
+pub const CORE_KEY_VALUE: &str = "CoreKeyValue";
+pub const CORE_MENU: &str = "CoreMenu";
+pub const CORE_MENU_ITEM: &str = "CoreMenuItem";
+pub const CORE_NODE: &str = "CoreNode";
+pub const CORE_NODE_TRIGGER_ATTRIBUTE_MATCH: &str = "CoreNodeTriggerAttributeMatch";
+pub const CORE_NODE_TRIGGER_MATCH: &str = "CoreNodeTriggerMatch";
+pub const CORE_NODE_TRIGGER_RELATIONSHIP_MATCH: &str = "CoreNodeTriggerRelationshipMatch";
+pub const CORE_NODE_TRIGGER_RULE: &str = "CoreNodeTriggerRule";
+pub const CORE_NUMBER_POOL: &str = "CoreNumberPool";
+pub const CORE_OBJECT_COMPONENT_TEMPLATE: &str = "CoreObjectComponentTemplate";
+pub const CORE_OBJECT_PERMISSION: &str = "CoreObjectPermission";
+pub const CORE_OBJECT_TEMPLATE: &str = "CoreObjectTemplate";
+pub const CORE_OBJECT_THREAD: &str = "CoreObjectThread";
+pub const CORE_PASSWORD_CREDENTIAL: &str = "CorePasswordCredential";
+pub const CORE_PROFILE: &str = "CoreProfile";
+pub const CORE_PROPOSED_CHANGE: &str = "CoreProposedChange";
+pub const CORE_READ_ONLY_REPOSITORY: &str = "CoreReadOnlyRepository";
+pub const CORE_REPOSITORY: &str = "CoreRepository";
+pub const CORE_REPOSITORY_GROUP: &str = "CoreRepositoryGroup";
+pub const CORE_REPOSITORY_VALIDATOR: &str = "CoreRepositoryValidator";
+pub const CORE_RESOURCE_POOL: &str = "CoreResourcePool";
+pub const CORE_SCHEMA_CHECK: &str = "CoreSchemaCheck";
+pub const CORE_SCHEMA_VALIDATOR: &str = "CoreSchemaValidator";
+pub const CORE_STANDARD_CHECK: &str = "CoreStandardCheck";
+pub const CORE_STANDARD_GROUP: &str = "CoreStandardGroup";
+pub const CORE_STANDARD_WEBHOOK: &str = "CoreStandardWebhook";
+pub const CORE_STATIC_KEY_VALUE: &str = "CoreStaticKeyValue";
+pub const CORE_TASK_TARGET: &str = "CoreTaskTarget";
+pub const CORE_THREAD: &str = "CoreThread";
+pub const CORE_THREAD_COMMENT: &str = "CoreThreadComment";
+pub const CORE_TRANSFORM_JINJA2: &str = "CoreTransformJinja2";
+pub const CORE_TRANSFORM_PYTHON: &str = "CoreTransformPython";
+pub const CORE_TRANSFORMATION: &str = "CoreTransformation";
+pub const CORE_TRIGGER_RULE: &str = "CoreTriggerRule";
+pub const CORE_USER_VALIDATOR: &str = "CoreUserValidator";
+pub const CORE_VALIDATOR: &str = "CoreValidator";
+pub const CORE_WEBHOOK: &str = "CoreWebhook";
+pub const CORE_WEIGHTED_POOL_RESOURCE: &str = "CoreWeightedPoolResource";
+pub const IPAM_NAMESPACE: &str = "IpamNamespace";
+pub const LINEAGE_OWNER: &str = "LineageOwner";
+pub const LINEAGE_SOURCE: &str = "LineageSource";
+pub const PROFILE_BUILTIN_IP_ADDRESS: &str = "ProfileBuiltinIPAddress";
+pub const PROFILE_BUILTIN_IP_PREFIX: &str = "ProfileBuiltinIPPrefix";
+pub const PROFILE_BUILTIN_TAG: &str = "ProfileBuiltinTag";
+pub const PROFILE_IPAM_NAMESPACE: &str = "ProfileIpamNamespace";
+
+/// a node kind of this schema
+///
+/// implements `AsRef<str>` and `Into<String>`, so it can be passed to the
+/// dynamic helpers on `infrahub::Client` in place of a kind name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Kind {
+    BuiltinIPAddress,
+    BuiltinIPNamespace,
+    BuiltinIPPrefix,
+    BuiltinTag,
+    CoreAccount,
+    CoreAccountGroup,
+    CoreAccountRole,
+    CoreAction,
+    CoreArtifact,
+    CoreArtifactCheck,
+    CoreArtifactDefinition,
+    CoreArtifactTarget,
+    CoreArtifactThread,
+    CoreArtifactValidator,
+    CoreBasePermission,
+    CoreChangeComment,
+    CoreChangeThread,
+    CoreCheck,
+    CoreCheckDefinition,
+    CoreComment,
+    CoreCredential,
+    CoreCustomWebhook,
+    CoreDataCheck,
+    CoreDataValidator,
+    CoreEnvKeyValue,
+    CoreFileCheck,
+    CoreFileObject,
+    CoreFileThread,
+    CoreGeneratorAction,
+    CoreGeneratorAwareGroup,
+    CoreGeneratorCheck,
+    CoreGeneratorDefinition,
+    CoreGeneratorGroup,
+    CoreGeneratorInstance,
+    CoreGeneratorValidator,
+    CoreGenericAccount,
+    CoreGenericRepository,
+    CoreGlobalPermission,
+    CoreGraphQLQuery,
+    CoreGraphQLQueryGroup,
+    CoreGroup,
+    CoreGroupAction,
+    CoreGroupTriggerRule,
+    CoreIPAddressPool,
+    CoreIPPrefixPool,
+    CoreKeyValue,
+    CoreMenu,
+    CoreMenuItem,
+    CoreNode,
+    CoreNodeTriggerAttributeMatch,
+    CoreNodeTriggerMatch,
+    CoreNodeTriggerRelationshipMatch,
+    CoreNodeTriggerRule,
+    CoreNumberPool,
+    CoreObjectComponentTemplate,
+    CoreObjectPermission,
+    CoreObjectTemplate,
+    CoreObjectThread,
+    CorePasswordCredential,
+    CoreProfile,
+    CoreProposedChange,
+    CoreReadOnlyRepository,
+    CoreRepository,
+    CoreRepositoryGroup,
+    CoreRepositoryValidator,
+    CoreResourcePool,
+    CoreSchemaCheck,
+    CoreSchemaValidator,
+    CoreStandardCheck,
+    CoreStandardGroup,
+    CoreStandardWebhook,
+    CoreStaticKeyValue,
+    CoreTaskTarget,
+    CoreThread,
+    CoreThreadComment,
+    CoreTransformJinja2,
+    CoreTransformPython,
+    CoreTransformation,
+    CoreTriggerRule,
+    CoreUserValidator,
+    CoreValidator,
+    CoreWebhook,
+    CoreWeightedPoolResource,
+    IpamNamespace,
+    LineageOwner,
+    LineageSource,
+    ProfileBuiltinIPAddress,
+    ProfileBuiltinIPPrefix,
+    ProfileBuiltinTag,
+    ProfileIpamNamespace,
+}
+
+impl Kind {
+    /// every kind, in name order
+    pub const ALL: &'static [Kind] = &[
+        Kind::BuiltinIPAddress,
+        Kind::BuiltinIPNamespace,
+        Kind::BuiltinIPPrefix,
+        Kind::BuiltinTag,
+        Kind::CoreAccount,
+        Kind::CoreAccountGroup,
+        Kind::CoreAccountRole,
+        Kind::CoreAction,
+        Kind::CoreArtifact,
+        Kind::CoreArtifactCheck,
+        Kind::CoreArtifactDefinition,
+        Kind::CoreArtifactTarget,
+        Kind::CoreArtifactThread,
+        Kind::CoreArtifactValidator,
+        Kind::CoreBasePermission,
+        Kind::CoreChangeComment,
+        Kind::CoreChangeThread,
+        Kind::CoreCheck,
+        Kind::CoreCheckDefinition,
+        Kind::CoreComment,
+        Kind::CoreCredential,
+        Kind::CoreCustomWebhook,
+        Kind::CoreDataCheck,
+        Kind::CoreDataValidator,
+        Kind::CoreEnvKeyValue,
+        Kind::CoreFileCheck,
+        Kind::CoreFileObject,
+        Kind::CoreFileThread,
+        Kind::CoreGeneratorAction,
+        Kind::CoreGeneratorAwareGroup,
+        Kind::CoreGeneratorCheck,
+        Kind::CoreGeneratorDefinition,
+        Kind::CoreGeneratorGroup,
+        Kind::CoreGeneratorInstance,
+        Kind::CoreGeneratorValidator,
+        Kind::CoreGenericAccount,
+        Kind::CoreGenericRepository,
+        Kind::CoreGlobalPermission,
+        Kind::CoreGraphQLQuery,
+        Kind::CoreGraphQLQueryGroup,
+        Kind::CoreGroup,
+        Kind::CoreGroupAction,
+        Kind::CoreGroupTriggerRule,
+        Kind::CoreIPAddressPool,
+        Kind::CoreIPPrefixPool,
+        Kind::CoreKeyValue,
+        Kind::CoreMenu,
+        Kind::CoreMenuItem,
+        Kind::CoreNode,
+        Kind::CoreNodeTriggerAttributeMatch,
+        Kind::CoreNodeTriggerMatch,
+        Kind::CoreNodeTriggerRelationshipMatch,
+        Kind::CoreNodeTriggerRule,
+        Kind::CoreNumberPool,
+        Kind::CoreObjectComponentTemplate,
+        Kind::CoreObjectPermission,
+        Kind::CoreObjectTemplate,
+        Kind::CoreObjectThread,
+        Kind::CorePasswordCredential,
+        Kind::CoreProfile,
+        Kind::CoreProposedChange,
+        Kind::CoreReadOnlyRepository,
+        Kind::CoreRepository,
+        Kind::CoreRepositoryGroup,
+        Kind::CoreRepositoryValidator,
+        Kind::CoreResourcePool,
+        Kind::CoreSchemaCheck,
+        Kind::CoreSchemaValidator,
+        Kind::CoreStandardCheck,
+        Kind::CoreStandardGroup,
+        Kind::CoreStandardWebhook,
+        Kind::CoreStaticKeyValue,
+        Kind::CoreTaskTarget,
+        Kind::CoreThread,
+        Kind::CoreThreadComment,
+        Kind::CoreTransformJinja2,
+        Kind::CoreTransformPython,
+        Kind::CoreTransformation,
+        Kind::CoreTriggerRule,
+        Kind::CoreUserValidator,
+        Kind::CoreValidator,
+        Kind::CoreWebhook,
+        Kind::CoreWeightedPoolResource,
+        Kind::IpamNamespace,
+        Kind::LineageOwner,
+        Kind::LineageSource,
+        Kind::ProfileBuiltinIPAddress,
+        Kind::ProfileBuiltinIPPrefix,
+        Kind::ProfileBuiltinTag,
+        Kind::ProfileIpamNamespace,
+    ];
+
+    /// kind name as used by the graphql api
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Kind::BuiltinIPAddress => "BuiltinIPAddress",
+            Kind::BuiltinIPNamespace => "BuiltinIPNamespace",
+            Kind::BuiltinIPPrefix => "BuiltinIPPrefix",
+            Kind::BuiltinTag => "BuiltinTag",
+            Kind::CoreAccount => "CoreAccount",
+            Kind::CoreAccountGroup => "CoreAccountGroup",
+            Kind::CoreAccountRole => "CoreAccountRole",
+            Kind::CoreAction => "CoreAction",
+            Kind::CoreArtifact => "CoreArtifact",
+            Kind::CoreArtifactCheck => "CoreArtifactCheck",
+            Kind::CoreArtifactDefinition => "CoreArtifactDefinition",
+            Kind::CoreArtifactTarget => "CoreArtifactTarget",
+            Kind::CoreArtifactThread => "CoreArtifactThread",
+            Kind::CoreArtifactValidator => "CoreArtifactValidator",
+            Kind::CoreBasePermission => "CoreBasePermission",
+            Kind::CoreChangeComment => "CoreChangeComment",
+            Kind::CoreChangeThread => "CoreChangeThread",
+            Kind::CoreCheck => "CoreCheck",
+            Kind::CoreCheckDefinition => "CoreCheckDefinition",
+            Kind::CoreComment => "CoreComment",
+            Kind::CoreCredential => "CoreCredential",
+            Kind::CoreCustomWebhook => "CoreCustomWebhook",
+            Kind::CoreDataCheck => "CoreDataCheck",
+            Kind::CoreDataValidator => "CoreDataValidator",
+            Kind::CoreEnvKeyValue => "CoreEnvKeyValue",
+            Kind::CoreFileCheck => "CoreFileCheck",
+            Kind::CoreFileObject => "CoreFileObject",
+            Kind::CoreFileThread => "CoreFileThread",
+            Kind::CoreGeneratorAction => "CoreGeneratorAction",
+            Kind::CoreGeneratorAwareGroup => "CoreGeneratorAwareGroup",
+            Kind::CoreGeneratorCheck => "CoreGeneratorCheck",
+            Kind::CoreGeneratorDefinition => "CoreGeneratorDefinition",
+            Kind::CoreGeneratorGroup => "CoreGeneratorGroup",
+            Kind::CoreGeneratorInstance => "CoreGeneratorInstance",
+            Kind::CoreGeneratorValidator => "CoreGeneratorValidator",
+            Kind::CoreGenericAccount => "CoreGenericAccount",
+            Kind::CoreGenericRepository => "CoreGenericRepository",
+            Kind::CoreGlobalPermission => "CoreGlobalPermission",
+            Kind::CoreGraphQLQuery => "CoreGraphQLQuery",
+            Kind::CoreGraphQLQueryGroup => "CoreGraphQLQueryGroup",
+            Kind::CoreGroup => "CoreGroup",
+            Kind::CoreGroupAction => "CoreGroupAction",
+            Kind::CoreGroupTriggerRule => "CoreGroupTriggerRule",
+            Kind::CoreIPAddressPool => "CoreIPAddressPool",
+            Kind::CoreIPPrefixPool => "CoreIPPrefixPool",
+            Kind::CoreKeyValue => "CoreKeyValue",
+            Kind::CoreMenu => "CoreMenu",
+            Kind::CoreMenuItem => "CoreMenuItem",
+            Kind::CoreNode => "CoreNode",
+            Kind::CoreNodeTriggerAttributeMatch => "CoreNodeTriggerAttributeMatch",
+            Kind::CoreNodeTriggerMatch => "CoreNodeTriggerMatch",
+            Kind::CoreNodeTriggerRelationshipMatch => "CoreNodeTriggerRelationshipMatch",
+            Kind::CoreNodeTriggerRule => "CoreNodeTriggerRule",
+            Kind::CoreNumberPool => "CoreNumberPool",
+            Kind::CoreObjectComponentTemplate => "CoreObjectComponentTemplate",
+            Kind::CoreObjectPermission => "CoreObjectPermission",
+            Kind::CoreObjectTemplate => "CoreObjectTemplate",
+            Kind::CoreObjectThread => "CoreObjectThread",
+            Kind::CorePasswordCredential => "CorePasswordCredential",
+            Kind::CoreProfile => "CoreProfile",
+            Kind::CoreProposedChange => "CoreProposedChange",
+            Kind::CoreReadOnlyRepository => "CoreReadOnlyRepository",
+            Kind::CoreRepository => "CoreRepository",
+            Kind::CoreRepositoryGroup => "CoreRepositoryGroup",
+            Kind::CoreRepositoryValidator => "CoreRepositoryValidator",
+            Kind::CoreResourcePool => "CoreResourcePool",
+            Kind::CoreSchemaCheck => "CoreSchemaCheck",
+            Kind::CoreSchemaValidator => "CoreSchemaValidator",
+            Kind::CoreStandardCheck => "CoreStandardCheck",
+            Kind::CoreStandardGroup => "CoreStandardGroup",
+            Kind::CoreStandardWebhook => "CoreStandardWebhook",
+            Kind::CoreStaticKeyValue => "CoreStaticKeyValue",
+            Kind::CoreTaskTarget => "CoreTaskTarget",
+            Kind::CoreThread => "CoreThread",
+            Kind::CoreThreadComment => "CoreThreadComment",
+            Kind::CoreTransformJinja2 => "CoreTransformJinja2",
+            Kind::CoreTransformPython => "CoreTransformPython",
+            Kind::CoreTransformation => "CoreTransformation",
+            Kind::CoreTriggerRule => "CoreTriggerRule",
+            Kind::CoreUserValidator => "CoreUserValidator",
+            Kind::CoreValidator => "CoreValidator",
+            Kind::CoreWebhook => "CoreWebhook",
+            Kind::CoreWeightedPoolResource => "CoreWeightedPoolResource",
+            Kind::IpamNamespace => "IpamNamespace",
+            Kind::LineageOwner => "LineageOwner",
+            Kind::LineageSource => "LineageSource",
+            Kind::ProfileBuiltinIPAddress => "ProfileBuiltinIPAddress",
+            Kind::ProfileBuiltinIPPrefix => "ProfileBuiltinIPPrefix",
+            Kind::ProfileBuiltinTag => "ProfileBuiltinTag",
+            Kind::ProfileIpamNamespace => "ProfileIpamNamespace",
+        }
+    }
+}
+
+impl std::str::FromStr for Kind {
+    type Err = infrahub::Error;
+
+    fn from_str(kind: &str) -> Result<Self, Self::Err> {
+        match kind {
+            "BuiltinIPAddress" => Ok(Kind::BuiltinIPAddress),
+            "BuiltinIPNamespace" => Ok(Kind::BuiltinIPNamespace),
+            "BuiltinIPPrefix" => Ok(Kind::BuiltinIPPrefix),
+            "BuiltinTag" => Ok(Kind::BuiltinTag),
+            "CoreAccount" => Ok(Kind::CoreAccount),
+            "CoreAccountGroup" => Ok(Kind::CoreAccountGroup),
+            "CoreAccountRole" => Ok(Kind::CoreAccountRole),
+            "CoreAction" => Ok(Kind::CoreAction),
+            "CoreArtifact" => Ok(Kind::CoreArtifact),
+            "CoreArtifactCheck" => Ok(Kind::CoreArtifactCheck),
+            "CoreArtifactDefinition" => Ok(Kind::CoreArtifactDefinition),
+            "CoreArtifactTarget" => Ok(Kind::CoreArtifactTarget),
+            "CoreArtifactThread" => Ok(Kind::CoreArtifactThread),
+            "CoreArtifactValidator" => Ok(Kind::CoreArtifactValidator),
+            "CoreBasePermission" => Ok(Kind::CoreBasePermission),
+            "CoreChangeComment" => Ok(Kind::CoreChangeComment),
+            "CoreChangeThread" => Ok(Kind::CoreChangeThread),
+            "CoreCheck" => Ok(Kind::CoreCheck),
+            "CoreCheckDefinition" => Ok(Kind::CoreCheckDefinition),
+            "CoreComment" => Ok(Kind::CoreComment),
+            "CoreCredential" => Ok(Kind::CoreCredential),
+            "CoreCustomWebhook" => Ok(Kind::CoreCustomWebhook),
+            "CoreDataCheck" => Ok(Kind::CoreDataCheck),
+            "CoreDataValidator" => Ok(Kind::CoreDataValidator),
+            "CoreEnvKeyValue" => Ok(Kind::CoreEnvKeyValue),
+            "CoreFileCheck" => Ok(Kind::CoreFileCheck),
+            "CoreFileObject" => Ok(Kind::CoreFileObject),
+            "CoreFileThread" => Ok(Kind::CoreFileThread),
+            "CoreGeneratorAction" => Ok(Kind::CoreGeneratorAction),
+            "CoreGeneratorAwareGroup" => Ok(Kind::CoreGeneratorAwareGroup),
+            "CoreGeneratorCheck" => Ok(Kind::CoreGeneratorCheck),
+            "CoreGeneratorDefinition" => Ok(Kind::CoreGeneratorDefinition),
+            "CoreGeneratorGroup" => Ok(Kind::CoreGeneratorGroup),
+            "CoreGeneratorInstance" => Ok(Kind::CoreGeneratorInstance),
+            "CoreGeneratorValidator" => Ok(Kind::CoreGeneratorValidator),
+            "CoreGenericAccount" => Ok(Kind::CoreGenericAccount),
+            "CoreGenericRepository" => Ok(Kind::CoreGenericRepository),
+            "CoreGlobalPermission" => Ok(Kind::CoreGlobalPermission),
+            "CoreGraphQLQuery" => Ok(Kind::CoreGraphQLQuery),
+            "CoreGraphQLQueryGroup" => Ok(Kind::CoreGraphQLQueryGroup),
+            "CoreGroup" => Ok(Kind::CoreGroup),
+            "CoreGroupAction" => Ok(Kind::CoreGroupAction),
+            "CoreGroupTriggerRule" => Ok(Kind::CoreGroupTriggerRule),
+            "CoreIPAddressPool" => Ok(Kind::CoreIPAddressPool),
+            "CoreIPPrefixPool" => Ok(Kind::CoreIPPrefixPool),
+            "CoreKeyValue" => Ok(Kind::CoreKeyValue),
+            "CoreMenu" => Ok(Kind::CoreMenu),
+            "CoreMenuItem" => Ok(Kind::CoreMenuItem),
+            "CoreNode" => Ok(Kind::CoreNode),
+            "CoreNodeTriggerAttributeMatch" => Ok(Kind::CoreNodeTriggerAttributeMatch),
+            "CoreNodeTriggerMatch" => Ok(Kind::CoreNodeTriggerMatch),
+            "CoreNodeTriggerRelationshipMatch" => Ok(Kind::CoreNodeTriggerRelationshipMatch),
+            "CoreNodeTriggerRule" => Ok(Kind::CoreNodeTriggerRule),
+            "CoreNumberPool" => Ok(Kind::CoreNumberPool),
+            "CoreObjectComponentTemplate" => Ok(Kind::CoreObjectComponentTemplate),
+            "CoreObjectPermission" => Ok(Kind::CoreObjectPermission),
+            "CoreObjectTemplate" => Ok(Kind::CoreObjectTemplate),
+            "CoreObjectThread" => Ok(Kind::CoreObjectThread),
+            "CorePasswordCredential" => Ok(Kind::CorePasswordCredential),
+            "CoreProfile" => Ok(Kind::CoreProfile),
+            "CoreProposedChange" => Ok(Kind::CoreProposedChange),
+            "CoreReadOnlyRepository" => Ok(Kind::CoreReadOnlyRepository),
+            "CoreRepository" => Ok(Kind::CoreRepository),
+            "CoreRepositoryGroup" => Ok(Kind::CoreRepositoryGroup),
+            "CoreRepositoryValidator" => Ok(Kind::CoreRepositoryValidator),
+            "CoreResourcePool" => Ok(Kind::CoreResourcePool),
+            "CoreSchemaCheck" => Ok(Kind::CoreSchemaCheck),
+            "CoreSchemaValidator" => Ok(Kind::CoreSchemaValidator),
+            "CoreStandardCheck" => Ok(Kind::CoreStandardCheck),
+            "CoreStandardGroup" => Ok(Kind::CoreStandardGroup),
+            "CoreStandardWebhook" => Ok(Kind::CoreStandardWebhook),
+            "CoreStaticKeyValue" => Ok(Kind::CoreStaticKeyValue),
+            "CoreTaskTarget" => Ok(Kind::CoreTaskTarget),
+            "CoreThread" => Ok(Kind::CoreThread),
+            "CoreThreadComment" => Ok(Kind::CoreThreadComment),
+            "CoreTransformJinja2" => Ok(Kind::CoreTransformJinja2),
+            "CoreTransformPython" => Ok(Kind::CoreTransformPython),
+            "CoreTransformation" => Ok(Kind::CoreTransformation),
+            "CoreTriggerRule" => Ok(Kind::CoreTriggerRule),
+            "CoreUserValidator" => Ok(Kind::CoreUserValidator),
+            "CoreValidator" => Ok(Kind::CoreValidator),
+            "CoreWebhook" => Ok(Kind::CoreWebhook),
+            "CoreWeightedPoolResource" => Ok(Kind::CoreWeightedPoolResource),
+            "IpamNamespace" => Ok(Kind::IpamNamespace),
+            "LineageOwner" => Ok(Kind::LineageOwner),
+            "LineageSource" => Ok(Kind::LineageSource),
+            "ProfileBuiltinIPAddress" => Ok(Kind::ProfileBuiltinIPAddress),
+            "ProfileBuiltinIPPrefix" => Ok(Kind::ProfileBuiltinIPPrefix),
+            "ProfileBuiltinTag" => Ok(Kind::ProfileBuiltinTag),
+            "ProfileIpamNamespace" => Ok(Kind::ProfileIpamNamespace),
+            _ => Err(infrahub::Error::Config(format!("unknown node kind: {kind:?}"))),
+        }
+    }
+}
+
+impl AsRef<str> for Kind {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<Kind> for String {
+    fn from(kind: Kind) -> Self {
+        kind.as_str().to_string()
+    }
+}
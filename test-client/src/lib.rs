@@ -3,7 +3,9 @@
 pub mod api;
 pub mod client;
 pub mod inputs;
+pub mod kinds;
 pub mod responses;
+pub mod schema;
 pub mod types;
 
 pub use client::GeneratedClient;
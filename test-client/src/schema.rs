@@ -0,0 +1,890 @@
+//! generated schema registry
+//!
+//! fields of every object and interface type with their base type names,
+//! used to validate selections passed to `list_with_selection`.
+
+pub static REGISTRY: infrahub::SchemaRegistry = infrahub::SchemaRegistry::new(&[
+    ("AccountGlobalPermissionEdge", &[("node", "AccountGlobalPermissionNode")]),
+    ("AccountGlobalPermissionEdges", &[("count", "Int"), ("edges", "AccountGlobalPermissionEdge")]),
+    ("AccountGlobalPermissionNode", &[("id", "String"), ("display_label", "String"), ("description", "String"), ("name", "String"), ("action", "String"), ("decision", "String"), ("identifier", "String")]),
+    ("AccountLoggedInEventType", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String"), ("kind", "String"), ("account_name", "String"), ("account_type", "String"), ("auth_method", "String"), ("session_id", "String"), ("groups", "String"), ("roles", "String"), ("identity_source", "String"), ("client_ip", "String"), ("user_agent", "String"), ("timestamp", "DateTime"), ("payload", "GenericScalar")]),
+    ("AccountLoggedOutEventType", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String"), ("kind", "String"), ("account_name", "String"), ("session_id", "String"), ("logout_type", "String"), ("client_ip", "String"), ("user_agent", "String"), ("timestamp", "DateTime"), ("payload", "GenericScalar")]),
+    ("AccountObjectPermissionEdge", &[("node", "AccountObjectPermissionNode")]),
+    ("AccountObjectPermissionEdges", &[("count", "Int"), ("edges", "AccountObjectPermissionEdge")]),
+    ("AccountObjectPermissionNode", &[("id", "String"), ("display_label", "String"), ("description", "String"), ("namespace", "String"), ("name", "String"), ("action", "String"), ("decision", "String"), ("identifier", "String")]),
+    ("AccountPermissionsEdges", &[("global_permissions", "AccountGlobalPermissionEdges"), ("object_permissions", "AccountObjectPermissionEdges")]),
+    ("AccountTokenEdge", &[("node", "AccountTokenNode")]),
+    ("AccountTokenEdges", &[("count", "Int"), ("edges", "AccountTokenEdge")]),
+    ("AccountTokenNode", &[("id", "String"), ("name", "String"), ("expiration", "String")]),
+    ("ActionAvailability", &[("action", "String"), ("available", "Boolean"), ("unavailability_reason", "String")]),
+    ("ActionAvailabilityEdge", &[("node", "ActionAvailability")]),
+    ("AnyAttribute", &[("is_default", "Boolean"), ("is_protected", "Boolean"), ("updated_at", "DateTime"), ("id", "String"), ("is_from_profile", "Boolean"), ("permissions", "PermissionType"), ("value", "GenericScalar"), ("source", "LineageSource"), ("owner", "LineageOwner"), ("updated_by", "CoreGenericAccount")]),
+    ("ArtifactEvent", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String"), ("checksum", "String"), ("checksum_previous", "String"), ("storage_id", "String"), ("storage_id_previous", "String"), ("artifact_definition_id", "String")]),
+    ("AttributeInterface", &[("is_default", "Boolean"), ("is_protected", "Boolean"), ("updated_at", "DateTime")]),
+    ("AvailableActions", &[("count", "Int"), ("edges", "ActionAvailabilityEdge")]),
+    ("Branch", &[("id", "String"), ("name", "String"), ("description", "String"), ("origin_branch", "String"), ("branched_from", "String"), ("status", "BranchStatus"), ("graph_version", "Int"), ("created_at", "String"), ("sync_with_git", "Boolean"), ("is_default", "Boolean"), ("has_schema_changes", "Boolean")]),
+    ("BranchCreate", &[("ok", "Boolean"), ("object", "Branch"), ("task", "TaskInfo")]),
+    ("BranchCreatedEvent", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String"), ("created_branch", "String"), ("payload", "GenericScalar")]),
+    ("BranchDelete", &[("ok", "Boolean"), ("task", "TaskInfo")]),
+    ("BranchDeletedEvent", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String"), ("deleted_branch", "String"), ("proposed_change_id", "String"), ("payload", "GenericScalar")]),
+    ("BranchMerge", &[("ok", "Boolean"), ("object", "Branch"), ("task", "TaskInfo")]),
+    ("BranchMergedEvent", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String"), ("source_branch", "String")]),
+    ("BranchRebase", &[("ok", "Boolean"), ("object", "Branch"), ("task", "TaskInfo")]),
+    ("BranchRebasedEvent", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String"), ("rebased_branch", "String"), ("payload", "GenericScalar")]),
+    ("BranchUpdate", &[("ok", "Boolean")]),
+    ("BranchValidate", &[("ok", "Boolean"), ("object", "Branch"), ("task", "TaskInfo")]),
+    ("BuiltinIPAddress", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("address", "IPHost"), ("description", "TextAttribute"), ("ip_namespace", "NestedEdgedBuiltinIPNamespace"), ("ip_prefix", "NestedEdgedBuiltinIPPrefix"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("profiles", "NestedPaginatedCoreProfile")]),
+    ("BuiltinIPAddressUpdate", &[("ok", "Boolean"), ("object", "BuiltinIPAddress")]),
+    ("BuiltinIPNamespace", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("ip_prefixes", "NestedPaginatedBuiltinIPPrefix"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("ip_addresses", "NestedPaginatedBuiltinIPAddress"), ("profiles", "NestedPaginatedCoreProfile")]),
+    ("BuiltinIPNamespaceUpdate", &[("ok", "Boolean"), ("object", "BuiltinIPNamespace")]),
+    ("BuiltinIPPrefix", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("netmask", "TextAttribute"), ("hostmask", "TextAttribute"), ("is_top_level", "CheckboxAttribute"), ("utilization", "NumberAttribute"), ("is_pool", "CheckboxAttribute"), ("broadcast_address", "TextAttribute"), ("member_type", "Dropdown"), ("network_address", "TextAttribute"), ("prefix", "IPNetwork"), ("description", "TextAttribute"), ("ip_namespace", "NestedEdgedBuiltinIPNamespace"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("ip_addresses", "NestedPaginatedBuiltinIPAddress"), ("resource_pool", "NestedPaginatedCoreIPAddressPool"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("profiles", "NestedPaginatedCoreProfile"), ("parent", "NestedEdgedBuiltinIPPrefix"), ("children", "NestedPaginatedBuiltinIPPrefix"), ("ancestors", "NestedPaginatedBuiltinIPPrefix"), ("descendants", "NestedPaginatedBuiltinIPPrefix")]),
+    ("BuiltinIPPrefixUpdate", &[("ok", "Boolean"), ("object", "BuiltinIPPrefix")]),
+    ("BuiltinTag", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("description", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("profiles", "NestedPaginatedCoreProfile")]),
+    ("BuiltinTagCreate", &[("ok", "Boolean"), ("object", "BuiltinTag")]),
+    ("BuiltinTagDelete", &[("ok", "Boolean")]),
+    ("BuiltinTagUpdate", &[("ok", "Boolean"), ("object", "BuiltinTag")]),
+    ("BuiltinTagUpsert", &[("ok", "Boolean"), ("object", "BuiltinTag")]),
+    ("CheckboxAttribute", &[("is_default", "Boolean"), ("is_protected", "Boolean"), ("updated_at", "DateTime"), ("id", "String"), ("is_from_profile", "Boolean"), ("permissions", "PermissionType"), ("value", "Boolean"), ("source", "LineageSource"), ("owner", "LineageOwner"), ("updated_by", "CoreGenericAccount")]),
+    ("ConflictDetails", &[("uuid", "String"), ("base_branch_action", "DiffAction"), ("base_branch_value", "String"), ("base_branch_changed_at", "DateTime"), ("base_branch_label", "String"), ("diff_branch_action", "DiffAction"), ("diff_branch_value", "String"), ("diff_branch_changed_at", "DateTime"), ("diff_branch_label", "String"), ("selected_branch", "ConflictSelection"), ("resolvable", "Boolean")]),
+    ("ConvertObjectType", &[("ok", "Boolean"), ("node", "GenericScalar")]),
+    ("CoreAccount", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("status", "Dropdown"), ("account_type", "TextAttribute"), ("label", "TextAttribute"), ("password", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("is_externally_managed", "Boolean")]),
+    ("CoreAccountCreate", &[("ok", "Boolean"), ("object", "CoreAccount")]),
+    ("CoreAccountDelete", &[("ok", "Boolean")]),
+    ("CoreAccountGroup", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("label", "TextAttribute"), ("group_type", "TextAttribute"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("roles", "NestedPaginatedCoreAccountRole"), ("members", "NestedPaginatedCoreNode"), ("subscribers", "NestedPaginatedCoreNode"), ("parent", "NestedEdgedCoreGroup"), ("children", "NestedPaginatedCoreGroup"), ("ancestors", "NestedPaginatedCoreGroup"), ("descendants", "NestedPaginatedCoreGroup")]),
+    ("CoreAccountGroupCreate", &[("ok", "Boolean"), ("object", "CoreAccountGroup")]),
+    ("CoreAccountGroupDelete", &[("ok", "Boolean")]),
+    ("CoreAccountGroupUpdate", &[("ok", "Boolean"), ("object", "CoreAccountGroup")]),
+    ("CoreAccountGroupUpsert", &[("ok", "Boolean"), ("object", "CoreAccountGroup")]),
+    ("CoreAccountRole", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("groups", "NestedPaginatedCoreAccountGroup"), ("permissions", "NestedPaginatedCoreBasePermission"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreAccountRoleCreate", &[("ok", "Boolean"), ("object", "CoreAccountRole")]),
+    ("CoreAccountRoleDelete", &[("ok", "Boolean")]),
+    ("CoreAccountRoleUpdate", &[("ok", "Boolean"), ("object", "CoreAccountRole")]),
+    ("CoreAccountRoleUpsert", &[("ok", "Boolean"), ("object", "CoreAccountRole")]),
+    ("CoreAccountUpdate", &[("ok", "Boolean"), ("object", "CoreAccount")]),
+    ("CoreAccountUpsert", &[("ok", "Boolean"), ("object", "CoreAccount")]),
+    ("CoreAction", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("triggers", "NestedPaginatedCoreTriggerRule")]),
+    ("CoreActionUpdate", &[("ok", "Boolean"), ("object", "CoreAction")]),
+    ("CoreArtifact", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("parameters", "JSONAttribute"), ("name", "TextAttribute"), ("status", "TextAttribute"), ("checksum", "TextAttribute"), ("content_type", "TextAttribute"), ("storage_id", "TextAttribute"), ("definition", "NestedEdgedCoreArtifactDefinition"), ("object", "NestedEdgedCoreArtifactTarget"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreArtifactCheck", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("severity", "TextAttribute"), ("kind", "TextAttribute"), ("origin", "TextAttribute"), ("created_at", "TextAttribute"), ("label", "TextAttribute"), ("conclusion", "TextAttribute"), ("message", "TextAttribute"), ("storage_id", "TextAttribute"), ("changed", "CheckboxAttribute"), ("checksum", "TextAttribute"), ("artifact_id", "TextAttribute"), ("line_number", "NumberAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("validator", "NestedEdgedCoreValidator")]),
+    ("CoreArtifactCheckCreate", &[("ok", "Boolean"), ("object", "CoreArtifactCheck")]),
+    ("CoreArtifactCheckDelete", &[("ok", "Boolean")]),
+    ("CoreArtifactCheckUpdate", &[("ok", "Boolean"), ("object", "CoreArtifactCheck")]),
+    ("CoreArtifactCheckUpsert", &[("ok", "Boolean"), ("object", "CoreArtifactCheck")]),
+    ("CoreArtifactCreate", &[("ok", "Boolean"), ("object", "CoreArtifact")]),
+    ("CoreArtifactDefinition", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("artifact_name", "TextAttribute"), ("name", "TextAttribute"), ("parameters", "JSONAttribute"), ("content_type", "TextAttribute"), ("description", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("transformation", "NestedEdgedCoreTransformation"), ("targets", "NestedEdgedCoreGroup")]),
+    ("CoreArtifactDefinitionCreate", &[("ok", "Boolean"), ("object", "CoreArtifactDefinition")]),
+    ("CoreArtifactDefinitionDelete", &[("ok", "Boolean")]),
+    ("CoreArtifactDefinitionUpdate", &[("ok", "Boolean"), ("object", "CoreArtifactDefinition")]),
+    ("CoreArtifactDefinitionUpsert", &[("ok", "Boolean"), ("object", "CoreArtifactDefinition")]),
+    ("CoreArtifactDelete", &[("ok", "Boolean")]),
+    ("CoreArtifactTarget", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("artifacts", "NestedPaginatedCoreArtifact"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreArtifactTargetUpdate", &[("ok", "Boolean"), ("object", "CoreArtifactTarget")]),
+    ("CoreArtifactThread", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("resolved", "CheckboxAttribute"), ("label", "TextAttribute"), ("storage_id", "TextAttribute"), ("artifact_id", "TextAttribute"), ("line_number", "NumberAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("change", "NestedEdgedCoreProposedChange"), ("comments", "NestedPaginatedCoreThreadComment")]),
+    ("CoreArtifactThreadCreate", &[("ok", "Boolean"), ("object", "CoreArtifactThread")]),
+    ("CoreArtifactThreadDelete", &[("ok", "Boolean")]),
+    ("CoreArtifactThreadUpdate", &[("ok", "Boolean"), ("object", "CoreArtifactThread")]),
+    ("CoreArtifactThreadUpsert", &[("ok", "Boolean"), ("object", "CoreArtifactThread")]),
+    ("CoreArtifactUpdate", &[("ok", "Boolean"), ("object", "CoreArtifact")]),
+    ("CoreArtifactUpsert", &[("ok", "Boolean"), ("object", "CoreArtifact")]),
+    ("CoreArtifactValidator", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("conclusion", "TextAttribute"), ("started_at", "TextAttribute"), ("state", "TextAttribute"), ("completed_at", "TextAttribute"), ("label", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("definition", "NestedEdgedCoreArtifactDefinition"), ("proposed_change", "NestedEdgedCoreProposedChange"), ("checks", "NestedPaginatedCoreCheck")]),
+    ("CoreArtifactValidatorCreate", &[("ok", "Boolean"), ("object", "CoreArtifactValidator")]),
+    ("CoreArtifactValidatorDelete", &[("ok", "Boolean")]),
+    ("CoreArtifactValidatorUpdate", &[("ok", "Boolean"), ("object", "CoreArtifactValidator")]),
+    ("CoreArtifactValidatorUpsert", &[("ok", "Boolean"), ("object", "CoreArtifactValidator")]),
+    ("CoreBasePermission", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("description", "TextAttribute"), ("identifier", "TextAttribute"), ("roles", "NestedPaginatedCoreAccountRole"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreBasePermissionUpdate", &[("ok", "Boolean"), ("object", "CoreBasePermission")]),
+    ("CoreChangeComment", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("text", "TextAttribute"), ("change", "NestedEdgedCoreProposedChange"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreChangeCommentCreate", &[("ok", "Boolean"), ("object", "CoreChangeComment")]),
+    ("CoreChangeCommentDelete", &[("ok", "Boolean")]),
+    ("CoreChangeCommentUpdate", &[("ok", "Boolean"), ("object", "CoreChangeComment")]),
+    ("CoreChangeCommentUpsert", &[("ok", "Boolean"), ("object", "CoreChangeComment")]),
+    ("CoreChangeThread", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("resolved", "CheckboxAttribute"), ("label", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("change", "NestedEdgedCoreProposedChange"), ("comments", "NestedPaginatedCoreThreadComment")]),
+    ("CoreChangeThreadCreate", &[("ok", "Boolean"), ("object", "CoreChangeThread")]),
+    ("CoreChangeThreadDelete", &[("ok", "Boolean")]),
+    ("CoreChangeThreadUpdate", &[("ok", "Boolean"), ("object", "CoreChangeThread")]),
+    ("CoreChangeThreadUpsert", &[("ok", "Boolean"), ("object", "CoreChangeThread")]),
+    ("CoreCheck", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("severity", "TextAttribute"), ("kind", "TextAttribute"), ("origin", "TextAttribute"), ("created_at", "TextAttribute"), ("label", "TextAttribute"), ("conclusion", "TextAttribute"), ("message", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("validator", "NestedEdgedCoreValidator")]),
+    ("CoreCheckDefinition", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("file_path", "TextAttribute"), ("name", "TextAttribute"), ("parameters", "JSONAttribute"), ("class_name", "TextAttribute"), ("timeout", "NumberAttribute"), ("description", "TextAttribute"), ("tags", "NestedPaginatedBuiltinTag"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("query", "NestedEdgedCoreGraphQLQuery"), ("targets", "NestedEdgedCoreGroup"), ("repository", "NestedEdgedCoreGenericRepository"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreCheckDefinitionCreate", &[("ok", "Boolean"), ("object", "CoreCheckDefinition")]),
+    ("CoreCheckDefinitionDelete", &[("ok", "Boolean")]),
+    ("CoreCheckDefinitionUpdate", &[("ok", "Boolean"), ("object", "CoreCheckDefinition")]),
+    ("CoreCheckDefinitionUpsert", &[("ok", "Boolean"), ("object", "CoreCheckDefinition")]),
+    ("CoreCheckUpdate", &[("ok", "Boolean"), ("object", "CoreCheck")]),
+    ("CoreComment", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("text", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreCommentUpdate", &[("ok", "Boolean"), ("object", "CoreComment")]),
+    ("CoreCredential", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("label", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreCredentialUpdate", &[("ok", "Boolean"), ("object", "CoreCredential")]),
+    ("CoreCustomWebhook", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("event_type", "TextAttribute"), ("validate_certificates", "CheckboxAttribute"), ("branch_scope", "Dropdown"), ("description", "TextAttribute"), ("url", "TextAttribute"), ("name", "TextAttribute"), ("node_kind", "TextAttribute"), ("active", "CheckboxAttribute"), ("shared_key", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("transformation", "NestedEdgedCoreTransformPython"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("headers", "NestedPaginatedCoreKeyValue")]),
+    ("CoreCustomWebhookCreate", &[("ok", "Boolean"), ("object", "CoreCustomWebhook")]),
+    ("CoreCustomWebhookDelete", &[("ok", "Boolean")]),
+    ("CoreCustomWebhookUpdate", &[("ok", "Boolean"), ("object", "CoreCustomWebhook")]),
+    ("CoreCustomWebhookUpsert", &[("ok", "Boolean"), ("object", "CoreCustomWebhook")]),
+    ("CoreDataCheck", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("severity", "TextAttribute"), ("kind", "TextAttribute"), ("origin", "TextAttribute"), ("created_at", "TextAttribute"), ("label", "TextAttribute"), ("conclusion", "TextAttribute"), ("message", "TextAttribute"), ("conflicts", "JSONAttribute"), ("keep_branch", "TextAttribute"), ("enriched_conflict_id", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("validator", "NestedEdgedCoreValidator")]),
+    ("CoreDataCheckCreate", &[("ok", "Boolean"), ("object", "CoreDataCheck")]),
+    ("CoreDataCheckDelete", &[("ok", "Boolean")]),
+    ("CoreDataCheckUpdate", &[("ok", "Boolean"), ("object", "CoreDataCheck")]),
+    ("CoreDataCheckUpsert", &[("ok", "Boolean"), ("object", "CoreDataCheck")]),
+    ("CoreDataValidator", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("conclusion", "TextAttribute"), ("started_at", "TextAttribute"), ("state", "TextAttribute"), ("completed_at", "TextAttribute"), ("label", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("proposed_change", "NestedEdgedCoreProposedChange"), ("checks", "NestedPaginatedCoreCheck")]),
+    ("CoreDataValidatorCreate", &[("ok", "Boolean"), ("object", "CoreDataValidator")]),
+    ("CoreDataValidatorDelete", &[("ok", "Boolean")]),
+    ("CoreDataValidatorUpdate", &[("ok", "Boolean"), ("object", "CoreDataValidator")]),
+    ("CoreDataValidatorUpsert", &[("ok", "Boolean"), ("object", "CoreDataValidator")]),
+    ("CoreEnvKeyValue", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("value", "TextAttribute"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("key", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreEnvKeyValueCreate", &[("ok", "Boolean"), ("object", "CoreEnvKeyValue")]),
+    ("CoreEnvKeyValueDelete", &[("ok", "Boolean")]),
+    ("CoreEnvKeyValueUpdate", &[("ok", "Boolean"), ("object", "CoreEnvKeyValue")]),
+    ("CoreEnvKeyValueUpsert", &[("ok", "Boolean"), ("object", "CoreEnvKeyValue")]),
+    ("CoreFileCheck", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("severity", "TextAttribute"), ("kind", "TextAttribute"), ("origin", "TextAttribute"), ("created_at", "TextAttribute"), ("label", "TextAttribute"), ("conclusion", "TextAttribute"), ("message", "TextAttribute"), ("commit", "TextAttribute"), ("files", "ListAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("validator", "NestedEdgedCoreValidator")]),
+    ("CoreFileCheckCreate", &[("ok", "Boolean"), ("object", "CoreFileCheck")]),
+    ("CoreFileCheckDelete", &[("ok", "Boolean")]),
+    ("CoreFileCheckUpdate", &[("ok", "Boolean"), ("object", "CoreFileCheck")]),
+    ("CoreFileCheckUpsert", &[("ok", "Boolean"), ("object", "CoreFileCheck")]),
+    ("CoreFileObject", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("file_name", "TextAttribute"), ("file_size", "NumberAttribute"), ("checksum", "TextAttribute"), ("file_type", "TextAttribute"), ("storage_id", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreFileObjectUpdate", &[("ok", "Boolean"), ("object", "CoreFileObject")]),
+    ("CoreFileThread", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("resolved", "CheckboxAttribute"), ("label", "TextAttribute"), ("line_number", "NumberAttribute"), ("commit", "TextAttribute"), ("file", "TextAttribute"), ("repository", "NestedEdgedCoreRepository"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("change", "NestedEdgedCoreProposedChange"), ("comments", "NestedPaginatedCoreThreadComment")]),
+    ("CoreFileThreadCreate", &[("ok", "Boolean"), ("object", "CoreFileThread")]),
+    ("CoreFileThreadDelete", &[("ok", "Boolean")]),
+    ("CoreFileThreadUpdate", &[("ok", "Boolean"), ("object", "CoreFileThread")]),
+    ("CoreFileThreadUpsert", &[("ok", "Boolean"), ("object", "CoreFileThread")]),
+    ("CoreGeneratorAction", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("generator", "NestedEdgedCoreGeneratorDefinition"), ("triggers", "NestedPaginatedCoreTriggerRule")]),
+    ("CoreGeneratorActionCreate", &[("ok", "Boolean"), ("object", "CoreGeneratorAction")]),
+    ("CoreGeneratorActionDelete", &[("ok", "Boolean")]),
+    ("CoreGeneratorActionUpdate", &[("ok", "Boolean"), ("object", "CoreGeneratorAction")]),
+    ("CoreGeneratorActionUpsert", &[("ok", "Boolean"), ("object", "CoreGeneratorAction")]),
+    ("CoreGeneratorAwareGroup", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("label", "TextAttribute"), ("group_type", "TextAttribute"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("members", "NestedPaginatedCoreNode"), ("subscribers", "NestedPaginatedCoreNode"), ("parent", "NestedEdgedCoreGroup"), ("children", "NestedPaginatedCoreGroup"), ("ancestors", "NestedPaginatedCoreGroup"), ("descendants", "NestedPaginatedCoreGroup")]),
+    ("CoreGeneratorAwareGroupCreate", &[("ok", "Boolean"), ("object", "CoreGeneratorAwareGroup")]),
+    ("CoreGeneratorAwareGroupDelete", &[("ok", "Boolean")]),
+    ("CoreGeneratorAwareGroupUpdate", &[("ok", "Boolean"), ("object", "CoreGeneratorAwareGroup")]),
+    ("CoreGeneratorAwareGroupUpsert", &[("ok", "Boolean"), ("object", "CoreGeneratorAwareGroup")]),
+    ("CoreGeneratorCheck", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("severity", "TextAttribute"), ("kind", "TextAttribute"), ("origin", "TextAttribute"), ("created_at", "TextAttribute"), ("label", "TextAttribute"), ("conclusion", "TextAttribute"), ("message", "TextAttribute"), ("instance", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("validator", "NestedEdgedCoreValidator")]),
+    ("CoreGeneratorCheckCreate", &[("ok", "Boolean"), ("object", "CoreGeneratorCheck")]),
+    ("CoreGeneratorCheckDelete", &[("ok", "Boolean")]),
+    ("CoreGeneratorCheckUpdate", &[("ok", "Boolean"), ("object", "CoreGeneratorCheck")]),
+    ("CoreGeneratorCheckUpsert", &[("ok", "Boolean"), ("object", "CoreGeneratorCheck")]),
+    ("CoreGeneratorDefinition", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("description", "TextAttribute"), ("execute_in_proposed_change", "CheckboxAttribute"), ("file_path", "TextAttribute"), ("class_name", "TextAttribute"), ("name", "TextAttribute"), ("parameters", "JSONAttribute"), ("convert_query_response", "CheckboxAttribute"), ("execute_after_merge", "CheckboxAttribute"), ("query", "NestedEdgedCoreGraphQLQuery"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("repository", "NestedEdgedCoreGenericRepository"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("targets", "NestedEdgedCoreGroup")]),
+    ("CoreGeneratorDefinitionCreate", &[("ok", "Boolean"), ("object", "CoreGeneratorDefinition")]),
+    ("CoreGeneratorDefinitionDelete", &[("ok", "Boolean")]),
+    ("CoreGeneratorDefinitionUpdate", &[("ok", "Boolean"), ("object", "CoreGeneratorDefinition")]),
+    ("CoreGeneratorDefinitionUpsert", &[("ok", "Boolean"), ("object", "CoreGeneratorDefinition")]),
+    ("CoreGeneratorGroup", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("label", "TextAttribute"), ("group_type", "TextAttribute"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("members", "NestedPaginatedCoreNode"), ("subscribers", "NestedPaginatedCoreNode"), ("parent", "NestedEdgedCoreGroup"), ("children", "NestedPaginatedCoreGroup"), ("ancestors", "NestedPaginatedCoreGroup"), ("descendants", "NestedPaginatedCoreGroup")]),
+    ("CoreGeneratorGroupCreate", &[("ok", "Boolean"), ("object", "CoreGeneratorGroup")]),
+    ("CoreGeneratorGroupDelete", &[("ok", "Boolean")]),
+    ("CoreGeneratorGroupUpdate", &[("ok", "Boolean"), ("object", "CoreGeneratorGroup")]),
+    ("CoreGeneratorGroupUpsert", &[("ok", "Boolean"), ("object", "CoreGeneratorGroup")]),
+    ("CoreGeneratorInstance", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("status", "TextAttribute"), ("name", "TextAttribute"), ("definition", "NestedEdgedCoreGeneratorDefinition"), ("object", "NestedEdgedCoreNode"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreGeneratorInstanceCreate", &[("ok", "Boolean"), ("object", "CoreGeneratorInstance")]),
+    ("CoreGeneratorInstanceDelete", &[("ok", "Boolean")]),
+    ("CoreGeneratorInstanceUpdate", &[("ok", "Boolean"), ("object", "CoreGeneratorInstance")]),
+    ("CoreGeneratorInstanceUpsert", &[("ok", "Boolean"), ("object", "CoreGeneratorInstance")]),
+    ("CoreGeneratorValidator", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("conclusion", "TextAttribute"), ("started_at", "TextAttribute"), ("state", "TextAttribute"), ("completed_at", "TextAttribute"), ("label", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("definition", "NestedEdgedCoreGeneratorDefinition"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("proposed_change", "NestedEdgedCoreProposedChange"), ("checks", "NestedPaginatedCoreCheck")]),
+    ("CoreGeneratorValidatorCreate", &[("ok", "Boolean"), ("object", "CoreGeneratorValidator")]),
+    ("CoreGeneratorValidatorDelete", &[("ok", "Boolean")]),
+    ("CoreGeneratorValidatorUpdate", &[("ok", "Boolean"), ("object", "CoreGeneratorValidator")]),
+    ("CoreGeneratorValidatorUpsert", &[("ok", "Boolean"), ("object", "CoreGeneratorValidator")]),
+    ("CoreGenericAccount", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("status", "Dropdown"), ("account_type", "TextAttribute"), ("label", "TextAttribute"), ("password", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("is_externally_managed", "Boolean")]),
+    ("CoreGenericAccountUpdate", &[("ok", "Boolean"), ("object", "CoreGenericAccount")]),
+    ("CoreGenericRepository", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("internal_status", "Dropdown"), ("location", "TextAttribute"), ("name", "TextAttribute"), ("description", "TextAttribute"), ("sync_status", "Dropdown"), ("operational_status", "Dropdown"), ("groups_objects", "NestedPaginatedCoreRepositoryGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("checks", "NestedPaginatedCoreCheckDefinition"), ("credential", "NestedEdgedCoreCredential"), ("queries", "NestedPaginatedCoreGraphQLQuery"), ("generators", "NestedPaginatedCoreGeneratorDefinition"), ("tags", "NestedPaginatedBuiltinTag"), ("transformations", "NestedPaginatedCoreTransformation")]),
+    ("CoreGenericRepositoryUpdate", &[("ok", "Boolean"), ("object", "CoreGenericRepository")]),
+    ("CoreGlobalPermission", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("description", "TextAttribute"), ("identifier", "TextAttribute"), ("decision", "NumberAttribute"), ("action", "Dropdown"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("roles", "NestedPaginatedCoreAccountRole")]),
+    ("CoreGlobalPermissionCreate", &[("ok", "Boolean"), ("object", "CoreGlobalPermission")]),
+    ("CoreGlobalPermissionDelete", &[("ok", "Boolean")]),
+    ("CoreGlobalPermissionUpdate", &[("ok", "Boolean"), ("object", "CoreGlobalPermission")]),
+    ("CoreGlobalPermissionUpsert", &[("ok", "Boolean"), ("object", "CoreGlobalPermission")]),
+    ("CoreGraphQLQuery", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("models", "ListAttribute"), ("query", "TextAttribute"), ("height", "NumberAttribute"), ("operations", "ListAttribute"), ("depth", "NumberAttribute"), ("variables", "JSONAttribute"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("repository", "NestedEdgedCoreGenericRepository"), ("tags", "NestedPaginatedBuiltinTag"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreGraphQLQueryCreate", &[("ok", "Boolean"), ("object", "CoreGraphQLQuery")]),
+    ("CoreGraphQLQueryDelete", &[("ok", "Boolean")]),
+    ("CoreGraphQLQueryGroup", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("label", "TextAttribute"), ("group_type", "TextAttribute"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("parameters", "JSONAttribute"), ("query", "NestedEdgedCoreGraphQLQuery"), ("members", "NestedPaginatedCoreNode"), ("subscribers", "NestedPaginatedCoreNode"), ("parent", "NestedEdgedCoreGroup"), ("children", "NestedPaginatedCoreGroup"), ("ancestors", "NestedPaginatedCoreGroup"), ("descendants", "NestedPaginatedCoreGroup")]),
+    ("CoreGraphQLQueryGroupCreate", &[("ok", "Boolean"), ("object", "CoreGraphQLQueryGroup")]),
+    ("CoreGraphQLQueryGroupDelete", &[("ok", "Boolean")]),
+    ("CoreGraphQLQueryGroupUpdate", &[("ok", "Boolean"), ("object", "CoreGraphQLQueryGroup")]),
+    ("CoreGraphQLQueryGroupUpsert", &[("ok", "Boolean"), ("object", "CoreGraphQLQueryGroup")]),
+    ("CoreGraphQLQueryUpdate", &[("ok", "Boolean"), ("object", "CoreGraphQLQuery")]),
+    ("CoreGraphQLQueryUpsert", &[("ok", "Boolean"), ("object", "CoreGraphQLQuery")]),
+    ("CoreGroup", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("label", "TextAttribute"), ("group_type", "TextAttribute"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("members", "NestedPaginatedCoreNode"), ("subscribers", "NestedPaginatedCoreNode"), ("parent", "NestedEdgedCoreGroup"), ("children", "NestedPaginatedCoreGroup"), ("ancestors", "NestedPaginatedCoreGroup"), ("descendants", "NestedPaginatedCoreGroup")]),
+    ("CoreGroupAction", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("member_action", "Dropdown"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("group", "NestedEdgedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("triggers", "NestedPaginatedCoreTriggerRule")]),
+    ("CoreGroupActionCreate", &[("ok", "Boolean"), ("object", "CoreGroupAction")]),
+    ("CoreGroupActionDelete", &[("ok", "Boolean")]),
+    ("CoreGroupActionUpdate", &[("ok", "Boolean"), ("object", "CoreGroupAction")]),
+    ("CoreGroupActionUpsert", &[("ok", "Boolean"), ("object", "CoreGroupAction")]),
+    ("CoreGroupTriggerRule", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("active", "CheckboxAttribute"), ("branch_scope", "Dropdown"), ("description", "TextAttribute"), ("member_update", "Dropdown"), ("group", "NestedEdgedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("action", "NestedEdgedCoreAction")]),
+    ("CoreGroupTriggerRuleCreate", &[("ok", "Boolean"), ("object", "CoreGroupTriggerRule")]),
+    ("CoreGroupTriggerRuleDelete", &[("ok", "Boolean")]),
+    ("CoreGroupTriggerRuleUpdate", &[("ok", "Boolean"), ("object", "CoreGroupTriggerRule")]),
+    ("CoreGroupTriggerRuleUpsert", &[("ok", "Boolean"), ("object", "CoreGroupTriggerRule")]),
+    ("CoreGroupUpdate", &[("ok", "Boolean"), ("object", "CoreGroup")]),
+    ("CoreIPAddressPool", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("description", "TextAttribute"), ("default_prefix_length", "NumberAttribute"), ("default_address_type", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("ip_namespace", "NestedEdgedBuiltinIPNamespace"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("resources", "NestedPaginatedBuiltinIPPrefix")]),
+    ("CoreIPAddressPoolCreate", &[("ok", "Boolean"), ("object", "CoreIPAddressPool")]),
+    ("CoreIPAddressPoolDelete", &[("ok", "Boolean")]),
+    ("CoreIPAddressPoolUpdate", &[("ok", "Boolean"), ("object", "CoreIPAddressPool")]),
+    ("CoreIPAddressPoolUpsert", &[("ok", "Boolean"), ("object", "CoreIPAddressPool")]),
+    ("CoreIPPrefixPool", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("description", "TextAttribute"), ("default_prefix_type", "TextAttribute"), ("default_prefix_length", "NumberAttribute"), ("default_member_type", "TextAttribute"), ("ip_namespace", "NestedEdgedBuiltinIPNamespace"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("resources", "NestedPaginatedBuiltinIPPrefix"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreIPPrefixPoolCreate", &[("ok", "Boolean"), ("object", "CoreIPPrefixPool")]),
+    ("CoreIPPrefixPoolDelete", &[("ok", "Boolean")]),
+    ("CoreIPPrefixPoolUpdate", &[("ok", "Boolean"), ("object", "CoreIPPrefixPool")]),
+    ("CoreIPPrefixPoolUpsert", &[("ok", "Boolean"), ("object", "CoreIPPrefixPool")]),
+    ("CoreKeyValue", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("value", "TextAttribute"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("key", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreKeyValueUpdate", &[("ok", "Boolean"), ("object", "CoreKeyValue")]),
+    ("CoreMenu", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("icon", "TextAttribute"), ("required_permissions", "ListAttribute"), ("description", "TextAttribute"), ("section", "TextAttribute"), ("namespace", "TextAttribute"), ("order_weight", "NumberAttribute"), ("path", "TextAttribute"), ("label", "TextAttribute"), ("protected", "CheckboxAttribute"), ("kind", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("parent", "NestedEdgedCoreMenu"), ("children", "NestedPaginatedCoreMenu"), ("ancestors", "NestedPaginatedCoreMenu"), ("descendants", "NestedPaginatedCoreMenu")]),
+    ("CoreMenuItem", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("icon", "TextAttribute"), ("required_permissions", "ListAttribute"), ("description", "TextAttribute"), ("section", "TextAttribute"), ("namespace", "TextAttribute"), ("order_weight", "NumberAttribute"), ("path", "TextAttribute"), ("label", "TextAttribute"), ("protected", "CheckboxAttribute"), ("kind", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("parent", "NestedEdgedCoreMenu"), ("children", "NestedPaginatedCoreMenu"), ("ancestors", "NestedPaginatedCoreMenu"), ("descendants", "NestedPaginatedCoreMenu")]),
+    ("CoreMenuItemCreate", &[("ok", "Boolean"), ("object", "CoreMenuItem")]),
+    ("CoreMenuItemDelete", &[("ok", "Boolean")]),
+    ("CoreMenuItemUpdate", &[("ok", "Boolean"), ("object", "CoreMenuItem")]),
+    ("CoreMenuItemUpsert", &[("ok", "Boolean"), ("object", "CoreMenuItem")]),
+    ("CoreMenuUpdate", &[("ok", "Boolean"), ("object", "CoreMenu")]),
+    ("CoreNode", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreNodeTriggerAttributeMatch", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("attribute_name", "TextAttribute"), ("value_previous", "TextAttribute"), ("value_match", "Dropdown"), ("value", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("trigger", "NestedEdgedCoreNodeTriggerRule")]),
+    ("CoreNodeTriggerAttributeMatchCreate", &[("ok", "Boolean"), ("object", "CoreNodeTriggerAttributeMatch")]),
+    ("CoreNodeTriggerAttributeMatchDelete", &[("ok", "Boolean")]),
+    ("CoreNodeTriggerAttributeMatchUpdate", &[("ok", "Boolean"), ("object", "CoreNodeTriggerAttributeMatch")]),
+    ("CoreNodeTriggerAttributeMatchUpsert", &[("ok", "Boolean"), ("object", "CoreNodeTriggerAttributeMatch")]),
+    ("CoreNodeTriggerMatch", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("trigger", "NestedEdgedCoreNodeTriggerRule"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreNodeTriggerMatchUpdate", &[("ok", "Boolean"), ("object", "CoreNodeTriggerMatch")]),
+    ("CoreNodeTriggerRelationshipMatch", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("relationship_name", "TextAttribute"), ("peer", "TextAttribute"), ("modification_type", "Dropdown"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("trigger", "NestedEdgedCoreNodeTriggerRule")]),
+    ("CoreNodeTriggerRelationshipMatchCreate", &[("ok", "Boolean"), ("object", "CoreNodeTriggerRelationshipMatch")]),
+    ("CoreNodeTriggerRelationshipMatchDelete", &[("ok", "Boolean")]),
+    ("CoreNodeTriggerRelationshipMatchUpdate", &[("ok", "Boolean"), ("object", "CoreNodeTriggerRelationshipMatch")]),
+    ("CoreNodeTriggerRelationshipMatchUpsert", &[("ok", "Boolean"), ("object", "CoreNodeTriggerRelationshipMatch")]),
+    ("CoreNodeTriggerRule", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("active", "CheckboxAttribute"), ("branch_scope", "Dropdown"), ("description", "TextAttribute"), ("mutation_action", "TextAttribute"), ("node_kind", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("matches", "NestedPaginatedCoreNodeTriggerMatch"), ("action", "NestedEdgedCoreAction")]),
+    ("CoreNodeTriggerRuleCreate", &[("ok", "Boolean"), ("object", "CoreNodeTriggerRule")]),
+    ("CoreNodeTriggerRuleDelete", &[("ok", "Boolean")]),
+    ("CoreNodeTriggerRuleUpdate", &[("ok", "Boolean"), ("object", "CoreNodeTriggerRule")]),
+    ("CoreNodeTriggerRuleUpsert", &[("ok", "Boolean"), ("object", "CoreNodeTriggerRule")]),
+    ("CoreNodeUpdate", &[("ok", "Boolean"), ("object", "CoreNode")]),
+    ("CoreNumberPool", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("description", "TextAttribute"), ("pool_type", "TextAttribute"), ("node", "TextAttribute"), ("end_range", "NumberAttribute"), ("node_attribute", "TextAttribute"), ("start_range", "NumberAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreNumberPoolCreate", &[("ok", "Boolean"), ("object", "CoreNumberPool")]),
+    ("CoreNumberPoolDelete", &[("ok", "Boolean")]),
+    ("CoreNumberPoolUpdate", &[("ok", "Boolean"), ("object", "CoreNumberPool")]),
+    ("CoreNumberPoolUpsert", &[("ok", "Boolean"), ("object", "CoreNumberPool")]),
+    ("CoreObjectComponentTemplate", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("template_name", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreObjectComponentTemplateUpdate", &[("ok", "Boolean"), ("object", "CoreObjectComponentTemplate")]),
+    ("CoreObjectPermission", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("description", "TextAttribute"), ("identifier", "TextAttribute"), ("namespace", "TextAttribute"), ("name", "TextAttribute"), ("decision", "NumberAttribute"), ("action", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("roles", "NestedPaginatedCoreAccountRole")]),
+    ("CoreObjectPermissionCreate", &[("ok", "Boolean"), ("object", "CoreObjectPermission")]),
+    ("CoreObjectPermissionDelete", &[("ok", "Boolean")]),
+    ("CoreObjectPermissionUpdate", &[("ok", "Boolean"), ("object", "CoreObjectPermission")]),
+    ("CoreObjectPermissionUpsert", &[("ok", "Boolean"), ("object", "CoreObjectPermission")]),
+    ("CoreObjectTemplate", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("template_name", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreObjectTemplateUpdate", &[("ok", "Boolean"), ("object", "CoreObjectTemplate")]),
+    ("CoreObjectThread", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("resolved", "CheckboxAttribute"), ("label", "TextAttribute"), ("object_path", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("change", "NestedEdgedCoreProposedChange"), ("comments", "NestedPaginatedCoreThreadComment")]),
+    ("CoreObjectThreadCreate", &[("ok", "Boolean"), ("object", "CoreObjectThread")]),
+    ("CoreObjectThreadDelete", &[("ok", "Boolean")]),
+    ("CoreObjectThreadUpdate", &[("ok", "Boolean"), ("object", "CoreObjectThread")]),
+    ("CoreObjectThreadUpsert", &[("ok", "Boolean"), ("object", "CoreObjectThread")]),
+    ("CorePasswordCredential", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("label", "TextAttribute"), ("username", "TextAttribute"), ("password", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CorePasswordCredentialCreate", &[("ok", "Boolean"), ("object", "CorePasswordCredential")]),
+    ("CorePasswordCredentialDelete", &[("ok", "Boolean")]),
+    ("CorePasswordCredentialUpdate", &[("ok", "Boolean"), ("object", "CorePasswordCredential")]),
+    ("CorePasswordCredentialUpsert", &[("ok", "Boolean"), ("object", "CorePasswordCredential")]),
+    ("CoreProfile", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("profile_name", "TextAttribute"), ("profile_priority", "NumberAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreProfileUpdate", &[("ok", "Boolean"), ("object", "CoreProfile")]),
+    ("CoreProposedChange", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("source_branch", "TextAttribute"), ("is_draft", "CheckboxAttribute"), ("state", "TextAttribute"), ("total_comments", "NumberAttribute"), ("description", "TextAttribute"), ("destination_branch", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("threads", "NestedPaginatedCoreThread"), ("validations", "NestedPaginatedCoreValidator"), ("reviewers", "NestedPaginatedCoreGenericAccount"), ("comments", "NestedPaginatedCoreChangeComment"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("approved_by", "NestedPaginatedCoreGenericAccount"), ("rejected_by", "NestedPaginatedCoreGenericAccount")]),
+    ("CoreProposedChangeCreate", &[("ok", "Boolean"), ("object", "CoreProposedChange")]),
+    ("CoreProposedChangeDelete", &[("ok", "Boolean")]),
+    ("CoreProposedChangeUpdate", &[("ok", "Boolean"), ("object", "CoreProposedChange")]),
+    ("CoreProposedChangeUpsert", &[("ok", "Boolean"), ("object", "CoreProposedChange")]),
+    ("CoreReadOnlyRepository", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("internal_status", "Dropdown"), ("location", "TextAttribute"), ("name", "TextAttribute"), ("description", "TextAttribute"), ("sync_status", "Dropdown"), ("operational_status", "Dropdown"), ("ref", "TextAttribute"), ("commit", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("groups_objects", "NestedPaginatedCoreRepositoryGroup"), ("checks", "NestedPaginatedCoreCheckDefinition"), ("credential", "NestedEdgedCoreCredential"), ("queries", "NestedPaginatedCoreGraphQLQuery"), ("generators", "NestedPaginatedCoreGeneratorDefinition"), ("tags", "NestedPaginatedBuiltinTag"), ("transformations", "NestedPaginatedCoreTransformation")]),
+    ("CoreReadOnlyRepositoryCreate", &[("ok", "Boolean"), ("object", "CoreReadOnlyRepository")]),
+    ("CoreReadOnlyRepositoryDelete", &[("ok", "Boolean")]),
+    ("CoreReadOnlyRepositoryUpdate", &[("ok", "Boolean"), ("object", "CoreReadOnlyRepository")]),
+    ("CoreReadOnlyRepositoryUpsert", &[("ok", "Boolean"), ("object", "CoreReadOnlyRepository")]),
+    ("CoreRepository", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("internal_status", "Dropdown"), ("location", "TextAttribute"), ("name", "TextAttribute"), ("description", "TextAttribute"), ("sync_status", "Dropdown"), ("operational_status", "Dropdown"), ("commit", "TextAttribute"), ("default_branch", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("groups_objects", "NestedPaginatedCoreRepositoryGroup"), ("checks", "NestedPaginatedCoreCheckDefinition"), ("credential", "NestedEdgedCoreCredential"), ("queries", "NestedPaginatedCoreGraphQLQuery"), ("generators", "NestedPaginatedCoreGeneratorDefinition"), ("tags", "NestedPaginatedBuiltinTag"), ("transformations", "NestedPaginatedCoreTransformation")]),
+    ("CoreRepositoryCreate", &[("ok", "Boolean"), ("object", "CoreRepository")]),
+    ("CoreRepositoryDelete", &[("ok", "Boolean")]),
+    ("CoreRepositoryGroup", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("label", "TextAttribute"), ("group_type", "TextAttribute"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("content", "Dropdown"), ("repository", "NestedEdgedCoreGenericRepository"), ("members", "NestedPaginatedCoreNode"), ("subscribers", "NestedPaginatedCoreNode"), ("parent", "NestedEdgedCoreGroup"), ("children", "NestedPaginatedCoreGroup"), ("ancestors", "NestedPaginatedCoreGroup"), ("descendants", "NestedPaginatedCoreGroup")]),
+    ("CoreRepositoryGroupCreate", &[("ok", "Boolean"), ("object", "CoreRepositoryGroup")]),
+    ("CoreRepositoryGroupDelete", &[("ok", "Boolean")]),
+    ("CoreRepositoryGroupUpdate", &[("ok", "Boolean"), ("object", "CoreRepositoryGroup")]),
+    ("CoreRepositoryGroupUpsert", &[("ok", "Boolean"), ("object", "CoreRepositoryGroup")]),
+    ("CoreRepositoryUpdate", &[("ok", "Boolean"), ("object", "CoreRepository")]),
+    ("CoreRepositoryUpsert", &[("ok", "Boolean"), ("object", "CoreRepository")]),
+    ("CoreRepositoryValidator", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("conclusion", "TextAttribute"), ("started_at", "TextAttribute"), ("state", "TextAttribute"), ("completed_at", "TextAttribute"), ("label", "TextAttribute"), ("repository", "NestedEdgedCoreGenericRepository"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("proposed_change", "NestedEdgedCoreProposedChange"), ("checks", "NestedPaginatedCoreCheck")]),
+    ("CoreRepositoryValidatorCreate", &[("ok", "Boolean"), ("object", "CoreRepositoryValidator")]),
+    ("CoreRepositoryValidatorDelete", &[("ok", "Boolean")]),
+    ("CoreRepositoryValidatorUpdate", &[("ok", "Boolean"), ("object", "CoreRepositoryValidator")]),
+    ("CoreRepositoryValidatorUpsert", &[("ok", "Boolean"), ("object", "CoreRepositoryValidator")]),
+    ("CoreResourcePool", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("description", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreResourcePoolUpdate", &[("ok", "Boolean"), ("object", "CoreResourcePool")]),
+    ("CoreSchemaCheck", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("severity", "TextAttribute"), ("kind", "TextAttribute"), ("origin", "TextAttribute"), ("created_at", "TextAttribute"), ("label", "TextAttribute"), ("conclusion", "TextAttribute"), ("message", "TextAttribute"), ("conflicts", "JSONAttribute"), ("enriched_conflict_id", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("validator", "NestedEdgedCoreValidator")]),
+    ("CoreSchemaCheckCreate", &[("ok", "Boolean"), ("object", "CoreSchemaCheck")]),
+    ("CoreSchemaCheckDelete", &[("ok", "Boolean")]),
+    ("CoreSchemaCheckUpdate", &[("ok", "Boolean"), ("object", "CoreSchemaCheck")]),
+    ("CoreSchemaCheckUpsert", &[("ok", "Boolean"), ("object", "CoreSchemaCheck")]),
+    ("CoreSchemaValidator", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("conclusion", "TextAttribute"), ("started_at", "TextAttribute"), ("state", "TextAttribute"), ("completed_at", "TextAttribute"), ("label", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("proposed_change", "NestedEdgedCoreProposedChange"), ("checks", "NestedPaginatedCoreCheck")]),
+    ("CoreSchemaValidatorCreate", &[("ok", "Boolean"), ("object", "CoreSchemaValidator")]),
+    ("CoreSchemaValidatorDelete", &[("ok", "Boolean")]),
+    ("CoreSchemaValidatorUpdate", &[("ok", "Boolean"), ("object", "CoreSchemaValidator")]),
+    ("CoreSchemaValidatorUpsert", &[("ok", "Boolean"), ("object", "CoreSchemaValidator")]),
+    ("CoreStandardCheck", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("severity", "TextAttribute"), ("kind", "TextAttribute"), ("origin", "TextAttribute"), ("created_at", "TextAttribute"), ("label", "TextAttribute"), ("conclusion", "TextAttribute"), ("message", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("validator", "NestedEdgedCoreValidator")]),
+    ("CoreStandardCheckCreate", &[("ok", "Boolean"), ("object", "CoreStandardCheck")]),
+    ("CoreStandardCheckDelete", &[("ok", "Boolean")]),
+    ("CoreStandardCheckUpdate", &[("ok", "Boolean"), ("object", "CoreStandardCheck")]),
+    ("CoreStandardCheckUpsert", &[("ok", "Boolean"), ("object", "CoreStandardCheck")]),
+    ("CoreStandardGroup", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("label", "TextAttribute"), ("group_type", "TextAttribute"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("members", "NestedPaginatedCoreNode"), ("subscribers", "NestedPaginatedCoreNode"), ("parent", "NestedEdgedCoreGroup"), ("children", "NestedPaginatedCoreGroup"), ("ancestors", "NestedPaginatedCoreGroup"), ("descendants", "NestedPaginatedCoreGroup")]),
+    ("CoreStandardGroupCreate", &[("ok", "Boolean"), ("object", "CoreStandardGroup")]),
+    ("CoreStandardGroupDelete", &[("ok", "Boolean")]),
+    ("CoreStandardGroupUpdate", &[("ok", "Boolean"), ("object", "CoreStandardGroup")]),
+    ("CoreStandardGroupUpsert", &[("ok", "Boolean"), ("object", "CoreStandardGroup")]),
+    ("CoreStandardWebhook", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("event_type", "TextAttribute"), ("validate_certificates", "CheckboxAttribute"), ("branch_scope", "Dropdown"), ("description", "TextAttribute"), ("url", "TextAttribute"), ("name", "TextAttribute"), ("node_kind", "TextAttribute"), ("active", "CheckboxAttribute"), ("shared_key", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("headers", "NestedPaginatedCoreKeyValue")]),
+    ("CoreStandardWebhookCreate", &[("ok", "Boolean"), ("object", "CoreStandardWebhook")]),
+    ("CoreStandardWebhookDelete", &[("ok", "Boolean")]),
+    ("CoreStandardWebhookUpdate", &[("ok", "Boolean"), ("object", "CoreStandardWebhook")]),
+    ("CoreStandardWebhookUpsert", &[("ok", "Boolean"), ("object", "CoreStandardWebhook")]),
+    ("CoreStaticKeyValue", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("value", "TextAttribute"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("key", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreStaticKeyValueCreate", &[("ok", "Boolean"), ("object", "CoreStaticKeyValue")]),
+    ("CoreStaticKeyValueDelete", &[("ok", "Boolean")]),
+    ("CoreStaticKeyValueUpdate", &[("ok", "Boolean"), ("object", "CoreStaticKeyValue")]),
+    ("CoreStaticKeyValueUpsert", &[("ok", "Boolean"), ("object", "CoreStaticKeyValue")]),
+    ("CoreTaskTarget", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreTaskTargetUpdate", &[("ok", "Boolean"), ("object", "CoreTaskTarget")]),
+    ("CoreThread", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("resolved", "CheckboxAttribute"), ("label", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("change", "NestedEdgedCoreProposedChange"), ("comments", "NestedPaginatedCoreThreadComment")]),
+    ("CoreThreadComment", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("text", "TextAttribute"), ("thread", "NestedEdgedCoreThread"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreThreadCommentCreate", &[("ok", "Boolean"), ("object", "CoreThreadComment")]),
+    ("CoreThreadCommentDelete", &[("ok", "Boolean")]),
+    ("CoreThreadCommentUpdate", &[("ok", "Boolean"), ("object", "CoreThreadComment")]),
+    ("CoreThreadCommentUpsert", &[("ok", "Boolean"), ("object", "CoreThreadComment")]),
+    ("CoreThreadUpdate", &[("ok", "Boolean"), ("object", "CoreThread")]),
+    ("CoreTransformJinja2", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("description", "TextAttribute"), ("timeout", "NumberAttribute"), ("name", "TextAttribute"), ("label", "TextAttribute"), ("template_path", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("repository", "NestedEdgedCoreGenericRepository"), ("tags", "NestedPaginatedBuiltinTag"), ("query", "NestedEdgedCoreGraphQLQuery")]),
+    ("CoreTransformJinja2Create", &[("ok", "Boolean"), ("object", "CoreTransformJinja2")]),
+    ("CoreTransformJinja2Delete", &[("ok", "Boolean")]),
+    ("CoreTransformJinja2Update", &[("ok", "Boolean"), ("object", "CoreTransformJinja2")]),
+    ("CoreTransformJinja2Upsert", &[("ok", "Boolean"), ("object", "CoreTransformJinja2")]),
+    ("CoreTransformPython", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("description", "TextAttribute"), ("timeout", "NumberAttribute"), ("name", "TextAttribute"), ("label", "TextAttribute"), ("class_name", "TextAttribute"), ("convert_query_response", "CheckboxAttribute"), ("file_path", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("repository", "NestedEdgedCoreGenericRepository"), ("tags", "NestedPaginatedBuiltinTag"), ("query", "NestedEdgedCoreGraphQLQuery")]),
+    ("CoreTransformPythonCreate", &[("ok", "Boolean"), ("object", "CoreTransformPython")]),
+    ("CoreTransformPythonDelete", &[("ok", "Boolean")]),
+    ("CoreTransformPythonUpdate", &[("ok", "Boolean"), ("object", "CoreTransformPython")]),
+    ("CoreTransformPythonUpsert", &[("ok", "Boolean"), ("object", "CoreTransformPython")]),
+    ("CoreTransformation", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("description", "TextAttribute"), ("timeout", "NumberAttribute"), ("name", "TextAttribute"), ("label", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("repository", "NestedEdgedCoreGenericRepository"), ("tags", "NestedPaginatedBuiltinTag"), ("query", "NestedEdgedCoreGraphQLQuery"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreTransformationUpdate", &[("ok", "Boolean"), ("object", "CoreTransformation")]),
+    ("CoreTriggerRule", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("name", "TextAttribute"), ("active", "CheckboxAttribute"), ("branch_scope", "Dropdown"), ("description", "TextAttribute"), ("action", "NestedEdgedCoreAction"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreTriggerRuleUpdate", &[("ok", "Boolean"), ("object", "CoreTriggerRule")]),
+    ("CoreUserValidator", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("conclusion", "TextAttribute"), ("started_at", "TextAttribute"), ("state", "TextAttribute"), ("completed_at", "TextAttribute"), ("label", "TextAttribute"), ("check_definition", "NestedEdgedCoreCheckDefinition"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("repository", "NestedEdgedCoreGenericRepository"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("proposed_change", "NestedEdgedCoreProposedChange"), ("checks", "NestedPaginatedCoreCheck")]),
+    ("CoreUserValidatorCreate", &[("ok", "Boolean"), ("object", "CoreUserValidator")]),
+    ("CoreUserValidatorDelete", &[("ok", "Boolean")]),
+    ("CoreUserValidatorUpdate", &[("ok", "Boolean"), ("object", "CoreUserValidator")]),
+    ("CoreUserValidatorUpsert", &[("ok", "Boolean"), ("object", "CoreUserValidator")]),
+    ("CoreValidator", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("conclusion", "TextAttribute"), ("started_at", "TextAttribute"), ("state", "TextAttribute"), ("completed_at", "TextAttribute"), ("label", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("proposed_change", "NestedEdgedCoreProposedChange"), ("checks", "NestedPaginatedCoreCheck"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreValidatorUpdate", &[("ok", "Boolean"), ("object", "CoreValidator")]),
+    ("CoreWebhook", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("event_type", "TextAttribute"), ("validate_certificates", "CheckboxAttribute"), ("branch_scope", "Dropdown"), ("description", "TextAttribute"), ("url", "TextAttribute"), ("name", "TextAttribute"), ("node_kind", "TextAttribute"), ("active", "CheckboxAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("headers", "NestedPaginatedCoreKeyValue")]),
+    ("CoreWebhookUpdate", &[("ok", "Boolean"), ("object", "CoreWebhook")]),
+    ("CoreWeightedPoolResource", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("allocation_weight", "NumberAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup")]),
+    ("CoreWeightedPoolResourceUpdate", &[("ok", "Boolean"), ("object", "CoreWeightedPoolResource")]),
+    ("DiffAttribute", &[("num_added", "Int"), ("num_updated", "Int"), ("num_removed", "Int"), ("num_conflicts", "Int"), ("name", "String"), ("last_changed_at", "DateTime"), ("status", "DiffAction"), ("path_identifier", "String"), ("properties", "DiffProperty"), ("contains_conflict", "Boolean"), ("conflict", "ConflictDetails")]),
+    ("DiffNode", &[("num_added", "Int"), ("num_updated", "Int"), ("num_removed", "Int"), ("num_conflicts", "Int"), ("uuid", "String"), ("kind", "String"), ("label", "String"), ("status", "DiffAction"), ("path_identifier", "String"), ("conflict", "ConflictDetails"), ("contains_conflict", "Boolean"), ("last_changed_at", "DateTime"), ("parent", "DiffNodeParent"), ("attributes", "DiffAttribute"), ("relationships", "DiffRelationship")]),
+    ("DiffNodeParent", &[("uuid", "String"), ("kind", "String"), ("relationship_name", "String")]),
+    ("DiffProperty", &[("property_type", "String"), ("last_changed_at", "DateTime"), ("previous_value", "String"), ("new_value", "String"), ("previous_label", "String"), ("new_label", "String"), ("status", "DiffAction"), ("path_identifier", "String"), ("conflict", "ConflictDetails")]),
+    ("DiffRelationship", &[("num_added", "Int"), ("num_updated", "Int"), ("num_removed", "Int"), ("num_conflicts", "Int"), ("name", "String"), ("label", "String"), ("last_changed_at", "DateTime"), ("cardinality", "RelationshipCardinality"), ("status", "DiffAction"), ("path_identifier", "String"), ("elements", "DiffSingleRelationship"), ("contains_conflict", "Boolean")]),
+    ("DiffSingleRelationship", &[("num_added", "Int"), ("num_updated", "Int"), ("num_removed", "Int"), ("num_conflicts", "Int"), ("last_changed_at", "DateTime"), ("status", "DiffAction"), ("peer_id", "String"), ("peer_label", "String"), ("path_identifier", "String"), ("contains_conflict", "Boolean"), ("conflict", "ConflictDetails"), ("properties", "DiffProperty")]),
+    ("DiffTree", &[("num_added", "Int"), ("num_updated", "Int"), ("num_removed", "Int"), ("num_conflicts", "Int"), ("base_branch", "String"), ("diff_branch", "String"), ("from_time", "DateTime"), ("to_time", "DateTime"), ("num_untracked_base_changes", "Int"), ("num_untracked_diff_changes", "Int"), ("name", "String"), ("nodes", "DiffNode")]),
+    ("DiffTreeSummary", &[("num_added", "Int"), ("num_updated", "Int"), ("num_removed", "Int"), ("num_conflicts", "Int"), ("base_branch", "String"), ("diff_branch", "String"), ("from_time", "DateTime"), ("to_time", "DateTime"), ("num_unchanged", "Int"), ("num_untracked_base_changes", "Int"), ("num_untracked_diff_changes", "Int")]),
+    ("DiffUpdateMutation", &[("ok", "Boolean"), ("task", "TaskInfo")]),
+    ("Dropdown", &[("is_default", "Boolean"), ("is_protected", "Boolean"), ("updated_at", "DateTime"), ("value", "String"), ("label", "String"), ("color", "String"), ("description", "String"), ("id", "String"), ("is_from_profile", "Boolean"), ("permissions", "PermissionType"), ("source", "LineageSource"), ("owner", "LineageOwner"), ("updated_by", "CoreGenericAccount")]),
+    ("DropdownFields", &[("value", "String"), ("label", "String"), ("color", "String"), ("description", "String")]),
+    ("EdgedBuiltinIPAddress", &[("node", "BuiltinIPAddress"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedBuiltinIPNamespace", &[("node", "BuiltinIPNamespace"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedBuiltinIPPrefix", &[("node", "BuiltinIPPrefix"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedBuiltinTag", &[("node", "BuiltinTag"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreAccount", &[("node", "CoreAccount"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreAccountGroup", &[("node", "CoreAccountGroup"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreAccountRole", &[("node", "CoreAccountRole"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreAction", &[("node", "CoreAction"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreArtifact", &[("node", "CoreArtifact"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreArtifactCheck", &[("node", "CoreArtifactCheck"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreArtifactDefinition", &[("node", "CoreArtifactDefinition"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreArtifactTarget", &[("node", "CoreArtifactTarget"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreArtifactThread", &[("node", "CoreArtifactThread"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreArtifactValidator", &[("node", "CoreArtifactValidator"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreBasePermission", &[("node", "CoreBasePermission"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreChangeComment", &[("node", "CoreChangeComment"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreChangeThread", &[("node", "CoreChangeThread"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreCheck", &[("node", "CoreCheck"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreCheckDefinition", &[("node", "CoreCheckDefinition"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreComment", &[("node", "CoreComment"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreCredential", &[("node", "CoreCredential"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreCustomWebhook", &[("node", "CoreCustomWebhook"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreDataCheck", &[("node", "CoreDataCheck"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreDataValidator", &[("node", "CoreDataValidator"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreEnvKeyValue", &[("node", "CoreEnvKeyValue"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreFileCheck", &[("node", "CoreFileCheck"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreFileObject", &[("node", "CoreFileObject"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreFileThread", &[("node", "CoreFileThread"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreGeneratorAction", &[("node", "CoreGeneratorAction"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreGeneratorAwareGroup", &[("node", "CoreGeneratorAwareGroup"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreGeneratorCheck", &[("node", "CoreGeneratorCheck"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreGeneratorDefinition", &[("node", "CoreGeneratorDefinition"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreGeneratorGroup", &[("node", "CoreGeneratorGroup"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreGeneratorInstance", &[("node", "CoreGeneratorInstance"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreGeneratorValidator", &[("node", "CoreGeneratorValidator"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreGenericAccount", &[("node", "CoreGenericAccount"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreGenericRepository", &[("node", "CoreGenericRepository"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreGlobalPermission", &[("node", "CoreGlobalPermission"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreGraphQLQuery", &[("node", "CoreGraphQLQuery"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreGraphQLQueryGroup", &[("node", "CoreGraphQLQueryGroup"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreGroup", &[("node", "CoreGroup"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreGroupAction", &[("node", "CoreGroupAction"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreGroupTriggerRule", &[("node", "CoreGroupTriggerRule"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreIPAddressPool", &[("node", "CoreIPAddressPool"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreIPPrefixPool", &[("node", "CoreIPPrefixPool"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreKeyValue", &[("node", "CoreKeyValue"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreMenu", &[("node", "CoreMenu"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreMenuItem", &[("node", "CoreMenuItem"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreNode", &[("node", "CoreNode"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreNodeTriggerAttributeMatch", &[("node", "CoreNodeTriggerAttributeMatch"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreNodeTriggerMatch", &[("node", "CoreNodeTriggerMatch"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreNodeTriggerRelationshipMatch", &[("node", "CoreNodeTriggerRelationshipMatch"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreNodeTriggerRule", &[("node", "CoreNodeTriggerRule"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreNumberPool", &[("node", "CoreNumberPool"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreObjectComponentTemplate", &[("node", "CoreObjectComponentTemplate"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreObjectPermission", &[("node", "CoreObjectPermission"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreObjectTemplate", &[("node", "CoreObjectTemplate"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreObjectThread", &[("node", "CoreObjectThread"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCorePasswordCredential", &[("node", "CorePasswordCredential"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreProfile", &[("node", "CoreProfile"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreProposedChange", &[("node", "CoreProposedChange"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreReadOnlyRepository", &[("node", "CoreReadOnlyRepository"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreRepository", &[("node", "CoreRepository"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreRepositoryGroup", &[("node", "CoreRepositoryGroup"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreRepositoryValidator", &[("node", "CoreRepositoryValidator"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreResourcePool", &[("node", "CoreResourcePool"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreSchemaCheck", &[("node", "CoreSchemaCheck"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreSchemaValidator", &[("node", "CoreSchemaValidator"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreStandardCheck", &[("node", "CoreStandardCheck"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreStandardGroup", &[("node", "CoreStandardGroup"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreStandardWebhook", &[("node", "CoreStandardWebhook"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreStaticKeyValue", &[("node", "CoreStaticKeyValue"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreTaskTarget", &[("node", "CoreTaskTarget"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreThread", &[("node", "CoreThread"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreThreadComment", &[("node", "CoreThreadComment"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreTransformJinja2", &[("node", "CoreTransformJinja2"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreTransformPython", &[("node", "CoreTransformPython"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreTransformation", &[("node", "CoreTransformation"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreTriggerRule", &[("node", "CoreTriggerRule"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreUserValidator", &[("node", "CoreUserValidator"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreValidator", &[("node", "CoreValidator"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreWebhook", &[("node", "CoreWebhook"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedCoreWeightedPoolResource", &[("node", "CoreWeightedPoolResource"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedInternalAccountToken", &[("node", "InternalAccountToken"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedInternalExternalIdentity", &[("node", "InternalExternalIdentity"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedInternalIPPrefixAvailable", &[("node", "InternalIPPrefixAvailable"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedInternalIPRangeAvailable", &[("node", "InternalIPRangeAvailable"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedInternalRefreshToken", &[("node", "InternalRefreshToken"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedIpamNamespace", &[("node", "IpamNamespace"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedLineageOwner", &[("node", "LineageOwner"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedLineageSource", &[("node", "LineageSource"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedProfileBuiltinIPAddress", &[("node", "ProfileBuiltinIPAddress"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedProfileBuiltinIPPrefix", &[("node", "ProfileBuiltinIPPrefix"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedProfileBuiltinTag", &[("node", "ProfileBuiltinTag"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EdgedProfileIpamNamespace", &[("node", "ProfileIpamNamespace"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("EventNodeInterface", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String")]),
+    ("EventNodes", &[("node", "EventNodeInterface")]),
+    ("Events", &[("edges", "EventNodes"), ("count", "Int")]),
+    ("FieldsMapping", &[("mapping", "GenericScalar")]),
+    ("GeneratorDefinitionRequestRun", &[("ok", "Boolean"), ("task", "TaskInfo")]),
+    ("GraphQLQueryReport", &[("targets_unique_nodes", "Boolean")]),
+    ("GroupEvent", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String"), ("members", "RelatedNode"), ("ancestors", "RelatedNode")]),
+    ("IPAddressGetNextAvailable", &[("address", "String")]),
+    ("IPAddressPoolGetResource", &[("ok", "Boolean"), ("node", "PoolAllocatedNode")]),
+    ("IPHost", &[("is_default", "Boolean"), ("is_protected", "Boolean"), ("updated_at", "DateTime"), ("id", "String"), ("is_from_profile", "Boolean"), ("permissions", "PermissionType"), ("value", "String"), ("ip", "String"), ("hostmask", "String"), ("netmask", "String"), ("prefixlen", "Int"), ("version", "Int"), ("with_hostmask", "String"), ("with_netmask", "String"), ("source", "LineageSource"), ("owner", "LineageOwner"), ("updated_by", "CoreGenericAccount")]),
+    ("IPNetwork", &[("is_default", "Boolean"), ("is_protected", "Boolean"), ("updated_at", "DateTime"), ("id", "String"), ("is_from_profile", "Boolean"), ("permissions", "PermissionType"), ("value", "String"), ("broadcast_address", "String"), ("hostmask", "String"), ("netmask", "String"), ("prefixlen", "Int"), ("num_addresses", "Int"), ("version", "Int"), ("with_hostmask", "String"), ("with_netmask", "String"), ("source", "LineageSource"), ("owner", "LineageOwner"), ("updated_by", "CoreGenericAccount")]),
+    ("IPPoolUtilizationResource", &[("id", "String"), ("display_label", "String"), ("kind", "String"), ("weight", "BigInt"), ("utilization", "Float"), ("utilization_branches", "Float"), ("utilization_default_branch", "Float")]),
+    ("IPPrefixGetNextAvailable", &[("prefix", "String")]),
+    ("IPPrefixPoolGetResource", &[("ok", "Boolean"), ("node", "PoolAllocatedNode")]),
+    ("IPPrefixUtilizationEdge", &[("node", "IPPoolUtilizationResource")]),
+    ("Info", &[("deployment_id", "String"), ("version", "String")]),
+    ("InfrahubAccountSelfUpdate", &[("ok", "Boolean")]),
+    ("InfrahubAccountTokenCreate", &[("ok", "Boolean"), ("object", "InfrahubAccountTokenType")]),
+    ("InfrahubAccountTokenDelete", &[("ok", "Boolean")]),
+    ("InfrahubAccountTokenType", &[("id", "String"), ("token", "ValueType")]),
+    ("InfrahubBranch", &[("id", "String"), ("name", "RequiredStringValueField"), ("description", "NonRequiredStringValueField"), ("origin_branch", "NonRequiredStringValueField"), ("branched_from", "NonRequiredStringValueField"), ("status", "StatusField"), ("graph_version", "NonRequiredIntValueField"), ("created_at", "String"), ("sync_with_git", "NonRequiredBooleanValueField"), ("is_default", "NonRequiredBooleanValueField"), ("has_schema_changes", "NonRequiredBooleanValueField")]),
+    ("InfrahubBranchEdge", &[("node", "InfrahubBranch"), ("node_metadata", "InfrahubNodeMetadata")]),
+    ("InfrahubBranchType", &[("count", "Int"), ("edges", "InfrahubBranchEdge"), ("default_branch", "InfrahubBranch")]),
+    ("InfrahubMutatedAttribute", &[("name", "String"), ("action", "DiffAction"), ("value", "String"), ("kind", "String"), ("value_previous", "String")]),
+    ("InfrahubMutatedRelationship", &[("name", "String"), ("action", "DiffAction"), ("peer", "RelatedNode")]),
+    ("InfrahubNodeMetadata", &[("created_at", "DateTime"), ("created_by", "CoreGenericAccount"), ("updated_at", "DateTime"), ("updated_by", "CoreGenericAccount")]),
+    ("InfrahubProfilesRefresh", &[("ok", "Boolean")]),
+    ("InfrahubRelationshipMetadata", &[("created_at", "DateTime"), ("created_by", "CoreGenericAccount"), ("updated_at", "DateTime"), ("updated_by", "CoreGenericAccount")]),
+    ("InternalAccountToken", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("token", "TextAttribute"), ("expiration", "TextAttribute"), ("name", "TextAttribute"), ("account", "NestedEdgedCoreGenericAccount"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("InternalExternalIdentity", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("sub", "TextAttribute"), ("provider_name", "TextAttribute"), ("protocol", "TextAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("account", "NestedEdgedCoreGenericAccount")]),
+    ("InternalIPPrefixAvailable", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("netmask", "TextAttribute"), ("hostmask", "TextAttribute"), ("is_top_level", "CheckboxAttribute"), ("utilization", "NumberAttribute"), ("is_pool", "CheckboxAttribute"), ("broadcast_address", "TextAttribute"), ("member_type", "Dropdown"), ("network_address", "TextAttribute"), ("prefix", "IPNetwork"), ("description", "TextAttribute"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("ip_namespace", "NestedEdgedBuiltinIPNamespace"), ("ip_addresses", "NestedPaginatedBuiltinIPAddress"), ("resource_pool", "NestedPaginatedCoreIPAddressPool"), ("profiles", "NestedPaginatedCoreProfile"), ("parent", "NestedEdgedBuiltinIPPrefix"), ("children", "NestedPaginatedBuiltinIPPrefix"), ("ancestors", "NestedPaginatedBuiltinIPPrefix"), ("descendants", "NestedPaginatedBuiltinIPPrefix")]),
+    ("InternalIPRangeAvailable", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("address", "IPHost"), ("description", "TextAttribute"), ("last_address", "IPHost"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("ip_namespace", "NestedEdgedBuiltinIPNamespace"), ("ip_prefix", "NestedEdgedBuiltinIPPrefix"), ("profiles", "NestedPaginatedCoreProfile")]),
+    ("InternalRefreshToken", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("expiration", "TextAttribute"), ("account", "NestedEdgedCoreGenericAccount"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("IpamNamespace", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("description", "TextAttribute"), ("name", "TextAttribute"), ("default", "CheckboxAttribute"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup"), ("ip_prefixes", "NestedPaginatedBuiltinIPPrefix"), ("ip_addresses", "NestedPaginatedBuiltinIPAddress"), ("profiles", "NestedPaginatedCoreProfile")]),
+    ("IpamNamespaceCreate", &[("ok", "Boolean"), ("object", "IpamNamespace")]),
+    ("IpamNamespaceDelete", &[("ok", "Boolean")]),
+    ("IpamNamespaceUpdate", &[("ok", "Boolean"), ("object", "IpamNamespace")]),
+    ("IpamNamespaceUpsert", &[("ok", "Boolean"), ("object", "IpamNamespace")]),
+    ("JSONAttribute", &[("is_default", "Boolean"), ("is_protected", "Boolean"), ("updated_at", "DateTime"), ("id", "String"), ("is_from_profile", "Boolean"), ("permissions", "PermissionType"), ("value", "GenericScalar"), ("source", "LineageSource"), ("owner", "LineageOwner"), ("updated_by", "CoreGenericAccount")]),
+    ("LineageOwner", &[("id", "String"), ("hfid", "String"), ("display_label", "String")]),
+    ("LineageSource", &[("id", "String"), ("hfid", "String"), ("display_label", "String")]),
+    ("ListAttribute", &[("is_default", "Boolean"), ("is_protected", "Boolean"), ("updated_at", "DateTime"), ("id", "String"), ("is_from_profile", "Boolean"), ("permissions", "PermissionType"), ("value", "GenericScalar"), ("source", "LineageSource"), ("owner", "LineageOwner"), ("updated_by", "CoreGenericAccount")]),
+    ("MacAddress", &[("is_default", "Boolean"), ("is_protected", "Boolean"), ("updated_at", "DateTime"), ("id", "String"), ("is_from_profile", "Boolean"), ("permissions", "PermissionType"), ("value", "String"), ("oui", "String"), ("ei", "String"), ("version", "Int"), ("binary", "String"), ("eui48", "String"), ("eui64", "String"), ("bare", "String"), ("dot_notation", "String"), ("semicolon_notation", "String"), ("split_notation", "String"), ("source", "LineageSource"), ("owner", "LineageOwner"), ("updated_by", "CoreGenericAccount")]),
+    ("Mutation", &[("CoreMenuItemCreate", "CoreMenuItemCreate"), ("CoreMenuItemUpdate", "CoreMenuItemUpdate"), ("CoreMenuItemUpsert", "CoreMenuItemUpsert"), ("CoreMenuItemDelete", "CoreMenuItemDelete"), ("CoreGroupActionCreate", "CoreGroupActionCreate"), ("CoreGroupActionUpdate", "CoreGroupActionUpdate"), ("CoreGroupActionUpsert", "CoreGroupActionUpsert"), ("CoreGroupActionDelete", "CoreGroupActionDelete"), ("CoreStandardGroupCreate", "CoreStandardGroupCreate"), ("CoreStandardGroupUpdate", "CoreStandardGroupUpdate"), ("CoreStandardGroupUpsert", "CoreStandardGroupUpsert"), ("CoreStandardGroupDelete", "CoreStandardGroupDelete"), ("CoreGeneratorGroupCreate", "CoreGeneratorGroupCreate"), ("CoreGeneratorGroupUpdate", "CoreGeneratorGroupUpdate"), ("CoreGeneratorGroupUpsert", "CoreGeneratorGroupUpsert"), ("CoreGeneratorGroupDelete", "CoreGeneratorGroupDelete"), ("CoreGeneratorAwareGroupCreate", "CoreGeneratorAwareGroupCreate"), ("CoreGeneratorAwareGroupUpdate", "CoreGeneratorAwareGroupUpdate"), ("CoreGeneratorAwareGroupUpsert", "CoreGeneratorAwareGroupUpsert"), ("CoreGeneratorAwareGroupDelete", "CoreGeneratorAwareGroupDelete"), ("CoreGraphQLQueryGroupCreate", "CoreGraphQLQueryGroupCreate"), ("CoreGraphQLQueryGroupUpdate", "CoreGraphQLQueryGroupUpdate"), ("CoreGraphQLQueryGroupUpsert", "CoreGraphQLQueryGroupUpsert"), ("CoreGraphQLQueryGroupDelete", "CoreGraphQLQueryGroupDelete"), ("CoreRepositoryGroupCreate", "CoreRepositoryGroupCreate"), ("CoreRepositoryGroupUpdate", "CoreRepositoryGroupUpdate"), ("CoreRepositoryGroupUpsert", "CoreRepositoryGroupUpsert"), ("CoreRepositoryGroupDelete", "CoreRepositoryGroupDelete"), ("BuiltinTagCreate", "BuiltinTagCreate"), ("BuiltinTagUpdate", "BuiltinTagUpdate"), ("BuiltinTagUpsert", "BuiltinTagUpsert"), ("BuiltinTagDelete", "BuiltinTagDelete"), ("CoreAccountCreate", "CoreAccountCreate"), ("CoreAccountUpdate", "CoreAccountUpdate"), ("CoreAccountUpsert", "CoreAccountUpsert"), ("CoreAccountDelete", "CoreAccountDelete"), ("CoreGeneratorActionCreate", "CoreGeneratorActionCreate"), ("CoreGeneratorActionUpdate", "CoreGeneratorActionUpdate"), ("CoreGeneratorActionUpsert", "CoreGeneratorActionUpsert"), ("CoreGeneratorActionDelete", "CoreGeneratorActionDelete"), ("CoreGroupTriggerRuleCreate", "CoreGroupTriggerRuleCreate"), ("CoreGroupTriggerRuleUpdate", "CoreGroupTriggerRuleUpdate"), ("CoreGroupTriggerRuleUpsert", "CoreGroupTriggerRuleUpsert"), ("CoreGroupTriggerRuleDelete", "CoreGroupTriggerRuleDelete"), ("CoreNodeTriggerRuleCreate", "CoreNodeTriggerRuleCreate"), ("CoreNodeTriggerRuleUpdate", "CoreNodeTriggerRuleUpdate"), ("CoreNodeTriggerRuleUpsert", "CoreNodeTriggerRuleUpsert"), ("CoreNodeTriggerRuleDelete", "CoreNodeTriggerRuleDelete"), ("CoreNodeTriggerAttributeMatchCreate", "CoreNodeTriggerAttributeMatchCreate"), ("CoreNodeTriggerAttributeMatchUpdate", "CoreNodeTriggerAttributeMatchUpdate"), ("CoreNodeTriggerAttributeMatchUpsert", "CoreNodeTriggerAttributeMatchUpsert"), ("CoreNodeTriggerAttributeMatchDelete", "CoreNodeTriggerAttributeMatchDelete"), ("CoreNodeTriggerRelationshipMatchCreate", "CoreNodeTriggerRelationshipMatchCreate"), ("CoreNodeTriggerRelationshipMatchUpdate", "CoreNodeTriggerRelationshipMatchUpdate"), ("CoreNodeTriggerRelationshipMatchUpsert", "CoreNodeTriggerRelationshipMatchUpsert"), ("CoreNodeTriggerRelationshipMatchDelete", "CoreNodeTriggerRelationshipMatchDelete"), ("CorePasswordCredentialCreate", "CorePasswordCredentialCreate"), ("CorePasswordCredentialUpdate", "CorePasswordCredentialUpdate"), ("CorePasswordCredentialUpsert", "CorePasswordCredentialUpsert"), ("CorePasswordCredentialDelete", "CorePasswordCredentialDelete"), ("CoreProposedChangeCreate", "CoreProposedChangeCreate"), ("CoreProposedChangeUpdate", "CoreProposedChangeUpdate"), ("CoreProposedChangeUpsert", "CoreProposedChangeUpsert"), ("CoreProposedChangeDelete", "CoreProposedChangeDelete"), ("CoreChangeThreadCreate", "CoreChangeThreadCreate"), ("CoreChangeThreadUpdate", "CoreChangeThreadUpdate"), ("CoreChangeThreadUpsert", "CoreChangeThreadUpsert"), ("CoreChangeThreadDelete", "CoreChangeThreadDelete"), ("CoreFileThreadCreate", "CoreFileThreadCreate"), ("CoreFileThreadUpdate", "CoreFileThreadUpdate"), ("CoreFileThreadUpsert", "CoreFileThreadUpsert"), ("CoreFileThreadDelete", "CoreFileThreadDelete"), ("CoreArtifactThreadCreate", "CoreArtifactThreadCreate"), ("CoreArtifactThreadUpdate", "CoreArtifactThreadUpdate"), ("CoreArtifactThreadUpsert", "CoreArtifactThreadUpsert"), ("CoreArtifactThreadDelete", "CoreArtifactThreadDelete"), ("CoreObjectThreadCreate", "CoreObjectThreadCreate"), ("CoreObjectThreadUpdate", "CoreObjectThreadUpdate"), ("CoreObjectThreadUpsert", "CoreObjectThreadUpsert"), ("CoreObjectThreadDelete", "CoreObjectThreadDelete"), ("CoreChangeCommentCreate", "CoreChangeCommentCreate"), ("CoreChangeCommentUpdate", "CoreChangeCommentUpdate"), ("CoreChangeCommentUpsert", "CoreChangeCommentUpsert"), ("CoreChangeCommentDelete", "CoreChangeCommentDelete"), ("CoreThreadCommentCreate", "CoreThreadCommentCreate"), ("CoreThreadCommentUpdate", "CoreThreadCommentUpdate"), ("CoreThreadCommentUpsert", "CoreThreadCommentUpsert"), ("CoreThreadCommentDelete", "CoreThreadCommentDelete"), ("CoreRepositoryCreate", "CoreRepositoryCreate"), ("CoreRepositoryUpdate", "CoreRepositoryUpdate"), ("CoreRepositoryUpsert", "CoreRepositoryUpsert"), ("CoreRepositoryDelete", "CoreRepositoryDelete"), ("CoreReadOnlyRepositoryCreate", "CoreReadOnlyRepositoryCreate"), ("CoreReadOnlyRepositoryUpdate", "CoreReadOnlyRepositoryUpdate"), ("CoreReadOnlyRepositoryUpsert", "CoreReadOnlyRepositoryUpsert"), ("CoreReadOnlyRepositoryDelete", "CoreReadOnlyRepositoryDelete"), ("CoreTransformJinja2Create", "CoreTransformJinja2Create"), ("CoreTransformJinja2Update", "CoreTransformJinja2Update"), ("CoreTransformJinja2Upsert", "CoreTransformJinja2Upsert"), ("CoreTransformJinja2Delete", "CoreTransformJinja2Delete"), ("CoreDataCheckCreate", "CoreDataCheckCreate"), ("CoreDataCheckUpdate", "CoreDataCheckUpdate"), ("CoreDataCheckUpsert", "CoreDataCheckUpsert"), ("CoreDataCheckDelete", "CoreDataCheckDelete"), ("CoreStandardCheckCreate", "CoreStandardCheckCreate"), ("CoreStandardCheckUpdate", "CoreStandardCheckUpdate"), ("CoreStandardCheckUpsert", "CoreStandardCheckUpsert"), ("CoreStandardCheckDelete", "CoreStandardCheckDelete"), ("CoreSchemaCheckCreate", "CoreSchemaCheckCreate"), ("CoreSchemaCheckUpdate", "CoreSchemaCheckUpdate"), ("CoreSchemaCheckUpsert", "CoreSchemaCheckUpsert"), ("CoreSchemaCheckDelete", "CoreSchemaCheckDelete"), ("CoreFileCheckCreate", "CoreFileCheckCreate"), ("CoreFileCheckUpdate", "CoreFileCheckUpdate"), ("CoreFileCheckUpsert", "CoreFileCheckUpsert"), ("CoreFileCheckDelete", "CoreFileCheckDelete"), ("CoreArtifactCheckCreate", "CoreArtifactCheckCreate"), ("CoreArtifactCheckUpdate", "CoreArtifactCheckUpdate"), ("CoreArtifactCheckUpsert", "CoreArtifactCheckUpsert"), ("CoreArtifactCheckDelete", "CoreArtifactCheckDelete"), ("CoreGeneratorCheckCreate", "CoreGeneratorCheckCreate"), ("CoreGeneratorCheckUpdate", "CoreGeneratorCheckUpdate"), ("CoreGeneratorCheckUpsert", "CoreGeneratorCheckUpsert"), ("CoreGeneratorCheckDelete", "CoreGeneratorCheckDelete"), ("CoreDataValidatorCreate", "CoreDataValidatorCreate"), ("CoreDataValidatorUpdate", "CoreDataValidatorUpdate"), ("CoreDataValidatorUpsert", "CoreDataValidatorUpsert"), ("CoreDataValidatorDelete", "CoreDataValidatorDelete"), ("CoreRepositoryValidatorCreate", "CoreRepositoryValidatorCreate"), ("CoreRepositoryValidatorUpdate", "CoreRepositoryValidatorUpdate"), ("CoreRepositoryValidatorUpsert", "CoreRepositoryValidatorUpsert"), ("CoreRepositoryValidatorDelete", "CoreRepositoryValidatorDelete"), ("CoreUserValidatorCreate", "CoreUserValidatorCreate"), ("CoreUserValidatorUpdate", "CoreUserValidatorUpdate"), ("CoreUserValidatorUpsert", "CoreUserValidatorUpsert"), ("CoreUserValidatorDelete", "CoreUserValidatorDelete"), ("CoreSchemaValidatorCreate", "CoreSchemaValidatorCreate"), ("CoreSchemaValidatorUpdate", "CoreSchemaValidatorUpdate"), ("CoreSchemaValidatorUpsert", "CoreSchemaValidatorUpsert"), ("CoreSchemaValidatorDelete", "CoreSchemaValidatorDelete"), ("CoreArtifactValidatorCreate", "CoreArtifactValidatorCreate"), ("CoreArtifactValidatorUpdate", "CoreArtifactValidatorUpdate"), ("CoreArtifactValidatorUpsert", "CoreArtifactValidatorUpsert"), ("CoreArtifactValidatorDelete", "CoreArtifactValidatorDelete"), ("CoreGeneratorValidatorCreate", "CoreGeneratorValidatorCreate"), ("CoreGeneratorValidatorUpdate", "CoreGeneratorValidatorUpdate"), ("CoreGeneratorValidatorUpsert", "CoreGeneratorValidatorUpsert"), ("CoreGeneratorValidatorDelete", "CoreGeneratorValidatorDelete"), ("CoreCheckDefinitionCreate", "CoreCheckDefinitionCreate"), ("CoreCheckDefinitionUpdate", "CoreCheckDefinitionUpdate"), ("CoreCheckDefinitionUpsert", "CoreCheckDefinitionUpsert"), ("CoreCheckDefinitionDelete", "CoreCheckDefinitionDelete"), ("CoreTransformPythonCreate", "CoreTransformPythonCreate"), ("CoreTransformPythonUpdate", "CoreTransformPythonUpdate"), ("CoreTransformPythonUpsert", "CoreTransformPythonUpsert"), ("CoreTransformPythonDelete", "CoreTransformPythonDelete"), ("CoreGraphQLQueryCreate", "CoreGraphQLQueryCreate"), ("CoreGraphQLQueryUpdate", "CoreGraphQLQueryUpdate"), ("CoreGraphQLQueryUpsert", "CoreGraphQLQueryUpsert"), ("CoreGraphQLQueryDelete", "CoreGraphQLQueryDelete"), ("CoreArtifactCreate", "CoreArtifactCreate"), ("CoreArtifactUpdate", "CoreArtifactUpdate"), ("CoreArtifactUpsert", "CoreArtifactUpsert"), ("CoreArtifactDelete", "CoreArtifactDelete"), ("CoreArtifactDefinitionCreate", "CoreArtifactDefinitionCreate"), ("CoreArtifactDefinitionUpdate", "CoreArtifactDefinitionUpdate"), ("CoreArtifactDefinitionUpsert", "CoreArtifactDefinitionUpsert"), ("CoreArtifactDefinitionDelete", "CoreArtifactDefinitionDelete"), ("CoreGeneratorDefinitionCreate", "CoreGeneratorDefinitionCreate"), ("CoreGeneratorDefinitionUpdate", "CoreGeneratorDefinitionUpdate"), ("CoreGeneratorDefinitionUpsert", "CoreGeneratorDefinitionUpsert"), ("CoreGeneratorDefinitionDelete", "CoreGeneratorDefinitionDelete"), ("CoreGeneratorInstanceCreate", "CoreGeneratorInstanceCreate"), ("CoreGeneratorInstanceUpdate", "CoreGeneratorInstanceUpdate"), ("CoreGeneratorInstanceUpsert", "CoreGeneratorInstanceUpsert"), ("CoreGeneratorInstanceDelete", "CoreGeneratorInstanceDelete"), ("CoreStaticKeyValueCreate", "CoreStaticKeyValueCreate"), ("CoreStaticKeyValueUpdate", "CoreStaticKeyValueUpdate"), ("CoreStaticKeyValueUpsert", "CoreStaticKeyValueUpsert"), ("CoreStaticKeyValueDelete", "CoreStaticKeyValueDelete"), ("CoreEnvKeyValueCreate", "CoreEnvKeyValueCreate"), ("CoreEnvKeyValueUpdate", "CoreEnvKeyValueUpdate"), ("CoreEnvKeyValueUpsert", "CoreEnvKeyValueUpsert"), ("CoreEnvKeyValueDelete", "CoreEnvKeyValueDelete"), ("CoreStandardWebhookCreate", "CoreStandardWebhookCreate"), ("CoreStandardWebhookUpdate", "CoreStandardWebhookUpdate"), ("CoreStandardWebhookUpsert", "CoreStandardWebhookUpsert"), ("CoreStandardWebhookDelete", "CoreStandardWebhookDelete"), ("CoreCustomWebhookCreate", "CoreCustomWebhookCreate"), ("CoreCustomWebhookUpdate", "CoreCustomWebhookUpdate"), ("CoreCustomWebhookUpsert", "CoreCustomWebhookUpsert"), ("CoreCustomWebhookDelete", "CoreCustomWebhookDelete"), ("IpamNamespaceCreate", "IpamNamespaceCreate"), ("IpamNamespaceUpdate", "IpamNamespaceUpdate"), ("IpamNamespaceUpsert", "IpamNamespaceUpsert"), ("IpamNamespaceDelete", "IpamNamespaceDelete"), ("CoreIPPrefixPoolCreate", "CoreIPPrefixPoolCreate"), ("CoreIPPrefixPoolUpdate", "CoreIPPrefixPoolUpdate"), ("CoreIPPrefixPoolUpsert", "CoreIPPrefixPoolUpsert"), ("CoreIPPrefixPoolDelete", "CoreIPPrefixPoolDelete"), ("CoreIPAddressPoolCreate", "CoreIPAddressPoolCreate"), ("CoreIPAddressPoolUpdate", "CoreIPAddressPoolUpdate"), ("CoreIPAddressPoolUpsert", "CoreIPAddressPoolUpsert"), ("CoreIPAddressPoolDelete", "CoreIPAddressPoolDelete"), ("CoreNumberPoolCreate", "CoreNumberPoolCreate"), ("CoreNumberPoolUpdate", "CoreNumberPoolUpdate"), ("CoreNumberPoolUpsert", "CoreNumberPoolUpsert"), ("CoreNumberPoolDelete", "CoreNumberPoolDelete"), ("CoreGlobalPermissionCreate", "CoreGlobalPermissionCreate"), ("CoreGlobalPermissionUpdate", "CoreGlobalPermissionUpdate"), ("CoreGlobalPermissionUpsert", "CoreGlobalPermissionUpsert"), ("CoreGlobalPermissionDelete", "CoreGlobalPermissionDelete"), ("CoreObjectPermissionCreate", "CoreObjectPermissionCreate"), ("CoreObjectPermissionUpdate", "CoreObjectPermissionUpdate"), ("CoreObjectPermissionUpsert", "CoreObjectPermissionUpsert"), ("CoreObjectPermissionDelete", "CoreObjectPermissionDelete"), ("CoreAccountRoleCreate", "CoreAccountRoleCreate"), ("CoreAccountRoleUpdate", "CoreAccountRoleUpdate"), ("CoreAccountRoleUpsert", "CoreAccountRoleUpsert"), ("CoreAccountRoleDelete", "CoreAccountRoleDelete"), ("CoreAccountGroupCreate", "CoreAccountGroupCreate"), ("CoreAccountGroupUpdate", "CoreAccountGroupUpdate"), ("CoreAccountGroupUpsert", "CoreAccountGroupUpsert"), ("CoreAccountGroupDelete", "CoreAccountGroupDelete"), ("CoreProfileUpdate", "CoreProfileUpdate"), ("CoreActionUpdate", "CoreActionUpdate"), ("CoreTriggerRuleUpdate", "CoreTriggerRuleUpdate"), ("CoreNodeTriggerMatchUpdate", "CoreNodeTriggerMatchUpdate"), ("CoreNodeUpdate", "CoreNodeUpdate"), ("CoreCommentUpdate", "CoreCommentUpdate"), ("CoreThreadUpdate", "CoreThreadUpdate"), ("CoreGroupUpdate", "CoreGroupUpdate"), ("CoreValidatorUpdate", "CoreValidatorUpdate"), ("CoreCheckUpdate", "CoreCheckUpdate"), ("CoreTransformationUpdate", "CoreTransformationUpdate"), ("CoreArtifactTargetUpdate", "CoreArtifactTargetUpdate"), ("CoreFileObjectUpdate", "CoreFileObjectUpdate"), ("CoreTaskTargetUpdate", "CoreTaskTargetUpdate"), ("CoreKeyValueUpdate", "CoreKeyValueUpdate"), ("CoreWebhookUpdate", "CoreWebhookUpdate"), ("CoreGenericRepositoryUpdate", "CoreGenericRepositoryUpdate"), ("BuiltinIPNamespaceUpdate", "BuiltinIPNamespaceUpdate"), ("BuiltinIPPrefixUpdate", "BuiltinIPPrefixUpdate"), ("BuiltinIPAddressUpdate", "BuiltinIPAddressUpdate"), ("CoreResourcePoolUpdate", "CoreResourcePoolUpdate"), ("CoreWeightedPoolResourceUpdate", "CoreWeightedPoolResourceUpdate"), ("CoreGenericAccountUpdate", "CoreGenericAccountUpdate"), ("CoreBasePermissionUpdate", "CoreBasePermissionUpdate"), ("CoreCredentialUpdate", "CoreCredentialUpdate"), ("CoreObjectTemplateUpdate", "CoreObjectTemplateUpdate"), ("CoreObjectComponentTemplateUpdate", "CoreObjectComponentTemplateUpdate"), ("CoreMenuUpdate", "CoreMenuUpdate"), ("ProfileBuiltinTagCreate", "ProfileBuiltinTagCreate"), ("ProfileBuiltinTagUpdate", "ProfileBuiltinTagUpdate"), ("ProfileBuiltinTagUpsert", "ProfileBuiltinTagUpsert"), ("ProfileBuiltinTagDelete", "ProfileBuiltinTagDelete"), ("ProfileIpamNamespaceCreate", "ProfileIpamNamespaceCreate"), ("ProfileIpamNamespaceUpdate", "ProfileIpamNamespaceUpdate"), ("ProfileIpamNamespaceUpsert", "ProfileIpamNamespaceUpsert"), ("ProfileIpamNamespaceDelete", "ProfileIpamNamespaceDelete"), ("ProfileBuiltinIPPrefixCreate", "ProfileBuiltinIPPrefixCreate"), ("ProfileBuiltinIPPrefixUpdate", "ProfileBuiltinIPPrefixUpdate"), ("ProfileBuiltinIPPrefixUpsert", "ProfileBuiltinIPPrefixUpsert"), ("ProfileBuiltinIPPrefixDelete", "ProfileBuiltinIPPrefixDelete"), ("ProfileBuiltinIPAddressCreate", "ProfileBuiltinIPAddressCreate"), ("ProfileBuiltinIPAddressUpdate", "ProfileBuiltinIPAddressUpdate"), ("ProfileBuiltinIPAddressUpsert", "ProfileBuiltinIPAddressUpsert"), ("ProfileBuiltinIPAddressDelete", "ProfileBuiltinIPAddressDelete"), ("InfrahubAccountTokenCreate", "InfrahubAccountTokenCreate"), ("InfrahubAccountSelfUpdate", "InfrahubAccountSelfUpdate"), ("InfrahubAccountTokenDelete", "InfrahubAccountTokenDelete"), ("CoreProposedChangeRunCheck", "ProposedChangeRequestRunCheck"), ("CoreProposedChangeMerge", "ProposedChangeMerge"), ("CoreProposedChangeReview", "ProposedChangeReview"), ("CoreGeneratorDefinitionRun", "GeneratorDefinitionRequestRun"), ("InfrahubIPPrefixPoolGetResource", "IPPrefixPoolGetResource"), ("InfrahubIPAddressPoolGetResource", "IPAddressPoolGetResource"), ("BranchCreate", "BranchCreate"), ("BranchDelete", "BranchDelete"), ("BranchRebase", "BranchRebase"), ("BranchMerge", "BranchMerge"), ("BranchUpdate", "BranchUpdate"), ("BranchValidate", "BranchValidate"), ("DiffUpdate", "DiffUpdateMutation"), ("InfrahubReadOnlyRepositoryImportLastCommit", "ReadOnlyRepositoryImportLastCommit"), ("InfrahubRepositoryProcess", "ProcessRepository"), ("InfrahubRepositoryConnectivity", "ValidateRepositoryConnectivity"), ("InfrahubUpdateComputedAttribute", "UpdateComputedAttribute"), ("InfrahubUpdateDisplayLabel", "UpdateDisplayLabel"), ("InfrahubUpdateHFID", "UpdateHFID"), ("InfrahubRecomputeComputedAttribute", "RecomputeComputedAttribute"), ("RelationshipAdd", "RelationshipAdd"), ("RelationshipRemove", "RelationshipRemove"), ("SchemaDropdownAdd", "SchemaDropdownAdd"), ("SchemaDropdownRemove", "SchemaDropdownRemove"), ("SchemaEnumAdd", "SchemaEnumAdd"), ("SchemaEnumRemove", "SchemaEnumRemove"), ("ResolveDiffConflict", "ResolveDiffConflict"), ("ConvertObjectType", "ConvertObjectType"), ("CoreProposedChangeCheckForApprovalRevoke", "ProposedChangeCheckForApprovalRevoke"), ("InfrahubProfilesRefresh", "InfrahubProfilesRefresh")]),
+    ("NestedEdgedBuiltinIPAddress", &[("node", "BuiltinIPAddress"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedBuiltinIPNamespace", &[("node", "BuiltinIPNamespace"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedBuiltinIPPrefix", &[("node", "BuiltinIPPrefix"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedBuiltinTag", &[("node", "BuiltinTag"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreAccount", &[("node", "CoreAccount"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreAccountGroup", &[("node", "CoreAccountGroup"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreAccountRole", &[("node", "CoreAccountRole"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreAction", &[("node", "CoreAction"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreArtifact", &[("node", "CoreArtifact"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreArtifactCheck", &[("node", "CoreArtifactCheck"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreArtifactDefinition", &[("node", "CoreArtifactDefinition"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreArtifactTarget", &[("node", "CoreArtifactTarget"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreArtifactThread", &[("node", "CoreArtifactThread"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreArtifactValidator", &[("node", "CoreArtifactValidator"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreBasePermission", &[("node", "CoreBasePermission"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreChangeComment", &[("node", "CoreChangeComment"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreChangeThread", &[("node", "CoreChangeThread"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreCheck", &[("node", "CoreCheck"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreCheckDefinition", &[("node", "CoreCheckDefinition"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreComment", &[("node", "CoreComment"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreCredential", &[("node", "CoreCredential"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreCustomWebhook", &[("node", "CoreCustomWebhook"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreDataCheck", &[("node", "CoreDataCheck"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreDataValidator", &[("node", "CoreDataValidator"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreEnvKeyValue", &[("node", "CoreEnvKeyValue"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreFileCheck", &[("node", "CoreFileCheck"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreFileObject", &[("node", "CoreFileObject"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreFileThread", &[("node", "CoreFileThread"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreGeneratorAction", &[("node", "CoreGeneratorAction"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreGeneratorAwareGroup", &[("node", "CoreGeneratorAwareGroup"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreGeneratorCheck", &[("node", "CoreGeneratorCheck"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreGeneratorDefinition", &[("node", "CoreGeneratorDefinition"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreGeneratorGroup", &[("node", "CoreGeneratorGroup"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreGeneratorInstance", &[("node", "CoreGeneratorInstance"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreGeneratorValidator", &[("node", "CoreGeneratorValidator"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreGenericAccount", &[("node", "CoreGenericAccount"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreGenericRepository", &[("node", "CoreGenericRepository"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreGlobalPermission", &[("node", "CoreGlobalPermission"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreGraphQLQuery", &[("node", "CoreGraphQLQuery"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreGraphQLQueryGroup", &[("node", "CoreGraphQLQueryGroup"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreGroup", &[("node", "CoreGroup"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreGroupAction", &[("node", "CoreGroupAction"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreGroupTriggerRule", &[("node", "CoreGroupTriggerRule"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreIPAddressPool", &[("node", "CoreIPAddressPool"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreIPPrefixPool", &[("node", "CoreIPPrefixPool"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreKeyValue", &[("node", "CoreKeyValue"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreMenu", &[("node", "CoreMenu"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreMenuItem", &[("node", "CoreMenuItem"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreNode", &[("node", "CoreNode"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreNodeTriggerAttributeMatch", &[("node", "CoreNodeTriggerAttributeMatch"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreNodeTriggerMatch", &[("node", "CoreNodeTriggerMatch"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreNodeTriggerRelationshipMatch", &[("node", "CoreNodeTriggerRelationshipMatch"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreNodeTriggerRule", &[("node", "CoreNodeTriggerRule"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreNumberPool", &[("node", "CoreNumberPool"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreObjectComponentTemplate", &[("node", "CoreObjectComponentTemplate"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreObjectPermission", &[("node", "CoreObjectPermission"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreObjectTemplate", &[("node", "CoreObjectTemplate"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreObjectThread", &[("node", "CoreObjectThread"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCorePasswordCredential", &[("node", "CorePasswordCredential"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreProfile", &[("node", "CoreProfile"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreProposedChange", &[("node", "CoreProposedChange"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreReadOnlyRepository", &[("node", "CoreReadOnlyRepository"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreRepository", &[("node", "CoreRepository"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreRepositoryGroup", &[("node", "CoreRepositoryGroup"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreRepositoryValidator", &[("node", "CoreRepositoryValidator"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreResourcePool", &[("node", "CoreResourcePool"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreSchemaCheck", &[("node", "CoreSchemaCheck"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreSchemaValidator", &[("node", "CoreSchemaValidator"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreStandardCheck", &[("node", "CoreStandardCheck"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreStandardGroup", &[("node", "CoreStandardGroup"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreStandardWebhook", &[("node", "CoreStandardWebhook"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreStaticKeyValue", &[("node", "CoreStaticKeyValue"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreTaskTarget", &[("node", "CoreTaskTarget"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreThread", &[("node", "CoreThread"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreThreadComment", &[("node", "CoreThreadComment"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreTransformJinja2", &[("node", "CoreTransformJinja2"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreTransformPython", &[("node", "CoreTransformPython"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreTransformation", &[("node", "CoreTransformation"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreTriggerRule", &[("node", "CoreTriggerRule"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreUserValidator", &[("node", "CoreUserValidator"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreValidator", &[("node", "CoreValidator"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreWebhook", &[("node", "CoreWebhook"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedCoreWeightedPoolResource", &[("node", "CoreWeightedPoolResource"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedInternalAccountToken", &[("node", "InternalAccountToken"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedInternalExternalIdentity", &[("node", "InternalExternalIdentity"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedInternalIPPrefixAvailable", &[("node", "InternalIPPrefixAvailable"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedInternalIPRangeAvailable", &[("node", "InternalIPRangeAvailable"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedInternalRefreshToken", &[("node", "InternalRefreshToken"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedIpamNamespace", &[("node", "IpamNamespace"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedLineageOwner", &[("node", "LineageOwner"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedLineageSource", &[("node", "LineageSource"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedProfileBuiltinIPAddress", &[("node", "ProfileBuiltinIPAddress"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedProfileBuiltinIPPrefix", &[("node", "ProfileBuiltinIPPrefix"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedProfileBuiltinTag", &[("node", "ProfileBuiltinTag"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedEdgedProfileIpamNamespace", &[("node", "ProfileIpamNamespace"), ("node_metadata", "InfrahubNodeMetadata"), ("properties", "RelationshipProperty"), ("relationship_metadata", "InfrahubRelationshipMetadata")]),
+    ("NestedPaginatedBuiltinIPAddress", &[("count", "Int"), ("edges", "NestedEdgedBuiltinIPAddress")]),
+    ("NestedPaginatedBuiltinIPNamespace", &[("count", "Int"), ("edges", "NestedEdgedBuiltinIPNamespace")]),
+    ("NestedPaginatedBuiltinIPPrefix", &[("count", "Int"), ("edges", "NestedEdgedBuiltinIPPrefix")]),
+    ("NestedPaginatedBuiltinTag", &[("count", "Int"), ("edges", "NestedEdgedBuiltinTag"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreAccount", &[("count", "Int"), ("edges", "NestedEdgedCoreAccount"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreAccountGroup", &[("count", "Int"), ("edges", "NestedEdgedCoreAccountGroup"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreAccountRole", &[("count", "Int"), ("edges", "NestedEdgedCoreAccountRole"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreAction", &[("count", "Int"), ("edges", "NestedEdgedCoreAction")]),
+    ("NestedPaginatedCoreArtifact", &[("count", "Int"), ("edges", "NestedEdgedCoreArtifact"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreArtifactCheck", &[("count", "Int"), ("edges", "NestedEdgedCoreArtifactCheck"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreArtifactDefinition", &[("count", "Int"), ("edges", "NestedEdgedCoreArtifactDefinition"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreArtifactTarget", &[("count", "Int"), ("edges", "NestedEdgedCoreArtifactTarget")]),
+    ("NestedPaginatedCoreArtifactThread", &[("count", "Int"), ("edges", "NestedEdgedCoreArtifactThread"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreArtifactValidator", &[("count", "Int"), ("edges", "NestedEdgedCoreArtifactValidator"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreBasePermission", &[("count", "Int"), ("edges", "NestedEdgedCoreBasePermission")]),
+    ("NestedPaginatedCoreChangeComment", &[("count", "Int"), ("edges", "NestedEdgedCoreChangeComment"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreChangeThread", &[("count", "Int"), ("edges", "NestedEdgedCoreChangeThread"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreCheck", &[("count", "Int"), ("edges", "NestedEdgedCoreCheck")]),
+    ("NestedPaginatedCoreCheckDefinition", &[("count", "Int"), ("edges", "NestedEdgedCoreCheckDefinition"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreComment", &[("count", "Int"), ("edges", "NestedEdgedCoreComment")]),
+    ("NestedPaginatedCoreCredential", &[("count", "Int"), ("edges", "NestedEdgedCoreCredential")]),
+    ("NestedPaginatedCoreCustomWebhook", &[("count", "Int"), ("edges", "NestedEdgedCoreCustomWebhook"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreDataCheck", &[("count", "Int"), ("edges", "NestedEdgedCoreDataCheck"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreDataValidator", &[("count", "Int"), ("edges", "NestedEdgedCoreDataValidator"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreEnvKeyValue", &[("count", "Int"), ("edges", "NestedEdgedCoreEnvKeyValue"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreFileCheck", &[("count", "Int"), ("edges", "NestedEdgedCoreFileCheck"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreFileObject", &[("count", "Int"), ("edges", "NestedEdgedCoreFileObject")]),
+    ("NestedPaginatedCoreFileThread", &[("count", "Int"), ("edges", "NestedEdgedCoreFileThread"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreGeneratorAction", &[("count", "Int"), ("edges", "NestedEdgedCoreGeneratorAction"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreGeneratorAwareGroup", &[("count", "Int"), ("edges", "NestedEdgedCoreGeneratorAwareGroup"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreGeneratorCheck", &[("count", "Int"), ("edges", "NestedEdgedCoreGeneratorCheck"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreGeneratorDefinition", &[("count", "Int"), ("edges", "NestedEdgedCoreGeneratorDefinition"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreGeneratorGroup", &[("count", "Int"), ("edges", "NestedEdgedCoreGeneratorGroup"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreGeneratorInstance", &[("count", "Int"), ("edges", "NestedEdgedCoreGeneratorInstance"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreGeneratorValidator", &[("count", "Int"), ("edges", "NestedEdgedCoreGeneratorValidator"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreGenericAccount", &[("count", "Int"), ("edges", "NestedEdgedCoreGenericAccount")]),
+    ("NestedPaginatedCoreGenericRepository", &[("count", "Int"), ("edges", "NestedEdgedCoreGenericRepository")]),
+    ("NestedPaginatedCoreGlobalPermission", &[("count", "Int"), ("edges", "NestedEdgedCoreGlobalPermission"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreGraphQLQuery", &[("count", "Int"), ("edges", "NestedEdgedCoreGraphQLQuery"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreGraphQLQueryGroup", &[("count", "Int"), ("edges", "NestedEdgedCoreGraphQLQueryGroup"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreGroup", &[("count", "Int"), ("edges", "NestedEdgedCoreGroup")]),
+    ("NestedPaginatedCoreGroupAction", &[("count", "Int"), ("edges", "NestedEdgedCoreGroupAction"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreGroupTriggerRule", &[("count", "Int"), ("edges", "NestedEdgedCoreGroupTriggerRule"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreIPAddressPool", &[("count", "Int"), ("edges", "NestedEdgedCoreIPAddressPool"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreIPPrefixPool", &[("count", "Int"), ("edges", "NestedEdgedCoreIPPrefixPool"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreKeyValue", &[("count", "Int"), ("edges", "NestedEdgedCoreKeyValue")]),
+    ("NestedPaginatedCoreMenu", &[("count", "Int"), ("edges", "NestedEdgedCoreMenu")]),
+    ("NestedPaginatedCoreMenuItem", &[("count", "Int"), ("edges", "NestedEdgedCoreMenuItem"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreNode", &[("count", "Int"), ("edges", "NestedEdgedCoreNode")]),
+    ("NestedPaginatedCoreNodeTriggerAttributeMatch", &[("count", "Int"), ("edges", "NestedEdgedCoreNodeTriggerAttributeMatch"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreNodeTriggerMatch", &[("count", "Int"), ("edges", "NestedEdgedCoreNodeTriggerMatch")]),
+    ("NestedPaginatedCoreNodeTriggerRelationshipMatch", &[("count", "Int"), ("edges", "NestedEdgedCoreNodeTriggerRelationshipMatch"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreNodeTriggerRule", &[("count", "Int"), ("edges", "NestedEdgedCoreNodeTriggerRule"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreNumberPool", &[("count", "Int"), ("edges", "NestedEdgedCoreNumberPool"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreObjectComponentTemplate", &[("count", "Int"), ("edges", "NestedEdgedCoreObjectComponentTemplate")]),
+    ("NestedPaginatedCoreObjectPermission", &[("count", "Int"), ("edges", "NestedEdgedCoreObjectPermission"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreObjectTemplate", &[("count", "Int"), ("edges", "NestedEdgedCoreObjectTemplate")]),
+    ("NestedPaginatedCoreObjectThread", &[("count", "Int"), ("edges", "NestedEdgedCoreObjectThread"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCorePasswordCredential", &[("count", "Int"), ("edges", "NestedEdgedCorePasswordCredential"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreProfile", &[("count", "Int"), ("edges", "NestedEdgedCoreProfile")]),
+    ("NestedPaginatedCoreProposedChange", &[("count", "Int"), ("edges", "NestedEdgedCoreProposedChange"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreReadOnlyRepository", &[("count", "Int"), ("edges", "NestedEdgedCoreReadOnlyRepository"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreRepository", &[("count", "Int"), ("edges", "NestedEdgedCoreRepository"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreRepositoryGroup", &[("count", "Int"), ("edges", "NestedEdgedCoreRepositoryGroup"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreRepositoryValidator", &[("count", "Int"), ("edges", "NestedEdgedCoreRepositoryValidator"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreResourcePool", &[("count", "Int"), ("edges", "NestedEdgedCoreResourcePool")]),
+    ("NestedPaginatedCoreSchemaCheck", &[("count", "Int"), ("edges", "NestedEdgedCoreSchemaCheck"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreSchemaValidator", &[("count", "Int"), ("edges", "NestedEdgedCoreSchemaValidator"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreStandardCheck", &[("count", "Int"), ("edges", "NestedEdgedCoreStandardCheck"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreStandardGroup", &[("count", "Int"), ("edges", "NestedEdgedCoreStandardGroup"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreStandardWebhook", &[("count", "Int"), ("edges", "NestedEdgedCoreStandardWebhook"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreStaticKeyValue", &[("count", "Int"), ("edges", "NestedEdgedCoreStaticKeyValue"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreTaskTarget", &[("count", "Int"), ("edges", "NestedEdgedCoreTaskTarget")]),
+    ("NestedPaginatedCoreThread", &[("count", "Int"), ("edges", "NestedEdgedCoreThread")]),
+    ("NestedPaginatedCoreThreadComment", &[("count", "Int"), ("edges", "NestedEdgedCoreThreadComment"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreTransformJinja2", &[("count", "Int"), ("edges", "NestedEdgedCoreTransformJinja2"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreTransformPython", &[("count", "Int"), ("edges", "NestedEdgedCoreTransformPython"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreTransformation", &[("count", "Int"), ("edges", "NestedEdgedCoreTransformation")]),
+    ("NestedPaginatedCoreTriggerRule", &[("count", "Int"), ("edges", "NestedEdgedCoreTriggerRule")]),
+    ("NestedPaginatedCoreUserValidator", &[("count", "Int"), ("edges", "NestedEdgedCoreUserValidator"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedCoreValidator", &[("count", "Int"), ("edges", "NestedEdgedCoreValidator")]),
+    ("NestedPaginatedCoreWebhook", &[("count", "Int"), ("edges", "NestedEdgedCoreWebhook")]),
+    ("NestedPaginatedCoreWeightedPoolResource", &[("count", "Int"), ("edges", "NestedEdgedCoreWeightedPoolResource")]),
+    ("NestedPaginatedInternalAccountToken", &[("count", "Int"), ("edges", "NestedEdgedInternalAccountToken"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedInternalExternalIdentity", &[("count", "Int"), ("edges", "NestedEdgedInternalExternalIdentity"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedInternalIPPrefixAvailable", &[("count", "Int"), ("edges", "NestedEdgedInternalIPPrefixAvailable"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedInternalIPRangeAvailable", &[("count", "Int"), ("edges", "NestedEdgedInternalIPRangeAvailable"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedInternalRefreshToken", &[("count", "Int"), ("edges", "NestedEdgedInternalRefreshToken"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedIpamNamespace", &[("count", "Int"), ("edges", "NestedEdgedIpamNamespace"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedLineageOwner", &[("count", "Int"), ("edges", "NestedEdgedLineageOwner")]),
+    ("NestedPaginatedLineageSource", &[("count", "Int"), ("edges", "NestedEdgedLineageSource")]),
+    ("NestedPaginatedProfileBuiltinIPAddress", &[("count", "Int"), ("edges", "NestedEdgedProfileBuiltinIPAddress"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedProfileBuiltinIPPrefix", &[("count", "Int"), ("edges", "NestedEdgedProfileBuiltinIPPrefix"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedProfileBuiltinTag", &[("count", "Int"), ("edges", "NestedEdgedProfileBuiltinTag"), ("permissions", "PaginatedObjectPermission")]),
+    ("NestedPaginatedProfileIpamNamespace", &[("count", "Int"), ("edges", "NestedEdgedProfileIpamNamespace"), ("permissions", "PaginatedObjectPermission")]),
+    ("Node", &[("id", "String"), ("kind", "String")]),
+    ("NodeEdge", &[("node", "Node")]),
+    ("NodeEdges", &[("count", "Int"), ("edges", "NodeEdge"), ("parent_prefixes", "NodeEdge")]),
+    ("NodeMutatedEvent", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String"), ("payload", "GenericScalar"), ("attributes", "InfrahubMutatedAttribute"), ("relationships", "InfrahubMutatedRelationship")]),
+    ("NonRequiredBooleanValueField", &[("value", "Boolean")]),
+    ("NonRequiredIntValueField", &[("value", "Int")]),
+    ("NonRequiredStringValueField", &[("value", "String")]),
+    ("NumberAttribute", &[("is_default", "Boolean"), ("is_protected", "Boolean"), ("updated_at", "DateTime"), ("id", "String"), ("is_from_profile", "Boolean"), ("permissions", "PermissionType"), ("value", "BigInt"), ("source", "LineageSource"), ("owner", "LineageOwner"), ("updated_by", "CoreGenericAccount")]),
+    ("ObjectPermission", &[("kind", "String"), ("view", "BranchRelativePermissionDecision"), ("create", "BranchRelativePermissionDecision"), ("update", "BranchRelativePermissionDecision"), ("delete", "BranchRelativePermissionDecision")]),
+    ("ObjectPermissionNode", &[("node", "ObjectPermission")]),
+    ("PaginatedBuiltinIPAddress", &[("count", "Int"), ("edges", "EdgedBuiltinIPAddress"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedBuiltinIPNamespace", &[("count", "Int"), ("edges", "EdgedBuiltinIPNamespace"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedBuiltinIPPrefix", &[("count", "Int"), ("edges", "EdgedBuiltinIPPrefix"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedBuiltinTag", &[("count", "Int"), ("edges", "EdgedBuiltinTag"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreAccount", &[("count", "Int"), ("edges", "EdgedCoreAccount"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreAccountGroup", &[("count", "Int"), ("edges", "EdgedCoreAccountGroup"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreAccountRole", &[("count", "Int"), ("edges", "EdgedCoreAccountRole"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreAction", &[("count", "Int"), ("edges", "EdgedCoreAction"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreArtifact", &[("count", "Int"), ("edges", "EdgedCoreArtifact"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreArtifactCheck", &[("count", "Int"), ("edges", "EdgedCoreArtifactCheck"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreArtifactDefinition", &[("count", "Int"), ("edges", "EdgedCoreArtifactDefinition"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreArtifactTarget", &[("count", "Int"), ("edges", "EdgedCoreArtifactTarget"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreArtifactThread", &[("count", "Int"), ("edges", "EdgedCoreArtifactThread"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreArtifactValidator", &[("count", "Int"), ("edges", "EdgedCoreArtifactValidator"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreBasePermission", &[("count", "Int"), ("edges", "EdgedCoreBasePermission"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreChangeComment", &[("count", "Int"), ("edges", "EdgedCoreChangeComment"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreChangeThread", &[("count", "Int"), ("edges", "EdgedCoreChangeThread"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreCheck", &[("count", "Int"), ("edges", "EdgedCoreCheck"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreCheckDefinition", &[("count", "Int"), ("edges", "EdgedCoreCheckDefinition"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreComment", &[("count", "Int"), ("edges", "EdgedCoreComment"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreCredential", &[("count", "Int"), ("edges", "EdgedCoreCredential"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreCustomWebhook", &[("count", "Int"), ("edges", "EdgedCoreCustomWebhook"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreDataCheck", &[("count", "Int"), ("edges", "EdgedCoreDataCheck"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreDataValidator", &[("count", "Int"), ("edges", "EdgedCoreDataValidator"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreEnvKeyValue", &[("count", "Int"), ("edges", "EdgedCoreEnvKeyValue"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreFileCheck", &[("count", "Int"), ("edges", "EdgedCoreFileCheck"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreFileObject", &[("count", "Int"), ("edges", "EdgedCoreFileObject"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreFileThread", &[("count", "Int"), ("edges", "EdgedCoreFileThread"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreGeneratorAction", &[("count", "Int"), ("edges", "EdgedCoreGeneratorAction"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreGeneratorAwareGroup", &[("count", "Int"), ("edges", "EdgedCoreGeneratorAwareGroup"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreGeneratorCheck", &[("count", "Int"), ("edges", "EdgedCoreGeneratorCheck"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreGeneratorDefinition", &[("count", "Int"), ("edges", "EdgedCoreGeneratorDefinition"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreGeneratorGroup", &[("count", "Int"), ("edges", "EdgedCoreGeneratorGroup"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreGeneratorInstance", &[("count", "Int"), ("edges", "EdgedCoreGeneratorInstance"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreGeneratorValidator", &[("count", "Int"), ("edges", "EdgedCoreGeneratorValidator"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreGenericAccount", &[("count", "Int"), ("edges", "EdgedCoreGenericAccount"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreGenericRepository", &[("count", "Int"), ("edges", "EdgedCoreGenericRepository"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreGlobalPermission", &[("count", "Int"), ("edges", "EdgedCoreGlobalPermission"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreGraphQLQuery", &[("count", "Int"), ("edges", "EdgedCoreGraphQLQuery"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreGraphQLQueryGroup", &[("count", "Int"), ("edges", "EdgedCoreGraphQLQueryGroup"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreGroup", &[("count", "Int"), ("edges", "EdgedCoreGroup"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreGroupAction", &[("count", "Int"), ("edges", "EdgedCoreGroupAction"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreGroupTriggerRule", &[("count", "Int"), ("edges", "EdgedCoreGroupTriggerRule"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreIPAddressPool", &[("count", "Int"), ("edges", "EdgedCoreIPAddressPool"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreIPPrefixPool", &[("count", "Int"), ("edges", "EdgedCoreIPPrefixPool"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreKeyValue", &[("count", "Int"), ("edges", "EdgedCoreKeyValue"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreMenu", &[("count", "Int"), ("edges", "EdgedCoreMenu"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreMenuItem", &[("count", "Int"), ("edges", "EdgedCoreMenuItem"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreNode", &[("count", "Int"), ("edges", "EdgedCoreNode"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreNodeTriggerAttributeMatch", &[("count", "Int"), ("edges", "EdgedCoreNodeTriggerAttributeMatch"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreNodeTriggerMatch", &[("count", "Int"), ("edges", "EdgedCoreNodeTriggerMatch"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreNodeTriggerRelationshipMatch", &[("count", "Int"), ("edges", "EdgedCoreNodeTriggerRelationshipMatch"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreNodeTriggerRule", &[("count", "Int"), ("edges", "EdgedCoreNodeTriggerRule"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreNumberPool", &[("count", "Int"), ("edges", "EdgedCoreNumberPool"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreObjectComponentTemplate", &[("count", "Int"), ("edges", "EdgedCoreObjectComponentTemplate"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreObjectPermission", &[("count", "Int"), ("edges", "EdgedCoreObjectPermission"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreObjectTemplate", &[("count", "Int"), ("edges", "EdgedCoreObjectTemplate"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreObjectThread", &[("count", "Int"), ("edges", "EdgedCoreObjectThread"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCorePasswordCredential", &[("count", "Int"), ("edges", "EdgedCorePasswordCredential"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreProfile", &[("count", "Int"), ("edges", "EdgedCoreProfile"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreProposedChange", &[("count", "Int"), ("edges", "EdgedCoreProposedChange"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreReadOnlyRepository", &[("count", "Int"), ("edges", "EdgedCoreReadOnlyRepository"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreRepository", &[("count", "Int"), ("edges", "EdgedCoreRepository"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreRepositoryGroup", &[("count", "Int"), ("edges", "EdgedCoreRepositoryGroup"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreRepositoryValidator", &[("count", "Int"), ("edges", "EdgedCoreRepositoryValidator"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreResourcePool", &[("count", "Int"), ("edges", "EdgedCoreResourcePool"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreSchemaCheck", &[("count", "Int"), ("edges", "EdgedCoreSchemaCheck"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreSchemaValidator", &[("count", "Int"), ("edges", "EdgedCoreSchemaValidator"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreStandardCheck", &[("count", "Int"), ("edges", "EdgedCoreStandardCheck"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreStandardGroup", &[("count", "Int"), ("edges", "EdgedCoreStandardGroup"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreStandardWebhook", &[("count", "Int"), ("edges", "EdgedCoreStandardWebhook"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreStaticKeyValue", &[("count", "Int"), ("edges", "EdgedCoreStaticKeyValue"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreTaskTarget", &[("count", "Int"), ("edges", "EdgedCoreTaskTarget"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreThread", &[("count", "Int"), ("edges", "EdgedCoreThread"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreThreadComment", &[("count", "Int"), ("edges", "EdgedCoreThreadComment"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreTransformJinja2", &[("count", "Int"), ("edges", "EdgedCoreTransformJinja2"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreTransformPython", &[("count", "Int"), ("edges", "EdgedCoreTransformPython"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreTransformation", &[("count", "Int"), ("edges", "EdgedCoreTransformation"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreTriggerRule", &[("count", "Int"), ("edges", "EdgedCoreTriggerRule"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreUserValidator", &[("count", "Int"), ("edges", "EdgedCoreUserValidator"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreValidator", &[("count", "Int"), ("edges", "EdgedCoreValidator"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreWebhook", &[("count", "Int"), ("edges", "EdgedCoreWebhook"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedCoreWeightedPoolResource", &[("count", "Int"), ("edges", "EdgedCoreWeightedPoolResource"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedInternalAccountToken", &[("count", "Int"), ("edges", "EdgedInternalAccountToken"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedInternalExternalIdentity", &[("count", "Int"), ("edges", "EdgedInternalExternalIdentity"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedInternalIPPrefixAvailable", &[("count", "Int"), ("edges", "EdgedInternalIPPrefixAvailable"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedInternalIPRangeAvailable", &[("count", "Int"), ("edges", "EdgedInternalIPRangeAvailable"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedInternalRefreshToken", &[("count", "Int"), ("edges", "EdgedInternalRefreshToken"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedIpamNamespace", &[("count", "Int"), ("edges", "EdgedIpamNamespace"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedLineageOwner", &[("count", "Int"), ("edges", "EdgedLineageOwner"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedLineageSource", &[("count", "Int"), ("edges", "EdgedLineageSource"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedObjectPermission", &[("count", "Int"), ("edges", "ObjectPermissionNode")]),
+    ("PaginatedProfileBuiltinIPAddress", &[("count", "Int"), ("edges", "EdgedProfileBuiltinIPAddress"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedProfileBuiltinIPPrefix", &[("count", "Int"), ("edges", "EdgedProfileBuiltinIPPrefix"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedProfileBuiltinTag", &[("count", "Int"), ("edges", "EdgedProfileBuiltinTag"), ("permissions", "PaginatedObjectPermission")]),
+    ("PaginatedProfileIpamNamespace", &[("count", "Int"), ("edges", "EdgedProfileIpamNamespace"), ("permissions", "PaginatedObjectPermission")]),
+    ("PermissionType", &[("update_value", "BranchRelativePermissionDecision")]),
+    ("PoolAllocated", &[("count", "BigInt"), ("edges", "PoolAllocatedEdge")]),
+    ("PoolAllocatedEdge", &[("node", "PoolAllocatedNode")]),
+    ("PoolAllocatedNode", &[("id", "String"), ("display_label", "String"), ("kind", "String"), ("branch", "String"), ("identifier", "String")]),
+    ("PoolUtilization", &[("count", "BigInt"), ("utilization", "Float"), ("utilization_branches", "Float"), ("utilization_default_branch", "Float"), ("edges", "IPPrefixUtilizationEdge")]),
+    ("ProcessRepository", &[("ok", "Boolean"), ("task", "TaskInfo")]),
+    ("ProfileBuiltinIPAddress", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("profile_name", "TextAttribute"), ("profile_priority", "NumberAttribute"), ("address", "IPHost"), ("description", "TextAttribute"), ("related_nodes", "NestedPaginatedBuiltinIPAddress"), ("ip_namespace", "NestedEdgedBuiltinIPNamespace"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("ProfileBuiltinIPAddressCreate", &[("ok", "Boolean"), ("object", "ProfileBuiltinIPAddress")]),
+    ("ProfileBuiltinIPAddressDelete", &[("ok", "Boolean")]),
+    ("ProfileBuiltinIPAddressUpdate", &[("ok", "Boolean"), ("object", "ProfileBuiltinIPAddress")]),
+    ("ProfileBuiltinIPAddressUpsert", &[("ok", "Boolean"), ("object", "ProfileBuiltinIPAddress")]),
+    ("ProfileBuiltinIPPrefix", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("profile_name", "TextAttribute"), ("profile_priority", "NumberAttribute"), ("is_pool", "CheckboxAttribute"), ("member_type", "Dropdown"), ("prefix", "IPNetwork"), ("description", "TextAttribute"), ("related_nodes", "NestedPaginatedBuiltinIPPrefix"), ("ip_namespace", "NestedEdgedBuiltinIPNamespace"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("ProfileBuiltinIPPrefixCreate", &[("ok", "Boolean"), ("object", "ProfileBuiltinIPPrefix")]),
+    ("ProfileBuiltinIPPrefixDelete", &[("ok", "Boolean")]),
+    ("ProfileBuiltinIPPrefixUpdate", &[("ok", "Boolean"), ("object", "ProfileBuiltinIPPrefix")]),
+    ("ProfileBuiltinIPPrefixUpsert", &[("ok", "Boolean"), ("object", "ProfileBuiltinIPPrefix")]),
+    ("ProfileBuiltinTag", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("profile_name", "TextAttribute"), ("profile_priority", "NumberAttribute"), ("description", "TextAttribute"), ("related_nodes", "NestedPaginatedBuiltinTag"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("ProfileBuiltinTagCreate", &[("ok", "Boolean"), ("object", "ProfileBuiltinTag")]),
+    ("ProfileBuiltinTagDelete", &[("ok", "Boolean")]),
+    ("ProfileBuiltinTagUpdate", &[("ok", "Boolean"), ("object", "ProfileBuiltinTag")]),
+    ("ProfileBuiltinTagUpsert", &[("ok", "Boolean"), ("object", "ProfileBuiltinTag")]),
+    ("ProfileIpamNamespace", &[("id", "String"), ("hfid", "String"), ("display_label", "String"), ("profile_name", "TextAttribute"), ("profile_priority", "NumberAttribute"), ("description", "TextAttribute"), ("related_nodes", "NestedPaginatedIpamNamespace"), ("ip_prefixes", "NestedPaginatedBuiltinIPPrefix"), ("ip_addresses", "NestedPaginatedBuiltinIPAddress"), ("member_of_groups", "NestedPaginatedCoreGroup"), ("subscriber_of_groups", "NestedPaginatedCoreGroup")]),
+    ("ProfileIpamNamespaceCreate", &[("ok", "Boolean"), ("object", "ProfileIpamNamespace")]),
+    ("ProfileIpamNamespaceDelete", &[("ok", "Boolean")]),
+    ("ProfileIpamNamespaceUpdate", &[("ok", "Boolean"), ("object", "ProfileIpamNamespace")]),
+    ("ProfileIpamNamespaceUpsert", &[("ok", "Boolean"), ("object", "ProfileIpamNamespace")]),
+    ("ProposedChangeApprovalsRevokedEvent", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String"), ("payload", "GenericScalar")]),
+    ("ProposedChangeCheckForApprovalRevoke", &[("ok", "Boolean")]),
+    ("ProposedChangeMerge", &[("ok", "Boolean"), ("task", "TaskInfo")]),
+    ("ProposedChangeMergedEvent", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String"), ("merged_by_account_id", "String"), ("merged_by_account_name", "String"), ("payload", "GenericScalar")]),
+    ("ProposedChangeRequestRunCheck", &[("ok", "Boolean")]),
+    ("ProposedChangeReview", &[("ok", "Boolean")]),
+    ("ProposedChangeReviewEvent", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String"), ("reviewer_account_id", "String"), ("reviewer_account_name", "String"), ("reviewer_decision", "String"), ("payload", "GenericScalar")]),
+    ("ProposedChangeReviewRequestedEvent", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String"), ("requested_by_account_id", "String"), ("requested_by_account_name", "String"), ("payload", "GenericScalar")]),
+    ("ProposedChangeReviewRevokedEvent", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String"), ("reviewer_account_id", "String"), ("reviewer_account_name", "String"), ("reviewer_former_decision", "String"), ("payload", "GenericScalar")]),
+    ("ProposedChangeThreadEvent", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String"), ("payload", "GenericScalar")]),
+    ("ReadOnlyRepositoryImportLastCommit", &[("ok", "Boolean"), ("task", "TaskInfo")]),
+    ("RecomputeComputedAttribute", &[("ok", "Boolean")]),
+    ("RelatedNode", &[("id", "String"), ("kind", "String")]),
+    ("Relationship", &[("updated_by", "String"), ("updated_at", "DateTime"), ("id", "String"), ("identifier", "String"), ("peers", "RelationshipPeer")]),
+    ("RelationshipAdd", &[("ok", "Boolean")]),
+    ("RelationshipNode", &[("node", "Relationship")]),
+    ("RelationshipPeer", &[("id", "String"), ("kind", "String")]),
+    ("RelationshipProperty", &[("is_protected", "Boolean"), ("updated_at", "DateTime"), ("source", "LineageSource"), ("owner", "LineageOwner")]),
+    ("RelationshipRemove", &[("ok", "Boolean")]),
+    ("Relationships", &[("edges", "RelationshipNode"), ("count", "Int")]),
+    ("RequiredStringValueField", &[("value", "String")]),
+    ("ResolveDiffConflict", &[("ok", "Boolean")]),
+    ("SchemaDropdownAdd", &[("ok", "Boolean"), ("object", "DropdownFields")]),
+    ("SchemaDropdownRemove", &[("ok", "Boolean")]),
+    ("SchemaEnumAdd", &[("ok", "Boolean")]),
+    ("SchemaEnumRemove", &[("ok", "Boolean")]),
+    ("StandardEvent", &[("id", "String"), ("event", "String"), ("branch", "String"), ("account_id", "String"), ("occurred_at", "DateTime"), ("level", "Int"), ("primary_node", "RelatedNode"), ("related_nodes", "RelatedNode"), ("has_children", "Boolean"), ("parent_id", "String"), ("payload", "GenericScalar")]),
+    ("Status", &[("summary", "StatusSummary"), ("workers", "StatusWorkerEdges")]),
+    ("StatusField", &[("value", "BranchStatus")]),
+    ("StatusSummary", &[("schema_hash_synced", "Boolean")]),
+    ("StatusWorker", &[("id", "String"), ("active", "Boolean"), ("schema_hash", "String")]),
+    ("StatusWorkerEdge", &[("node", "StatusWorker")]),
+    ("StatusWorkerEdges", &[("edges", "StatusWorkerEdge")]),
+    ("Subscription", &[("query", "GenericScalar")]),
+    ("TaskInfo", &[("id", "String")]),
+    ("TaskLog", &[("message", "String"), ("severity", "String"), ("task_id", "String"), ("timestamp", "String"), ("id", "String")]),
+    ("TaskLogEdge", &[("edges", "TaskLogNodes"), ("count", "Int")]),
+    ("TaskLogNodes", &[("node", "TaskLog")]),
+    ("TaskNode", &[("id", "String"), ("title", "String"), ("conclusion", "String"), ("state", "StateType"), ("progress", "Float"), ("workflow", "String"), ("branch", "String"), ("created_at", "String"), ("updated_at", "String"), ("parameters", "GenericScalar"), ("tags", "String"), ("start_time", "String"), ("related_nodes", "TaskRelatedNode"), ("logs", "TaskLogEdge")]),
+    ("TaskNodes", &[("node", "TaskNode")]),
+    ("TaskRelatedNode", &[("id", "String"), ("kind", "String")]),
+    ("Tasks", &[("edges", "TaskNodes"), ("count", "Int")]),
+    ("TextAttribute", &[("is_default", "Boolean"), ("is_protected", "Boolean"), ("updated_at", "DateTime"), ("id", "String"), ("is_from_profile", "Boolean"), ("permissions", "PermissionType"), ("value", "String"), ("source", "LineageSource"), ("owner", "LineageOwner"), ("updated_by", "CoreGenericAccount")]),
+    ("UpdateComputedAttribute", &[("ok", "Boolean")]),
+    ("UpdateDisplayLabel", &[("ok", "Boolean")]),
+    ("UpdateHFID", &[("ok", "Boolean")]),
+    ("ValidateRepositoryConnectivity", &[("ok", "Boolean"), ("message", "String")]),
+    ("ValueType", &[("value", "String")]),
+]);
@@ -136,6 +136,7 @@ pub struct AccountGlobalPermissionEdge {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountGlobalPermissionEdges {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<AccountGlobalPermissionEdge>,
 }
 
@@ -206,6 +207,7 @@ pub struct AccountObjectPermissionEdge {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountObjectPermissionEdges {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<AccountObjectPermissionEdge>,
 }
 
@@ -235,6 +237,7 @@ pub struct AccountTokenEdge {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountTokenEdges {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<AccountTokenEdge>,
 }
 
@@ -293,6 +296,7 @@ pub struct ArtifactEvent {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AvailableActions {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<ActionAvailabilityEdge>,
 }
 
@@ -3536,6 +3540,7 @@ pub struct EventNodes {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Events {
+    #[serde(default)]
     pub edges: Vec<EventNodes>,
     pub count: i64,
 }
@@ -3705,6 +3710,7 @@ pub struct InfrahubBranchEdge {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InfrahubBranchType {
     pub count: Option<i64>,
+    #[serde(default)]
     pub edges: Vec<InfrahubBranchEdge>,
     pub default_branch: Box<InfrahubBranch>,
 }
@@ -5302,6 +5308,7 @@ pub struct NestedPaginatedBuiltinIPPrefix {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedBuiltinTag {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedBuiltinTag>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5309,6 +5316,7 @@ pub struct NestedPaginatedBuiltinTag {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreAccount {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreAccount>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5316,6 +5324,7 @@ pub struct NestedPaginatedCoreAccount {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreAccountGroup {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreAccountGroup>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5323,6 +5332,7 @@ pub struct NestedPaginatedCoreAccountGroup {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreAccountRole {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreAccountRole>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5336,6 +5346,7 @@ pub struct NestedPaginatedCoreAction {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreArtifact {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreArtifact>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5343,6 +5354,7 @@ pub struct NestedPaginatedCoreArtifact {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreArtifactCheck {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreArtifactCheck>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5350,6 +5362,7 @@ pub struct NestedPaginatedCoreArtifactCheck {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreArtifactDefinition {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreArtifactDefinition>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5363,6 +5376,7 @@ pub struct NestedPaginatedCoreArtifactTarget {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreArtifactThread {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreArtifactThread>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5370,6 +5384,7 @@ pub struct NestedPaginatedCoreArtifactThread {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreArtifactValidator {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreArtifactValidator>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5383,6 +5398,7 @@ pub struct NestedPaginatedCoreBasePermission {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreChangeComment {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreChangeComment>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5390,6 +5406,7 @@ pub struct NestedPaginatedCoreChangeComment {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreChangeThread {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreChangeThread>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5403,6 +5420,7 @@ pub struct NestedPaginatedCoreCheck {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreCheckDefinition {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreCheckDefinition>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5422,6 +5440,7 @@ pub struct NestedPaginatedCoreCredential {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreCustomWebhook {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreCustomWebhook>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5429,6 +5448,7 @@ pub struct NestedPaginatedCoreCustomWebhook {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreDataCheck {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreDataCheck>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5436,6 +5456,7 @@ pub struct NestedPaginatedCoreDataCheck {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreDataValidator {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreDataValidator>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5443,6 +5464,7 @@ pub struct NestedPaginatedCoreDataValidator {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreEnvKeyValue {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreEnvKeyValue>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5450,6 +5472,7 @@ pub struct NestedPaginatedCoreEnvKeyValue {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreFileCheck {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreFileCheck>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5463,6 +5486,7 @@ pub struct NestedPaginatedCoreFileObject {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreFileThread {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreFileThread>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5470,6 +5494,7 @@ pub struct NestedPaginatedCoreFileThread {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreGeneratorAction {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreGeneratorAction>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5477,6 +5502,7 @@ pub struct NestedPaginatedCoreGeneratorAction {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreGeneratorAwareGroup {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreGeneratorAwareGroup>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5484,6 +5510,7 @@ pub struct NestedPaginatedCoreGeneratorAwareGroup {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreGeneratorCheck {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreGeneratorCheck>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5491,6 +5518,7 @@ pub struct NestedPaginatedCoreGeneratorCheck {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreGeneratorDefinition {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreGeneratorDefinition>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5498,6 +5526,7 @@ pub struct NestedPaginatedCoreGeneratorDefinition {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreGeneratorGroup {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreGeneratorGroup>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5505,6 +5534,7 @@ pub struct NestedPaginatedCoreGeneratorGroup {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreGeneratorInstance {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreGeneratorInstance>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5512,6 +5542,7 @@ pub struct NestedPaginatedCoreGeneratorInstance {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreGeneratorValidator {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreGeneratorValidator>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5531,6 +5562,7 @@ pub struct NestedPaginatedCoreGenericRepository {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreGlobalPermission {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreGlobalPermission>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5538,6 +5570,7 @@ pub struct NestedPaginatedCoreGlobalPermission {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreGraphQLQuery {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreGraphQLQuery>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5545,6 +5578,7 @@ pub struct NestedPaginatedCoreGraphQLQuery {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreGraphQLQueryGroup {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreGraphQLQueryGroup>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5558,6 +5592,7 @@ pub struct NestedPaginatedCoreGroup {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreGroupAction {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreGroupAction>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5565,6 +5600,7 @@ pub struct NestedPaginatedCoreGroupAction {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreGroupTriggerRule {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreGroupTriggerRule>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5572,6 +5608,7 @@ pub struct NestedPaginatedCoreGroupTriggerRule {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreIPAddressPool {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreIPAddressPool>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5579,6 +5616,7 @@ pub struct NestedPaginatedCoreIPAddressPool {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreIPPrefixPool {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreIPPrefixPool>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5598,6 +5636,7 @@ pub struct NestedPaginatedCoreMenu {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreMenuItem {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreMenuItem>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5611,6 +5650,7 @@ pub struct NestedPaginatedCoreNode {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreNodeTriggerAttributeMatch {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreNodeTriggerAttributeMatch>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5624,6 +5664,7 @@ pub struct NestedPaginatedCoreNodeTriggerMatch {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreNodeTriggerRelationshipMatch {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreNodeTriggerRelationshipMatch>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5631,6 +5672,7 @@ pub struct NestedPaginatedCoreNodeTriggerRelationshipMatch {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreNodeTriggerRule {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreNodeTriggerRule>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5638,6 +5680,7 @@ pub struct NestedPaginatedCoreNodeTriggerRule {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreNumberPool {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreNumberPool>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5651,6 +5694,7 @@ pub struct NestedPaginatedCoreObjectComponentTemplate {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreObjectPermission {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreObjectPermission>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5664,6 +5708,7 @@ pub struct NestedPaginatedCoreObjectTemplate {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreObjectThread {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreObjectThread>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5671,6 +5716,7 @@ pub struct NestedPaginatedCoreObjectThread {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCorePasswordCredential {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCorePasswordCredential>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5684,6 +5730,7 @@ pub struct NestedPaginatedCoreProfile {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreProposedChange {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreProposedChange>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5691,6 +5738,7 @@ pub struct NestedPaginatedCoreProposedChange {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreReadOnlyRepository {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreReadOnlyRepository>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5698,6 +5746,7 @@ pub struct NestedPaginatedCoreReadOnlyRepository {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreRepository {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreRepository>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5705,6 +5754,7 @@ pub struct NestedPaginatedCoreRepository {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreRepositoryGroup {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreRepositoryGroup>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5712,6 +5762,7 @@ pub struct NestedPaginatedCoreRepositoryGroup {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreRepositoryValidator {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreRepositoryValidator>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5725,6 +5776,7 @@ pub struct NestedPaginatedCoreResourcePool {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreSchemaCheck {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreSchemaCheck>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5732,6 +5784,7 @@ pub struct NestedPaginatedCoreSchemaCheck {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreSchemaValidator {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreSchemaValidator>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5739,6 +5792,7 @@ pub struct NestedPaginatedCoreSchemaValidator {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreStandardCheck {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreStandardCheck>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5746,6 +5800,7 @@ pub struct NestedPaginatedCoreStandardCheck {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreStandardGroup {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreStandardGroup>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5753,6 +5808,7 @@ pub struct NestedPaginatedCoreStandardGroup {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreStandardWebhook {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreStandardWebhook>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5760,6 +5816,7 @@ pub struct NestedPaginatedCoreStandardWebhook {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreStaticKeyValue {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreStaticKeyValue>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5779,6 +5836,7 @@ pub struct NestedPaginatedCoreThread {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreThreadComment {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreThreadComment>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5786,6 +5844,7 @@ pub struct NestedPaginatedCoreThreadComment {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreTransformJinja2 {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreTransformJinja2>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5793,6 +5852,7 @@ pub struct NestedPaginatedCoreTransformJinja2 {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreTransformPython {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreTransformPython>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5812,6 +5872,7 @@ pub struct NestedPaginatedCoreTriggerRule {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedCoreUserValidator {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedCoreUserValidator>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5837,6 +5898,7 @@ pub struct NestedPaginatedCoreWeightedPoolResource {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedInternalAccountToken {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedInternalAccountToken>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5844,6 +5906,7 @@ pub struct NestedPaginatedInternalAccountToken {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedInternalExternalIdentity {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedInternalExternalIdentity>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5851,6 +5914,7 @@ pub struct NestedPaginatedInternalExternalIdentity {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedInternalIPPrefixAvailable {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedInternalIPPrefixAvailable>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5858,6 +5922,7 @@ pub struct NestedPaginatedInternalIPPrefixAvailable {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedInternalIPRangeAvailable {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedInternalIPRangeAvailable>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5865,6 +5930,7 @@ pub struct NestedPaginatedInternalIPRangeAvailable {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedInternalRefreshToken {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedInternalRefreshToken>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5872,6 +5938,7 @@ pub struct NestedPaginatedInternalRefreshToken {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedIpamNamespace {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedIpamNamespace>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5891,6 +5958,7 @@ pub struct NestedPaginatedLineageSource {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedProfileBuiltinIPAddress {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedProfileBuiltinIPAddress>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5898,6 +5966,7 @@ pub struct NestedPaginatedProfileBuiltinIPAddress {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedProfileBuiltinIPPrefix {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedProfileBuiltinIPPrefix>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5905,6 +5974,7 @@ pub struct NestedPaginatedProfileBuiltinIPPrefix {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedProfileBuiltinTag {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedProfileBuiltinTag>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5912,6 +5982,7 @@ pub struct NestedPaginatedProfileBuiltinTag {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NestedPaginatedProfileIpamNamespace {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NestedEdgedProfileIpamNamespace>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -5930,6 +6001,7 @@ pub struct NodeEdge {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NodeEdges {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<NodeEdge>,
     pub parent_prefixes: Option<Vec<NodeEdge>>,
 }
@@ -5997,6 +6069,7 @@ pub struct ObjectPermissionNode {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedBuiltinIPAddress {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedBuiltinIPAddress>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6004,6 +6077,7 @@ pub struct PaginatedBuiltinIPAddress {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedBuiltinIPNamespace {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedBuiltinIPNamespace>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6011,6 +6085,7 @@ pub struct PaginatedBuiltinIPNamespace {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedBuiltinIPPrefix {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedBuiltinIPPrefix>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6018,6 +6093,7 @@ pub struct PaginatedBuiltinIPPrefix {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedBuiltinTag {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedBuiltinTag>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6025,6 +6101,7 @@ pub struct PaginatedBuiltinTag {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreAccount {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreAccount>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6032,6 +6109,7 @@ pub struct PaginatedCoreAccount {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreAccountGroup {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreAccountGroup>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6039,6 +6117,7 @@ pub struct PaginatedCoreAccountGroup {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreAccountRole {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreAccountRole>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6046,6 +6125,7 @@ pub struct PaginatedCoreAccountRole {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreAction {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreAction>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6053,6 +6133,7 @@ pub struct PaginatedCoreAction {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreArtifact {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreArtifact>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6060,6 +6141,7 @@ pub struct PaginatedCoreArtifact {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreArtifactCheck {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreArtifactCheck>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6067,6 +6149,7 @@ pub struct PaginatedCoreArtifactCheck {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreArtifactDefinition {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreArtifactDefinition>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6074,6 +6157,7 @@ pub struct PaginatedCoreArtifactDefinition {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreArtifactTarget {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreArtifactTarget>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6081,6 +6165,7 @@ pub struct PaginatedCoreArtifactTarget {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreArtifactThread {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreArtifactThread>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6088,6 +6173,7 @@ pub struct PaginatedCoreArtifactThread {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreArtifactValidator {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreArtifactValidator>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6095,6 +6181,7 @@ pub struct PaginatedCoreArtifactValidator {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreBasePermission {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreBasePermission>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6102,6 +6189,7 @@ pub struct PaginatedCoreBasePermission {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreChangeComment {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreChangeComment>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6109,6 +6197,7 @@ pub struct PaginatedCoreChangeComment {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreChangeThread {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreChangeThread>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6116,6 +6205,7 @@ pub struct PaginatedCoreChangeThread {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreCheck {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreCheck>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6123,6 +6213,7 @@ pub struct PaginatedCoreCheck {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreCheckDefinition {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreCheckDefinition>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6130,6 +6221,7 @@ pub struct PaginatedCoreCheckDefinition {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreComment {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreComment>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6137,6 +6229,7 @@ pub struct PaginatedCoreComment {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreCredential {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreCredential>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6144,6 +6237,7 @@ pub struct PaginatedCoreCredential {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreCustomWebhook {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreCustomWebhook>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6151,6 +6245,7 @@ pub struct PaginatedCoreCustomWebhook {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreDataCheck {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreDataCheck>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6158,6 +6253,7 @@ pub struct PaginatedCoreDataCheck {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreDataValidator {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreDataValidator>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6165,6 +6261,7 @@ pub struct PaginatedCoreDataValidator {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreEnvKeyValue {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreEnvKeyValue>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6172,6 +6269,7 @@ pub struct PaginatedCoreEnvKeyValue {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreFileCheck {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreFileCheck>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6179,6 +6277,7 @@ pub struct PaginatedCoreFileCheck {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreFileObject {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreFileObject>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6186,6 +6285,7 @@ pub struct PaginatedCoreFileObject {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreFileThread {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreFileThread>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6193,6 +6293,7 @@ pub struct PaginatedCoreFileThread {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreGeneratorAction {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreGeneratorAction>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6200,6 +6301,7 @@ pub struct PaginatedCoreGeneratorAction {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreGeneratorAwareGroup {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreGeneratorAwareGroup>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6207,6 +6309,7 @@ pub struct PaginatedCoreGeneratorAwareGroup {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreGeneratorCheck {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreGeneratorCheck>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6214,6 +6317,7 @@ pub struct PaginatedCoreGeneratorCheck {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreGeneratorDefinition {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreGeneratorDefinition>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6221,6 +6325,7 @@ pub struct PaginatedCoreGeneratorDefinition {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreGeneratorGroup {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreGeneratorGroup>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6228,6 +6333,7 @@ pub struct PaginatedCoreGeneratorGroup {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreGeneratorInstance {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreGeneratorInstance>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6235,6 +6341,7 @@ pub struct PaginatedCoreGeneratorInstance {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreGeneratorValidator {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreGeneratorValidator>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6242,6 +6349,7 @@ pub struct PaginatedCoreGeneratorValidator {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreGenericAccount {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreGenericAccount>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6249,6 +6357,7 @@ pub struct PaginatedCoreGenericAccount {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreGenericRepository {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreGenericRepository>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6256,6 +6365,7 @@ pub struct PaginatedCoreGenericRepository {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreGlobalPermission {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreGlobalPermission>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6263,6 +6373,7 @@ pub struct PaginatedCoreGlobalPermission {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreGraphQLQuery {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreGraphQLQuery>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6270,6 +6381,7 @@ pub struct PaginatedCoreGraphQLQuery {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreGraphQLQueryGroup {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreGraphQLQueryGroup>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6277,6 +6389,7 @@ pub struct PaginatedCoreGraphQLQueryGroup {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreGroup {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreGroup>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6284,6 +6397,7 @@ pub struct PaginatedCoreGroup {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreGroupAction {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreGroupAction>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6291,6 +6405,7 @@ pub struct PaginatedCoreGroupAction {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreGroupTriggerRule {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreGroupTriggerRule>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6298,6 +6413,7 @@ pub struct PaginatedCoreGroupTriggerRule {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreIPAddressPool {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreIPAddressPool>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6305,6 +6421,7 @@ pub struct PaginatedCoreIPAddressPool {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreIPPrefixPool {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreIPPrefixPool>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6312,6 +6429,7 @@ pub struct PaginatedCoreIPPrefixPool {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreKeyValue {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreKeyValue>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6319,6 +6437,7 @@ pub struct PaginatedCoreKeyValue {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreMenu {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreMenu>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6326,6 +6445,7 @@ pub struct PaginatedCoreMenu {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreMenuItem {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreMenuItem>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6333,6 +6453,7 @@ pub struct PaginatedCoreMenuItem {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreNode {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreNode>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6340,6 +6461,7 @@ pub struct PaginatedCoreNode {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreNodeTriggerAttributeMatch {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreNodeTriggerAttributeMatch>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6347,6 +6469,7 @@ pub struct PaginatedCoreNodeTriggerAttributeMatch {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreNodeTriggerMatch {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreNodeTriggerMatch>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6354,6 +6477,7 @@ pub struct PaginatedCoreNodeTriggerMatch {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreNodeTriggerRelationshipMatch {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreNodeTriggerRelationshipMatch>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6361,6 +6485,7 @@ pub struct PaginatedCoreNodeTriggerRelationshipMatch {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreNodeTriggerRule {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreNodeTriggerRule>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6368,6 +6493,7 @@ pub struct PaginatedCoreNodeTriggerRule {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreNumberPool {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreNumberPool>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6375,6 +6501,7 @@ pub struct PaginatedCoreNumberPool {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreObjectComponentTemplate {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreObjectComponentTemplate>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6382,6 +6509,7 @@ pub struct PaginatedCoreObjectComponentTemplate {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreObjectPermission {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreObjectPermission>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6389,6 +6517,7 @@ pub struct PaginatedCoreObjectPermission {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreObjectTemplate {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreObjectTemplate>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6396,6 +6525,7 @@ pub struct PaginatedCoreObjectTemplate {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreObjectThread {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreObjectThread>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6403,6 +6533,7 @@ pub struct PaginatedCoreObjectThread {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCorePasswordCredential {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCorePasswordCredential>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6410,6 +6541,7 @@ pub struct PaginatedCorePasswordCredential {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreProfile {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreProfile>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6417,6 +6549,7 @@ pub struct PaginatedCoreProfile {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreProposedChange {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreProposedChange>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6424,6 +6557,7 @@ pub struct PaginatedCoreProposedChange {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreReadOnlyRepository {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreReadOnlyRepository>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6431,6 +6565,7 @@ pub struct PaginatedCoreReadOnlyRepository {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreRepository {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreRepository>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6438,6 +6573,7 @@ pub struct PaginatedCoreRepository {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreRepositoryGroup {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreRepositoryGroup>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6445,6 +6581,7 @@ pub struct PaginatedCoreRepositoryGroup {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreRepositoryValidator {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreRepositoryValidator>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6452,6 +6589,7 @@ pub struct PaginatedCoreRepositoryValidator {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreResourcePool {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreResourcePool>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6459,6 +6597,7 @@ pub struct PaginatedCoreResourcePool {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreSchemaCheck {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreSchemaCheck>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6466,6 +6605,7 @@ pub struct PaginatedCoreSchemaCheck {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreSchemaValidator {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreSchemaValidator>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6473,6 +6613,7 @@ pub struct PaginatedCoreSchemaValidator {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreStandardCheck {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreStandardCheck>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6480,6 +6621,7 @@ pub struct PaginatedCoreStandardCheck {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreStandardGroup {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreStandardGroup>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6487,6 +6629,7 @@ pub struct PaginatedCoreStandardGroup {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreStandardWebhook {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreStandardWebhook>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6494,6 +6637,7 @@ pub struct PaginatedCoreStandardWebhook {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreStaticKeyValue {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreStaticKeyValue>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6501,6 +6645,7 @@ pub struct PaginatedCoreStaticKeyValue {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreTaskTarget {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreTaskTarget>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6508,6 +6653,7 @@ pub struct PaginatedCoreTaskTarget {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreThread {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreThread>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6515,6 +6661,7 @@ pub struct PaginatedCoreThread {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreThreadComment {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreThreadComment>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6522,6 +6669,7 @@ pub struct PaginatedCoreThreadComment {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreTransformJinja2 {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreTransformJinja2>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6529,6 +6677,7 @@ pub struct PaginatedCoreTransformJinja2 {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreTransformPython {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreTransformPython>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6536,6 +6685,7 @@ pub struct PaginatedCoreTransformPython {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreTransformation {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreTransformation>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6543,6 +6693,7 @@ pub struct PaginatedCoreTransformation {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreTriggerRule {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreTriggerRule>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6550,6 +6701,7 @@ pub struct PaginatedCoreTriggerRule {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreUserValidator {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreUserValidator>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6557,6 +6709,7 @@ pub struct PaginatedCoreUserValidator {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreValidator {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreValidator>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6564,6 +6717,7 @@ pub struct PaginatedCoreValidator {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreWebhook {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreWebhook>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6571,6 +6725,7 @@ pub struct PaginatedCoreWebhook {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedCoreWeightedPoolResource {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedCoreWeightedPoolResource>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6578,6 +6733,7 @@ pub struct PaginatedCoreWeightedPoolResource {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedInternalAccountToken {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedInternalAccountToken>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6585,6 +6741,7 @@ pub struct PaginatedInternalAccountToken {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedInternalExternalIdentity {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedInternalExternalIdentity>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6592,6 +6749,7 @@ pub struct PaginatedInternalExternalIdentity {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedInternalIPPrefixAvailable {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedInternalIPPrefixAvailable>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6599,6 +6757,7 @@ pub struct PaginatedInternalIPPrefixAvailable {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedInternalIPRangeAvailable {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedInternalIPRangeAvailable>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6606,6 +6765,7 @@ pub struct PaginatedInternalIPRangeAvailable {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedInternalRefreshToken {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedInternalRefreshToken>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6613,6 +6773,7 @@ pub struct PaginatedInternalRefreshToken {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedIpamNamespace {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedIpamNamespace>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6620,6 +6781,7 @@ pub struct PaginatedIpamNamespace {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedLineageOwner {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedLineageOwner>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6627,6 +6789,7 @@ pub struct PaginatedLineageOwner {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedLineageSource {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedLineageSource>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6634,12 +6797,14 @@ pub struct PaginatedLineageSource {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedObjectPermission {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<ObjectPermissionNode>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedProfileBuiltinIPAddress {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedProfileBuiltinIPAddress>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6647,6 +6812,7 @@ pub struct PaginatedProfileBuiltinIPAddress {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedProfileBuiltinIPPrefix {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedProfileBuiltinIPPrefix>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6654,6 +6820,7 @@ pub struct PaginatedProfileBuiltinIPPrefix {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedProfileBuiltinTag {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedProfileBuiltinTag>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6661,6 +6828,7 @@ pub struct PaginatedProfileBuiltinTag {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedProfileIpamNamespace {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<EdgedProfileIpamNamespace>,
     pub permissions: Box<PaginatedObjectPermission>,
 }
@@ -6673,6 +6841,7 @@ pub struct PermissionType {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolAllocated {
     pub count: i64,
+    #[serde(default)]
     pub edges: Vec<PoolAllocatedEdge>,
 }
 
@@ -6696,6 +6865,7 @@ pub struct PoolUtilization {
     pub utilization: f64,
     pub utilization_branches: f64,
     pub utilization_default_branch: f64,
+    #[serde(default)]
     pub edges: Vec<IPPrefixUtilizationEdge>,
 }
 
@@ -7035,6 +7205,7 @@ pub struct RelationshipRemove {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Relationships {
+    #[serde(default)]
     pub edges: Vec<RelationshipNode>,
     pub count: i64,
 }
@@ -7139,6 +7310,7 @@ pub struct TaskLog {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskLogEdge {
+    #[serde(default)]
     pub edges: Vec<TaskLogNodes>,
     pub count: i64,
 }
@@ -7179,6 +7351,7 @@ pub struct TaskRelatedNode {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tasks {
+    #[serde(default)]
     pub edges: Vec<TaskNodes>,
     pub count: i64,
 }
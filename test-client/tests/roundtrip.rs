@@ -0,0 +1,67 @@
+//! roundtrip tests for the generated types
+//!
+//! a node deserialized from a response and serialized again should produce
+//! the same json for every field the schema defines. these run offline.
+
+use infrahub_test_client::types::BuiltinTag;
+use serde_json::{json, Value};
+
+fn text_attribute(value: &str) -> Value {
+    json!({
+        "is_default": false,
+        "is_protected": true,
+        "updated_at": "2024-05-01T10:00:00Z",
+        "id": "attr-1",
+        "is_from_profile": false,
+        "permissions": null,
+        "value": value,
+        "source": { "id": "account-1" },
+        "owner": null,
+        "updated_by": null,
+    })
+}
+
+fn tag() -> Value {
+    json!({
+        "id": "tag-1",
+        "hfid": ["red"],
+        "display_label": "red",
+        "name": text_attribute("red"),
+        "description": text_attribute("a colour"),
+        "subscriber_of_groups": { "count": 0, "edges": [] },
+        "member_of_groups": { "count": 2, "edges": null },
+        "profiles": { "count": 0, "edges": [] },
+    })
+}
+
+#[test]
+fn node_roundtrips_every_defined_field() {
+    let original = tag();
+    let node: BuiltinTag = serde_json::from_value(original.clone()).expect("deserialize");
+    assert_eq!(serde_json::to_value(&node).expect("serialize"), original);
+}
+
+#[test]
+fn nullable_fields_roundtrip_as_null() {
+    let mut original = tag();
+    original["hfid"] = Value::Null;
+    original["display_label"] = Value::Null;
+    original["description"] = Value::Null;
+    let node: BuiltinTag = serde_json::from_value(original.clone()).expect("deserialize");
+    assert_eq!(serde_json::to_value(&node).expect("serialize"), original);
+}
+
+#[test]
+fn unknown_fields_are_dropped_without_capture() {
+    // generated without `capture_unknown_fields`; see docs/codegen.md
+    let mut original = tag();
+    original["added_in_newer_server"] = json!(true);
+    let node: BuiltinTag = serde_json::from_value(original.clone()).expect("deserialize");
+    let value = serde_json::to_value(&node).expect("serialize");
+    assert!(value.get("added_in_newer_server").is_none());
+    original
+        .as_object_mut()
+        .unwrap()
+        .remove("added_in_newer_server");
+    assert_eq!(value, original);
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "macros")]
+
+use infrahub::testing::MockClient;
+use infrahub::Operation;
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Debug, Deserialize)]
+struct Tags {
+    #[serde(rename = "BuiltinTag")]
+    tags: infrahub::Paginated<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Tag {
+    id: String,
+}
+
+infrahub::query! {
+    schema = "schema/infrahub.graphql",
+    query = r#"
+        query GetTags($name: String) {
+            BuiltinTag(name__value: $name) {
+                count
+                edges { node { id name { value } } }
+            }
+        }
+    "#,
+    response = Tags,
+}
+
+infrahub::query! {
+    schema = "schema/infrahub.graphql",
+    query = "query Branches { Branch { id name } }",
+}
+
+#[test]
+fn test_generated_operation() {
+    assert_eq!(GetTags::NAME, "GetTags");
+    assert!(GetTags::QUERY.starts_with("query GetTags($name: String)"));
+    assert_eq!(Branches::NAME, "Branches");
+    assert!(Branches::check_variables(None).is_ok());
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn test_execute_generated_operation() {
+    let mock = MockClient::new();
+    mock.expect("GetTags").returning(json!({
+        "BuiltinTag": { "count": 1, "edges": [{ "node": { "id": "t1" } }] }
+    }));
+    let response = mock
+        .client()
+        .execute_operation::<GetTags>(Some(json!({ "name": "red" })), None)
+        .await
+        .unwrap();
+    let tags = response.into_data().unwrap().tags;
+    assert_eq!(tags.count, 1);
+    assert_eq!(tags.nodes[0].id, "t1");
+}